@@ -29,7 +29,7 @@ use itp_types::{
 	parentchain::{FilterEvents, HandleParentchainEvents, ParentchainEventProcessingError},
 	WorkerType,
 };
-use litentry_primitives::{Address32, Identity};
+use litentry_primitives::{Address32, Identity, RelayerRole};
 use log::*;
 use sp_core::{blake2_256, H256};
 use sp_std::vec::Vec;
@@ -38,9 +38,14 @@ use std::string::ToString;
 pub struct ParentchainEventHandler {}
 
 impl ParentchainEventHandler {
-	fn add_relayer(relayer_registry: &RelayerRegistry, account: Identity) -> Result<(), Error> {
-		info!("Adding Relayer Account to Registry: {:?}", account);
-		relayer_registry.update(account).map_err(|e| {
+	// expiry is not tracked here, the pallet prunes expired relayers and emits `RelayerRemoved`
+	fn add_relayer(
+		relayer_registry: &RelayerRegistry,
+		account: Identity,
+		role: RelayerRole,
+	) -> Result<(), Error> {
+		info!("Adding Relayer Account to Registry: {:?}, role: {:?}", account, role);
+		relayer_registry.update(account, role).map_err(|e| {
 			error!("Error adding relayer: {:?}", e);
 			Error::Other("Error adding relayer".into())
 		})?;
@@ -107,6 +112,20 @@ impl ParentchainEventHandler {
 
 		Ok(())
 	}
+
+	fn save_sol_signer(
+		signer_registry: &SignerRegistry,
+		account_id: Address32,
+		pub_key: [u8; 32],
+	) -> Result<(), Error> {
+		info!("Saving Solana Signer Account to Registry: {:?}", account_id);
+		signer_registry.update_sol(account_id, pub_key).map_err(|e| {
+			error!("Error saving solana signer: {:?}", e);
+			Error::Other("Error saving solana signer".into())
+		})?;
+
+		Ok(())
+	}
 }
 
 impl<Executor>
@@ -137,7 +156,23 @@ where
 				.iter()
 				.try_for_each(|event| {
 					debug!("found RelayerAdded event: {:?}", event);
-					let result = Self::add_relayer(relayer_registry, event.who.clone());
+					let result =
+						Self::add_relayer(relayer_registry, event.who.clone(), RelayerRole::Full);
+					handled_events.push(hash_of(&event));
+
+					result
+				})
+				.map_err(|_| ParentchainEventProcessingError::RelayerAddFailure)?;
+		}
+
+		if let Ok(events) = events.get_relayer_set_events() {
+			debug!("Handling RelayerSet events");
+			let relayer_registry = executor.get_relayer_registry_updater();
+			events
+				.iter()
+				.try_for_each(|event| {
+					debug!("found RelayerSet event: {:?}", event);
+					let result = Self::add_relayer(relayer_registry, event.who.clone(), event.role);
 					handled_events.push(hash_of(&event));
 
 					result
@@ -195,6 +230,15 @@ where
 				.map_err(|_| ParentchainEventProcessingError::EnclaveRemoveFailure)?;
 		}
 
+		// the pallet emits `EnclaveRemoved` along with each eviction, which updates the registry
+		if let Ok(events) = events.get_enclave_evicted_events() {
+			debug!("Handling EnclaveEvicted events");
+			events.iter().for_each(|event| {
+				warn!("Enclave {:?} was evicted, reason: {:?}", event.who, event.reason);
+				handled_events.push(hash_of(&event));
+			});
+		}
+
 		if let Ok(events) = events.get_btc_wallet_generated_events() {
 			debug!("Handling BtcWalletGenerated events");
 			let signer_registry = executor.get_signer_registry_updater();
@@ -214,6 +258,25 @@ where
 				.map_err(|_| ParentchainEventProcessingError::BtcWalletGeneratedFailure)?;
 		}
 
+		if let Ok(events) = events.get_sol_wallet_generated_events() {
+			debug!("Handling SolWalletGenerated events");
+			let signer_registry = executor.get_signer_registry_updater();
+			events
+				.iter()
+				.try_for_each(|event| {
+					debug!("found SolWalletGenerated event: {:?}", event);
+					let result = Self::save_sol_signer(
+						signer_registry,
+						event.account_id.clone().into(),
+						event.pub_key,
+					);
+					handled_events.push(hash_of(&event));
+
+					result
+				})
+				.map_err(|_| ParentchainEventProcessingError::SolWalletGeneratedFailure)?;
+		}
+
 		Ok(handled_events)
 	}
 }
@@ -22,8 +22,8 @@ use itp_node_api::api_client::StaticEvent;
 use itp_types::{
 	parentchain::{
 		events::{
-			BalanceTransfer, BtcWalletGenerated, EnclaveAdded, EnclaveRemoved, EnclaveUnauthorized,
-			RelayerAdded, RelayerRemoved,
+			BalanceTransfer, BtcWalletGenerated, EnclaveAdded, EnclaveEvicted, EnclaveRemoved,
+			EnclaveUnauthorized, RelayerAdded, RelayerRemoved, RelayerSet, SolWalletGenerated,
 		},
 		FilterEvents,
 	},
@@ -82,6 +82,10 @@ impl FilterEvents for FilterableEvents {
 		self.filter()
 	}
 
+	fn get_relayer_set_events(&self) -> Result<Vec<RelayerSet>, Self::Error> {
+		self.filter()
+	}
+
 	fn get_enclave_added_events(&self) -> Result<Vec<EnclaveAdded>, Self::Error> {
 		self.filter()
 	}
@@ -90,7 +94,15 @@ impl FilterEvents for FilterableEvents {
 		self.filter()
 	}
 
+	fn get_enclave_evicted_events(&self) -> Result<Vec<EnclaveEvicted>, Self::Error> {
+		self.filter()
+	}
+
 	fn get_btc_wallet_generated_events(&self) -> Result<Vec<BtcWalletGenerated>, Self::Error> {
 		self.filter()
 	}
+
+	fn get_sol_wallet_generated_events(&self) -> Result<Vec<SolWalletGenerated>, Self::Error> {
+		self.filter()
+	}
 }
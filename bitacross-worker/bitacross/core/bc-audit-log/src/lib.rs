@@ -0,0 +1,640 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Append-only, hash-chained log of all signing requests handled by this enclave.
+//!
+//! Every entry commits to the hash of its predecessor, so the head hash is a
+//! commitment to the full history. The head is periodically anchored on the
+//! parachain, which allows to detect a truncated or rewritten log afterwards.
+//!
+//! The log is split into segments of `SEGMENT_SIZE` entries. Only the open segment
+//! is kept in memory and resealed on append, closed segments are sealed once to
+//! their own file. At most `MAX_SEGMENTS` closed segments are kept, older ones are
+//! dropped as their history is already committed to by the anchored heads.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate core;
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+#[cfg(all(feature = "std", feature = "sgx"))]
+compile_error!("feature \"std\" and feature \"sgx\" cannot be enabled at the same time");
+
+use sp_std::{boxed::Box, fmt::Debug};
+
+use bc_frost_ceremony::SignSolanaPayload;
+use bc_musig2_ceremony::{get_current_timestamp, SignBitcoinPayload};
+use codec::{Decode, Encode};
+use litentry_primitives::{Identity, LitentryMultiSignature};
+use sp_core::{blake2_256, H256};
+use std::{path::PathBuf, vec::Vec};
+
+#[cfg(feature = "sgx")]
+use std::sync::SgxRwLock as RwLock;
+#[cfg(feature = "std")]
+use std::{collections::BTreeMap, sync::RwLock};
+
+/// Maximum number of entries returned by a single lookup
+pub const MAX_ENTRIES_PER_QUERY: u64 = 100;
+/// Number of entries in a sealed segment
+pub const SEGMENT_SIZE: u64 = 1024;
+/// Number of closed segments kept, older ones are dropped
+pub const MAX_SEGMENTS: u64 = 64;
+/// Maximum age in seconds of a signed audit log request
+pub const MAX_REQUEST_AGE: u64 = 60;
+
+pub type RequestHash = H256;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub enum AuditedCall {
+	SignBitcoin(SignBitcoinPayload),
+	SignEthereum([u8; 32]),
+	SignSolana(SignSolanaPayload),
+}
+
+impl AuditedCall {
+	// same hash the request is tracked with in the rpc responder
+	pub fn hash(&self) -> RequestHash {
+		match self {
+			Self::SignBitcoin(payload) => blake2_256(&payload.encode()).into(),
+			Self::SignEthereum(msg) => blake2_256(&msg.encode()).into(),
+			Self::SignSolana(payload) => blake2_256(&payload.encode()).into(),
+		}
+	}
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub enum AuditEvent {
+	// relayer asked the enclave to sign the call
+	Requested(Identity, AuditedCall),
+	// request was signed, carries the produced signature
+	Signed(RequestHash, Vec<u8>),
+	// request was rejected or the signing failed, carries the encoded error
+	Failed(RequestHash, Vec<u8>),
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct AuditLogEntry {
+	pub index: u64,
+	pub timestamp: u64,
+	pub prev_hash: H256,
+	pub event: AuditEvent,
+}
+
+impl AuditLogEntry {
+	pub fn hash(&self) -> H256 {
+		blake2_256(&self.encode()).into()
+	}
+}
+
+/// The open segment of the log, together with the position of the retained history
+#[derive(Encode, Decode, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditLogState {
+	// the oldest closed segment that is still kept
+	pub first_segment: u64,
+	// the segment `entries` belong to
+	pub current_segment: u64,
+	// hash of the last entry of the previous segment
+	pub segment_prev_hash: H256,
+	pub entries: Vec<AuditLogEntry>,
+	pub head: H256,
+}
+
+impl AuditLogState {
+	// number of entries ever appended
+	pub fn total(&self, segment_size: u64) -> u64 {
+		self.current_segment * segment_size + self.entries.len() as u64
+	}
+
+	pub fn append(&mut self, event: AuditEvent, timestamp: u64, segment_size: u64) -> H256 {
+		let entry = AuditLogEntry {
+			index: self.total(segment_size),
+			timestamp,
+			prev_hash: self.head,
+			event,
+		};
+		self.head = entry.hash();
+		self.entries.push(entry);
+		self.head
+	}
+
+	// closes the open segment if it is full, returns it together with the
+	// segment to drop to stay within `max_segments`
+	pub fn rotate(
+		&mut self,
+		segment_size: u64,
+		max_segments: u64,
+	) -> Option<(u64, Vec<AuditLogEntry>, Option<u64>)> {
+		if (self.entries.len() as u64) < segment_size {
+			return None
+		}
+		let closed = core::mem::take(&mut self.entries);
+		let closed_segment = self.current_segment;
+		self.segment_prev_hash = self.head;
+		self.current_segment += 1;
+		let dropped = if self.current_segment - self.first_segment > max_segments {
+			self.first_segment += 1;
+			Some(self.first_segment - 1)
+		} else {
+			None
+		};
+		Some((closed_segment, closed, dropped))
+	}
+
+	// re-computes the hash chain of the open segment, returns false if any link is broken
+	pub fn verify(&self, segment_size: u64) -> bool {
+		verify_segment(self.current_segment, segment_size, self.segment_prev_hash, &self.entries)
+			== Some(self.head)
+	}
+}
+
+// returns the hash of the last entry if `entries` form segment `segment`, chained to `prev_hash`
+pub fn verify_segment(
+	segment: u64,
+	segment_size: u64,
+	mut prev_hash: H256,
+	entries: &[AuditLogEntry],
+) -> Option<H256> {
+	for (pos, entry) in entries.iter().enumerate() {
+		if entry.index != segment * segment_size + pos as u64 || entry.prev_hash != prev_hash {
+			return None
+		}
+		prev_hash = entry.hash();
+	}
+	Some(prev_hash)
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct AuditLogPage {
+	pub total: u64,
+	pub head: H256,
+	pub entries: Vec<AuditLogEntry>,
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct AuditLogRequest {
+	pub from: u64,
+	pub limit: u64,
+	// seconds since unix epoch, requests older than `MAX_REQUEST_AGE` are rejected
+	pub timestamp: u64,
+}
+
+impl AuditLogRequest {
+	// the payload to sign, prefixed so that it can't be replayed as any other signed payload
+	pub fn signature_payload(&self) -> Vec<u8> {
+		(b"bitacross_getAuditLog", self).encode()
+	}
+}
+
+/// A request to read the audit log, only registered relayers may read it
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct AuditLogRequestSigned {
+	pub request: AuditLogRequest,
+	pub signer: Identity,
+	pub signature: LitentryMultiSignature,
+}
+
+impl AuditLogRequestSigned {
+	pub fn verify(&self, now: u64) -> bool {
+		now.saturating_sub(self.request.timestamp) <= MAX_REQUEST_AGE
+			&& self.request.timestamp <= now.saturating_add(MAX_REQUEST_AGE)
+			&& self.signature.verify(&self.request.signature_payload(), &self.signer)
+	}
+}
+
+pub struct AuditLog {
+	pub log: RwLock<AuditLogState>,
+	pub seal_path: PathBuf,
+	pub segment_size: u64,
+	pub max_segments: u64,
+	// closed segments, they are sealed to files in sgx
+	#[cfg(feature = "std")]
+	pub segments: RwLock<BTreeMap<u64, Vec<AuditLogEntry>>>,
+}
+
+impl Default for AuditLog {
+	fn default() -> Self {
+		Self::new(Default::default())
+	}
+}
+
+impl AuditLog {
+	pub fn new(base_dir: PathBuf) -> Self {
+		Self::with_limits(base_dir, SEGMENT_SIZE, MAX_SEGMENTS)
+	}
+
+	pub fn with_limits(base_dir: PathBuf, segment_size: u64, max_segments: u64) -> Self {
+		AuditLog {
+			log: Default::default(),
+			seal_path: base_dir,
+			segment_size,
+			max_segments,
+			#[cfg(feature = "std")]
+			segments: Default::default(),
+		}
+	}
+}
+
+pub type AuditLogResult<T> = core::result::Result<T, AuditLogError>;
+
+#[cfg(feature = "sgx")]
+use thiserror_sgx as thiserror;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuditLogError {
+	#[error("poison lock")]
+	PoisonLock,
+	#[error("broken hash chain")]
+	BrokenChain,
+	#[error(transparent)]
+	Other(#[from] Box<dyn std::error::Error + Sync + Send + 'static>),
+}
+
+impl From<std::io::Error> for AuditLogError {
+	fn from(e: std::io::Error) -> Self {
+		Self::Other(e.into())
+	}
+}
+
+impl From<codec::Error> for AuditLogError {
+	#[cfg(feature = "std")]
+	fn from(e: codec::Error) -> Self {
+		Self::Other(e.into())
+	}
+
+	#[cfg(feature = "sgx")]
+	fn from(e: codec::Error) -> Self {
+		Self::Other(std::format!("{:?}", e).into())
+	}
+}
+
+#[cfg(feature = "sgx")]
+mod sgx {
+	use crate::{AuditLogEntry, AuditLogError as Error, AuditLogResult as Result, AuditLogState};
+	pub use codec::{Decode, Encode};
+	pub use itp_settings::files::{AUDIT_LOG_FILE, AUDIT_LOG_SEGMENT_FILE_PREFIX};
+	pub use itp_sgx_io::{seal, unseal, SealedIO};
+	pub use log::*;
+	pub use std::{boxed::Box, format, fs, path::PathBuf, sgxfs::SgxFile, sync::Arc, vec::Vec};
+
+	#[derive(Clone, Debug)]
+	pub struct AuditLogSeal {
+		base_path: PathBuf,
+	}
+
+	impl AuditLogSeal {
+		pub fn new(base_path: PathBuf) -> Self {
+			Self { base_path }
+		}
+
+		pub fn path(&self) -> PathBuf {
+			self.base_path.join(AUDIT_LOG_FILE)
+		}
+	}
+
+	impl SealedIO for AuditLogSeal {
+		type Error = Error;
+		type Unsealed = AuditLogState;
+
+		fn unseal(&self) -> Result<Self::Unsealed> {
+			Ok(unseal(self.path()).map(|b| Decode::decode(&mut b.as_slice()))??)
+		}
+
+		fn seal(&self, unsealed: &Self::Unsealed) -> Result<()> {
+			debug!(
+				"Seal audit log to file, segment: {}, entries: {}, head: {:?}",
+				unsealed.current_segment,
+				unsealed.entries.len(),
+				unsealed.head
+			);
+			Ok(unsealed.using_encoded(|bytes| seal(bytes, self.path()))?)
+		}
+	}
+
+	// a closed segment, it's sealed once and never modified afterwards
+	#[derive(Clone, Debug)]
+	pub struct AuditLogSegmentSeal {
+		base_path: PathBuf,
+		segment: u64,
+	}
+
+	impl AuditLogSegmentSeal {
+		pub fn new(base_path: PathBuf, segment: u64) -> Self {
+			Self { base_path, segment }
+		}
+
+		pub fn path(&self) -> PathBuf {
+			self.base_path
+				.join(format!("{}_{}.bin", AUDIT_LOG_SEGMENT_FILE_PREFIX, self.segment))
+		}
+
+		pub fn remove(&self) -> Result<()> {
+			Ok(fs::remove_file(self.path())?)
+		}
+	}
+
+	impl SealedIO for AuditLogSegmentSeal {
+		type Error = Error;
+		type Unsealed = Vec<AuditLogEntry>;
+
+		fn unseal(&self) -> Result<Self::Unsealed> {
+			Ok(unseal(self.path()).map(|b| Decode::decode(&mut b.as_slice()))??)
+		}
+
+		fn seal(&self, unsealed: &Self::Unsealed) -> Result<()> {
+			debug!("Seal audit log segment {} to file", self.segment);
+			Ok(unsealed.using_encoded(|bytes| seal(bytes, self.path()))?)
+		}
+	}
+}
+
+#[cfg(feature = "sgx")]
+use sgx::*;
+
+pub trait AuditLogUpdater {
+	fn init(&self) -> AuditLogResult<()>;
+	// appends the event and returns the new head hash
+	fn append(&self, event: AuditEvent) -> AuditLogResult<H256>;
+}
+
+pub trait AuditLogLookup {
+	// number of entries and the current head hash
+	fn head(&self) -> (u64, H256);
+	// entries of dropped segments are skipped
+	fn get_entries(&self, from: u64, limit: u64) -> Vec<AuditLogEntry>;
+}
+
+impl AuditLog {
+	#[cfg(feature = "std")]
+	fn load_segment(&self, segment: u64) -> AuditLogResult<Vec<AuditLogEntry>> {
+		let segments = self.segments.read().map_err(|_| AuditLogError::PoisonLock)?;
+		Ok(segments.get(&segment).cloned().unwrap_or_default())
+	}
+
+	#[cfg(feature = "std")]
+	fn store_segment(
+		&self,
+		segment: u64,
+		entries: Vec<AuditLogEntry>,
+		dropped: Option<u64>,
+	) -> AuditLogResult<()> {
+		let mut segments = self.segments.write().map_err(|_| AuditLogError::PoisonLock)?;
+		segments.insert(segment, entries);
+		if let Some(dropped) = dropped {
+			segments.remove(&dropped);
+		}
+		Ok(())
+	}
+
+	#[cfg(feature = "sgx")]
+	fn load_segment(&self, segment: u64) -> AuditLogResult<Vec<AuditLogEntry>> {
+		AuditLogSegmentSeal::new(self.seal_path.clone(), segment).unseal()
+	}
+
+	#[cfg(feature = "sgx")]
+	fn store_segment(
+		&self,
+		segment: u64,
+		entries: Vec<AuditLogEntry>,
+		dropped: Option<u64>,
+	) -> AuditLogResult<()> {
+		AuditLogSegmentSeal::new(self.seal_path.clone(), segment).seal(&entries)?;
+		if let Some(dropped) = dropped {
+			if let Err(e) = AuditLogSegmentSeal::new(self.seal_path.clone(), dropped).remove() {
+				warn!("Could not remove audit log segment {}, reason: {:?}", dropped, e);
+			}
+		}
+		Ok(())
+	}
+
+	fn append_and_rotate(
+		&self,
+		log: &mut AuditLogState,
+		event: AuditEvent,
+	) -> AuditLogResult<H256> {
+		let head = log.append(event, get_current_timestamp(), self.segment_size);
+		if let Some((closed_segment, closed, dropped)) =
+			log.rotate(self.segment_size, self.max_segments)
+		{
+			self.store_segment(closed_segment, closed, dropped)?;
+		}
+		Ok(head)
+	}
+}
+
+impl AuditLogUpdater for AuditLog {
+	#[cfg(feature = "std")]
+	fn init(&self) -> AuditLogResult<()> {
+		Ok(())
+	}
+
+	#[cfg(feature = "std")]
+	fn append(&self, event: AuditEvent) -> AuditLogResult<H256> {
+		let mut log = self.log.write().map_err(|_| AuditLogError::PoisonLock)?;
+		self.append_and_rotate(&mut log, event)
+	}
+
+	// if `AUDIT_LOG_FILE` exists, unseal and init from it
+	// otherwise create a new instance and seal to static file
+	#[cfg(feature = "sgx")]
+	fn init(&self) -> AuditLogResult<()> {
+		let enclave_seal = AuditLogSeal::new(self.seal_path.clone());
+		if SgxFile::open(AUDIT_LOG_FILE).is_err() {
+			info!("[Enclave] AuditLog file not found, creating new! {}", AUDIT_LOG_FILE);
+			let log = self.log.write().map_err(|_| AuditLogError::PoisonLock)?;
+			enclave_seal.seal(&*log)
+		} else {
+			let state = enclave_seal.unseal()?;
+			if !state.verify(self.segment_size) {
+				error!("[Enclave] AuditLog unsealed from file has a broken hash chain");
+				return Err(AuditLogError::BrokenChain)
+			}
+			// the open segment must continue the last closed one
+			if state.current_segment > state.first_segment {
+				let previous = self.load_segment(state.current_segment - 1)?;
+				if previous.last().map(|e| e.hash()) != Some(state.segment_prev_hash) {
+					error!("[Enclave] AuditLog segments are not chained");
+					return Err(AuditLogError::BrokenChain)
+				}
+			}
+			info!(
+				"[Enclave] AuditLog unsealed from file, entries: {}, head: {:?}",
+				state.total(self.segment_size),
+				state.head
+			);
+			let mut log = self.log.write().map_err(|_| AuditLogError::PoisonLock)?;
+			*log = state;
+			Ok(())
+		}
+	}
+
+	// only the open segment is resealed, so the cost of an append is bounded by `segment_size`
+	#[cfg(feature = "sgx")]
+	fn append(&self, event: AuditEvent) -> AuditLogResult<H256> {
+		let mut log = self.log.write().map_err(|_| AuditLogError::PoisonLock)?;
+		let head = self.append_and_rotate(&mut log, event)?;
+		AuditLogSeal::new(self.seal_path.clone()).seal(&*log)?;
+		Ok(head)
+	}
+}
+
+impl AuditLogLookup for AuditLog {
+	fn head(&self) -> (u64, H256) {
+		// Using unwrap because poisoned locks are unrecoverable errors
+		let log = self.log.read().unwrap();
+		(log.total(self.segment_size), log.head)
+	}
+
+	fn get_entries(&self, from: u64, limit: u64) -> Vec<AuditLogEntry> {
+		// Using unwrap because poisoned locks are unrecoverable errors
+		let log = self.log.read().unwrap();
+		let limit = limit.min(MAX_ENTRIES_PER_QUERY) as usize;
+		let total = log.total(self.segment_size);
+		let mut from = from.max(log.first_segment * self.segment_size);
+		let mut entries = Vec::new();
+		while entries.len() < limit && from < total {
+			let segment = from / self.segment_size;
+			let offset = (from % self.segment_size) as usize;
+			let closed;
+			let segment_entries = if segment == log.current_segment {
+				&log.entries
+			} else {
+				closed = self.load_segment(segment).unwrap_or_else(|e| {
+					log::error!("Could not load audit log segment {}, reason: {:?}", segment, e);
+					Default::default()
+				});
+				&closed
+			};
+			let taken: Vec<_> = segment_entries
+				.iter()
+				.skip(offset)
+				.take(limit - entries.len())
+				.cloned()
+				.collect();
+			if taken.is_empty() {
+				break
+			}
+			from += taken.len() as u64;
+			entries.extend(taken);
+		}
+		entries
+	}
+}
+
+#[cfg(test)]
+pub mod test {
+	use super::*;
+	use litentry_primitives::Address32;
+
+	fn relayer() -> Identity {
+		Identity::Substrate(Address32::from([1u8; 32]))
+	}
+
+	#[test]
+	pub fn append_chains_entries() {
+		let log = AuditLog::default();
+		let call = AuditedCall::SignEthereum([2u8; 32]);
+
+		let first = log.append(AuditEvent::Requested(relayer(), call.clone())).unwrap();
+		let second = log.append(AuditEvent::Signed(call.hash(), vec![3u8; 65])).unwrap();
+
+		let entries = log.get_entries(0, 10);
+		assert_eq!(entries.len(), 2);
+		assert_eq!(entries[0].prev_hash, H256::default());
+		assert_eq!(entries[0].hash(), first);
+		assert_eq!(entries[1].prev_hash, first);
+		assert_eq!(log.head(), (2, second));
+		assert!(log.log.read().unwrap().verify(log.segment_size));
+	}
+
+	#[test]
+	pub fn verify_detects_tampering() {
+		let log = AuditLog::default();
+		let call = AuditedCall::SignEthereum([2u8; 32]);
+		log.append(AuditEvent::Requested(relayer(), call.clone())).unwrap();
+		log.append(AuditEvent::Failed(call.hash(), vec![])).unwrap();
+
+		let mut state = log.log.read().unwrap().clone();
+		state.entries[0].event =
+			AuditEvent::Requested(relayer(), AuditedCall::SignEthereum([0u8; 32]));
+		assert!(!state.verify(log.segment_size));
+
+		let mut state = log.log.read().unwrap().clone();
+		state.entries.pop();
+		assert!(!state.verify(log.segment_size));
+	}
+
+	#[test]
+	pub fn get_entries_respects_range() {
+		let log = AuditLog::default();
+		for i in 0..5u8 {
+			log.append(AuditEvent::Failed([i; 32].into(), vec![])).unwrap();
+		}
+
+		let entries = log.get_entries(3, 10);
+		assert_eq!(entries.len(), 2);
+		assert_eq!(entries[0].index, 3);
+		assert!(log.get_entries(5, 10).is_empty());
+	}
+
+	#[test]
+	pub fn segments_rotate_and_drop_oldest() {
+		let log = AuditLog::with_limits(Default::default(), 2, 2);
+		for i in 0..7u8 {
+			log.append(AuditEvent::Failed([i; 32].into(), vec![])).unwrap();
+		}
+
+		let state = log.log.read().unwrap().clone();
+		assert_eq!(state.current_segment, 3);
+		assert_eq!(state.first_segment, 1);
+		assert_eq!(state.entries.len(), 1);
+		assert!(state.verify(log.segment_size));
+		assert_eq!(log.head().0, 7);
+
+		// the dropped segment is skipped, the retained ones are chained
+		let entries = log.get_entries(0, 10);
+		assert_eq!(entries.iter().map(|e| e.index).collect::<Vec<_>>(), vec![2, 3, 4, 5, 6]);
+		for pair in entries.windows(2) {
+			assert_eq!(pair[1].prev_hash, pair[0].hash());
+		}
+		assert_eq!(entries.last().unwrap().hash(), log.head().1);
+		assert_eq!(log.get_entries(3, 2).iter().map(|e| e.index).collect::<Vec<_>>(), vec![3, 4]);
+	}
+
+	#[test]
+	pub fn signed_request_is_verified() {
+		use sp_core::{sr25519, Pair};
+
+		let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+		let request = AuditLogRequest { from: 0, limit: 10, timestamp: 1_000 };
+		let signed = AuditLogRequestSigned {
+			request: request.clone(),
+			signer: Identity::Substrate(pair.public().into()),
+			signature: LitentryMultiSignature::Sr25519(pair.sign(&request.signature_payload())),
+		};
+		assert!(signed.verify(1_000 + MAX_REQUEST_AGE));
+		assert!(!signed.verify(1_001 + MAX_REQUEST_AGE));
+
+		let mut tampered = signed.clone();
+		tampered.request.limit = 100;
+		assert!(!tampered.verify(1_000));
+
+		let mut other_signer = signed;
+		other_signer.signer = relayer();
+		assert!(!other_signer.verify(1_000));
+	}
+}
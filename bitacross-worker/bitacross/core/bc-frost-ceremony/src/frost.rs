@@ -0,0 +1,312 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! FROST(Ed25519, SHA-512) signing as specified in RFC 9591.
+//!
+//! The signing key is shared additively between all participants (n-of-n), so the group
+//! key is the sum of all public key shares and every lagrange coefficient equals 1.
+//! Every share has to come with a proof of possession when it's registered, otherwise a
+//! participant could pick its share as a function of the others' and control the group key.
+
+use curve25519_dalek::{
+	constants::ED25519_BASEPOINT_TABLE,
+	edwards::{CompressedEdwardsY, EdwardsPoint},
+	scalar::Scalar,
+	traits::{Identity, IsIdentity},
+};
+use sha2::{Digest, Sha512};
+use std::vec::Vec;
+
+const CONTEXT_STRING: &[u8] = b"FROST-ED25519-SHA512-v1";
+// must match the context used by pallet-bitacross to check the proof
+const POSSESSION_CONTEXT: &[u8] = b"bitacross:sol-key-possession";
+
+pub type SerializedCommitments = [u8; 64];
+pub type SerializedShare = [u8; 32];
+pub type SerializedSignature = [u8; 64];
+pub type SerializedProof = [u8; 64];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrostError {
+	InvalidPoint,
+	InvalidScalar,
+	InvalidShare,
+	InvalidSignature,
+	UnknownParticipant,
+}
+
+pub struct SigningNonces {
+	hiding: Scalar,
+	binding: Scalar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigningCommitments {
+	pub hiding: [u8; 32],
+	pub binding: [u8; 32],
+}
+
+impl SigningCommitments {
+	pub fn to_bytes(&self) -> SerializedCommitments {
+		let mut bytes = [0u8; 64];
+		bytes[..32].copy_from_slice(&self.hiding);
+		bytes[32..].copy_from_slice(&self.binding);
+		bytes
+	}
+
+	pub fn from_bytes(bytes: &SerializedCommitments) -> Result<Self, FrostError> {
+		let mut hiding = [0u8; 32];
+		let mut binding = [0u8; 32];
+		hiding.copy_from_slice(&bytes[..32]);
+		binding.copy_from_slice(&bytes[32..]);
+		// both commitments must be valid non-identity points
+		decompress(&hiding)?;
+		decompress(&binding)?;
+		Ok(Self { hiding, binding })
+	}
+}
+
+fn hash(parts: &[&[u8]]) -> [u8; 64] {
+	let mut hasher = Sha512::new();
+	parts.iter().for_each(|p| hasher.update(p));
+	let mut out = [0u8; 64];
+	out.copy_from_slice(&hasher.finalize());
+	out
+}
+
+fn hash_to_scalar(parts: &[&[u8]]) -> Scalar {
+	Scalar::from_bytes_mod_order_wide(&hash(parts))
+}
+
+fn h1(m: &[u8]) -> Scalar {
+	hash_to_scalar(&[CONTEXT_STRING, b"rho", m])
+}
+
+// the challenge is the plain ed25519 one, so that the result verifies as a regular signature
+fn h2(m: &[u8]) -> Scalar {
+	hash_to_scalar(&[m])
+}
+
+fn h3(m: &[u8]) -> Scalar {
+	hash_to_scalar(&[CONTEXT_STRING, b"nonce", m])
+}
+
+fn h4(m: &[u8]) -> [u8; 64] {
+	hash(&[CONTEXT_STRING, b"msg", m])
+}
+
+fn h5(m: &[u8]) -> [u8; 64] {
+	hash(&[CONTEXT_STRING, b"com", m])
+}
+
+fn decompress(bytes: &[u8; 32]) -> Result<EdwardsPoint, FrostError> {
+	let point = CompressedEdwardsY(*bytes).decompress().ok_or(FrostError::InvalidPoint)?;
+	if point.is_identity() {
+		return Err(FrostError::InvalidPoint)
+	}
+	Ok(point)
+}
+
+fn scalar_from_bytes(bytes: &[u8; 32]) -> Result<Scalar, FrostError> {
+	Scalar::from_canonical_bytes(*bytes).ok_or(FrostError::InvalidScalar)
+}
+
+// participant identifiers are 1-based positions in the sorted signers list
+fn identifier(index: usize) -> Scalar {
+	Scalar::from(index as u64 + 1)
+}
+
+pub fn aggregate_public_key(public_keys: &[[u8; 32]]) -> Result<[u8; 32], FrostError> {
+	let mut sum = EdwardsPoint::identity();
+	for key in public_keys {
+		sum += decompress(key)?;
+	}
+	Ok(sum.compress().to_bytes())
+}
+
+fn generate_nonce(secret: &Scalar, random_bytes: &[u8]) -> Scalar {
+	h3(&[random_bytes, secret.as_bytes()].concat())
+}
+
+// First round: generates the nonces and the matching public commitments.
+// `random_bytes` must be fresh randomness and the nonces must never be reused.
+pub fn commit(secret: &Scalar, random_bytes: &[u8; 64]) -> (SigningNonces, SigningCommitments) {
+	let hiding = generate_nonce(secret, &random_bytes[..32]);
+	let binding = generate_nonce(secret, &random_bytes[32..]);
+	let commitments = SigningCommitments {
+		hiding: (&hiding * &ED25519_BASEPOINT_TABLE).compress().to_bytes(),
+		binding: (&binding * &ED25519_BASEPOINT_TABLE).compress().to_bytes(),
+	};
+	(SigningNonces { hiding, binding }, commitments)
+}
+
+// the proof binds the share to the account registering it, so it can't be replayed by another one
+pub fn possession_message(public_key: &[u8; 32], owner: &[u8; 32]) -> Vec<u8> {
+	[POSSESSION_CONTEXT, public_key.as_slice(), owner.as_slice()].concat()
+}
+
+// Proves the knowledge of `secret`, the proof is a regular ed25519 signature
+// over the possession message.
+pub fn prove_possession(
+	secret: &Scalar,
+	owner: &[u8; 32],
+	random_bytes: &[u8; 32],
+) -> SerializedProof {
+	let public_key = (secret * &ED25519_BASEPOINT_TABLE).compress().to_bytes();
+	let message = possession_message(&public_key, owner);
+	let nonce = generate_nonce(secret, random_bytes);
+	let r = (&nonce * &ED25519_BASEPOINT_TABLE).compress().to_bytes();
+	let challenge = h2(&[r.as_slice(), public_key.as_slice(), message.as_slice()].concat());
+	let z = nonce + challenge * secret;
+	let mut proof = [0u8; 64];
+	proof[..32].copy_from_slice(&r);
+	proof[32..].copy_from_slice(z.as_bytes());
+	proof
+}
+
+pub fn verify_possession(
+	public_key: &[u8; 32],
+	owner: &[u8; 32],
+	proof: &SerializedProof,
+) -> Result<(), FrostError> {
+	verify(public_key, &possession_message(public_key, owner), proof)
+}
+
+fn encode_group_commitment_list(commitments: &[SigningCommitments]) -> Vec<u8> {
+	let mut encoded = Vec::with_capacity(commitments.len() * 96);
+	commitments.iter().enumerate().for_each(|(index, c)| {
+		encoded.extend_from_slice(identifier(index).as_bytes());
+		encoded.extend_from_slice(&c.hiding);
+		encoded.extend_from_slice(&c.binding);
+	});
+	encoded
+}
+
+/// Everything the participants have to agree on to produce signature shares,
+/// `commitments` are ordered the same way as the signers.
+pub struct SigningPackage<'a> {
+	pub group_public_key: [u8; 32],
+	pub commitments: &'a [SigningCommitments],
+	pub message: &'a [u8],
+}
+
+impl<'a> SigningPackage<'a> {
+	fn binding_factors(&self) -> Vec<Scalar> {
+		let msg_hash = h4(self.message);
+		let encoded_commitment_hash = h5(&encode_group_commitment_list(self.commitments));
+		let rho_input_prefix = [
+			self.group_public_key.as_slice(),
+			msg_hash.as_slice(),
+			encoded_commitment_hash.as_slice(),
+		]
+		.concat();
+		(0..self.commitments.len())
+			.map(|index| h1(&[rho_input_prefix.as_slice(), identifier(index).as_bytes()].concat()))
+			.collect()
+	}
+
+	fn commitment_share(
+		commitments: &SigningCommitments,
+		binding_factor: &Scalar,
+	) -> Result<EdwardsPoint, FrostError> {
+		Ok(decompress(&commitments.hiding)? + decompress(&commitments.binding)? * binding_factor)
+	}
+
+	fn group_commitment(&self, binding_factors: &[Scalar]) -> Result<EdwardsPoint, FrostError> {
+		let mut group_commitment = EdwardsPoint::identity();
+		for (c, binding_factor) in self.commitments.iter().zip(binding_factors) {
+			group_commitment += Self::commitment_share(c, binding_factor)?;
+		}
+		Ok(group_commitment)
+	}
+
+	fn challenge(&self, group_commitment: &EdwardsPoint) -> Scalar {
+		h2(&[
+			group_commitment.compress().as_bytes().as_slice(),
+			self.group_public_key.as_slice(),
+			self.message,
+		]
+		.concat())
+	}
+
+	// Second round: produces the signature share of the participant at `index`.
+	pub fn sign(
+		&self,
+		index: usize,
+		secret: &Scalar,
+		nonces: SigningNonces,
+	) -> Result<SerializedShare, FrostError> {
+		let binding_factors = self.binding_factors();
+		let binding_factor = binding_factors.get(index).ok_or(FrostError::UnknownParticipant)?;
+		let challenge = self.challenge(&self.group_commitment(&binding_factors)?);
+		let share = nonces.hiding + nonces.binding * binding_factor + challenge * secret;
+		Ok(share.to_bytes())
+	}
+
+	pub fn verify_share(
+		&self,
+		index: usize,
+		public_key: &[u8; 32],
+		share: &SerializedShare,
+	) -> Result<(), FrostError> {
+		let binding_factors = self.binding_factors();
+		let binding_factor = binding_factors.get(index).ok_or(FrostError::UnknownParticipant)?;
+		let commitments = self.commitments.get(index).ok_or(FrostError::UnknownParticipant)?;
+		let challenge = self.challenge(&self.group_commitment(&binding_factors)?);
+		let share = scalar_from_bytes(share)?;
+
+		let expected = Self::commitment_share(commitments, binding_factor)?
+			+ decompress(public_key)? * challenge;
+		if &share * &ED25519_BASEPOINT_TABLE == expected {
+			Ok(())
+		} else {
+			Err(FrostError::InvalidShare)
+		}
+	}
+
+	// Combines all signature shares, the result is a regular ed25519 signature over the message.
+	pub fn aggregate(&self, shares: &[SerializedShare]) -> Result<SerializedSignature, FrostError> {
+		let group_commitment = self.group_commitment(&self.binding_factors())?;
+		let mut z = Scalar::zero();
+		for share in shares {
+			z += scalar_from_bytes(share)?;
+		}
+		let mut signature = [0u8; 64];
+		signature[..32].copy_from_slice(group_commitment.compress().as_bytes());
+		signature[32..].copy_from_slice(z.as_bytes());
+		verify(&self.group_public_key, self.message, &signature)?;
+		Ok(signature)
+	}
+}
+
+pub fn verify(
+	public_key: &[u8; 32],
+	message: &[u8],
+	signature: &SerializedSignature,
+) -> Result<(), FrostError> {
+	let mut r_bytes = [0u8; 32];
+	let mut z_bytes = [0u8; 32];
+	r_bytes.copy_from_slice(&signature[..32]);
+	z_bytes.copy_from_slice(&signature[32..]);
+	let r = CompressedEdwardsY(r_bytes).decompress().ok_or(FrostError::InvalidSignature)?;
+	let z = scalar_from_bytes(&z_bytes).map_err(|_| FrostError::InvalidSignature)?;
+	let challenge = h2(&[r_bytes.as_slice(), public_key.as_slice(), message].concat());
+	if &z * &ED25519_BASEPOINT_TABLE == r + decompress(public_key)? * challenge {
+		Ok(())
+	} else {
+		Err(FrostError::InvalidSignature)
+	}
+}
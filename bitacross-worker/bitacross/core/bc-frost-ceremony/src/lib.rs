@@ -0,0 +1,615 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+extern crate core;
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+#[cfg(all(feature = "std", feature = "sgx"))]
+compile_error!("feature \"std\" and feature \"sgx\" cannot be enabled at the same time");
+
+pub mod frost;
+
+pub use bc_musig2_ceremony::{
+	get_current_timestamp, CeremonyError, CeremonyErrorReason, Registry, SignaturePayload,
+	SignerId, Signers,
+};
+use codec::{Decode, Encode};
+use curve25519_dalek::scalar::Scalar;
+use frost::{
+	aggregate_public_key, commit, prove_possession, SerializedCommitments, SerializedProof,
+	SerializedShare, SerializedSignature, SigningCommitments, SigningNonces, SigningPackage,
+};
+use itp_sgx_crypto::{frost::Pair as FrostPair, key_repository::AccessKey};
+use log::*;
+use std::{format, string::String, sync::Arc, vec, vec::Vec};
+
+pub type SolanaCeremonyId = SignSolanaPayload;
+pub type FrostCeremonyRegistry<AK> = Registry<SolanaCeremonyId, FrostCeremony<AK>>;
+pub type FrostCeremonyCommandTmp = Registry<SolanaCeremonyId, Vec<FrostCeremonyCommand>>;
+pub type SolanaPubKey = [u8; 32];
+pub type SignersWithSolanaKeys = Vec<(SignerId, SolanaPubKey)>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FrostCeremonyCommand {
+	InitCeremony(SignersWithSolanaKeys, SignSolanaPayload),
+	SaveCommitments(SignerId, SerializedCommitments),
+	SaveSignatureShare(SignerId, SerializedShare),
+	KillCeremony,
+}
+
+// events are created by ceremony and executed by runner
+#[derive(Debug, Eq, PartialEq)]
+pub enum FrostCeremonyEvent {
+	FirstRoundStarted(Signers, SolanaCeremonyId, SerializedCommitments),
+	SecondRoundStarted(Signers, SolanaCeremonyId, SerializedShare),
+	CeremonyEnded(SerializedSignature),
+	CeremonyError(Signers, CeremonyError),
+}
+
+// serialized solana transaction message
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignSolanaPayload(pub SignaturePayload);
+
+pub fn generate_aggregated_public_key(
+	public_keys: &[SolanaPubKey],
+) -> Result<SolanaPubKey, String> {
+	aggregate_public_key(public_keys).map_err(|e| format!("Key aggregation error: {:?}", e))
+}
+
+// proof of possession of the key share, it's published together with the public key share
+pub fn prove_key_possession(key: &FrostPair, owner: &[u8; 32]) -> SerializedProof {
+	let seed = random_seed();
+	let mut random_bytes = [0u8; 32];
+	random_bytes.copy_from_slice(&seed[..32]);
+	prove_possession(&Scalar::from_bytes_mod_order(key.private_bytes()), owner, &random_bytes)
+}
+
+pub struct FrostCeremonyData<AK: AccessKey<KeyType = FrostPair>> {
+	payload: SignSolanaPayload,
+	me: SignerId,
+	my_index: usize,
+	// sorted by public key, the position is used as participant identifier
+	signers: SignersWithSolanaKeys,
+	signing_key_access: Arc<AK>,
+	agg_key: SolanaPubKey,
+}
+
+pub struct FirstRound {
+	nonces: SigningNonces,
+	commitments: Vec<Option<SigningCommitments>>,
+}
+
+pub struct SecondRound {
+	commitments: Vec<SigningCommitments>,
+	signature_shares: Vec<Option<SerializedShare>>,
+}
+
+pub struct FrostCeremonyState {
+	first_round: Option<FirstRound>,
+	second_round: Option<SecondRound>,
+}
+
+pub struct FrostCeremony<AK: AccessKey<KeyType = FrostPair>> {
+	ceremony_data: FrostCeremonyData<AK>,
+	ceremony_state: FrostCeremonyState,
+}
+
+impl<AK: AccessKey<KeyType = FrostPair>> FrostCeremony<AK> {
+	// Creates new ceremony
+	pub fn new(
+		me: SignerId,
+		mut signers: SignersWithSolanaKeys,
+		payload: SignSolanaPayload,
+		signing_key_access: Arc<AK>,
+	) -> Result<(Self, FrostCeremonyEvent), String> {
+		info!("Creating new frost ceremony {:?}", payload);
+		if signers.len() < 3 {
+			return Err(format!("Not enough signers, minimum: {:?}, actual {:?}", 3, signers.len()))
+		}
+
+		signers.sort_by_key(|k| k.1);
+		let my_index = signers.iter().position(|r| r.0 == me).ok_or("Could not determine index")?;
+		let all_keys = signers.iter().map(|p| p.1).collect::<Vec<SolanaPubKey>>();
+		let agg_key = generate_aggregated_public_key(&all_keys)?;
+		info!("Ceremony aggregated public key: {:?}", agg_key);
+
+		let key = signing_key_access
+			.retrieve_key()
+			.map_err(|e| format!("Could not retrieve signing key: {:?}", e))?;
+		if key.public_bytes() != signers[my_index].1 {
+			return Err("Registered public key doesn't match signing key".into())
+		}
+		let secret = Scalar::from_bytes_mod_order(key.private_bytes());
+		let (nonces, my_commitments) = commit(&secret, &random_seed());
+
+		let mut commitments = vec![None; signers.len()];
+		commitments[my_index] = Some(my_commitments);
+
+		let ceremony = Self {
+			ceremony_data: FrostCeremonyData {
+				payload,
+				me,
+				my_index,
+				signers,
+				signing_key_access,
+				agg_key,
+			},
+			ceremony_state: FrostCeremonyState {
+				first_round: Some(FirstRound { nonces, commitments }),
+				second_round: None,
+			},
+		};
+		let event = FrostCeremonyEvent::FirstRoundStarted(
+			ceremony.get_signers_except_self(),
+			ceremony.ceremony_data.payload.clone(),
+			my_commitments.to_bytes(),
+		);
+		Ok((ceremony, event))
+	}
+
+	// Saves signer's commitments
+	pub fn receive_commitments(
+		&mut self,
+		signer: SignerId,
+		commitments: SerializedCommitments,
+	) -> Result<Option<FrostCeremonyEvent>, CeremonyError> {
+		info!("Saving commitments from signer: {:?}", signer);
+		let peer_index = self
+			.ceremony_data
+			.signers
+			.iter()
+			.position(|p| p.0 == signer)
+			.ok_or(CeremonyError::NonceReceivingError(CeremonyErrorReason::SignerNotFound))?;
+
+		if let Some(ref mut r) = self.ceremony_state.first_round {
+			let commitments = SigningCommitments::from_bytes(&commitments).map_err(|e| {
+				error!("Commitments receiving error: {:?}", e);
+				CeremonyError::NonceReceivingError(CeremonyErrorReason::ContributionError)
+			})?;
+			match r.commitments[peer_index] {
+				Some(existing) if existing != commitments =>
+					return Err(CeremonyError::NonceReceivingError(
+						CeremonyErrorReason::ContributionError,
+					)),
+				_ => r.commitments[peer_index] = Some(commitments),
+			}
+			if r.commitments.iter().all(Option::is_some) {
+				self.start_second_round().map(Some)
+			} else {
+				Ok(None)
+			}
+		} else {
+			Err(CeremonyError::NonceReceivingError(CeremonyErrorReason::IncorrectRound))
+		}
+	}
+
+	// Starts the second round
+	fn start_second_round(&mut self) -> Result<FrostCeremonyEvent, CeremonyError> {
+		let first_round = self
+			.ceremony_state
+			.first_round
+			.take()
+			.ok_or(CeremonyError::NonceReceivingError(CeremonyErrorReason::IncorrectRound))?;
+		let commitments: Vec<SigningCommitments> =
+			first_round.commitments.into_iter().flatten().collect();
+
+		let key = self.ceremony_data.signing_key_access.retrieve_key().map_err(|e| {
+			error!("Could not start second round: {:?}", e);
+			CeremonyError::NonceReceivingError(CeremonyErrorReason::RoundFinalizationError)
+		})?;
+		let secret = Scalar::from_bytes_mod_order(key.private_bytes());
+		let package = SigningPackage {
+			group_public_key: self.ceremony_data.agg_key,
+			commitments: &commitments,
+			message: &self.ceremony_data.payload.0,
+		};
+		let signature_share = package
+			.sign(self.ceremony_data.my_index, &secret, first_round.nonces)
+			.map_err(|e| {
+				error!("Could not start second round: {:?}", e);
+				CeremonyError::NonceReceivingError(CeremonyErrorReason::RoundFinalizationError)
+			})?;
+
+		let mut signature_shares = vec![None; commitments.len()];
+		signature_shares[self.ceremony_data.my_index] = Some(signature_share);
+		self.ceremony_state.second_round = Some(SecondRound { commitments, signature_shares });
+
+		Ok(FrostCeremonyEvent::SecondRoundStarted(
+			self.get_signers_except_self(),
+			self.get_id_ref().clone(),
+			signature_share,
+		))
+	}
+
+	// Saves signer's signature share
+	pub fn receive_signature_share(
+		&mut self,
+		signer: SignerId,
+		signature_share: SerializedShare,
+	) -> Result<Option<FrostCeremonyEvent>, CeremonyError> {
+		info!("Saving signature share from signer: {:?}", signer);
+		let peer_index = self.ceremony_data.signers.iter().position(|p| p.0 == signer).ok_or(
+			CeremonyError::PartialSignatureReceivingError(CeremonyErrorReason::SignerNotFound),
+		)?;
+
+		if let Some(ref mut r) = self.ceremony_state.second_round {
+			let package = SigningPackage {
+				group_public_key: self.ceremony_data.agg_key,
+				commitments: &r.commitments,
+				message: &self.ceremony_data.payload.0,
+			};
+			// a bad share is attributed to its signer instead of failing at aggregation
+			package
+				.verify_share(
+					peer_index,
+					&self.ceremony_data.signers[peer_index].1,
+					&signature_share,
+				)
+				.map_err(|e| {
+					error!("Signature share receiving error: {:?}", e);
+					CeremonyError::PartialSignatureReceivingError(
+						CeremonyErrorReason::ContributionError,
+					)
+				})?;
+			r.signature_shares[peer_index] = Some(signature_share);
+
+			if r.signature_shares.iter().all(Option::is_some) {
+				let shares: Vec<SerializedShare> =
+					r.signature_shares.iter().flatten().copied().collect();
+				let signature = package.aggregate(&shares).map_err(|e| {
+					error!("Could not finish second round: {:?}", e);
+					CeremonyError::PartialSignatureReceivingError(
+						CeremonyErrorReason::RoundFinalizationError,
+					)
+				})?;
+				self.ceremony_state.second_round = None;
+
+				info!("Ceremony {:?} has ended", self.get_id_ref());
+				info!("Aggregated public key {:?}", self.ceremony_data.agg_key);
+				info!("Signature {:?}", signature);
+				Ok(Some(FrostCeremonyEvent::CeremonyEnded(signature)))
+			} else {
+				Ok(None)
+			}
+		} else {
+			Err(CeremonyError::PartialSignatureReceivingError(CeremonyErrorReason::IncorrectRound))
+		}
+	}
+
+	pub fn get_signers_except_self(&self) -> Signers {
+		self.ceremony_data
+			.signers
+			.iter()
+			.filter(|e| e.0 != self.ceremony_data.me)
+			.map(|s| s.0)
+			.collect()
+	}
+
+	pub fn get_id_ref(&self) -> &SolanaCeremonyId {
+		&self.ceremony_data.payload
+	}
+
+	pub fn get_aggregated_key(&self) -> SolanaPubKey {
+		self.ceremony_data.agg_key
+	}
+
+	pub fn is_first_round(&self) -> bool {
+		self.ceremony_state.first_round.is_some()
+	}
+}
+
+#[cfg(feature = "std")]
+fn random_seed() -> [u8; 64] {
+	use rand::{thread_rng, RngCore};
+
+	let mut seed = [0u8; 64];
+	let mut rand = thread_rng();
+	rand.fill_bytes(&mut seed);
+	seed
+}
+
+#[cfg(feature = "sgx")]
+fn random_seed() -> [u8; 64] {
+	use sgx_rand::{Rng, StdRng};
+	let mut seed = [0u8; 64];
+	let mut rand = StdRng::new().unwrap();
+	rand.fill_bytes(&mut seed);
+	seed
+}
+
+#[cfg(test)]
+pub mod test {
+	use crate::{
+		frost::{aggregate_public_key, possession_message, verify_possession},
+		prove_key_possession, CeremonyError, CeremonyErrorReason, FrostCeremony,
+		FrostCeremonyEvent, SignSolanaPayload, SignerId, SignersWithSolanaKeys,
+	};
+	use alloc::sync::Arc;
+	use curve25519_dalek::edwards::CompressedEdwardsY;
+	use ed25519_dalek::{PublicKey, Signature, Verifier};
+	use itp_sgx_crypto::{frost::Pair as FrostPair, key_repository::AccessKey};
+
+	pub const MY_SIGNER_ID: SignerId = [0u8; 32];
+	pub const SIGNER_1_ID: SignerId = [1u8; 32];
+	pub const SIGNER_2_ID: SignerId = [2u8; 32];
+
+	pub const MY_PRIV_KEY: [u8; 32] = [
+		252, 240, 35, 85, 243, 83, 129, 54, 7, 155, 24, 114, 254, 0, 134, 251, 207, 83, 177, 9, 92,
+		118, 222, 5, 202, 239, 188, 215, 132, 113, 127, 4,
+	];
+	pub const SIGNER_1_PRIV_KEY: [u8; 32] = [
+		42, 82, 57, 169, 208, 130, 125, 141, 62, 185, 167, 41, 142, 217, 252, 135, 158, 128, 44,
+		129, 222, 71, 55, 86, 230, 183, 54, 111, 152, 83, 85, 11,
+	];
+	pub const SIGNER_2_PRIV_KEY: [u8; 32] = [
+		117, 130, 176, 36, 185, 53, 187, 61, 123, 86, 24, 38, 174, 143, 129, 73, 245, 210, 127,
+		148, 115, 136, 32, 98, 62, 47, 26, 196, 57, 211, 171, 9,
+	];
+
+	pub const SAMPLE_SIGNATURE_PAYLOAD: [u8; 32] = [7u8; 32];
+
+	struct MockedSigningKeyAccess {
+		signing_key: [u8; 32],
+	}
+
+	impl AccessKey for MockedSigningKeyAccess {
+		type KeyType = FrostPair;
+
+		fn retrieve_key(&self) -> itp_sgx_crypto::Result<Self::KeyType> {
+			Ok(FrostPair::from_bytes(self.signing_key))
+		}
+	}
+
+	fn signers_with_keys() -> SignersWithSolanaKeys {
+		vec![
+			(MY_SIGNER_ID, FrostPair::from_bytes(MY_PRIV_KEY).public_bytes()),
+			(SIGNER_1_ID, FrostPair::from_bytes(SIGNER_1_PRIV_KEY).public_bytes()),
+			(SIGNER_2_ID, FrostPair::from_bytes(SIGNER_2_PRIV_KEY).public_bytes()),
+		]
+	}
+
+	fn create_ceremony(
+		me: SignerId,
+		key: [u8; 32],
+	) -> (FrostCeremony<MockedSigningKeyAccess>, FrostCeremonyEvent) {
+		FrostCeremony::new(
+			me,
+			signers_with_keys(),
+			SignSolanaPayload(SAMPLE_SIGNATURE_PAYLOAD.to_vec()),
+			Arc::new(MockedSigningKeyAccess { signing_key: key }),
+		)
+		.unwrap()
+	}
+
+	fn first_round_commitments(event: &FrostCeremonyEvent) -> [u8; 64] {
+		match event {
+			FrostCeremonyEvent::FirstRoundStarted(_, _, commitments) => *commitments,
+			_ => panic!("unexpected event: {:?}", event),
+		}
+	}
+
+	fn second_round_share(event: &Option<FrostCeremonyEvent>) -> [u8; 32] {
+		match event {
+			Some(FrostCeremonyEvent::SecondRoundStarted(_, _, share)) => *share,
+			_ => panic!("unexpected event: {:?}", event),
+		}
+	}
+
+	#[test]
+	fn it_should_create_ceremony_in_firstround() {
+		// given
+		let signing_key_access = MockedSigningKeyAccess { signing_key: MY_PRIV_KEY };
+
+		// when
+		let result = FrostCeremony::new(
+			MY_SIGNER_ID,
+			signers_with_keys(),
+			SignSolanaPayload(SAMPLE_SIGNATURE_PAYLOAD.to_vec()),
+			Arc::new(signing_key_access),
+		);
+
+		// then
+		assert!(result.is_ok());
+		assert!(result.unwrap().0.is_first_round())
+	}
+
+	#[test]
+	fn it_should_prevent_from_creating_ceremony_without_sufficient_signers() {
+		// given
+		let signing_key_access = MockedSigningKeyAccess { signing_key: MY_PRIV_KEY };
+
+		// when
+		let result = FrostCeremony::new(
+			MY_SIGNER_ID,
+			signers_with_keys()[0..1].to_vec(),
+			SignSolanaPayload(SAMPLE_SIGNATURE_PAYLOAD.to_vec()),
+			Arc::new(signing_key_access),
+		);
+
+		// then
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn it_should_prevent_from_creating_ceremony_with_mismatched_key() {
+		// given
+		let signing_key_access = MockedSigningKeyAccess { signing_key: SIGNER_1_PRIV_KEY };
+
+		// when
+		let result = FrostCeremony::new(
+			MY_SIGNER_ID,
+			signers_with_keys(),
+			SignSolanaPayload(SAMPLE_SIGNATURE_PAYLOAD.to_vec()),
+			Arc::new(signing_key_access),
+		);
+
+		// then
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn it_should_produce_error_due_to_commitments_from_unknown_signer() {
+		// given
+		let (mut ceremony, _) = create_ceremony(MY_SIGNER_ID, MY_PRIV_KEY);
+		let (_, event) = create_ceremony(SIGNER_1_ID, SIGNER_1_PRIV_KEY);
+
+		// when
+		let result = ceremony.receive_commitments([10u8; 32], first_round_commitments(&event));
+
+		// then
+		assert!(matches!(
+			result,
+			Err(CeremonyError::NonceReceivingError(CeremonyErrorReason::SignerNotFound))
+		))
+	}
+
+	#[test]
+	fn it_should_produce_error_due_to_signature_share_in_first_round() {
+		// given
+		let (mut ceremony, _) = create_ceremony(MY_SIGNER_ID, MY_PRIV_KEY);
+
+		// when
+		let result = ceremony.receive_signature_share(SIGNER_1_ID, [0u8; 32]);
+
+		// then
+		assert!(matches!(
+			result,
+			Err(CeremonyError::PartialSignatureReceivingError(CeremonyErrorReason::IncorrectRound))
+		))
+	}
+
+	#[test]
+	fn it_should_produce_valid_signature() {
+		// given
+		let (mut my_ceremony, my_event) = create_ceremony(MY_SIGNER_ID, MY_PRIV_KEY);
+		let (mut signer1_ceremony, signer1_event) = create_ceremony(SIGNER_1_ID, SIGNER_1_PRIV_KEY);
+		let (mut signer2_ceremony, signer2_event) = create_ceremony(SIGNER_2_ID, SIGNER_2_PRIV_KEY);
+		let my_commitments = first_round_commitments(&my_event);
+		let signer1_commitments = first_round_commitments(&signer1_event);
+		let signer2_commitments = first_round_commitments(&signer2_event);
+
+		// when
+		assert_eq!(my_ceremony.receive_commitments(SIGNER_1_ID, signer1_commitments), Ok(None));
+		let my_share = second_round_share(
+			&my_ceremony.receive_commitments(SIGNER_2_ID, signer2_commitments).unwrap(),
+		);
+		signer1_ceremony.receive_commitments(MY_SIGNER_ID, my_commitments).unwrap();
+		let signer1_share = second_round_share(
+			&signer1_ceremony.receive_commitments(SIGNER_2_ID, signer2_commitments).unwrap(),
+		);
+		signer2_ceremony.receive_commitments(MY_SIGNER_ID, my_commitments).unwrap();
+		let signer2_share = second_round_share(
+			&signer2_ceremony.receive_commitments(SIGNER_1_ID, signer1_commitments).unwrap(),
+		);
+		assert!(!my_ceremony.is_first_round());
+
+		assert_eq!(my_ceremony.receive_signature_share(SIGNER_1_ID, signer1_share), Ok(None));
+		let result = my_ceremony.receive_signature_share(SIGNER_2_ID, signer2_share).unwrap();
+		let _ = signer1_ceremony.receive_signature_share(MY_SIGNER_ID, my_share).unwrap();
+
+		// then
+		let signature = match result {
+			Some(FrostCeremonyEvent::CeremonyEnded(signature)) => signature,
+			_ => panic!("unexpected event: {:?}", result),
+		};
+		let public_key = PublicKey::from_bytes(&my_ceremony.get_aggregated_key()).unwrap();
+		let signature = Signature::from_bytes(&signature).unwrap();
+		assert!(public_key.verify(&SAMPLE_SIGNATURE_PAYLOAD, &signature).is_ok());
+		assert!(public_key.verify_strict(&SAMPLE_SIGNATURE_PAYLOAD, &signature).is_ok());
+	}
+
+	#[test]
+	fn it_should_verify_proof_of_possession() {
+		// given
+		let key = FrostPair::from_bytes(MY_PRIV_KEY);
+		let owner = [5u8; 32];
+
+		// when
+		let proof = prove_key_possession(&key, &owner);
+
+		// then
+		assert!(verify_possession(&key.public_bytes(), &owner, &proof).is_ok());
+		// the proof is a plain ed25519 signature, so it can be checked on chain too
+		let public_key = PublicKey::from_bytes(&key.public_bytes()).unwrap();
+		let signature = Signature::from_bytes(&proof).unwrap();
+		assert!(public_key
+			.verify_strict(&possession_message(&key.public_bytes(), &owner), &signature)
+			.is_ok());
+		// replayed for another account
+		assert!(verify_possession(&key.public_bytes(), &[6u8; 32], &proof).is_err());
+	}
+
+	#[test]
+	fn it_should_reject_proof_of_possession_of_rogue_key() {
+		// given
+		// a rogue share cancelling the honest ones, its discrete log is unknown to the attacker
+		let honest = [
+			FrostPair::from_bytes(MY_PRIV_KEY).public_bytes(),
+			FrostPair::from_bytes(SIGNER_1_PRIV_KEY).public_bytes(),
+		];
+		let target = FrostPair::from_bytes(SIGNER_2_PRIV_KEY);
+		let honest_sum =
+			CompressedEdwardsY(aggregate_public_key(&honest).unwrap()).decompress().unwrap();
+		let rogue = (CompressedEdwardsY(target.public_bytes()).decompress().unwrap() - honest_sum)
+			.compress()
+			.to_bytes();
+		assert_eq!(
+			aggregate_public_key(&[honest[0], honest[1], rogue]).unwrap(),
+			target.public_bytes()
+		);
+
+		// when
+		// the best the attacker can do is to prove the possession of the target key
+		let proof = prove_key_possession(&target, &SIGNER_2_ID);
+
+		// then
+		assert!(verify_possession(&rogue, &SIGNER_2_ID, &proof).is_err());
+	}
+
+	#[test]
+	fn it_should_produce_error_due_to_invalid_signature_share() {
+		// given
+		let (mut my_ceremony, my_event) = create_ceremony(MY_SIGNER_ID, MY_PRIV_KEY);
+		let (mut signer1_ceremony, signer1_event) = create_ceremony(SIGNER_1_ID, SIGNER_1_PRIV_KEY);
+		let (_, signer2_event) = create_ceremony(SIGNER_2_ID, SIGNER_2_PRIV_KEY);
+		let signer1_commitments = first_round_commitments(&signer1_event);
+		let signer2_commitments = first_round_commitments(&signer2_event);
+		my_ceremony.receive_commitments(SIGNER_1_ID, signer1_commitments).unwrap();
+		my_ceremony.receive_commitments(SIGNER_2_ID, signer2_commitments).unwrap();
+		signer1_ceremony
+			.receive_commitments(MY_SIGNER_ID, first_round_commitments(&my_event))
+			.unwrap();
+		let signer1_share = second_round_share(
+			&signer1_ceremony.receive_commitments(SIGNER_2_ID, signer2_commitments).unwrap(),
+		);
+
+		// when
+		// signer 1's share is sent as signer 2's one
+		let result = my_ceremony.receive_signature_share(SIGNER_2_ID, signer1_share);
+
+		// then
+		assert!(matches!(
+			result,
+			Err(CeremonyError::PartialSignatureReceivingError(
+				CeremonyErrorReason::ContributionError
+			))
+		))
+	}
+}
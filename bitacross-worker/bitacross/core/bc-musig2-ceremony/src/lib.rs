@@ -40,23 +40,30 @@ use k256::SecretKey;
 pub use k256::{elliptic_curve::sec1::FromEncodedPoint, PublicKey};
 use log::*;
 use musig2::{
+	aggregate_partial_signatures,
 	secp::{Point, Scalar},
-	verify_single, BinaryEncoding, CompactSignature, KeyAggContext, LiftedSignature,
-	SecNonceSpices,
+	verify_partial, verify_single, AggNonce, BinaryEncoding, CompactSignature, KeyAggContext,
+	LiftedSignature, SecNonceSpices,
 };
 pub use musig2::{PartialSignature, PubNonce};
 use std::{
 	collections::HashMap,
-	time::{SystemTime, UNIX_EPOCH},
+	time::{Duration, SystemTime, UNIX_EPOCH},
 	vec,
 	vec::Vec,
 };
 
+pub mod store;
+use store::{CeremonySnapshot, RoundSnapshot};
+
 pub type CeremonyId = SignBitcoinPayload;
 pub type SignaturePayload = Vec<u8>;
 pub type Signers = Vec<SignerId>;
-pub type CeremonyRegistry<AK> = HashMap<CeremonyId, (Arc<RwLock<MuSig2Ceremony<AK>>>, u64)>;
-pub type CeremonyCommandTmp = HashMap<CeremonyId, (Arc<RwLock<Vec<CeremonyCommand>>>, u64)>;
+// ceremonies of all signature schemes and their pending commands are tracked the same way,
+// keyed by ceremony id together with the creation timestamp
+pub type Registry<Id, C> = HashMap<Id, (Arc<RwLock<C>>, u64)>;
+pub type CeremonyRegistry<AK> = Registry<CeremonyId, MuSig2Ceremony<AK>>;
+pub type CeremonyCommandTmp = Registry<CeremonyId, Vec<CeremonyCommand>>;
 // enclave public key is used as signer identifier
 pub type SignerId = [u8; 32];
 pub type SignersWithKeys = Vec<(SignerId, PublicKey)>;
@@ -66,9 +73,21 @@ pub enum CeremonyError {
 	CeremonyInitError(CeremonyErrorReason),
 	NonceReceivingError(CeremonyErrorReason),
 	PartialSignatureReceivingError(CeremonyErrorReason),
+	CeremonyAborted(CeremonyErrorReason),
 }
 
-#[derive(Debug, Eq, PartialEq, Encode)]
+impl CeremonyError {
+	pub fn reason(&self) -> &CeremonyErrorReason {
+		match self {
+			CeremonyError::CeremonyInitError(reason)
+			| CeremonyError::NonceReceivingError(reason)
+			| CeremonyError::PartialSignatureReceivingError(reason)
+			| CeremonyError::CeremonyAborted(reason) => reason,
+		}
+	}
+}
+
+#[derive(Debug, Eq, PartialEq, Encode, Decode, Clone)]
 pub enum CeremonyErrorReason {
 	AlreadyExist,
 	CreateCeremonyError,
@@ -76,6 +95,8 @@ pub enum CeremonyErrorReason {
 	ContributionError,
 	IncorrectRound,
 	RoundFinalizationError,
+	// a signer restarted and couldn't resume the ceremony
+	EnclaveRestarted,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -84,6 +105,7 @@ pub enum CeremonyCommand {
 	SaveNonce(SignerId, PubNonce),
 	SavePartialSignature(SignerId, PartialSignature),
 	KillCeremony,
+	AbortCeremony(CeremonyErrorReason),
 }
 
 // events are created by ceremony and executed by runner
@@ -93,6 +115,8 @@ pub enum CeremonyEvent {
 	SecondRoundStarted(Signers, CeremonyId, PartialSignature),
 	CeremonyEnded([u8; 64], bool, bool),
 	CeremonyError(Signers, CeremonyError),
+	// ceremony couldn't be recovered after a restart, peers are told to drop it
+	CeremonyAborted(Signers, CeremonyErrorReason),
 }
 
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, Hash)]
@@ -103,24 +127,90 @@ pub enum SignBitcoinPayload {
 	WithTweaks(SignaturePayload, Vec<([u8; 32], bool)>),
 }
 
+impl SignBitcoinPayload {
+	pub fn message(&self) -> &SignaturePayload {
+		match self {
+			SignBitcoinPayload::Derived(p) => p,
+			SignBitcoinPayload::TaprootUnspendable(p) => p,
+			SignBitcoinPayload::TaprootSpendable(p, _) => p,
+			SignBitcoinPayload::WithTweaks(p, _) => p,
+		}
+	}
+}
+
 pub fn generate_aggregated_public_key(mut public_keys: Vec<PublicKey>) -> PublicKey {
 	public_keys.sort();
 	KeyAggContext::new(public_keys).unwrap().aggregated_pubkey()
 }
 
+fn key_agg_context(
+	signers: &SignersWithKeys,
+	payload: &SignBitcoinPayload,
+) -> Result<KeyAggContext, String> {
+	let all_keys = signers.iter().map(|p| Point::from(p.1)).collect::<Vec<Point>>();
+	let key_context =
+		KeyAggContext::new(all_keys).map_err(|e| format!("Key context creation error: {:?}", e))?;
+	match payload {
+		SignBitcoinPayload::TaprootSpendable(_, root_hash) => key_context
+			.with_taproot_tweak(root_hash)
+			.map_err(|e| format!("Key context creation error: {:?}", e)),
+		SignBitcoinPayload::TaprootUnspendable(_) => key_context
+			.with_unspendable_taproot_tweak()
+			.map_err(|e| format!("Key context creation error: {:?}", e)),
+		SignBitcoinPayload::Derived(_) => Ok(key_context),
+		SignBitcoinPayload::WithTweaks(_, tweaks) => {
+			let mut prepared_tweaks = vec![];
+			for (tweak_bytes, is_x_only) in tweaks.iter() {
+				let scalar: Scalar = tweak_bytes.try_into().map_err(|e| {
+					format!("Key context creation error, could not parse scalar: {:?}", e)
+				})?;
+				prepared_tweaks.push((scalar, *is_x_only));
+			}
+			key_context
+				.with_tweaks(prepared_tweaks)
+				.map_err(|e| format!("Key context creation error: {:?}", e))
+		},
+	}
+}
+
 pub struct MuSig2CeremonyData<AK: AccessKey<KeyType = SchnorrPair>> {
 	payload: SignBitcoinPayload,
 	me: SignerId,
+	my_index: usize,
 	signers: SignersWithKeys,
 	signing_key_access: Arc<AK>,
+	key_context: KeyAggContext,
 	agg_key: PublicKey,
 	// indicates whether it's check run - signature verification result is returned instead of signature
 	check_run: bool,
 }
 
+// contains only public data, so unlike the first round it can be sealed and resumed
+pub struct SecondRound {
+	pub_nonces: Vec<PubNonce>,
+	agg_nonce: AggNonce,
+	our_signature: PartialSignature,
+	partial_signatures: Vec<Option<PartialSignature>>,
+}
+
+impl SecondRound {
+	pub fn our_signature(&self) -> PartialSignature {
+		self.our_signature
+	}
+
+	fn is_complete(&self) -> bool {
+		self.partial_signatures.iter().all(Option::is_some)
+	}
+}
+
 pub struct MuSig2CeremonyState {
+	// in milliseconds, used for latency metrics
+	started_at: u64,
+	second_round_started_at: Option<u64>,
 	first_round: Option<musig2::FirstRound>,
-	second_round: Option<musig2::SecondRound<SignaturePayload>>,
+	// nonces received in the first round, needed to verify partial signatures
+	pub_nonces: Vec<Option<PubNonce>>,
+	second_round: Option<SecondRound>,
 }
 
 pub struct MuSig2Ceremony<AK: AccessKey<KeyType = SchnorrPair>> {
@@ -145,35 +235,7 @@ impl<AK: AccessKey<KeyType = SchnorrPair>> MuSig2Ceremony<AK> {
 		signers.sort_by_key(|k| k.1);
 		// we are always the first key in the vector
 		let my_index = signers.iter().position(|r| r.0 == me).ok_or("Could not determine index")?;
-		let all_keys = signers.iter().map(|p| p.1).collect::<Vec<PublicKey>>();
-		let key_context = match &payload {
-			SignBitcoinPayload::TaprootSpendable(_, root_hash) =>
-				KeyAggContext::new(all_keys.iter().map(|p| Point::from(*p)))
-					.map_err(|e| format!("Key context creation error: {:?}", e))?
-					.with_taproot_tweak(root_hash)
-					.map_err(|e| format!("Key context creation error: {:?}", e))?,
-			SignBitcoinPayload::TaprootUnspendable(_) =>
-				KeyAggContext::new(all_keys.iter().map(|p| Point::from(*p)))
-					.map_err(|e| format!("Key context creation error: {:?}", e))?
-					.with_unspendable_taproot_tweak()
-					.map_err(|e| format!("Key context creation error: {:?}", e))?,
-			SignBitcoinPayload::Derived(_) =>
-				KeyAggContext::new(all_keys.iter().map(|p| Point::from(*p)))
-					.map_err(|e| format!("Key context creation error: {:?}", e))?,
-			SignBitcoinPayload::WithTweaks(_, tweaks) => {
-				let mut prepared_tweaks = vec![];
-				for (tweak_bytes, is_x_only) in tweaks.iter() {
-					let scalar: Scalar = tweak_bytes.try_into().map_err(|e| {
-						format!("Key context creation error, could not parse scalar: {:?}", e)
-					})?;
-					prepared_tweaks.push((scalar, *is_x_only));
-				}
-				KeyAggContext::new(all_keys.iter().map(|p| Point::from(*p)))
-					.map_err(|e| format!("Key context creation error: {:?}", e))?
-					.with_tweaks(prepared_tweaks)
-					.map_err(|e| format!("Key context creation error: {:?}", e))?
-			},
-		};
+		let key_context = key_agg_context(&signers, &payload)?;
 
 		info!(
 			"Ceremony aggregated public key: {:?}",
@@ -181,21 +243,33 @@ impl<AK: AccessKey<KeyType = SchnorrPair>> MuSig2Ceremony<AK> {
 		);
 		let agg_key = key_context.aggregated_pubkey::<PublicKey>();
 		let nonce_seed = random_seed();
-		let first_round =
-			musig2::FirstRound::new(key_context, nonce_seed, my_index, SecNonceSpices::new())
-				.map_err(|e| format!("First round creation error: {:?}", e))?;
+		let first_round = musig2::FirstRound::new(
+			key_context.clone(),
+			nonce_seed,
+			my_index,
+			SecNonceSpices::new(),
+		)
+		.map_err(|e| format!("First round creation error: {:?}", e))?;
+
+		let mut pub_nonces = vec![None; signers.len()];
+		pub_nonces[my_index] = Some(first_round.our_public_nonce());
 
 		let ceremony = Self {
 			ceremony_data: MuSig2CeremonyData {
 				payload,
 				me,
+				my_index,
 				signers,
 				signing_key_access,
+				key_context,
 				agg_key,
 				check_run,
 			},
 			ceremony_state: MuSig2CeremonyState {
+				started_at: get_current_timestamp_millis(),
+				second_round_started_at: None,
 				first_round: Some(first_round),
+				pub_nonces,
 				second_round: None,
 			},
 		};
@@ -232,10 +306,11 @@ impl<AK: AccessKey<KeyType = SchnorrPair>> MuSig2Ceremony<AK> {
 			.ok_or(CeremonyError::NonceReceivingError(CeremonyErrorReason::SignerNotFound))?;
 
 		if let Some(ref mut r) = self.ceremony_state.first_round {
-			r.receive_nonce(peer_index, nonce).map_err(|e| {
+			r.receive_nonce(peer_index, nonce.clone()).map_err(|e| {
 				error!("Nonce receiving error: {:?}", e);
 				CeremonyError::NonceReceivingError(CeremonyErrorReason::ContributionError)
 			})?;
+			self.ceremony_state.pub_nonces[peer_index] = Some(nonce);
 			if r.is_complete() {
 				let secret_key = SecretKey::from_slice(
 					&self
@@ -274,12 +349,7 @@ impl<AK: AccessKey<KeyType = SchnorrPair>> MuSig2Ceremony<AK> {
 			.take()
 			.ok_or(CeremonyError::NonceReceivingError(CeremonyErrorReason::IncorrectRound))?;
 
-		let message = match &self.ceremony_data.payload {
-			SignBitcoinPayload::TaprootSpendable(message, _) => message.clone(),
-			SignBitcoinPayload::Derived(message) => message.clone(),
-			SignBitcoinPayload::TaprootUnspendable(message) => message.clone(),
-			SignBitcoinPayload::WithTweaks(message, _) => message.clone(),
-		};
+		let message = self.ceremony_data.payload.message().clone();
 		let second_round = first_round.finalize(private_key, message).map_err(|e| {
 			error!("Could not start second round: {:?}", e);
 			CeremonyError::NonceReceivingError(CeremonyErrorReason::RoundFinalizationError)
@@ -287,7 +357,25 @@ impl<AK: AccessKey<KeyType = SchnorrPair>> MuSig2Ceremony<AK> {
 
 		let partial_signature: PartialSignature = second_round.our_signature();
 
-		self.ceremony_state.second_round = Some(second_round);
+		let pub_nonces = self
+			.ceremony_state
+			.pub_nonces
+			.iter()
+			.cloned()
+			.collect::<Option<Vec<PubNonce>>>()
+			.ok_or(CeremonyError::NonceReceivingError(
+				CeremonyErrorReason::RoundFinalizationError,
+			))?;
+		let mut partial_signatures = vec![None; pub_nonces.len()];
+		partial_signatures[self.ceremony_data.my_index] = Some(partial_signature);
+
+		self.ceremony_state.second_round_started_at = Some(get_current_timestamp_millis());
+		self.ceremony_state.second_round = Some(SecondRound {
+			agg_nonce: AggNonce::sum(&pub_nonces),
+			pub_nonces,
+			our_signature: partial_signature,
+			partial_signatures,
+		});
 
 		Ok(CeremonyEvent::SecondRoundStarted(
 			self.get_signers_except_self(),
@@ -307,56 +395,190 @@ impl<AK: AccessKey<KeyType = SchnorrPair>> MuSig2Ceremony<AK> {
 			CeremonyError::PartialSignatureReceivingError(CeremonyErrorReason::SignerNotFound),
 		)?;
 
+		let partial_signature: PartialSignature = partial_signature.into();
+		let data = &self.ceremony_data;
 		if let Some(ref mut r) = self.ceremony_state.second_round {
-			r.receive_signature(peer_index, partial_signature).map_err(|e| {
+			match r.partial_signatures[peer_index] {
+				// the same share may be re-sent by a peer resuming after a restart
+				Some(existing) if existing == partial_signature => return Ok(None),
+				Some(_) =>
+					return Err(CeremonyError::PartialSignatureReceivingError(
+						CeremonyErrorReason::ContributionError,
+					)),
+				None => {},
+			}
+			verify_partial(
+				&data.key_context,
+				partial_signature,
+				&r.agg_nonce,
+				data.signers[peer_index].1,
+				&r.pub_nonces[peer_index],
+				data.payload.message(),
+			)
+			.map_err(|e| {
 				error!("Signature receiving error: {:?}", e);
 				CeremonyError::PartialSignatureReceivingError(
 					CeremonyErrorReason::ContributionError,
 				)
 			})?;
-			if r.is_complete() {
-				if let Some(r) = self.ceremony_state.second_round.take() {
-					let signature: CompactSignature = r
-						.finalize::<LiftedSignature>()
-						.map_err(|e| {
-							error!("Could not finish second round: {:?}", e);
-							CeremonyError::PartialSignatureReceivingError(
-								CeremonyErrorReason::RoundFinalizationError,
-							)
-						})?
-						.compact();
-
-					info!("Ceremony {:?} `has ended`", self.get_id_ref());
-					info!("Aggregated public key {:?}", self.ceremony_data.agg_key.to_sec1_bytes());
-					info!("Signature {:?}", signature.to_bytes());
-
-					let message = match &self.ceremony_data.payload {
-						SignBitcoinPayload::Derived(p) => p,
-						SignBitcoinPayload::TaprootUnspendable(p) => p,
-						SignBitcoinPayload::TaprootSpendable(p, _) => p,
-						SignBitcoinPayload::WithTweaks(p, _) => p,
-					};
-
-					let result =
-						verify_single(self.ceremony_data.agg_key, signature, message).is_ok();
-					Ok(Some(CeremonyEvent::CeremonyEnded(
-						signature.to_bytes(),
-						self.ceremony_data.check_run,
-						result,
-					)))
-				} else {
-					Err(CeremonyError::PartialSignatureReceivingError(
-						CeremonyErrorReason::IncorrectRound,
-					))
-				}
-			} else {
-				Ok(None)
-			}
+			r.partial_signatures[peer_index] = Some(partial_signature);
+			self.finalize_if_complete()
 		} else {
 			Err(CeremonyError::PartialSignatureReceivingError(CeremonyErrorReason::IncorrectRound))
 		}
 	}
 
+	// Aggregates the partial signatures once all of them are collected
+	fn finalize_if_complete(&mut self) -> Result<Option<CeremonyEvent>, CeremonyError> {
+		match self.ceremony_state.second_round {
+			Some(ref r) if r.is_complete() => {},
+			Some(_) => return Ok(None),
+			None =>
+				return Err(CeremonyError::PartialSignatureReceivingError(
+					CeremonyErrorReason::IncorrectRound,
+				)),
+		}
+		let r = self.ceremony_state.second_round.take().ok_or(
+			CeremonyError::PartialSignatureReceivingError(CeremonyErrorReason::IncorrectRound),
+		)?;
+		let message = self.ceremony_data.payload.message();
+		let signature: CompactSignature = aggregate_partial_signatures::<_, LiftedSignature>(
+			&self.ceremony_data.key_context,
+			&r.agg_nonce,
+			r.partial_signatures.into_iter().flatten(),
+			message,
+		)
+		.map_err(|e| {
+			error!("Could not finish second round: {:?}", e);
+			CeremonyError::PartialSignatureReceivingError(
+				CeremonyErrorReason::RoundFinalizationError,
+			)
+		})?
+		.compact();
+
+		info!("Ceremony {:?} `has ended`", self.get_id_ref());
+		info!("Aggregated public key {:?}", self.ceremony_data.agg_key.to_sec1_bytes());
+		info!("Signature {:?}", signature.to_bytes());
+
+		let result = verify_single(self.ceremony_data.agg_key, signature, message).is_ok();
+		Ok(Some(CeremonyEvent::CeremonyEnded(
+			signature.to_bytes(),
+			self.ceremony_data.check_run,
+			result,
+		)))
+	}
+
+	// Public parts of the ceremony state, the secret nonce is never included
+	pub fn snapshot(&self) -> CeremonySnapshot {
+		let round = match self.ceremony_state.second_round {
+			Some(ref r) => RoundSnapshot::Second {
+				pub_nonces: r.pub_nonces.iter().map(|n| n.serialize()).collect(),
+				partial_signatures: r
+					.partial_signatures
+					.iter()
+					.map(|s| s.map(|s| s.serialize()))
+					.collect(),
+			},
+			None => RoundSnapshot::First,
+		};
+		CeremonySnapshot {
+			payload: self.ceremony_data.payload.clone(),
+			signers: self
+				.ceremony_data
+				.signers
+				.iter()
+				.map(|(id, key)| (*id, key.to_sec1_bytes().to_vec()))
+				.collect(),
+			check_run: self.ceremony_data.check_run,
+			round,
+		}
+	}
+
+	// Resumes a ceremony from its snapshot. Only the second round can be resumed, a ceremony
+	// still in the first round lost its secret nonce and a new one must never be used instead.
+	pub fn resume(
+		me: SignerId,
+		snapshot: CeremonySnapshot,
+		signing_key_access: Arc<AK>,
+	) -> Result<(Self, CeremonyEvent), CeremonyErrorReason> {
+		info!("Resuming ceremony {:?}", snapshot.payload);
+		let (pub_nonces, partial_signatures) = match snapshot.round {
+			RoundSnapshot::First => return Err(CeremonyErrorReason::EnclaveRestarted),
+			RoundSnapshot::Second { pub_nonces, partial_signatures } =>
+				(pub_nonces, partial_signatures),
+		};
+		let restore_error = |e: String| {
+			error!("Could not resume ceremony: {}", e);
+			CeremonyErrorReason::EnclaveRestarted
+		};
+
+		let signers = snapshot
+			.signers
+			.iter()
+			.map(|(id, key)| PublicKey::from_sec1_bytes(key).map(|k| (*id, k)))
+			.collect::<Result<SignersWithKeys, _>>()
+			.map_err(|e| restore_error(format!("invalid signer key: {:?}", e)))?;
+		let my_index = signers
+			.iter()
+			.position(|r| r.0 == me)
+			.ok_or_else(|| restore_error("could not determine index".into()))?;
+		let pub_nonces = pub_nonces
+			.iter()
+			.map(|n| PubNonce::from_bytes(n.as_slice()))
+			.collect::<Result<Vec<PubNonce>, _>>()
+			.map_err(|e| restore_error(format!("invalid nonce: {:?}", e)))?;
+		let partial_signatures = partial_signatures
+			.iter()
+			.map(|s| s.map(|s| PartialSignature::from_slice(&s)).transpose())
+			.collect::<Result<Vec<Option<PartialSignature>>, _>>()
+			.map_err(|e| restore_error(format!("invalid partial signature: {:?}", e)))?;
+		if pub_nonces.len() != signers.len() || partial_signatures.len() != signers.len() {
+			return Err(restore_error("round data doesn't match signers".into()))
+		}
+		let our_signature = partial_signatures[my_index]
+			.ok_or_else(|| restore_error("missing own partial signature".into()))?;
+
+		let key_context = key_agg_context(&signers, &snapshot.payload).map_err(restore_error)?;
+		let agg_key = key_context.aggregated_pubkey::<PublicKey>();
+
+		let mut ceremony = Self {
+			ceremony_data: MuSig2CeremonyData {
+				payload: snapshot.payload,
+				me,
+				my_index,
+				signers,
+				signing_key_access,
+				key_context,
+				agg_key,
+				check_run: snapshot.check_run,
+			},
+			ceremony_state: MuSig2CeremonyState {
+				started_at: get_current_timestamp_millis(),
+				second_round_started_at: Some(get_current_timestamp_millis()),
+				first_round: None,
+				pub_nonces: pub_nonces.iter().cloned().map(Some).collect(),
+				second_round: Some(SecondRound {
+					agg_nonce: AggNonce::sum(&pub_nonces),
+					pub_nonces,
+					our_signature,
+					partial_signatures,
+				}),
+			},
+		};
+
+		// peers may have missed our partial signature, so it's shared again
+		let event = match ceremony.finalize_if_complete() {
+			Ok(Some(event)) => event,
+			Ok(None) => CeremonyEvent::SecondRoundStarted(
+				ceremony.get_signers_except_self(),
+				ceremony.get_id_ref().clone(),
+				our_signature,
+			),
+			Err(e) => return Err(restore_error(format!("{:?}", e))),
+		};
+		Ok((ceremony, event))
+	}
+
 	pub fn get_signers_except_self(&self) -> Signers {
 		self.ceremony_data
 			.signers
@@ -373,12 +595,69 @@ impl<AK: AccessKey<KeyType = SchnorrPair>> MuSig2Ceremony<AK> {
 	pub fn is_first_round(&self) -> bool {
 		self.ceremony_state.first_round.is_some()
 	}
+
+	// time since the ceremony was created, or resumed after a restart
+	pub fn elapsed(&self) -> Duration {
+		elapsed_since(self.ceremony_state.started_at)
+	}
+
+	// time since the current round started
+	pub fn round_elapsed(&self) -> Duration {
+		elapsed_since(
+			self.ceremony_state
+				.second_round_started_at
+				.unwrap_or(self.ceremony_state.started_at),
+		)
+	}
+
+	pub fn first_round_duration(&self) -> Option<Duration> {
+		self.ceremony_state.second_round_started_at.map(|second_round_started_at| {
+			Duration::from_millis(
+				second_round_started_at.saturating_sub(self.ceremony_state.started_at),
+			)
+		})
+	}
+
+	pub fn is_check_run(&self) -> bool {
+		self.ceremony_data.check_run
+	}
+}
+
+// drops the entries created more than `time_to_live` seconds ago, `on_expired` is called
+// for each of them, returns the number of dropped entries
+pub fn remove_expired<Id, C, F>(
+	registry: &mut Registry<Id, C>,
+	now: u64,
+	time_to_live: u64,
+	mut on_expired: F,
+) -> u8
+where
+	F: FnMut(&Id, &C),
+{
+	let mut expired_count: u8 = 0;
+	registry.retain(|id, (entry, create_time)| {
+		let if_retain = now.saturating_sub(*create_time) < time_to_live;
+		if !if_retain {
+			on_expired(id, &entry.read().unwrap());
+			expired_count = expired_count.saturating_add(1);
+		}
+		if_retain
+	});
+	expired_count
 }
 
 pub fn get_current_timestamp() -> u64 {
 	SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
 }
 
+pub fn get_current_timestamp_millis() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
+fn elapsed_since(timestamp_millis: u64) -> Duration {
+	Duration::from_millis(get_current_timestamp_millis().saturating_sub(timestamp_millis))
+}
+
 #[cfg(feature = "std")]
 fn random_seed() -> [u8; 32] {
 	use rand::{thread_rng, RngCore};
@@ -401,10 +680,12 @@ fn random_seed() -> [u8; 32] {
 #[cfg(test)]
 pub mod test {
 	use crate::{
+		store::{CeremonySnapshot, RoundSnapshot},
 		CeremonyError, CeremonyErrorReason, CeremonyEvent, MuSig2Ceremony, SignBitcoinPayload,
 		SignerId, SignersWithKeys,
 	};
 	use alloc::sync::Arc;
+	use codec::{Decode, Encode};
 	use itp_sgx_crypto::{key_repository::AccessKey, schnorr::Pair as SchnorrPair};
 	use k256::{elliptic_curve::PublicKey, schnorr::SigningKey};
 	use musig2::SecNonce;
@@ -585,6 +866,125 @@ pub mod test {
 			)
 		);
 	}
+
+	fn new_ceremony(
+		me: SignerId,
+		key: SigningKey,
+	) -> (MuSig2Ceremony<MockedSigningKeyAccess>, crate::PubNonce) {
+		let (ceremony, event) = MuSig2Ceremony::new(
+			me,
+			signers_with_keys(),
+			SignBitcoinPayload::Derived(SAMPLE_SIGNATURE_PAYLOAD.to_vec()),
+			Arc::new(MockedSigningKeyAccess { signing_key: key }),
+			false,
+		)
+		.unwrap();
+		match event {
+			CeremonyEvent::FirstRoundStarted(_, _, nonce) => (ceremony, nonce),
+			_ => panic!("Ceremony should start first round"),
+		}
+	}
+
+	fn partial_signature(event: Option<CeremonyEvent>) -> crate::PartialSignature {
+		match event {
+			Some(CeremonyEvent::SecondRoundStarted(_, _, signature)) => signature,
+			_ => panic!("Ceremony should start second round"),
+		}
+	}
+
+	// runs the first round of all three ceremonies and returns the partial signatures
+	fn ceremonies_in_second_round() -> (
+		MuSig2Ceremony<MockedSigningKeyAccess>,
+		crate::PartialSignature,
+		crate::PartialSignature,
+		crate::PartialSignature,
+	) {
+		let (mut my_ceremony, my_nonce) = new_ceremony(MY_SIGNER_ID, my_priv_key());
+		let (mut signer1_ceremony, signer1_nonce) = new_ceremony(SIGNER_1_ID, signer1_priv_key());
+		let (mut signer2_ceremony, signer2_nonce) = new_ceremony(SIGNER_2_ID, signer2_priv_key());
+
+		my_ceremony.receive_nonce(SIGNER_1_ID, signer1_nonce.clone()).unwrap();
+		let my_signature = partial_signature(
+			my_ceremony.receive_nonce(SIGNER_2_ID, signer2_nonce.clone()).unwrap(),
+		);
+		signer1_ceremony.receive_nonce(MY_SIGNER_ID, my_nonce.clone()).unwrap();
+		let signer1_signature =
+			partial_signature(signer1_ceremony.receive_nonce(SIGNER_2_ID, signer2_nonce).unwrap());
+		signer2_ceremony.receive_nonce(MY_SIGNER_ID, my_nonce).unwrap();
+		let signer2_signature =
+			partial_signature(signer2_ceremony.receive_nonce(SIGNER_1_ID, signer1_nonce).unwrap());
+
+		(my_ceremony, my_signature, signer1_signature, signer2_signature)
+	}
+
+	#[test]
+	fn it_should_not_resume_ceremony_in_first_round() {
+		// given
+		let (ceremony, _) = new_ceremony(MY_SIGNER_ID, my_priv_key());
+		let snapshot = ceremony.snapshot();
+		assert_eq!(snapshot.round, RoundSnapshot::First);
+
+		// when
+		let result = MuSig2Ceremony::resume(
+			MY_SIGNER_ID,
+			snapshot,
+			Arc::new(MockedSigningKeyAccess { signing_key: my_priv_key() }),
+		);
+
+		// then
+		assert!(matches!(result, Err(CeremonyErrorReason::EnclaveRestarted)));
+	}
+
+	#[test]
+	fn it_should_resume_ceremony_in_second_round() {
+		// given
+		let (my_ceremony, my_signature, signer1_signature, signer2_signature) =
+			ceremonies_in_second_round();
+		let encoded = my_ceremony.snapshot().encode();
+		let snapshot = CeremonySnapshot::decode(&mut encoded.as_slice()).unwrap();
+
+		// when
+		let (mut resumed, event) = MuSig2Ceremony::resume(
+			MY_SIGNER_ID,
+			snapshot,
+			Arc::new(MockedSigningKeyAccess { signing_key: my_priv_key() }),
+		)
+		.unwrap();
+
+		// then
+		assert_eq!(
+			event,
+			CeremonyEvent::SecondRoundStarted(
+				vec![SIGNER_1_ID, SIGNER_2_ID],
+				SignBitcoinPayload::Derived(SAMPLE_SIGNATURE_PAYLOAD.to_vec()),
+				my_signature,
+			)
+		);
+		assert!(resumed.receive_partial_sign(SIGNER_1_ID, signer1_signature).unwrap().is_none());
+		// a share re-sent by a resuming peer is ignored
+		assert!(resumed.receive_partial_sign(SIGNER_1_ID, signer1_signature).unwrap().is_none());
+		assert!(matches!(
+			resumed.receive_partial_sign(SIGNER_2_ID, signer2_signature).unwrap(),
+			Some(CeremonyEvent::CeremonyEnded(_, false, true))
+		));
+	}
+
+	#[test]
+	fn it_should_reject_invalid_partial_signature() {
+		// given
+		let (mut my_ceremony, _, signer1_signature, _) = ceremonies_in_second_round();
+
+		// when
+		let result = my_ceremony.receive_partial_sign(SIGNER_2_ID, signer1_signature);
+
+		// then
+		assert!(matches!(
+			result,
+			Err(CeremonyError::PartialSignatureReceivingError(
+				CeremonyErrorReason::ContributionError
+			))
+		));
+	}
 }
 
 #[cfg(feature = "sgx-test")]
@@ -0,0 +1,156 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Sealed snapshots of in-flight ceremonies, used to recover them after the enclave restarts.
+//!
+//! Secret nonces are never part of a snapshot: a nonce must not be used twice, so a
+//! ceremony that restarts before its first round is finalized can only be aborted.
+
+use crate::{SignBitcoinPayload, SignerId};
+use codec::{Decode, Encode};
+use std::{path::PathBuf, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "sgx")]
+use std::sync::SgxMutex as Mutex;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub enum RoundSnapshot {
+	// nothing to keep, the secret nonce is gone after a restart
+	First,
+	// public nonces and partial signatures collected so far, in signer order
+	Second { pub_nonces: Vec<[u8; 66]>, partial_signatures: Vec<Option<[u8; 32]>> },
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct CeremonySnapshot {
+	pub payload: SignBitcoinPayload,
+	// signers sorted the same way as in the ceremony, with sec1 encoded public keys
+	pub signers: Vec<(SignerId, Vec<u8>)>,
+	pub check_run: bool,
+	pub round: RoundSnapshot,
+}
+
+#[derive(Debug)]
+pub enum CeremonyStoreError {
+	PoisonLock,
+	Io(std::io::Error),
+	Codec(codec::Error),
+}
+
+impl From<std::io::Error> for CeremonyStoreError {
+	fn from(e: std::io::Error) -> Self {
+		Self::Io(e)
+	}
+}
+
+impl From<codec::Error> for CeremonyStoreError {
+	fn from(e: codec::Error) -> Self {
+		Self::Codec(e)
+	}
+}
+
+pub type CeremonyStoreResult<T> = core::result::Result<T, CeremonyStoreError>;
+
+#[cfg(feature = "sgx")]
+mod sgx {
+	use super::{CeremonySnapshot, CeremonyStoreError as Error, CeremonyStoreResult as Result};
+	pub use codec::{Decode, Encode};
+	pub use itp_settings::files::MUSIG2_CEREMONIES_FILE;
+	pub use itp_sgx_io::{seal, unseal, SealedIO};
+	pub use log::*;
+	pub use std::{path::PathBuf, sgxfs::SgxFile, vec::Vec};
+
+	#[derive(Clone, Debug)]
+	pub struct CeremonyStoreSeal {
+		base_path: PathBuf,
+	}
+
+	impl CeremonyStoreSeal {
+		pub fn new(base_path: PathBuf) -> Self {
+			Self { base_path }
+		}
+
+		pub fn path(&self) -> PathBuf {
+			self.base_path.join(MUSIG2_CEREMONIES_FILE)
+		}
+	}
+
+	impl SealedIO for CeremonyStoreSeal {
+		type Error = Error;
+		type Unsealed = Vec<CeremonySnapshot>;
+
+		fn unseal(&self) -> Result<Self::Unsealed> {
+			Ok(unseal(self.path()).map(|b| Decode::decode(&mut b.as_slice()))??)
+		}
+
+		fn seal(&self, unsealed: &Self::Unsealed) -> Result<()> {
+			debug!("Seal musig2 ceremonies to file, count: {}", unsealed.len());
+			Ok(unsealed.using_encoded(|bytes| seal(bytes, self.path()))?)
+		}
+	}
+}
+
+#[cfg(feature = "sgx")]
+use sgx::*;
+
+pub struct CeremonyStore {
+	// serializes taking a snapshot and sealing it, so an older snapshot never overwrites a newer one
+	lock: Mutex<()>,
+	pub seal_path: PathBuf,
+}
+
+impl CeremonyStore {
+	pub fn new(base_dir: PathBuf) -> Self {
+		CeremonyStore { lock: Mutex::new(()), seal_path: base_dir }
+	}
+
+	#[cfg(feature = "std")]
+	pub fn load(&self) -> CeremonyStoreResult<Vec<CeremonySnapshot>> {
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "std")]
+	pub fn save_with<F>(&self, snapshots: F) -> CeremonyStoreResult<()>
+	where
+		F: FnOnce() -> Vec<CeremonySnapshot>,
+	{
+		let _guard = self.lock.lock().map_err(|_| CeremonyStoreError::PoisonLock)?;
+		let _ = snapshots();
+		Ok(())
+	}
+
+	// returns the ceremonies sealed before the restart, if `MUSIG2_CEREMONIES_FILE` exists
+	#[cfg(feature = "sgx")]
+	pub fn load(&self) -> CeremonyStoreResult<Vec<CeremonySnapshot>> {
+		let _guard = self.lock.lock().map_err(|_| CeremonyStoreError::PoisonLock)?;
+		if SgxFile::open(MUSIG2_CEREMONIES_FILE).is_err() {
+			info!("[Enclave] MuSig2 ceremonies file not found, nothing to recover");
+			return Ok(Vec::new())
+		}
+		CeremonyStoreSeal::new(self.seal_path.clone()).unseal()
+	}
+
+	#[cfg(feature = "sgx")]
+	pub fn save_with<F>(&self, snapshots: F) -> CeremonyStoreResult<()>
+	where
+		F: FnOnce() -> Vec<CeremonySnapshot>,
+	{
+		let _guard = self.lock.lock().map_err(|_| CeremonyStoreError::PoisonLock)?;
+		CeremonyStoreSeal::new(self.seal_path.clone()).seal(&snapshots())
+	}
+}
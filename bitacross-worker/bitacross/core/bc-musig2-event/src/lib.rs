@@ -43,13 +43,18 @@ use std::sync::RwLock;
 use std::sync::SgxRwLock as RwLock;
 
 use bc_enclave_registry::EnclaveRegistryLookup;
-use bc_musig2_ceremony::{CeremonyEvent, CeremonyId, CeremonyRegistry, SignerId};
+use bc_frost_ceremony::{FrostCeremonyEvent, FrostCeremonyRegistry, SolanaCeremonyId};
+use bc_musig2_ceremony::{
+	get_current_timestamp, CeremonyEvent, CeremonyId, CeremonyRegistry, SignerId, Signers,
+};
 use codec::Encode;
 use itc_direct_rpc_client::{DirectRpcClient, DirectRpcClientFactory, RpcClient, RpcClientFactory};
 use itc_direct_rpc_server::SendRpcResponse;
 use itp_ocall_api::EnclaveAttestationOCallApi;
 use itp_rpc::{Id, RpcRequest};
-use itp_sgx_crypto::{key_repository::AccessKey, schnorr::Pair as SchnorrPair};
+use itp_sgx_crypto::{
+	frost::Pair as FrostPair, key_repository::AccessKey, schnorr::Pair as SchnorrPair,
+};
 pub use itp_types::{DirectRequestStatus, Hash};
 use itp_utils::hex::ToHexPrefixed;
 use lc_direct_call::CeremonyRoundCall;
@@ -58,6 +63,9 @@ use log::*;
 use sp_core::{blake2_256, ed25519, Pair as SpCorePair, H256};
 use std::{collections::HashMap, string::ToString, sync::Arc, thread::sleep, vec};
 
+// how long a restarted signer keeps trying to reach its peers to abort a ceremony, in seconds
+const ABORT_REQUEST_TTL: u64 = 30;
+
 #[allow(clippy::too_many_arguments)]
 pub fn process_event<OCallApi, SIGNINGAK, Responder, ECL, BKR>(
 	signing_key_access: Arc<SIGNINGAK>,
@@ -100,11 +108,15 @@ pub fn process_event<OCallApi, SIGNINGAK, Responder, ECL, BKR>(
 				event_threads_pool.execute(move || {
 					send_request(
 						signer_id,
-						&ceremony_id_cloned,
 						request,
 						peers_map_clone,
 						enclave_lookup_cloned,
-						ceremony_registry_cloned,
+						|| {
+							ceremony_registry_cloned
+								.read()
+								.unwrap()
+								.contains_key(&ceremony_id_cloned)
+						},
 					);
 				});
 			});
@@ -129,11 +141,15 @@ pub fn process_event<OCallApi, SIGNINGAK, Responder, ECL, BKR>(
 				event_threads_pool.execute(move || {
 					send_request(
 						signer_id,
-						&ceremony_id_cloned,
 						request,
 						peers_map_clone,
 						enclave_lookup_cloned,
-						ceremony_registry_cloned,
+						|| {
+							ceremony_registry_cloned
+								.read()
+								.unwrap()
+								.contains_key(&ceremony_id_cloned)
+						},
 					);
 				});
 			});
@@ -190,11 +206,44 @@ pub fn process_event<OCallApi, SIGNINGAK, Responder, ECL, BKR>(
 				event_threads_pool.execute(move || {
 					send_request(
 						signer_id,
-						&ceremony_id_cloned,
 						request,
 						peers_map_clone,
 						enclave_lookup_cloned,
-						ceremony_registry_cloned,
+						|| {
+							ceremony_registry_cloned
+								.read()
+								.unwrap()
+								.contains_key(&ceremony_id_cloned)
+						},
+					);
+				});
+			});
+		},
+		CeremonyEvent::CeremonyAborted(signers, reason) => {
+			debug!("Ceremony {:?} aborted, reason {:?}", ceremony_id, reason);
+			let direct_call =
+				CeremonyRoundCall::AbortCeremony(identity, ceremony_id.clone(), reason);
+			let request = prepare_request(signing_key_access.as_ref(), mr_enclave, direct_call);
+			// the ceremony is not in the registry anymore, so peers are tried until the deadline
+			let deadline = get_current_timestamp() + ABORT_REQUEST_TTL;
+
+			signers.iter().for_each(|signer_id| {
+				debug!(
+					"Requesting ceremony abort on signer: {:?} for ceremony: {:?}",
+					signer_id, ceremony_id
+				);
+
+				let signer_id = *signer_id;
+				let peers_map_clone = peers_map.clone();
+				let request = request.clone();
+				let enclave_lookup_cloned = enclave_registry_lookup.clone();
+				event_threads_pool.execute(move || {
+					send_request(
+						signer_id,
+						request,
+						peers_map_clone,
+						enclave_lookup_cloned,
+						|| get_current_timestamp() < deadline,
 					);
 				});
 			});
@@ -202,17 +251,108 @@ pub fn process_event<OCallApi, SIGNINGAK, Responder, ECL, BKR>(
 	}
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn process_frost_event<OCallApi, SIGNINGAK, Responder, ECL, SOLAK>(
+	signing_key_access: Arc<SIGNINGAK>,
+	ocall_api: Arc<OCallApi>,
+	responder: Arc<Responder>,
+	enclave_registry_lookup: Arc<ECL>,
+	event: FrostCeremonyEvent,
+	ceremony_id: SolanaCeremonyId,
+	event_threads_pool: ThreadPool,
+	peers_map: Arc<Mutex<HashMap<[u8; 32], DirectRpcClient>>>,
+	ceremony_registry: Arc<RwLock<FrostCeremonyRegistry<SOLAK>>>,
+) where
+	OCallApi: EnclaveAttestationOCallApi + 'static,
+	SIGNINGAK: AccessKey<KeyType = ed25519::Pair> + Send + Sync + 'static,
+	Responder: SendRpcResponse<Hash = H256> + 'static,
+	ECL: EnclaveRegistryLookup + Send + Sync + 'static,
+	SOLAK: AccessKey<KeyType = FrostPair> + Send + Sync + 'static,
+{
+	let my_identity: Address32 = signing_key_access.retrieve_key().unwrap().public().0.into();
+	let identity = Identity::Substrate(my_identity);
+	let mr_enclave = ocall_api.get_mrenclave_of_self().unwrap().m;
+
+	let broadcast = |signers: Signers, direct_call: CeremonyRoundCall| {
+		let request = prepare_request(signing_key_access.as_ref(), mr_enclave, direct_call);
+		signers.iter().for_each(|signer_id| {
+			let signer_id = *signer_id;
+			let peers_map_clone = peers_map.clone();
+			let request = request.clone();
+			let enclave_lookup_cloned = enclave_registry_lookup.clone();
+			let ceremony_registry_cloned = ceremony_registry.clone();
+			let ceremony_id_cloned = ceremony_id.clone();
+			event_threads_pool.execute(move || {
+				send_request(signer_id, request, peers_map_clone, enclave_lookup_cloned, || {
+					ceremony_registry_cloned.read().unwrap().contains_key(&ceremony_id_cloned)
+				});
+			});
+		});
+	};
+
+	match event {
+		FrostCeremonyEvent::FirstRoundStarted(signers, payload, commitments) => {
+			debug!(
+				"Sharing commitments with signers: {:?} for ceremony: {:?}",
+				signers, ceremony_id
+			);
+			broadcast(signers, CeremonyRoundCall::CommitmentsShare(identity, payload, commitments));
+		},
+		FrostCeremonyEvent::SecondRoundStarted(signers, payload, share) => {
+			debug!(
+				"Sharing signature share with signers: {:?} for ceremony: {:?}",
+				signers, ceremony_id
+			);
+			broadcast(signers, CeremonyRoundCall::SignatureShare(identity, payload, share));
+		},
+		FrostCeremonyEvent::CeremonyEnded(signature) => {
+			debug!("Frost ceremony {:?} ended, signature {:?}", ceremony_id, signature);
+			let hash = blake2_256(&ceremony_id.encode());
+			let result = signature.encode();
+			event_threads_pool.execute(move || {
+				if let Err(e) = responder.send_state_with_status(
+					Hash::from_slice(&hash),
+					result,
+					DirectRequestStatus::Ok,
+				) {
+					error!("Could not send response to {:?}, reason: {:?}", &hash, e);
+				}
+			});
+		},
+		FrostCeremonyEvent::CeremonyError(signers, error) => {
+			debug!("Frost ceremony {:?} error {:?}", ceremony_id, error);
+			let hash = blake2_256(&ceremony_id.encode());
+			let encoded_result = error.encode();
+			event_threads_pool.execute(move || {
+				if let Err(e) = responder.send_state_with_status(
+					Hash::from_slice(&hash),
+					encoded_result,
+					DirectRequestStatus::Error,
+				) {
+					error!("Could not send response to {:?}, reason: {:?}", &hash, e);
+				}
+			});
+
+			//kill ceremonies on other workers
+			debug!("Requesting frost ceremony kill on signers: {:?}", signers);
+			broadcast(
+				signers,
+				CeremonyRoundCall::KillSolanaCeremony(identity, ceremony_id.clone()),
+			);
+		},
+	}
+}
+
 // it will try to send request until it succeeds, the peer is removed from registry or ceremony is removed
-fn send_request<ECL, BKR>(
+fn send_request<ECL, F>(
 	signer_id: SignerId,
-	ceremony_id: &CeremonyId,
 	request: RpcRequest,
 	peers_map: Arc<Mutex<HashMap<[u8; 32], DirectRpcClient>>>,
 	enclave_registry_lookup: Arc<ECL>,
-	ceremony_registry: Arc<RwLock<CeremonyRegistry<BKR>>>,
+	ceremony_exists: F,
 ) where
 	ECL: EnclaveRegistryLookup,
-	BKR: AccessKey<KeyType = SchnorrPair>,
+	F: Fn() -> bool,
 {
 	loop {
 		let client = peers_map.lock().unwrap().get(&signer_id).cloned();
@@ -228,7 +368,7 @@ fn send_request<ECL, BKR>(
 			}
 		} else {
 			// check if ceremony still exists, if not stop
-			if !ceremony_registry.read().unwrap().contains_key(ceremony_id) {
+			if !ceremony_exists() {
 				break
 			}
 
@@ -25,7 +25,7 @@ compile_error!("feature \"std\" and feature \"sgx\" cannot be enabled at the sam
 
 use sp_std::{boxed::Box, fmt::Debug};
 
-use litentry_primitives::Identity;
+use litentry_primitives::{Identity, RelayerRole};
 use log::error;
 use std::{collections::BTreeMap, path::PathBuf};
 
@@ -34,7 +34,7 @@ use std::sync::RwLock;
 #[cfg(feature = "sgx")]
 use std::sync::SgxRwLock as RwLock;
 
-pub type RelayerRegistryMap = BTreeMap<Identity, ()>;
+pub type RelayerRegistryMap = BTreeMap<Identity, RelayerRole>;
 
 #[derive(Default)]
 pub struct RelayerRegistry {
@@ -85,10 +85,13 @@ impl From<codec::Error> for RegistryError {
 mod sgx {
 	use crate::{RegistryError as Error, RegistryResult as Result, RelayerRegistryMap};
 	pub use codec::{Decode, Encode};
-	pub use itp_settings::files::RELAYER_REGISTRY_FILE;
+	pub use itp_settings::files::{RELAYER_REGISTRY_FILE, RELAYER_REGISTRY_V1_FILE};
 	pub use itp_sgx_io::{seal, unseal, SealedIO};
+	pub use litentry_primitives::{Identity, RelayerRole};
 	pub use log::*;
-	pub use std::{boxed::Box, fs, path::PathBuf, sgxfs::SgxFile, sync::Arc};
+	pub use std::{
+		boxed::Box, collections::BTreeMap, fs, path::PathBuf, sgxfs::SgxFile, sync::Arc,
+	};
 
 	#[derive(Clone, Debug)]
 	pub struct RelayerRegistrySeal {
@@ -103,6 +106,13 @@ mod sgx {
 		pub fn path(&self) -> PathBuf {
 			self.base_path.join(RELAYER_REGISTRY_FILE)
 		}
+
+		// the registry before relayer roles were introduced, every relayer in it was fully trusted
+		pub fn unseal_v1(&self) -> Result<RelayerRegistryMap> {
+			let v1: BTreeMap<Identity, ()> = unseal(self.base_path.join(RELAYER_REGISTRY_V1_FILE))
+				.map(|b| Decode::decode(&mut b.as_slice()))??;
+			Ok(v1.into_keys().map(|account| (account, RelayerRole::Full)).collect())
+		}
 	}
 
 	impl SealedIO for RelayerRegistrySeal {
@@ -125,12 +135,13 @@ use sgx::*;
 
 pub trait RelayerRegistryUpdater {
 	fn init(&self) -> RegistryResult<()>;
-	fn update(&self, account: Identity) -> RegistryResult<()>;
+	fn update(&self, account: Identity, role: RelayerRole) -> RegistryResult<()>;
 	fn remove(&self, account: Identity) -> RegistryResult<()>;
 }
 
 pub trait RelayerRegistryLookup {
 	fn contains_key(&self, account: &Identity) -> bool;
+	fn get_role(&self, account: &Identity) -> Option<RelayerRole>;
 }
 
 impl RelayerRegistryUpdater for RelayerRegistry {
@@ -140,9 +151,9 @@ impl RelayerRegistryUpdater for RelayerRegistry {
 	}
 
 	#[cfg(feature = "std")]
-	fn update(&self, account: Identity) -> RegistryResult<()> {
+	fn update(&self, account: Identity, role: RelayerRole) -> RegistryResult<()> {
 		let mut registry = self.registry.write().unwrap();
-		registry.insert(account, ());
+		registry.insert(account, role);
 		Ok(())
 	}
 
@@ -152,16 +163,25 @@ impl RelayerRegistryUpdater for RelayerRegistry {
 	}
 
 	// if `RELAYER_REGISTRY_FILE` exists, unseal and init from it
-	// otherwise create a new instance and seal to static file
+	// otherwise migrate `RELAYER_REGISTRY_V1_FILE` if it exists, or create a new instance,
+	// and seal to static file
 	#[cfg(feature = "sgx")]
 	fn init(&self) -> RegistryResult<()> {
 		let enclave_seal = RelayerRegistrySeal::new(self.seal_path.clone());
 		if SgxFile::open(RELAYER_REGISTRY_FILE).is_err() {
-			info!(
-				"[Enclave] RelayerRegistry file not found, creating new! {}",
-				RELAYER_REGISTRY_FILE
-			);
-			let registry = self.registry.write().map_err(|_| RegistryError::PoisonLock)?;
+			let mut registry = self.registry.write().map_err(|_| RegistryError::PoisonLock)?;
+			if SgxFile::open(RELAYER_REGISTRY_V1_FILE).is_ok() {
+				info!(
+					"[Enclave] Migrating RelayerRegistry from {} to {}",
+					RELAYER_REGISTRY_V1_FILE, RELAYER_REGISTRY_FILE
+				);
+				*registry = enclave_seal.unseal_v1()?;
+			} else {
+				info!(
+					"[Enclave] RelayerRegistry file not found, creating new! {}",
+					RELAYER_REGISTRY_FILE
+				);
+			}
 			enclave_seal.seal(&*registry)
 		} else {
 			let m = enclave_seal.unseal()?;
@@ -173,9 +193,9 @@ impl RelayerRegistryUpdater for RelayerRegistry {
 	}
 
 	#[cfg(feature = "sgx")]
-	fn update(&self, account: Identity) -> RegistryResult<()> {
+	fn update(&self, account: Identity, role: RelayerRole) -> RegistryResult<()> {
 		let mut registry = self.registry.write().map_err(|_| RegistryError::PoisonLock)?;
-		registry.insert(account, ());
+		registry.insert(account, role);
 		RelayerRegistrySeal::new(self.seal_path.clone()).seal(&*registry)
 	}
 
@@ -203,4 +223,17 @@ impl RelayerRegistryLookup for RelayerRegistry {
 		let registry = self.registry.read().unwrap();
 		registry.contains_key(account)
 	}
+
+	#[cfg(feature = "std")]
+	fn get_role(&self, account: &Identity) -> Option<RelayerRole> {
+		let registry = self.registry.read().unwrap();
+		registry.get(account).copied()
+	}
+
+	#[cfg(feature = "sgx")]
+	fn get_role(&self, account: &Identity) -> Option<RelayerRole> {
+		// Using unwrap becaused poisoned locks are unrecoverable errors
+		let registry = self.registry.read().unwrap();
+		registry.get(account).copied()
+	}
 }
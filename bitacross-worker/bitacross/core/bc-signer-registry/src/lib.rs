@@ -37,15 +37,25 @@ pub type PubKey = [u8; 33];
 
 pub type SignerRegistryMap = BTreeMap<Address32, PubKey>;
 
+// ed25519 public key shares used in FROST ceremonies
+pub type SolPubKey = [u8; 32];
+
+pub type SolSignerRegistryMap = BTreeMap<Address32, SolPubKey>;
+
 #[derive(Default)]
 pub struct SignerRegistry {
 	pub registry: RwLock<SignerRegistryMap>,
+	pub sol_registry: RwLock<SolSignerRegistryMap>,
 	pub seal_path: PathBuf,
 }
 
 impl SignerRegistry {
 	pub fn new(base_dir: PathBuf) -> Self {
-		SignerRegistry { registry: Default::default(), seal_path: base_dir }
+		SignerRegistry {
+			registry: Default::default(),
+			sol_registry: Default::default(),
+			seal_path: base_dir,
+		}
 	}
 }
 
@@ -85,9 +95,11 @@ impl From<codec::Error> for RegistryError {
 
 #[cfg(feature = "sgx")]
 mod sgx {
-	use crate::{RegistryError as Error, RegistryResult as Result, SignerRegistryMap};
+	use crate::{
+		RegistryError as Error, RegistryResult as Result, SignerRegistryMap, SolSignerRegistryMap,
+	};
 	pub use codec::{Decode, Encode};
-	pub use itp_settings::files::SIGNER_REGISTRY_FILE;
+	pub use itp_settings::files::{SIGNER_REGISTRY_FILE, SOL_SIGNER_REGISTRY_FILE};
 	pub use itp_sgx_io::{seal, unseal, SealedIO};
 	pub use log::*;
 	pub use std::{boxed::Box, fs, path::PathBuf, sgxfs::SgxFile, sync::Arc};
@@ -120,6 +132,35 @@ mod sgx {
 			Ok(unsealed.using_encoded(|bytes| seal(bytes, self.path()))?)
 		}
 	}
+
+	#[derive(Clone, Debug)]
+	pub struct SolSignerRegistrySeal {
+		base_path: PathBuf,
+	}
+
+	impl SolSignerRegistrySeal {
+		pub fn new(base_path: PathBuf) -> Self {
+			Self { base_path }
+		}
+
+		pub fn path(&self) -> PathBuf {
+			self.base_path.join(SOL_SIGNER_REGISTRY_FILE)
+		}
+	}
+
+	impl SealedIO for SolSignerRegistrySeal {
+		type Error = Error;
+		type Unsealed = SolSignerRegistryMap;
+
+		fn unseal(&self) -> Result<Self::Unsealed> {
+			Ok(unseal(self.path()).map(|b| Decode::decode(&mut b.as_slice()))??)
+		}
+
+		fn seal(&self, unsealed: &Self::Unsealed) -> Result<()> {
+			info!("Seal sol signer registry to file: {:?}", unsealed);
+			Ok(unsealed.using_encoded(|bytes| seal(bytes, self.path()))?)
+		}
+	}
 }
 
 #[cfg(feature = "sgx")]
@@ -133,12 +174,14 @@ pub trait SignerRegistrySealer {
 pub trait SignerRegistryUpdater {
 	fn init(&self) -> RegistryResult<()>;
 	fn update(&self, account: Address32, key: PubKey) -> RegistryResult<()>;
+	fn update_sol(&self, account: Address32, key: SolPubKey) -> RegistryResult<()>;
 	fn remove(&self, account: Address32) -> RegistryResult<()>;
 }
 
 pub trait SignerRegistryLookup {
 	fn contains_key(&self, account: &Address32) -> bool;
 	fn get_all(&self) -> Vec<(Address32, PubKey)>;
+	fn get_all_sol(&self) -> Vec<(Address32, SolPubKey)>;
 }
 
 impl SignerRegistrySealer for SignerRegistry {
@@ -183,12 +226,19 @@ impl SignerRegistryUpdater for SignerRegistry {
 		Ok(())
 	}
 
+	#[cfg(feature = "std")]
+	fn update_sol(&self, account: Address32, key: SolPubKey) -> RegistryResult<()> {
+		let mut registry = self.sol_registry.write().unwrap();
+		registry.insert(account, key);
+		Ok(())
+	}
+
 	#[cfg(feature = "std")]
 	fn remove(&self, _account: Address32) -> RegistryResult<()> {
 		Ok(())
 	}
 
-	// if `SIGNER_REGISTRY_FILE` exists, unseal and init from it
+	// if `SIGNER_REGISTRY_FILE` (or `SOL_SIGNER_REGISTRY_FILE`) exists, unseal and init from it
 	// otherwise create a new instance and seal to static file
 	#[cfg(feature = "sgx")]
 	fn init(&self) -> RegistryResult<()> {
@@ -196,12 +246,27 @@ impl SignerRegistryUpdater for SignerRegistry {
 		if SgxFile::open(SIGNER_REGISTRY_FILE).is_err() {
 			info!("[Signer] SignerRegistry file not found, creating new! {}", SIGNER_REGISTRY_FILE);
 			let registry = self.registry.write().map_err(|_| RegistryError::PoisonLock)?;
-			enclave_seal.seal(&*registry)
+			enclave_seal.seal(&*registry)?;
 		} else {
 			let m = enclave_seal.unseal()?;
 			info!("[Signer] SignerRegistry unsealed from file: {:?}", m);
 			let mut registry = self.registry.write().map_err(|_| RegistryError::PoisonLock)?;
 			*registry = m;
+		}
+
+		let sol_seal = SolSignerRegistrySeal::new(self.seal_path.clone());
+		if SgxFile::open(SOL_SIGNER_REGISTRY_FILE).is_err() {
+			info!(
+				"[Signer] Sol SignerRegistry file not found, creating new! {}",
+				SOL_SIGNER_REGISTRY_FILE
+			);
+			let registry = self.sol_registry.write().map_err(|_| RegistryError::PoisonLock)?;
+			sol_seal.seal(&*registry)
+		} else {
+			let m = sol_seal.unseal()?;
+			info!("[Signer] Sol SignerRegistry unsealed from file: {:?}", m);
+			let mut registry = self.sol_registry.write().map_err(|_| RegistryError::PoisonLock)?;
+			*registry = m;
 			Ok(())
 		}
 	}
@@ -213,8 +278,21 @@ impl SignerRegistryUpdater for SignerRegistry {
 		SignerRegistrySeal::new(self.seal_path.clone()).seal(&*registry)
 	}
 
+	#[cfg(feature = "sgx")]
+	fn update_sol(&self, account: Address32, key: SolPubKey) -> RegistryResult<()> {
+		let mut registry = self.sol_registry.write().map_err(|_| RegistryError::PoisonLock)?;
+		registry.insert(account, key);
+		SolSignerRegistrySeal::new(self.seal_path.clone()).seal(&*registry)
+	}
+
 	#[cfg(feature = "sgx")]
 	fn remove(&self, account: Address32) -> RegistryResult<()> {
+		{
+			let mut registry = self.sol_registry.write().map_err(|_| RegistryError::PoisonLock)?;
+			if registry.remove(&account).is_some() {
+				SolSignerRegistrySeal::new(self.seal_path.clone()).seal(&*registry)?;
+			}
+		}
 		let mut registry = self.registry.write().map_err(|_| RegistryError::PoisonLock)?;
 		let old_value = registry.remove(&account);
 		if old_value.is_some() {
@@ -237,6 +315,12 @@ impl SignerRegistryLookup for SignerRegistry {
 		registry.iter().map(|(k, v)| (*k, *v)).collect()
 	}
 
+	#[cfg(feature = "std")]
+	fn get_all_sol(&self) -> Vec<(Address32, SolPubKey)> {
+		let registry = self.sol_registry.read().unwrap();
+		registry.iter().map(|(k, v)| (*k, *v)).collect()
+	}
+
 	#[cfg(feature = "sgx")]
 	fn contains_key(&self, account: &Address32) -> bool {
 		// Using unwrap because poisoned locks are unrecoverable errors
@@ -250,4 +334,10 @@ impl SignerRegistryLookup for SignerRegistry {
 		let registry = self.registry.read().unwrap();
 		registry.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
 	}
+	#[cfg(feature = "sgx")]
+	fn get_all_sol(&self) -> Vec<(Address32, SolPubKey)> {
+		// Using unwrap because poisoned locks are unrecoverable errors
+		let registry = self.sol_registry.read().unwrap();
+		registry.iter().map(|(k, v)| (k.clone(), *v)).collect()
+	}
 }
@@ -46,12 +46,18 @@ use std::sync::Mutex;
 #[cfg(feature = "sgx")]
 use std::sync::SgxMutex as Mutex;
 
+use bc_audit_log::{AuditEvent, AuditLogUpdater, AuditedCall};
 use bc_enclave_registry::EnclaveRegistryLookup;
+use bc_frost_ceremony::{
+	FrostCeremony, FrostCeremonyCommand, FrostCeremonyCommandTmp, FrostCeremonyEvent,
+	FrostCeremonyRegistry, SolanaCeremonyId,
+};
 use bc_musig2_ceremony::{
-	get_current_timestamp, CeremonyCommand, CeremonyCommandTmp, CeremonyError, CeremonyErrorReason,
-	CeremonyEvent, CeremonyId, CeremonyRegistry, MuSig2Ceremony, SignBitcoinPayload,
+	get_current_timestamp, remove_expired, store::CeremonyStore, CeremonyCommand,
+	CeremonyCommandTmp, CeremonyError, CeremonyErrorReason, CeremonyEvent, CeremonyId,
+	CeremonyRegistry, MuSig2Ceremony, SignBitcoinPayload,
 };
-use bc_musig2_event::{process_event, DirectRequestStatus, Hash};
+use bc_musig2_event::{process_event, process_frost_event, DirectRequestStatus, Hash};
 use bc_relayer_registry::RelayerRegistryLookup;
 use bc_signer_registry::SignerRegistryLookup;
 use bc_task_sender::{
@@ -67,6 +73,7 @@ use itp_enclave_metrics::EnclaveMetric;
 use itp_ocall_api::{EnclaveAttestationOCallApi, EnclaveMetricsOCallApi, EnclaveOnChainOCallApi};
 use itp_sgx_crypto::{
 	ecdsa::Pair as EcdsaPair,
+	frost::Pair as FrostPair,
 	key_repository::{AccessKey, AccessPubkey},
 	schnorr::Pair as SchnorrPair,
 	ShieldingCryptoDecrypt, ShieldingCryptoEncrypt,
@@ -76,18 +83,21 @@ use itp_stf_executor::traits::StfEnclaveSigning;
 use itp_stf_state_handler::handle_state::HandleState;
 use lc_direct_call::{
 	handler::{
-		kill_ceremony, nonce_share, partial_signature_share,
+		commitments_share, kill_ceremony, nonce_share, partial_signature_share,
 		sign_bitcoin::{self, SignBitcoinError},
-		sign_ethereum,
+		sign_ethereum::{self, SignEthereumError},
+		sign_solana::{self, SignSolanaError},
+		signature_share,
 	},
 	CeremonyRoundCall, CeremonyRoundCallSigned, DirectCall, DirectCallSigned,
 };
-use litentry_primitives::{Address32, PlainRequest};
+use litentry_primitives::{Address32, Identity, PlainRequest, RelayerRole};
 use log::*;
 use sgx_crypto_helper::rsa3072::Rsa3072PubKey;
 use sp_core::{ed25519, Pair, H256};
 use std::{
 	collections::HashMap,
+	format,
 	string::{String, ToString},
 	sync::Arc,
 	vec,
@@ -104,23 +114,33 @@ pub enum Error {
 	OtherError(String),
 }
 
+// ceremony command together with the id of the ceremony it targets
+#[derive(Debug, Clone)]
+pub enum SigningCommand {
+	MuSig2(CeremonyId, CeremonyCommand),
+	Frost(SolanaCeremonyId, FrostCeremonyCommand),
+}
+
 pub struct BitAcrossTaskContext<
 	SKR,
 	SIGNINGAK,
 	EKR,
 	BKR,
+	SOLAK,
 	S: StfEnclaveSigning<TrustedCallSigned>,
 	H: HandleState,
 	O: EnclaveOnChainOCallApi,
 	RRL: RelayerRegistryLookup,
 	ERL: EnclaveRegistryLookup,
 	SRL: SignerRegistryLookup,
+	AL: AuditLogUpdater,
 	Responder,
 > where
 	SKR: AccessKey + AccessPubkey<KeyType = Rsa3072PubKey>,
 	SIGNINGAK: AccessKey<KeyType = ed25519::Pair>,
 	EKR: AccessKey<KeyType = EcdsaPair>,
 	BKR: AccessKey<KeyType = SchnorrPair>,
+	SOLAK: AccessKey<KeyType = FrostPair>,
 	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + 'static,
 	Responder: SendRpcResponse<Hash = H256>,
 {
@@ -128,16 +148,21 @@ pub struct BitAcrossTaskContext<
 	pub signing_key_access: Arc<SIGNINGAK>,
 	pub ethereum_key_repository: Arc<EKR>,
 	pub bitcoin_key_repository: Arc<BKR>,
+	pub solana_key_repository: Arc<SOLAK>,
 	pub enclave_signer: Arc<S>,
 	pub state_handler: Arc<H>,
 	pub ocall_api: Arc<O>,
 	pub relayer_registry_lookup: Arc<RRL>,
 	pub enclave_registry_lookup: Arc<ERL>,
 	pub signer_registry_lookup: Arc<SRL>,
+	pub audit_log: Arc<AL>,
 	pub signing_key_pub: [u8; 32],
 	pub responder: Arc<Responder>,
 	pub ceremony_registry: Arc<RwLock<CeremonyRegistry<BKR>>>,
 	pub ceremony_command_tmp: Arc<RwLock<CeremonyCommandTmp>>,
+	pub ceremony_store: Arc<CeremonyStore>,
+	pub frost_ceremony_registry: Arc<RwLock<FrostCeremonyRegistry<SOLAK>>>,
+	pub frost_ceremony_command_tmp: Arc<RwLock<FrostCeremonyCommandTmp>>,
 }
 
 impl<
@@ -145,19 +170,22 @@ impl<
 		SIGNINGAK,
 		EKR,
 		BKR,
+		SOLAK,
 		S: StfEnclaveSigning<TrustedCallSigned>,
 		H: HandleState,
 		O: EnclaveOnChainOCallApi,
 		RRL: RelayerRegistryLookup,
 		ERL: EnclaveRegistryLookup,
 		SRL: SignerRegistryLookup,
+		AL: AuditLogUpdater,
 		Responder,
-	> BitAcrossTaskContext<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>
+	> BitAcrossTaskContext<SKR, SIGNINGAK, EKR, BKR, SOLAK, S, H, O, RRL, ERL, SRL, AL, Responder>
 where
 	SKR: AccessKey + AccessPubkey<KeyType = Rsa3072PubKey>,
 	SIGNINGAK: AccessKey<KeyType = ed25519::Pair>,
 	EKR: AccessKey<KeyType = EcdsaPair>,
 	BKR: AccessKey<KeyType = SchnorrPair>,
+	SOLAK: AccessKey<KeyType = FrostPair>,
 	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + 'static,
 	H::StateT: SgxExternalitiesTrait,
 	Responder: SendRpcResponse<Hash = H256>,
@@ -168,15 +196,20 @@ where
 		signing_key_access: Arc<SIGNINGAK>,
 		ethereum_key_repository: Arc<EKR>,
 		bitcoin_key_repository: Arc<BKR>,
+		solana_key_repository: Arc<SOLAK>,
 		enclave_signer: Arc<S>,
 		state_handler: Arc<H>,
 		ocall_api: Arc<O>,
 		relayer_registry_lookup: Arc<RRL>,
 		enclave_registry_lookup: Arc<ERL>,
 		signer_registry_lookup: Arc<SRL>,
+		audit_log: Arc<AL>,
 		signing_key_pub: [u8; 32],
 		ceremony_registry: Arc<RwLock<CeremonyRegistry<BKR>>>,
 		ceremony_command_tmp: Arc<RwLock<CeremonyCommandTmp>>,
+		ceremony_store: Arc<CeremonyStore>,
+		frost_ceremony_registry: Arc<RwLock<FrostCeremonyRegistry<SOLAK>>>,
+		frost_ceremony_command_tmp: Arc<RwLock<FrostCeremonyCommandTmp>>,
 		responder: Arc<Responder>,
 	) -> Self {
 		Self {
@@ -184,23 +217,58 @@ where
 			signing_key_access,
 			ethereum_key_repository,
 			bitcoin_key_repository,
+			solana_key_repository,
 			enclave_signer,
 			state_handler,
 			ocall_api,
 			relayer_registry_lookup,
 			enclave_registry_lookup,
 			signer_registry_lookup,
+			audit_log,
 			signing_key_pub,
 			ceremony_registry,
 			ceremony_command_tmp,
+			ceremony_store,
+			frost_ceremony_registry,
+			frost_ceremony_command_tmp,
 			responder,
 		}
 	}
 }
 
 #[allow(clippy::type_complexity)]
-pub fn run_bit_across_handler_runner<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>(
-	context: Arc<BitAcrossTaskContext<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>>,
+pub fn run_bit_across_handler_runner<
+	SKR,
+	SIGNINGAK,
+	EKR,
+	BKR,
+	SOLAK,
+	S,
+	H,
+	O,
+	RRL,
+	ERL,
+	SRL,
+	AL,
+	Responder,
+>(
+	context: Arc<
+		BitAcrossTaskContext<
+			SKR,
+			SIGNINGAK,
+			EKR,
+			BKR,
+			SOLAK,
+			S,
+			H,
+			O,
+			RRL,
+			ERL,
+			SRL,
+			AL,
+			Responder,
+		>,
+	>,
 	ceremony_commands_thread_count: u8,
 	ceremony_events_thread_count: u8,
 ) where
@@ -208,6 +276,7 @@ pub fn run_bit_across_handler_runner<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL
 	SIGNINGAK: AccessKey<KeyType = ed25519::Pair> + Send + Sync + 'static,
 	EKR: AccessKey<KeyType = EcdsaPair> + Send + Sync + 'static,
 	BKR: AccessKey<KeyType = SchnorrPair> + Send + Sync + 'static,
+	SOLAK: AccessKey<KeyType = FrostPair> + Send + Sync + 'static,
 	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
 	S: StfEnclaveSigning<TrustedCallSigned> + Send + Sync + 'static,
 	H: HandleState + Send + Sync + 'static,
@@ -216,46 +285,78 @@ pub fn run_bit_across_handler_runner<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL
 	RRL: RelayerRegistryLookup + Send + Sync + 'static,
 	ERL: EnclaveRegistryLookup + Send + Sync + 'static,
 	SRL: SignerRegistryLookup + Send + Sync + 'static,
+	AL: AuditLogUpdater + Send + Sync + 'static,
 	Responder: SendRpcResponse<Hash = H256> + Send + Sync + 'static,
 {
 	// timeout tick
 	let ceremony_registry = context.ceremony_registry.clone();
 	let ceremony_command_tmp = context.ceremony_command_tmp.clone();
+	let frost_ceremony_registry = context.frost_ceremony_registry.clone();
+	let frost_ceremony_command_tmp = context.frost_ceremony_command_tmp.clone();
+	let ceremony_store = context.ceremony_store.clone();
 	let responder = context.responder.clone();
+	let audit_log = context.audit_log.clone();
 	let time_to_live = 30u64;
 	let cloned_ocall_api = context.ocall_api.clone();
 	std::thread::spawn(move || loop {
 		std::thread::sleep(Duration::from_secs(3));
 		let now = get_current_timestamp();
-		let mut timed_out_count: u8 = 0;
-		{
-			let mut ceremony_registry_write = ceremony_registry.write().unwrap();
-			ceremony_registry_write.retain(|_, (ceremony, create_time)| {
-				let if_retain = now - *create_time < time_to_live;
-				if !if_retain {
-					let ceremony_rwlock = ceremony.clone();
-					let ceremony = ceremony_rwlock.read().unwrap();
-					let hash = blake2_256(&ceremony.get_id_ref().encode());
-					let encrypted_result = SignBitcoinError::CeremonyError.encode();
-					if let Err(e) = responder.send_state_with_status(
-						Hash::from_slice(&hash),
-						encrypted_result,
-						DirectRequestStatus::Error,
-					) {
-						error!("Could not send response to {:?}, reason: {:?}", &hash, e);
-					}
-					timed_out_count += 1;
+		let timed_out_count = remove_expired(
+			&mut ceremony_registry.write().unwrap(),
+			now,
+			time_to_live,
+			|_, ceremony| {
+				let hash = blake2_256(&ceremony.get_id_ref().encode());
+				let encrypted_result = SignBitcoinError::CeremonyError.encode();
+				if !ceremony.is_check_run() {
+					record_audit_event(
+						audit_log.as_ref(),
+						AuditEvent::Failed(hash.into(), encrypted_result.clone()),
+					);
 				}
-				if_retain
-			});
-		}
-		{
-			let mut command_tmp_write = ceremony_command_tmp.write().unwrap();
-			command_tmp_write.retain(|_, &mut (_, create_time)| now - create_time < time_to_live);
-		}
+				if let Err(e) = responder.send_state_with_status(
+					Hash::from_slice(&hash),
+					encrypted_result,
+					DirectRequestStatus::Error,
+				) {
+					error!("Could not send response to {:?}, reason: {:?}", &hash, e);
+				}
+			},
+		);
+		remove_expired(&mut ceremony_command_tmp.write().unwrap(), now, time_to_live, |_, _| {});
+		remove_expired(
+			&mut frost_ceremony_registry.write().unwrap(),
+			now,
+			time_to_live,
+			|ceremony_id, _| {
+				let hash = blake2_256(&ceremony_id.encode());
+				let encrypted_result = SignSolanaError::CeremonyError.encode();
+				record_audit_event(
+					audit_log.as_ref(),
+					AuditEvent::Failed(hash.into(), encrypted_result.clone()),
+				);
+				if let Err(e) = responder.send_state_with_status(
+					Hash::from_slice(&hash),
+					encrypted_result,
+					DirectRequestStatus::Error,
+				) {
+					error!("Could not send response to {:?}, reason: {:?}", &hash, e);
+				}
+			},
+		);
+		remove_expired(
+			&mut frost_ceremony_command_tmp.write().unwrap(),
+			now,
+			time_to_live,
+			|_, _| {},
+		);
 		if timed_out_count > 0 {
+			persist_ceremonies(ceremony_store.as_ref(), ceremony_registry.as_ref());
 			let _ = cloned_ocall_api
 				.update_metric(EnclaveMetric::Musig2CeremonyTimedout(timed_out_count));
+			let active_ceremonies = ceremony_registry.read().unwrap().len() as u64;
+			let _ = cloned_ocall_api
+				.update_metric(EnclaveMetric::Musig2ActiveCeremonies(active_ceremonies));
 		}
 	});
 
@@ -264,20 +365,28 @@ pub fn run_bit_across_handler_runner<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL
 	let command_threads_pool = ThreadPool::new(ceremony_commands_thread_count.into());
 	let event_threads_pool = ThreadPool::new(ceremony_events_thread_count.into());
 
+	recover_ceremonies(context.clone(), event_threads_pool.clone(), peers_map.clone());
+
 	while let Ok(req) = bit_across_task_receiver.recv() {
 		let context = context.clone();
 		let event_threads_pool = event_threads_pool.clone();
 		let peers_map = peers_map.clone();
-		command_threads_pool.execute(move || {
-			if let Some((ceremony_id, command)) = handle_request(req, context.clone()) {
-				handle_ceremony_command(
-					context,
-					ceremony_id,
-					command,
-					event_threads_pool,
-					peers_map,
-				);
-			}
+		command_threads_pool.execute(move || match handle_request(req, context.clone()) {
+			Some(SigningCommand::MuSig2(ceremony_id, command)) => handle_ceremony_command(
+				context,
+				ceremony_id,
+				command,
+				event_threads_pool,
+				peers_map,
+			),
+			Some(SigningCommand::Frost(ceremony_id, command)) => handle_frost_ceremony_command(
+				context,
+				ceremony_id,
+				command,
+				event_threads_pool,
+				peers_map,
+			),
+			None => {},
 		});
 	}
 
@@ -287,8 +396,24 @@ pub fn run_bit_across_handler_runner<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL
 }
 
 #[allow(clippy::type_complexity)]
-fn handle_ceremony_command<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>(
-	context: Arc<BitAcrossTaskContext<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>>,
+fn handle_ceremony_command<SKR, SIGNINGAK, EKR, BKR, SOLAK, S, H, O, RRL, ERL, SRL, AL, Responder>(
+	context: Arc<
+		BitAcrossTaskContext<
+			SKR,
+			SIGNINGAK,
+			EKR,
+			BKR,
+			SOLAK,
+			S,
+			H,
+			O,
+			RRL,
+			ERL,
+			SRL,
+			AL,
+			Responder,
+		>,
+	>,
 	ceremony_id: CeremonyId,
 	command: CeremonyCommand,
 	event_threads_pool: ThreadPool,
@@ -298,6 +423,7 @@ fn handle_ceremony_command<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Res
 	SIGNINGAK: AccessKey<KeyType = ed25519::Pair> + Send + Sync + 'static,
 	EKR: AccessKey<KeyType = EcdsaPair> + Send + Sync + 'static,
 	BKR: AccessKey<KeyType = SchnorrPair> + Send + Sync + 'static,
+	SOLAK: AccessKey<KeyType = FrostPair> + Send + Sync + 'static,
 	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
 	S: StfEnclaveSigning<TrustedCallSigned> + Send + Sync + 'static,
 	H: HandleState + Send + Sync + 'static,
@@ -306,6 +432,7 @@ fn handle_ceremony_command<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Res
 	RRL: RelayerRegistryLookup + Send + Sync + 'static,
 	ERL: EnclaveRegistryLookup + Send + Sync + 'static,
 	SRL: SignerRegistryLookup + Send + Sync + 'static,
+	AL: AuditLogUpdater + Send + Sync + 'static,
 	Responder: SendRpcResponse<Hash = H256> + Send + Sync + 'static,
 {
 	// check whether to store command to tmp
@@ -321,7 +448,8 @@ fn handle_ceremony_command<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Res
 		(None, CeremonyCommand::InitCeremony(_, _, _))
 		| (Some(true), CeremonyCommand::SaveNonce(_, _))
 		| (Some(false), CeremonyCommand::SavePartialSignature(_, _))
-		| (_, CeremonyCommand::KillCeremony) => {},
+		| (_, CeremonyCommand::KillCeremony)
+		| (_, CeremonyCommand::AbortCeremony(_)) => {},
 		(None, CeremonyCommand::SaveNonce(_, _))
 		| (Some(true), CeremonyCommand::SavePartialSignature(_, _)) => {
 			context
@@ -343,25 +471,11 @@ fn handle_ceremony_command<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Res
 	}
 
 	// try to udpate peers_map
-	let my_identity: Address32 =
-		context.signing_key_access.retrieve_key().unwrap().public().0.into();
-	context
-		.enclave_registry_lookup
-		.get_all()
-		.iter()
-		.for_each(|(identity, address)| {
-			if my_identity != *identity
-				&& !peers_map.lock().unwrap().contains_key(identity.as_ref())
-			{
-				info!("creating new connection to peer: {:?}", address);
-				match (DirectRpcClientFactory {}).create(address) {
-					Ok(client) => {
-						peers_map.lock().unwrap().insert(*identity.as_ref(), client);
-					},
-					Err(e) => error!("Could not connect to peer {}, reason: {:?}", address, e),
-				}
-			}
-		});
+	update_peers_map(
+		context.signing_key_access.as_ref(),
+		context.enclave_registry_lookup.as_ref(),
+		peers_map.as_ref(),
+	);
 
 	// process commands and events
 	let mut commands_to_process = vec![command];
@@ -372,20 +486,61 @@ fn handle_ceremony_command<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Res
 
 		if let Some(event) = event {
 			// update metrics
+			let ceremony = context
+				.ceremony_registry
+				.read()
+				.unwrap()
+				.get(&ceremony_id)
+				.map(|(c, _)| c.clone());
 			match event {
 				CeremonyEvent::FirstRoundStarted(_, _, _) => {
 					let _ = context.ocall_api.update_metric(EnclaveMetric::Musig2CeremonyStarted);
 				},
-				CeremonyEvent::CeremonyError(_, _) => {
-					let _ = context.ocall_api.update_metric(EnclaveMetric::Musig2CeremonyFailed);
+				CeremonyEvent::SecondRoundStarted(_, _, _) => {
+					if let Some(duration) =
+						ceremony.and_then(|c| c.read().unwrap().first_round_duration())
+					{
+						let _ = context
+							.ocall_api
+							.update_metric(EnclaveMetric::Musig2CeremonyRoundDuration(1, duration));
+					}
+				},
+				CeremonyEvent::CeremonyError(_, ref error) => {
+					let _ = context.ocall_api.update_metric(EnclaveMetric::Musig2CeremonyFailed(
+						format!("{:?}", error.reason()),
+					));
 				},
 				CeremonyEvent::CeremonyEnded(_, _, _) => {
-					let ceremony_start_time =
-						context.ceremony_registry.read().unwrap().get(&ceremony_id).unwrap().1;
-					let _ = context.ocall_api.update_metric(EnclaveMetric::Musig2CeremonyDuration(
-						Duration::from_millis(get_current_timestamp() - ceremony_start_time),
-					));
+					let _ = context.ocall_api.update_metric(EnclaveMetric::Musig2CeremonyCompleted);
+					if let Some(ceremony) = ceremony {
+						let ceremony = ceremony.read().unwrap();
+						let _ = context.ocall_api.update_metric(
+							EnclaveMetric::Musig2CeremonyRoundDuration(2, ceremony.round_elapsed()),
+						);
+						let _ = context.ocall_api.update_metric(
+							EnclaveMetric::Musig2CeremonyDuration(ceremony.elapsed()),
+						);
+					}
 				},
+				CeremonyEvent::CeremonyAborted(_, _) => {},
+			}
+
+			// record the outcome of signing requests, check runs are not audited
+			match &event {
+				CeremonyEvent::CeremonyEnded(signature, false, _) => record_audit_event(
+					context.audit_log.as_ref(),
+					AuditEvent::Signed(
+						AuditedCall::SignBitcoin(ceremony_id.clone()).hash(),
+						signature.to_vec(),
+					),
+				),
+				CeremonyEvent::CeremonyError(_, error) => record_audit_event(
+					context.audit_log.as_ref(),
+					AuditEvent::Failed(
+						AuditedCall::SignBitcoin(ceremony_id.clone()).hash(),
+						error.encode(),
+					),
+				),
 				_ => {},
 			}
 
@@ -401,7 +556,9 @@ fn handle_ceremony_command<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Res
 						commands_to_process = ceremony_command_tmp.read().unwrap().clone();
 					}
 				},
-				CeremonyEvent::CeremonyEnded(_, _, _) | CeremonyEvent::CeremonyError(_, _) => {
+				CeremonyEvent::CeremonyEnded(_, _, _)
+				| CeremonyEvent::CeremonyError(_, _)
+				| CeremonyEvent::CeremonyAborted(_, _) => {
 					// remove ceremony
 					{
 						let mut registry_write = context.ceremony_registry.write().unwrap();
@@ -426,11 +583,178 @@ fn handle_ceremony_command<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Res
 			);
 		}
 	}
+
+	persist_ceremonies(context.ceremony_store.as_ref(), context.ceremony_registry.as_ref());
+	let active_ceremonies = context.ceremony_registry.read().unwrap().len() as u64;
+	let _ = context
+		.ocall_api
+		.update_metric(EnclaveMetric::Musig2ActiveCeremonies(active_ceremonies));
+}
+
+// seals the public state of all in-flight ceremonies, so they can be resumed after a restart
+fn persist_ceremonies<BKR>(store: &CeremonyStore, ceremony_registry: &RwLock<CeremonyRegistry<BKR>>)
+where
+	BKR: AccessKey<KeyType = SchnorrPair>,
+{
+	let result = store.save_with(|| {
+		ceremony_registry
+			.read()
+			.unwrap()
+			.values()
+			.map(|(ceremony, _)| ceremony.read().unwrap().snapshot())
+			.collect()
+	});
+	if let Err(e) = result {
+		error!("Could not seal ceremonies, reason: {:?}", e);
+	}
+}
+
+// resumes ceremonies sealed before the enclave restarted, the ones that can't be resumed
+// are aborted on the peers
+#[allow(clippy::type_complexity)]
+fn recover_ceremonies<SKR, SIGNINGAK, EKR, BKR, SOLAK, S, H, O, RRL, ERL, SRL, AL, Responder>(
+	context: Arc<
+		BitAcrossTaskContext<
+			SKR,
+			SIGNINGAK,
+			EKR,
+			BKR,
+			SOLAK,
+			S,
+			H,
+			O,
+			RRL,
+			ERL,
+			SRL,
+			AL,
+			Responder,
+		>,
+	>,
+	event_threads_pool: ThreadPool,
+	peers_map: Arc<Mutex<HashMap<[u8; 32], DirectRpcClient>>>,
+) where
+	SKR: AccessKey + AccessPubkey<KeyType = Rsa3072PubKey> + Send + Sync + 'static,
+	SIGNINGAK: AccessKey<KeyType = ed25519::Pair> + Send + Sync + 'static,
+	EKR: AccessKey<KeyType = EcdsaPair> + Send + Sync + 'static,
+	BKR: AccessKey<KeyType = SchnorrPair> + Send + Sync + 'static,
+	SOLAK: AccessKey<KeyType = FrostPair> + Send + Sync + 'static,
+	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
+	S: StfEnclaveSigning<TrustedCallSigned> + Send + Sync + 'static,
+	H: HandleState + Send + Sync + 'static,
+	H::StateT: SgxExternalitiesTrait,
+	O: EnclaveOnChainOCallApi + EnclaveMetricsOCallApi + EnclaveAttestationOCallApi + 'static,
+	RRL: RelayerRegistryLookup + Send + Sync + 'static,
+	ERL: EnclaveRegistryLookup + Send + Sync + 'static,
+	SRL: SignerRegistryLookup + Send + Sync + 'static,
+	AL: AuditLogUpdater + Send + Sync + 'static,
+	Responder: SendRpcResponse<Hash = H256> + Send + Sync + 'static,
+{
+	let snapshots = match context.ceremony_store.load() {
+		Ok(snapshots) => snapshots,
+		Err(e) => {
+			error!("Could not unseal ceremonies, reason: {:?}", e);
+			return
+		},
+	};
+	if snapshots.is_empty() {
+		return
+	}
+	info!("Recovering {} ceremonies", snapshots.len());
+
+	update_peers_map(
+		context.signing_key_access.as_ref(),
+		context.enclave_registry_lookup.as_ref(),
+		peers_map.as_ref(),
+	);
+
+	for snapshot in snapshots {
+		let ceremony_id = snapshot.payload.clone();
+		let check_run = snapshot.check_run;
+		let signers = snapshot
+			.signers
+			.iter()
+			.map(|(signer, _)| *signer)
+			.filter(|signer| *signer != context.signing_key_pub)
+			.collect();
+
+		let event = match MuSig2Ceremony::resume(
+			context.signing_key_pub,
+			snapshot,
+			context.bitcoin_key_repository.clone(),
+		) {
+			Ok((ceremony, event)) => {
+				// the timeout starts over, peers need time to reconnect
+				context.ceremony_registry.write().unwrap().insert(
+					ceremony_id.clone(),
+					(Arc::new(RwLock::new(ceremony)), get_current_timestamp()),
+				);
+				event
+			},
+			Err(reason) => {
+				warn!("Could not resume ceremony {:?}, aborting it", ceremony_id);
+				let _ = context
+					.ocall_api
+					.update_metric(EnclaveMetric::Musig2CeremonyFailed(format!("{:?}", reason)));
+				CeremonyEvent::CeremonyAborted(signers, reason)
+			},
+		};
+
+		match &event {
+			CeremonyEvent::CeremonyEnded(signature, false, _) => record_audit_event(
+				context.audit_log.as_ref(),
+				AuditEvent::Signed(
+					AuditedCall::SignBitcoin(ceremony_id.clone()).hash(),
+					signature.to_vec(),
+				),
+			),
+			CeremonyEvent::CeremonyAborted(_, reason) if !check_run => record_audit_event(
+				context.audit_log.as_ref(),
+				AuditEvent::Failed(
+					AuditedCall::SignBitcoin(ceremony_id.clone()).hash(),
+					CeremonyError::CeremonyAborted(reason.clone()).encode(),
+				),
+			),
+			_ => {},
+		}
+		if let CeremonyEvent::CeremonyEnded(_, _, _) = event {
+			context.ceremony_registry.write().unwrap().remove(&ceremony_id);
+		}
+
+		process_event(
+			context.signing_key_access.clone(),
+			context.ocall_api.clone(),
+			context.responder.clone(),
+			context.enclave_registry_lookup.clone(),
+			event,
+			ceremony_id,
+			event_threads_pool.clone(),
+			peers_map.clone(),
+			context.ceremony_registry.clone(),
+		);
+	}
+
+	persist_ceremonies(context.ceremony_store.as_ref(), context.ceremony_registry.as_ref());
 }
 
 #[allow(clippy::type_complexity)]
-fn process_command<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>(
-	context: Arc<BitAcrossTaskContext<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>>,
+fn process_command<SKR, SIGNINGAK, EKR, BKR, SOLAK, S, H, O, RRL, ERL, SRL, AL, Responder>(
+	context: Arc<
+		BitAcrossTaskContext<
+			SKR,
+			SIGNINGAK,
+			EKR,
+			BKR,
+			SOLAK,
+			S,
+			H,
+			O,
+			RRL,
+			ERL,
+			SRL,
+			AL,
+			Responder,
+		>,
+	>,
 	ceremony_id: CeremonyId,
 	command: CeremonyCommand,
 ) -> Option<CeremonyEvent>
@@ -439,6 +763,7 @@ where
 	SIGNINGAK: AccessKey<KeyType = ed25519::Pair> + Send + Sync + 'static,
 	EKR: AccessKey<KeyType = EcdsaPair> + Send + Sync + 'static,
 	BKR: AccessKey<KeyType = SchnorrPair> + Send + Sync + 'static,
+	SOLAK: AccessKey<KeyType = FrostPair> + Send + Sync + 'static,
 	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
 	S: StfEnclaveSigning<TrustedCallSigned> + Send + Sync + 'static,
 	H: HandleState + Send + Sync + 'static,
@@ -447,6 +772,7 @@ where
 	RRL: RelayerRegistryLookup + Send + Sync + 'static,
 	ERL: EnclaveRegistryLookup + Send + Sync + 'static,
 	SRL: SignerRegistryLookup + Send + Sync + 'static,
+	AL: AuditLogUpdater + Send + Sync + 'static,
 	Responder: SendRpcResponse<Hash = H256> + Send + Sync + 'static,
 {
 	match command {
@@ -489,7 +815,13 @@ where
 				context.ceremony_registry.read().unwrap().get(&ceremony_id).cloned();
 			if let Some(ceremony_rwlock) = ceremony_rwlock {
 				let mut ceremony_write_lock = ceremony_rwlock.0.write().unwrap();
+				let delay = ceremony_write_lock.round_elapsed();
 				let event_ret = ceremony_write_lock.receive_nonce(signer, nonce);
+				if event_ret.is_ok() {
+					let _ = context
+						.ocall_api
+						.update_metric(EnclaveMetric::Musig2PeerNonceDelay(signer, delay));
+				}
 				match event_ret {
 					Ok(event) => event,
 					Err(e) => Some(CeremonyEvent::CeremonyError(
@@ -506,7 +838,13 @@ where
 				context.ceremony_registry.read().unwrap().get(&ceremony_id).cloned();
 			if let Some(ceremony_rwlock) = ceremony_rwlock {
 				let mut ceremony_write_lock = ceremony_rwlock.0.write().unwrap();
+				let delay = ceremony_write_lock.round_elapsed();
 				let event_ret = ceremony_write_lock.receive_partial_sign(signer, partial_signature);
+				if event_ret.is_ok() {
+					let _ = context.ocall_api.update_metric(
+						EnclaveMetric::Musig2PeerPartialSignatureDelay(signer, delay),
+					);
+				}
 				match event_ret {
 					Ok(event) => event,
 					Err(e) => Some(CeremonyEvent::CeremonyError(
@@ -527,19 +865,344 @@ where
 			}
 			None
 		},
+		CeremonyCommand::AbortCeremony(reason) => {
+			let removed = { context.ceremony_registry.write().unwrap().remove(&ceremony_id) };
+			{
+				context.ceremony_command_tmp.write().unwrap().remove(&ceremony_id);
+			}
+			// the peer is already gone, only the requester needs to be told
+			removed.map(|_| {
+				CeremonyEvent::CeremonyError(vec![], CeremonyError::CeremonyAborted(reason))
+			})
+		},
+	}
+}
+
+#[allow(clippy::type_complexity)]
+fn handle_frost_ceremony_command<
+	SKR,
+	SIGNINGAK,
+	EKR,
+	BKR,
+	SOLAK,
+	S,
+	H,
+	O,
+	RRL,
+	ERL,
+	SRL,
+	AL,
+	Responder,
+>(
+	context: Arc<
+		BitAcrossTaskContext<
+			SKR,
+			SIGNINGAK,
+			EKR,
+			BKR,
+			SOLAK,
+			S,
+			H,
+			O,
+			RRL,
+			ERL,
+			SRL,
+			AL,
+			Responder,
+		>,
+	>,
+	ceremony_id: SolanaCeremonyId,
+	command: FrostCeremonyCommand,
+	event_threads_pool: ThreadPool,
+	peers_map: Arc<Mutex<HashMap<[u8; 32], DirectRpcClient>>>,
+) where
+	SKR: AccessKey + AccessPubkey<KeyType = Rsa3072PubKey> + Send + Sync + 'static,
+	SIGNINGAK: AccessKey<KeyType = ed25519::Pair> + Send + Sync + 'static,
+	EKR: AccessKey<KeyType = EcdsaPair> + Send + Sync + 'static,
+	BKR: AccessKey<KeyType = SchnorrPair> + Send + Sync + 'static,
+	SOLAK: AccessKey<KeyType = FrostPair> + Send + Sync + 'static,
+	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
+	S: StfEnclaveSigning<TrustedCallSigned> + Send + Sync + 'static,
+	H: HandleState + Send + Sync + 'static,
+	H::StateT: SgxExternalitiesTrait,
+	O: EnclaveOnChainOCallApi + EnclaveMetricsOCallApi + EnclaveAttestationOCallApi + 'static,
+	RRL: RelayerRegistryLookup + Send + Sync + 'static,
+	ERL: EnclaveRegistryLookup + Send + Sync + 'static,
+	SRL: SignerRegistryLookup + Send + Sync + 'static,
+	AL: AuditLogUpdater + Send + Sync + 'static,
+	Responder: SendRpcResponse<Hash = H256> + Send + Sync + 'static,
+{
+	// check whether to store command to tmp
+	let is_first_round = {
+		context
+			.frost_ceremony_registry
+			.read()
+			.unwrap()
+			.get(&ceremony_id)
+			.map(|(c, _)| c.read().unwrap().is_first_round())
+	};
+	match (is_first_round, &command) {
+		(None, FrostCeremonyCommand::InitCeremony(_, _))
+		| (Some(true), FrostCeremonyCommand::SaveCommitments(_, _))
+		| (Some(false), FrostCeremonyCommand::SaveSignatureShare(_, _))
+		| (_, FrostCeremonyCommand::KillCeremony) => {},
+		(None, FrostCeremonyCommand::SaveCommitments(_, _))
+		| (Some(true), FrostCeremonyCommand::SaveSignatureShare(_, _)) => {
+			context
+				.frost_ceremony_command_tmp
+				.write()
+				.unwrap()
+				.entry(ceremony_id)
+				.and_modify(|(command_tmp, _)| command_tmp.write().unwrap().push(command.clone()))
+				.or_insert((Arc::new(RwLock::new(vec![command])), get_current_timestamp()));
+			return
+		},
+		(is_first_round, command) => {
+			error!(
+				"receive wrong frost command: is_first_round: {:?}, command: {:?}, drop it",
+				is_first_round, command
+			);
+			return
+		},
+	}
+
+	// try to udpate peers_map
+	update_peers_map(
+		context.signing_key_access.as_ref(),
+		context.enclave_registry_lookup.as_ref(),
+		peers_map.as_ref(),
+	);
+
+	// process commands and events
+	let mut commands_to_process = vec![command];
+	while !commands_to_process.is_empty() {
+		let command = commands_to_process.pop().unwrap();
+
+		let event = process_frost_command(context.clone(), ceremony_id.clone(), command);
+
+		if let Some(event) = event {
+			match &event {
+				FrostCeremonyEvent::CeremonyEnded(signature) => record_audit_event(
+					context.audit_log.as_ref(),
+					AuditEvent::Signed(
+						AuditedCall::SignSolana(ceremony_id.clone()).hash(),
+						signature.to_vec(),
+					),
+				),
+				FrostCeremonyEvent::CeremonyError(_, error) => record_audit_event(
+					context.audit_log.as_ref(),
+					AuditEvent::Failed(
+						AuditedCall::SignSolana(ceremony_id.clone()).hash(),
+						error.encode(),
+					),
+				),
+				_ => {},
+			}
+
+			match event {
+				FrostCeremonyEvent::FirstRoundStarted(_, _, _)
+				| FrostCeremonyEvent::SecondRoundStarted(_, _, _) => {
+					// get all frost_ceremony_command_tmp
+					let mut command_tmp_write = context.frost_ceremony_command_tmp.write().unwrap();
+					if let Some((command_tmp, _)) = command_tmp_write.remove(&ceremony_id) {
+						commands_to_process = command_tmp.read().unwrap().clone();
+					}
+				},
+				FrostCeremonyEvent::CeremonyEnded(_) | FrostCeremonyEvent::CeremonyError(_, _) => {
+					// remove ceremony
+					{
+						context.frost_ceremony_registry.write().unwrap().remove(&ceremony_id);
+					}
+					{
+						context.frost_ceremony_command_tmp.write().unwrap().remove(&ceremony_id);
+					}
+				},
+			}
+
+			process_frost_event(
+				context.signing_key_access.clone(),
+				context.ocall_api.clone(),
+				context.responder.clone(),
+				context.enclave_registry_lookup.clone(),
+				event,
+				ceremony_id.clone(),
+				event_threads_pool.clone(),
+				peers_map.clone(),
+				context.frost_ceremony_registry.clone(),
+			);
+		}
+	}
+}
+
+#[allow(clippy::type_complexity)]
+fn process_frost_command<SKR, SIGNINGAK, EKR, BKR, SOLAK, S, H, O, RRL, ERL, SRL, AL, Responder>(
+	context: Arc<
+		BitAcrossTaskContext<
+			SKR,
+			SIGNINGAK,
+			EKR,
+			BKR,
+			SOLAK,
+			S,
+			H,
+			O,
+			RRL,
+			ERL,
+			SRL,
+			AL,
+			Responder,
+		>,
+	>,
+	ceremony_id: SolanaCeremonyId,
+	command: FrostCeremonyCommand,
+) -> Option<FrostCeremonyEvent>
+where
+	SKR: AccessKey + AccessPubkey<KeyType = Rsa3072PubKey> + Send + Sync + 'static,
+	SIGNINGAK: AccessKey<KeyType = ed25519::Pair> + Send + Sync + 'static,
+	EKR: AccessKey<KeyType = EcdsaPair> + Send + Sync + 'static,
+	BKR: AccessKey<KeyType = SchnorrPair> + Send + Sync + 'static,
+	SOLAK: AccessKey<KeyType = FrostPair> + Send + Sync + 'static,
+	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
+	S: StfEnclaveSigning<TrustedCallSigned> + Send + Sync + 'static,
+	H: HandleState + Send + Sync + 'static,
+	H::StateT: SgxExternalitiesTrait,
+	O: EnclaveOnChainOCallApi + EnclaveMetricsOCallApi + EnclaveAttestationOCallApi + 'static,
+	RRL: RelayerRegistryLookup + Send + Sync + 'static,
+	ERL: EnclaveRegistryLookup + Send + Sync + 'static,
+	SRL: SignerRegistryLookup + Send + Sync + 'static,
+	AL: AuditLogUpdater + Send + Sync + 'static,
+	Responder: SendRpcResponse<Hash = H256> + Send + Sync + 'static,
+{
+	match command {
+		FrostCeremonyCommand::InitCeremony(signers, payload) => {
+			// InitCeremony should create ceremony first
+			let result = FrostCeremony::new(
+				context.signing_key_pub,
+				signers,
+				payload,
+				context.solana_key_repository.clone(),
+			);
+
+			match result {
+				Ok((ceremony, event)) => {
+					{
+						let mut registry_write = context.frost_ceremony_registry.write().unwrap();
+						if registry_write.contains_key(&ceremony_id) {
+							let error =
+								CeremonyError::CeremonyInitError(CeremonyErrorReason::AlreadyExist);
+							return Some(FrostCeremonyEvent::CeremonyError(vec![], error))
+						}
+						registry_write.insert(
+							ceremony_id,
+							(Arc::new(RwLock::new(ceremony)), get_current_timestamp()),
+						);
+					}
+					Some(event)
+				},
+				Err(e) => {
+					error!("Could not start frost ceremony, error: {:?}", e);
+					let error =
+						CeremonyError::CeremonyInitError(CeremonyErrorReason::CreateCeremonyError);
+					Some(FrostCeremonyEvent::CeremonyError(vec![], error))
+				},
+			}
+		},
+		FrostCeremonyCommand::SaveCommitments(signer, commitments) => {
+			let ceremony_rwlock =
+				context.frost_ceremony_registry.read().unwrap().get(&ceremony_id).cloned();
+			if let Some(ceremony_rwlock) = ceremony_rwlock {
+				let mut ceremony_write_lock = ceremony_rwlock.0.write().unwrap();
+				let event_ret = ceremony_write_lock.receive_commitments(signer, commitments);
+				match event_ret {
+					Ok(event) => event,
+					Err(e) => Some(FrostCeremonyEvent::CeremonyError(
+						ceremony_write_lock.get_signers_except_self(),
+						e,
+					)),
+				}
+			} else {
+				None
+			}
+		},
+		FrostCeremonyCommand::SaveSignatureShare(signer, share) => {
+			let ceremony_rwlock =
+				context.frost_ceremony_registry.read().unwrap().get(&ceremony_id).cloned();
+			if let Some(ceremony_rwlock) = ceremony_rwlock {
+				let mut ceremony_write_lock = ceremony_rwlock.0.write().unwrap();
+				let event_ret = ceremony_write_lock.receive_signature_share(signer, share);
+				match event_ret {
+					Ok(event) => event,
+					Err(e) => Some(FrostCeremonyEvent::CeremonyError(
+						ceremony_write_lock.get_signers_except_self(),
+						e,
+					)),
+				}
+			} else {
+				None
+			}
+		},
+		FrostCeremonyCommand::KillCeremony => {
+			{
+				context.frost_ceremony_registry.write().unwrap().remove(&ceremony_id);
+			}
+			{
+				context.frost_ceremony_command_tmp.write().unwrap().remove(&ceremony_id);
+			}
+			None
+		},
 	}
 }
 
+// connects to all registered enclaves we don't have a client for yet
+fn update_peers_map<SIGNINGAK, ERL>(
+	signing_key_access: &SIGNINGAK,
+	enclave_registry_lookup: &ERL,
+	peers_map: &Mutex<HashMap<[u8; 32], DirectRpcClient>>,
+) where
+	SIGNINGAK: AccessKey<KeyType = ed25519::Pair>,
+	ERL: EnclaveRegistryLookup,
+{
+	let my_identity: Address32 = signing_key_access.retrieve_key().unwrap().public().0.into();
+	enclave_registry_lookup.get_all().iter().for_each(|(identity, address)| {
+		if my_identity != *identity && !peers_map.lock().unwrap().contains_key(identity.as_ref()) {
+			info!("creating new connection to peer: {:?}", address);
+			match (DirectRpcClientFactory {}).create(address) {
+				Ok(client) => {
+					peers_map.lock().unwrap().insert(*identity.as_ref(), client);
+				},
+				Err(e) => error!("Could not connect to peer {}, reason: {:?}", address, e),
+			}
+		}
+	});
+}
+
 #[allow(clippy::type_complexity)]
-fn handle_request<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>(
+fn handle_request<SKR, SIGNINGAK, EKR, BKR, SOLAK, S, H, O, RRL, ERL, SRL, AL, Responder>(
 	request: BitAcrossRequest,
-	context: Arc<BitAcrossTaskContext<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>>,
-) -> Option<(CeremonyId, CeremonyCommand)>
+	context: Arc<
+		BitAcrossTaskContext<
+			SKR,
+			SIGNINGAK,
+			EKR,
+			BKR,
+			SOLAK,
+			S,
+			H,
+			O,
+			RRL,
+			ERL,
+			SRL,
+			AL,
+			Responder,
+		>,
+	>,
+) -> Option<SigningCommand>
 where
 	SKR: AccessKey + AccessPubkey<KeyType = Rsa3072PubKey>,
 	SIGNINGAK: AccessKey<KeyType = ed25519::Pair>,
 	EKR: AccessKey<KeyType = EcdsaPair>,
 	BKR: AccessKey<KeyType = SchnorrPair>,
+	SOLAK: AccessKey<KeyType = FrostPair>,
 	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
 	S: StfEnclaveSigning<TrustedCallSigned> + Send + Sync + 'static,
 	H: HandleState + Send + Sync + 'static,
@@ -547,6 +1210,7 @@ where
 	RRL: RelayerRegistryLookup + 'static,
 	ERL: EnclaveRegistryLookup + 'static,
 	SRL: SignerRegistryLookup + 'static,
+	AL: AuditLogUpdater + 'static,
 	Responder: SendRpcResponse<Hash = H256> + Send + Sync + 'static,
 {
 	match request {
@@ -572,15 +1236,32 @@ where
 }
 
 #[allow(clippy::type_complexity)]
-fn handle_direct_call<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>(
+fn handle_direct_call<SKR, SIGNINGAK, EKR, BKR, SOLAK, S, H, O, RRL, ERL, SRL, AL, Responder>(
 	request: PlainRequest,
-	context: Arc<BitAcrossTaskContext<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>>,
-) -> Result<(Option<BitAcrossProcessingResult>, Option<(CeremonyId, CeremonyCommand)>), Vec<u8>>
+	context: Arc<
+		BitAcrossTaskContext<
+			SKR,
+			SIGNINGAK,
+			EKR,
+			BKR,
+			SOLAK,
+			S,
+			H,
+			O,
+			RRL,
+			ERL,
+			SRL,
+			AL,
+			Responder,
+		>,
+	>,
+) -> Result<(Option<BitAcrossProcessingResult>, Option<SigningCommand>), Vec<u8>>
 where
 	SKR: AccessKey + AccessPubkey<KeyType = Rsa3072PubKey>,
 	SIGNINGAK: AccessKey<KeyType = ed25519::Pair>,
 	EKR: AccessKey<KeyType = EcdsaPair>,
 	BKR: AccessKey<KeyType = SchnorrPair>,
+	SOLAK: AccessKey<KeyType = FrostPair>,
 	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
 	S: StfEnclaveSigning<TrustedCallSigned> + Send + Sync + 'static,
 	H: HandleState + Send + Sync + 'static,
@@ -588,6 +1269,7 @@ where
 	RRL: RelayerRegistryLookup + 'static,
 	ERL: EnclaveRegistryLookup + 'static,
 	SRL: SignerRegistryLookup + 'static,
+	AL: AuditLogUpdater + 'static,
 	Responder: SendRpcResponse<Hash = H256> + Send + Sync + 'static,
 {
 	let dc = DirectCallSigned::decode(&mut request.payload.as_slice()).map_err(|e| {
@@ -608,20 +1290,37 @@ where
 	match dc.call {
 		DirectCall::SignBitcoin(signer, payload) => {
 			let hash = blake2_256(&payload.encode());
-			let command = sign_bitcoin::handle(
-				signer,
-				payload.clone(),
+			let audited_call = AuditedCall::SignBitcoin(payload.clone());
+			record_audit_event(
+				context.audit_log.as_ref(),
+				AuditEvent::Requested(signer.clone(), audited_call.clone()),
+			);
+			let command = ensure_relayer_role(
 				context.relayer_registry_lookup.deref(),
-				context.signer_registry_lookup.clone(),
-				context.enclave_registry_lookup.as_ref(),
-				false,
+				&signer,
+				RelayerRole::can_sign_btc,
+				SignBitcoinError::InvalidSigner,
 			)
+			.and_then(|_| {
+				sign_bitcoin::handle(
+					signer,
+					payload.clone(),
+					context.relayer_registry_lookup.deref(),
+					context.signer_registry_lookup.clone(),
+					context.enclave_registry_lookup.as_ref(),
+					false,
+				)
+			})
 			.map_err(|e| {
 				error!("SignBitcoin error: {:?}", e);
+				record_audit_event(
+					context.audit_log.as_ref(),
+					AuditEvent::Failed(audited_call.hash(), e.encode()),
+				);
 				e.encode()
 			})?;
 			let ret = BitAcrossProcessingResult::Submitted(hash);
-			Ok((Some(ret), Some((payload, command))))
+			Ok((Some(ret), Some(SigningCommand::MuSig2(payload, command))))
 		},
 		DirectCall::CheckSignBitcoin(signer) => {
 			let payload = SignBitcoinPayload::Derived([0u8; 32].to_vec());
@@ -639,32 +1338,121 @@ where
 				e.encode()
 			})?;
 			let ret = BitAcrossProcessingResult::Submitted(hash);
-			Ok((Some(ret), Some((payload, command))))
+			Ok((Some(ret), Some(SigningCommand::MuSig2(payload, command))))
+		},
+		DirectCall::SignEthereum(signer, msg) => {
+			let audited_call = AuditedCall::SignEthereum(msg);
+			record_audit_event(
+				context.audit_log.as_ref(),
+				AuditEvent::Requested(signer.clone(), audited_call.clone()),
+			);
+			ensure_relayer_role(
+				context.relayer_registry_lookup.deref(),
+				&signer,
+				RelayerRole::can_sign_eth,
+				SignEthereumError::InvalidSigner,
+			)
+			.and_then(|_| {
+				sign_ethereum::handle(
+					signer,
+					msg,
+					context.relayer_registry_lookup.deref(),
+					context.ethereum_key_repository.deref(),
+				)
+			})
+			.map_err(|e| {
+				error!("SignEthereum error: {:?}", e);
+				record_audit_event(
+					context.audit_log.as_ref(),
+					AuditEvent::Failed(audited_call.hash(), e.encode()),
+				);
+				e.encode()
+			})
+			.map(|r| {
+				record_audit_event(
+					context.audit_log.as_ref(),
+					AuditEvent::Signed(audited_call.hash(), r.to_vec()),
+				);
+				(Some(BitAcrossProcessingResult::Ok(r.encode())), None)
+			})
+		},
+		DirectCall::SignSolana(signer, payload) => {
+			let hash = blake2_256(&payload.encode());
+			let audited_call = AuditedCall::SignSolana(payload.clone());
+			record_audit_event(
+				context.audit_log.as_ref(),
+				AuditEvent::Requested(signer.clone(), audited_call.clone()),
+			);
+			let command = ensure_relayer_role(
+				context.relayer_registry_lookup.deref(),
+				&signer,
+				RelayerRole::can_sign_sol,
+				SignSolanaError::InvalidSigner,
+			)
+			.and_then(|_| {
+				sign_solana::handle(
+					signer,
+					payload.clone(),
+					context.relayer_registry_lookup.deref(),
+					context.signer_registry_lookup.clone(),
+					context.enclave_registry_lookup.as_ref(),
+				)
+			})
+			.map_err(|e| {
+				error!("SignSolana error: {:?}", e);
+				record_audit_event(
+					context.audit_log.as_ref(),
+					AuditEvent::Failed(audited_call.hash(), e.encode()),
+				);
+				e.encode()
+			})?;
+			let ret = BitAcrossProcessingResult::Submitted(hash);
+			Ok((Some(ret), Some(SigningCommand::Frost(payload, command))))
 		},
-		DirectCall::SignEthereum(signer, msg) => sign_ethereum::handle(
-			signer,
-			msg,
-			context.relayer_registry_lookup.deref(),
-			context.ethereum_key_repository.deref(),
-		)
-		.map_err(|e| {
-			error!("SignEthereum error: {:?}", e);
-			e.encode()
-		})
-		.map(|r| (Some(BitAcrossProcessingResult::Ok(r.encode())), None)),
 	}
 }
 
 #[allow(clippy::type_complexity)]
-fn handle_ceremony_round_call<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>(
+fn handle_ceremony_round_call<
+	SKR,
+	SIGNINGAK,
+	EKR,
+	BKR,
+	SOLAK,
+	S,
+	H,
+	O,
+	RRL,
+	ERL,
+	SRL,
+	AL,
+	Responder,
+>(
 	request: PlainRequest,
-	context: Arc<BitAcrossTaskContext<SKR, SIGNINGAK, EKR, BKR, S, H, O, RRL, ERL, SRL, Responder>>,
-) -> Result<Option<(CeremonyId, CeremonyCommand)>, Vec<u8>>
+	context: Arc<
+		BitAcrossTaskContext<
+			SKR,
+			SIGNINGAK,
+			EKR,
+			BKR,
+			SOLAK,
+			S,
+			H,
+			O,
+			RRL,
+			ERL,
+			SRL,
+			AL,
+			Responder,
+		>,
+	>,
+) -> Result<Option<SigningCommand>, Vec<u8>>
 where
 	SKR: AccessKey + AccessPubkey<KeyType = Rsa3072PubKey>,
 	SIGNINGAK: AccessKey<KeyType = ed25519::Pair>,
 	EKR: AccessKey<KeyType = EcdsaPair>,
 	BKR: AccessKey<KeyType = SchnorrPair>,
+	SOLAK: AccessKey<KeyType = FrostPair>,
 	<SKR as AccessKey>::KeyType: ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
 	S: StfEnclaveSigning<TrustedCallSigned> + Send + Sync + 'static,
 	H: HandleState + Send + Sync + 'static,
@@ -672,6 +1460,7 @@ where
 	RRL: RelayerRegistryLookup + 'static,
 	ERL: EnclaveRegistryLookup + 'static,
 	SRL: SignerRegistryLookup + 'static,
+	AL: AuditLogUpdater + 'static,
 	Responder: SendRpcResponse<Hash = H256> + Send + Sync + 'static,
 {
 	let crc = CeremonyRoundCallSigned::decode(&mut request.payload.as_slice()).map_err(|e| {
@@ -696,7 +1485,7 @@ where
 					error!("NonceShare error: {:?}", e);
 					e.encode()
 				})
-				.map(|command| Some((message, command))),
+				.map(|command| Some(SigningCommand::MuSig2(message, command))),
 		CeremonyRoundCall::PartialSignatureShare(signer, message, signature) =>
 			partial_signature_share::handle(
 				signer,
@@ -708,13 +1497,74 @@ where
 				error!("PartialSignatureShare error: {:?}", e);
 				e.encode()
 			})
-			.map(|command| Some((message, command))),
+			.map(|command| Some(SigningCommand::MuSig2(message, command))),
 		CeremonyRoundCall::KillCeremony(signer, message) =>
 			kill_ceremony::handle(signer, context.enclave_registry_lookup.as_ref())
 				.map_err(|e| {
 					error!("KillCeremony error: {:?}", e);
 					e.encode()
 				})
-				.map(|command| Some((message, command))),
+				.map(|command| Some(SigningCommand::MuSig2(message, command))),
+		CeremonyRoundCall::CommitmentsShare(signer, payload, commitments) =>
+			commitments_share::handle(
+				signer,
+				&payload,
+				commitments,
+				context.enclave_registry_lookup.clone(),
+			)
+			.map_err(|e| {
+				error!("CommitmentsShare error: {:?}", e);
+				e.encode()
+			})
+			.map(|command| Some(SigningCommand::Frost(payload, command))),
+		CeremonyRoundCall::SignatureShare(signer, payload, share) => signature_share::handle(
+			signer,
+			&payload,
+			share,
+			context.enclave_registry_lookup.clone(),
+		)
+		.map_err(|e| {
+			error!("SignatureShare error: {:?}", e);
+			e.encode()
+		})
+		.map(|command| Some(SigningCommand::Frost(payload, command))),
+		CeremonyRoundCall::KillSolanaCeremony(signer, payload) =>
+			kill_ceremony::handle_solana(signer, context.enclave_registry_lookup.as_ref())
+				.map_err(|e| {
+					error!("KillSolanaCeremony error: {:?}", e);
+					e.encode()
+				})
+				.map(|command| Some(SigningCommand::Frost(payload, command))),
+		CeremonyRoundCall::AbortCeremony(signer, message, reason) =>
+			kill_ceremony::handle_abort(signer, reason, context.enclave_registry_lookup.as_ref())
+				.map_err(|e| {
+					error!("AbortCeremony error: {:?}", e);
+					e.encode()
+				})
+				.map(|command| Some(SigningCommand::MuSig2(message, command))),
+	}
+}
+
+// Rejects relayers whose role does not allow the call. Signers that are not relayers are left
+// to the handler, which also accepts enclaves for some calls.
+fn ensure_relayer_role<RRL: RelayerRegistryLookup, E>(
+	relayer_registry: &RRL,
+	signer: &Identity,
+	allowed: fn(&RelayerRole) -> bool,
+	err: E,
+) -> Result<(), E> {
+	match relayer_registry.get_role(signer) {
+		Some(role) if !allowed(&role) => {
+			warn!("Relayer {:?} with role {:?} is not allowed to make this call", signer, role);
+			Err(err)
+		},
+		_ => Ok(()),
+	}
+}
+
+// a failure to write the audit log must not block signing, but it's loud
+fn record_audit_event<AL: AuditLogUpdater>(audit_log: &AL, event: AuditEvent) {
+	if let Err(e) = audit_log.append(event) {
+		error!("Could not append to audit log, reason: {:?}", e);
 	}
 }
@@ -16,5 +16,6 @@
 
 pub mod direct_call_sign_bitcoin;
 pub mod direct_call_sign_ethereum;
+pub mod direct_call_sign_solana;
 
 pub mod utils;
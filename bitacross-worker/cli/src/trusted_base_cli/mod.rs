@@ -21,6 +21,7 @@ use crate::{
 		bitacross::{
 			direct_call_sign_bitcoin::RequestDirectCallSignBitcoinCommand,
 			direct_call_sign_ethereum::RequestDirectCallSignEthereumCommand,
+			direct_call_sign_solana::RequestDirectCallSignSolanaCommand,
 		},
 		get_shard::GetShardCommand,
 		nonce::NonceCommand,
@@ -71,6 +72,9 @@ pub enum TrustedBaseCommand {
 
 	/// sign ethereum transaction using custodian wallet
 	RequestDirectCallSignEthereum(RequestDirectCallSignEthereumCommand),
+
+	/// sign solana transaction using custodian wallet
+	RequestDirectCallSignSolana(RequestDirectCallSignSolanaCommand),
 }
 
 impl TrustedBaseCommand {
@@ -86,6 +90,7 @@ impl TrustedBaseCommand {
 			TrustedBaseCommand::GetShard(cmd) => cmd.run(cli, trusted_cli),
 			TrustedBaseCommand::RequestDirectCallSignBitcoin(cmd) => cmd.run(cli, trusted_cli),
 			TrustedBaseCommand::RequestDirectCallSignEthereum(cmd) => cmd.run(cli, trusted_cli),
+			TrustedBaseCommand::RequestDirectCallSignSolana(cmd) => cmd.run(cli, trusted_cli),
 		}
 	}
 }
@@ -193,6 +193,16 @@ extern "C" {
 		unchecked_extrinsic_size: *mut u32,
 	) -> sgx_status_t;
 
+	pub fn generate_register_collateral_bundle_extrinsic(
+		eid: sgx_enclave_id_t,
+		retval: *mut sgx_status_t,
+		bundle: *const u8,
+		bundle_size: u32,
+		unchecked_extrinsic: *mut u8,
+		unchecked_extrinsic_max_size: u32,
+		unchecked_extrinsic_size: *mut u32,
+	) -> sgx_status_t;
+
 	pub fn dump_ias_ra_cert_to_disk(
 		eid: sgx_enclave_id_t,
 		retval: *mut sgx_status_t,
@@ -18,7 +18,7 @@
 
 use crate::EnclaveResult;
 use itp_types::ShardIdentifier;
-use pallet_teebag::Fmspc;
+use pallet_teebag::{CollateralBundle, Fmspc};
 use sgx_types::*;
 
 /// Struct that unites all relevant data reported by the QVE
@@ -45,6 +45,11 @@ pub trait RemoteAttestation {
 
 	fn generate_register_tcb_info_extrinsic(&self, fmspc: Fmspc) -> EnclaveResult<Vec<u8>>;
 
+	fn generate_register_collateral_bundle_extrinsic(
+		&self,
+		bundle: &CollateralBundle,
+	) -> EnclaveResult<Vec<u8>>;
+
 	fn dump_ias_ra_cert_to_disk(&self) -> EnclaveResult<()>;
 
 	fn dump_dcap_ra_cert_to_disk(&self) -> EnclaveResult<()>;
@@ -128,7 +133,7 @@ mod impl_ffi {
 	use itp_settings::worker::EXTRINSIC_MAX_SIZE;
 	use itp_types::ShardIdentifier;
 	use log::*;
-	use pallet_teebag::Fmspc;
+	use pallet_teebag::{CollateralBundle, Fmspc};
 	use sgx_types::*;
 
 	const OS_SYSTEM_PATH: &str = "/usr/lib/x86_64-linux-gnu/";
@@ -356,6 +361,34 @@ mod impl_ffi {
 			Ok(Vec::from(&unchecked_extrinsic[..unchecked_extrinsic_size as usize]))
 		}
 
+		fn generate_register_collateral_bundle_extrinsic(
+			&self,
+			bundle: &CollateralBundle,
+		) -> EnclaveResult<Vec<u8>> {
+			let mut retval = sgx_status_t::SGX_SUCCESS;
+			let bundle = bundle.encode();
+			// the extrinsic carries the whole bundle, which can exceed a single collateral
+			let mut unchecked_extrinsic: Vec<u8> = vec![0u8; bundle.len() + EXTRINSIC_MAX_SIZE];
+			let mut unchecked_extrinsic_size: u32 = 0;
+
+			trace!("Generating collateral bundle registration");
+
+			let result = unsafe {
+				ffi::generate_register_collateral_bundle_extrinsic(
+					self.eid,
+					&mut retval,
+					bundle.as_ptr(),
+					bundle.len() as u32,
+					unchecked_extrinsic.as_mut_ptr(),
+					unchecked_extrinsic.len() as u32,
+					&mut unchecked_extrinsic_size as *mut u32,
+				)
+			};
+			ensure!(result == sgx_status_t::SGX_SUCCESS, Error::Sgx(result));
+			ensure!(retval == sgx_status_t::SGX_SUCCESS, Error::Sgx(retval));
+			Ok(Vec::from(&unchecked_extrinsic[..unchecked_extrinsic_size as usize]))
+		}
+
 		fn dump_ias_ra_cert_to_disk(&self) -> EnclaveResult<()> {
 			let mut retval = sgx_status_t::SGX_SUCCESS;
 
@@ -26,12 +26,21 @@ extern crate sgx_tstd as std;
 
 use codec::{Decode, Encode};
 use core::time::Duration;
+use std::string::String;
 
 #[derive(Encode, Decode, Debug)]
 pub enum EnclaveMetric {
 	ParentchainBlockImportTime(Duration),
 	Musig2CeremonyStarted,
-	Musig2CeremonyFailed,
+	// labelled with the `CeremonyErrorReason`
+	Musig2CeremonyFailed(String),
 	Musig2CeremonyTimedout(u8),
 	Musig2CeremonyDuration(Duration),
+	Musig2CeremonyCompleted,
+	// round number (1 or 2) and its duration
+	Musig2CeremonyRoundDuration(u8, Duration),
+	// time from the start of the round until the peer's contribution arrived
+	Musig2PeerNonceDelay([u8; 32], Duration),
+	Musig2PeerPartialSignatureDelay([u8; 32], Duration),
+	Musig2ActiveCeremonies(u64),
 }
@@ -16,7 +16,9 @@
 
 use crate::ApiResult;
 use itp_api_client_types::{traits::GetStorage, Api, Config, Request};
-use itp_types::{AccountId, Enclave, ShardIdentifier, WorkerType};
+use itp_types::{
+	AccountId, Enclave, Fmspc, QuotingEnclave, ShardIdentifier, TcbInfoOnChain, WorkerType,
+};
 
 pub const TEEBAG: &str = "Teebag";
 
@@ -51,6 +53,14 @@ pub trait PalletTeebagApi {
 		worker_type: WorkerType,
 		at_block: Option<Self::Hash>,
 	) -> ApiResult<Vec<Enclave>>;
+	fn reattestation_period(&self, at_block: Option<Self::Hash>) -> ApiResult<Option<u64>>;
+	fn quoting_enclave(&self, at_block: Option<Self::Hash>) -> ApiResult<Option<QuotingEnclave>>;
+	fn tcb_info(
+		&self,
+		fmspc: Fmspc,
+		at_block: Option<Self::Hash>,
+	) -> ApiResult<Option<TcbInfoOnChain>>;
+	fn all_tcb_info_fmspcs(&self, at_block: Option<Self::Hash>) -> ApiResult<Vec<Fmspc>>;
 }
 
 impl<RuntimeConfig, Client> PalletTeebagApi for Api<RuntimeConfig, Client>
@@ -131,4 +141,35 @@ where
 			.collect();
 		Ok(enclaves)
 	}
+
+	fn reattestation_period(&self, at_block: Option<Self::Hash>) -> ApiResult<Option<u64>> {
+		self.get_storage(TEEBAG, "ReattestationPeriod", at_block)
+	}
+
+	fn quoting_enclave(&self, at_block: Option<Self::Hash>) -> ApiResult<Option<QuotingEnclave>> {
+		self.get_storage(TEEBAG, "QuotingEnclaveRegistry", at_block)
+	}
+
+	fn tcb_info(
+		&self,
+		fmspc: Fmspc,
+		at_block: Option<Self::Hash>,
+	) -> ApiResult<Option<TcbInfoOnChain>> {
+		self.get_storage_map(TEEBAG, "TcbInfo", fmspc, at_block)
+	}
+
+	fn all_tcb_info_fmspcs(&self, at_block: Option<Self::Hash>) -> ApiResult<Vec<Fmspc>> {
+		// there is one entry per platform type, so a single page is plenty
+		const MAX_FMSPCS: u32 = 1000;
+		let prefix = self.get_storage_map_key_prefix(TEEBAG, "TcbInfo")?;
+		let keys = self.get_storage_keys_paged(Some(prefix), MAX_FMSPCS, None, at_block)?;
+		// the `Blake2_128Concat` hasher appends the raw key to its hash
+		Ok(keys
+			.into_iter()
+			.filter_map(|key| {
+				let start = key.0.len().checked_sub(6)?;
+				Fmspc::try_from(&key.0[start..]).ok()
+			})
+			.collect())
+	}
 }
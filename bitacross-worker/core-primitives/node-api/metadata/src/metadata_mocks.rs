@@ -43,6 +43,7 @@ pub struct NodeMetadataMock {
 	unregister_enclave: u8,
 	register_quoting_enclave: u8,
 	register_tcb_info: u8,
+	register_collateral_bundle: u8,
 	post_opaque_task: u8,
 	parentchain_block_processed: u8,
 	sidechain_block_imported: u8,
@@ -72,6 +73,8 @@ pub struct NodeMetadataMock {
 	bitacross_remove_relayer: u8,
 	btc_wallet_generated: u8,
 	eth_wallet_generated: u8,
+	audit_log_anchored: u8,
+	sol_wallet_generated: u8,
 }
 
 impl NodeMetadataMock {
@@ -84,6 +87,7 @@ impl NodeMetadataMock {
 			unregister_enclave: 3u8,
 			register_quoting_enclave: 4u8,
 			register_tcb_info: 5u8,
+			register_collateral_bundle: 9u8,
 			post_opaque_task: 6u8,
 			parentchain_block_processed: 7u8,
 			sidechain_block_imported: 8u8,
@@ -113,6 +117,8 @@ impl NodeMetadataMock {
 			bitacross_remove_relayer: 1u8,
 			btc_wallet_generated: 2u8,
 			eth_wallet_generated: 3u8,
+			audit_log_anchored: 33u8,
+			sol_wallet_generated: 34u8,
 		}
 	}
 }
@@ -136,6 +142,9 @@ impl TeebagCallIndexes for NodeMetadataMock {
 	fn register_tcb_info_call_indexes(&self) -> Result<[u8; 2]> {
 		Ok([self.teebag_module, self.register_tcb_info])
 	}
+	fn register_collateral_bundle_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.teebag_module, self.register_collateral_bundle])
+	}
 	fn post_opaque_task_call_indexes(&self) -> Result<[u8; 2]> {
 		Ok([self.teebag_module, self.post_opaque_task])
 	}
@@ -221,6 +230,14 @@ impl BitAcrossCallIndexes for NodeMetadataMock {
 	fn eth_wallet_generated_indexes(&self) -> Result<[u8; 2]> {
 		Ok([self.bitacross_module, self.eth_wallet_generated])
 	}
+
+	fn audit_log_anchored_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.bitacross_module, self.audit_log_anchored])
+	}
+
+	fn sol_wallet_generated_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.bitacross_module, self.sol_wallet_generated])
+	}
 }
 
 impl TimestampCallIndexes for NodeMetadataMock {
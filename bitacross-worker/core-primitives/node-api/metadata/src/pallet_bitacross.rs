@@ -24,6 +24,8 @@ pub trait BitAcrossCallIndexes {
 	fn remove_relayer_call_indexes(&self) -> Result<[u8; 2]>;
 	fn btc_wallet_generated_indexes(&self) -> Result<[u8; 2]>;
 	fn eth_wallet_generated_indexes(&self) -> Result<[u8; 2]>;
+	fn audit_log_anchored_indexes(&self) -> Result<[u8; 2]>;
+	fn sol_wallet_generated_indexes(&self) -> Result<[u8; 2]>;
 }
 
 impl BitAcrossCallIndexes for NodeMetadata {
@@ -42,4 +44,12 @@ impl BitAcrossCallIndexes for NodeMetadata {
 	fn eth_wallet_generated_indexes(&self) -> Result<[u8; 2]> {
 		self.call_indexes(BITACROSS, "eth_wallet_generated")
 	}
+
+	fn audit_log_anchored_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(BITACROSS, "audit_log_anchored")
+	}
+
+	fn sol_wallet_generated_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(BITACROSS, "sol_wallet_generated")
+	}
 }
@@ -37,12 +37,20 @@ pub mod files {
 	pub const TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH: &str = "target_b_lcdb";
 
 	// bitacross
-	pub const RELAYER_REGISTRY_FILE: &str = "relayer_registry_sealed.bin";
+	pub const RELAYER_REGISTRY_FILE: &str = "relayer_registry_v2_sealed.bin";
+	pub const RELAYER_REGISTRY_V1_FILE: &str = "relayer_registry_sealed.bin";
 
 	pub const ENCLAVE_REGISTRY_FILE: &str = "enclave_registry_sealed.bin";
 
 	pub const SIGNER_REGISTRY_FILE: &str = "signer_registry_sealed.bin";
 
+	pub const SOL_SIGNER_REGISTRY_FILE: &str = "sol_signer_registry_sealed.bin";
+
+	pub const AUDIT_LOG_FILE: &str = "audit_log_sealed.bin";
+	pub const AUDIT_LOG_SEGMENT_FILE_PREFIX: &str = "audit_log_segment_sealed";
+
+	pub const MUSIG2_CEREMONIES_FILE: &str = "musig2_ceremonies_sealed.bin";
+
 	pub const RA_DUMP_CERT_DER_FILE: &str = "ra_dump_cert.der";
 
 	// used by worker and enclave
@@ -80,6 +88,8 @@ pub mod worker {
 	// Should be set to a value that ensures that at least 2 sidechain blocks are finalized per
 	// parentchain block.
 	pub const BLOCK_NUMBER_FINALIZATION_DIFF: u64 = 20;
+	// interval in seconds in which the head of the signing audit log is anchored on the parentchain
+	pub const AUDIT_LOG_ANCHOR_INTERVAL: u64 = 600;
 }
 
 pub mod sidechain {
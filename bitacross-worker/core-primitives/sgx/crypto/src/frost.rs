@@ -0,0 +1,190 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+#[cfg(feature = "sgx")]
+pub use sgx::*;
+
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, scalar::Scalar};
+
+/// File name of the sealed seed file.
+pub const SEALED_SIGNER_SEED_FILE: &str = "frost_key_sealed.bin";
+
+/// Ed25519 key share used in FROST ceremonies, the group key is the sum
+/// of the public shares of all signers.
+#[derive(Clone)]
+pub struct Pair {
+	pub public: [u8; 32],
+	private: Scalar,
+}
+
+impl Pair {
+	pub fn new(private: Scalar) -> Self {
+		let public = (&private * &ED25519_BASEPOINT_TABLE).compress().to_bytes();
+		Self { private, public }
+	}
+
+	// any 32 bytes are accepted, they are reduced modulo the group order
+	pub fn from_bytes(bytes: [u8; 32]) -> Self {
+		Self::new(Scalar::from_bytes_mod_order(bytes))
+	}
+
+	pub fn public_bytes(&self) -> [u8; 32] {
+		self.public
+	}
+
+	pub fn private_bytes(&self) -> [u8; 32] {
+		self.private.to_bytes()
+	}
+}
+
+#[cfg(feature = "sgx")]
+pub mod sgx {
+	use super::SEALED_SIGNER_SEED_FILE;
+	use crate::{
+		error::{Error, Result},
+		frost::Pair,
+		key_repository::KeyRepository,
+		std::string::ToString,
+	};
+	use itp_sgx_io::{seal, unseal, SealedIO};
+	use log::*;
+	use sgx_rand::{Rng, StdRng};
+	use std::{path::PathBuf, string::String};
+
+	/// Creates a repository for a FROST key share and initializes
+	/// a fresh private key if it doesn't exist at `path`.
+	pub fn create_frost_repository(
+		path: PathBuf,
+		key_file_prefix: &str,
+		key: Option<[u8; 32]>,
+	) -> Result<KeyRepository<Pair, Seal>> {
+		let seal = Seal::new(path, key_file_prefix.to_string());
+		Ok(KeyRepository::new(seal.init(key)?, seal.into()))
+	}
+
+	#[derive(Clone, Debug)]
+	pub struct Seal {
+		base_path: PathBuf,
+		key_file_prefix: String,
+	}
+
+	impl Seal {
+		pub fn new(base_path: PathBuf, key_file_prefix: String) -> Self {
+			Self { base_path, key_file_prefix }
+		}
+
+		pub fn path(&self) -> PathBuf {
+			self.base_path
+				.join(self.key_file_prefix.clone() + "_" + SEALED_SIGNER_SEED_FILE)
+		}
+	}
+
+	impl Seal {
+		fn unseal_pair(&self) -> Result<Pair> {
+			self.unseal()
+		}
+
+		pub fn exists(&self) -> bool {
+			self.path().exists()
+		}
+
+		pub fn init(&self, key: Option<[u8; 32]>) -> Result<Pair> {
+			if !self.exists() || key.is_some() {
+				if !self.exists() {
+					info!("Keyfile not found, creating new! {}", self.path().display());
+				}
+				if key.is_some() {
+					info!("New key provided, it will be sealed!");
+				}
+				let key = if let Some(key) = key {
+					key
+				} else {
+					let mut seed = [0u8; 32];
+					let mut rand = StdRng::new()?;
+					rand.fill_bytes(&mut seed);
+					seed
+				};
+				// always seal the reduced scalar so the sealed key is canonical
+				seal(&Pair::from_bytes(key).private_bytes(), self.path())?;
+			}
+			self.unseal_pair()
+		}
+	}
+
+	impl SealedIO for Seal {
+		type Error = Error;
+		type Unsealed = Pair;
+
+		fn unseal(&self) -> Result<Self::Unsealed> {
+			let raw = unseal(self.path())?;
+			let secret: [u8; 32] =
+				raw.try_into().map_err(|e| Error::Other(format!("{:?}", e).into()))?;
+			Ok(Pair::from_bytes(secret))
+		}
+
+		fn seal(&self, unsealed: &Self::Unsealed) -> Result<()> {
+			let raw = unsealed.private_bytes();
+			seal(&raw, self.path()).map_err(|e| e.into())
+		}
+	}
+}
+
+#[cfg(feature = "test")]
+pub mod sgx_tests {
+	use crate::{
+		frost::{create_frost_repository, Pair, Seal},
+		key_repository::AccessKey,
+		std::string::ToString,
+	};
+	use itp_sgx_temp_dir::TempDir;
+	use std::path::PathBuf;
+
+	static PRIVATE_KEY: &str = "189ab2ba2ace8ee33cb578c200766628e24083c5996441ba50097f200b9ea702";
+
+	pub fn frost_creating_repository_with_same_path_and_prefix_results_in_same_key() {
+		//given
+		let key_file_prefix = "test";
+		fn get_key_from_repo(path: PathBuf, prefix: &str) -> Pair {
+			create_frost_repository(path, prefix, None).unwrap().retrieve_key().unwrap()
+		}
+		let temp_dir = TempDir::with_prefix(
+			"frost_creating_repository_with_same_path_and_prefix_results_in_same_key",
+		)
+		.unwrap();
+		let temp_path = temp_dir.path().to_path_buf();
+
+		//when
+		let first_key = get_key_from_repo(temp_path.clone(), key_file_prefix);
+		let second_key = get_key_from_repo(temp_path.clone(), key_file_prefix);
+
+		//then
+		assert_eq!(first_key.public, second_key.public);
+	}
+
+	pub fn frost_seal_init_should_seal_provided_key() {
+		//given
+		let temp_dir = TempDir::with_prefix("frost_seal_init_should_seal_provided_key").unwrap();
+		let seal = Seal::new(temp_dir.path().to_path_buf(), "test".to_string());
+		assert!(!seal.exists());
+		let new_key: [u8; 32] = hex::decode(PRIVATE_KEY).unwrap().try_into().unwrap();
+
+		//when
+		let pair = seal.init(Some(new_key)).unwrap();
+
+		//then
+		assert!(seal.exists());
+		assert_eq!(pair.public_bytes(), Pair::from_bytes(new_key).public_bytes())
+	}
+}
@@ -37,6 +37,7 @@ pub mod ecdsa;
 pub mod ed25519;
 pub mod ed25519_derivation;
 pub mod error;
+pub mod frost;
 pub mod key_repository;
 pub mod rsa3072;
 pub mod schnorr;
@@ -81,4 +82,9 @@ pub mod tests {
 		schnorr_seal_init_should_seal_provided_key,
 		schnorr_seal_init_with_key_should_change_key_current_key,
 	};
+
+	pub use super::frost::sgx_tests::{
+		frost_creating_repository_with_same_path_and_prefix_results_in_same_key,
+		frost_seal_init_should_seal_provided_key,
+	};
 }
@@ -29,8 +29,9 @@ pub mod storage;
 
 pub use itp_sgx_runtime_primitives::types::*;
 pub use litentry_primitives::{
-	AttestationType, DcapProvider, DecryptableRequest, Enclave, EnclaveFingerprint, MrEnclave,
-	WorkerType,
+	AttestationType, CollateralBundle, DcapProvider, DecryptableRequest, Enclave,
+	EnclaveFingerprint, Fmspc, MrEnclave, QuotingEnclave, SignedCollateral, TcbInfoOnChain,
+	WorkerType, MAX_COLLATERAL_BUNDLE_LEN,
 };
 pub use sp_core::{crypto::AccountId32 as AccountId, H256};
 
@@ -3,7 +3,7 @@ use crate::{AccountId, Balance, BlockNumber, Hash, MrEnclave, ShardIdentifier, W
 use codec::{Decode, Encode};
 use core::fmt::Debug;
 use itp_utils::{hex::ToHexPrefixed, stringify::account_id_to_string};
-use litentry_primitives::{Address32, Identity};
+use litentry_primitives::{Address32, EvictionReason, Identity, RelayerRole};
 use substrate_api_client::ac_node_api::StaticEvent;
 
 #[derive(Encode, Decode, Debug)]
@@ -131,6 +131,24 @@ impl StaticEvent for EnclaveRemoved {
 	const EVENT: &'static str = "EnclaveRemoved";
 }
 
+#[derive(Encode, Decode, Debug)]
+pub struct EnclaveEvicted {
+	pub who: Address32,
+	pub reason: EvictionReason,
+}
+
+impl core::fmt::Display for EnclaveEvicted {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let message = format!("EnclaveEvicted :: who: {:?}, reason: {:?}", self.who, self.reason);
+		write!(f, "{}", message)
+	}
+}
+
+impl StaticEvent for EnclaveEvicted {
+	const PALLET: &'static str = "Teebag";
+	const EVENT: &'static str = "EnclaveEvicted";
+}
+
 //  Bitacross pallet events
 
 #[derive(Encode, Decode, Debug)]
@@ -154,6 +172,34 @@ impl StaticEvent for RelayerAdded {
 	const EVENT: &'static str = "RelayerAdded";
 }
 
+// a relayer was added or updated with a restricted role or an expiry, `RelayerAdded`
+// keeps its layout so that the events of past blocks can still be decoded
+#[derive(Encode, Decode, Debug)]
+pub struct RelayerSet {
+	pub who: Identity,
+	pub role: RelayerRole,
+	pub expiry: Option<BlockNumber>,
+}
+
+impl core::fmt::Display for RelayerSet {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		if let Some(account_id) = self.who.to_account_id() {
+			let message = format!(
+				"RelayerSet :: account_id: {:?}, role: {:?}, expiry: {:?}",
+				account_id, self.role, self.expiry
+			);
+			write!(f, "{}", message)
+		} else {
+			write!(f, "RelayerSet :: account_id: None, role: {:?}", self.role)
+		}
+	}
+}
+
+impl StaticEvent for RelayerSet {
+	const PALLET: &'static str = "Bitacross";
+	const EVENT: &'static str = "RelayerSet";
+}
+
 #[derive(Encode, Decode, Debug)]
 pub struct RelayerRemoved {
 	pub who: Identity,
@@ -193,3 +239,22 @@ impl StaticEvent for BtcWalletGenerated {
 	const PALLET: &'static str = "Bitacross";
 	const EVENT: &'static str = "BtcWalletGenerated";
 }
+
+#[derive(Encode, Decode, Debug)]
+pub struct SolWalletGenerated {
+	pub pub_key: [u8; 32],
+	pub account_id: AccountId,
+}
+
+impl core::fmt::Display for SolWalletGenerated {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let account_id = account_id_to_string::<AccountId>(&self.account_id);
+		let message = format!("SolWalletGenerated :: account_id: {:?}", account_id);
+		write!(f, "{}", message)
+	}
+}
+
+impl StaticEvent for SolWalletGenerated {
+	const PALLET: &'static str = "Bitacross";
+	const EVENT: &'static str = "SolWalletGenerated";
+}
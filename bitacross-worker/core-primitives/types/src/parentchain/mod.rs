@@ -22,8 +22,8 @@ use alloc::vec::Vec;
 use codec::{Decode, Encode};
 use core::fmt::Debug;
 use events::{
-	BalanceTransfer, BtcWalletGenerated, EnclaveAdded, EnclaveRemoved, EnclaveUnauthorized,
-	RelayerAdded, RelayerRemoved,
+	BalanceTransfer, BtcWalletGenerated, EnclaveAdded, EnclaveEvicted, EnclaveRemoved,
+	EnclaveUnauthorized, RelayerAdded, RelayerRemoved, RelayerSet, SolWalletGenerated,
 };
 use itp_stf_primitives::traits::{IndirectExecutor, TrustedCallVerification};
 #[cfg(feature = "std")]
@@ -103,11 +103,17 @@ pub trait FilterEvents {
 
 	fn get_relayers_removed_events(&self) -> Result<Vec<RelayerRemoved>, Self::Error>;
 
+	fn get_relayer_set_events(&self) -> Result<Vec<RelayerSet>, Self::Error>;
+
 	fn get_enclave_added_events(&self) -> Result<Vec<EnclaveAdded>, Self::Error>;
 
 	fn get_enclave_removed_events(&self) -> Result<Vec<EnclaveRemoved>, Self::Error>;
 
+	fn get_enclave_evicted_events(&self) -> Result<Vec<EnclaveEvicted>, Self::Error>;
+
 	fn get_btc_wallet_generated_events(&self) -> Result<Vec<BtcWalletGenerated>, Self::Error>;
+
+	fn get_sol_wallet_generated_events(&self) -> Result<Vec<SolWalletGenerated>, Self::Error>;
 }
 
 pub trait HandleParentchainEvents<Executor, TCS, Error, RRU, SRU, ERU>
@@ -131,6 +137,7 @@ pub enum ParentchainEventProcessingError {
 	EnclaveAddFailure,
 	EnclaveRemoveFailure,
 	BtcWalletGeneratedFailure,
+	SolWalletGeneratedFailure,
 }
 
 impl core::fmt::Display for ParentchainEventProcessingError {
@@ -152,6 +159,8 @@ impl core::fmt::Display for ParentchainEventProcessingError {
 				"Parentchain Event Processing Error: EnclaveRemoveFailure",
 			ParentchainEventProcessingError::BtcWalletGeneratedFailure =>
 				"Parentchain Event Processing Error: BtcWalletGeneratedFailure",
+			ParentchainEventProcessingError::SolWalletGeneratedFailure =>
+				"Parentchain Event Processing Error: SolWalletGeneratedFailure",
 		};
 		write!(f, "{}", message)
 	}
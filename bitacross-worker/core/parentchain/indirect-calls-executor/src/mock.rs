@@ -159,6 +159,12 @@ impl FilterEvents for MockEvents {
 		Ok(Vec::new())
 	}
 
+	fn get_relayer_set_events(
+		&self,
+	) -> Result<Vec<itp_types::parentchain::events::RelayerSet>, Self::Error> {
+		Ok(Vec::new())
+	}
+
 	fn get_enclave_added_events(
 		&self,
 	) -> Result<Vec<itp_types::parentchain::events::EnclaveAdded>, Self::Error> {
@@ -171,11 +177,23 @@ impl FilterEvents for MockEvents {
 		Ok(Vec::new())
 	}
 
+	fn get_enclave_evicted_events(
+		&self,
+	) -> Result<Vec<itp_types::parentchain::events::EnclaveEvicted>, Self::Error> {
+		Ok(Vec::new())
+	}
+
 	fn get_btc_wallet_generated_events(
 		&self,
 	) -> Result<Vec<itp_types::parentchain::events::BtcWalletGenerated>, Self::Error> {
 		Ok(Vec::new())
 	}
+
+	fn get_sol_wallet_generated_events(
+		&self,
+	) -> Result<Vec<itp_types::parentchain::events::SolWalletGenerated>, Self::Error> {
+		Ok(Vec::new())
+	}
 }
 
 pub struct MockParentchainEventHandler {}
@@ -51,7 +51,7 @@ use itp_settings::worker::MR_ENCLAVE_SIZE;
 use itp_sgx_crypto::{
 	ed25519_derivation::DeriveEd25519, key_repository::AccessKey, Error as SgxCryptoError,
 };
-use itp_types::{AttestationType, DcapProvider, OpaqueCall, WorkerType};
+use itp_types::{AttestationType, CollateralBundle, DcapProvider, OpaqueCall, WorkerType};
 use itp_utils::write_slice_and_whitespace_pad;
 use litentry_primitives::WorkerMode;
 use log::*;
@@ -486,6 +486,56 @@ pub unsafe extern "C" fn generate_register_tcb_info_extrinsic(
 	sgx_status_t::SGX_SUCCESS
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn generate_register_collateral_bundle_extrinsic(
+	bundle: *const u8,
+	bundle_size: u32,
+	unchecked_extrinsic: *mut u8,
+	unchecked_extrinsic_max_size: u32,
+	unchecked_extrinsic_size: *mut u32,
+) -> sgx_status_t {
+	if bundle.is_null() || unchecked_extrinsic.is_null() {
+		return sgx_status_t::SGX_ERROR_INVALID_PARAMETER
+	}
+	let extrinsic_slice =
+		slice::from_raw_parts_mut(unchecked_extrinsic, unchecked_extrinsic_max_size as usize);
+	let mut bundle_slice = slice::from_raw_parts(bundle, bundle_size as usize);
+	let bundle = match CollateralBundle::decode(&mut bundle_slice) {
+		Ok(b) => b,
+		Err(e) => {
+			error!("Could not decode collateral bundle: {:?}", e);
+			return sgx_status_t::SGX_ERROR_INVALID_PARAMETER
+		},
+	};
+
+	*unchecked_extrinsic_size =
+		match generate_register_collateral_bundle_extrinsic_internal(extrinsic_slice, &bundle) {
+			Ok(l) => l as u32,
+			Err(e) => return e.into(),
+		};
+	sgx_status_t::SGX_SUCCESS
+}
+
+fn generate_register_collateral_bundle_extrinsic_internal(
+	extrinsic_slice: &mut [u8],
+	bundle: &CollateralBundle,
+) -> EnclaveResult<usize> {
+	let node_metadata_repo = get_node_metadata_repository_from_integritee_solo_or_parachain()?;
+	let call_ids = node_metadata_repo
+		.get_from_metadata(|m| m.register_collateral_bundle_call_indexes())?
+		.map_err(MetadataProviderError::MetadataError)?;
+	info!(
+		"    [Enclave] Compose register collateral bundle call: {:?}, entries: {}",
+		call_ids,
+		bundle.len()
+	);
+	let call = OpaqueCall::from_tuple(&(call_ids, bundle));
+
+	let xt = create_extrinsics(call)?;
+	write_slice_and_whitespace_pad(extrinsic_slice, xt.encode())
+		.map_err(|e| format!("{:?}", e).into())
+}
+
 pub fn generate_generic_register_collateral_extrinsic<F>(
 	getter: F,
 	extrinsic_slice: &mut [u8],
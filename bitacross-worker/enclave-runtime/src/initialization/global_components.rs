@@ -30,6 +30,7 @@ use crate::{
 	rpc::rpc_response_channel::RpcResponseChannel,
 	tls_ra::seal_handler::SealHandler,
 };
+use bc_audit_log::AuditLog;
 use bc_enclave_registry::EnclaveRegistry;
 use bc_relayer_registry::RelayerRegistry;
 use bc_signer_registry::SignerRegistry;
@@ -66,6 +67,7 @@ use itp_node_api::{
 use itp_nonce_cache::NonceCache;
 use itp_sgx_crypto::{
 	ecdsa::{Pair as EcdsaPair, Seal as EcdsaSeal},
+	frost::{Pair as FrostPair, Seal as FrostSeal},
 	key_repository::KeyRepository,
 	schnorr::{Pair as SchnorrPair, Seal as SchnorrSeal},
 	Aes, AesSeal, Ed25519Seal, Rsa3072Seal,
@@ -103,6 +105,7 @@ pub type EnclaveShieldingKeyRepository = KeyRepository<Rsa3072KeyPair, Rsa3072Se
 pub type EnclaveSigningKeyRepository = KeyRepository<ed25519::Pair, Ed25519Seal>;
 pub type EnclaveBitcoinKeyRepository = KeyRepository<SchnorrPair, SchnorrSeal>;
 pub type EnclaveEthereumKeyRepository = KeyRepository<EcdsaPair, EcdsaSeal>;
+pub type EnclaveSolanaKeyRepository = KeyRepository<FrostPair, FrostSeal>;
 pub type EnclaveStateFileIo = SgxStateFileIo<EnclaveStateKeyRepository, StfState>;
 pub type EnclaveStateSnapshotRepository = StateSnapshotRepository<EnclaveStateFileIo>;
 pub type EnclaveStateObserver = StateObserver<StfState>;
@@ -356,6 +359,10 @@ pub static GLOBAL_ETHEREUM_KEY_REPOSITORY_COMPONENT: ComponentContainer<
 	EnclaveEthereumKeyRepository,
 > = ComponentContainer::new("Ethereum key repository");
 
+/// Solana key repository
+pub static GLOBAL_SOLANA_KEY_REPOSITORY_COMPONENT: ComponentContainer<EnclaveSolanaKeyRepository> =
+	ComponentContainer::new("Solana key repository");
+
 /// Light client db seal for the Integritee parentchain
 pub static GLOBAL_INTEGRITEE_PARENTCHAIN_LIGHT_CLIENT_SEAL: ComponentContainer<
 	EnclaveLightClientSeal,
@@ -452,3 +459,6 @@ pub static GLOBAL_SIGNER_REGISTRY: ComponentContainer<SignerRegistry> =
 /// Enclave registry
 pub static GLOBAL_ENCLAVE_REGISTRY: ComponentContainer<EnclaveRegistry> =
 	ComponentContainer::new("enclave_registry");
+
+/// Signing audit log
+pub static GLOBAL_AUDIT_LOG: ComponentContainer<AuditLog> = ComponentContainer::new("audit_log");
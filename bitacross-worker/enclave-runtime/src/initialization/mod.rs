@@ -32,9 +32,9 @@ use crate::{
 		GLOBAL_BITCOIN_KEY_REPOSITORY_COMPONENT, GLOBAL_ETHEREUM_KEY_REPOSITORY_COMPONENT,
 		GLOBAL_INTEGRITEE_PARENTCHAIN_LIGHT_CLIENT_SEAL, GLOBAL_OCALL_API_COMPONENT,
 		GLOBAL_RPC_WS_HANDLER_COMPONENT, GLOBAL_SHIELDING_KEY_REPOSITORY_COMPONENT,
-		GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT, GLOBAL_STATE_HANDLER_COMPONENT,
-		GLOBAL_STATE_KEY_REPOSITORY_COMPONENT, GLOBAL_STATE_OBSERVER_COMPONENT,
-		GLOBAL_TARGET_A_PARENTCHAIN_LIGHT_CLIENT_SEAL,
+		GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT, GLOBAL_SOLANA_KEY_REPOSITORY_COMPONENT,
+		GLOBAL_STATE_HANDLER_COMPONENT, GLOBAL_STATE_KEY_REPOSITORY_COMPONENT,
+		GLOBAL_STATE_OBSERVER_COMPONENT, GLOBAL_TARGET_A_PARENTCHAIN_LIGHT_CLIENT_SEAL,
 		GLOBAL_TARGET_B_PARENTCHAIN_LIGHT_CLIENT_SEAL, GLOBAL_TOP_POOL_AUTHOR_COMPONENT,
 		GLOBAL_WEB_SOCKET_SERVER_COMPONENT,
 	},
@@ -44,8 +44,12 @@ use crate::{
 	Hash,
 };
 use base58::ToBase58;
+use bc_audit_log::{AuditLog, AuditLogLookup, AuditLogUpdater};
 use bc_enclave_registry::EnclaveRegistryUpdater;
-use bc_musig2_ceremony::{CeremonyCommandTmp, CeremonyId, CeremonyRegistry, MuSig2Ceremony};
+use bc_frost_ceremony::{prove_key_possession, FrostCeremonyCommandTmp, FrostCeremonyRegistry};
+use bc_musig2_ceremony::{
+	store::CeremonyStore, CeremonyCommandTmp, CeremonyId, CeremonyRegistry, MuSig2Ceremony,
+};
 use bc_relayer_registry::{RelayerRegistry, RelayerRegistryUpdater};
 use bc_signer_registry::SignerRegistryUpdater;
 use bc_task_processor::{run_bit_across_handler_runner, BitAcrossTaskContext};
@@ -68,19 +72,23 @@ use itp_extrinsics_factory::CreateExtrinsics;
 use itp_node_api_metadata::pallet_bitacross::BitAcrossCallIndexes;
 use itp_node_api_metadata_provider::AccessNodeMetadata;
 use itp_primitives_cache::GLOBAL_PRIMITIVES_CACHE;
-use itp_settings::files::{
-	LITENTRY_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, STATE_SNAPSHOTS_CACHE_SIZE,
-	TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
+use itp_settings::{
+	files::{
+		LITENTRY_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, STATE_SNAPSHOTS_CACHE_SIZE,
+		TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
+	},
+	worker::AUDIT_LOG_ANCHOR_INTERVAL,
 };
 use itp_sgx_crypto::{
 	ecdsa::create_ecdsa_repository,
+	frost::{create_frost_repository, Pair as FrostPair, Seal as FrostSeal},
 	get_aes_repository, get_ed25519_repository, get_rsa3072_repository,
 	key_repository::{AccessKey, KeyRepository},
 	schnorr::{create_schnorr_repository, Pair as SchnorrPair, Seal},
 };
 
 use crate::initialization::global_components::{
-	GLOBAL_ENCLAVE_REGISTRY, GLOBAL_RELAYER_REGISTRY, GLOBAL_SIGNER_REGISTRY,
+	GLOBAL_AUDIT_LOG, GLOBAL_ENCLAVE_REGISTRY, GLOBAL_RELAYER_REGISTRY, GLOBAL_SIGNER_REGISTRY,
 };
 use bc_enclave_registry::EnclaveRegistry;
 use bc_signer_registry::SignerRegistry;
@@ -127,6 +135,12 @@ pub(crate) fn init_enclave(
 	let ethereum_key = ethereum_key_repository.retrieve_key()?;
 	info!("[Enclave initialized] Ethereum public key raw : {:?}", ethereum_key.public_bytes());
 
+	let solana_key_repository =
+		Arc::new(create_frost_repository(base_dir.clone(), "solana", None)?);
+	GLOBAL_SOLANA_KEY_REPOSITORY_COMPONENT.initialize(solana_key_repository.clone());
+	let solana_key = solana_key_repository.retrieve_key()?;
+	info!("[Enclave initialized] Solana public key raw : {:?}", solana_key.public_bytes());
+
 	let shielding_key_repository = Arc::new(get_rsa3072_repository(base_dir.clone())?);
 	GLOBAL_SHIELDING_KEY_REPOSITORY_COMPONENT.initialize(shielding_key_repository.clone());
 
@@ -223,23 +237,32 @@ pub(crate) fn init_enclave(
 	>::new()));
 
 	let ceremony_command_tmp = Arc::new(RwLock::new(CeremonyCommandTmp::new()));
+	let ceremony_store = Arc::new(CeremonyStore::new(base_dir.clone()));
+
+	let frost_ceremony_registry =
+		Arc::new(RwLock::new(FrostCeremonyRegistry::<KeyRepository<FrostPair, FrostSeal>>::new()));
+	let frost_ceremony_command_tmp = Arc::new(RwLock::new(FrostCeremonyCommandTmp::new()));
 
 	let attestation_handler =
 		Arc::new(IntelAttestationHandler::new(ocall_api.clone(), signing_key_repository.clone()));
 	GLOBAL_ATTESTATION_HANDLER_COMPONENT.initialize(attestation_handler);
 
-	let relayer_registry = RelayerRegistry::new(base_dir.clone());
+	let relayer_registry = Arc::new(RelayerRegistry::new(base_dir.clone()));
 	relayer_registry.init().map_err(|e| Error::Other(e.into()))?;
-	GLOBAL_RELAYER_REGISTRY.initialize(relayer_registry.into());
+	GLOBAL_RELAYER_REGISTRY.initialize(relayer_registry.clone());
 
 	let signer_registry = Arc::new(SignerRegistry::new(base_dir.clone()));
 	signer_registry.init().map_err(|e| Error::Other(e.into()))?;
 	GLOBAL_SIGNER_REGISTRY.initialize(signer_registry.clone());
 
-	let enclave_registry = Arc::new(EnclaveRegistry::new(base_dir));
+	let enclave_registry = Arc::new(EnclaveRegistry::new(base_dir.clone()));
 	enclave_registry.init().map_err(|e| Error::Other(e.into()))?;
 	GLOBAL_ENCLAVE_REGISTRY.initialize(enclave_registry);
 
+	let audit_log = Arc::new(AuditLog::new(base_dir));
+	audit_log.init().map_err(|e| Error::Other(e.into()))?;
+	GLOBAL_AUDIT_LOG.initialize(audit_log.clone());
+
 	let io_handler = public_api_rpc_handler(
 		top_pool_author,
 		getter_executor,
@@ -248,7 +271,10 @@ pub(crate) fn init_enclave(
 		signing_key_repository,
 		bitcoin_key_repository,
 		ethereum_key_repository,
+		solana_key_repository,
 		signer_registry,
+		audit_log,
+		relayer_registry,
 	);
 	let rpc_handler = Arc::new(RpcWsHandler::new(io_handler, watch_extractor, connection_registry));
 	GLOBAL_RPC_WS_HANDLER_COMPONENT.initialize(rpc_handler);
@@ -257,6 +283,9 @@ pub(crate) fn init_enclave(
 		run_bit_across_handler(
 			ceremony_registry,
 			ceremony_command_tmp,
+			ceremony_store,
+			frost_ceremony_registry,
+			frost_ceremony_command_tmp,
 			signer.public().0,
 			rpc_responder,
 			ceremony_commands_thread_count,
@@ -265,6 +294,8 @@ pub(crate) fn init_enclave(
 		.unwrap()
 	});
 
+	std::thread::spawn(move || run_audit_log_anchoring());
+
 	Ok(())
 }
 
@@ -278,12 +309,15 @@ pub(crate) fn finish_enclave_init() -> EnclaveResult<()> {
 pub(crate) fn init_wallets(base_dir: PathBuf) -> EnclaveResult<()> {
 	if_development_or!(
 		{
-			println!("Initializing wallets from BTC_KEY and ETH_KEY env variables");
+			println!("Initializing wallets from BTC_KEY, ETH_KEY and SOL_KEY env variables");
 			let btc_key: Option<[u8; 32]> = read_key_from_env("BTC_KEY")?;
 			create_schnorr_repository(base_dir.clone(), "bitcoin", btc_key)?;
 
 			let eth_key: Option<[u8; 32]> = read_key_from_env("ETH_KEY")?;
-			create_ecdsa_repository(base_dir, "ethereum", eth_key)?;
+			create_ecdsa_repository(base_dir.clone(), "ethereum", eth_key)?;
+
+			let sol_key: Option<[u8; 32]> = read_key_from_env("SOL_KEY")?;
+			create_frost_repository(base_dir, "solana", sol_key)?;
 		},
 		{
 			println!("Init wallets available in dev mode only!");
@@ -335,8 +369,69 @@ pub(crate) fn publish_wallets() -> EnclaveResult<()> {
 	let ethereum_opaque_call =
 		OpaqueCall::from_tuple(&(ethereum_call, ethereum_key.public_bytes()));
 
+	let solana_key_repository = GLOBAL_SOLANA_KEY_REPOSITORY_COMPONENT.get()?;
+	let solana_key = solana_key_repository.retrieve_key()?;
+
+	let solana_call = metadata_repository
+		.get_from_metadata(|m| m.sol_wallet_generated_indexes())
+		.map_err(|e| Error::Other(e.into()))?
+		.map_err(|e| Error::Other(format!("{:?}", e).into()))?;
+
+	// the key share is only accepted together with a proof that we own it
+	let enclave_account = GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT.get()?.retrieve_key()?.public().0;
+	let solana_key_proof = prove_key_possession(&solana_key, &enclave_account);
+	let solana_opaque_call =
+		OpaqueCall::from_tuple(&(solana_call, solana_key.public_bytes(), solana_key_proof));
+
+	let xts = extrinsics_factory
+		.create_extrinsics(&[bitcoin_opaque_call, ethereum_opaque_call, solana_opaque_call], None)
+		.map_err(|e| Error::Other(e.into()))?;
+	validator_accessor
+		.execute_mut_on_validator(|v| v.send_extrinsics(xts))
+		.map_err(|e| Error::Other(e.into()))?;
+
+	Ok(())
+}
+
+// periodically anchors the head of the signing audit log on the parentchain,
+// nothing is sent if the log didn't change since the last anchor
+fn run_audit_log_anchoring() {
+	let mut last_anchored: Option<(u64, H256)> = None;
+	loop {
+		std::thread::sleep(core::time::Duration::from_secs(AUDIT_LOG_ANCHOR_INTERVAL));
+		let head = match GLOBAL_AUDIT_LOG.get() {
+			Ok(audit_log) => audit_log.head(),
+			Err(e) => {
+				error!("Could not get audit log, reason: {:?}", e);
+				continue
+			},
+		};
+		if head.0 == 0 || Some(head) == last_anchored {
+			continue
+		}
+		match anchor_audit_log(head) {
+			Ok(()) => {
+				debug!("Anchored audit log head: {:?}, entries: {}", head.1, head.0);
+				last_anchored = Some(head);
+			},
+			Err(e) => error!("Could not anchor audit log, reason: {:?}", e),
+		}
+	}
+}
+
+fn anchor_audit_log((entries, head): (u64, H256)) -> EnclaveResult<()> {
+	let metadata_repository = get_node_metadata_repository_from_integritee_solo_or_parachain()?;
+	let extrinsics_factory = get_extrinsic_factory_from_integritee_solo_or_parachain()?;
+	let validator_accessor = get_validator_accessor_from_integritee_solo_or_parachain()?;
+
+	let call = metadata_repository
+		.get_from_metadata(|m| m.audit_log_anchored_indexes())
+		.map_err(|e| Error::Other(e.into()))?
+		.map_err(|e| Error::Other(format!("{:?}", e).into()))?;
+
+	let opaque_call = OpaqueCall::from_tuple(&(call, entries, head));
 	let xts = extrinsics_factory
-		.create_extrinsics(&[bitcoin_opaque_call, ethereum_opaque_call], None)
+		.create_extrinsics(&[opaque_call], None)
 		.map_err(|e| Error::Other(e.into()))?;
 	validator_accessor
 		.execute_mut_on_validator(|v| v.send_extrinsics(xts))
@@ -360,9 +455,15 @@ fn initialize_state_observer(
 	Ok(Arc::new(EnclaveStateObserver::from_map(states_map)))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn run_bit_across_handler(
 	ceremony_registry: Arc<RwLock<CeremonyRegistry<KeyRepository<SchnorrPair, Seal>>>>,
 	musig2_ceremony_pending_commands: Arc<RwLock<CeremonyCommandTmp>>,
+	musig2_ceremony_store: Arc<CeremonyStore>,
+	frost_ceremony_registry: Arc<
+		RwLock<FrostCeremonyRegistry<KeyRepository<FrostPair, FrostSeal>>>,
+	>,
+	frost_ceremony_pending_commands: Arc<RwLock<FrostCeremonyCommandTmp>>,
 	signing_key_pub: [u8; 32],
 	responder: Arc<
 		RpcResponder<ConnectionRegistry<H256, ConnectionToken>, H256, RpcResponseChannel>,
@@ -377,10 +478,12 @@ fn run_bit_across_handler(
 	let relayer_registry_lookup = GLOBAL_RELAYER_REGISTRY.get()?;
 	let enclave_registry_lookup = GLOBAL_ENCLAVE_REGISTRY.get()?;
 	let signer_registry_lookup = GLOBAL_SIGNER_REGISTRY.get()?;
+	let audit_log = GLOBAL_AUDIT_LOG.get()?;
 
 	let shielding_key_repository = GLOBAL_SHIELDING_KEY_REPOSITORY_COMPONENT.get()?;
 	let ethereum_key_repository = GLOBAL_ETHEREUM_KEY_REPOSITORY_COMPONENT.get()?;
 	let bitcoin_key_repository = GLOBAL_BITCOIN_KEY_REPOSITORY_COMPONENT.get()?;
+	let solana_key_repository = GLOBAL_SOLANA_KEY_REPOSITORY_COMPONENT.get()?;
 
 	#[allow(clippy::unwrap_used)]
 	let ocall_api = GLOBAL_OCALL_API_COMPONENT.get()?;
@@ -396,15 +499,20 @@ fn run_bit_across_handler(
 		signing_key,
 		ethereum_key_repository,
 		bitcoin_key_repository,
+		solana_key_repository,
 		stf_enclave_signer,
 		state_handler,
 		ocall_api,
 		relayer_registry_lookup,
 		enclave_registry_lookup,
 		signer_registry_lookup,
+		audit_log,
 		signing_key_pub,
 		ceremony_registry,
 		musig2_ceremony_pending_commands,
+		musig2_ceremony_store,
+		frost_ceremony_registry,
+		frost_ceremony_pending_commands,
 		responder,
 	);
 	run_bit_across_handler_runner(
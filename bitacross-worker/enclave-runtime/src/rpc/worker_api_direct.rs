@@ -22,11 +22,15 @@ use crate::{
 	},
 	initialization::global_components::{
 		EnclaveBitcoinKeyRepository, EnclaveEthereumKeyRepository, EnclaveSigningKeyRepository,
+		EnclaveSolanaKeyRepository,
 	},
 	std::string::ToString,
 	utils::get_validator_accessor_from_integritee_solo_or_parachain,
 };
-use bc_musig2_ceremony::{generate_aggregated_public_key, PublicKey};
+use bc_audit_log::{AuditLogLookup, AuditLogPage, AuditLogRequestSigned};
+use bc_frost_ceremony::generate_aggregated_public_key as generate_aggregated_solana_public_key;
+use bc_musig2_ceremony::{generate_aggregated_public_key, get_current_timestamp, PublicKey};
+use bc_relayer_registry::RelayerRegistryLookup;
 use bc_signer_registry::SignerRegistryLookup;
 use bc_task_sender::{BitAcrossProcessingResult, BitAcrossRequest, BitAcrossRequestSender};
 use codec::Encode;
@@ -72,7 +76,7 @@ fn get_all_rpc_methods_string(io_handler: &IoHandler) -> String {
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn public_api_rpc_handler<Author, GetterExecutor, AccessShieldingKey, OcallApi, SR>(
+pub fn public_api_rpc_handler<Author, GetterExecutor, AccessShieldingKey, OcallApi, SR, AL, RRL>(
 	top_pool_author: Arc<Author>,
 	getter_executor: Arc<GetterExecutor>,
 	shielding_key: Arc<AccessShieldingKey>,
@@ -80,7 +84,10 @@ pub fn public_api_rpc_handler<Author, GetterExecutor, AccessShieldingKey, OcallA
 	signing_key_repository: Arc<EnclaveSigningKeyRepository>,
 	bitcoin_key_repository: Arc<EnclaveBitcoinKeyRepository>,
 	ethereum_key_repository: Arc<EnclaveEthereumKeyRepository>,
+	solana_key_repository: Arc<EnclaveSolanaKeyRepository>,
 	signer_lookup: Arc<SR>,
+	audit_log: Arc<AL>,
+	relayer_registry: Arc<RRL>,
 ) -> IoHandler
 where
 	Author: AuthorApi<H256, H256, TrustedCallSigned, Getter> + Send + Sync + 'static,
@@ -90,6 +97,8 @@ where
 		ShieldingCryptoDecrypt + ShieldingCryptoEncrypt + DeriveEd25519 + Send + Sync + 'static,
 	OcallApi: EnclaveAttestationOCallApi + Send + Sync + 'static,
 	SR: SignerRegistryLookup + Send + Sync + 'static,
+	AL: AuditLogLookup + Send + Sync + 'static,
+	RRL: RelayerRegistryLookup + Send + Sync + 'static,
 {
 	let mut io = IoHandler::new();
 
@@ -203,6 +212,19 @@ where
 		}
 	});
 
+	let sol_signer_lookup = signer_lookup.clone();
+	io.add_sync_method("bitacross_aggregatedSolanaPublicKey", move |_: Params| {
+		debug!("worker_api_direct rpc was called: bitacross_aggregatedSolanaPublicKey");
+		let keys: Vec<[u8; 32]> =
+			sol_signer_lookup.get_all_sol().iter().map(|(_, pub_key)| *pub_key).collect();
+		if let Ok(key) = generate_aggregated_solana_public_key(&keys) {
+			let json_value = RpcReturnValue::new(key.to_vec(), false, DirectRequestStatus::Ok);
+			Ok(json!(json_value.to_hex()))
+		} else {
+			Ok(json!(compute_hex_encoded_return_error("Could not produce aggregate key")))
+		}
+	});
+
 	io.add_sync_method("bitacross_aggregatedPublicKey", move |_: Params| {
 		debug!("worker_api_direct rpc was called: bitacross_aggregatedPublicKey");
 		if let Ok(keys) = signer_lookup
@@ -237,10 +259,16 @@ where
 			Err(_e) => compute_hex_encoded_return_error("Can not obtain ethereum key"),
 		};
 
+		let solana_key = match solana_key_repository.retrieve_key() {
+			Ok(pair) => pair.public_bytes().to_hex(),
+			Err(_e) => compute_hex_encoded_return_error("Can not obtain solana key"),
+		};
+
 		Ok(json!({
 			"signer": signer,
 			"bitcoin_key": bitcoin_key,
-			"ethereum_key": ethereum_key
+			"ethereum_key": ethereum_key,
+			"solana_key": solana_key
 		}))
 	});
 
@@ -260,6 +288,18 @@ where
 		Ok(json!(keys))
 	});
 
+	// params: [hex encoded `AuditLogRequestSigned`], only registered relayers may read the log
+	io.add_sync_method("bitacross_getAuditLog", move |params: Params| {
+		debug!("worker_api_direct rpc was called: bitacross_getAuditLog");
+		let json_value =
+			match get_audit_log_inner(audit_log.as_ref(), relayer_registry.as_ref(), params) {
+				Ok(page) =>
+					RpcReturnValue::new(page.encode(), false, DirectRequestStatus::Ok).to_hex(),
+				Err(e) => compute_hex_encoded_return_error(e.as_str()),
+			};
+		Ok(json!(json_value))
+	});
+
 	io.add_sync_method("author_getShard", move |_: Params| {
 		debug!("worker_api_direct rpc was called: author_getShard");
 		let shard = top_pool_author.list_handled_shards().first().copied().unwrap_or_default();
@@ -441,6 +481,28 @@ fn execute_getter_inner<GE: ExecuteGetter>(
 	Ok(getter_result)
 }
 
+fn get_audit_log_inner<AL: AuditLogLookup, RRL: RelayerRegistryLookup>(
+	audit_log: &AL,
+	relayer_registry: &RRL,
+	params: Params,
+) -> Result<AuditLogPage, String> {
+	let hex_encoded_params = params.parse::<Vec<String>>().map_err(|e| format!("{:?}", e))?;
+
+	let param = &hex_encoded_params.get(0).ok_or("Could not get first param")?;
+	let signed = AuditLogRequestSigned::from_hex(param).map_err(|e| format!("{:?}", e))?;
+
+	if !signed.verify(get_current_timestamp()) {
+		return Err("Invalid or expired audit log request signature".to_owned())
+	}
+	if !relayer_registry.contains_key(&signed.signer) {
+		return Err("Audit log request signer is not a registered relayer".to_owned())
+	}
+
+	let (total, head) = audit_log.head();
+	let entries = audit_log.get_entries(signed.request.from, signed.request.limit);
+	Ok(AuditLogPage { total, head, entries })
+}
+
 fn forward_dcap_quote_inner(params: Params) -> Result<OpaqueExtrinsic, String> {
 	let hex_encoded_params = params.parse::<Vec<String>>().map_err(|e| format!("{:?}", e))?;
 
@@ -19,7 +19,7 @@
 use crate::{
 	initialization::global_components::{
 		GLOBAL_BITCOIN_KEY_REPOSITORY_COMPONENT, GLOBAL_ETHEREUM_KEY_REPOSITORY_COMPONENT,
-		GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT,
+		GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT, GLOBAL_SOLANA_KEY_REPOSITORY_COMPONENT,
 	},
 	rpc::worker_api_direct::public_api_rpc_handler,
 	test::{
@@ -28,7 +28,9 @@ use crate::{
 	},
 	Hash,
 };
-use bc_signer_registry::{PubKey, SignerRegistryLookup};
+use bc_audit_log::AuditLog;
+use bc_relayer_registry::RelayerRegistry;
+use bc_signer_registry::{PubKey, SignerRegistryLookup, SolPubKey};
 use codec::{Decode, Encode};
 use ita_stf::{Getter, PublicGetter};
 use itc_direct_rpc_server::{
@@ -59,6 +61,9 @@ impl SignerRegistryLookup for SignerRegistryMock {
 	fn get_all(&self) -> Vec<(Address32, PubKey)> {
 		vec![]
 	}
+	fn get_all_sol(&self) -> Vec<(Address32, SolPubKey)> {
+		vec![]
+	}
 }
 
 pub fn state_get_mrenclave_works() {
@@ -89,7 +94,10 @@ pub fn state_get_mrenclave_works() {
 		GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT.get().unwrap(),
 		GLOBAL_BITCOIN_KEY_REPOSITORY_COMPONENT.get().unwrap(),
 		GLOBAL_ETHEREUM_KEY_REPOSITORY_COMPONENT.get().unwrap(),
+		GLOBAL_SOLANA_KEY_REPOSITORY_COMPONENT.get().unwrap(),
 		signer_lookup,
+		Arc::new(AuditLog::default()),
+		Arc::new(RelayerRegistry::default()),
 	);
 	let rpc_handler = Arc::new(RpcWsHandler::new(io_handler, watch_extractor, connection_registry));
 
@@ -143,7 +151,10 @@ pub fn get_state_request_works() {
 		GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT.get().unwrap(),
 		GLOBAL_BITCOIN_KEY_REPOSITORY_COMPONENT.get().unwrap(),
 		GLOBAL_ETHEREUM_KEY_REPOSITORY_COMPONENT.get().unwrap(),
+		GLOBAL_SOLANA_KEY_REPOSITORY_COMPONENT.get().unwrap(),
 		signer_lookup,
+		Arc::new(AuditLog::default()),
+		Arc::new(RelayerRegistry::default()),
 	);
 	let rpc_handler = Arc::new(RpcWsHandler::new(io_handler, watch_extractor, connection_registry));
 
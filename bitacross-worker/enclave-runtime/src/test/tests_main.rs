@@ -97,6 +97,8 @@ pub extern "C" fn test_main_entrance() -> size_t {
 		itp_sgx_crypto::tests::schnorr_seal_init_should_seal_provided_key,
 		itp_sgx_crypto::tests::schnorr_seal_init_should_not_change_key_if_exists_and_not_provided,
 		itp_sgx_crypto::tests::schnorr_seal_init_with_key_should_change_key_current_key,
+		itp_sgx_crypto::tests::frost_creating_repository_with_same_path_and_prefix_results_in_same_key,
+		itp_sgx_crypto::tests::frost_seal_init_should_seal_provided_key,
 		test_submit_trusted_call_to_top_pool,
 		test_submit_trusted_getter_to_top_pool,
 		test_differentiate_getter_and_call_works,
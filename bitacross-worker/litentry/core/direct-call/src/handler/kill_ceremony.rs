@@ -15,7 +15,8 @@
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
 use bc_enclave_registry::EnclaveRegistryLookup;
-use bc_musig2_ceremony::CeremonyCommand;
+use bc_frost_ceremony::FrostCeremonyCommand;
+use bc_musig2_ceremony::{CeremonyCommand, CeremonyErrorReason};
 use codec::Encode;
 use litentry_primitives::Identity;
 
@@ -41,3 +42,18 @@ pub fn handle<ER: EnclaveRegistryLookup>(
 		_ => Err(KillCeremonyError::InvalidSigner),
 	}
 }
+
+pub fn handle_solana<ER: EnclaveRegistryLookup>(
+	signer: Identity,
+	enclave_registry: &ER,
+) -> Result<FrostCeremonyCommand, KillCeremonyError> {
+	handle(signer, enclave_registry).map(|_| FrostCeremonyCommand::KillCeremony)
+}
+
+pub fn handle_abort<ER: EnclaveRegistryLookup>(
+	signer: Identity,
+	reason: CeremonyErrorReason,
+	enclave_registry: &ER,
+) -> Result<CeremonyCommand, KillCeremonyError> {
+	handle(signer, enclave_registry).map(|_| CeremonyCommand::AbortCeremony(reason))
+}
@@ -65,9 +65,9 @@ pub mod test {
 	use bc_enclave_registry::{EnclaveRegistry, EnclaveRegistryUpdater};
 	use bc_musig2_ceremony::SignBitcoinPayload;
 	use bc_relayer_registry::{RelayerRegistry, RelayerRegistryUpdater};
-	use bc_signer_registry::{PubKey, SignerRegistryLookup};
+	use bc_signer_registry::{PubKey, SignerRegistryLookup, SolPubKey};
 	use itp_sgx_crypto::{key_repository::AccessKey, schnorr::Pair as SchnorrPair, Error};
-	use litentry_primitives::{Address32, Identity};
+	use litentry_primitives::{Address32, Identity, RelayerRole};
 	use sp_core::{sr25519, Pair};
 
 	struct SignersRegistryMock {}
@@ -102,6 +102,10 @@ pub mod test {
 				),
 			]
 		}
+
+		fn get_all_sol(&self) -> Vec<(Address32, SolPubKey)> {
+			vec![]
+		}
 	}
 
 	struct SignerAccess {}
@@ -121,7 +125,7 @@ pub mod test {
 		let enclave_registry = EnclaveRegistry::default();
 		let alice_key_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
 		let relayer_account = Identity::Substrate(alice_key_pair.public().into());
-		relayer_registry.update(relayer_account.clone()).unwrap();
+		relayer_registry.update(relayer_account.clone(), RelayerRole::Full).unwrap();
 		let signers_registry = Arc::new(SignersRegistryMock {});
 
 		// when
@@ -54,7 +54,7 @@ pub mod test {
 	use bc_relayer_registry::{RelayerRegistry, RelayerRegistryUpdater};
 	use itp_sgx_crypto::{ecdsa::Pair as EcdsaPair, mocks::KeyRepositoryMock};
 	use k256::{ecdsa::SigningKey, elliptic_curve::rand_core};
-	use litentry_primitives::Identity;
+	use litentry_primitives::{Identity, RelayerRole};
 	use sp_core::{sr25519, Pair};
 
 	#[test]
@@ -63,7 +63,7 @@ pub mod test {
 		let relayer_registry = RelayerRegistry::default();
 		let alice_key_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
 		let relayer_account = Identity::Substrate(alice_key_pair.public().into());
-		relayer_registry.update(relayer_account.clone()).unwrap();
+		relayer_registry.update(relayer_account.clone(), RelayerRole::Full).unwrap();
 
 		let private = SigningKey::random(&mut rand_core::OsRng);
 		let signing_key = EcdsaPair::new(private);
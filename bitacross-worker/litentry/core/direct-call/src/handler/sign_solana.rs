@@ -0,0 +1,156 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use bc_enclave_registry::EnclaveRegistryLookup;
+use bc_frost_ceremony::{FrostCeremonyCommand, SignSolanaPayload, SignersWithSolanaKeys};
+use bc_relayer_registry::RelayerRegistryLookup;
+use bc_signer_registry::SignerRegistryLookup;
+use codec::Encode;
+use litentry_primitives::Identity;
+use std::sync::Arc;
+
+#[derive(Encode, Debug)]
+pub enum SignSolanaError {
+	InvalidSigner,
+	CeremonyError,
+}
+
+pub fn handle<RRL: RelayerRegistryLookup, SR: SignerRegistryLookup, ER: EnclaveRegistryLookup>(
+	signer: Identity,
+	payload: SignSolanaPayload,
+	relayer_registry: &RRL,
+	signer_registry: Arc<SR>,
+	enclave_registry: &ER,
+) -> Result<FrostCeremonyCommand, SignSolanaError> {
+	if relayer_registry.contains_key(&signer)
+		|| match &signer {
+			Identity::Substrate(address) => enclave_registry.contains_key(address),
+			_ => false,
+		} {
+		let signers: SignersWithSolanaKeys = signer_registry
+			.get_all_sol()
+			.iter()
+			.map(|(address, pub_key)| (*address.as_ref(), *pub_key))
+			.collect();
+
+		Ok(FrostCeremonyCommand::InitCeremony(signers, payload))
+	} else {
+		Err(SignSolanaError::InvalidSigner)
+	}
+}
+
+#[cfg(test)]
+pub mod test {
+	use crate::handler::sign_solana::{handle, SignSolanaError};
+	use alloc::sync::Arc;
+	use bc_enclave_registry::{EnclaveRegistry, EnclaveRegistryUpdater};
+	use bc_frost_ceremony::{FrostCeremonyCommand, SignSolanaPayload};
+	use bc_relayer_registry::{RelayerRegistry, RelayerRegistryUpdater};
+	use bc_signer_registry::{PubKey, SignerRegistryLookup, SolPubKey};
+	use litentry_primitives::{Address32, Identity, RelayerRole};
+	use sp_core::{sr25519, Pair};
+
+	struct SignersRegistryMock {}
+
+	impl SignerRegistryLookup for SignersRegistryMock {
+		fn contains_key(&self, _account: &Address32) -> bool {
+			true
+		}
+
+		fn get_all(&self) -> Vec<(Address32, PubKey)> {
+			vec![]
+		}
+
+		fn get_all_sol(&self) -> Vec<(Address32, SolPubKey)> {
+			vec![
+				(Address32::from([0u8; 32]), [1u8; 32]),
+				(Address32::from([1u8; 32]), [2u8; 32]),
+				(Address32::from([2u8; 32]), [3u8; 32]),
+			]
+		}
+	}
+
+	#[test]
+	pub fn it_should_return_ok_for_relayer_signer() {
+		// given
+		let relayer_registry = RelayerRegistry::default();
+		let enclave_registry = EnclaveRegistry::default();
+		let alice_key_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let relayer_account = Identity::Substrate(alice_key_pair.public().into());
+		relayer_registry.update(relayer_account.clone(), RelayerRole::Full).unwrap();
+		let signers_registry = Arc::new(SignersRegistryMock {});
+
+		// when
+		let result = handle(
+			relayer_account,
+			SignSolanaPayload(vec![]),
+			&relayer_registry,
+			signers_registry,
+			&enclave_registry,
+		);
+
+		// then
+		assert!(
+			matches!(result, Ok(FrostCeremonyCommand::InitCeremony(signers, _)) if signers.len() == 3)
+		)
+	}
+
+	#[test]
+	pub fn it_should_return_ok_for_enclave_signer() {
+		// given
+		let relayer_registry = RelayerRegistry::default();
+		let enclave_registry = EnclaveRegistry::default();
+		let alice_key_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let enclave_account = Identity::Substrate(alice_key_pair.public().into());
+		enclave_registry.update(alice_key_pair.public().into(), "".to_string()).unwrap();
+		let signers_registry = Arc::new(SignersRegistryMock {});
+
+		// when
+		let result = handle(
+			enclave_account,
+			SignSolanaPayload(vec![]),
+			&relayer_registry,
+			signers_registry,
+			&enclave_registry,
+		);
+
+		// then
+		assert!(result.is_ok())
+	}
+
+	#[test]
+	pub fn it_should_return_err_for_non_relayer_and_non_enclave_signer() {
+		//given
+		let relayer_registry = RelayerRegistry::default();
+		let enclave_registry = EnclaveRegistry::default();
+
+		let alice_key_pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+		let non_relayer_account = Identity::Substrate(alice_key_pair.public().into());
+		let signers_registry = Arc::new(SignersRegistryMock {});
+
+		//when
+		let result = handle(
+			non_relayer_account,
+			SignSolanaPayload(vec![]),
+			&relayer_registry,
+			signers_registry,
+			&enclave_registry,
+		);
+
+		//then
+		assert!(matches!(result, Err(SignSolanaError::InvalidSigner)))
+	}
+}
@@ -20,7 +20,8 @@ extern crate alloc;
 #[cfg(all(not(feature = "std"), feature = "sgx"))]
 extern crate sgx_tstd as std;
 
-use bc_musig2_ceremony::SignBitcoinPayload;
+use bc_frost_ceremony::SignSolanaPayload;
+use bc_musig2_ceremony::{CeremonyErrorReason, SignBitcoinPayload};
 use codec::{Decode, Encode};
 use itp_stf_primitives::types::KeyPair;
 use litentry_primitives::{Identity, LitentryMultiSignature, ShardIdentifier};
@@ -51,6 +52,7 @@ pub enum DirectCall {
 	SignBitcoin(Identity, SignBitcoinPayload),
 	SignEthereum(Identity, PrehashedEthereumMessage),
 	CheckSignBitcoin(Identity),
+	SignSolana(Identity, SignSolanaPayload),
 }
 
 impl DirectCall {
@@ -59,6 +61,7 @@ impl DirectCall {
 			Self::SignBitcoin(signer, ..) => signer,
 			Self::SignEthereum(signer, ..) => signer,
 			Self::CheckSignBitcoin(signer) => signer,
+			Self::SignSolana(signer, ..) => signer,
 		}
 	}
 
@@ -100,6 +103,11 @@ pub enum CeremonyRoundCall {
 	NonceShare(Identity, SignBitcoinPayload, [u8; 66]),
 	PartialSignatureShare(Identity, SignBitcoinPayload, [u8; 32]),
 	KillCeremony(Identity, SignBitcoinPayload),
+	CommitmentsShare(Identity, SignSolanaPayload, [u8; 64]),
+	SignatureShare(Identity, SignSolanaPayload, [u8; 32]),
+	KillSolanaCeremony(Identity, SignSolanaPayload),
+	// sent by a signer that restarted and couldn't resume the ceremony
+	AbortCeremony(Identity, SignBitcoinPayload, CeremonyErrorReason),
 }
 
 impl CeremonyRoundCall {
@@ -108,6 +116,10 @@ impl CeremonyRoundCall {
 			Self::NonceShare(signer, ..) => signer,
 			Self::PartialSignatureShare(signer, ..) => signer,
 			Self::KillCeremony(signer, ..) => signer,
+			Self::CommitmentsShare(signer, ..) => signer,
+			Self::SignatureShare(signer, ..) => signer,
+			Self::KillSolanaCeremony(signer, ..) => signer,
+			Self::AbortCeremony(signer, ..) => signer,
 		}
 	}
 
@@ -44,13 +44,15 @@ use itp_sgx_crypto::ShieldingCryptoDecrypt;
 use litentry_hex_utils::hex_encode;
 use log::error;
 pub use pallet_teebag::{
-	decl_rsa_request, extract_tcb_info_from_raw_dcap_quote, AttestationType, DcapProvider, Enclave,
-	EnclaveFingerprint, MrEnclave, ShardIdentifier, SidechainBlockNumber, WorkerMode, WorkerType,
+	decl_rsa_request, extract_tcb_info_from_raw_dcap_quote, AttestationType, CollateralBundle,
+	DcapProvider, Enclave, EnclaveFingerprint, EvictionReason, Fmspc, MrEnclave, QuotingEnclave,
+	ShardIdentifier, SidechainBlockNumber, SignedCollateral, TcbInfoOnChain, WorkerMode,
+	WorkerType, MAX_COLLATERAL_BUNDLE_LEN,
 };
 pub use parentchain_primitives::{
 	identity::*, AccountId as ParentchainAccountId, Balance as ParentchainBalance,
 	BlockNumber as ParentchainBlockNumber, ErrorDetail, ErrorString, Hash as ParentchainHash,
-	Header as ParentchainHeader, Index as ParentchainIndex, ParameterString,
+	Header as ParentchainHeader, Index as ParentchainIndex, ParameterString, RelayerRole,
 	Signature as ParentchainSignature, MINUTES,
 };
 use scale_info::TypeInfo;
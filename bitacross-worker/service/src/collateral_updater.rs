@@ -0,0 +1,309 @@
+/*
+	Copyright 2021 Integritee AG and Supercomputing Systems AG
+	Copyright (C) 2017-2019 Baidu, Inc. All Rights Reserved.
+
+	Licensed under the Apache License, Version 2.0 (the "License");
+	you may not use this file except in compliance with the License.
+	You may obtain a copy of the License at
+
+		http://www.apache.org/licenses/LICENSE-2.0
+
+	Unless required by applicable law or agreed to in writing, software
+	distributed under the License is distributed on an "AS IS" BASIS,
+	WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+	See the License for the specific language governing permissions and
+	limitations under the License.
+
+*/
+
+//! Keeps the on-chain DCAP collateral (quoting enclave identity and TCB info) up to date by
+//! fetching it from a PCCS-compatible endpoint and submitting only the entries that changed.
+
+use crate::{
+	error::{Error, ServiceResult},
+	prometheus_metrics::{increment_collateral_update_failures, increment_collateral_updates},
+};
+use itp_node_api::api_client::{PalletTeebagApi, ParentchainApi};
+use itp_types::{
+	CollateralBundle, Fmspc, QuotingEnclave, SignedCollateral, TcbInfoOnChain,
+	MAX_COLLATERAL_BUNDLE_LEN,
+};
+use log::*;
+use regex::Regex;
+
+const QUOTING_ENCLAVE: &str = "quoting_enclave";
+const TCB_INFO: &str = "tcb_info";
+
+/// Collateral as served by the PCCS, together with its `issueDate` in unix epoch milliseconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchedCollateral {
+	pub issue_date: u64,
+	pub collateral: SignedCollateral,
+}
+
+/// Source of signed DCAP collateral.
+pub trait FetchCollateral {
+	fn fetch_quoting_enclave(&self) -> ServiceResult<FetchedCollateral>;
+
+	fn fetch_tcb_info(&self, fmspc: &Fmspc) -> ServiceResult<FetchedCollateral>;
+}
+
+/// Fetches collateral from a PCCS (or the Intel PCS) using the v4 certification API.
+pub struct PccsClient {
+	base_url: String,
+}
+
+impl PccsClient {
+	pub fn new(base_url: &str) -> Self {
+		Self { base_url: base_url.trim_end_matches('/').to_string() }
+	}
+
+	fn get(
+		&self,
+		path: &str,
+		issuer_chain_header: &str,
+		field: &str,
+	) -> ServiceResult<FetchedCollateral> {
+		let url = format!("{}/sgx/certification/v4/{}", self.base_url, path);
+		let response = reqwest::blocking::get(&url)
+			.and_then(|r| r.error_for_status())
+			.map_err(|e| Error::Custom(format!("Failed to fetch {}: {:?}", url, e).into()))?;
+
+		let issuer_chain = response
+			.headers()
+			.get(issuer_chain_header)
+			.and_then(|h| h.to_str().ok())
+			.ok_or_else(|| {
+				Error::Custom(format!("Missing {} header", issuer_chain_header).into())
+			})?;
+		let issuer_chain = urlencoding::decode(issuer_chain)
+			.map_err(|e| Error::Custom(format!("Invalid issuer chain: {:?}", e).into()))?
+			.to_string();
+
+		let body = response
+			.text()
+			.map_err(|e| Error::Custom(format!("Failed to read {}: {:?}", url, e).into()))?;
+		parse_signed_collateral(&body, field, issuer_chain.as_bytes())
+	}
+}
+
+impl FetchCollateral for PccsClient {
+	fn fetch_quoting_enclave(&self) -> ServiceResult<FetchedCollateral> {
+		self.get("qe/identity", "SGX-Enclave-Identity-Issuer-Chain", "enclaveIdentity")
+	}
+
+	fn fetch_tcb_info(&self, fmspc: &Fmspc) -> ServiceResult<FetchedCollateral> {
+		self.get(&format!("tcb?fmspc={}", hex::encode(fmspc)), "TCB-Info-Issuer-Chain", "tcbInfo")
+	}
+}
+
+/// Extract the signed part `field` and its signature from a PCCS response body.
+///
+/// The signed part is kept byte-for-byte as served, because the signature is over the raw json.
+pub fn parse_signed_collateral(
+	body: &str,
+	field: &str,
+	certificate_chain: &[u8],
+) -> ServiceResult<FetchedCollateral> {
+	let parse_error =
+		|what: &str| Error::Custom(format!("Failed to parse {}: {}", field, what).into());
+
+	let re = Regex::new(&format!(r#"{}\"\s?:(\{{.*\}}),\s?\"signature"#, field))
+		.map_err(|e| parse_error(&e.to_string()))?;
+	let data = re.captures(body).ok_or_else(|| parse_error("no signed data"))?[1].to_string();
+	let re =
+		Regex::new(r#"\"signature\"\s?:\s?\"(.*)\"\}"#).map_err(|e| parse_error(&e.to_string()))?;
+	let signature = &re.captures(body).ok_or_else(|| parse_error("no signature"))?[1];
+	let signature = hex::decode(signature).map_err(|e| parse_error(&e.to_string()))?;
+
+	let json: serde_json::Value =
+		serde_json::from_str(&data).map_err(|e| parse_error(&e.to_string()))?;
+	let issue_date = json["issueDate"].as_str().ok_or_else(|| parse_error("no issueDate"))?;
+	let issue_date = chrono::DateTime::parse_from_rfc3339(issue_date)
+		.map_err(|e| parse_error(&e.to_string()))?
+		.timestamp_millis() as u64;
+
+	Ok(FetchedCollateral {
+		issue_date,
+		collateral: SignedCollateral {
+			data: data.into_bytes(),
+			signature,
+			certificate_chain: certificate_chain.to_vec(),
+		},
+	})
+}
+
+/// Build a bundle of all collateral that is newer than what is registered on-chain.
+///
+/// Fetch failures are logged and counted, but don't prevent the other entries from being updated.
+pub fn collect_collateral_updates<F: FetchCollateral>(
+	fetcher: &F,
+	on_chain_quoting_enclave: Option<&QuotingEnclave>,
+	on_chain_tcb_infos: &[(Fmspc, Option<TcbInfoOnChain>)],
+) -> CollateralBundle {
+	let mut bundle = CollateralBundle::default();
+
+	match fetcher.fetch_quoting_enclave() {
+		Ok(fetched) =>
+			if on_chain_quoting_enclave.map_or(true, |qe| fetched.issue_date > qe.issue_date) {
+				bundle.quoting_enclave = Some(fetched.collateral);
+			},
+		Err(e) => {
+			warn!("Failed to fetch quoting enclave collateral: {:?}", e);
+			increment_collateral_update_failures(QUOTING_ENCLAVE);
+		},
+	}
+
+	for (fmspc, on_chain) in on_chain_tcb_infos {
+		match fetcher.fetch_tcb_info(fmspc) {
+			Ok(fetched) =>
+				if on_chain.as_ref().map_or(true, |tcb| fetched.issue_date > tcb.issue_date) {
+					bundle.tcb_infos.push(fetched.collateral);
+				},
+			Err(e) => {
+				warn!("Failed to fetch tcb info for fmspc {}: {:?}", hex::encode(fmspc), e);
+				increment_collateral_update_failures(TCB_INFO);
+			},
+		}
+	}
+	bundle
+}
+
+/// Split a bundle into chunks the pallet accepts in a single extrinsic.
+pub fn split_collateral_bundle(bundle: CollateralBundle) -> Vec<CollateralBundle> {
+	let mut chunks = vec![];
+	let mut current =
+		CollateralBundle { quoting_enclave: bundle.quoting_enclave, tcb_infos: vec![] };
+	for tcb_info in bundle.tcb_infos {
+		if current.len() == MAX_COLLATERAL_BUNDLE_LEN {
+			chunks.push(core::mem::take(&mut current));
+		}
+		current.tcb_infos.push(tcb_info);
+	}
+	if !current.is_empty() {
+		chunks.push(current);
+	}
+	chunks
+}
+
+/// Fetch collateral from the PCCS and submit every chunk of changed entries with `submit`.
+pub fn update_collateral<F, S>(api: &ParentchainApi, fetcher: &F, submit: S) -> ServiceResult<()>
+where
+	F: FetchCollateral,
+	S: Fn(&CollateralBundle) -> ServiceResult<()>,
+{
+	let quoting_enclave = api.quoting_enclave(None)?;
+	let tcb_infos = api
+		.all_tcb_info_fmspcs(None)?
+		.into_iter()
+		.map(|fmspc| Ok((fmspc, api.tcb_info(fmspc, None)?)))
+		.collect::<ServiceResult<Vec<_>>>()?;
+
+	let bundle = collect_collateral_updates(fetcher, quoting_enclave.as_ref(), &tcb_infos);
+	if bundle.is_empty() {
+		debug!("On-chain collateral is up to date");
+		return Ok(())
+	}
+	let updated_quoting_enclave = bundle.quoting_enclave.is_some();
+	let updated_tcb_infos = bundle.tcb_infos.len();
+
+	info!(
+		"Updating collateral: quoting enclave: {}, tcb infos: {}",
+		updated_quoting_enclave, updated_tcb_infos
+	);
+	for chunk in split_collateral_bundle(bundle) {
+		submit(&chunk)?;
+	}
+
+	if updated_quoting_enclave {
+		increment_collateral_updates(QUOTING_ENCLAVE, 1);
+	}
+	increment_collateral_updates(TCB_INFO, updated_tcb_infos as u64);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::mocks::pccs_mock::PccsMock;
+
+	const FMSPC_A: Fmspc = [0, 0x90, 0x6e, 0xa1, 0, 0];
+	const FMSPC_B: Fmspc = [0, 0x60, 0x6a, 0, 0, 0];
+
+	#[test]
+	fn parse_signed_collateral_works() {
+		let body = r#"{"tcbInfo":{"id":"SGX","version":3,"issueDate":"2022-11-17T12:45:32Z","fmspc":"00906EA10000"},"signature":"0a0b"}"#;
+
+		let fetched = parse_signed_collateral(body, "tcbInfo", b"chain").unwrap();
+
+		assert_eq!(fetched.issue_date, 1668689132000);
+		assert_eq!(
+			fetched.collateral.data,
+			br#"{"id":"SGX","version":3,"issueDate":"2022-11-17T12:45:32Z","fmspc":"00906EA10000"}"#
+				.to_vec()
+		);
+		assert_eq!(fetched.collateral.signature, vec![0x0a, 0x0b]);
+		assert_eq!(fetched.collateral.certificate_chain, b"chain".to_vec());
+	}
+
+	#[test]
+	fn parse_signed_collateral_fails_without_signature() {
+		let body = r#"{"enclaveIdentity":{"issueDate":"2022-11-17T12:45:32Z"}}"#;
+
+		assert!(parse_signed_collateral(body, "enclaveIdentity", b"chain").is_err());
+	}
+
+	#[test]
+	fn only_newer_collateral_is_collected() {
+		let pccs = PccsMock::default()
+			.with_quoting_enclave(100)
+			.with_tcb_info(FMSPC_A, 100)
+			.with_tcb_info(FMSPC_B, 200);
+		let on_chain_qe = QuotingEnclave { issue_date: 100, ..Default::default() };
+		let on_chain_tcb_infos = vec![
+			(FMSPC_A, Some(TcbInfoOnChain::new(100, 0, vec![]))),
+			(FMSPC_B, Some(TcbInfoOnChain::new(100, 0, vec![]))),
+		];
+
+		let bundle = collect_collateral_updates(&pccs, Some(&on_chain_qe), &on_chain_tcb_infos);
+
+		assert!(bundle.quoting_enclave.is_none());
+		assert_eq!(bundle.tcb_infos, vec![pccs.tcb_info(&FMSPC_B).collateral]);
+	}
+
+	#[test]
+	fn collateral_missing_on_chain_is_collected() {
+		let pccs = PccsMock::default().with_quoting_enclave(100).with_tcb_info(FMSPC_A, 100);
+
+		let bundle = collect_collateral_updates(&pccs, None, &[(FMSPC_A, None)]);
+
+		assert_eq!(bundle.len(), 2);
+	}
+
+	#[test]
+	fn fetch_failures_are_skipped() {
+		let pccs = PccsMock::default().with_tcb_info(FMSPC_A, 100);
+
+		let bundle = collect_collateral_updates(&pccs, None, &[(FMSPC_A, None), (FMSPC_B, None)]);
+
+		assert!(bundle.quoting_enclave.is_none());
+		assert_eq!(bundle.tcb_infos.len(), 1);
+	}
+
+	#[test]
+	fn split_collateral_bundle_respects_max_len() {
+		let pccs = PccsMock::default().with_quoting_enclave(100).with_tcb_info(FMSPC_A, 100);
+		let bundle = CollateralBundle {
+			quoting_enclave: Some(pccs.quoting_enclave().collateral),
+			tcb_infos: vec![pccs.tcb_info(&FMSPC_A).collateral; MAX_COLLATERAL_BUNDLE_LEN],
+		};
+
+		let chunks = split_collateral_bundle(bundle);
+
+		assert_eq!(chunks.len(), 2);
+		assert_eq!(chunks[0].len(), MAX_COLLATERAL_BUNDLE_LEN);
+		assert!(chunks[0].quoting_enclave.is_some());
+		assert_eq!(chunks[1].len(), 1);
+		assert!(chunks[1].quoting_enclave.is_none());
+	}
+}
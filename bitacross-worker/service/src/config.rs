@@ -304,6 +304,8 @@ pub struct RunConfig {
 	shard: Option<String>,
 	/// Marblerun's Prometheus endpoint base URL
 	marblerun_base_url: Option<String>,
+	/// Base URL of the PCCS-compatible endpoint to keep the on-chain DCAP collateral up to date.
+	pccs_base_url: Option<String>,
 	/// parentchain which should be used for shielding/unshielding the stf's native token
 	pub shielding_target: Option<ParentchainId>,
 }
@@ -327,6 +329,10 @@ impl RunConfig {
 		// https://github.com/edgelesssys/marblerun/blob/master/docs/docs/workflows/monitoring.md?plain=1#L26
 		self.marblerun_base_url.as_deref().unwrap_or("http://localhost:9944")
 	}
+
+	pub fn pccs_base_url(&self) -> Option<&str> {
+		self.pccs_base_url.as_deref()
+	}
 }
 
 impl From<&ArgMatches<'_>> for RunConfig {
@@ -341,6 +347,12 @@ impl From<&ArgMatches<'_>> for RunConfig {
 				.to_string()
 		});
 
+		let pccs_base_url = m.value_of("pccs-url").map(|i| {
+			Url::parse(i)
+				.unwrap_or_else(|e| panic!("pccs-url parsing error: {:?}", e))
+				.to_string()
+		});
+
 		let shielding_target = m.value_of("shielding-target").map(|i| match i {
 			"litentry" => ParentchainId::Litentry,
 			"target_a" => ParentchainId::TargetA,
@@ -351,7 +363,7 @@ impl From<&ArgMatches<'_>> for RunConfig {
 			),
 		});
 
-		Self { skip_ra, dev, shard, marblerun_base_url, shielding_target }
+		Self { skip_ra, dev, shard, marblerun_base_url, pccs_base_url, shielding_target }
 	}
 }
 
@@ -497,6 +509,7 @@ mod test {
 		assert_eq!(run_config.dev, false);
 		assert_eq!(run_config.skip_ra, false);
 		assert!(run_config.shard.is_none());
+		assert!(run_config.pccs_base_url().is_none());
 	}
 
 	#[test]
@@ -19,6 +19,7 @@
 #![allow(unused)]
 
 mod account_funding;
+mod collateral_updater;
 mod config;
 mod enclave;
 mod error;
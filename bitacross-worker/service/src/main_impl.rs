@@ -58,8 +58,14 @@ use sp_core::crypto::{AccountId32, Ss58Codec};
 use sp_keyring::AccountKeyring;
 use sp_runtime::MultiSigner;
 use std::{
-	collections::HashSet, fmt::Debug, path::PathBuf, str, str::Utf8Error, sync::Arc, thread,
-	time::Duration,
+	collections::HashSet,
+	fmt::Debug,
+	path::PathBuf,
+	str,
+	str::Utf8Error,
+	sync::Arc,
+	thread,
+	time::{Duration, Instant},
 };
 use substrate_api_client::ac_node_api::{EventRecord, Phase::ApplyExtrinsic};
 
@@ -395,6 +401,17 @@ fn start_worker<E, T, InitializationHandler>(
 	#[cfg(feature = "dcap")]
 	register_collateral(&litentry_rpc_api, &*enclave, &tee_accountid, is_development_mode, skip_ra);
 
+	#[cfg(feature = "dcap")]
+	if let Some(pccs_base_url) = run_config.pccs_base_url() {
+		start_collateral_update_thread(
+			litentry_rpc_api.clone(),
+			enclave.clone(),
+			tee_accountid.clone(),
+			is_development_mode,
+			pccs_base_url.to_string(),
+		);
+	}
+
 	let trusted_url = config.trusted_worker_url_external();
 
 	#[cfg(feature = "attesteer")]
@@ -463,6 +480,8 @@ fn start_worker<E, T, InitializationHandler>(
 		.expect("our enclave should be registered at this point");
 	trace!("verified that our enclave is registered: {:?}", my_enclave);
 
+	start_periodic_reattestation_thread(litentry_rpc_api.clone(), send_register_xt.clone());
+
 	// Litentry:
 	// the logic differs from upstream a bit here (due to different impl in parachain pallet),
 	// theoretically the `primary_enclave_identifier_for_shard` should never be empty, unless the previous
@@ -820,6 +839,80 @@ fn register_collateral(
 	}
 }
 
+/// Periodically fetch the DCAP collateral from the PCCS and register the entries that are newer
+/// than the on-chain ones.
+#[cfg(feature = "dcap")]
+fn start_collateral_update_thread<E>(
+	api: ParentchainApi,
+	enclave: Arc<E>,
+	accountid: AccountId32,
+	is_development_mode: bool,
+	pccs_base_url: String,
+) where
+	E: RemoteAttestation + Sync + Send + 'static,
+{
+	use crate::collateral_updater::{update_collateral, PccsClient};
+	const POLL_INTERVAL_SECS: u64 = 60 * 60;
+
+	thread::Builder::new()
+		.name("collateral_update_loop".to_owned())
+		.spawn(move || {
+			let pccs = PccsClient::new(&pccs_base_url);
+			loop {
+				info!("Checking {} for collateral updates", pccs_base_url);
+				let submit = |bundle: &_| {
+					let xt = enclave.generate_register_collateral_bundle_extrinsic(bundle)?;
+					send_litentry_extrinsic(xt, &api, &accountid, is_development_mode).map(|_| ())
+				};
+				if let Err(e) = update_collateral(&api, &pccs, submit) {
+					error!("Failed to update collateral: {:?}", e);
+				}
+				thread::sleep(Duration::from_secs(POLL_INTERVAL_SECS));
+			}
+		})
+		.unwrap();
+}
+
+/// Re-register the enclave after half of the on-chain `ReattestationPeriod`, so that it's never
+/// evicted for an expired attestation.
+fn start_periodic_reattestation_thread<F>(api: ParentchainApi, send_register_xt: F)
+where
+	F: Fn() -> ServiceResult<Hash> + Send + 'static,
+{
+	const MIN_INTERVAL_SECS: u64 = 60;
+	const POLL_INTERVAL_SECS: u64 = 60 * 60;
+
+	thread::Builder::new()
+		.name("reattestation_loop".to_owned())
+		.spawn(move || {
+			let mut last_attested = Instant::now();
+			loop {
+				let half_period = api
+					.reattestation_period(None)
+					.map_err(|e| warn!("Failed to get the re-attestation period: {:?}", e))
+					.ok()
+					.flatten()
+					.map(|period| Duration::from_millis(period / 2));
+
+				if let Some(half_period) = half_period {
+					if last_attested.elapsed() >= half_period {
+						info!("Re-attesting enclave");
+						match send_register_xt() {
+							Ok(_) => last_attested = Instant::now(),
+							Err(e) => error!("Failed to re-attest enclave: {:?}", e),
+						}
+					}
+				}
+
+				let poll_interval = Duration::from_secs(POLL_INTERVAL_SECS);
+				thread::sleep(half_period.map_or(poll_interval, |p| {
+					p.clamp(Duration::from_secs(MIN_INTERVAL_SECS), poll_interval)
+				}));
+			}
+		})
+		.unwrap();
+}
+
 fn send_litentry_extrinsic(
 	extrinsic: Vec<u8>,
 	api: &ParentchainApi,
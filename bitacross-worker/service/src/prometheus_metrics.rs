@@ -36,9 +36,9 @@ use itp_enclave_metrics::EnclaveMetric;
 use lazy_static::lazy_static;
 use log::*;
 use prometheus::{
-	proto::MetricFamily, register_counter, register_histogram, register_histogram_vec,
-	register_int_gauge, register_int_gauge_vec, Counter, Histogram, HistogramVec, IntGauge,
-	IntGaugeVec,
+	proto::MetricFamily, register_counter, register_counter_vec, register_histogram,
+	register_histogram_vec, register_int_gauge, register_int_gauge_vec, Counter, CounterVec,
+	Histogram, HistogramVec, IntGauge, IntGaugeVec,
 };
 use serde::{Deserialize, Serialize};
 use std::{net::SocketAddr, sync::Arc};
@@ -52,19 +52,41 @@ lazy_static! {
 	static ref MUSIG2_CEREMONIES_STARTED: Counter =
 		register_counter!("bitacross_worker_ceremonies_started", "Musig2 ceremonies started")
 			.unwrap();
-	static ref MUSIG2_CEREMONIES_FAILED: Counter =
-		register_counter!("bitacross_worker_ceremonies_failed", "Musig2 ceremonies failed")
+	static ref MUSIG2_CEREMONIES_FAILED: CounterVec =
+		register_counter_vec!("bitacross_worker_ceremonies_failed", "Musig2 ceremonies failed", &["reason"])
+			.unwrap();
+	static ref MUSIG2_CEREMONIES_COMPLETED: Counter =
+		register_counter!("bitacross_worker_ceremonies_completed", "Musig2 ceremonies completed")
 			.unwrap();
 	static ref MUSIG2_CEREMONIES_TIMED_OUT: Counter =
 		register_counter!("bitacross_worker_ceremonies_timed_out", "Musig2 ceremonies timed out")
 			.unwrap();
 	static ref MUSIG2_CEREMONY_DURATION: Histogram =
-		register_histogram!("bitacross_worker_ceremony_duration", "Time taken to perform musig2 ceremony", vec![0.0005, 0.005, 0.01, 0.025, 0.05, 0.1])
+		register_histogram!("bitacross_worker_ceremony_duration", "Time taken to perform musig2 ceremony", CEREMONY_LATENCY_BUCKETS.to_vec())
+			.unwrap();
+	static ref MUSIG2_CEREMONY_ROUND_DURATION: HistogramVec =
+		register_histogram_vec!("bitacross_worker_ceremony_round_duration", "Time taken to perform a round of musig2 ceremony", &["round"], CEREMONY_LATENCY_BUCKETS.to_vec())
+			.unwrap();
+	static ref MUSIG2_PEER_NONCE_DELAY: HistogramVec =
+		register_histogram_vec!("bitacross_worker_ceremony_peer_nonce_delay", "Time from the start of the first round until the peer's nonce arrived", &["peer"], CEREMONY_LATENCY_BUCKETS.to_vec())
+			.unwrap();
+	static ref MUSIG2_PEER_PARTIAL_SIGNATURE_DELAY: HistogramVec =
+		register_histogram_vec!("bitacross_worker_ceremony_peer_partial_signature_delay", "Time from the start of the second round until the peer's partial signature arrived", &["peer"], CEREMONY_LATENCY_BUCKETS.to_vec())
+			.unwrap();
+	static ref MUSIG2_ACTIVE_CEREMONIES: IntGauge =
+		register_int_gauge!("bitacross_worker_active_ceremonies", "Musig2 ceremonies in progress")
+			.unwrap();
+	static ref COLLATERAL_UPDATES: CounterVec =
+		register_counter_vec!("bitacross_worker_collateral_updates", "DCAP collateral entries updated on-chain", &["kind"])
+			.unwrap();
+	static ref COLLATERAL_UPDATE_FAILURES: CounterVec =
+		register_counter_vec!("bitacross_worker_collateral_update_failures", "DCAP collateral entries that could not be fetched", &["kind"])
 			.unwrap();
-
-
 }
 
+/// Buckets in seconds for ceremony latencies, which include network round trips between signers.
+const CEREMONY_LATENCY_BUCKETS: [f64; 10] = [0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
 pub async fn start_metrics_server<MetricsHandler>(
 	metrics_handler: Arc<MetricsHandler>,
 	port: u16,
@@ -130,6 +152,14 @@ fn gather_metrics_into_reply(metrics: &[MetricFamily]) -> ServiceResult<String>
 	Ok(result_string)
 }
 
+pub fn increment_collateral_updates(kind: &str, count: u64) {
+	COLLATERAL_UPDATES.with_label_values(&[kind]).inc_by(count as f64);
+}
+
+pub fn increment_collateral_update_failures(kind: &str) {
+	COLLATERAL_UPDATE_FAILURES.with_label_values(&[kind]).inc();
+}
+
 /// Trait to receive metric updates from inside the enclave.
 pub trait ReceiveEnclaveMetrics {
 	fn receive_enclave_metric(&self, metric: EnclaveMetric) -> ServiceResult<()>;
@@ -143,13 +173,28 @@ impl ReceiveEnclaveMetrics for EnclaveMetricsReceiver {
 			EnclaveMetric::ParentchainBlockImportTime(time) =>
 				ENCLAVE_PARENTCHAIN_BLOCK_IMPORT_TIME.observe(time.as_secs_f64()),
 			EnclaveMetric::Musig2CeremonyStarted => MUSIG2_CEREMONIES_STARTED.inc(),
-			EnclaveMetric::Musig2CeremonyFailed => MUSIG2_CEREMONIES_FAILED.inc(),
+			EnclaveMetric::Musig2CeremonyFailed(reason) =>
+				MUSIG2_CEREMONIES_FAILED.with_label_values(&[reason.as_str()]).inc(),
 			EnclaveMetric::Musig2CeremonyTimedout(count) =>
 				for i in 0..count {
 					MUSIG2_CEREMONIES_TIMED_OUT.inc()
 				},
 			EnclaveMetric::Musig2CeremonyDuration(time) =>
 				MUSIG2_CEREMONY_DURATION.observe(time.as_secs_f64()),
+			EnclaveMetric::Musig2CeremonyCompleted => MUSIG2_CEREMONIES_COMPLETED.inc(),
+			EnclaveMetric::Musig2CeremonyRoundDuration(round, time) =>
+				MUSIG2_CEREMONY_ROUND_DURATION
+					.with_label_values(&[round.to_string().as_str()])
+					.observe(time.as_secs_f64()),
+			EnclaveMetric::Musig2PeerNonceDelay(peer, time) => MUSIG2_PEER_NONCE_DELAY
+				.with_label_values(&[hex::encode(peer).as_str()])
+				.observe(time.as_secs_f64()),
+			EnclaveMetric::Musig2PeerPartialSignatureDelay(peer, time) =>
+				MUSIG2_PEER_PARTIAL_SIGNATURE_DELAY
+					.with_label_values(&[hex::encode(peer).as_str()])
+					.observe(time.as_secs_f64()),
+			EnclaveMetric::Musig2ActiveCeremonies(count) =>
+				MUSIG2_ACTIVE_CEREMONIES.set(count as i64),
 		}
 		Ok(())
 	}
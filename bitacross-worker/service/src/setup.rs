@@ -18,8 +18,9 @@
 
 use crate::error::{Error, ServiceResult};
 use itp_settings::files::{
-	ENCLAVE_REGISTRY_FILE, LITENTRY_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, RELAYER_REGISTRY_FILE,
-	SHARDS_PATH, SIGNER_REGISTRY_FILE, TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
+	ENCLAVE_REGISTRY_FILE, LITENTRY_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, MUSIG2_CEREMONIES_FILE,
+	RELAYER_REGISTRY_FILE, RELAYER_REGISTRY_V1_FILE, SHARDS_PATH, SIGNER_REGISTRY_FILE,
+	SOL_SIGNER_REGISTRY_FILE, TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
 	TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
 };
 use std::{fs, path::Path};
@@ -146,8 +147,11 @@ fn purge_files(root_directory: &Path) -> ServiceResult<()> {
 	remove_dir_if_it_exists(root_directory, TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH)?;
 
 	remove_file_if_it_exists(root_directory, RELAYER_REGISTRY_FILE)?;
+	remove_file_if_it_exists(root_directory, RELAYER_REGISTRY_V1_FILE)?;
 	remove_file_if_it_exists(root_directory, ENCLAVE_REGISTRY_FILE)?;
 	remove_file_if_it_exists(root_directory, SIGNER_REGISTRY_FILE)?;
+	remove_file_if_it_exists(root_directory, SOL_SIGNER_REGISTRY_FILE)?;
+	remove_file_if_it_exists(root_directory, MUSIG2_CEREMONIES_FILE)?;
 	Ok(())
 }
 
@@ -171,7 +175,8 @@ fn remove_file_if_it_exists(root_directory: &Path, file_name: &str) -> ServiceRe
 mod tests {
 	use super::*;
 	use itp_settings::files::{
-		SHARDS_PATH, SIGNER_REGISTRY_FILE, TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
+		MUSIG2_CEREMONIES_FILE, SHARDS_PATH, SIGNER_REGISTRY_FILE, SOL_SIGNER_REGISTRY_FILE,
+		TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
 	};
 	use std::{fs, path::PathBuf};
 
@@ -187,8 +192,11 @@ mod tests {
 		fs::File::create(&shards_path.join("state_2.bin")).unwrap();
 
 		fs::File::create(&root_directory.join(RELAYER_REGISTRY_FILE)).unwrap();
+		fs::File::create(&root_directory.join(RELAYER_REGISTRY_V1_FILE)).unwrap();
 		fs::File::create(&root_directory.join(ENCLAVE_REGISTRY_FILE)).unwrap();
 		fs::File::create(&root_directory.join(SIGNER_REGISTRY_FILE)).unwrap();
+		fs::File::create(&root_directory.join(SOL_SIGNER_REGISTRY_FILE)).unwrap();
+		fs::File::create(&root_directory.join(MUSIG2_CEREMONIES_FILE)).unwrap();
 
 		fs::create_dir_all(&root_directory.join(LITENTRY_PARENTCHAIN_LIGHT_CLIENT_DB_PATH))
 			.unwrap();
@@ -204,8 +212,11 @@ mod tests {
 		assert!(!root_directory.join(TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH).exists());
 		assert!(!root_directory.join(TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH).exists());
 		assert!(!root_directory.join(RELAYER_REGISTRY_FILE).exists());
+		assert!(!root_directory.join(RELAYER_REGISTRY_V1_FILE).exists());
 		assert!(!root_directory.join(ENCLAVE_REGISTRY_FILE).exists());
 		assert!(!root_directory.join(SIGNER_REGISTRY_FILE).exists());
+		assert!(!root_directory.join(SOL_SIGNER_REGISTRY_FILE).exists());
+		assert!(!root_directory.join(MUSIG2_CEREMONIES_FILE).exists());
 	}
 
 	#[test]
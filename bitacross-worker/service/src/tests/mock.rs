@@ -16,7 +16,10 @@
 */
 
 use itp_node_api::api_client::{ApiResult, PalletTeebagApi};
-use itp_types::{AccountId, Enclave, MrEnclave, ShardIdentifier, WorkerType, H256 as Hash};
+use itp_types::{
+	AccountId, Enclave, Fmspc, MrEnclave, QuotingEnclave, ShardIdentifier, TcbInfoOnChain,
+	WorkerType, H256 as Hash,
+};
 use std::collections::HashSet;
 
 pub struct TestNodeApi;
@@ -66,4 +69,24 @@ impl PalletTeebagApi for TestNodeApi {
 	) -> ApiResult<Option<Enclave>> {
 		unreachable!()
 	}
+
+	fn reattestation_period(&self, _at_block: Option<Hash>) -> ApiResult<Option<u64>> {
+		unreachable!()
+	}
+
+	fn quoting_enclave(&self, _at_block: Option<Hash>) -> ApiResult<Option<QuotingEnclave>> {
+		unreachable!()
+	}
+
+	fn tcb_info(
+		&self,
+		_fmspc: Fmspc,
+		_at_block: Option<Hash>,
+	) -> ApiResult<Option<TcbInfoOnChain>> {
+		unreachable!()
+	}
+
+	fn all_tcb_info_fmspcs(&self, _at_block: Option<Hash>) -> ApiResult<Vec<Fmspc>> {
+		unreachable!()
+	}
 }
@@ -18,4 +18,5 @@
 pub mod enclave_api_mock;
 pub mod initialization_handler_mock;
 pub mod parentchain_api_mock;
+pub mod pccs_mock;
 pub mod update_worker_peers_mock;
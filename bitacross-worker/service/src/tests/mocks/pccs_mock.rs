@@ -0,0 +1,78 @@
+/*
+	Copyright 2021 Integritee AG and Supercomputing Systems AG
+
+	Licensed under the Apache License, Version 2.0 (the "License");
+	you may not use this file except in compliance with the License.
+	You may obtain a copy of the License at
+
+		http://www.apache.org/licenses/LICENSE-2.0
+
+	Unless required by applicable law or agreed to in writing, software
+	distributed under the License is distributed on an "AS IS" BASIS,
+	WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+	See the License for the specific language governing permissions and
+	limitations under the License.
+
+*/
+
+use crate::{
+	collateral_updater::{FetchCollateral, FetchedCollateral},
+	error::{Error, ServiceResult},
+};
+use itp_types::{Fmspc, SignedCollateral};
+use std::collections::HashMap;
+
+/// PCCS serving fixed collateral, distinguishable by its issue date.
+#[derive(Default)]
+pub struct PccsMock {
+	quoting_enclave: Option<FetchedCollateral>,
+	tcb_infos: HashMap<Fmspc, FetchedCollateral>,
+}
+
+impl PccsMock {
+	pub fn with_quoting_enclave(mut self, issue_date: u64) -> Self {
+		self.quoting_enclave = Some(collateral(issue_date, b"qe"));
+		self
+	}
+
+	pub fn with_tcb_info(mut self, fmspc: Fmspc, issue_date: u64) -> Self {
+		self.tcb_infos.insert(fmspc, collateral(issue_date, &fmspc));
+		self
+	}
+
+	pub fn quoting_enclave(&self) -> FetchedCollateral {
+		self.quoting_enclave.clone().unwrap()
+	}
+
+	pub fn tcb_info(&self, fmspc: &Fmspc) -> FetchedCollateral {
+		self.tcb_infos[fmspc].clone()
+	}
+}
+
+impl FetchCollateral for PccsMock {
+	fn fetch_quoting_enclave(&self) -> ServiceResult<FetchedCollateral> {
+		self.quoting_enclave
+			.clone()
+			.ok_or_else(|| Error::Custom("no quoting enclave collateral".into()))
+	}
+
+	fn fetch_tcb_info(&self, fmspc: &Fmspc) -> ServiceResult<FetchedCollateral> {
+		self.tcb_infos
+			.get(fmspc)
+			.cloned()
+			.ok_or_else(|| Error::Custom("no tcb info collateral".into()))
+	}
+}
+
+fn collateral(issue_date: u64, id: &[u8]) -> FetchedCollateral {
+	let mut data = id.to_vec();
+	data.extend(issue_date.to_le_bytes());
+	FetchedCollateral {
+		issue_date,
+		collateral: SignedCollateral {
+			data,
+			signature: vec![1u8; 64],
+			certificate_chain: b"chain".to_vec(),
+		},
+	}
+}
@@ -26,10 +26,11 @@ use frame_support::{
 		tokens::{
 			fungible::{Inspect as FInspect, Mutate as FMutate},
 			fungibles::Mutate as FsMutate,
-			Fortitude, Precision,
+			Fortitude, Precision, Preservation,
 		},
 		StorageVersion,
 	},
+	PalletId,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
@@ -39,11 +40,18 @@ type BalanceOf<T> =
 use pallet_bridge_transfer::BridgeHandler;
 use pallet_parachain_staking::IssuanceAdapter;
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedSub},
-	ArithmeticError, DispatchError, FixedPointOperand,
+	traits::{AccountIdConversion, CheckedAdd, CheckedSub, Saturating, Zero},
+	ArithmeticError, DispatchError, FixedPointOperand, Perbill,
 };
 use sp_std::{fmt::Debug, prelude::*};
 type ResourceId = pallet_bridge::ResourceId;
+type BridgeChainId = pallet_bridge::BridgeChainId;
+type QueuedTransferId = u64;
+
+/// Holds the amount of the queued outgoing transfers until they're released or cancelled
+const ESCROW_ID: PalletId = PalletId(*b"asthndlr");
+/// Maximum number of transfers queued per resource and direction
+const MAX_QUEUED_TRANSFERS: u32 = 256;
 
 #[derive(PartialEq, Eq, Clone, Encode, Debug, Decode, TypeInfo)]
 pub struct AssetInfo<AssetId, Balance> {
@@ -52,6 +60,87 @@ pub struct AssetInfo<AssetId, Balance> {
 	pub asset: Option<AssetId>,
 }
 
+/// Limits of the amount bridged within a sliding window of `window` blocks
+#[derive(PartialEq, Eq, Clone, Encode, Debug, Decode, TypeInfo)]
+pub struct RateLimit<BlockNumber, Balance> {
+	pub window: BlockNumber,
+	pub max_inflow: Balance,
+	pub max_outflow: Balance,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Debug, Decode, TypeInfo)]
+pub enum FlowDirection {
+	#[codec(index = 0)]
+	In,
+	#[codec(index = 1)]
+	Out,
+}
+
+/// The amount bridged in the current and the previous window period, the sliding window
+/// usage is estimated by weighting the previous period by its overlap with the window
+#[derive(PartialEq, Eq, Clone, Default, Encode, Debug, Decode, TypeInfo)]
+pub struct FlowWindow<BlockNumber, Balance> {
+	pub started_at: BlockNumber,
+	pub previous: Balance,
+	pub current: Balance,
+}
+
+impl<BlockNumber, Balance> FlowWindow<BlockNumber, Balance>
+where
+	BlockNumber: sp_runtime::traits::AtLeast32BitUnsigned + Copy,
+	Balance: sp_runtime::traits::AtLeast32BitUnsigned + Copy,
+{
+	/// Moves the current period forward so that it contains `now`
+	fn roll(&mut self, now: BlockNumber, window: BlockNumber) {
+		let elapsed = now.saturating_sub(self.started_at);
+		if elapsed >= window.saturating_add(window) {
+			self.previous = Zero::zero();
+			self.current = Zero::zero();
+			self.started_at = now;
+		} else if elapsed >= window {
+			self.previous = self.current;
+			self.current = Zero::zero();
+			self.started_at = self.started_at.saturating_add(window);
+		}
+	}
+
+	/// The amount counted against the limit at `now`, `roll` must be called first
+	fn usage(&self, now: BlockNumber, window: BlockNumber) -> Balance {
+		let elapsed = now.saturating_sub(self.started_at);
+		let overlap = Perbill::from_rational(window.saturating_sub(elapsed), window);
+		(overlap * self.previous).saturating_add(self.current)
+	}
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Debug, Decode, TypeInfo)]
+pub enum QueuedTransferKind<AccountId> {
+	/// Mint to `to` on this chain
+	#[codec(index = 0)]
+	In { to: AccountId },
+	/// Signal a transfer to `recipient` on `dest_id`, the amount is held in the escrow account
+	/// until it's burned on release
+	#[codec(index = 1)]
+	Out { from: AccountId, dest_id: BridgeChainId, recipient: Vec<u8> },
+}
+
+/// A bridge transfer held back as it exceeded the rate limit of its resource
+#[derive(PartialEq, Eq, Clone, Encode, Debug, Decode, TypeInfo)]
+pub struct QueuedTransfer<AccountId, Balance, BlockNumber> {
+	pub resource_id: ResourceId,
+	pub kind: QueuedTransferKind<AccountId>,
+	pub amount: Balance,
+	pub queued_at: BlockNumber,
+}
+
+impl<AccountId, Balance, BlockNumber> QueuedTransfer<AccountId, Balance, BlockNumber> {
+	fn direction(&self) -> FlowDirection {
+		match self.kind {
+			QueuedTransferKind::In { .. } => FlowDirection::In,
+			QueuedTransferKind::Out { .. } => FlowDirection::Out,
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -69,8 +158,8 @@ pub mod pallet {
 	pub trait Config:
 		frame_system::Config
 		+ pallet_balances::Config
-		+ pallet_assets::Config
-		+ pallet_bridge::Config
+		+ pallet_assets::Config<Balance = <Self as pallet_balances::Config>::Balance>
+		+ pallet_bridge::Config<Balance = <Self as pallet_balances::Config>::Balance>
 	{
 		/// Overarching event type
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -114,6 +203,56 @@ pub mod pallet {
 	pub type MaximumIssuance<T: Config> =
 		StorageValue<_, BalanceOf<T>, ValueQuery, T::DefaultMaximumIssuance>;
 
+	// Bridge rate limits per resource, resources without a limit are not rate limited
+	#[pallet::storage]
+	#[pallet::getter(fn rate_limit)]
+	pub type RateLimits<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ResourceId,
+		RateLimit<BlockNumberFor<T>, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn flow_window)]
+	pub type FlowWindows<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ResourceId,
+		Twox64Concat,
+		FlowDirection,
+		FlowWindow<BlockNumberFor<T>, BalanceOf<T>>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn queued_transfer)]
+	pub type QueuedTransfers<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		QueuedTransferId,
+		QueuedTransfer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_queued_transfer_id)]
+	pub type NextQueuedTransferId<T: Config> = StorageValue<_, QueuedTransferId, ValueQuery>;
+
+	// ids of the queued transfers of each resource and direction, in the order they're released
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_queue)]
+	pub type TransferQueues<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ResourceId,
+		Twox64Concat,
+		FlowDirection,
+		BoundedVec<QueuedTransferId, ConstU32<MAX_QUEUED_TRANSFERS>>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -142,6 +281,24 @@ pub mod pallet {
 		MaximumIssuanceChanged {
 			old_value: BalanceOf<T>,
 		},
+		RateLimitSet {
+			resource_id: ResourceId,
+			limit: RateLimit<BlockNumberFor<T>, BalanceOf<T>>,
+		},
+		RateLimitRemoved {
+			resource_id: ResourceId,
+		},
+		/// A transfer exceeded the rate limit of its resource and was queued
+		TransferQueued {
+			id: QueuedTransferId,
+			transfer: QueuedTransfer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		},
+		QueuedTransferReleased {
+			id: QueuedTransferId,
+		},
+		QueuedTransferCancelled {
+			id: QueuedTransferId,
+		},
 	}
 
 	#[pallet::error]
@@ -150,6 +307,15 @@ pub mod pallet {
 		CannotPayAsFee,
 		ReachMaximumSupply,
 		Overflow,
+		/// Rate limit window must not be zero
+		InvalidRateLimit,
+		QueuedTransferNotFound,
+		/// The queued transfer still exceeds the rate limit
+		RateLimitExceeded,
+		/// Transfers must be released in the order they were queued
+		QueuedTransferNotFirst,
+		/// Too many transfers are queued for the resource
+		TransferQueueFull,
 	}
 
 	#[pallet::call]
@@ -205,31 +371,182 @@ pub mod pallet {
 			<ExternalBalances<T>>::put(external_balances);
 			Ok(())
 		}
+
+		/// Sets the inflow and outflow limits of a resource, transfers exceeding them are queued
+		#[pallet::call_index(4)]
+		#[pallet::weight({1000})]
+		pub fn set_rate_limit(
+			origin: OriginFor<T>,
+			resource_id: ResourceId,
+			limit: RateLimit<BlockNumberFor<T>, BalanceOf<T>>,
+		) -> DispatchResult {
+			<T as pallet_bridge::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			ensure!(!limit.window.is_zero(), Error::<T>::InvalidRateLimit);
+			RateLimits::<T>::insert(resource_id, limit.clone());
+			Self::deposit_event(Event::RateLimitSet { resource_id, limit });
+			Ok(())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight({1000})]
+		pub fn remove_rate_limit(origin: OriginFor<T>, resource_id: ResourceId) -> DispatchResult {
+			<T as pallet_bridge::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			RateLimits::<T>::remove(resource_id);
+			let _ = FlowWindows::<T>::clear_prefix(resource_id, 2, None);
+			Self::deposit_event(Event::RateLimitRemoved { resource_id });
+			Ok(())
+		}
+
+		/// Releases the first queued transfer of a resource and direction. Anyone can release it
+		/// once it fits in the rate limit, the bridge committee can release it regardless of the
+		/// limit.
+		#[pallet::call_index(6)]
+		#[pallet::weight(Weight::from_parts(1000, 0).saturating_add(
+			<<T as pallet_bridge::Config>::WeightInfo as pallet_bridge::WeightInfo>::charge_relayer_fee(
+				<T as pallet_bridge::Config>::MaxRelayers::get(),
+			),
+		))]
+		pub fn release_queued_transfer(
+			origin: OriginFor<T>,
+			id: QueuedTransferId,
+		) -> DispatchResult {
+			let bypass_limit =
+				match <T as pallet_bridge::Config>::BridgeCommitteeOrigin::try_origin(origin) {
+					Ok(_) => true,
+					Err(origin) => {
+						ensure_signed(origin)?;
+						false
+					},
+				};
+			let transfer =
+				QueuedTransfers::<T>::get(id).ok_or(Error::<T>::QueuedTransferNotFound)?;
+			let direction = transfer.direction();
+			ensure!(
+				Self::transfer_queue(transfer.resource_id, direction).first() == Some(&id),
+				Error::<T>::QueuedTransferNotFirst
+			);
+			ensure!(
+				Self::try_record_flow(
+					transfer.resource_id,
+					direction,
+					transfer.amount,
+					bypass_limit
+				),
+				Error::<T>::RateLimitExceeded
+			);
+
+			QueuedTransfers::<T>::remove(id);
+			TransferQueues::<T>::mutate(transfer.resource_id, direction, |queue| {
+				queue.remove(0);
+			});
+			match transfer.kind {
+				QueuedTransferKind::In { to } => {
+					Self::do_token_bridge_in(transfer.resource_id, to, transfer.amount)?;
+				},
+				QueuedTransferKind::Out { from, dest_id, recipient } => {
+					let dest_amount = Self::do_token_bridge_out(
+						transfer.resource_id,
+						from,
+						&Self::escrow_account(),
+						dest_id,
+						transfer.amount,
+					)?;
+					pallet_bridge::Pallet::<T>::signal_transfer_fungible(
+						dest_id,
+						transfer.resource_id,
+						recipient,
+						dest_amount,
+					)?;
+				},
+			}
+			Self::deposit_event(Event::QueuedTransferReleased { id });
+			Ok(())
+		}
+
+		/// Cancels a queued transfer. The amount of an outgoing transfer is given back in full, its
+		/// fee and relayer fee are only charged on release.
+		#[pallet::call_index(7)]
+		#[pallet::weight({1000})]
+		pub fn cancel_queued_transfer(
+			origin: OriginFor<T>,
+			id: QueuedTransferId,
+		) -> DispatchResult {
+			<T as pallet_bridge::Config>::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			let transfer =
+				QueuedTransfers::<T>::get(id).ok_or(Error::<T>::QueuedTransferNotFound)?;
+			if let QueuedTransferKind::Out { ref from, .. } = transfer.kind {
+				Self::release_escrow(transfer.resource_id, from, transfer.amount)?;
+			}
+			QueuedTransfers::<T>::remove(id);
+			TransferQueues::<T>::mutate(transfer.resource_id, transfer.direction(), |queue| {
+				queue.retain(|queued| *queued != id)
+			});
+			Self::deposit_event(Event::QueuedTransferCancelled { id });
+			Ok(())
+		}
 	}
 }
 
-impl<T, B, A> BridgeHandler<B, A, ResourceId> for Pallet<T>
-where
-	T: Config
-		+ frame_system::Config<AccountId = A>
-		+ pallet_bridge::Config<Balance = B>
-		+ pallet_assets::Config<Balance = B>
-		+ pallet_balances::Config<Balance = B>,
-	B: Copy
-		+ FixedPointOperand
-		+ CheckedSub
-		+ CheckedAdd
-		+ MaybeSerializeDeserialize
-		+ Debug
-		+ FullCodec
-		+ 'static,
-	A: Clone,
-{
-	fn prepare_token_bridge_in(
+impl<T: Config> Pallet<T> {
+	/// Account holding the amount of the queued outgoing transfers
+	pub fn escrow_account() -> T::AccountId {
+		ESCROW_ID.into_account_truncating()
+	}
+
+	/// Records `amount` in the flow window of the resource if it stays within the rate limit,
+	/// or regardless of the limit if `bypass_limit` is set. Returns false if it doesn't fit.
+	fn try_record_flow(
 		resource_id: ResourceId,
-		who: A,
-		amount: B,
-	) -> Result<B, DispatchError> {
+		direction: FlowDirection,
+		amount: BalanceOf<T>,
+		bypass_limit: bool,
+	) -> bool {
+		let limit = match RateLimits::<T>::get(resource_id) {
+			Some(limit) => limit,
+			None => return true,
+		};
+		let max = match direction {
+			FlowDirection::In => limit.max_inflow,
+			FlowDirection::Out => limit.max_outflow,
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+
+		FlowWindows::<T>::mutate(resource_id, direction, |flow| {
+			flow.roll(now, limit.window);
+			if !bypass_limit && flow.usage(now, limit.window).saturating_add(amount) > max {
+				return false
+			}
+			flow.current = flow.current.saturating_add(amount);
+			true
+		})
+	}
+
+	fn queue_transfer(
+		resource_id: ResourceId,
+		kind: QueuedTransferKind<T::AccountId>,
+		amount: BalanceOf<T>,
+	) -> Result<QueuedTransferId, DispatchError> {
+		let id = Self::next_queued_transfer_id();
+		NextQueuedTransferId::<T>::put(id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+		let transfer = QueuedTransfer {
+			resource_id,
+			kind,
+			amount,
+			queued_at: frame_system::Pallet::<T>::block_number(),
+		};
+		TransferQueues::<T>::try_mutate(resource_id, transfer.direction(), |queue| {
+			queue.try_push(id).map_err(|_| Error::<T>::TransferQueueFull)
+		})?;
+		QueuedTransfers::<T>::insert(id, transfer.clone());
+		Self::deposit_event(Event::TransferQueued { id, transfer });
+		Ok(id)
+	}
+
+	fn do_token_bridge_in(
+		resource_id: ResourceId,
+		who: T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
 		let asset_info = Self::resource_to_asset_info(resource_id);
 		match asset_info {
 			None => Err(Error::<T>::InvalidResourceId.into()),
@@ -266,20 +583,28 @@ where
 			},
 		}
 	}
-	// Return actual amount to target chain after deduction e.g fee
-	fn prepare_token_bridge_out(
+
+	// Burns `amount` from `burn_from` for a transfer out of `who` to `dest_id` and pays the fee to
+	// the treasury, the relayer fee of a native token transfer is paid out of `amount` as well.
+	// Returns the amount left for the target chain.
+	fn do_token_bridge_out(
 		resource_id: ResourceId,
-		who: A,
-		amount: B,
-	) -> Result<B, DispatchError> {
+		who: T::AccountId,
+		burn_from: &T::AccountId,
+		dest_id: BridgeChainId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
 		let asset_info = Self::resource_to_asset_info(resource_id);
 		match asset_info {
 			None => Err(Error::<T>::InvalidResourceId.into()),
 			// Native token
 			Some(AssetInfo { fee, asset: None }) => {
+				let relayer_fee = pallet_bridge::Pallet::<T>::relayer_fee(dest_id);
+				ensure!(amount > fee.saturating_add(relayer_fee), Error::<T>::CannotPayAsFee);
+				pallet_bridge::Pallet::<T>::charge_relayer_fee(burn_from, dest_id)?;
 				let burn_amount = pallet_balances::Pallet::<T>::burn_from(
-					&who,
-					amount,
+					burn_from,
+					amount.saturating_sub(relayer_fee),
 					Precision::Exact,
 					Fortitude::Polite,
 				)?;
@@ -291,7 +616,10 @@ where
 					.ok_or(Error::<T>::Overflow)?;
 				<ExternalBalances<T>>::put(external_balances);
 
-				pallet_balances::Pallet::<T>::mint_into(&T::TreasuryAccount::get(), fee)?;
+				pallet_balances::Pallet::<T>::mint_into(
+					&<T as Config>::TreasuryAccount::get(),
+					fee,
+				)?;
 				Self::deposit_event(Event::TokenBridgeOut {
 					asset_id: None,
 					from: who,
@@ -307,13 +635,17 @@ where
 				// Otherwise
 				let burn_amount = pallet_assets::Pallet::<T>::burn_from(
 					asset,
-					&who,
+					burn_from,
 					amount,
 					Precision::Exact,
 					Fortitude::Polite,
 				)?;
 				ensure!(burn_amount > fee, Error::<T>::CannotPayAsFee);
-				pallet_assets::Pallet::<T>::mint_into(asset, &T::TreasuryAccount::get(), fee)?;
+				pallet_assets::Pallet::<T>::mint_into(
+					asset,
+					&<T as Config>::TreasuryAccount::get(),
+					fee,
+				)?;
 				Self::deposit_event(Event::TokenBridgeOut {
 					asset_id: Some(asset),
 					from: who,
@@ -324,6 +656,140 @@ where
 			},
 		}
 	}
+
+	// Moves the amount of a queued outgoing transfer into the escrow account
+	fn hold_in_escrow(
+		resource_id: ResourceId,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let escrow = Self::escrow_account();
+		match Self::resource_to_asset_info(resource_id) {
+			None => Err(Error::<T>::InvalidResourceId.into()),
+			// Native token
+			Some(AssetInfo { fee: _, asset: None }) =>
+				<pallet_balances::Pallet<T> as FMutate<T::AccountId>>::transfer(
+					who,
+					&escrow,
+					amount,
+					Preservation::Expendable,
+				),
+			// pallet assets
+			Some(AssetInfo { fee: _, asset: Some(asset) }) =>
+				<pallet_assets::Pallet<T> as FsMutate<T::AccountId>>::transfer(
+					asset,
+					who,
+					&escrow,
+					amount,
+					Preservation::Expendable,
+				),
+		}
+	}
+
+	// Gives back the amount of a cancelled outgoing transfer from the escrow account
+	fn release_escrow(
+		resource_id: ResourceId,
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let escrow = Self::escrow_account();
+		match Self::resource_to_asset_info(resource_id) {
+			None => Err(Error::<T>::InvalidResourceId.into()),
+			// Native token
+			Some(AssetInfo { fee: _, asset: None }) =>
+				<pallet_balances::Pallet<T> as FMutate<T::AccountId>>::transfer(
+					&escrow,
+					who,
+					amount,
+					Preservation::Expendable,
+				),
+			// pallet assets
+			Some(AssetInfo { fee: _, asset: Some(asset) }) =>
+				<pallet_assets::Pallet<T> as FsMutate<T::AccountId>>::transfer(
+					asset,
+					&escrow,
+					who,
+					amount,
+					Preservation::Expendable,
+				),
+		}
+	}
+}
+
+impl<T, B, A> BridgeHandler<B, A, ResourceId> for Pallet<T>
+where
+	T: Config
+		+ frame_system::Config<AccountId = A>
+		+ pallet_bridge::Config<Balance = B>
+		+ pallet_assets::Config<Balance = B>
+		+ pallet_balances::Config<Balance = B>,
+	B: Copy
+		+ FixedPointOperand
+		+ CheckedSub
+		+ CheckedAdd
+		+ MaybeSerializeDeserialize
+		+ Debug
+		+ FullCodec
+		+ 'static,
+	A: Clone,
+{
+	fn prepare_token_bridge_in(
+		resource_id: ResourceId,
+		who: A,
+		amount: B,
+	) -> Result<B, DispatchError> {
+		Self::do_token_bridge_in(resource_id, who, amount)
+	}
+	// Return actual amount to target chain after deduction e.g fee
+	fn prepare_token_bridge_out(
+		resource_id: ResourceId,
+		who: A,
+		dest_id: BridgeChainId,
+		amount: B,
+	) -> Result<B, DispatchError> {
+		Self::do_token_bridge_out(resource_id, who.clone(), &who, dest_id, amount)
+	}
+
+	fn defer_token_bridge_in(
+		resource_id: ResourceId,
+		who: A,
+		amount: B,
+	) -> Result<bool, DispatchError> {
+		ensure!(ResourceToAssetInfo::<T>::contains_key(resource_id), Error::<T>::InvalidResourceId);
+		if Self::try_record_flow(resource_id, FlowDirection::In, amount, false) {
+			return Ok(false)
+		}
+		Self::queue_transfer(resource_id, QueuedTransferKind::In { to: who }, amount)?;
+		Ok(true)
+	}
+
+	fn defer_token_bridge_out(
+		resource_id: ResourceId,
+		who: A,
+		dest_id: BridgeChainId,
+		recipient: Vec<u8>,
+		amount: B,
+	) -> Result<bool, DispatchError> {
+		let AssetInfo { fee, asset } =
+			Self::resource_to_asset_info(resource_id).ok_or(Error::<T>::InvalidResourceId)?;
+		// the relayer fee is only charged on release, but it has to fit in the amount as well
+		let relayer_fee = match asset {
+			None => pallet_bridge::Pallet::<T>::relayer_fee(dest_id),
+			Some(_) => Zero::zero(),
+		};
+		ensure!(amount > fee.saturating_add(relayer_fee), Error::<T>::CannotPayAsFee);
+		// the limit counts the amount before fee
+		if Self::try_record_flow(resource_id, FlowDirection::Out, amount, false) {
+			return Ok(false)
+		}
+		Self::hold_in_escrow(resource_id, &who, amount)?;
+		Self::queue_transfer(
+			resource_id,
+			QueuedTransferKind::Out { from: who, dest_id, recipient },
+			amount,
+		)?;
+		Ok(true)
+	}
 }
 
 impl<T: Config> IssuanceAdapter<BalanceOf<T>> for Pallet<T> {
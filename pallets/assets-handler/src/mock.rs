@@ -107,6 +107,7 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const TestChainId: u8 = 5;
 	pub const ProposalLifetime: u64 = 50;
+	pub const CompletedProposalLifetime: u64 = 100;
 	pub const TreasuryAccount:u64 = 0x8;
 }
 
@@ -117,6 +118,10 @@ impl pallet_bridge::Config for Test {
 	type BridgeChainId = TestChainId;
 	type Balance = Balance;
 	type ProposalLifetime = ProposalLifetime;
+	type CompletedProposalLifetime = CompletedProposalLifetime;
+	type MaxRelayers = ConstU32<16>;
+	type Currency = Balances;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 }
 
@@ -406,3 +406,251 @@ fn set_maximum_issuance_fails_with_unprivileged_origin() {
 		assert_eq!(MaximumIssuanceStorage::<Test>::get(), mock::MaximumIssuance::get());
 	});
 }
+
+#[test]
+fn rate_limited_inflow_is_queued_and_released() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo { fee: 0u64, asset: None };
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_noop!(
+				AssetsHandler::set_rate_limit(
+					RuntimeOrigin::root(),
+					resource_id,
+					RateLimit { window: 0, max_inflow: 15, max_outflow: 15 }
+				),
+				Error::<Test>::InvalidRateLimit
+			);
+			assert_ok!(AssetsHandler::set_rate_limit(
+				RuntimeOrigin::root(),
+				resource_id,
+				RateLimit { window: 10, max_inflow: 15, max_outflow: 15 }
+			));
+
+			assert_ok!(BridgeTransfer::transfer(
+				RuntimeOrigin::signed(Bridge::account_id()),
+				RELAYER_A,
+				10,
+				resource_id,
+			));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 10);
+
+			// exceeds the inflow limit, nothing is minted
+			assert_ok!(BridgeTransfer::transfer(
+				RuntimeOrigin::signed(Bridge::account_id()),
+				RELAYER_A,
+				10,
+				resource_id,
+			));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 10);
+			let transfer = QueuedTransfer {
+				resource_id,
+				kind: QueuedTransferKind::In { to: RELAYER_A },
+				amount: 10,
+				queued_at: 1,
+			};
+			assert_eq!(AssetsHandler::queued_transfer(0), Some(transfer.clone()));
+			assert_events(vec![RuntimeEvent::AssetsHandler(Event::TransferQueued {
+				id: 0,
+				transfer,
+			})]);
+
+			assert_noop!(
+				AssetsHandler::release_queued_transfer(RuntimeOrigin::signed(RELAYER_B), 0),
+				Error::<Test>::RateLimitExceeded
+			);
+
+			// the previous window only partially overlaps the sliding window
+			frame_system::Pallet::<Test>::set_block_number(16);
+			assert_ok!(AssetsHandler::release_queued_transfer(RuntimeOrigin::signed(RELAYER_B), 0));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 20);
+			assert_eq!(AssetsHandler::queued_transfer(0), None);
+			assert_events(vec![
+				RuntimeEvent::AssetsHandler(Event::TokenBridgeIn {
+					asset_id: None,
+					to: RELAYER_A,
+					amount: 10,
+				}),
+				RuntimeEvent::Balances(pallet_balances::Event::Minted {
+					who: RELAYER_A,
+					amount: 10,
+				}),
+				RuntimeEvent::AssetsHandler(Event::QueuedTransferReleased { id: 0 }),
+			]);
+		},
+	)
+}
+
+#[test]
+fn rate_limited_outflow_is_queued_and_released() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo { fee: 10u64, asset: None };
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_ok!(AssetsHandler::set_rate_limit(
+				RuntimeOrigin::root(),
+				resource_id,
+				RateLimit { window: 10, max_inflow: 50, max_outflow: 50 }
+			));
+			assert_ok!(Bridge::set_relayer_fee(RuntimeOrigin::root(), dest_bridge_id, 5));
+			let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+			let external_balances = ExternalBalances::<Test>::get();
+			let dest_account: Vec<u8> = vec![1];
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				100,
+				dest_account.clone(),
+				dest_bridge_id,
+				resource_id
+			));
+			// the amount is held until the transfer is released, the fees aren't charged yet
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE - 100);
+			assert_eq!(Balances::free_balance(AssetsHandler::escrow_account()), 100);
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury_balance);
+			assert_eq!(ExternalBalances::<Test>::get(), external_balances);
+			let transfer = QueuedTransfer {
+				resource_id,
+				kind: QueuedTransferKind::Out {
+					from: RELAYER_A,
+					dest_id: dest_bridge_id,
+					recipient: dest_account.clone(),
+				},
+				amount: 100,
+				queued_at: 1,
+			};
+			// neither burned nor signaled to the bridge
+			assert_events(vec![RuntimeEvent::AssetsHandler(Event::TransferQueued {
+				id: 0,
+				transfer,
+			})]);
+
+			// the committee can release it regardless of the limit, the relayer fee is deducted
+			// from the amount and goes to the treasury as no relayer approved a proposal yet
+			assert_ok!(AssetsHandler::release_queued_transfer(RuntimeOrigin::root(), 0));
+			assert_events(vec![
+				RuntimeEvent::AssetsHandler(Event::TokenBridgeOut {
+					asset_id: None,
+					from: RELAYER_A,
+					amount: 95,
+					fee: 10,
+				}),
+				RuntimeEvent::Bridge(pallet_bridge::Event::FungibleTransfer(
+					dest_bridge_id,
+					1,
+					resource_id,
+					85,
+					dest_account,
+				)),
+				RuntimeEvent::AssetsHandler(Event::QueuedTransferReleased { id: 0 }),
+			]);
+			assert_eq!(Balances::free_balance(AssetsHandler::escrow_account()), 0);
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury_balance + 5 + 10);
+			assert_eq!(ExternalBalances::<Test>::get(), external_balances + 95);
+			assert_eq!(AssetsHandler::flow_window(resource_id, FlowDirection::Out).current, 100);
+		},
+	)
+}
+
+#[test]
+fn cancel_queued_outflow_refunds() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo { fee: 10u64, asset: None };
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_ok!(AssetsHandler::set_rate_limit(
+				RuntimeOrigin::root(),
+				resource_id,
+				RateLimit { window: 10, max_inflow: 50, max_outflow: 50 }
+			));
+			assert_ok!(Bridge::set_relayer_fee(RuntimeOrigin::root(), dest_bridge_id, 5));
+			let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+			let external_balances = ExternalBalances::<Test>::get();
+			assert_ok!(pallet_bridge_transfer::Pallet::<Test>::transfer_assets(
+				RuntimeOrigin::signed(RELAYER_A),
+				100,
+				vec![1],
+				dest_bridge_id,
+				resource_id
+			));
+
+			assert_noop!(
+				AssetsHandler::cancel_queued_transfer(RuntimeOrigin::signed(RELAYER_A), 0),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(AssetsHandler::cancel_queued_transfer(RuntimeOrigin::root(), 0));
+			// the full amount is given back, no fee was charged
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE);
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury_balance);
+			assert_eq!(ExternalBalances::<Test>::get(), external_balances);
+			assert_eq!(AssetsHandler::queued_transfer(0), None);
+			assert!(AssetsHandler::transfer_queue(resource_id, FlowDirection::Out).is_empty());
+			assert_noop!(
+				AssetsHandler::release_queued_transfer(RuntimeOrigin::root(), 0),
+				Error::<Test>::QueuedTransferNotFound
+			);
+		},
+	)
+}
+
+#[test]
+fn queued_transfers_are_released_in_order() {
+	let dest_bridge_id: pallet_bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+	let native_token_asset_info: AssetInfo<
+		<Test as pallet_assets::Config>::AssetId,
+		<Test as pallet_assets::Config>::Balance,
+	> = AssetInfo { fee: 0u64, asset: None };
+
+	new_test_ext_initialized(dest_bridge_id, resource_id, native_token_asset_info).execute_with(
+		|| {
+			assert_ok!(AssetsHandler::set_rate_limit(
+				RuntimeOrigin::root(),
+				resource_id,
+				RateLimit { window: 10, max_inflow: 5, max_outflow: 5 }
+			));
+			// queued inflows are reported to the caller
+			for _ in 0..3 {
+				assert_eq!(
+					<AssetsHandler as BridgeHandler<_, _, _>>::defer_token_bridge_in(
+						resource_id,
+						RELAYER_A,
+						10
+					),
+					Ok(true)
+				);
+			}
+			assert_eq!(
+				AssetsHandler::transfer_queue(resource_id, FlowDirection::In),
+				vec![0, 1, 2]
+			);
+
+			assert_noop!(
+				AssetsHandler::release_queued_transfer(RuntimeOrigin::root(), 1),
+				Error::<Test>::QueuedTransferNotFirst
+			);
+			assert_ok!(AssetsHandler::release_queued_transfer(RuntimeOrigin::root(), 0));
+
+			// a cancelled transfer leaves the queue
+			assert_ok!(AssetsHandler::cancel_queued_transfer(RuntimeOrigin::root(), 1));
+			assert_eq!(AssetsHandler::transfer_queue(resource_id, FlowDirection::In), vec![2]);
+			assert_ok!(AssetsHandler::release_queued_transfer(RuntimeOrigin::root(), 2));
+			assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 20);
+			assert!(AssetsHandler::transfer_queue(resource_id, FlowDirection::In).is_empty());
+		},
+	)
+}
@@ -18,12 +18,19 @@ use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
 pub type PubKey = [u8; 33];
+pub type SolPubKey = [u8; 32];
+pub type SolKeyProof = [u8; 64];
+
+/// domain of the message a solana key share proof of possession is signed over,
+/// it must match the one used by the enclave
+pub const SOL_KEY_POSSESSION_CONTEXT: &[u8] = b"bitacross:sol-key-possession";
 
 /// custodial wallet that each tee worker generates and holds
 #[derive(Encode, Decode, Clone, Default, Debug, PartialEq, Eq, TypeInfo)]
 pub struct CustodialWallet {
 	pub btc: Option<PubKey>,
 	pub eth: Option<PubKey>,
+	pub sol: Option<SolPubKey>,
 }
 
 impl CustodialWallet {
@@ -34,4 +41,21 @@ impl CustodialWallet {
 	pub fn has_eth(&self) -> bool {
 		self.eth.is_some()
 	}
+
+	pub fn has_sol(&self) -> bool {
+		self.sol.is_some()
+	}
+}
+
+/// the layout of `CustodialWallet` before storage version 1
+#[derive(Encode, Decode, Clone, Default, Debug, PartialEq, Eq, TypeInfo)]
+pub struct CustodialWalletV0 {
+	pub btc: Option<PubKey>,
+	pub eth: Option<PubKey>,
+}
+
+impl From<CustodialWalletV0> for CustodialWallet {
+	fn from(old: CustodialWalletV0) -> Self {
+		Self { btc: old.btc, eth: old.eth, sol: None }
+	}
 }
@@ -16,7 +16,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use core_primitives::Identity;
+use core_primitives::{Identity, RelayerRole};
 use frame_support::{
 	dispatch::{DispatchResult, DispatchResultWithPostInfo},
 	ensure,
@@ -30,11 +30,24 @@ pub use pallet::*;
 mod custodial_wallet;
 pub use custodial_wallet::*;
 
+mod relayer;
+pub use relayer::*;
+
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -55,7 +68,15 @@ pub mod pallet {
 	// adding them
 	#[pallet::storage]
 	#[pallet::getter(fn relayer)]
-	pub type Relayer<T: Config> = StorageMap<_, Blake2_128Concat, Identity, (), OptionQuery>;
+	pub type Relayer<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identity, RelayerInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	// relayers to be pruned at the given block, an entry is moved or removed together with
+	// the expiry of its relayer
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_expiry)]
+	pub type RelayerExpiry<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, ExpiringRelayers<Identity>, ValueQuery>;
 
 	// `ValueQuery` is used as each field in CustodialWallet is optional already
 	// not using Option<CustodialWallet> either as each field is set separately
@@ -64,6 +85,13 @@ pub mod pallet {
 	pub type Vault<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, CustodialWallet, ValueQuery>;
 
+	// the latest anchored head of the signing audit log of each tee worker:
+	// (number of log entries, head hash of the log)
+	#[pallet::storage]
+	#[pallet::getter(fn audit_log_anchor)]
+	pub type AuditLogAnchor<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (u64, T::Hash), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -72,7 +100,12 @@ pub mod pallet {
 		RelayerRemoved { who: Identity },
 		BtcWalletGenerated { pub_key: PubKey, account_id: T::AccountId },
 		EthWalletGenerated { pub_key: PubKey },
+		SolWalletGenerated { pub_key: SolPubKey, account_id: T::AccountId },
 		VaultRemoved { who: T::AccountId },
+		AuditLogAnchored { account_id: T::AccountId, entries: u64, head: T::Hash },
+		// emitted instead of `RelayerAdded` by `set_relayer`, it's a separate event so that
+		// `RelayerAdded` keeps the layout tee workers decode past blocks with
+		RelayerSet { who: Identity, role: RelayerRole, expiry: Option<BlockNumberFor<T>> },
 	}
 
 	#[pallet::error]
@@ -82,7 +115,12 @@ pub mod pallet {
 		UnsupportedRelayerType,
 		BtcWalletAlreadyExist,
 		EthWalletAlreadyExist,
+		SolWalletAlreadyExist,
+		InvalidSolKeyProof,
 		VaultNotExist,
+		AuditLogAnchorOutdated,
+		RelayerExpiryInPast,
+		TooManyRelayersExpiring,
 	}
 
 	#[pallet::genesis_config]
@@ -106,6 +144,17 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			Self::prune_expired_relayers(n)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::migrate_to_v1::<T>().saturating_add(migrations::migrate_to_v2::<T>())
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Set the admin account
@@ -127,10 +176,11 @@ pub mod pallet {
 		#[pallet::weight({195_000_000})]
 		pub fn add_relayer(origin: OriginFor<T>, account: Identity) -> DispatchResultWithPostInfo {
 			Self::ensure_admin_or_root(origin)?;
-			ensure!(account.is_substrate() || account.is_evm(), Error::<T>::UnsupportedRelayerType);
-			// we don't care if `account` already exists
-			Relayer::<T>::insert(account.clone(), ());
-			Self::deposit_event(Event::RelayerAdded { who: account });
+			// an existing relayer keeps its role and expiry, they are changed by `set_relayer`
+			if !Relayer::<T>::contains_key(&account) {
+				Self::do_set_relayer(account.clone(), Default::default())?;
+				Self::deposit_event(Event::RelayerAdded { who: account });
+			}
 			Ok(Pays::No.into())
 		}
 
@@ -141,8 +191,10 @@ pub mod pallet {
 			account: Identity,
 		) -> DispatchResultWithPostInfo {
 			Self::ensure_admin_or_root(origin)?;
-			ensure!(Relayer::<T>::contains_key(&account), Error::<T>::RelayerNotExist);
-			Relayer::<T>::remove(account.clone());
+			let info = Relayer::<T>::take(&account).ok_or(Error::<T>::RelayerNotExist)?;
+			if let Some(expiry) = info.expiry {
+				Self::remove_relayer_expiry(expiry, &account);
+			}
 			Self::deposit_event(Event::RelayerRemoved { who: account });
 			Ok(Pays::No.into())
 		}
@@ -160,6 +212,30 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 
+		/// Add a relayer or update an existing one with the given role, expiry and metadata.
+		///
+		/// An expired relayer is removed at the beginning of the `expiry` block.
+		#[pallet::call_index(4)]
+		#[pallet::weight({195_000_000})]
+		pub fn set_relayer(
+			origin: OriginFor<T>,
+			account: Identity,
+			role: RelayerRole,
+			expiry: Option<BlockNumberFor<T>>,
+			metadata: RelayerMetadata,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_admin_or_root(origin)?;
+			if let Some(expiry) = expiry {
+				ensure!(
+					expiry > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::RelayerExpiryInPast
+				);
+			}
+			Self::do_set_relayer(account.clone(), RelayerInfo { role, expiry, metadata })?;
+			Self::deposit_event(Event::RelayerSet { who: account, role, expiry });
+			Ok(Pays::No.into())
+		}
+
 		/// ---------------------------------------------------
 		/// The following extrinsics are supposed to be called by TEE only
 		/// ---------------------------------------------------
@@ -200,10 +276,127 @@ pub mod pallet {
 			let _ = T::TEECallOrigin::ensure_origin(origin)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Anchor the head of the signing audit log kept by the calling tee worker.
+		///
+		/// The log is append-only, so an anchor with fewer entries than the stored one
+		/// means the log was truncated, and a different head at the same number of entries
+		/// means it was rewritten. Both are rejected.
+		#[pallet::call_index(33)]
+		#[pallet::weight(({195_000_000}, DispatchClass::Normal, Pays::No))]
+		pub fn audit_log_anchored(
+			origin: OriginFor<T>,
+			entries: u64,
+			head: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let tee_account = T::TEECallOrigin::ensure_origin(origin)?;
+			AuditLogAnchor::<T>::try_mutate(tee_account.clone(), |a| {
+				if let Some((anchored_entries, anchored_head)) = a {
+					ensure!(
+						entries > *anchored_entries ||
+							(entries == *anchored_entries && head == *anchored_head),
+						Error::<T>::AuditLogAnchorOutdated
+					);
+				}
+				*a = Some((entries, head));
+				Self::deposit_event(Event::AuditLogAnchored {
+					account_id: tee_account,
+					entries,
+					head,
+				});
+				Ok(Pays::No.into())
+			})
+		}
+
+		#[pallet::call_index(34)]
+		#[pallet::weight(({195_000_000}, DispatchClass::Normal, Pays::No))]
+		pub fn sol_wallet_generated(
+			origin: OriginFor<T>,
+			pub_key: SolPubKey,
+			proof: SolKeyProof,
+		) -> DispatchResultWithPostInfo {
+			let tee_account = T::TEECallOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::verify_sol_key_possession(&tee_account, &pub_key, &proof),
+				Error::<T>::InvalidSolKeyProof
+			);
+			Vault::<T>::try_mutate(tee_account.clone(), |v| {
+				ensure!(!v.has_sol(), Error::<T>::SolWalletAlreadyExist);
+				v.sol = Some(pub_key);
+				Self::deposit_event(Event::SolWalletGenerated { pub_key, account_id: tee_account });
+				Ok(Pays::No.into())
+			})
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	// solana keys are aggregated by summing up the shares of all workers, a worker must prove
+	// it knows its share, otherwise it could register one cancelling out the others
+	pub fn verify_sol_key_possession(
+		account: &T::AccountId,
+		pub_key: &SolPubKey,
+		proof: &SolKeyProof,
+	) -> bool {
+		let message =
+			[SOL_KEY_POSSESSION_CONTEXT, pub_key.as_slice(), account.encode().as_slice()].concat();
+		sp_io::crypto::ed25519_verify(
+			&sp_core::ed25519::Signature::from_raw(*proof),
+			&message,
+			&sp_core::ed25519::Public::from_raw(*pub_key),
+		)
+	}
+
+	fn do_set_relayer(account: Identity, info: RelayerInfo<BlockNumberFor<T>>) -> DispatchResult {
+		ensure!(account.is_substrate() || account.is_evm(), Error::<T>::UnsupportedRelayerType);
+		let old_expiry = Relayer::<T>::get(&account).and_then(|old| old.expiry);
+		if old_expiry != info.expiry {
+			if let Some(expiry) = info.expiry {
+				RelayerExpiry::<T>::try_mutate(expiry, |relayers| {
+					if relayers.contains(&account) {
+						return Ok(())
+					}
+					relayers
+						.try_push(account.clone())
+						.map_err(|_| Error::<T>::TooManyRelayersExpiring)
+				})?;
+			}
+			// the outdated entry would still count towards the bound of its block
+			if let Some(expiry) = old_expiry {
+				Self::remove_relayer_expiry(expiry, &account);
+			}
+		}
+		Relayer::<T>::insert(account, info);
+		Ok(())
+	}
+
+	fn remove_relayer_expiry(expiry: BlockNumberFor<T>, account: &Identity) {
+		RelayerExpiry::<T>::mutate_exists(expiry, |relayers| {
+			if let Some(r) = relayers {
+				r.retain(|a| a != account);
+				if r.is_empty() {
+					*relayers = None;
+				}
+			}
+		});
+	}
+
+	// removes the relayers whose authorization expires at block `n`, the tee workers
+	// mirror it through the `RelayerRemoved` event
+	fn prune_expired_relayers(n: BlockNumberFor<T>) -> Weight {
+		let candidates = RelayerExpiry::<T>::take(n);
+		let mut removed = 0u64;
+		for account in candidates.iter() {
+			if Relayer::<T>::get(account).and_then(|info| info.expiry) == Some(n) {
+				Relayer::<T>::remove(account);
+				Self::deposit_event(Event::RelayerRemoved { who: account.clone() });
+				removed += 1;
+			}
+		}
+		let count = candidates.len() as u64;
+		T::DbWeight::get().reads_writes(count + 1, removed + 1)
+	}
+
 	fn ensure_admin_or_root(origin: OriginFor<T>) -> DispatchResult {
 		ensure!(
 			ensure_root(origin.clone()).is_ok() || Some(ensure_signed(origin)?) == Self::admin(),
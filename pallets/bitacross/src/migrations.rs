@@ -0,0 +1,56 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, CustodialWallet, CustodialWalletV0, Pallet, Relayer, RelayerInfo, Vault};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, StorageVersion},
+};
+
+// v0 -> v1: `CustodialWallet` gained the `sol` field
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let on_chain_version = Pallet::<T>::on_chain_storage_version();
+	if on_chain_version >= 1 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut translated = 0u64;
+	Vault::<T>::translate::<CustodialWalletV0, _>(|_, old| {
+		translated += 1;
+		Some(CustodialWallet::from(old))
+	});
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+}
+
+// v1 -> v2: `Relayer` values changed from `()` to `RelayerInfo`, existing relayers
+// keep full permissions without expiry
+pub fn migrate_to_v2<T: Config>() -> Weight {
+	let on_chain_version = Pallet::<T>::on_chain_storage_version();
+	if on_chain_version >= 2 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut translated = 0u64;
+	Relayer::<T>::translate::<(), _>(|_, _| {
+		translated += 1;
+		Some(RelayerInfo::default())
+	});
+	StorageVersion::new(2).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+}
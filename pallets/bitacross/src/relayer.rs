@@ -0,0 +1,38 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use core_primitives::RelayerRole;
+use frame_support::{traits::ConstU32, BoundedVec};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+pub const MAX_RELAYER_METADATA_LEN: u32 = 256;
+// bounds the work of pruning expired relayers in a single block
+pub const MAX_RELAYERS_EXPIRING_PER_BLOCK: u32 = 32;
+
+pub type RelayerMetadata = BoundedVec<u8, ConstU32<MAX_RELAYER_METADATA_LEN>>;
+pub type ExpiringRelayers<Identity> =
+	BoundedVec<Identity, ConstU32<MAX_RELAYERS_EXPIRING_PER_BLOCK>>;
+
+/// authorization of a relayer, mirrored into the tee workers without the metadata
+#[derive(Encode, Decode, Clone, Default, Debug, PartialEq, Eq, TypeInfo)]
+pub struct RelayerInfo<BlockNumber> {
+	pub role: RelayerRole,
+	// the relayer is removed at the beginning of this block
+	pub expiry: Option<BlockNumber>,
+	// free-form description, e.g. the operator of the relayer
+	pub metadata: RelayerMetadata,
+}
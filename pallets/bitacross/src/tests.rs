@@ -0,0 +1,289 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	mock::*, AuditLogAnchor, Error, Event, Relayer, RelayerExpiry, Vault,
+	MAX_RELAYERS_EXPIRING_PER_BLOCK, SOL_KEY_POSSESSION_CONTEXT,
+};
+use core_primitives::{Address32, Identity, RelayerRole};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use parity_scale_codec::Encode;
+use sp_core::{ed25519, Pair, H256};
+
+fn relayer(i: u32) -> Identity {
+	let mut raw = [0u8; 32];
+	raw[..4].copy_from_slice(&i.to_le_bytes());
+	Identity::Substrate(Address32::from(raw))
+}
+
+fn sol_key_proof(pair: &ed25519::Pair, account: u64) -> [u8; 64] {
+	let message =
+		[SOL_KEY_POSSESSION_CONTEXT, pair.public().0.as_slice(), account.encode().as_slice()]
+			.concat();
+	pair.sign(&message).0
+}
+
+#[test]
+fn audit_log_anchored_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bitacross::audit_log_anchored(
+			RuntimeOrigin::signed(1),
+			5,
+			H256::from([1u8; 32])
+		));
+		assert_eq!(AuditLogAnchor::<Test>::get(1), Some((5, H256::from([1u8; 32]))));
+		System::assert_last_event(RuntimeEvent::Bitacross(Event::AuditLogAnchored {
+			account_id: 1,
+			entries: 5,
+			head: H256::from([1u8; 32]),
+		}));
+
+		// re-anchoring the same head is fine, e.g. if no request was signed in between
+		assert_ok!(Bitacross::audit_log_anchored(
+			RuntimeOrigin::signed(1),
+			5,
+			H256::from([1u8; 32])
+		));
+		assert_ok!(Bitacross::audit_log_anchored(
+			RuntimeOrigin::signed(1),
+			8,
+			H256::from([2u8; 32])
+		));
+		assert_eq!(AuditLogAnchor::<Test>::get(1), Some((8, H256::from([2u8; 32]))));
+
+		// anchors are kept per tee worker
+		assert_ok!(Bitacross::audit_log_anchored(
+			RuntimeOrigin::signed(2),
+			1,
+			H256::from([3u8; 32])
+		));
+		assert_eq!(AuditLogAnchor::<Test>::get(1), Some((8, H256::from([2u8; 32]))));
+	});
+}
+
+#[test]
+fn audit_log_anchored_rejects_truncated_log() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bitacross::audit_log_anchored(
+			RuntimeOrigin::signed(1),
+			5,
+			H256::from([1u8; 32])
+		));
+		assert_noop!(
+			Bitacross::audit_log_anchored(RuntimeOrigin::signed(1), 4, H256::from([2u8; 32])),
+			Error::<Test>::AuditLogAnchorOutdated
+		);
+	});
+}
+
+#[test]
+fn audit_log_anchored_rejects_rewritten_log() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bitacross::audit_log_anchored(
+			RuntimeOrigin::signed(1),
+			5,
+			H256::from([1u8; 32])
+		));
+		assert_noop!(
+			Bitacross::audit_log_anchored(RuntimeOrigin::signed(1), 5, H256::from([2u8; 32])),
+			Error::<Test>::AuditLogAnchorOutdated
+		);
+	});
+}
+
+#[test]
+fn audit_log_anchored_requires_tee_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Bitacross::audit_log_anchored(RuntimeOrigin::root(), 5, H256::from([1u8; 32])),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn sol_wallet_generated_works() {
+	new_test_ext().execute_with(|| {
+		let pair = ed25519::Pair::from_seed(&[1u8; 32]);
+		assert_ok!(Bitacross::sol_wallet_generated(
+			RuntimeOrigin::signed(1),
+			pair.public().0,
+			sol_key_proof(&pair, 1)
+		));
+		assert_eq!(Vault::<Test>::get(1).sol, Some(pair.public().0));
+		System::assert_last_event(RuntimeEvent::Bitacross(Event::SolWalletGenerated {
+			pub_key: pair.public().0,
+			account_id: 1,
+		}));
+	});
+}
+
+#[test]
+fn sol_wallet_generated_requires_proof_of_possession() {
+	new_test_ext().execute_with(|| {
+		let pair = ed25519::Pair::from_seed(&[1u8; 32]);
+		// a proof made for another worker can't be replayed
+		assert_noop!(
+			Bitacross::sol_wallet_generated(
+				RuntimeOrigin::signed(2),
+				pair.public().0,
+				sol_key_proof(&pair, 1)
+			),
+			Error::<Test>::InvalidSolKeyProof
+		);
+		// a key without the matching secret
+		let other = ed25519::Pair::from_seed(&[2u8; 32]);
+		assert_noop!(
+			Bitacross::sol_wallet_generated(
+				RuntimeOrigin::signed(1),
+				pair.public().0,
+				sol_key_proof(&other, 1)
+			),
+			Error::<Test>::InvalidSolKeyProof
+		);
+	});
+}
+
+#[test]
+fn add_relayer_emits_legacy_event() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bitacross::add_relayer(RuntimeOrigin::root(), relayer(1)));
+		assert_eq!(Relayer::<Test>::get(relayer(1)).unwrap().role, RelayerRole::Full);
+		System::assert_last_event(RuntimeEvent::Bitacross(Event::RelayerAdded { who: relayer(1) }));
+	});
+}
+
+#[test]
+fn add_relayer_keeps_existing_relayer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bitacross::set_relayer(
+			RuntimeOrigin::root(),
+			relayer(1),
+			RelayerRole::ReadOnly,
+			Some(5),
+			Default::default()
+		));
+		System::reset_events();
+		assert_ok!(Bitacross::add_relayer(RuntimeOrigin::root(), relayer(1)));
+		let info = Relayer::<Test>::get(relayer(1)).unwrap();
+		assert_eq!(info.role, RelayerRole::ReadOnly);
+		assert_eq!(info.expiry, Some(5));
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn set_relayer_works_and_expires() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bitacross::set_relayer(
+			RuntimeOrigin::root(),
+			relayer(1),
+			RelayerRole::BtcOnly,
+			Some(5),
+			Default::default()
+		));
+		System::assert_last_event(RuntimeEvent::Bitacross(Event::RelayerSet {
+			who: relayer(1),
+			role: RelayerRole::BtcOnly,
+			expiry: Some(5),
+		}));
+		// setting the same expiry again doesn't add a duplicated entry
+		assert_ok!(Bitacross::set_relayer(
+			RuntimeOrigin::root(),
+			relayer(1),
+			RelayerRole::Full,
+			Some(5),
+			Default::default()
+		));
+		assert_eq!(RelayerExpiry::<Test>::get(5).len(), 1);
+
+		Bitacross::on_initialize(5);
+		assert!(Relayer::<Test>::get(relayer(1)).is_none());
+		assert!(RelayerExpiry::<Test>::get(5).is_empty());
+		System::assert_last_event(RuntimeEvent::Bitacross(Event::RelayerRemoved {
+			who: relayer(1),
+		}));
+	});
+}
+
+#[test]
+fn set_relayer_bounds_expiring_relayers_per_block() {
+	new_test_ext().execute_with(|| {
+		for i in 0..MAX_RELAYERS_EXPIRING_PER_BLOCK {
+			assert_ok!(Bitacross::set_relayer(
+				RuntimeOrigin::root(),
+				relayer(i),
+				RelayerRole::Full,
+				Some(5),
+				Default::default()
+			));
+		}
+		assert_noop!(
+			Bitacross::set_relayer(
+				RuntimeOrigin::root(),
+				relayer(MAX_RELAYERS_EXPIRING_PER_BLOCK),
+				RelayerRole::Full,
+				Some(5),
+				Default::default()
+			),
+			Error::<Test>::TooManyRelayersExpiring
+		);
+		assert_ok!(Bitacross::set_relayer(
+			RuntimeOrigin::root(),
+			relayer(MAX_RELAYERS_EXPIRING_PER_BLOCK),
+			RelayerRole::Full,
+			Some(6),
+			Default::default()
+		));
+	});
+}
+
+#[test]
+fn changing_relayer_expiry_frees_its_slot() {
+	new_test_ext().execute_with(|| {
+		for i in 0..MAX_RELAYERS_EXPIRING_PER_BLOCK {
+			assert_ok!(Bitacross::set_relayer(
+				RuntimeOrigin::root(),
+				relayer(i),
+				RelayerRole::Full,
+				Some(5),
+				Default::default()
+			));
+		}
+		assert_ok!(Bitacross::set_relayer(
+			RuntimeOrigin::root(),
+			relayer(0),
+			RelayerRole::Full,
+			Some(6),
+			Default::default()
+		));
+		assert!(!RelayerExpiry::<Test>::get(5).contains(&relayer(0)));
+		assert_eq!(RelayerExpiry::<Test>::get(6).into_inner(), vec![relayer(0)]);
+		assert_ok!(Bitacross::remove_relayer(RuntimeOrigin::root(), relayer(1)));
+		assert!(!RelayerExpiry::<Test>::get(5).contains(&relayer(1)));
+
+		// both freed slots can be taken again
+		for i in [MAX_RELAYERS_EXPIRING_PER_BLOCK, MAX_RELAYERS_EXPIRING_PER_BLOCK + 1] {
+			assert_ok!(Bitacross::set_relayer(
+				RuntimeOrigin::root(),
+				relayer(i),
+				RelayerRole::Full,
+				Some(5),
+				Default::default()
+			));
+		}
+	});
+}
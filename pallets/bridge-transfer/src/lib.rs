@@ -73,9 +73,14 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Transfers some amount of non-native token to some recipient on a (whitelisted)
-		/// destination chain.
+		/// destination chain. The relayer fee of the destination chain, if any, is deducted from
+		/// the amount by the `BridgeHandler` when the transfer is signaled.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::transfer_assets())]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_assets().saturating_add(
+			<<T as bridge::Config>::WeightInfo as bridge::WeightInfo>::charge_relayer_fee(
+				<T as bridge::Config>::MaxRelayers::get(),
+			),
+		))]
 		#[transactional]
 		pub fn transfer_assets(
 			origin: OriginFor<T>,
@@ -86,8 +91,18 @@ pub mod pallet {
 		) -> DispatchResult {
 			let source = ensure_signed(origin)?;
 			ensure!(T::TransferAssetsMembers::contains(&source), BadOrigin);
+			if T::BridgeHandler::defer_token_bridge_out(
+				resource_id,
+				source.clone(),
+				dest_id,
+				recipient.clone(),
+				amount,
+			)? {
+				// the handler burns and signals the transfer once it's released
+				return Ok(())
+			}
 			let actual_dest_amount =
-				T::BridgeHandler::prepare_token_bridge_out(resource_id, source, amount)?;
+				T::BridgeHandler::prepare_token_bridge_out(resource_id, source, dest_id, amount)?;
 			<bridge::Pallet<T>>::signal_transfer_fungible(
 				dest_id,
 				resource_id,
@@ -108,6 +123,10 @@ pub mod pallet {
 			rid: ResourceId,
 		) -> DispatchResult {
 			T::BridgeOrigin::ensure_origin(origin)?;
+			if T::BridgeHandler::defer_token_bridge_in(rid, to.clone(), amount)? {
+				// the handler mints the amount once it's released
+				return Ok(())
+			}
 			T::BridgeHandler::prepare_token_bridge_in(rid, to, amount)?;
 			Ok(())
 		}
@@ -115,7 +134,33 @@ pub mod pallet {
 
 	pub trait BridgeHandler<B, A, R> {
 		fn prepare_token_bridge_in(resource_id: R, who: A, amount: B) -> Result<B, DispatchError>;
-		// Return actual amount to target chain after deduction e.g fee
-		fn prepare_token_bridge_out(resource_id: R, who: A, amount: B) -> Result<B, DispatchError>;
+		// Return actual amount to target chain after deduction e.g fee and the relayer fee of
+		// `dest_id`, see `bridge::Pallet::charge_relayer_fee`
+		fn prepare_token_bridge_out(
+			resource_id: R,
+			who: A,
+			dest_id: BridgeChainId,
+			amount: B,
+		) -> Result<B, DispatchError>;
+		// Return true if the incoming transfer is held back (e.g. rate limited), the handler is
+		// then responsible for preparing it later
+		fn defer_token_bridge_in(
+			_resource_id: R,
+			_who: A,
+			_amount: B,
+		) -> Result<bool, DispatchError> {
+			Ok(false)
+		}
+		// Return true if the outgoing transfer is held back (e.g. rate limited), the handler is
+		// then responsible for preparing and signaling it to the bridge later
+		fn defer_token_bridge_out(
+			_resource_id: R,
+			_who: A,
+			_dest_id: BridgeChainId,
+			_recipient: Vec<u8>,
+			_amount: B,
+		) -> Result<bool, DispatchError> {
+			Ok(false)
+		}
 	}
 }
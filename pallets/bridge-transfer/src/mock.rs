@@ -110,6 +110,7 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const TestChainId: u8 = 5;
 	pub const ProposalLifetime: u64 = 50;
+	pub const CompletedProposalLifetime: u64 = 100;
 	pub const TreasuryAccount:u64 = 0x8;
 }
 
@@ -120,6 +121,10 @@ impl bridge::Config for Test {
 	type BridgeChainId = TestChainId;
 	type Balance = Balance;
 	type ProposalLifetime = ProposalLifetime;
+	type CompletedProposalLifetime = CompletedProposalLifetime;
+	type MaxRelayers = ConstU32<16>;
+	type Currency = Balances;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 }
 
@@ -165,11 +170,13 @@ impl bridge_transfer::BridgeHandler<Balance, AccountId, ResourceId> for MockAsse
 	fn prepare_token_bridge_out(
 		_: ResourceId,
 		who: AccountId,
+		dest_id: bridge::BridgeChainId,
 		amount: Balance,
 	) -> Result<Balance, DispatchError> {
+		let relayer_fee = Bridge::charge_relayer_fee(&who, dest_id)?;
 		<Balances as fungible::Mutate<AccountId>>::burn_from(
 			&who,
-			amount,
+			amount - relayer_fee,
 			Precision::Exact,
 			Fortitude::Polite,
 		)
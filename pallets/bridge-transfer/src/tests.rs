@@ -186,3 +186,56 @@ fn create_successful_transfer_proposal() {
 		]);
 	})
 }
+
+#[test]
+fn transfer_assets_deducts_relayer_fee() {
+	let dest_bridge_id: bridge::BridgeChainId = 0;
+	let resource_id = NativeTokenResourceId::get();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::set_relayer_fee(RuntimeOrigin::root(), dest_bridge_id, 11));
+
+		// RELAYER_B and RELAYER_C get paid after approving a proposal
+		let proposal = make_transfer_proposal(RELAYER_A, 10);
+		assert_ok!(Bridge::acknowledge_proposal(
+			RuntimeOrigin::signed(RELAYER_B),
+			1,
+			dest_bridge_id,
+			resource_id,
+			Box::new(proposal.clone())
+		));
+		assert_ok!(Bridge::acknowledge_proposal(
+			RuntimeOrigin::signed(RELAYER_C),
+			1,
+			dest_bridge_id,
+			resource_id,
+			Box::new(proposal)
+		));
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 10);
+
+		let dest_account: Vec<u8> = vec![1];
+		assert_ok!(Pallet::<Test>::transfer_assets(
+			RuntimeOrigin::signed(RELAYER_A),
+			100,
+			dest_account.clone(),
+			dest_bridge_id,
+			resource_id
+		));
+		// the relayer fee is deducted from the amount
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 10 - 100);
+		assert_eq!(Balances::free_balance(RELAYER_B), 5);
+		assert_eq!(Balances::free_balance(RELAYER_C), 5);
+		// the remainder of the split goes to the treasury
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), ENDOWED_BALANCE + 1);
+		assert_events(vec![
+			RuntimeEvent::Balances(balances::Event::Burned { who: RELAYER_A, amount: 89 }),
+			RuntimeEvent::Bridge(bridge::Event::FungibleTransfer(
+				dest_bridge_id,
+				1,
+				resource_id,
+				89,
+				dest_account,
+			)),
+		]);
+	})
+}
@@ -23,6 +23,7 @@ use super::*;
 use crate::{BridgeChainId, Call, Event, Pallet as bridge};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::{Call as SystemCall, RawOrigin};
+use sp_runtime::traits::Saturating;
 use sp_std::{boxed::Box, vec, vec::Vec};
 
 const USER_SEED: u32 = 9966;
@@ -160,6 +161,58 @@ benchmarks! {
 
 	}:_(RawOrigin::Signed(relayer_id_c),prop_id,src_id,Box::new(proposal))
 
+	set_relayer_fee{
+		let dest_id:BridgeChainId = T::BridgeChainId::get().saturating_add(1);
+		let fee: BalanceOf<T> = 10u32.into();
+
+		bridge::<T>::whitelist_chain(
+			RawOrigin::Root.into(),
+			dest_id,
+		)?;
+	}:_(RawOrigin::Root,dest_id,fee)
+	verify{
+		assert_eq!(RelayerFees::<T>::get(dest_id),fee);
+	}
+
+	charge_relayer_fee{
+		let r in 1 .. T::MaxRelayers::get();
+
+		let dest_id:BridgeChainId = T::BridgeChainId::get().saturating_add(1);
+		let payer: T::AccountId = account("PAYER", 0u32, USER_SEED);
+		// every relayer gets a share and the remainder goes to the treasury
+		let share = T::Currency::minimum_balance().saturating_mul(10u32.into());
+		let fee = share.saturating_mul(r.into()).saturating_add(1u32.into());
+
+		T::Currency::make_free_balance_be(&payer, fee.saturating_mul(10u32.into()));
+		T::Currency::make_free_balance_be(&T::TreasuryAccount::get(), T::Currency::minimum_balance());
+		bridge::<T>::whitelist_chain(
+			RawOrigin::Root.into(),
+			dest_id,
+		)?;
+		bridge::<T>::set_relayer_fee(
+			RawOrigin::Root.into(),
+			dest_id,
+			fee,
+		)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		for i in 0..r {
+			let relayer_id: T::AccountId = account("RELAYER", i, USER_SEED);
+			bridge::<T>::add_relayer(
+				RawOrigin::Root.into(),
+				relayer_id.clone(),
+			)?;
+			RelayerVoteStats::<T>::insert(
+				relayer_id,
+				RelayerStats { last_approved_at: Some(now), ..Default::default() },
+			);
+		}
+	}:{
+		bridge::<T>::charge_relayer_fee(&payer, dest_id)?;
+	}
+	verify{
+		assert_last_event::<T>(Event::RelayerFeeToTreasury(1u32.into()).into());
+	}
+
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -34,14 +34,18 @@ pub use weights::WeightInfo;
 pub mod pallet {
 	use crate::weights::WeightInfo;
 	use frame_support::dispatch::GetDispatchInfo;
-	pub use frame_support::{pallet_prelude::*, traits::StorageVersion, PalletId, Parameter};
+	pub use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement, StorageVersion},
+		PalletId, Parameter,
+	};
 	use frame_system::{
 		pallet_prelude::*,
 		{self as system},
 	};
 	use parity_scale_codec::{Codec, EncodeLike};
 	use sp_runtime::{
-		traits::{AccountIdConversion, AtLeast32BitUnsigned, Dispatchable},
+		traits::{AccountIdConversion, AtLeast32BitUnsigned, Dispatchable, Saturating, Zero},
 		FixedPointOperand, SaturatedConversion,
 	};
 
@@ -79,6 +83,8 @@ pub mod pallet {
 		Approved,
 		#[codec(index = 2)]
 		Rejected,
+		#[codec(index = 3)]
+		Expired,
 	}
 
 	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -130,6 +136,17 @@ pub mod pallet {
 		}
 	}
 
+	/// Vote statistics of a relayer, used to track its liveness and the fees it earned
+	#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct RelayerStats<BlockNumber, Balance> {
+		pub votes_for: u32,
+		pub votes_against: u32,
+		pub last_voted_at: BlockNumber,
+		/// Block of the last approved proposal the relayer voted for
+		pub last_approved_at: Option<BlockNumber>,
+		pub fees_earned: Balance,
+	}
+
 	impl<AccountId, BlockNumber: Default> Default for ProposalVotes<AccountId, BlockNumber> {
 		fn default() -> Self {
 			Self {
@@ -182,6 +199,22 @@ pub mod pallet {
 		#[pallet::constant]
 		type ProposalLifetime: Get<BlockNumberFor<Self>>;
 
+		/// Number of blocks the status of a completed or expired proposal is kept, the
+		/// proposals of older deposits can't be voted on anymore once it's pruned
+		#[pallet::constant]
+		type CompletedProposalLifetime: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of relayers, bounds the relayers paid for each transfer
+		#[pallet::constant]
+		type MaxRelayers: Get<u32>;
+
+		/// The currency in which the relayer fees are paid
+		type Currency: Currency<Self::AccountId, Balance = BalanceOf<Self>>;
+
+		/// Account receiving the part of the relayer fees that can't be split evenly among the
+		/// relayers
+		type TreasuryAccount: Get<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -217,6 +250,15 @@ pub mod pallet {
 		ProposalSucceeded(BridgeChainId, DepositNonce),
 		/// Execution of call failed
 		ProposalFailed(BridgeChainId, DepositNonce),
+		/// Relayer fee for transfers to a chain has changed (dest_id, fee)
+		RelayerFeeSet(BridgeChainId, BalanceOf<T>),
+		/// Relayer received its share of a transfer fee (relayer, amount)
+		RelayerFeePaid(T::AccountId, BalanceOf<T>),
+		/// Completed or expired proposal was removed from storage
+		ProposalPruned(BridgeChainId, DepositNonce),
+		/// Transfer fee, or the part of it that couldn't be split evenly among the relayers, was
+		/// sent to the treasury (amount)
+		RelayerFeeToTreasury(BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -252,6 +294,8 @@ pub mod pallet {
 		/// Lifetime of proposal has been exceeded
 		ProposalExpired,
 		NonceOverflow,
+		/// Relayer set is full
+		TooManyRelayers,
 	}
 
 	#[pallet::storage]
@@ -294,6 +338,55 @@ pub mod pallet {
 	#[pallet::getter(fn bridge_events)]
 	pub type BridgeEvents<T> = StorageValue<_, Vec<BridgeEvent>, ValueQuery>;
 
+	// status of the proposals that were approved, rejected or expired and the block it was
+	// reached at, kept after the proposal votes are pruned so that the same deposit can't be voted
+	// on again
+	#[pallet::storage]
+	#[pallet::getter(fn completed_proposals)]
+	pub type CompletedProposals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_256,
+		BridgeChainId,
+		Blake2_256,
+		DepositNonce,
+		(ProposalStatus, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
+	// nonce of each source chain up to which the completed proposal statuses were pruned,
+	// proposals for nonces up to it can't be created again. It only advances over contiguous
+	// nonces, the statuses after a nonce that wasn't completed yet are kept until it's pruned
+	#[pallet::storage]
+	#[pallet::getter(fn pruned_nonce)]
+	pub type PrunedNonces<T> = StorageMap<_, Blake2_256, BridgeChainId, DepositNonce, ValueQuery>;
+
+	// raw `Votes` key of the last proposal checked by the pruning sweep,
+	// the next sweep continues after it
+	#[pallet::storage]
+	pub type PruningCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	// raw `CompletedProposals` key of the last status checked by the pruning sweep,
+	// the next sweep continues after it
+	#[pallet::storage]
+	pub type CompletedPruningCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_stats)]
+	pub type RelayerVoteStats<T: Config> = StorageMap<
+		_,
+		Blake2_256,
+		T::AccountId,
+		RelayerStats<BlockNumberFor<T>, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	// fee deducted from the amount of each native token transfer to the chain, split among the
+	// relayers that recently voted for an approved proposal
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_fee)]
+	pub type RelayerFees<T: Config> =
+		StorageMap<_, Blake2_256, BridgeChainId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
@@ -301,6 +394,13 @@ pub mod pallet {
 			BridgeEvents::<T>::kill();
 			Weight::zero()
 		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let used_weight = Self::prune_proposals(remaining_weight);
+			used_weight.saturating_add(Self::prune_completed_proposals(
+				remaining_weight.saturating_sub(used_weight),
+			))
+		}
 	}
 
 	#[pallet::call]
@@ -368,6 +468,7 @@ pub mod pallet {
 		#[pallet::weight({
 		let di = call.get_dispatch_info();
 		(< T as Config >::WeightInfo::acknowledge_proposal()
+		.saturating_add(Pallet::<T>::approval_weight())
 		.saturating_add(di.weight),
 		di.class)
 		})]
@@ -422,6 +523,7 @@ pub mod pallet {
 		#[pallet::weight({
 		let di = prop.get_dispatch_info();
 		(< T as Config >::WeightInfo::eval_vote_state()
+		.saturating_add(Pallet::<T>::approval_weight())
 		.saturating_add(di.weight),
 		di.class)
 		})]
@@ -435,6 +537,26 @@ pub mod pallet {
 
 			Self::try_resolve_proposal(nonce, src_id, prop)
 		}
+
+		/// Sets the fee charged for each transfer to the given chain, it's split among the
+		/// relayers that voted for a proposal approved within the last `ProposalLifetime` blocks.
+		///
+		/// # <weight>
+		/// - O(1) write
+		/// # </weight>
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_relayer_fee())]
+		pub fn set_relayer_fee(
+			origin: OriginFor<T>,
+			dest_id: BridgeChainId,
+			fee: BalanceOf<T>,
+		) -> DispatchResult {
+			T::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			ensure!(Self::chain_whitelisted(dest_id), Error::<T>::ChainNotWhitelisted);
+			RelayerFees::<T>::insert(dest_id, fee);
+			Self::deposit_event(Event::RelayerFeeSet(dest_id, fee));
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -490,6 +612,7 @@ pub mod pallet {
 		/// Adds a new relayer to the set
 		pub fn register_relayer(relayer: T::AccountId) -> DispatchResult {
 			ensure!(!Self::is_relayer(&relayer), Error::<T>::RelayerAlreadyExists);
+			ensure!(Self::relayer_count() < T::MaxRelayers::get(), Error::<T>::TooManyRelayers);
 			Relayers::<T>::insert(&relayer, true);
 			RelayerCount::<T>::mutate(|i| *i += 1);
 
@@ -501,6 +624,7 @@ pub mod pallet {
 		pub fn unregister_relayer(relayer: T::AccountId) -> DispatchResult {
 			ensure!(Self::is_relayer(&relayer), Error::<T>::RelayerInvalid);
 			Relayers::<T>::remove(&relayer);
+			RelayerVoteStats::<T>::remove(&relayer);
 			RelayerCount::<T>::mutate(|i| *i -= 1);
 			Self::deposit_event(Event::RelayerRemoved(relayer));
 			Ok(())
@@ -519,8 +643,18 @@ pub mod pallet {
 			let now = <frame_system::Pallet<T>>::block_number();
 			let mut votes = match Votes::<T>::get(src_id, (nonce, prop.clone())) {
 				Some(v) => v,
-				None =>
-					ProposalVotes { expiry: now + T::ProposalLifetime::get(), ..Default::default() },
+				None => {
+					// the votes of a completed or expired proposal may have been pruned already
+					match Self::completed_proposals(src_id, nonce) {
+						Some((ProposalStatus::Expired, _)) =>
+							return Err(Error::<T>::ProposalExpired.into()),
+						Some(_) => return Err(Error::<T>::ProposalAlreadyComplete.into()),
+						None => (),
+					}
+					// and so may the status of old deposits, see `PrunedNonces`
+					ensure!(nonce > Self::pruned_nonce(src_id), Error::<T>::ProposalExpired);
+					ProposalVotes { expiry: now + T::ProposalLifetime::get(), ..Default::default() }
+				},
 			};
 
 			// Ensure the proposal isn't complete and relayer hasn't already voted
@@ -528,6 +662,16 @@ pub mod pallet {
 			ensure!(!votes.is_expired(now), Error::<T>::ProposalExpired);
 			ensure!(!votes.has_voted(&who), Error::<T>::RelayerAlreadyVoted);
 
+			RelayerVoteStats::<T>::mutate(&who, |stats| {
+				let stats = stats.get_or_insert_with(Default::default);
+				if in_favour {
+					stats.votes_for.saturating_inc();
+				} else {
+					stats.votes_against.saturating_inc();
+				}
+				stats.last_voted_at = now;
+			});
+
 			if in_favour {
 				votes.votes_for.push(who.clone());
 				Self::deposit_event(Event::VoteFor(src_id, nonce, who));
@@ -554,7 +698,19 @@ pub mod pallet {
 
 				let status =
 					votes.try_to_complete(RelayerThreshold::<T>::get(), RelayerCount::<T>::get());
+				if status == ProposalStatus::Approved {
+					for relayer in votes.votes_for.iter() {
+						RelayerVoteStats::<T>::mutate(relayer, |stats| {
+							if let Some(stats) = stats {
+								stats.last_approved_at = Some(now);
+							}
+						});
+					}
+				}
 				Votes::<T>::insert(src_id, (nonce, prop.clone()), votes);
+				if status != ProposalStatus::Initiated {
+					CompletedProposals::<T>::insert(src_id, nonce, (status.clone(), now));
+				}
 
 				match status {
 					ProposalStatus::Approved => Self::finalize_execution(src_id, nonce, prop),
@@ -610,6 +766,198 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Weight of updating the stats of the voters once a proposal is approved
+		pub fn approval_weight() -> Weight {
+			let max_relayers = T::MaxRelayers::get() as u64;
+			T::DbWeight::get().reads_writes(max_relayers, max_relayers)
+		}
+
+		/// Removes the proposals that are completed or expired, continuing from where the last
+		/// sweep stopped. An expired proposal leaves its status behind so that it can't be
+		/// voted on again. Returns the consumed weight.
+		fn prune_proposals(remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			// cursor
+			let mut used_weight = db_weight.reads_writes(1, 1);
+			// proposal votes
+			let check_weight = db_weight.reads(1);
+			// proposal votes and status
+			let prune_weight = db_weight.reads_writes(1, 2);
+
+			let step_weight = check_weight.saturating_add(prune_weight);
+			if remaining_weight.any_lt(used_weight.saturating_add(step_weight)) {
+				return Weight::zero()
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut proposals = match PruningCursor::<T>::get() {
+				Some(cursor) => Votes::<T>::iter_from(cursor),
+				None => Votes::<T>::iter(),
+			};
+
+			let mut cursor = None;
+			while remaining_weight.all_gte(used_weight.saturating_add(step_weight)) {
+				let (src_id, key, votes) = match proposals.next() {
+					Some(proposal) => proposal,
+					None => {
+						// all proposals are checked, start over in the next sweep
+						cursor = None;
+						break
+					},
+				};
+				used_weight.saturating_accrue(check_weight);
+				cursor = Some(Votes::<T>::hashed_key_for(src_id, &key));
+				if votes.is_complete() || votes.is_expired(now) {
+					used_weight.saturating_accrue(prune_weight);
+					let nonce = key.0;
+					// another proposal with the same nonce may have been completed already
+					if !votes.is_complete() && !CompletedProposals::<T>::contains_key(src_id, nonce)
+					{
+						CompletedProposals::<T>::insert(
+							src_id,
+							nonce,
+							(ProposalStatus::Expired, now),
+						);
+					}
+					Votes::<T>::remove(src_id, key);
+					Self::deposit_event(Event::ProposalPruned(src_id, nonce));
+				}
+			}
+
+			PruningCursor::<T>::set(cursor);
+			used_weight
+		}
+
+		/// Removes the status of the proposals completed or expired at least
+		/// `CompletedProposalLifetime` blocks ago, continuing from where the last sweep stopped.
+		/// Returns the consumed weight.
+		fn prune_completed_proposals(remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			// cursor
+			let mut used_weight = db_weight.reads_writes(1, 1);
+			// proposal status and pruned nonce
+			let check_weight = db_weight.reads(2);
+			// proposal status and pruned nonce, and the status of the next nonce
+			let prune_weight = db_weight.reads_writes(1, 2);
+
+			let step_weight = check_weight.saturating_add(prune_weight);
+			if remaining_weight.any_lt(used_weight.saturating_add(step_weight)) {
+				return Weight::zero()
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let is_prunable = |completed_at: BlockNumberFor<T>| {
+				completed_at.saturating_add(T::CompletedProposalLifetime::get()) <= now
+			};
+			let mut statuses = match CompletedPruningCursor::<T>::get() {
+				Some(cursor) => CompletedProposals::<T>::iter_from(cursor),
+				None => CompletedProposals::<T>::iter(),
+			};
+
+			let mut cursor = None;
+			while remaining_weight.all_gte(used_weight.saturating_add(step_weight)) {
+				let (src_id, nonce, (_, completed_at)) = match statuses.next() {
+					Some(status) => status,
+					None => {
+						// all statuses are checked, start over in the next sweep
+						cursor = None;
+						break
+					},
+				};
+				used_weight.saturating_accrue(check_weight);
+				cursor = Some(CompletedProposals::<T>::hashed_key_for(src_id, nonce));
+				if !is_prunable(completed_at) ||
+					nonce > Self::pruned_nonce(src_id).saturating_add(1)
+				{
+					continue
+				}
+				// the statuses of the following nonces may have been checked already in this
+				// sweep, they are pruned right away as long as the nonces are contiguous
+				let mut nonce = nonce;
+				loop {
+					used_weight.saturating_accrue(prune_weight);
+					CompletedProposals::<T>::remove(src_id, nonce);
+					PrunedNonces::<T>::mutate(src_id, |pruned| *pruned = nonce.max(*pruned));
+					let next = nonce.saturating_add(1);
+					match CompletedProposals::<T>::get(src_id, next) {
+						Some((_, completed_at))
+							if is_prunable(completed_at) &&
+								remaining_weight
+									.all_gte(used_weight.saturating_add(prune_weight)) =>
+							nonce = next,
+						_ => break,
+					}
+				}
+			}
+
+			CompletedPruningCursor::<T>::set(cursor);
+			used_weight
+		}
+
+		/// Returns the relayers that voted for a proposal approved within the last
+		/// `ProposalLifetime` blocks
+		pub fn approving_relayers() -> Vec<T::AccountId> {
+			let now = <frame_system::Pallet<T>>::block_number();
+			// the stats are removed together with the relayer
+			RelayerVoteStats::<T>::iter()
+				.filter(|(_, stats)| {
+					stats.last_approved_at.map_or(false, |approved_at| {
+						approved_at.saturating_add(T::ProposalLifetime::get()) >= now
+					})
+				})
+				.map(|(relayer, _)| relayer)
+				.collect()
+		}
+
+		/// Charges the relayer fee for a transfer to `dest_id` from `who` and splits it evenly
+		/// among the relayers that recently voted for an approved proposal. What can't be split
+		/// evenly, or the whole fee if there's no such relayer, goes to the treasury.
+		/// Returns the charged amount. This should be called by another pallet when the transfer
+		/// is signaled, with the fee being part of the transferred amount.
+		pub fn charge_relayer_fee(
+			who: &T::AccountId,
+			dest_id: BridgeChainId,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let fee = Self::relayer_fee(dest_id);
+			if fee.is_zero() {
+				return Ok(Zero::zero())
+			}
+
+			let relayers = Self::approving_relayers();
+			let mut remainder = fee;
+			if !relayers.is_empty() {
+				let share = fee / BalanceOf::<T>::from(relayers.len() as u32);
+				if !share.is_zero() {
+					for relayer in relayers {
+						T::Currency::transfer(
+							who,
+							&relayer,
+							share,
+							ExistenceRequirement::AllowDeath,
+						)?;
+						RelayerVoteStats::<T>::mutate(&relayer, |stats| {
+							if let Some(stats) = stats {
+								stats.fees_earned = stats.fees_earned.saturating_add(share);
+							}
+						});
+						remainder = remainder.saturating_sub(share);
+						Self::deposit_event(Event::RelayerFeePaid(relayer, share));
+					}
+				}
+			}
+
+			if !remainder.is_zero() {
+				T::Currency::transfer(
+					who,
+					&T::TreasuryAccount::get(),
+					remainder,
+					ExistenceRequirement::AllowDeath,
+				)?;
+				Self::deposit_event(Event::RelayerFeeToTreasury(remainder));
+			}
+			Ok(fee)
+		}
+
 		/// Initiates a singal Event for fungible asset out of the chain. This should be called by
 		/// another pallet.
 		pub fn signal_transfer_fungible(
@@ -16,14 +16,15 @@
 
 use super::{
 	mock::{
-		assert_events, new_test_ext, new_test_ext_initialized, Balances, Bridge, ProposalLifetime,
-		RuntimeCall, RuntimeEvent, RuntimeOrigin, System, Test, TestChainId, ENDOWED_BALANCE,
-		RELAYER_A, RELAYER_B, RELAYER_C, TEST_THRESHOLD,
+		assert_events, new_test_ext, new_test_ext_initialized, Balances, Bridge,
+		CompletedProposalLifetime, ProposalLifetime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+		System, Test, TestChainId, TreasuryAccount, ENDOWED_BALANCE, RELAYER_A, RELAYER_B,
+		RELAYER_C, TEST_THRESHOLD,
 	},
 	pallet::Event as PalletEvent,
 	*,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
 use frame_system as system;
 
 #[test]
@@ -158,6 +159,20 @@ fn add_remove_relayer() {
 	})
 }
 
+#[test]
+fn add_relayer_fails_when_relayer_set_is_full() {
+	new_test_ext().execute_with(|| {
+		let max_relayers = <Test as Config>::MaxRelayers::get() as u64;
+		for relayer in 0..max_relayers {
+			assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), 100 + relayer));
+		}
+		assert_noop!(
+			Bridge::add_relayer(RuntimeOrigin::root(), RELAYER_A),
+			Error::<Test>::TooManyRelayers
+		);
+	})
+}
+
 fn make_proposal(remark: Vec<u8>) -> RuntimeCall {
 	RuntimeCall::System(system::Call::remark { remark })
 }
@@ -472,3 +487,265 @@ fn transfer_fungible_no_whitelist() {
 		);
 	})
 }
+
+#[test]
+fn prune_proposals_works() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"remark");
+
+	new_test_ext_initialized(src_id, r_id).execute_with(|| {
+		let proposal = make_proposal(vec![10]);
+
+		// proposal 1 stays initiated
+		assert_ok!(Bridge::acknowledge_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			1,
+			src_id,
+			r_id,
+			Box::new(proposal.clone())
+		));
+		// proposal 2 gets approved
+		assert_ok!(Bridge::acknowledge_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			2,
+			src_id,
+			r_id,
+			Box::new(proposal.clone())
+		));
+		assert_ok!(Bridge::acknowledge_proposal(
+			RuntimeOrigin::signed(RELAYER_B),
+			2,
+			src_id,
+			r_id,
+			Box::new(proposal.clone())
+		));
+		assert_eq!(Bridge::completed_proposals(src_id, 2), Some((ProposalStatus::Approved, 1)));
+
+		// not enough weight to check a single proposal
+		assert_eq!(Bridge::on_idle(1, Weight::zero()), Weight::zero());
+		assert!(Bridge::votes(src_id, (2, proposal.clone())).is_some());
+
+		Bridge::on_idle(1, Weight::MAX);
+		assert!(Bridge::votes(src_id, (1, proposal.clone())).is_some());
+		assert!(Bridge::votes(src_id, (2, proposal.clone())).is_none());
+		assert_events(vec![RuntimeEvent::Bridge(PalletEvent::ProposalPruned(src_id, 2))]);
+
+		// the pruned proposal can't be voted on again
+		assert_noop!(
+			Bridge::acknowledge_proposal(
+				RuntimeOrigin::signed(RELAYER_C),
+				2,
+				src_id,
+				r_id,
+				Box::new(proposal.clone())
+			),
+			Error::<Test>::ProposalAlreadyComplete
+		);
+
+		// proposal 1 is pruned once it expires
+		let expired_at = ProposalLifetime::get() + 1;
+		System::set_block_number(expired_at);
+		Bridge::on_idle(expired_at, Weight::MAX);
+		assert!(Bridge::votes(src_id, (1, proposal.clone())).is_none());
+		assert_events(vec![RuntimeEvent::Bridge(PalletEvent::ProposalPruned(src_id, 1))]);
+		assert_eq!(
+			Bridge::completed_proposals(src_id, 1),
+			Some((ProposalStatus::Expired, expired_at))
+		);
+
+		// and can't be voted on again either
+		assert_noop!(
+			Bridge::acknowledge_proposal(
+				RuntimeOrigin::signed(RELAYER_B),
+				1,
+				src_id,
+				r_id,
+				Box::new(proposal)
+			),
+			Error::<Test>::ProposalExpired
+		);
+	})
+}
+
+#[test]
+fn completed_proposals_are_pruned_by_age() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"remark");
+
+	new_test_ext_initialized(src_id, r_id).execute_with(|| {
+		let proposal = make_proposal(vec![10]);
+		let approve = |nonce| {
+			for relayer in [RELAYER_A, RELAYER_B] {
+				assert_ok!(Bridge::acknowledge_proposal(
+					RuntimeOrigin::signed(relayer),
+					nonce,
+					src_id,
+					r_id,
+					Box::new(proposal.clone())
+				));
+			}
+		};
+		approve(2);
+		Bridge::on_idle(1, Weight::MAX);
+		assert!(Bridge::votes(src_id, (2, proposal.clone())).is_none());
+
+		// the status is kept for `CompletedProposalLifetime` blocks
+		let pruned_at = 1 + CompletedProposalLifetime::get();
+		System::set_block_number(pruned_at - 1);
+		Bridge::on_idle(pruned_at - 1, Weight::MAX);
+		assert_eq!(Bridge::completed_proposals(src_id, 2), Some((ProposalStatus::Approved, 1)));
+		assert_eq!(Bridge::pruned_nonce(src_id), 0);
+
+		// and after that as long as the deposit before it isn't completed
+		System::set_block_number(pruned_at);
+		Bridge::on_idle(pruned_at, Weight::MAX);
+		assert_eq!(Bridge::completed_proposals(src_id, 2), Some((ProposalStatus::Approved, 1)));
+		assert_eq!(Bridge::pruned_nonce(src_id), 0);
+
+		// which can still be voted on
+		approve(1);
+		let pruned_at = pruned_at + CompletedProposalLifetime::get();
+		System::set_block_number(pruned_at);
+		Bridge::on_idle(pruned_at, Weight::MAX);
+		assert_eq!(Bridge::completed_proposals(src_id, 1), None);
+		assert_eq!(Bridge::completed_proposals(src_id, 2), None);
+		assert_eq!(Bridge::pruned_nonce(src_id), 2);
+
+		// deposits up to the pruned nonce can't be voted on anymore
+		for nonce in [1, 2] {
+			assert_noop!(
+				Bridge::acknowledge_proposal(
+					RuntimeOrigin::signed(RELAYER_C),
+					nonce,
+					src_id,
+					r_id,
+					Box::new(proposal.clone())
+				),
+				Error::<Test>::ProposalExpired
+			);
+		}
+		assert_ok!(Bridge::acknowledge_proposal(
+			RuntimeOrigin::signed(RELAYER_C),
+			3,
+			src_id,
+			r_id,
+			Box::new(proposal)
+		));
+	})
+}
+
+#[test]
+fn relayer_stats_are_tracked() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"remark");
+
+	new_test_ext_initialized(src_id, r_id).execute_with(|| {
+		let proposal = make_proposal(vec![10]);
+		assert_eq!(Bridge::relayer_stats(RELAYER_A), None);
+
+		assert_ok!(Bridge::acknowledge_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			1,
+			src_id,
+			r_id,
+			Box::new(proposal.clone())
+		));
+		System::set_block_number(2);
+		assert_ok!(Bridge::reject_proposal(
+			RuntimeOrigin::signed(RELAYER_B),
+			1,
+			src_id,
+			r_id,
+			Box::new(proposal)
+		));
+
+		assert_eq!(
+			Bridge::relayer_stats(RELAYER_A),
+			Some(RelayerStats {
+				votes_for: 1,
+				votes_against: 0,
+				last_voted_at: 1,
+				last_approved_at: None,
+				fees_earned: 0
+			})
+		);
+		assert_eq!(
+			Bridge::relayer_stats(RELAYER_B),
+			Some(RelayerStats {
+				votes_for: 0,
+				votes_against: 1,
+				last_voted_at: 2,
+				last_approved_at: None,
+				fees_earned: 0
+			})
+		);
+
+		// stats are removed together with the relayer
+		assert_ok!(Bridge::remove_relayer(RuntimeOrigin::root(), RELAYER_B));
+		assert_eq!(Bridge::relayer_stats(RELAYER_B), None);
+	})
+}
+
+#[test]
+fn relayer_fee_is_distributed_to_approving_relayers() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"remark");
+	let payer = Bridge::account_id();
+	let treasury = TreasuryAccount::get();
+
+	new_test_ext_initialized(src_id, r_id).execute_with(|| {
+		assert_noop!(
+			Bridge::set_relayer_fee(RuntimeOrigin::root(), src_id + 1, 11),
+			Error::<Test>::ChainNotWhitelisted
+		);
+		assert_ok!(Bridge::set_relayer_fee(RuntimeOrigin::root(), src_id, 11));
+		assert_events(vec![RuntimeEvent::Bridge(PalletEvent::RelayerFeeSet(src_id, 11))]);
+
+		// no relayer has voted for an approved proposal yet, the fee goes to the treasury
+		assert_eq!(Bridge::charge_relayer_fee(&payer, src_id), Ok(11));
+		assert_eq!(Balances::free_balance(payer), ENDOWED_BALANCE - 11);
+		assert_eq!(Balances::free_balance(treasury), ENDOWED_BALANCE + 11);
+		assert_events(vec![RuntimeEvent::Bridge(PalletEvent::RelayerFeeToTreasury(11))]);
+
+		// RELAYER_C votes against the proposal approved by RELAYER_A and RELAYER_B
+		let proposal = make_proposal(vec![10]);
+		assert_ok!(Bridge::reject_proposal(
+			RuntimeOrigin::signed(RELAYER_C),
+			1,
+			src_id,
+			r_id,
+			Box::new(proposal.clone())
+		));
+		for relayer in [RELAYER_A, RELAYER_B] {
+			assert_ok!(Bridge::acknowledge_proposal(
+				RuntimeOrigin::signed(relayer),
+				1,
+				src_id,
+				r_id,
+				Box::new(proposal.clone())
+			));
+		}
+		assert_eq!(Bridge::relayer_stats(RELAYER_A).unwrap().last_approved_at, Some(1));
+		assert_eq!(Bridge::relayer_stats(RELAYER_C).unwrap().last_approved_at, None);
+
+		// the remainder of the split goes to the treasury
+		assert_eq!(Bridge::charge_relayer_fee(&payer, src_id), Ok(11));
+		assert_eq!(Balances::free_balance(payer), ENDOWED_BALANCE - 22);
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE + 5);
+		assert_eq!(Balances::free_balance(RELAYER_B), 5);
+		assert_eq!(Balances::free_balance(RELAYER_C), 0);
+		assert_eq!(Balances::free_balance(treasury), ENDOWED_BALANCE + 12);
+		assert_eq!(Bridge::relayer_stats(RELAYER_A).unwrap().fees_earned, 5);
+		assert_eq!(Bridge::relayer_stats(RELAYER_B).unwrap().fees_earned, 5);
+		assert!(System::events()
+			.iter()
+			.any(|e| e.event == RuntimeEvent::Bridge(PalletEvent::RelayerFeePaid(RELAYER_A, 5))));
+		assert_events(vec![RuntimeEvent::Bridge(PalletEvent::RelayerFeeToTreasury(1))]);
+
+		// relayers only get paid within the proposal lifetime of their last approval
+		System::set_block_number(ProposalLifetime::get() + 2);
+		assert!(Bridge::approving_relayers().is_empty());
+		assert_eq!(Bridge::charge_relayer_fee(&payer, src_id), Ok(11));
+		assert_eq!(Balances::free_balance(treasury), ENDOWED_BALANCE + 23);
+	})
+}
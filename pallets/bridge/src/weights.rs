@@ -54,6 +54,8 @@ pub trait WeightInfo {
 	fn acknowledge_proposal() -> Weight;
 	fn reject_proposal() -> Weight;
 	fn eval_vote_state() -> Weight;
+	fn set_relayer_fee() -> Weight;
+	fn charge_relayer_fee(r: u32, ) -> Weight;
 }
 
 /// Weights for pallet_bridge using the Litentry node and recommended hardware.
@@ -114,6 +116,24 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI: the `set_relayer_fee` benchmark has not
+	// been run yet. The call does one `ChainNonces` read and one `RelayerFees` write, the same
+	// storage access as `whitelist_chain`, so it reuses that weight.
+	fn set_relayer_fee() -> Weight {
+		Self::whitelist_chain()
+	}
+	// Estimate, not generated by the benchmark CLI: the `charge_relayer_fee` benchmark has not
+	// been run yet. The figures are hand-picked upper bounds for one balance transfer and one
+	// `RelayerVoteStats` update per paid relayer, plus the transfer of the remainder to the
+	// treasury. Replace them with the CLI output once the benchmark has been run.
+	fn charge_relayer_fee(r: u32, ) -> Weight {
+		Weight::from_parts(50_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(50_000_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -173,4 +193,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI: the `set_relayer_fee` benchmark has not
+	// been run yet. The call does one `ChainNonces` read and one `RelayerFees` write, the same
+	// storage access as `whitelist_chain`, so it reuses that weight.
+	fn set_relayer_fee() -> Weight {
+		Self::whitelist_chain()
+	}
+	// Estimate, not generated by the benchmark CLI: the `charge_relayer_fee` benchmark has not
+	// been run yet. The figures are hand-picked upper bounds for one balance transfer and one
+	// `RelayerVoteStats` update per paid relayer, plus the transfer of the remainder to the
+	// treasury. Replace them with the CLI output once the benchmark has been run.
+	fn charge_relayer_fee(r: u32, ) -> Weight {
+		Weight::from_parts(50_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(50_000_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+	}
 }
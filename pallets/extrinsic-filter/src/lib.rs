@@ -56,7 +56,22 @@
 //! - simplicity
 //! - whitelisting is not supported
 //!
-//! All dispatchables in this pallet must come from a priviledged origin.
+//! A block can be given an expiry block via `block_extrinsics_until`, it's lifted automatically
+//! once the expiry is reached. Mode transitions can be scheduled ahead with `schedule_mode`,
+//! e.g. to enter and leave the safe mode for a maintenance window.
+//!
+//! Accounts in the allowlist (e.g. teebag enclaves) can bypass blocked extrinsics during
+//! incidents by wrapping their calls in `dispatch_allowlisted`, the mode filter still applies.
+//!
+//! Blocking can be narrowed down to certain call arguments with `set_argument_filter`, e.g. a
+//! transfer to a specific account or calls for a single asset id. The predicates are matched
+//! against the decoded call by the runtime, see [`CallArgumentMatcher`]. A filter can cover a
+//! single extrinsic or all extrinsics in a pallet, the latter also catches sibling calls like
+//! `transfer_keep_alive` or `transfer_all`. Calls nested in e.g. `utility::batch` go through
+//! the same filter when they are dispatched.
+//!
+//! Apart from `dispatch_allowlisted`, all dispatchables in this pallet must come from a
+//! priviledged origin.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -71,13 +86,14 @@ mod benchmarking;
 pub mod weights;
 
 use frame_support::{
-	dispatch::{CallMetadata, GetCallMetadata},
+	dispatch::{CallMetadata, GetCallMetadata, GetDispatchInfo, PostDispatchInfo},
 	pallet_prelude::*,
-	traits::{Contains, StorageVersion},
+	traits::{Contains, StorageVersion, UnfilteredDispatchable},
 	transactional,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
+use sp_runtime::traits::Dispatchable;
 use sp_std::prelude::*;
 
 use scale_info::TypeInfo;
@@ -97,6 +113,20 @@ pub enum OperationalMode {
 	Test,
 }
 
+/// Matches the decoded arguments of a call against a predicate
+///
+/// It's implemented in the runtime which knows the concrete call types, e.g. to resolve a
+/// `MultiAddress` before comparing the account.
+pub trait CallArgumentMatcher<Call, Predicate> {
+	fn matches(call: &Call, predicate: &Predicate) -> bool;
+}
+
+impl<Call, Predicate> CallArgumentMatcher<Call, Predicate> for () {
+	fn matches(_call: &Call, _predicate: &Predicate) -> bool {
+		false
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -114,16 +144,41 @@ pub mod pallet {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The overarching call type, used by `dispatch_allowlisted`
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
 		/// The priviledged origin to perform all operations
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// Filters that will be specified in runtime binding
-		type NormalModeFilter: Contains<Self::RuntimeCall>;
-		type SafeModeFilter: Contains<Self::RuntimeCall>;
-		type TestModeFilter: Contains<Self::RuntimeCall>;
+		type NormalModeFilter: Contains<<Self as frame_system::Config>::RuntimeCall>;
+		type SafeModeFilter: Contains<<Self as frame_system::Config>::RuntimeCall>;
+		type TestModeFilter: Contains<<Self as frame_system::Config>::RuntimeCall>;
 
 		/// Weights
 		type WeightInfo: WeightInfo;
+
+		/// The maximum number of blocked extrinsics expiring at the same block
+		#[pallet::constant]
+		type MaxExpiringExtrinsicsPerBlock: Get<u32>;
+
+		/// Predicate on the call arguments, e.g. a target account or an asset id
+		type ArgumentPredicate: Parameter + Member;
+
+		/// Matches the calls against `ArgumentPredicate`
+		type ArgumentMatcher: CallArgumentMatcher<
+			<Self as frame_system::Config>::RuntimeCall,
+			Self::ArgumentPredicate,
+		>;
+
+		/// The maximum number of argument predicates per extrinsic
+		#[pallet::constant]
+		type MaxArgumentPredicates: Get<u32>;
 	}
 
 	/// current mode, ValueQuery as it can't be None
@@ -139,6 +194,46 @@ pub mod pallet {
 	pub type BlockedExtrinsics<T: Config> =
 		StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
 
+	/// a tuple (pallet_name_bytes, function_name_bytes) to the argument predicates, the extrinsic
+	/// is blocked if any of the predicates matches its arguments
+	/// an empty `function_name_bytes` applies the predicates to all extrinsics in the pallet
+	#[pallet::storage]
+	#[pallet::getter(fn argument_filter)]
+	pub type ArgumentFilters<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		(Vec<u8>, Vec<u8>),
+		BoundedVec<T::ArgumentPredicate, T::MaxArgumentPredicates>,
+		ValueQuery,
+	>;
+
+	/// the block at which a blocked extrinsic is unblocked, absent if it's blocked indefinitely
+	#[pallet::storage]
+	#[pallet::getter(fn blocked_extrinsic_expiry)]
+	pub type BlockedExtrinsicExpiries<T: Config> =
+		StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), BlockNumberFor<T>, OptionQuery>;
+
+	/// blocked extrinsics to be unblocked at a given block
+	#[pallet::storage]
+	pub type ExpiringExtrinsics<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(Vec<u8>, Vec<u8>), T::MaxExpiringExtrinsicsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// mode transitions to be applied at a given block
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_mode)]
+	pub type ScheduledModes<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, OperationalMode, OptionQuery>;
+
+	/// accounts allowed to dispatch blocked extrinsics via `dispatch_allowlisted`
+	#[pallet::storage]
+	#[pallet::getter(fn allowlisted)]
+	pub type Allowlist<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -148,6 +243,28 @@ pub mod pallet {
 		ExtrinsicsBlocked { pallet_name_bytes: Vec<u8>, function_name_bytes: Option<Vec<u8>> },
 		/// some extrinsics are unblocked
 		ExtrinsicsUnblocked { pallet_name_bytes: Vec<u8>, function_name_bytes: Option<Vec<u8>> },
+		/// some extrinsics are blocked until the expiry block
+		ExtrinsicsBlockedUntil {
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Option<Vec<u8>>,
+			expiry: BlockNumberFor<T>,
+		},
+		/// a mode transition was scheduled
+		ModeScheduled { mode: OperationalMode, at: BlockNumberFor<T> },
+		/// a scheduled mode transition was cancelled
+		ScheduledModeCancelled { at: BlockNumberFor<T> },
+		/// the argument predicates of an extrinsic were set, an empty list removes them
+		ArgumentFilterSet {
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Option<Vec<u8>>,
+			predicates: BoundedVec<T::ArgumentPredicate, T::MaxArgumentPredicates>,
+		},
+		/// an account was added to the allowlist
+		AllowlistAdded { who: T::AccountId },
+		/// an account was removed from the allowlist
+		AllowlistRemoved { who: T::AccountId },
+		/// an allowlisted account dispatched a call
+		AllowlistedCallDispatched { who: T::AccountId, result: DispatchResult },
 	}
 
 	#[pallet::error]
@@ -160,6 +277,52 @@ pub mod pallet {
 		ExtrinsicAlreadyBlocked,
 		/// Error when trying to unblock a non-existent extrinsic
 		ExtrinsicNotBlocked,
+		/// Error when the expiry or schedule block is not in the future
+		BlockNumberInPast,
+		/// Error when trying to schedule a mode transition at a block which already has one
+		ModeAlreadyScheduled,
+		/// Error when trying to cancel a non-existent mode transition
+		ModeNotScheduled,
+		/// Error when trying to allowlist an account more than once
+		AlreadyAllowlisted,
+		/// Error when trying to remove a non-allowlisted account
+		NotAllowlisted,
+		/// Error when too many blocked extrinsics expire at the same block
+		TooManyExpiringExtrinsics,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// `ScheduledModes` and `ExpiringExtrinsics` are taken, the latter is bounded by
+			// `MaxExpiringExtrinsicsPerBlock`
+			let mut weight = T::DbWeight::get().reads_writes(2, 2);
+
+			if let Some(mode) = ScheduledModes::<T>::take(now) {
+				Mode::<T>::put(mode);
+				Self::deposit_event(Event::ModeSet { new_mode: mode });
+				weight.saturating_accrue(T::DbWeight::get().writes(3));
+			}
+
+			for key in ExpiringExtrinsics::<T>::take(now) {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+				// the entry might have been unblocked or re-blocked in the meantime
+				if BlockedExtrinsicExpiries::<T>::get(&key) != Some(now) {
+					continue
+				}
+				BlockedExtrinsicExpiries::<T>::remove(&key);
+				BlockedExtrinsics::<T>::remove(&key);
+				let (pallet_name_bytes, function_name_bytes) = key;
+				Self::deposit_event(Event::ExtrinsicsUnblocked {
+					pallet_name_bytes,
+					function_name_bytes: (!function_name_bytes.is_empty())
+						.then_some(function_name_bytes),
+				});
+				weight.saturating_accrue(T::DbWeight::get().writes(3));
+			}
+
+			weight
+		}
 	}
 
 	#[pallet::call]
@@ -198,30 +361,13 @@ pub mod pallet {
 			function_name_bytes: Option<Vec<u8>>,
 		) -> DispatchResultWithPostInfo {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			let pallet_name_string = sp_std::str::from_utf8(&pallet_name_bytes)
-				.map_err(|_| Error::<T>::CannotConvertToString)?;
-			// we disallow blocking this pallet itself
-			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotBlock
-			);
-
-			BlockedExtrinsics::<T>::mutate(
-				(pallet_name_bytes.clone(), function_name_bytes.clone().unwrap_or_default()),
-				|v| {
-					if v.is_none() {
-						*v = Some(());
-						Self::deposit_event(Event::ExtrinsicsBlocked {
-							pallet_name_bytes,
-							function_name_bytes,
-						});
-						// do not pay the fee upon successful block
-						Ok(Pays::No.into())
-					} else {
-						Err(Error::<T>::ExtrinsicAlreadyBlocked.into())
-					}
-				},
-			)
+			Self::do_block_extrinsics(pallet_name_bytes.clone(), function_name_bytes.clone())?;
+			Self::deposit_event(Event::ExtrinsicsBlocked {
+				pallet_name_bytes,
+				function_name_bytes,
+			});
+			// do not pay the fee upon successful block
+			Ok(Pays::No.into())
 		}
 
 		/// unblock the given extrinsics
@@ -236,12 +382,10 @@ pub mod pallet {
 			function_name_bytes: Option<Vec<u8>>,
 		) -> DispatchResultWithPostInfo {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			if BlockedExtrinsics::<T>::take((
-				pallet_name_bytes.clone(),
-				function_name_bytes.clone().unwrap_or_default(),
-			))
-			.is_some()
-			{
+			let key = (pallet_name_bytes.clone(), function_name_bytes.clone().unwrap_or_default());
+			// the stale entry in `ExpiringExtrinsics` is skipped in `on_initialize`
+			BlockedExtrinsicExpiries::<T>::remove(&key);
+			if BlockedExtrinsics::<T>::take(key).is_some() {
 				Self::deposit_event(Event::ExtrinsicsUnblocked {
 					pallet_name_bytes,
 					function_name_bytes,
@@ -252,29 +396,215 @@ pub mod pallet {
 			// do not pay the fee upon successful unblock
 			Ok(Pays::No.into())
 		}
+
+		/// block the given extrinsics until the `expiry` block, when they are unblocked
+		/// automatically. They can still be unblocked earlier with `unblock_extrinsics`.
+		#[pallet::call_index(3)]
+		#[pallet::weight({10_000})]
+		#[transactional]
+		pub fn block_extrinsics_until(
+			origin: OriginFor<T>,
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Option<Vec<u8>>,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::BlockNumberInPast
+			);
+			let key =
+				Self::do_block_extrinsics(pallet_name_bytes.clone(), function_name_bytes.clone())?;
+			BlockedExtrinsicExpiries::<T>::insert(&key, expiry);
+			ExpiringExtrinsics::<T>::try_append(expiry, key)
+				.map_err(|_| Error::<T>::TooManyExpiringExtrinsics)?;
+			Self::deposit_event(Event::ExtrinsicsBlockedUntil {
+				pallet_name_bytes,
+				function_name_bytes,
+				expiry,
+			});
+			Ok(Pays::No.into())
+		}
+
+		/// schedule the mode to be set at the beginning of block `at`
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+		pub fn schedule_mode(
+			origin: OriginFor<T>,
+			mode: OperationalMode,
+			at: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T>::BlockNumberInPast);
+			ensure!(!ScheduledModes::<T>::contains_key(at), Error::<T>::ModeAlreadyScheduled);
+			ScheduledModes::<T>::insert(at, mode);
+			Self::deposit_event(Event::ModeScheduled { mode, at });
+			Ok(().into())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+		pub fn cancel_scheduled_mode(
+			origin: OriginFor<T>,
+			at: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(ScheduledModes::<T>::take(at).is_some(), Error::<T>::ModeNotScheduled);
+			Self::deposit_event(Event::ScheduledModeCancelled { at });
+			Ok(().into())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+		pub fn add_to_allowlist(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(!Allowlist::<T>::contains_key(&who), Error::<T>::AlreadyAllowlisted);
+			Allowlist::<T>::insert(&who, ());
+			Self::deposit_event(Event::AllowlistAdded { who });
+			Ok(().into())
+		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+		pub fn remove_from_allowlist(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(Allowlist::<T>::take(&who).is_some(), Error::<T>::NotAllowlisted);
+			Self::deposit_event(Event::AllowlistRemoved { who });
+			Ok(().into())
+		}
+
+		/// dispatch a call as an allowlisted account, ignoring `BlockedExtrinsics`
+		///
+		/// The call must still be allowed by the current mode, or by the runtime's
+		/// `BaseCallFilter` (e.g. core calls). Only the call itself bypasses `BlockedExtrinsics`,
+		/// any call nested in it (e.g. in `utility::batch`) goes through `BaseCallFilter`.
+		#[pallet::call_index(8)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(dispatch_info.weight.saturating_add(T::DbWeight::get().reads_writes(2, 1)), dispatch_info.class)
+		})]
+		pub fn dispatch_allowlisted(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Allowlist::<T>::contains_key(&who), Error::<T>::NotAllowlisted);
+
+			// `reset_filter` would restore the base call filter which rejects the blocked
+			// extrinsics, so the filters are checked here and the call is dispatched unfiltered
+			let call_ref = (*call).into_ref();
+			let result = if <T as frame_system::Config>::BaseCallFilter::contains(call_ref) ||
+				Self::allowed_by_mode(call_ref)
+			{
+				let origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(who.clone()).into();
+				(*call).dispatch_bypass_filter(origin)
+			} else {
+				Err(frame_system::Error::<T>::CallFiltered.into())
+			};
+			Self::deposit_event(Event::AllowlistedCallDispatched {
+				who,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(().into())
+		}
+
+		/// replace the argument predicates of the given extrinsic(s)
+		/// the extrinsic is blocked when any of the predicates matches its arguments
+		/// if `function_name_bytes` is None, the predicates apply to all extrinsics in the pallet
+		#[pallet::call_index(9)]
+		#[pallet::weight({10_000})]
+		pub fn set_argument_filter(
+			origin: OriginFor<T>,
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Option<Vec<u8>>,
+			predicates: BoundedVec<T::ArgumentPredicate, T::MaxArgumentPredicates>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_can_block(&pallet_name_bytes)?;
+
+			let key = (pallet_name_bytes.clone(), function_name_bytes.clone().unwrap_or_default());
+			if predicates.is_empty() {
+				ArgumentFilters::<T>::remove(key);
+			} else {
+				ArgumentFilters::<T>::insert(key, predicates.clone());
+			}
+			Self::deposit_event(Event::ArgumentFilterSet {
+				pallet_name_bytes,
+				function_name_bytes,
+				predicates,
+			});
+			Ok(Pays::No.into())
+		}
 	}
 
-	impl<T: Config> Contains<T::RuntimeCall> for Pallet<T>
-	where
-		<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
-	{
-		fn contains(call: &T::RuntimeCall) -> bool {
-			let allowed_by_mode = match Self::mode() {
+	impl<T: Config> Pallet<T> {
+		fn ensure_can_block(pallet_name_bytes: &[u8]) -> DispatchResult {
+			let pallet_name_string = sp_std::str::from_utf8(pallet_name_bytes)
+				.map_err(|_| Error::<T>::CannotConvertToString)?;
+			// we disallow blocking this pallet itself
+			ensure!(
+				pallet_name_string != <Self as PalletInfoAccess>::name(),
+				Error::<T>::CannotBlock
+			);
+			Ok(())
+		}
+
+		// insert the blocked entry and return its key
+		fn do_block_extrinsics(
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Option<Vec<u8>>,
+		) -> Result<(Vec<u8>, Vec<u8>), DispatchError> {
+			Self::ensure_can_block(&pallet_name_bytes)?;
+
+			let key = (pallet_name_bytes, function_name_bytes.unwrap_or_default());
+			ensure!(
+				!BlockedExtrinsics::<T>::contains_key(&key),
+				Error::<T>::ExtrinsicAlreadyBlocked
+			);
+			BlockedExtrinsics::<T>::insert(&key, ());
+			Ok(key)
+		}
+
+		fn allowed_by_mode(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+			match Self::mode() {
 				OperationalMode::Normal => T::NormalModeFilter::contains(call),
 				OperationalMode::Safe => T::SafeModeFilter::contains(call),
 				OperationalMode::Test => T::TestModeFilter::contains(call),
-			};
+			}
+		}
+	}
+
+	impl<T: Config> Contains<<T as frame_system::Config>::RuntimeCall> for Pallet<T>
+	where
+		<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+	{
+		fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
 			// if allowed_by_mode is true, continue to check BlockedExtrinsics
 			// remember we only have blacklisting, no whitelisting
-			if allowed_by_mode {
+			if Self::allowed_by_mode(call) {
 				let CallMetadata { function_name, pallet_name } = call.get_call_metadata();
-				return !BlockedExtrinsics::<T>::contains_key((
+				if BlockedExtrinsics::<T>::contains_key((
 					pallet_name.as_bytes(),
 					function_name.as_bytes(),
-				)) && !BlockedExtrinsics::<T>::contains_key((
+				)) || BlockedExtrinsics::<T>::contains_key((
 					pallet_name.as_bytes(),
 					Vec::<u8>::default(),
-				))
+				)) {
+					return false
+				}
+
+				let blocked_by_argument = |function_name_bytes: &[u8]| {
+					ArgumentFilters::<T>::get((pallet_name.as_bytes(), function_name_bytes))
+						.iter()
+						.any(|p| T::ArgumentMatcher::matches(call, p))
+				};
+				return !blocked_by_argument(function_name.as_bytes()) && !blocked_by_argument(&[])
 			}
 
 			false
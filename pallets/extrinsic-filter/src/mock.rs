@@ -42,6 +42,7 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+		Utility: pallet_utility::{Pallet, Call, Event},
 		ExtrinsicFilter: pallet_extrinsic_filter::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -51,7 +52,7 @@ parameter_types! {
 }
 
 impl system::Config for Test {
-	type BaseCallFilter = ExtrinsicFilter;
+	type BaseCallFilter = BaseCallFilter;
 	type BlockWeights = ();
 	type BlockLength = ();
 	type DbWeight = ();
@@ -100,6 +101,21 @@ impl pallet_balances::Config for Test {
 	type MaxFreezes = ();
 }
 
+impl pallet_utility::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
+}
+
+// like the runtimes, some calls are always allowed regardless of the mode
+pub struct BaseCallFilter;
+impl Contains<RuntimeCall> for BaseCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::Utility(_)) || ExtrinsicFilter::contains(call)
+	}
+}
+
 pub struct SafeModeFilter;
 impl Contains<RuntimeCall> for SafeModeFilter {
 	fn contains(call: &RuntimeCall) -> bool {
@@ -119,11 +135,30 @@ impl Contains<RuntimeCall> for NormalModeFilter {
 
 impl pallet_extrinsic_filter::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type UpdateOrigin = EnsureRoot<Self::AccountId>;
 	type SafeModeFilter = SafeModeFilter;
 	type NormalModeFilter = NormalModeFilter;
 	type TestModeFilter = Everything;
 	type WeightInfo = ();
+	type MaxExpiringExtrinsicsPerBlock = ConstU32<2>;
+	type ArgumentPredicate = u64;
+	type ArgumentMatcher = DestinationMatcher;
+	type MaxArgumentPredicates = ConstU32<2>;
+}
+
+// matches the balance transfers to the given account
+pub struct DestinationMatcher;
+impl pallet_extrinsic_filter::CallArgumentMatcher<RuntimeCall, u64> for DestinationMatcher {
+	fn matches(call: &RuntimeCall, predicate: &u64) -> bool {
+		match call {
+			RuntimeCall::Balances(pallet_balances::Call::transfer { dest, .. }) |
+			RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { dest, .. }) |
+			RuntimeCall::Balances(pallet_balances::Call::transfer_all { dest, .. }) |
+			RuntimeCall::Balances(pallet_balances::Call::force_transfer { dest, .. }) => dest == predicate,
+			_ => false,
+		}
+	}
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
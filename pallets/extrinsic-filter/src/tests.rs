@@ -15,7 +15,7 @@
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use sp_runtime::traits::Dispatchable;
 
 #[test]
@@ -498,3 +498,378 @@ fn blocked_extrinsic_is_retained_upon_mode_switch() {
 		assert_ok!(call.dispatch(RuntimeOrigin::none()));
 	});
 }
+
+#[test]
+fn block_extrinsics_until_expires() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Test));
+		assert_noop!(
+			ExtrinsicFilter::block_extrinsics_until(
+				RuntimeOrigin::root(),
+				b"Balances".to_vec(),
+				None,
+				1
+			),
+			Error::<Test>::BlockNumberInPast
+		);
+		assert_ok!(ExtrinsicFilter::block_extrinsics_until(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			None,
+			5
+		));
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(
+			crate::Event::ExtrinsicsBlockedUntil {
+				pallet_name_bytes: b"Balances".to_vec(),
+				function_name_bytes: None,
+				expiry: 5,
+			},
+		));
+		let call: RuntimeCall = pallet_balances::Call::transfer { dest: 2, value: 10 }.into();
+		assert_noop!(
+			call.clone().dispatch(RuntimeOrigin::signed(1)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+
+		System::set_block_number(5);
+		ExtrinsicFilter::on_initialize(5);
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(
+			crate::Event::ExtrinsicsUnblocked {
+				pallet_name_bytes: b"Balances".to_vec(),
+				function_name_bytes: None,
+			},
+		));
+		assert_eq!(ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), Vec::new())), None);
+		assert_eq!(
+			ExtrinsicFilter::blocked_extrinsic_expiry((b"Balances".to_vec(), Vec::new())),
+			None
+		);
+		assert_ok!(call.dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(2), 10);
+	});
+}
+
+#[test]
+fn reblocking_indefinitely_overrides_expiry() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::block_extrinsics_until(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			Some(b"transfer".to_vec()),
+			5
+		));
+		assert_ok!(ExtrinsicFilter::unblock_extrinsics(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			Some(b"transfer".to_vec())
+		));
+		assert_ok!(ExtrinsicFilter::block_extrinsics(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			Some(b"transfer".to_vec())
+		));
+
+		ExtrinsicFilter::on_initialize(5);
+		assert_eq!(
+			ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), b"transfer".to_vec())),
+			Some(())
+		);
+	});
+}
+
+#[test]
+fn block_extrinsics_until_is_bounded_per_block() {
+	new_test_ext().execute_with(|| {
+		for function_name in [b"transfer".to_vec(), b"transfer_all".to_vec()] {
+			assert_ok!(ExtrinsicFilter::block_extrinsics_until(
+				RuntimeOrigin::root(),
+				b"Balances".to_vec(),
+				Some(function_name),
+				5
+			));
+		}
+		// MaxExpiringExtrinsicsPerBlock is 2 in the mock
+		assert_noop!(
+			ExtrinsicFilter::block_extrinsics_until(
+				RuntimeOrigin::root(),
+				b"Balances".to_vec(),
+				Some(b"transfer_keep_alive".to_vec()),
+				5
+			),
+			Error::<Test>::TooManyExpiringExtrinsics
+		);
+		assert_ok!(ExtrinsicFilter::block_extrinsics_until(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			Some(b"transfer_keep_alive".to_vec()),
+			6
+		));
+
+		ExtrinsicFilter::on_initialize(5);
+		for function_name in [b"transfer".to_vec(), b"transfer_all".to_vec()] {
+			assert_eq!(
+				ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), function_name)),
+				None
+			);
+		}
+		assert_eq!(
+			ExtrinsicFilter::blocked_extrinsics((
+				b"Balances".to_vec(),
+				b"transfer_keep_alive".to_vec()
+			)),
+			Some(())
+		);
+		ExtrinsicFilter::on_initialize(6);
+		assert_eq!(
+			ExtrinsicFilter::blocked_extrinsics((
+				b"Balances".to_vec(),
+				b"transfer_keep_alive".to_vec()
+			)),
+			None
+		);
+	});
+}
+
+#[test]
+fn scheduled_mode_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ExtrinsicFilter::schedule_mode(
+				RuntimeOrigin::signed(1),
+				crate::OperationalMode::Safe,
+				3
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ExtrinsicFilter::schedule_mode(
+			RuntimeOrigin::root(),
+			crate::OperationalMode::Safe,
+			3
+		));
+		assert_ok!(ExtrinsicFilter::schedule_mode(
+			RuntimeOrigin::root(),
+			crate::OperationalMode::Normal,
+			6
+		));
+		assert_noop!(
+			ExtrinsicFilter::schedule_mode(RuntimeOrigin::root(), crate::OperationalMode::Test, 6),
+			Error::<Test>::ModeAlreadyScheduled
+		);
+
+		ExtrinsicFilter::on_initialize(3);
+		assert_eq!(ExtrinsicFilter::mode(), crate::OperationalMode::Safe);
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(crate::Event::ModeSet {
+			new_mode: crate::OperationalMode::Safe,
+		}));
+
+		assert_ok!(ExtrinsicFilter::cancel_scheduled_mode(RuntimeOrigin::root(), 6));
+		assert_noop!(
+			ExtrinsicFilter::cancel_scheduled_mode(RuntimeOrigin::root(), 6),
+			Error::<Test>::ModeNotScheduled
+		);
+		ExtrinsicFilter::on_initialize(6);
+		assert_eq!(ExtrinsicFilter::mode(), crate::OperationalMode::Safe);
+	});
+}
+
+#[test]
+fn allowlisted_account_bypasses_blocked_extrinsics() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Test));
+		assert_ok!(ExtrinsicFilter::block_extrinsics(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			None
+		));
+		let call: RuntimeCall = pallet_balances::Call::transfer { dest: 2, value: 10 }.into();
+		assert_noop!(
+			ExtrinsicFilter::dispatch_allowlisted(RuntimeOrigin::signed(1), Box::new(call.clone())),
+			Error::<Test>::NotAllowlisted
+		);
+
+		assert_ok!(ExtrinsicFilter::add_to_allowlist(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			ExtrinsicFilter::add_to_allowlist(RuntimeOrigin::root(), 1),
+			Error::<Test>::AlreadyAllowlisted
+		);
+		assert_ok!(ExtrinsicFilter::dispatch_allowlisted(
+			RuntimeOrigin::signed(1),
+			Box::new(call.clone())
+		));
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(
+			crate::Event::AllowlistedCallDispatched { who: 1, result: Ok(()) },
+		));
+		assert_eq!(Balances::free_balance(2), 10);
+
+		// the mode filter still applies
+		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Safe));
+		assert_ok!(ExtrinsicFilter::dispatch_allowlisted(
+			RuntimeOrigin::signed(1),
+			Box::new(call.clone())
+		));
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(
+			crate::Event::AllowlistedCallDispatched {
+				who: 1,
+				result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+			},
+		));
+		assert_eq!(Balances::free_balance(2), 10);
+
+		assert_ok!(ExtrinsicFilter::remove_from_allowlist(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			ExtrinsicFilter::dispatch_allowlisted(RuntimeOrigin::signed(1), Box::new(call)),
+			Error::<Test>::NotAllowlisted
+		);
+	});
+}
+
+#[test]
+fn allowlisted_account_keeps_calls_allowed_by_base_filter() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Safe));
+		assert_ok!(ExtrinsicFilter::add_to_allowlist(RuntimeOrigin::root(), 1));
+
+		// `Utility` isn't allowed in safe mode, but always by the base filter
+		let batch: RuntimeCall = pallet_utility::Call::batch_all {
+			calls: vec![frame_system::Call::remark { remark: vec![] }.into()],
+		}
+		.into();
+		assert_ok!(batch.clone().dispatch(RuntimeOrigin::signed(1)));
+		assert_ok!(ExtrinsicFilter::dispatch_allowlisted(
+			RuntimeOrigin::signed(1),
+			Box::new(batch)
+		));
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(
+			crate::Event::AllowlistedCallDispatched { who: 1, result: Ok(()) },
+		));
+
+		// the nested calls go through the base filter
+		let batch: RuntimeCall = pallet_utility::Call::batch_all {
+			calls: vec![pallet_balances::Call::transfer { dest: 2, value: 10 }.into()],
+		}
+		.into();
+		assert_ok!(ExtrinsicFilter::dispatch_allowlisted(
+			RuntimeOrigin::signed(1),
+			Box::new(batch)
+		));
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(
+			crate::Event::AllowlistedCallDispatched {
+				who: 1,
+				result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+			},
+		));
+		assert_eq!(Balances::free_balance(2), 0);
+	});
+}
+
+#[test]
+fn argument_filter_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Test));
+
+		// block Balances.transfer to account 3
+		let predicates: frame_support::BoundedVec<_, _> = vec![3].try_into().unwrap();
+		assert_ok!(ExtrinsicFilter::set_argument_filter(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			Some(b"transfer".to_vec()),
+			predicates.clone()
+		));
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(crate::Event::ArgumentFilterSet {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: Some(b"transfer".to_vec()),
+			predicates,
+		}));
+
+		let call: RuntimeCall = pallet_balances::Call::transfer { dest: 3, value: 10 }.into();
+		assert_noop!(
+			call.clone().dispatch(RuntimeOrigin::signed(1)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+		// other destinations and functions are not affected
+		let call_to_2: RuntimeCall = pallet_balances::Call::transfer { dest: 2, value: 10 }.into();
+		assert_ok!(call_to_2.dispatch(RuntimeOrigin::signed(1)));
+		let keep_alive: RuntimeCall =
+			pallet_balances::Call::transfer_keep_alive { dest: 3, value: 10 }.into();
+		assert_ok!(keep_alive.dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(2), 10);
+		assert_eq!(Balances::free_balance(3), 10);
+
+		// an empty list removes the filter
+		assert_ok!(ExtrinsicFilter::set_argument_filter(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			Some(b"transfer".to_vec()),
+			Default::default()
+		));
+		assert!(ExtrinsicFilter::argument_filter((b"Balances".to_vec(), b"transfer".to_vec()))
+			.is_empty());
+		assert_ok!(call.dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(3), 20);
+	});
+}
+
+#[test]
+fn pallet_wide_argument_filter_covers_sibling_and_batched_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Test));
+		assert_ok!(ExtrinsicFilter::set_argument_filter(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			None,
+			vec![3].try_into().unwrap()
+		));
+
+		for call in [
+			pallet_balances::Call::transfer { dest: 3, value: 10 },
+			pallet_balances::Call::transfer_keep_alive { dest: 3, value: 10 },
+			pallet_balances::Call::transfer_all { dest: 3, keep_alive: true },
+		] {
+			assert_noop!(
+				RuntimeCall::from(call).dispatch(RuntimeOrigin::signed(1)),
+				frame_system::Error::<Test>::CallFiltered
+			);
+		}
+
+		// the nested calls are filtered when the batch dispatches them
+		let batch: RuntimeCall = pallet_utility::Call::batch_all {
+			calls: vec![
+				pallet_balances::Call::transfer { dest: 2, value: 10 }.into(),
+				pallet_balances::Call::transfer { dest: 3, value: 10 }.into(),
+			],
+		}
+		.into();
+		assert!(batch.dispatch(RuntimeOrigin::signed(1)).is_err());
+		assert_eq!(Balances::free_balance(2), 0);
+		assert_eq!(Balances::free_balance(3), 0);
+
+		let call_to_2: RuntimeCall =
+			pallet_balances::Call::transfer_keep_alive { dest: 2, value: 10 }.into();
+		assert_ok!(call_to_2.dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(2), 10);
+	});
+}
+
+#[test]
+fn argument_filter_fails_for_this_pallet_or_unauthorized_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ExtrinsicFilter::set_argument_filter(
+				RuntimeOrigin::signed(1),
+				b"Balances".to_vec(),
+				Some(b"transfer".to_vec()),
+				Default::default()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ExtrinsicFilter::set_argument_filter(
+				RuntimeOrigin::root(),
+				b"ExtrinsicFilter".to_vec(),
+				Some(b"set_mode".to_vec()),
+				vec![1].try_into().unwrap()
+			),
+			Error::<Test>::CannotBlock
+		);
+	});
+}
@@ -25,6 +25,7 @@ use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
 use frame_support::traits::EnsureOrigin;
 use frame_system::RawOrigin;
 use sp_core::H256;
+use sp_runtime::traits::Saturating;
 use sp_std::vec;
 
 use pallet_teebag::test_util::TEST8_MRENCLAVE;
@@ -40,6 +41,16 @@ benchmarks! {
 	add_delegatee {
 		let account: T::AccountId =  frame_benchmarking::account("TEST_A", 0u32, USER_SEED);
 	}: _(RawOrigin::Root, account.clone())
+	verify{
+		assert!(PendingDelegatees::<T>::contains_key(account));
+	}
+	// Benchmark `accept_delegatee`. There are no worst conditions.
+	accept_delegatee {
+		let account: T::AccountId =  frame_benchmarking::account("TEST_A", 0u32, USER_SEED);
+		let balance = T::DelegateeDeposit::get().saturating_mul(2u32.into());
+		T::Currency::make_free_balance_be(&account, balance);
+		IdentityManagement::<T>::add_delegatee(RawOrigin::Root.into(), account.clone())?;
+	}: _(RawOrigin::Signed(account.clone()))
 	verify{
 		assert!(Delegatee::<T>::contains_key(account));
 	}
@@ -47,7 +58,10 @@ benchmarks! {
 	// execution time is constant irrespective of encrypted_data size.
 	remove_delegatee {
 		let account: T::AccountId =  frame_benchmarking::account("TEST_A", 0u32, USER_SEED);
+		let balance = T::DelegateeDeposit::get().saturating_mul(2u32.into());
+		T::Currency::make_free_balance_be(&account, balance);
 		IdentityManagement::<T>::add_delegatee(RawOrigin::Root.into(), account.clone())?;
+		IdentityManagement::<T>::accept_delegatee(RawOrigin::Signed(account.clone()).into())?;
 	}: _(RawOrigin::Root, account.clone())
 	verify{
 		assert!(!Delegatee::<T>::contains_key(account));
@@ -155,6 +169,126 @@ benchmarks! {
 		assert_last_event::<T>(Event::IdentityNetworksSet { prime_identity, id_graph_hash, req_ext_hash }.into());
 	}
 
+	// Benchmark `identity_unlinked`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	identity_unlinked {
+		let id_graph_hash = H256::default();
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, prime_identity.clone(), id_graph_hash, req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::IdentityUnlinked { prime_identity, id_graph_hash, req_ext_hash }.into());
+	}
+
+	// Benchmark `id_graphs_merged`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	id_graphs_merged {
+		let id_graph_hash = H256::default();
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+		let merged_prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_B", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, prime_identity.clone(), merged_prime_identity.clone(), id_graph_hash, req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::IDGraphsMerged { prime_identity, merged_prime_identity, id_graph_hash, req_ext_hash }.into());
+	}
+
+	// Benchmark `prime_identity_transferred`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	prime_identity_transferred {
+		let id_graph_hash = H256::default();
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let old_prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_B", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, old_prime_identity.clone(), prime_identity.clone(), id_graph_hash, req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::PrimeIdentityTransferred { old_prime_identity, prime_identity, id_graph_hash, req_ext_hash }.into());
+	}
+
+	// Benchmark `recovery_guardians_set`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	recovery_guardians_set {
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, prime_identity.clone(), req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::RecoveryGuardiansSet { prime_identity, req_ext_hash }.into());
+	}
+
+	// Benchmark `recovery_initiated`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	recovery_initiated {
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+		let new_prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_B", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, prime_identity.clone(), new_prime_identity.clone(), req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::RecoveryInitiated { prime_identity, new_prime_identity, req_ext_hash }.into());
+	}
+
+	// Benchmark `recovery_approved`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	recovery_approved {
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, prime_identity.clone(), 1u32, req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::RecoveryApproved { prime_identity, approvals: 1u32, req_ext_hash }.into());
+	}
+
+	// Benchmark `recovery_cancelled`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	recovery_cancelled {
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, prime_identity.clone(), req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::RecoveryCancelled { prime_identity, req_ext_hash }.into());
+	}
+
+	// Benchmark `prime_identity_recovered`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	prime_identity_recovered {
+		let id_graph_hash = H256::default();
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let old_prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_B", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, old_prime_identity.clone(), prime_identity.clone(), id_graph_hash, req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::PrimeIdentityRecovered { old_prime_identity, prime_identity, id_graph_hash, req_ext_hash }.into());
+	}
+
+	// Benchmark `identity_label_set`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	identity_label_set {
+		let id_graph_hash = H256::default();
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, prime_identity.clone(), id_graph_hash, req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::IdentityLabelSet { prime_identity, id_graph_hash, req_ext_hash }.into());
+	}
+
+	// Benchmark `identity_reverified`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	identity_reverified {
+		let id_graph_hash = H256::default();
+		let req_ext_hash = H256::default();
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let prime_identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
+	}: _<T::RuntimeOrigin>(call_origin, prime_identity.clone(), id_graph_hash, req_ext_hash)
+	verify {
+		assert_last_event::<T>(Event::IdentityReverified { prime_identity, id_graph_hash, req_ext_hash }.into());
+	}
+
 	// Benchmark `some_error`. There are no worst conditions. The benchmark showed that
 	// execution time is constant irrespective of encrypted_data size.
 	some_error {
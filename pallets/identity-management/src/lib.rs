@@ -38,33 +38,76 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod migrations;
 pub mod weights;
 
 pub use crate::weights::WeightInfo;
 pub use pallet::*;
 
+use frame_support::traits::{Currency, ReservableCurrency};
 use pallet_teebag::ShardIdentifier;
 use sp_core::H256;
+use sp_runtime::traits::Saturating;
 use sp_std::vec::Vec;
 
 const MAX_REDIRECT_URL_LEN: u32 = 256;
+const MAX_CLIENT_NAME_LEN: u32 = 64;
+const MAX_SCOPE_LEN: u32 = 64;
+const MAX_OIDC_CLIENT_SCOPES: u32 = 16;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type OIDCClientOf<T> = OIDCClient<
+	<T as Config>::MaxOIDCClientRedirectUris,
+	frame_support::traits::ConstU32<MAX_REDIRECT_URL_LEN>,
+	BalanceOf<T>,
+>;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use super::{ShardIdentifier, Vec, WeightInfo, H256, MAX_REDIRECT_URL_LEN};
+	use super::{
+		BalanceOf, OIDCClientOf, ReservableCurrency, Saturating, ShardIdentifier, Vec, WeightInfo,
+		H256, MAX_CLIENT_NAME_LEN, MAX_OIDC_CLIENT_SCOPES, MAX_REDIRECT_URL_LEN, MAX_SCOPE_LEN,
+	};
 	use core_primitives::{ErrorDetail, IMPError, Identity};
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	// information shown to the user when a client asks for consent
+	#[derive(
+		Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct OIDCClientMetadata {
+		pub name: BoundedVec<u8, ConstU32<MAX_CLIENT_NAME_LEN>>,
+		pub logo_uri: BoundedVec<u8, ConstU32<MAX_REDIRECT_URL_LEN>>,
+		pub scopes:
+			BoundedVec<BoundedVec<u8, ConstU32<MAX_SCOPE_LEN>>, ConstU32<MAX_OIDC_CLIENT_SCOPES>>,
+	}
+
+	impl OIDCClientMetadata {
+		pub fn byte_len(&self) -> usize {
+			self.name.len() +
+				self.logo_uri.len() +
+				self.scopes.iter().map(|scope| scope.len()).sum::<usize>()
+		}
+	}
+
 	#[derive(
 		Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
 	)]
 	#[scale_info(skip_type_params(MaxOIDCClientUris, MaxRedirectUriLen))]
-	pub struct OIDCClient<MaxOIDCClientUris: Get<u32>, MaxRedirectUriLen: Get<u32>> {
-		redirect_uris: BoundedVec<BoundedVec<u8, MaxRedirectUriLen>, MaxOIDCClientUris>,
+	pub struct OIDCClient<MaxOIDCClientUris: Get<u32>, MaxRedirectUriLen: Get<u32>, Balance> {
+		pub redirect_uris: BoundedVec<BoundedVec<u8, MaxRedirectUriLen>, MaxOIDCClientUris>,
+		pub metadata: OIDCClientMetadata,
+		// the amount reserved from the client account for this registration
+		pub deposit: Balance,
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -80,10 +123,25 @@ pub mod pallet {
 		// maximum number of OIDC client URIs
 		#[pallet::constant]
 		type MaxOIDCClientRedirectUris: Get<u32>;
+		// currency used to reserve the registration deposits
+		type Currency: ReservableCurrency<Self::AccountId>;
+		// base deposit for registering an OIDC client
+		#[pallet::constant]
+		type OIDCClientDepositBase: Get<BalanceOf<Self>>;
+		// additional deposit per byte of redirect URIs and metadata of an OIDC client
+		#[pallet::constant]
+		type OIDCClientDepositPerByte: Get<BalanceOf<Self>>;
+		// deposit reserved from a delegatee account when it accepts to be a delegatee
+		#[pallet::constant]
+		type DelegateeDeposit: Get<BalanceOf<Self>>;
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate_to_v1::<T>()
+		}
+
 		fn integrity_test() {
 			assert!(
 				<T as Config>::MaxOIDCClientRedirectUris::get() > 0,
@@ -95,6 +153,9 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
+		DelegateeInvited {
+			account: T::AccountId,
+		},
 		DelegateeAdded {
 			account: T::AccountId,
 		},
@@ -143,6 +204,59 @@ pub mod pallet {
 			id_graph_hash: H256,
 			req_ext_hash: H256,
 		},
+		IdentityUnlinked {
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		},
+		IDGraphsMerged {
+			prime_identity: Identity,
+			merged_prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		},
+		PrimeIdentityTransferred {
+			old_prime_identity: Identity,
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		},
+		// social recovery, the guardians themselves are kept private in TEE
+		RecoveryGuardiansSet {
+			prime_identity: Identity,
+			req_ext_hash: H256,
+		},
+		RecoveryInitiated {
+			prime_identity: Identity,
+			new_prime_identity: Identity,
+			req_ext_hash: H256,
+		},
+		RecoveryApproved {
+			prime_identity: Identity,
+			approvals: u32,
+			req_ext_hash: H256,
+		},
+		RecoveryCancelled {
+			prime_identity: Identity,
+			req_ext_hash: H256,
+		},
+		PrimeIdentityRecovered {
+			old_prime_identity: Identity,
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		},
+		// the label itself is kept private in TEE
+		IdentityLabelSet {
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		},
+		IdentityReverified {
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		},
 		// event errors caused by processing in TEE
 		// copied from core_primitives::IMPError, we use events instead of pallet::errors,
 		// see https://github.com/litentry/litentry-parachain/issues/1275
@@ -169,12 +283,45 @@ pub mod pallet {
 			detail: ErrorDetail,
 			req_ext_hash: H256,
 		},
+		UnlinkIdentityFailed {
+			prime_identity: Option<Identity>,
+			detail: ErrorDetail,
+			req_ext_hash: H256,
+		},
+		MergeIDGraphsFailed {
+			prime_identity: Option<Identity>,
+			detail: ErrorDetail,
+			req_ext_hash: H256,
+		},
+		TransferPrimeIdentityFailed {
+			prime_identity: Option<Identity>,
+			detail: ErrorDetail,
+			req_ext_hash: H256,
+		},
+		RecoveryFailed {
+			prime_identity: Option<Identity>,
+			detail: ErrorDetail,
+			req_ext_hash: H256,
+		},
+		ReverifyIdentityFailed {
+			prime_identity: Option<Identity>,
+			detail: ErrorDetail,
+			req_ext_hash: H256,
+		},
 		OIDCClientRegistered {
 			client_id: T::AccountId,
 		},
 		OIDCClientUnregistered {
 			client_id: T::AccountId,
 		},
+		OIDCClientUpdated {
+			client_id: T::AccountId,
+		},
+		SetIdentityLabelFailed {
+			prime_identity: Option<Identity>,
+			detail: ErrorDetail,
+			req_ext_hash: H256,
+		},
 	}
 
 	// delegatees who can send extrinsics(currently only `link_identity`) on users' behalf
@@ -182,21 +329,32 @@ pub mod pallet {
 	#[pallet::getter(fn delegatee)]
 	pub type Delegatee<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
 
+	// accounts that were invited to be delegatees by `DelegateeAdminOrigin`, they become
+	// delegatees once they accept and pay the deposit
+	#[pallet::storage]
+	#[pallet::getter(fn pending_delegatee)]
+	pub type PendingDelegatees<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	// deposits reserved from the delegatees, kept apart from `Delegatee` as the worker reads its
+	// keys
+	#[pallet::storage]
+	#[pallet::getter(fn delegatee_deposit)]
+	pub type DelegateeDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	// OIDC clients who can use the OIDC flow
 	#[pallet::storage]
 	#[pallet::getter(fn oidc_client)]
-	pub type OIDCClients<T: Config> = StorageMap<
-		_,
-		Blake2_128Concat,
-		T::AccountId,
-		OIDCClient<T::MaxOIDCClientRedirectUris, ConstU32<MAX_REDIRECT_URL_LEN>>,
-		OptionQuery,
-	>;
+	pub type OIDCClients<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, OIDCClientOf<T>, OptionQuery>;
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// a delegatee doesn't exist
 		DelegateeNotExist,
+		/// the account wasn't invited to be a delegatee
+		DelegateeNotInvited,
 		/// a `link_identity` request from unauthorized user
 		UnauthorizedUser,
 		/// redirect_uris exceed the maximum length
@@ -209,32 +367,61 @@ pub mod pallet {
 		OIDCClientAlreadyRegistered,
 		/// OIDC client does not exists
 		OIDCClientDoesNotExist,
+		/// client name exceeds the maximum length
+		ClientNameTooLong,
+		/// logo_uri exceeds the maximum length
+		LogoUriTooLong,
+		/// scopes exceed the maximum length
+		TooManyScopes,
+		/// scope exceeds the maximum length
+		ScopeTooLong,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// add an account to the delegatees
+		/// invite an account to be a delegatee, it becomes a delegatee when it accepts with
+		/// `accept_delegatee`, which reserves the deposit from it
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_delegatee())]
 		pub fn add_delegatee(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
 			let _ = T::DelegateeAdminOrigin::ensure_origin(origin)?;
 			// we don't care if `account` already exists
-			Delegatee::<T>::insert(account.clone(), ());
-			Self::deposit_event(Event::DelegateeAdded { account });
+			if !Delegatee::<T>::contains_key(&account) {
+				PendingDelegatees::<T>::insert(&account, ());
+				Self::deposit_event(Event::DelegateeInvited { account });
+			}
 			Ok(())
 		}
 
-		/// remove an account from the delegatees
+		/// remove an account from the delegatees or withdraw its invitation
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as Config>::WeightInfo::remove_delegatee())]
 		pub fn remove_delegatee(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
 			let _ = T::DelegateeAdminOrigin::ensure_origin(origin)?;
-			ensure!(Delegatee::<T>::contains_key(&account), Error::<T>::DelegateeNotExist);
-			Delegatee::<T>::remove(account.clone());
+			if PendingDelegatees::<T>::take(&account).is_none() {
+				ensure!(Delegatee::<T>::contains_key(&account), Error::<T>::DelegateeNotExist);
+				T::Currency::unreserve(&account, DelegateeDeposits::<T>::take(&account));
+				Delegatee::<T>::remove(account.clone());
+			}
 			Self::deposit_event(Event::DelegateeRemoved { account });
 			Ok(())
 		}
 
+		/// accept the invitation to be a delegatee, the deposit is reserved from the caller
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_delegatee())]
+		pub fn accept_delegatee(origin: OriginFor<T>) -> DispatchResult {
+			let who = T::ExtrinsicWhitelistOrigin::ensure_origin(origin)?;
+			ensure!(PendingDelegatees::<T>::contains_key(&who), Error::<T>::DelegateeNotInvited);
+			let deposit = T::DelegateeDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			PendingDelegatees::<T>::remove(&who);
+			DelegateeDeposits::<T>::insert(&who, deposit);
+			Delegatee::<T>::insert(&who, ());
+			Self::deposit_event(Event::DelegateeAdded { account: who });
+			Ok(())
+		}
+
 		/// Link an identity with given network types and validation data.
 		/// We do the origin check for this extrinsic, it has to be
 		/// - either the caller themselves, i.e. ensure_signed(origin)? == who
@@ -311,53 +498,74 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		/// Register an OIDC client
-		// TODO: take a deposit to cover the storage cost and prevent spamming
+		/// Register an OIDC client, a deposit sized by the redirect URIs and metadata is
+		/// reserved from the client account
 		#[pallet::call_index(6)]
 		#[pallet::weight((195_000_000, DispatchClass::Normal))]
 		pub fn register_oidc_client(
 			origin: OriginFor<T>,
 			redirect_uris: Vec<Vec<u8>>,
+			name: Vec<u8>,
+			logo_uri: Vec<u8>,
+			scopes: Vec<Vec<u8>>,
 		) -> DispatchResult {
 			let client_id = ensure_signed(origin)?;
-			ensure!(!redirect_uris.is_empty(), Error::<T>::EmptyRedirectUris);
 			ensure!(
 				!OIDCClients::<T>::contains_key(&client_id),
 				Error::<T>::OIDCClientAlreadyRegistered
 			);
 
-			let client_redirect_uris = redirect_uris
-				.into_iter()
-				.map(|uri| {
-					BoundedVec::<u8, _>::try_from(uri).map_err(|_| Error::<T>::RedirectUriTooLong)
-				})
-				.collect::<Result<Vec<_>, _>>()?;
-
-			OIDCClients::<T>::insert(
-				&client_id,
-				OIDCClient {
-					redirect_uris: BoundedVec::try_from(client_redirect_uris)
-						.map_err(|_| Error::<T>::TooManyRedirectUris)?,
-				},
-			);
+			let client = Self::build_oidc_client(redirect_uris, name, logo_uri, scopes)?;
+			T::Currency::reserve(&client_id, client.deposit)?;
+			OIDCClients::<T>::insert(&client_id, client);
 
 			Self::deposit_event(Event::OIDCClientRegistered { client_id });
 
 			Ok(())
 		}
 
-		/// Unregister an OIDC client
+		/// Unregister an OIDC client, the deposit is released
 		#[pallet::call_index(7)]
 		#[pallet::weight((195_000_000, DispatchClass::Normal))]
 		pub fn unregister_oidc_client(origin: OriginFor<T>) -> DispatchResult {
 			let client_id = ensure_signed(origin)?;
-			ensure!(OIDCClients::<T>::contains_key(&client_id), Error::<T>::OIDCClientDoesNotExist);
-			OIDCClients::<T>::remove(&client_id);
+			let client =
+				OIDCClients::<T>::take(&client_id).ok_or(Error::<T>::OIDCClientDoesNotExist)?;
+			T::Currency::unreserve(&client_id, client.deposit);
 			Self::deposit_event(Event::OIDCClientUnregistered { client_id });
 
 			Ok(())
 		}
 
+		/// Update the redirect URIs and metadata of an OIDC client, the deposit is
+		/// adjusted to the new size
+		#[pallet::call_index(8)]
+		#[pallet::weight((195_000_000, DispatchClass::Normal))]
+		pub fn update_oidc_client(
+			origin: OriginFor<T>,
+			redirect_uris: Vec<Vec<u8>>,
+			name: Vec<u8>,
+			logo_uri: Vec<u8>,
+			scopes: Vec<Vec<u8>>,
+		) -> DispatchResult {
+			let client_id = ensure_signed(origin)?;
+			let old_deposit = OIDCClients::<T>::get(&client_id)
+				.ok_or(Error::<T>::OIDCClientDoesNotExist)?
+				.deposit;
+
+			let client = Self::build_oidc_client(redirect_uris, name, logo_uri, scopes)?;
+			if client.deposit > old_deposit {
+				T::Currency::reserve(&client_id, client.deposit.saturating_sub(old_deposit))?;
+			} else {
+				T::Currency::unreserve(&client_id, old_deposit.saturating_sub(client.deposit));
+			}
+			OIDCClients::<T>::insert(&client_id, client);
+
+			Self::deposit_event(Event::OIDCClientUpdated { client_id });
+
+			Ok(())
+		}
+
 		/// ---------------------------------------------------
 		/// The following extrinsics are supposed to be called by TEE only
 		/// ---------------------------------------------------
@@ -463,8 +671,253 @@ pub mod pallet {
 						detail,
 						req_ext_hash,
 					}),
+				IMPError::UnlinkIdentityFailed(detail) =>
+					Self::deposit_event(Event::UnlinkIdentityFailed {
+						prime_identity,
+						detail,
+						req_ext_hash,
+					}),
+				IMPError::MergeIDGraphsFailed(detail) =>
+					Self::deposit_event(Event::MergeIDGraphsFailed {
+						prime_identity,
+						detail,
+						req_ext_hash,
+					}),
+				IMPError::TransferPrimeIdentityFailed(detail) =>
+					Self::deposit_event(Event::TransferPrimeIdentityFailed {
+						prime_identity,
+						detail,
+						req_ext_hash,
+					}),
+				IMPError::RecoveryFailed(detail) => Self::deposit_event(Event::RecoveryFailed {
+					prime_identity,
+					detail,
+					req_ext_hash,
+				}),
+				IMPError::ReverifyIdentityFailed(detail) =>
+					Self::deposit_event(Event::ReverifyIdentityFailed {
+						prime_identity,
+						detail,
+						req_ext_hash,
+					}),
+				IMPError::SetIdentityLabelFailed(detail) =>
+					Self::deposit_event(Event::SetIdentityLabelFailed {
+						prime_identity,
+						detail,
+						req_ext_hash,
+					}),
 			}
 			Ok(Pays::No.into())
 		}
+
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as Config>::WeightInfo::identity_unlinked())]
+		pub fn identity_unlinked(
+			origin: OriginFor<T>,
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::IdentityUnlinked {
+				prime_identity,
+				id_graph_hash,
+				req_ext_hash,
+			});
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as Config>::WeightInfo::id_graphs_merged())]
+		pub fn id_graphs_merged(
+			origin: OriginFor<T>,
+			prime_identity: Identity,
+			merged_prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::IDGraphsMerged {
+				prime_identity,
+				merged_prime_identity,
+				id_graph_hash,
+				req_ext_hash,
+			});
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as Config>::WeightInfo::prime_identity_transferred())]
+		pub fn prime_identity_transferred(
+			origin: OriginFor<T>,
+			old_prime_identity: Identity,
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::PrimeIdentityTransferred {
+				old_prime_identity,
+				prime_identity,
+				id_graph_hash,
+				req_ext_hash,
+			});
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as Config>::WeightInfo::recovery_guardians_set())]
+		pub fn recovery_guardians_set(
+			origin: OriginFor<T>,
+			prime_identity: Identity,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::RecoveryGuardiansSet { prime_identity, req_ext_hash });
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(40)]
+		#[pallet::weight(<T as Config>::WeightInfo::recovery_initiated())]
+		pub fn recovery_initiated(
+			origin: OriginFor<T>,
+			prime_identity: Identity,
+			new_prime_identity: Identity,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::RecoveryInitiated {
+				prime_identity,
+				new_prime_identity,
+				req_ext_hash,
+			});
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(41)]
+		#[pallet::weight(<T as Config>::WeightInfo::recovery_approved())]
+		pub fn recovery_approved(
+			origin: OriginFor<T>,
+			prime_identity: Identity,
+			approvals: u32,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::RecoveryApproved {
+				prime_identity,
+				approvals,
+				req_ext_hash,
+			});
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(42)]
+		#[pallet::weight(<T as Config>::WeightInfo::recovery_cancelled())]
+		pub fn recovery_cancelled(
+			origin: OriginFor<T>,
+			prime_identity: Identity,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::RecoveryCancelled { prime_identity, req_ext_hash });
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(43)]
+		#[pallet::weight(<T as Config>::WeightInfo::prime_identity_recovered())]
+		pub fn prime_identity_recovered(
+			origin: OriginFor<T>,
+			old_prime_identity: Identity,
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::PrimeIdentityRecovered {
+				old_prime_identity,
+				prime_identity,
+				id_graph_hash,
+				req_ext_hash,
+			});
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(44)]
+		#[pallet::weight(<T as Config>::WeightInfo::identity_label_set())]
+		pub fn identity_label_set(
+			origin: OriginFor<T>,
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::IdentityLabelSet {
+				prime_identity,
+				id_graph_hash,
+				req_ext_hash,
+			});
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(45)]
+		#[pallet::weight(<T as Config>::WeightInfo::identity_reverified())]
+		pub fn identity_reverified(
+			origin: OriginFor<T>,
+			prime_identity: Identity,
+			id_graph_hash: H256,
+			req_ext_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			Self::deposit_event(Event::IdentityReverified {
+				prime_identity,
+				id_graph_hash,
+				req_ext_hash,
+			});
+			Ok(Pays::No.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn build_oidc_client(
+			redirect_uris: Vec<Vec<u8>>,
+			name: Vec<u8>,
+			logo_uri: Vec<u8>,
+			scopes: Vec<Vec<u8>>,
+		) -> Result<OIDCClientOf<T>, DispatchError> {
+			ensure!(!redirect_uris.is_empty(), Error::<T>::EmptyRedirectUris);
+
+			let redirect_uris = redirect_uris
+				.into_iter()
+				.map(|uri| {
+					BoundedVec::<u8, _>::try_from(uri).map_err(|_| Error::<T>::RedirectUriTooLong)
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+			let scopes = scopes
+				.into_iter()
+				.map(|scope| {
+					BoundedVec::<u8, _>::try_from(scope).map_err(|_| Error::<T>::ScopeTooLong)
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let redirect_uris =
+				BoundedVec::<_, T::MaxOIDCClientRedirectUris>::try_from(redirect_uris)
+					.map_err(|_| Error::<T>::TooManyRedirectUris)?;
+			let metadata = OIDCClientMetadata {
+				name: BoundedVec::try_from(name).map_err(|_| Error::<T>::ClientNameTooLong)?,
+				logo_uri: BoundedVec::try_from(logo_uri).map_err(|_| Error::<T>::LogoUriTooLong)?,
+				scopes: BoundedVec::try_from(scopes).map_err(|_| Error::<T>::TooManyScopes)?,
+			};
+
+			let byte_len =
+				redirect_uris.iter().map(|uri| uri.len()).sum::<usize>() + metadata.byte_len();
+			let deposit = Self::oidc_client_deposit(byte_len as u32);
+
+			Ok(OIDCClient { redirect_uris, metadata, deposit })
+		}
+
+		pub fn oidc_client_deposit(byte_len: u32) -> BalanceOf<T> {
+			let per_byte = T::OIDCClientDepositPerByte::get().saturating_mul(byte_len.into());
+			T::OIDCClientDepositBase::get().saturating_add(per_byte)
+		}
 	}
 }
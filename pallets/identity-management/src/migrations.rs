@@ -0,0 +1,49 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, OIDCClient, OIDCClientMetadata, OIDCClients, Pallet, MAX_REDIRECT_URL_LEN};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, StorageVersion},
+};
+use sp_runtime::traits::Zero;
+
+#[derive(Encode, Decode)]
+struct OIDCClientV0<MaxOIDCClientUris: Get<u32>> {
+	redirect_uris: BoundedVec<BoundedVec<u8, ConstU32<MAX_REDIRECT_URL_LEN>>, MaxOIDCClientUris>,
+}
+
+// v0 -> v1: `OIDCClient` gained the `metadata` and `deposit` fields, clients registered
+// before deposits were introduced keep an empty metadata and a zero deposit
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let on_chain_version = Pallet::<T>::on_chain_storage_version();
+	if on_chain_version >= 1 {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut translated = 0u64;
+	OIDCClients::<T>::translate::<OIDCClientV0<T::MaxOIDCClientRedirectUris>, _>(|_, old| {
+		translated += 1;
+		Some(OIDCClient {
+			redirect_uris: old.redirect_uris,
+			metadata: OIDCClientMetadata::default(),
+			deposit: Zero::zero(),
+		})
+	});
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+}
@@ -163,6 +163,10 @@ impl pallet_identity_management::Config for Test {
 	type DelegateeAdminOrigin = EnsureRoot<Self::AccountId>;
 	type ExtrinsicWhitelistOrigin = IMPExtrinsicWhitelist;
 	type MaxOIDCClientRedirectUris = ConstU32<3>;
+	type Currency = Balances;
+	type OIDCClientDepositBase = ConstU128<100>;
+	type OIDCClientDepositPerByte = ConstU128<1>;
+	type DelegateeDeposit = ConstU128<50>;
 }
 
 impl pallet_group::Config for Test {
@@ -175,12 +179,22 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		get_signer, TEST8_CERT, TEST8_SIGNER_PUB, TEST8_TIMESTAMP, URL,
 	};
 
-	let t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
 	let eddie: SystemAccountId = get_signer(&[5u8; 32]);
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(get_signer(&[1u8; 32]), 1_000),
+			(get_signer(&[2u8; 32]), 1_000),
+			(eddie.clone(), 1_000),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| {
 		// add `5` to delegatee
-		let _ = IdentityManagement::add_delegatee(RuntimeOrigin::root(), eddie);
+		let _ = IdentityManagement::add_delegatee(RuntimeOrigin::root(), eddie.clone());
+		let _ = IdentityManagement::accept_delegatee(RuntimeOrigin::signed(eddie));
 		System::set_block_number(1);
 		let signer: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
 		assert_ok!(Teebag::set_admin(RuntimeOrigin::root(), signer.clone()));
@@ -14,12 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 #[allow(unused)]
-use crate::{mock::*, Error, OIDCClients, ShardIdentifier};
+use crate::{
+	mock::*, Delegatee, DelegateeDeposits, Error, OIDCClients, Pallet, PendingDelegatees,
+	ShardIdentifier,
+};
 use core_primitives::{ErrorDetail, IMPError};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, StorageVersion},
+};
+use parity_scale_codec::Encode;
 use sp_core::H256;
 
-use pallet_teebag::test_util::{get_signer, TEST8_MRENCLAVE};
+use pallet_teebag::test_util::{get_signer, TEST8_MRENCLAVE, TEST8_SIGNER_PUB};
 type SystemAccountId = <Test as frame_system::Config>::AccountId;
 const ALICE_PUBKEY: &[u8; 32] = &[1u8; 32];
 const BOB_PUBKEY: &[u8; 32] = &[2u8; 32];
@@ -152,6 +159,310 @@ fn tee_callback_with_unregistered_enclave_fails() {
 	});
 }
 
+#[test]
+fn identity_unlinked_works() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let id_graph_hash = H256::repeat_byte(1);
+		let req_ext_hash = H256::repeat_byte(2);
+		assert_ok!(IdentityManagement::identity_unlinked(
+			RuntimeOrigin::signed(enclave),
+			alice.clone().into(),
+			id_graph_hash,
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::IdentityUnlinked {
+				prime_identity: alice.into(),
+				id_graph_hash,
+				req_ext_hash,
+			},
+		));
+	});
+}
+
+#[test]
+fn unlink_identity_error_is_emitted() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		assert_ok!(IdentityManagement::some_error(
+			RuntimeOrigin::signed(enclave),
+			Some(alice.clone().into()),
+			IMPError::UnlinkIdentityFailed(ErrorDetail::UnauthorizedSigner),
+			H256::default(),
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::UnlinkIdentityFailed {
+				prime_identity: Some(alice.into()),
+				detail: ErrorDetail::UnauthorizedSigner,
+				req_ext_hash: H256::default(),
+			},
+		));
+	});
+}
+
+#[test]
+fn id_graphs_merged_works() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		let id_graph_hash = H256::repeat_byte(1);
+		let req_ext_hash = H256::repeat_byte(2);
+		assert_ok!(IdentityManagement::id_graphs_merged(
+			RuntimeOrigin::signed(enclave),
+			alice.clone().into(),
+			bob.clone().into(),
+			id_graph_hash,
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(crate::Event::IDGraphsMerged {
+			prime_identity: alice.into(),
+			merged_prime_identity: bob.into(),
+			id_graph_hash,
+			req_ext_hash,
+		}));
+	});
+}
+
+#[test]
+fn prime_identity_transferred_works() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		let id_graph_hash = H256::repeat_byte(1);
+		let req_ext_hash = H256::repeat_byte(2);
+		assert_ok!(IdentityManagement::prime_identity_transferred(
+			RuntimeOrigin::signed(enclave),
+			alice.clone().into(),
+			bob.clone().into(),
+			id_graph_hash,
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::PrimeIdentityTransferred {
+				old_prime_identity: alice.into(),
+				prime_identity: bob.into(),
+				id_graph_hash,
+				req_ext_hash,
+			},
+		));
+	});
+}
+
+#[test]
+fn recovery_callbacks_work() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		let req_ext_hash = H256::repeat_byte(2);
+
+		assert_ok!(IdentityManagement::recovery_guardians_set(
+			RuntimeOrigin::signed(enclave.clone()),
+			alice.clone().into(),
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::RecoveryGuardiansSet {
+				prime_identity: alice.clone().into(),
+				req_ext_hash,
+			},
+		));
+
+		assert_ok!(IdentityManagement::recovery_initiated(
+			RuntimeOrigin::signed(enclave.clone()),
+			alice.clone().into(),
+			bob.clone().into(),
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::RecoveryInitiated {
+				prime_identity: alice.clone().into(),
+				new_prime_identity: bob.clone().into(),
+				req_ext_hash,
+			},
+		));
+
+		assert_ok!(IdentityManagement::recovery_approved(
+			RuntimeOrigin::signed(enclave.clone()),
+			alice.clone().into(),
+			2,
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::RecoveryApproved {
+				prime_identity: alice.clone().into(),
+				approvals: 2,
+				req_ext_hash,
+			},
+		));
+
+		assert_ok!(IdentityManagement::recovery_cancelled(
+			RuntimeOrigin::signed(enclave),
+			alice.clone().into(),
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::RecoveryCancelled { prime_identity: alice.into(), req_ext_hash },
+		));
+	});
+}
+
+#[test]
+fn prime_identity_recovered_works() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		let id_graph_hash = H256::repeat_byte(1);
+		let req_ext_hash = H256::repeat_byte(2);
+		assert_ok!(IdentityManagement::prime_identity_recovered(
+			RuntimeOrigin::signed(enclave),
+			alice.clone().into(),
+			bob.clone().into(),
+			id_graph_hash,
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::PrimeIdentityRecovered {
+				old_prime_identity: alice.into(),
+				prime_identity: bob.into(),
+				id_graph_hash,
+				req_ext_hash,
+			},
+		));
+	});
+}
+
+#[test]
+fn identity_label_set_works() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let id_graph_hash = H256::repeat_byte(1);
+		let req_ext_hash = H256::repeat_byte(2);
+		assert_ok!(IdentityManagement::identity_label_set(
+			RuntimeOrigin::signed(enclave),
+			alice.clone().into(),
+			id_graph_hash,
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::IdentityLabelSet {
+				prime_identity: alice.into(),
+				id_graph_hash,
+				req_ext_hash,
+			},
+		));
+	});
+}
+
+#[test]
+fn identity_reverified_works() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let id_graph_hash = H256::repeat_byte(1);
+		let req_ext_hash = H256::repeat_byte(2);
+		assert_ok!(IdentityManagement::identity_reverified(
+			RuntimeOrigin::signed(enclave),
+			alice.clone().into(),
+			id_graph_hash,
+			req_ext_hash,
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::IdentityReverified {
+				prime_identity: alice.into(),
+				id_graph_hash,
+				req_ext_hash,
+			},
+		));
+	});
+}
+
+#[test]
+fn recovery_failed_error_is_emitted() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		assert_ok!(IdentityManagement::some_error(
+			RuntimeOrigin::signed(enclave),
+			Some(alice.clone().into()),
+			IMPError::RecoveryFailed(ErrorDetail::UnauthorizedSigner),
+			H256::default(),
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(crate::Event::RecoveryFailed {
+			prime_identity: Some(alice.into()),
+			detail: ErrorDetail::UnauthorizedSigner,
+			req_ext_hash: H256::default(),
+		}));
+	});
+}
+
+#[test]
+fn reverify_identity_failed_error_is_emitted() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		assert_ok!(IdentityManagement::some_error(
+			RuntimeOrigin::signed(enclave),
+			Some(alice.clone().into()),
+			IMPError::ReverifyIdentityFailed(ErrorDetail::VerificationExpired),
+			H256::default(),
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::ReverifyIdentityFailed {
+				prime_identity: Some(alice.into()),
+				detail: ErrorDetail::VerificationExpired,
+				req_ext_hash: H256::default(),
+			},
+		));
+	});
+}
+
+#[test]
+fn set_identity_label_failed_error_is_emitted() {
+	new_test_ext().execute_with(|| {
+		let enclave: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		assert_ok!(IdentityManagement::some_error(
+			RuntimeOrigin::signed(enclave),
+			Some(alice.clone().into()),
+			IMPError::SetIdentityLabelFailed(ErrorDetail::UnauthorizedSigner),
+			H256::default(),
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::SetIdentityLabelFailed {
+				prime_identity: Some(alice.into()),
+				detail: ErrorDetail::UnauthorizedSigner,
+				req_ext_hash: H256::default(),
+			},
+		));
+	});
+}
+
+#[test]
+fn id_graphs_merged_fails_with_non_tee_origin() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		assert_noop!(
+			IdentityManagement::id_graphs_merged(
+				RuntimeOrigin::signed(alice.clone()),
+				alice.into(),
+				bob.into(),
+				H256::default(),
+				H256::default(),
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
 #[test]
 fn extrinsic_whitelist_origin_works() {
 	new_test_ext().execute_with(|| {
@@ -200,7 +511,10 @@ fn register_oidc_client_works() {
 		let redirect_uris = vec!["https://example.com".as_bytes().to_vec()];
 		assert_ok!(IdentityManagement::register_oidc_client(
 			RuntimeOrigin::signed(alice.clone()),
-			redirect_uris
+			redirect_uris,
+			vec![],
+			vec![],
+			vec![]
 		));
 		System::assert_last_event(RuntimeEvent::IdentityManagement(
 			crate::Event::OIDCClientRegistered { client_id: alice.clone() },
@@ -214,7 +528,13 @@ fn register_oidc_client_empty_redirect_uris_check_works() {
 	new_test_ext().execute_with(|| {
 		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
 		assert_noop!(
-			IdentityManagement::register_oidc_client(RuntimeOrigin::signed(alice), vec![]),
+			IdentityManagement::register_oidc_client(
+				RuntimeOrigin::signed(alice),
+				vec![],
+				vec![],
+				vec![],
+				vec![]
+			),
 			Error::<Test>::EmptyRedirectUris
 		);
 	});
@@ -227,10 +547,19 @@ fn register_oidc_client_already_registered_check_works() {
 		let redirect_uris = vec!["https://example.com".as_bytes().to_vec()];
 		assert_ok!(IdentityManagement::register_oidc_client(
 			RuntimeOrigin::signed(alice.clone()),
-			redirect_uris.clone()
+			redirect_uris.clone(),
+			vec![],
+			vec![],
+			vec![]
 		));
 		assert_noop!(
-			IdentityManagement::register_oidc_client(RuntimeOrigin::signed(alice), redirect_uris),
+			IdentityManagement::register_oidc_client(
+				RuntimeOrigin::signed(alice),
+				redirect_uris,
+				vec![],
+				vec![],
+				vec![]
+			),
 			Error::<Test>::OIDCClientAlreadyRegistered
 		);
 	});
@@ -242,7 +571,7 @@ fn register_oidc_client_redirect_uri_too_long_check_works() {
 		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
 		let redirect_uris = vec!["https://example.com".as_bytes().to_vec(), "https://very-long-uriiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiiii.com".as_bytes().to_vec()];
 		assert_noop!(
-			IdentityManagement::register_oidc_client(RuntimeOrigin::signed(alice), redirect_uris),
+			IdentityManagement::register_oidc_client(RuntimeOrigin::signed(alice), redirect_uris, vec![], vec![], vec![]),
 			Error::<Test>::RedirectUriTooLong
 		);
 	});
@@ -259,7 +588,13 @@ fn register_oidc_client_too_many_redirect_uris_check_works() {
 			"https://example4.com".as_bytes().to_vec(),
 		];
 		assert_noop!(
-			IdentityManagement::register_oidc_client(RuntimeOrigin::signed(alice), redirect_uris),
+			IdentityManagement::register_oidc_client(
+				RuntimeOrigin::signed(alice),
+				redirect_uris,
+				vec![],
+				vec![],
+				vec![]
+			),
 			Error::<Test>::TooManyRedirectUris
 		);
 	});
@@ -272,7 +607,10 @@ fn unregister_oidc_client_works() {
 		let redirect_uris = vec!["https://example.com".as_bytes().to_vec()];
 		assert_ok!(IdentityManagement::register_oidc_client(
 			RuntimeOrigin::signed(alice.clone()),
-			redirect_uris
+			redirect_uris,
+			vec![],
+			vec![],
+			vec![]
 		));
 		assert!(OIDCClients::<Test>::contains_key(&alice));
 		assert_ok!(IdentityManagement::unregister_oidc_client(RuntimeOrigin::signed(
@@ -303,7 +641,10 @@ fn unregister_oidc_client_unauthorized_sender() {
 		let redirect_uris = vec!["https://example.com".as_bytes().to_vec()];
 		assert_ok!(IdentityManagement::register_oidc_client(
 			RuntimeOrigin::signed(alice),
-			redirect_uris
+			redirect_uris,
+			vec![],
+			vec![],
+			vec![]
 		));
 		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
 		assert_noop!(
@@ -312,3 +653,252 @@ fn unregister_oidc_client_unauthorized_sender() {
 		);
 	});
 }
+
+#[test]
+fn register_oidc_client_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let redirect_uris = vec!["https://example.com".as_bytes().to_vec()];
+		assert_ok!(IdentityManagement::register_oidc_client(
+			RuntimeOrigin::signed(alice.clone()),
+			redirect_uris,
+			b"Example".to_vec(),
+			b"https://example.com/logo.png".to_vec(),
+			vec![b"openid".to_vec(), b"vcs".to_vec()],
+		));
+		// base 100 + 1 per byte: 19 (uri) + 7 (name) + 28 (logo) + 9 (scopes)
+		let client = OIDCClients::<Test>::get(&alice).unwrap();
+		assert_eq!(client.deposit, 163);
+		assert_eq!(client.metadata.name.to_vec(), b"Example".to_vec());
+		assert_eq!(client.metadata.scopes.len(), 2);
+		assert_eq!(Balances::reserved_balance(&alice), 163);
+
+		assert_ok!(IdentityManagement::unregister_oidc_client(RuntimeOrigin::signed(
+			alice.clone()
+		)));
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+	});
+}
+
+#[test]
+fn register_oidc_client_without_enough_balance_fails() {
+	new_test_ext().execute_with(|| {
+		let charlie: SystemAccountId = get_signer(&[3u8; 32]);
+		let redirect_uris = vec!["https://example.com".as_bytes().to_vec()];
+		assert!(IdentityManagement::register_oidc_client(
+			RuntimeOrigin::signed(charlie.clone()),
+			redirect_uris,
+			vec![],
+			vec![],
+			vec![]
+		)
+		.is_err());
+		assert!(!OIDCClients::<Test>::contains_key(&charlie));
+	});
+}
+
+#[test]
+fn register_oidc_client_metadata_checks_work() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let redirect_uris = vec!["https://example.com".as_bytes().to_vec()];
+		assert_noop!(
+			IdentityManagement::register_oidc_client(
+				RuntimeOrigin::signed(alice.clone()),
+				redirect_uris.clone(),
+				vec![1u8; 65],
+				vec![],
+				vec![]
+			),
+			Error::<Test>::ClientNameTooLong
+		);
+		assert_noop!(
+			IdentityManagement::register_oidc_client(
+				RuntimeOrigin::signed(alice.clone()),
+				redirect_uris.clone(),
+				vec![],
+				vec![1u8; 257],
+				vec![]
+			),
+			Error::<Test>::LogoUriTooLong
+		);
+		assert_noop!(
+			IdentityManagement::register_oidc_client(
+				RuntimeOrigin::signed(alice.clone()),
+				redirect_uris.clone(),
+				vec![],
+				vec![],
+				vec![vec![1u8; 65]]
+			),
+			Error::<Test>::ScopeTooLong
+		);
+		assert_noop!(
+			IdentityManagement::register_oidc_client(
+				RuntimeOrigin::signed(alice),
+				redirect_uris,
+				vec![],
+				vec![],
+				vec![b"openid".to_vec(); 17]
+			),
+			Error::<Test>::TooManyScopes
+		);
+	});
+}
+
+#[test]
+fn update_oidc_client_works() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		assert_ok!(IdentityManagement::register_oidc_client(
+			RuntimeOrigin::signed(alice.clone()),
+			vec!["https://example.com".as_bytes().to_vec()],
+			vec![],
+			vec![],
+			vec![]
+		));
+		assert_eq!(Balances::reserved_balance(&alice), 119);
+
+		assert_ok!(IdentityManagement::update_oidc_client(
+			RuntimeOrigin::signed(alice.clone()),
+			vec![
+				"https://example.com".as_bytes().to_vec(),
+				"https://example.org".as_bytes().to_vec()
+			],
+			b"Example".to_vec(),
+			vec![],
+			vec![]
+		));
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::OIDCClientUpdated { client_id: alice.clone() },
+		));
+		let client = OIDCClients::<Test>::get(&alice).unwrap();
+		assert_eq!(client.redirect_uris.len(), 2);
+		assert_eq!(client.deposit, 145);
+		assert_eq!(Balances::reserved_balance(&alice), 145);
+
+		// shrinking the registration releases the difference
+		assert_ok!(IdentityManagement::update_oidc_client(
+			RuntimeOrigin::signed(alice.clone()),
+			vec!["https://a.io".as_bytes().to_vec()],
+			vec![],
+			vec![],
+			vec![]
+		));
+		assert_eq!(Balances::reserved_balance(&alice), 112);
+	});
+}
+
+#[test]
+fn update_oidc_client_does_not_exist_fails() {
+	new_test_ext().execute_with(|| {
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		assert_noop!(
+			IdentityManagement::update_oidc_client(
+				RuntimeOrigin::signed(bob),
+				vec!["https://example.com".as_bytes().to_vec()],
+				vec![],
+				vec![],
+				vec![]
+			),
+			Error::<Test>::OIDCClientDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn delegatee_deposit_is_reserved_and_released() {
+	new_test_ext().execute_with(|| {
+		let eddie: SystemAccountId = get_signer(EDDIE_PUBKEY);
+		assert_eq!(Balances::reserved_balance(&eddie), 50);
+		assert_eq!(DelegateeDeposits::<Test>::get(&eddie), 50);
+		// adding an existing delegatee doesn't invite it again
+		assert_ok!(IdentityManagement::add_delegatee(RuntimeOrigin::root(), eddie.clone()));
+		assert!(!PendingDelegatees::<Test>::contains_key(&eddie));
+		assert_eq!(Balances::reserved_balance(&eddie), 50);
+
+		assert_ok!(IdentityManagement::remove_delegatee(RuntimeOrigin::root(), eddie.clone()));
+		assert_eq!(Balances::reserved_balance(&eddie), 0);
+		assert!(!DelegateeDeposits::<Test>::contains_key(&eddie));
+		assert!(!Delegatee::<Test>::contains_key(&eddie));
+	});
+}
+
+#[test]
+fn delegatee_deposit_is_only_reserved_when_accepted() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		assert_ok!(IdentityManagement::add_delegatee(RuntimeOrigin::root(), alice.clone()));
+		assert!(PendingDelegatees::<Test>::contains_key(&alice));
+		assert!(!Delegatee::<Test>::contains_key(&alice));
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			crate::Event::DelegateeInvited { account: alice.clone() },
+		));
+
+		assert_ok!(IdentityManagement::accept_delegatee(RuntimeOrigin::signed(alice.clone())));
+		assert!(!PendingDelegatees::<Test>::contains_key(&alice));
+		assert!(Delegatee::<Test>::contains_key(&alice));
+		assert_eq!(Balances::reserved_balance(&alice), 50);
+		System::assert_last_event(RuntimeEvent::IdentityManagement(crate::Event::DelegateeAdded {
+			account: alice,
+		}));
+	});
+}
+
+#[test]
+fn accept_delegatee_without_invitation_fails() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		assert_noop!(
+			IdentityManagement::accept_delegatee(RuntimeOrigin::signed(alice)),
+			Error::<Test>::DelegateeNotInvited
+		);
+	});
+}
+
+#[test]
+fn accept_delegatee_without_enough_balance_fails() {
+	new_test_ext().execute_with(|| {
+		// an account without any balance
+		let bob: SystemAccountId = get_signer(&[9u8; 32]);
+		assert_ok!(IdentityManagement::add_delegatee(RuntimeOrigin::root(), bob.clone()));
+		assert!(IdentityManagement::accept_delegatee(RuntimeOrigin::signed(bob.clone())).is_err());
+		assert!(PendingDelegatees::<Test>::contains_key(&bob));
+		assert!(!Delegatee::<Test>::contains_key(&bob));
+	});
+}
+
+#[test]
+fn remove_delegatee_withdraws_invitation() {
+	new_test_ext().execute_with(|| {
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		assert_ok!(IdentityManagement::add_delegatee(RuntimeOrigin::root(), bob.clone()));
+		assert_ok!(IdentityManagement::remove_delegatee(RuntimeOrigin::root(), bob.clone()));
+		assert!(!PendingDelegatees::<Test>::contains_key(&bob));
+		assert_noop!(
+			IdentityManagement::accept_delegatee(RuntimeOrigin::signed(bob)),
+			Error::<Test>::DelegateeNotInvited
+		);
+	});
+}
+
+#[test]
+fn migrate_oidc_clients_to_v1_works() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		let old_redirect_uris = vec!["https://example.com".as_bytes().to_vec()];
+		frame_support::storage::unhashed::put_raw(
+			&OIDCClients::<Test>::hashed_key_for(&alice),
+			&old_redirect_uris.encode(),
+		);
+		StorageVersion::new(0).put::<Pallet<Test>>();
+
+		crate::migrations::migrate_to_v1::<Test>();
+
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		let client = OIDCClients::<Test>::get(&alice).unwrap();
+		assert_eq!(client.redirect_uris[0].to_vec(), old_redirect_uris[0]);
+		assert_eq!(client.deposit, 0);
+		assert!(client.metadata.name.is_empty());
+	});
+}
@@ -52,6 +52,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn add_delegatee() -> Weight;
 	fn remove_delegatee() -> Weight;
+	fn accept_delegatee() -> Weight;
 	fn link_identity() -> Weight;
 	fn deactivate_identity() -> Weight;
 	fn activate_identity() -> Weight;
@@ -59,6 +60,16 @@ pub trait WeightInfo {
 	fn identity_deactivated() -> Weight;
 	fn identity_activated() -> Weight;
 	fn identity_networks_set() -> Weight;
+	fn identity_unlinked() -> Weight;
+	fn id_graphs_merged() -> Weight;
+	fn prime_identity_transferred() -> Weight;
+	fn recovery_guardians_set() -> Weight;
+	fn recovery_initiated() -> Weight;
+	fn recovery_approved() -> Weight;
+	fn recovery_cancelled() -> Weight;
+	fn prime_identity_recovered() -> Weight;
+	fn identity_label_set() -> Weight;
+	fn identity_reverified() -> Weight;
 	fn some_error() -> Weight;
 }
 
@@ -86,6 +97,14 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// The delegatee deposit isn't covered by the generated `add_delegatee` and `remove_delegatee`
+	// weights, they have to be regenerated together with `accept_delegatee`. Until then
+	// `accept_delegatee` is estimated as `remove_delegatee` plus the reserve and the
+	// `PendingDelegatees` and `DelegateeDeposits` accesses.
+	fn accept_delegatee() -> Weight {
+		Self::remove_delegatee()
+			.saturating_add(T::DbWeight::get().reads_writes(3_u64, 3_u64))
+	}
 	/// Storage: IMPExtrinsicWhitelist GroupControlOn (r:1 w:0)
 	/// Proof Skipped: IMPExtrinsicWhitelist GroupControlOn (max_values: Some(1), max_size: None, mode: Measured)
 	fn link_identity() -> Weight {
@@ -168,6 +187,39 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// The TEE callbacks from `identity_unlinked` to `identity_reverified` are benchmarked but
+	// their weights are not generated yet, they reuse the `identity_networks_set` weight
+	// until the weight files are regenerated with the benchmark CLI.
+	fn identity_unlinked() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn id_graphs_merged() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn prime_identity_transferred() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn recovery_guardians_set() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn recovery_initiated() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn recovery_approved() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn recovery_cancelled() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn prime_identity_recovered() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn identity_label_set() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn identity_reverified() -> Weight {
+		Self::identity_networks_set()
+	}
 	/// Storage: Teerex EnclaveIndex (r:1 w:0)
 	/// Proof Skipped: Teerex EnclaveIndex (max_values: None, max_size: None, mode: Measured)
 	fn some_error() -> Weight {
@@ -203,6 +255,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// The delegatee deposit isn't covered by the generated `add_delegatee` and `remove_delegatee`
+	// weights, they have to be regenerated together with `accept_delegatee`. Until then
+	// `accept_delegatee` is estimated as `remove_delegatee` plus the reserve and the
+	// `PendingDelegatees` and `DelegateeDeposits` accesses.
+	fn accept_delegatee() -> Weight {
+		Self::remove_delegatee()
+			.saturating_add(RocksDbWeight::get().reads_writes(3_u64, 3_u64))
+	}
 	/// Storage: IMPExtrinsicWhitelist GroupControlOn (r:1 w:0)
 	/// Proof Skipped: IMPExtrinsicWhitelist GroupControlOn (max_values: Some(1), max_size: None, mode: Measured)
 	fn link_identity() -> Weight {
@@ -285,6 +345,39 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// The TEE callbacks from `identity_unlinked` to `identity_reverified` are benchmarked but
+	// their weights are not generated yet, they reuse the `identity_networks_set` weight
+	// until the weight files are regenerated with the benchmark CLI.
+	fn identity_unlinked() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn id_graphs_merged() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn prime_identity_transferred() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn recovery_guardians_set() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn recovery_initiated() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn recovery_approved() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn recovery_cancelled() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn prime_identity_recovered() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn identity_label_set() -> Weight {
+		Self::identity_networks_set()
+	}
+	fn identity_reverified() -> Weight {
+		Self::identity_networks_set()
+	}
 	/// Storage: Teerex EnclaveIndex (r:1 w:0)
 	/// Proof Skipped: Teerex EnclaveIndex (max_values: None, max_size: None, mode: Measured)
 	fn some_error() -> Weight {
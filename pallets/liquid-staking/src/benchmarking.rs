@@ -0,0 +1,172 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarking
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::traits::OnInitialize;
+
+const SEED: u32 = 0;
+const DERIVATIVE_ID: u32 = 1000;
+
+fn min_candidate_stk<T: Config>() -> BalanceOf<T> {
+	<T as ParaStaking::Config>::MinCandidateStk::get()
+}
+
+/// LIT staked at once, enough to open a new pool delegation
+fn stake_amount<T: Config>() -> BalanceOf<T> {
+	<T as ParaStaking::Config>::MinDelegatorStk::get() * 10u32.into()
+}
+
+/// The most delegations the pool can hold
+fn max_pool_delegations<T: Config>() -> u32 {
+	T::MaxCandidates::get().min(<T as ParaStaking::Config>::MaxDelegationsPerDelegator::get())
+}
+
+fn create_funded_user<T: Config>(name: &'static str, n: u32) -> T::AccountId {
+	let user = account(name, n, SEED);
+	let total = min_candidate_stk::<T>() * 100u32.into();
+	let _ = <T as ParaStaking::Config>::Currency::make_free_balance_be(&user, total);
+	user
+}
+
+fn create_candidate<T: Config>(n: u32) -> Result<T::AccountId, &'static str> {
+	let candidate = create_funded_user::<T>("candidate", n);
+	ParaStaking::Pallet::<T>::add_candidates_whitelist(RawOrigin::Root.into(), candidate.clone())?;
+	ParaStaking::Pallet::<T>::join_candidates(
+		RawOrigin::Signed(candidate.clone()).into(),
+		min_candidate_stk::<T>(),
+	)?;
+	Ok(candidate)
+}
+
+/// Create the derivative if needed and whitelist `n` new candidates
+fn setup_pool<T: Config>(n: u32) -> Result<Vec<T::AccountId>, &'static str>
+where
+	AssetIdOf<T>: From<u32>,
+{
+	let admin = T::AdminOrigin::try_successful_origin().map_err(|_| "no admin origin")?;
+	if Pallet::<T>::derivative_asset_id().is_none() {
+		Pallet::<T>::create_derivative(admin.clone(), DERIVATIVE_ID.into(), 1u32.into())?;
+	}
+	let mut candidates = Vec::new();
+	for i in 0..n {
+		let candidate = create_candidate::<T>(i)?;
+		Pallet::<T>::add_candidate(admin.clone(), candidate.clone())?;
+		candidates.push(candidate);
+	}
+	Ok(candidates)
+}
+
+/// Stake `d` times, so that the pool delegates to `d` candidates, and unstake it all
+fn setup_unstaked<T: Config>(d: u32) -> Result<T::AccountId, &'static str>
+where
+	AssetIdOf<T>: From<u32>,
+{
+	setup_pool::<T>(d)?;
+	let staker = create_funded_user::<T>("staker", 0);
+	for _ in 0..d {
+		Pallet::<T>::stake(RawOrigin::Signed(staker.clone()).into(), stake_amount::<T>())?;
+	}
+	let derivative_amount = T::Assets::balance(DERIVATIVE_ID.into(), &staker);
+	Pallet::<T>::unstake(RawOrigin::Signed(staker.clone()).into(), derivative_amount)?;
+	Ok(staker)
+}
+
+/// Run the parachain-staking hooks until `rounds` more rounds have started
+fn roll_rounds<T: Config>(rounds: u32) {
+	let target = ParaStaking::Pallet::<T>::round().current.saturating_add(rounds);
+	while ParaStaking::Pallet::<T>::round().current < target {
+		let now = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		frame_system::Pallet::<T>::set_block_number(now);
+		ParaStaking::Pallet::<T>::on_initialize(now);
+	}
+}
+
+benchmarks! {
+	where_clause { where AssetIdOf<T>: From<u32> }
+
+	create_derivative {
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, DERIVATIVE_ID.into(), 1u32.into())
+	verify {
+		assert_eq!(Pallet::<T>::derivative_asset_id(), Some(DERIVATIVE_ID.into()));
+	}
+
+	add_candidate {
+		setup_pool::<T>(T::MaxCandidates::get() - 1)?;
+		let candidate = create_candidate::<T>(T::MaxCandidates::get())?;
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, candidate.clone())
+	verify {
+		assert!(Pallet::<T>::candidates().contains(&candidate));
+	}
+
+	remove_candidate {
+		let candidates = setup_pool::<T>(1)?;
+		let staker = create_funded_user::<T>("staker", 0);
+		Pallet::<T>::stake(RawOrigin::Signed(staker).into(), stake_amount::<T>())?;
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, candidates[0].clone())
+	verify {
+		assert!(Pallet::<T>::candidates().is_empty());
+		assert_eq!(Pallet::<T>::reserve(), stake_amount::<T>());
+	}
+
+	stake {
+		setup_pool::<T>(1)?;
+		let staker = create_funded_user::<T>("staker", 0);
+	}: _(RawOrigin::Signed(staker.clone()), stake_amount::<T>())
+	verify {
+		assert_eq!(T::Assets::balance(DERIVATIVE_ID.into(), &staker), stake_amount::<T>());
+	}
+
+	unstake {
+		let d in 1 .. max_pool_delegations::<T>();
+		setup_pool::<T>(d)?;
+		let staker = create_funded_user::<T>("staker", 0);
+		for _ in 0..d {
+			Pallet::<T>::stake(RawOrigin::Signed(staker.clone()).into(), stake_amount::<T>())?;
+		}
+		let derivative_amount = T::Assets::balance(DERIVATIVE_ID.into(), &staker);
+	}: _(RawOrigin::Signed(staker.clone()), derivative_amount)
+	verify {
+		assert!(Pallet::<T>::unbonding(&staker).is_some());
+		assert!(Pallet::<T>::pending_unbond().is_zero());
+	}
+
+	withdraw_unbonded {
+		let d in 1 .. max_pool_delegations::<T>();
+		let staker = setup_unstaked::<T>(d)?;
+		roll_rounds::<T>(<T as ParaStaking::Config>::RevokeDelegationDelay::get());
+	}: _(RawOrigin::Signed(staker.clone()))
+	verify {
+		assert!(Pallet::<T>::unbonding(&staker).is_none());
+	}
+
+	process_unbonding {
+		let d in 1 .. max_pool_delegations::<T>();
+		let staker = setup_unstaked::<T>(d)?;
+		roll_rounds::<T>(<T as ParaStaking::Config>::RevokeDelegationDelay::get());
+	}: _(RawOrigin::Signed(staker))
+	verify {
+		assert_eq!(Pallet::<T>::liquidity(), Pallet::<T>::total_unbonding());
+	}
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_bench_ext(), crate::mock::Test);
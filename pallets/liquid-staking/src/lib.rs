@@ -0,0 +1,656 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! A liquid staking pool on top of `pallet-parachain-staking`
+//!
+//! Stakers deposit LIT into the pool account, which delegates it to the whitelisted candidate
+//! with the lowest pool delegation, and receive a fungible derivative minted in `pallet-assets`.
+//! Being a regular asset, the derivative is transferable through the assets-erc20 precompile at
+//! `0xFFFFFFFF` followed by the big endian asset id.
+//!
+//! The pool delegations auto-compound their rewards, so the exchange rate
+//!
+//! rate = (delegated + unbonded LIT held by the pool - LIT owed to unstakers) / derivative supply
+//!
+//! grows with the rewards (and drops with slashes). The unbonded LIT is tracked in `Reserve`
+//! rather than read from the free balance of the pool account, so that donations to the pool
+//! account can't move the exchange rate.
+//!
+//! Unstaking burns the derivative and queues the LIT for unbonding. Since parachain-staking
+//! only allows one scheduled request per delegation, the queued amount is scheduled on the
+//! largest delegations without a pending request, the rest waits for `process_unbonding`.
+//! New stakes are first used to cover the queued amount instead of being delegated.
+//! The unstaker withdraws the LIT once `RevokeDelegationDelay` rounds have passed and the
+//! unbonded LIT covers the unstaked amount, in the order of unstaking. The pool account is
+//! endowed with the existential deposit when the derivative is created, it is not part of the
+//! pooled LIT.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
+	pallet_prelude::*,
+	storage::with_storage_layer,
+	traits::{
+		tokens::{
+			fungibles::{Create, Inspect, Mutate},
+			Fortitude, Precision,
+		},
+		Currency, ExistenceRequirement, StorageVersion,
+	},
+	PalletId,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use pallet_parachain_staking as ParaStaking;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, One, Saturating, Zero},
+	FixedPointNumber, FixedU128, Percent, Rounding, SaturatedConversion,
+};
+use sp_std::prelude::*;
+
+pub use pallet::*;
+
+mod types;
+pub use types::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> = ParaStaking::BalanceOf<T>;
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + ParaStaking::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The assets in which the derivative is minted
+		type Assets: Create<Self::AccountId> + Mutate<Self::AccountId, Balance = BalanceOf<Self>>;
+		/// The pool account is derived from it
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Maximum number of whitelisted candidates
+		#[pallet::constant]
+		type MaxCandidates: Get<u32>;
+		/// Maximum number of pending unbonding chunks per unstaker
+		#[pallet::constant]
+		type MaxUnbondingChunks: Get<u32>;
+		/// The origin who manages this pallet
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Weights
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The derivative asset is already created
+		DerivativeAlreadyCreated,
+		/// The derivative asset is not created yet
+		DerivativeNotCreated,
+		/// The account is not a parachain-staking candidate
+		NotCandidate,
+		/// The candidate is already whitelisted
+		CandidateAlreadyWhitelisted,
+		/// The candidate is not whitelisted
+		CandidateNotWhitelisted,
+		/// Too many whitelisted candidates
+		TooManyCandidates,
+		/// No whitelisted candidate can receive a delegation
+		NoCandidateAvailable,
+		/// The pool has a scheduled request towards the candidate
+		PendingDelegationRequest,
+		/// The amount is worth nothing at the current exchange rate
+		AmountTooLow,
+		/// Too many pending unbonding chunks
+		TooManyUnbondingChunks,
+		/// No unbonding chunk is unlocked
+		NothingToWithdraw,
+		/// The pool does not hold enough free balance yet
+		InsufficientLiquidity,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		DerivativeCreated {
+			asset_id: AssetIdOf<T>,
+		},
+		CandidateAdded {
+			candidate: T::AccountId,
+		},
+		CandidateRemoved {
+			candidate: T::AccountId,
+		},
+		Staked {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			derivative_amount: BalanceOf<T>,
+		},
+		Unstaked {
+			who: T::AccountId,
+			derivative_amount: BalanceOf<T>,
+			amount: BalanceOf<T>,
+			unlock_round: ParaStaking::RoundIndex,
+		},
+		UnbondingScheduled {
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		Withdrawn {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn derivative_asset_id)]
+	pub type DerivativeAssetId<T: Config> = StorageValue<_, AssetIdOf<T>, OptionQuery>;
+
+	/// Candidates receiving the pool delegations
+	#[pallet::storage]
+	#[pallet::getter(fn candidates)]
+	pub type Candidates<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxCandidates>, ValueQuery>;
+
+	/// LIT owed to unstakers, excluded from the exchange rate
+	#[pallet::storage]
+	#[pallet::getter(fn total_unbonding)]
+	pub type TotalUnbonding<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Part of `TotalUnbonding` not scheduled in parachain-staking yet
+	#[pallet::storage]
+	#[pallet::getter(fn pending_unbond)]
+	pub type PendingUnbond<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// LIT of the pool outside its active delegations, i.e. scheduled for unbonding or held
+	/// free by the pool account
+	#[pallet::storage]
+	#[pallet::getter(fn reserve)]
+	pub type Reserve<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// LIT unstaked since genesis, the unbonding chunks are withdrawn in this order
+	#[pallet::storage]
+	#[pallet::getter(fn total_unstaked)]
+	pub type TotalUnstaked<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// LIT withdrawn since genesis
+	#[pallet::storage]
+	#[pallet::getter(fn total_withdrawn)]
+	pub type TotalWithdrawn<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn unbonding)]
+	pub type Unbonding<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<UnbondChunk<BalanceOf<T>>, T::MaxUnbondingChunks>,
+		OptionQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create the derivative asset, owned by the pool account
+		///
+		/// Its metadata can be set with `pallet_assets::force_set_metadata`. The pool account is
+		/// endowed with the existential deposit if needed.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_derivative())]
+		pub fn create_derivative(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			min_balance: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(Self::derivative_asset_id().is_none(), Error::<T>::DerivativeAlreadyCreated);
+			let pool = Self::account_id();
+			let existential_deposit = <T as ParaStaking::Config>::Currency::minimum_balance();
+			if <T as ParaStaking::Config>::Currency::free_balance(&pool) < existential_deposit {
+				let _ = <T as ParaStaking::Config>::Currency::make_free_balance_be(
+					&pool,
+					existential_deposit,
+				);
+			}
+			T::Assets::create(asset_id, pool, true, min_balance)?;
+			DerivativeAssetId::<T>::put(asset_id);
+			Self::deposit_event(Event::DerivativeCreated { asset_id });
+			Ok(Pays::No.into())
+		}
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_candidate())]
+		pub fn add_candidate(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(ParaStaking::Pallet::<T>::is_candidate(&candidate), Error::<T>::NotCandidate);
+			Candidates::<T>::try_mutate(|candidates| {
+				ensure!(!candidates.contains(&candidate), Error::<T>::CandidateAlreadyWhitelisted);
+				candidates
+					.try_push(candidate.clone())
+					.map_err(|_| Error::<T>::TooManyCandidates)
+			})?;
+			Self::deposit_event(Event::CandidateAdded { candidate });
+			Ok(Pays::No.into())
+		}
+
+		/// Remove a candidate from the whitelist and revoke the pool delegation to it
+		///
+		/// The revoked LIT stays in the pool account and backs the withdrawals
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_candidate())]
+		pub fn remove_candidate(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let mut candidates = Self::candidates();
+			let index = candidates
+				.iter()
+				.position(|c| c == &candidate)
+				.ok_or(Error::<T>::CandidateNotWhitelisted)?;
+			let pool = Self::account_id();
+			ensure!(
+				!ParaStaking::Pallet::<T>::delegation_request_exists(&candidate, &pool),
+				Error::<T>::PendingDelegationRequest
+			);
+
+			candidates.remove(index);
+			Candidates::<T>::put(candidates);
+			if let Some(bond) = Self::pool_bond(&candidate) {
+				ParaStaking::Pallet::<T>::schedule_revoke_delegation(
+					RawOrigin::Signed(pool).into(),
+					candidate.clone(),
+				)?;
+				PendingUnbond::<T>::mutate(|pending| *pending = pending.saturating_sub(bond));
+				Reserve::<T>::mutate(|reserve| *reserve = reserve.saturating_add(bond));
+			}
+			Self::deposit_event(Event::CandidateRemoved { candidate });
+			Ok(Pays::No.into())
+		}
+
+		/// Deposit `amount` LIT into the pool and receive the derivative
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::stake())]
+		pub fn stake(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let asset_id = Self::derivative_asset_id().ok_or(Error::<T>::DerivativeNotCreated)?;
+			let derivative_amount = Self::to_derivative(amount);
+			ensure!(!derivative_amount.is_zero(), Error::<T>::AmountTooLow);
+
+			// cover the queued unbonding first, it saves a round trip through parachain-staking
+			let pool = Self::account_id();
+			let pending = Self::pending_unbond();
+			let covered = pending.min(amount);
+			let to_delegate = amount.saturating_sub(covered);
+			let target = if to_delegate.is_zero() {
+				None
+			} else {
+				Some(Self::pick_candidate(&pool, to_delegate)?)
+			};
+
+			<T as ParaStaking::Config>::Currency::transfer(
+				&who,
+				&pool,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			PendingUnbond::<T>::put(pending.saturating_sub(covered));
+			Reserve::<T>::mutate(|reserve| *reserve = reserve.saturating_add(covered));
+			if let Some((candidate, bonded)) = target {
+				Self::delegate(pool, candidate, bonded, to_delegate)?;
+			}
+
+			T::Assets::mint_into(asset_id, &who, derivative_amount)?;
+			Self::deposit_event(Event::Staked { who, amount, derivative_amount });
+			Ok(().into())
+		}
+
+		/// Burn `derivative_amount` of the derivative and queue the LIT it is worth for unbonding
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::unstake(
+			<T as ParaStaking::Config>::MaxDelegationsPerDelegator::get()
+		))]
+		pub fn unstake(
+			origin: OriginFor<T>,
+			derivative_amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let asset_id = Self::derivative_asset_id().ok_or(Error::<T>::DerivativeNotCreated)?;
+			let amount = Self::to_native(derivative_amount);
+			ensure!(!amount.is_zero(), Error::<T>::AmountTooLow);
+
+			let unlock_round = ParaStaking::Pallet::<T>::round()
+				.current
+				.saturating_add(<T as ParaStaking::Config>::RevokeDelegationDelay::get());
+			let queue_position = Self::total_unstaked().saturating_add(amount);
+			Unbonding::<T>::try_mutate(&who, |chunks| {
+				chunks
+					.get_or_insert_with(Default::default)
+					.try_push(UnbondChunk { amount, unlock_round, queue_position })
+					.map_err(|_| Error::<T>::TooManyUnbondingChunks)?;
+				T::Assets::burn_from(
+					asset_id,
+					&who,
+					derivative_amount,
+					Precision::Exact,
+					Fortitude::Polite,
+				)
+			})?;
+			TotalUnbonding::<T>::mutate(|total| *total = total.saturating_add(amount));
+			TotalUnstaked::<T>::put(queue_position);
+			PendingUnbond::<T>::mutate(|pending| *pending = pending.saturating_add(amount));
+			Self::schedule_pending_unbond();
+
+			Self::deposit_event(Event::Unstaked { who, derivative_amount, amount, unlock_round });
+			Ok(().into())
+		}
+
+		/// Withdraw the unlocked unbonding chunks of the caller
+		///
+		/// A chunk is unlocked once its unlock round is reached and the unbonded LIT covers all
+		/// LIT unstaked up to it, so that the unstakers are paid out in order.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonded(
+			<T as ParaStaking::Config>::MaxDelegationsPerDelegator::get()
+		))]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::execute_due_requests();
+
+			let now = ParaStaking::Pallet::<T>::round().current;
+			let pool = Self::account_id();
+			let liquidity = Self::liquidity();
+			// the withdrawals so far plus the current liquidity don't change with a withdrawal
+			let withdrawable = Self::total_withdrawn().saturating_add(liquidity);
+			Unbonding::<T>::try_mutate_exists(&who, |maybe_chunks| {
+				let chunks = maybe_chunks.as_mut().ok_or(Error::<T>::NothingToWithdraw)?;
+				ensure!(
+					chunks.iter().any(|chunk| chunk.unlock_round <= now),
+					Error::<T>::NothingToWithdraw
+				);
+				let mut amount = BalanceOf::<T>::zero();
+				chunks.retain(|chunk| {
+					if chunk.unlock_round <= now && chunk.queue_position <= withdrawable {
+						amount = amount.saturating_add(chunk.amount);
+						false
+					} else {
+						true
+					}
+				});
+				ensure!(
+					!amount.is_zero() && liquidity >= amount,
+					Error::<T>::InsufficientLiquidity
+				);
+				<T as ParaStaking::Config>::Currency::transfer(
+					&pool,
+					&who,
+					amount,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				TotalUnbonding::<T>::mutate(|total| *total = total.saturating_sub(amount));
+				TotalWithdrawn::<T>::mutate(|total| *total = total.saturating_add(amount));
+				Reserve::<T>::mutate(|reserve| *reserve = reserve.saturating_sub(amount));
+				if chunks.is_empty() {
+					*maybe_chunks = None;
+				}
+				Self::deposit_event(Event::Withdrawn { who: who.clone(), amount });
+				Ok(().into())
+			})
+		}
+
+		/// Execute the due requests of the pool and schedule the queued unbonding, callable by
+		/// anyone
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::process_unbonding(
+			<T as ParaStaking::Config>::MaxDelegationsPerDelegator::get()
+		))]
+		pub fn process_unbonding(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::execute_due_requests();
+			Self::schedule_pending_unbond();
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account holding and delegating the pooled LIT
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Unbonded LIT held by the pool account, i.e. the part of `Reserve` which is not
+	/// scheduled for unbonding anymore, capped by its free balance above the existential deposit
+	pub fn liquidity() -> BalanceOf<T> {
+		let pool = Self::account_id();
+		let scheduled = ParaStaking::Pallet::<T>::delegator_state(&pool)
+			.map(|state| state.less_total)
+			.unwrap_or_default();
+		let free = <T as ParaStaking::Config>::Currency::free_balance(&pool)
+			.saturating_sub(<T as ParaStaking::Config>::Currency::minimum_balance());
+		Self::reserve().saturating_sub(scheduled).min(free)
+	}
+
+	/// LIT backing the derivative supply
+	pub fn total_pooled() -> BalanceOf<T> {
+		let delegated = ParaStaking::Pallet::<T>::delegator_state(Self::account_id())
+			.map(|state| state.total)
+			.unwrap_or_default();
+		delegated
+			.saturating_add(Self::liquidity())
+			.saturating_sub(Self::total_unbonding())
+	}
+
+	fn derivative_supply() -> BalanceOf<T> {
+		Self::derivative_asset_id().map(T::Assets::total_issuance).unwrap_or_default()
+	}
+
+	/// LIT per unit of derivative
+	pub fn exchange_rate() -> FixedU128 {
+		let supply = Self::derivative_supply().saturated_into::<u128>();
+		let pooled = Self::total_pooled().saturated_into::<u128>();
+		if supply.is_zero() || pooled.is_zero() {
+			return FixedU128::one()
+		}
+		FixedU128::checked_from_rational(pooled, supply).unwrap_or_else(FixedU128::one)
+	}
+
+	/// Derivative minted for `amount` LIT
+	pub fn to_derivative(amount: BalanceOf<T>) -> BalanceOf<T> {
+		Self::convert(amount, Self::derivative_supply(), Self::total_pooled())
+	}
+
+	/// LIT paid out for `derivative_amount` of the derivative
+	pub fn to_native(derivative_amount: BalanceOf<T>) -> BalanceOf<T> {
+		Self::convert(derivative_amount, Self::total_pooled(), Self::derivative_supply())
+	}
+
+	// `amount * numerator / denominator` rounded down, 1:1 while the pool is empty
+	fn convert(
+		amount: BalanceOf<T>,
+		numerator: BalanceOf<T>,
+		denominator: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		if numerator.is_zero() || denominator.is_zero() {
+			return amount
+		}
+		multiply_by_rational_with_rounding(
+			amount.saturated_into(),
+			numerator.saturated_into(),
+			denominator.saturated_into(),
+			Rounding::Down,
+		)
+		.map(SaturatedConversion::saturated_into)
+		.unwrap_or_else(Zero::zero)
+	}
+
+	fn pool_bond(candidate: &T::AccountId) -> Option<BalanceOf<T>> {
+		ParaStaking::Pallet::<T>::delegator_state(Self::account_id())
+			.and_then(|state| state.get_bond_amount(candidate))
+	}
+
+	// the whitelisted candidate with the lowest pool delegation which can take `amount`,
+	// and whether the pool already delegates to it
+	fn pick_candidate(
+		pool: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<(T::AccountId, bool), Error<T>> {
+		let min_new_delegation = if ParaStaking::Pallet::<T>::is_delegator(pool) {
+			<T as ParaStaking::Config>::MinDelegation::get()
+		} else {
+			<T as ParaStaking::Config>::MinDelegatorStk::get()
+		};
+		Self::candidates()
+			.into_iter()
+			.filter(|c| !ParaStaking::Pallet::<T>::delegation_request_revoke_exists(c, pool))
+			.map(|c| {
+				let bond = Self::pool_bond(&c);
+				(c, bond)
+			})
+			.filter(|(_, bond)| bond.is_some() || amount >= min_new_delegation)
+			.min_by_key(|(_, bond)| bond.unwrap_or_default())
+			.map(|(candidate, bond)| (candidate, bond.is_some()))
+			.ok_or(Error::<T>::NoCandidateAvailable)
+	}
+
+	// the rewards of a new delegation are fully auto-compounded
+	fn delegate(
+		pool: T::AccountId,
+		candidate: T::AccountId,
+		bonded: bool,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let origin: OriginFor<T> = RawOrigin::Signed(pool).into();
+		let result = if bonded {
+			ParaStaking::Pallet::<T>::delegator_bond_more(origin, candidate, amount)
+		} else {
+			ParaStaking::Pallet::<T>::delegate_with_auto_compound(
+				origin,
+				candidate,
+				amount,
+				Percent::from_percent(100),
+			)
+		};
+		result.map(|_| ()).map_err(|e| e.error)
+	}
+
+	// schedules the queued unbonding on the largest pool delegations without a pending request
+	fn schedule_pending_unbond() {
+		let queued = Self::pending_unbond();
+		let mut pending = queued;
+		if pending.is_zero() {
+			return
+		}
+		let pool = Self::account_id();
+		let mut bonds = match ParaStaking::Pallet::<T>::delegator_state(&pool) {
+			Some(state) => state.delegations.0,
+			None => return,
+		};
+		bonds.sort_by(|a, b| b.amount.cmp(&a.amount));
+
+		let min_delegation = <T as ParaStaking::Config>::MinDelegation::get();
+		for bond in bonds {
+			if pending.is_zero() {
+				break
+			}
+			if ParaStaking::Pallet::<T>::delegation_request_exists(&bond.owner, &pool) {
+				continue
+			}
+			let origin: OriginFor<T> = RawOrigin::Signed(pool.clone()).into();
+			let (scheduled, result) = if pending >= bond.amount {
+				(
+					bond.amount,
+					with_storage_layer(|| {
+						ParaStaking::Pallet::<T>::schedule_revoke_delegation(
+							origin,
+							bond.owner.clone(),
+						)
+					}),
+				)
+			} else {
+				let less = pending.min(bond.amount.saturating_sub(min_delegation));
+				if less.is_zero() {
+					continue
+				}
+				(
+					less,
+					with_storage_layer(|| {
+						ParaStaking::Pallet::<T>::schedule_delegator_bond_less(
+							origin,
+							bond.owner.clone(),
+							less,
+						)
+					}),
+				)
+			};
+			if result.is_ok() {
+				pending = pending.saturating_sub(scheduled);
+				Self::deposit_event(Event::UnbondingScheduled {
+					candidate: bond.owner,
+					amount: scheduled,
+				});
+			}
+		}
+		PendingUnbond::<T>::put(pending);
+		Reserve::<T>::mutate(|reserve| {
+			*reserve = reserve.saturating_add(queued.saturating_sub(pending))
+		});
+	}
+
+	// executes the scheduled requests of the pool which are due
+	fn execute_due_requests() {
+		let pool = Self::account_id();
+		let bonds = match ParaStaking::Pallet::<T>::delegator_state(&pool) {
+			Some(state) => state.delegations.0,
+			None => return,
+		};
+		let now = ParaStaking::Pallet::<T>::round().current;
+		for bond in bonds {
+			let due = ParaStaking::Pallet::<T>::delegation_scheduled_requests(&bond.owner)
+				.iter()
+				.any(|request| request.delegator == pool && request.when_executable <= now);
+			if due {
+				let _ = with_storage_layer(|| {
+					ParaStaking::Pallet::<T>::execute_delegation_request(
+						RawOrigin::Signed(pool.clone()).into(),
+						pool.clone(),
+						bond.owner.clone(),
+					)
+				});
+			}
+		}
+	}
+}
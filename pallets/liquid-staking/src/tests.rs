@@ -0,0 +1,223 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Error, Event, UnbondChunk};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	traits::{fungibles::Inspect, Currency},
+};
+use sp_runtime::{FixedPointNumber, FixedU128, Percent};
+
+fn pool_bond(candidate: AccountId) -> Option<Balance> {
+	ParachainStaking::delegator_state(LiquidStaking::account_id())
+		.and_then(|state| state.get_bond_amount(&candidate))
+}
+
+#[test]
+fn admin_calls_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LiquidStaking::create_derivative(RuntimeOrigin::root(), 2, 1),
+			Error::<Test>::DerivativeAlreadyCreated
+		);
+		assert_noop!(
+			LiquidStaking::add_candidate(RuntimeOrigin::signed(1), 10),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			LiquidStaking::add_candidate(RuntimeOrigin::root(), 1),
+			Error::<Test>::NotCandidate
+		);
+		assert_noop!(
+			LiquidStaking::add_candidate(RuntimeOrigin::root(), 10),
+			Error::<Test>::CandidateAlreadyWhitelisted
+		);
+		assert_noop!(
+			LiquidStaking::remove_candidate(RuntimeOrigin::root(), 1),
+			Error::<Test>::CandidateNotWhitelisted
+		);
+
+		assert_ok!(LiquidStaking::remove_candidate(RuntimeOrigin::root(), 11));
+		assert_eq!(LiquidStaking::candidates().into_inner(), vec![10]);
+		System::assert_last_event(RuntimeEvent::LiquidStaking(Event::<Test>::CandidateRemoved {
+			candidate: 11,
+		}));
+	})
+}
+
+#[test]
+fn stake_mints_derivative_and_delegates() {
+	new_test_ext().execute_with(|| {
+		let pool = LiquidStaking::account_id();
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 10));
+		System::assert_last_event(RuntimeEvent::LiquidStaking(Event::<Test>::Staked {
+			who: 1,
+			amount: 10,
+			derivative_amount: 10,
+		}));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 20));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(3), 5));
+
+		// each stake goes to the lowest pool delegation
+		assert_eq!(pool_bond(10), Some(15));
+		assert_eq!(pool_bond(11), Some(20));
+		assert_eq!(
+			ParachainStaking::delegation_auto_compound(&10, &pool),
+			Percent::from_percent(100)
+		);
+		assert_eq!(Assets::balance(DERIVATIVE_ID, 1), 10);
+		assert_eq!(Assets::balance(DERIVATIVE_ID, 2), 20);
+		assert_eq!(Assets::total_issuance(DERIVATIVE_ID), 35);
+		assert_eq!(Balances::free_balance(1), 90);
+		assert_eq!(LiquidStaking::exchange_rate(), FixedU128::saturating_from_integer(1));
+	})
+}
+
+#[test]
+fn stake_fails_without_candidate_able_to_take_it() {
+	new_test_ext().execute_with(|| {
+		// below the minimal delegator stake
+		assert_noop!(
+			LiquidStaking::stake(RuntimeOrigin::signed(1), 4),
+			Error::<Test>::NoCandidateAvailable
+		);
+		assert_noop!(
+			LiquidStaking::stake(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::AmountTooLow
+		);
+	})
+}
+
+#[test]
+fn create_derivative_endows_pool_account() {
+	new_test_ext().execute_with(|| {
+		// the existential deposit is not pooled
+		assert_eq!(Balances::free_balance(LiquidStaking::account_id()), 1);
+		assert_eq!(LiquidStaking::liquidity(), 0);
+		assert_eq!(LiquidStaking::total_pooled(), 0);
+	})
+}
+
+#[test]
+fn donation_to_pool_account_does_not_change_exchange_rate() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 10));
+		let _ = Balances::deposit_creating(&LiquidStaking::account_id(), 10);
+		assert_eq!(LiquidStaking::total_pooled(), 10);
+		assert_eq!(LiquidStaking::exchange_rate(), FixedU128::saturating_from_integer(1));
+
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 10));
+		assert_eq!(Assets::balance(DERIVATIVE_ID, 2), 10);
+		assert_eq!(LiquidStaking::to_native(10), 10);
+	})
+}
+
+#[test]
+fn unstake_and_withdraw_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 20));
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(1), 10));
+		System::assert_last_event(RuntimeEvent::LiquidStaking(Event::<Test>::Unstaked {
+			who: 1,
+			derivative_amount: 10,
+			amount: 10,
+			unlock_round: 3,
+		}));
+		assert_eq!(Assets::balance(DERIVATIVE_ID, 1), 10);
+		assert_eq!(LiquidStaking::total_unbonding(), 10);
+		assert_eq!(LiquidStaking::pending_unbond(), 0);
+		assert_eq!(
+			LiquidStaking::unbonding(1).unwrap().into_inner(),
+			vec![UnbondChunk { amount: 10, unlock_round: 3, queue_position: 10 }]
+		);
+		// the unbonding does not change the exchange rate
+		assert_eq!(LiquidStaking::exchange_rate(), FixedU128::saturating_from_integer(1));
+		assert_noop!(
+			LiquidStaking::withdraw_unbonded(RuntimeOrigin::signed(1)),
+			Error::<Test>::NothingToWithdraw
+		);
+
+		run_to_block(10);
+		assert_ok!(LiquidStaking::withdraw_unbonded(RuntimeOrigin::signed(1)));
+		System::assert_last_event(RuntimeEvent::LiquidStaking(Event::<Test>::Withdrawn {
+			who: 1,
+			amount: 10,
+		}));
+		assert_eq!(Balances::free_balance(1), 90);
+		assert_eq!(pool_bond(10), Some(10));
+		assert_eq!(LiquidStaking::total_unbonding(), 0);
+		assert!(LiquidStaking::unbonding(1).is_none());
+	})
+}
+
+#[test]
+fn stake_covers_pending_unbond() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 10));
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(1), 4));
+		// only one request per delegation, the rest is queued
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(1), 3));
+		assert_eq!(LiquidStaking::pending_unbond(), 3);
+		assert_noop!(
+			LiquidStaking::unstake(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::TooManyUnbondingChunks
+		);
+
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 5));
+		assert_eq!(Assets::balance(DERIVATIVE_ID, 2), 5);
+		assert_eq!(LiquidStaking::pending_unbond(), 0);
+		assert_eq!(pool_bond(10), Some(12));
+		assert_eq!(Balances::free_balance(LiquidStaking::account_id()), 4);
+
+		run_to_block(10);
+		assert_ok!(LiquidStaking::withdraw_unbonded(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 97);
+		assert_eq!(pool_bond(10), Some(8));
+	})
+}
+
+#[test]
+fn withdraw_unbonded_follows_unstaking_order() {
+	new_test_ext().execute_with(|| {
+		// a single pool delegation, so the second unstake is queued
+		assert_ok!(LiquidStaking::remove_candidate(RuntimeOrigin::root(), 11));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 20));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 20));
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(1), 10));
+		assert_ok!(LiquidStaking::unstake(RuntimeOrigin::signed(2), 10));
+		assert_eq!(LiquidStaking::pending_unbond(), 10);
+
+		run_to_block(10);
+		// the unbonded LIT belongs to the first unstaker
+		assert_err!(
+			LiquidStaking::withdraw_unbonded(RuntimeOrigin::signed(2)),
+			Error::<Test>::InsufficientLiquidity
+		);
+		assert_ok!(LiquidStaking::withdraw_unbonded(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 90);
+
+		assert_ok!(LiquidStaking::process_unbonding(RuntimeOrigin::signed(3)));
+		assert_err!(
+			LiquidStaking::withdraw_unbonded(RuntimeOrigin::signed(2)),
+			Error::<Test>::InsufficientLiquidity
+		);
+		run_to_block(20);
+		assert_ok!(LiquidStaking::withdraw_unbonded(RuntimeOrigin::signed(2)));
+		assert_eq!(Balances::free_balance(2), 90);
+		assert_eq!(LiquidStaking::total_unbonding(), 0);
+		assert_eq!(pool_bond(10), Some(20));
+	})
+}
@@ -0,0 +1,29 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::pallet_prelude::*;
+use pallet_parachain_staking::RoundIndex;
+use parity_scale_codec::{Decode, Encode};
+
+/// LIT owed to an unstaker, withdrawable from `unlock_round` on
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct UnbondChunk<Balance> {
+	pub amount: Balance,
+	pub unlock_round: RoundIndex,
+	/// `TotalUnstaked` including this chunk, it's withdrawable once the LIT withdrawn so far
+	/// and the pool liquidity reach it
+	pub queue_position: Balance,
+}
@@ -0,0 +1,237 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for pallet_liquid_staking
+//!
+//! Hand estimates, the benchmarks exist but the benchmark CLI has not been run for this pallet
+//! yet. The weights are derived from the parachain-staking calls they wrap and the storage
+//! accesses listed per call are counted from the code, not measured. `d` is the number of pool
+//! delegations. Regenerate the file with `--pallet=pallet_liquid_staking` before the pallet is
+//! deployed.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_liquid_staking.
+pub trait WeightInfo {
+	fn create_derivative() -> Weight;
+	fn add_candidate() -> Weight;
+	fn remove_candidate() -> Weight;
+	fn stake() -> Weight;
+	fn unstake(d: u32, ) -> Weight;
+	fn withdraw_unbonded(d: u32, ) -> Weight;
+	fn process_unbonding(d: u32, ) -> Weight;
+}
+
+/// Weights for pallet_liquid_staking using the Litentry node and recommended hardware.
+pub struct LitentryWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
+	// Storage: LiquidStaking DerivativeAssetId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	fn create_derivative() -> Weight {
+		Weight::from_parts(45_000_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	// Storage: LiquidStaking Candidates (r:1 w:1)
+	fn add_candidate() -> Weight {
+		Weight::from_parts(25_000_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: LiquidStaking Candidates (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(80_000_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: LiquidStaking DerivativeAssetId (r:1 w:0)
+	// Storage: LiquidStaking Candidates (r:1 w:0)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking Total (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn stake() -> Weight {
+		Weight::from_parts(230_000_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+	}
+	// Storage: LiquidStaking DerivativeAssetId (r:1 w:0)
+	// Storage: LiquidStaking Unbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalUnstaked (r:1 w:1)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:d w:d)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:d w:d)
+	fn unstake(d: u32, ) -> Weight {
+		Weight::from_parts(110_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(60_000_000 as u64, 0).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(d as u64)))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: ParachainStaking DelegatorState (r:d w:d)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:d w:d)
+	// Storage: ParachainStaking CandidateInfo (r:d w:d)
+	// Storage: ParachainStaking Total (r:d w:d)
+	// Storage: LiquidStaking Unbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalWithdrawn (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn withdraw_unbonded(d: u32, ) -> Weight {
+		Weight::from_parts(90_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(110_000_000 as u64, 0).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(d as u64)))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: ParachainStaking DelegatorState (r:d w:d)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:d w:d)
+	// Storage: ParachainStaking CandidateInfo (r:d w:d)
+	// Storage: ParachainStaking Total (r:d w:d)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	fn process_unbonding(d: u32, ) -> Weight {
+		Weight::from_parts(25_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(160_000_000 as u64, 0).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(d as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(d as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: LiquidStaking DerivativeAssetId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	fn create_derivative() -> Weight {
+		Weight::from_parts(45_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	// Storage: LiquidStaking Candidates (r:1 w:1)
+	fn add_candidate() -> Weight {
+		Weight::from_parts(25_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: LiquidStaking Candidates (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(80_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: LiquidStaking DerivativeAssetId (r:1 w:0)
+	// Storage: LiquidStaking Candidates (r:1 w:0)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking Total (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn stake() -> Weight {
+		Weight::from_parts(230_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
+	}
+	// Storage: LiquidStaking DerivativeAssetId (r:1 w:0)
+	// Storage: LiquidStaking Unbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalUnstaked (r:1 w:1)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:d w:d)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:d w:d)
+	fn unstake(d: u32, ) -> Weight {
+		Weight::from_parts(110_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(60_000_000 as u64, 0).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(d as u64)))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: ParachainStaking DelegatorState (r:d w:d)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:d w:d)
+	// Storage: ParachainStaking CandidateInfo (r:d w:d)
+	// Storage: ParachainStaking Total (r:d w:d)
+	// Storage: LiquidStaking Unbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalWithdrawn (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn withdraw_unbonded(d: u32, ) -> Weight {
+		Weight::from_parts(90_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(110_000_000 as u64, 0).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(d as u64)))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: ParachainStaking DelegatorState (r:d w:d)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:d w:d)
+	// Storage: ParachainStaking CandidateInfo (r:d w:d)
+	// Storage: ParachainStaking Total (r:d w:d)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	fn process_unbonding(d: u32, ) -> Weight {
+		Weight::from_parts(25_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(160_000_000 as u64, 0).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(d as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(d as u64)))
+	}
+}
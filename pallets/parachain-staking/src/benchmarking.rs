@@ -24,7 +24,7 @@
 //! Benchmarking
 use crate::{
 	BalanceOf, Call, CandidateBondLessRequest, Config, DelegationAction, Event, Pallet, Range,
-	Round, ScheduledRequest,
+	Round, ScheduledRequest, SlashingConfig,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize, ReservableCurrency};
@@ -254,6 +254,43 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::round().length, 1200u32);
 	}
 
+	set_slashing_config {
+		let config = SlashingConfig {
+			min_authored: Perbill::from_percent(50),
+			slash_fraction: Perbill::from_percent(1),
+			max_missed_rounds: 3,
+		};
+	}: _(RawOrigin::Root, config)
+	verify {
+		assert_eq!(Pallet::<T>::slashing_config(), config);
+	}
+
+	slash_candidate {
+		// x is total number of delegations for the candidate
+		let x in 2..(<<T as Config>::MaxTopDelegationsPerCandidate as Get<u32>>::get()
+		+ <<T as Config>::MaxBottomDelegationsPerCandidate as Get<u32>>::get());
+		let candidate: T::AccountId = create_funded_collator::<T>(
+			"unique_caller",
+			USER_SEED - 100,
+			0u32.into(),
+			true,
+		)?;
+		for i in 1..x {
+			let seed = USER_SEED + i;
+			create_funded_delegator::<T>(
+				"delegator",
+				seed,
+				min_delegator_stk::<T>(),
+				candidate.clone(),
+				true,
+			)?;
+		}
+		let bond = Pallet::<T>::candidate_info(&candidate).expect("candidate exists").bond;
+	}: _(RawOrigin::Root, candidate.clone(), Perbill::from_percent(10))
+	verify {
+		assert!(Pallet::<T>::candidate_info(&candidate).expect("candidate exists").bond < bond);
+	}
+
 	// USER DISPATCHABLES
 
 	join_candidates {
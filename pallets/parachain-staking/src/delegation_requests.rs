@@ -214,6 +214,8 @@ impl<T: Config> Pallet<T> {
 
 		let now = <Round<T>>::get().current;
 		ensure!(request.when_executable <= now, <Error<T>>::PendingDelegationRequestNotDueYet);
+		// the stake backing governance votes stays bonded until the vote locks are lowered
+		Self::ensure_unbond_respects_votes(&delegator, request.action.amount())?;
 		match request.action {
 			DelegationAction::Revoke(amount) => {
 				// revoking last delegation => leaving set of delegators
@@ -438,6 +440,7 @@ impl<T: Config> Pallet<T> {
 
 			validated_scheduled_requests.push((bond.clone(), scheduled_requests, request_idx))
 		}
+		Self::ensure_unbond_respects_votes(&delegator, state.total)?;
 
 		let mut updated_scheduled_requests = vec![];
 		// we do not update the delegator state, since the it will be completely removed
@@ -0,0 +1,215 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Governance voting with staked balance
+//!
+//! [`StakedVotingCurrency`] wraps the staking currency for `pallet_democracy`, so that the
+//! delegations and the candidate bond of an account count toward its voting balance. The vote
+//! locks are applied to the wrapped currency and recorded in [`GovernanceLocks`], the part of a
+//! lock that exceeds the free balance is backed by stake, which can't be unbonded until the
+//! lock is lowered or removed.
+
+use crate::pallet::{
+	BalanceOf, CandidateInfo, Config, DelegatorState, Error, GovernanceLocks, NegativeImbalanceOf,
+	Pallet,
+};
+use frame_support::{
+	ensure,
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, LockIdentifier, LockableCurrency,
+		ReservableCurrency, SignedImbalance, WithdrawReasons,
+	},
+};
+use sp_runtime::{traits::Saturating, DispatchError, DispatchResult};
+use sp_std::marker::PhantomData;
+
+type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::PositiveImbalance;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the delegated amount and the candidate self bond of an account
+	pub fn total_staked(acc: &T::AccountId) -> BalanceOf<T> {
+		let delegated = <DelegatorState<T>>::get(acc).map(|state| state.total).unwrap_or_default();
+		let bond = <CandidateInfo<T>>::get(acc).map(|info| info.bond).unwrap_or_default();
+		delegated.saturating_add(bond)
+	}
+
+	/// Ensures the stake left after unbonding `amount` still backs the outstanding vote locks
+	pub(crate) fn ensure_unbond_respects_votes(
+		acc: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let stake_backed =
+			<GovernanceLocks<T>>::get(acc).saturating_sub(T::Currency::free_balance(acc));
+		ensure!(
+			Self::total_staked(acc).saturating_sub(amount) >= stake_backed,
+			Error::<T>::StakeLockedByVote
+		);
+		Ok(())
+	}
+}
+
+/// Currency adapter for governance voting, see the module docs
+pub struct StakedVotingCurrency<T>(PhantomData<T>);
+
+impl<T: Config> Currency<T::AccountId> for StakedVotingCurrency<T> {
+	type Balance = BalanceOf<T>;
+	type PositiveImbalance = PositiveImbalanceOf<T>;
+	type NegativeImbalance = NegativeImbalanceOf<T>;
+
+	fn total_balance(who: &T::AccountId) -> Self::Balance {
+		T::Currency::total_balance(who)
+	}
+
+	fn can_slash(who: &T::AccountId, value: Self::Balance) -> bool {
+		T::Currency::can_slash(who, value)
+	}
+
+	fn total_issuance() -> Self::Balance {
+		T::Currency::total_issuance()
+	}
+
+	fn minimum_balance() -> Self::Balance {
+		T::Currency::minimum_balance()
+	}
+
+	fn burn(amount: Self::Balance) -> Self::PositiveImbalance {
+		T::Currency::burn(amount)
+	}
+
+	fn issue(amount: Self::Balance) -> Self::NegativeImbalance {
+		T::Currency::issue(amount)
+	}
+
+	// the staked balance is available for voting
+	fn free_balance(who: &T::AccountId) -> Self::Balance {
+		T::Currency::free_balance(who).saturating_add(Pallet::<T>::total_staked(who))
+	}
+
+	fn ensure_can_withdraw(
+		who: &T::AccountId,
+		amount: Self::Balance,
+		reasons: WithdrawReasons,
+		new_balance: Self::Balance,
+	) -> DispatchResult {
+		T::Currency::ensure_can_withdraw(who, amount, reasons, new_balance)
+	}
+
+	fn transfer(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		value: Self::Balance,
+		existence_requirement: ExistenceRequirement,
+	) -> DispatchResult {
+		T::Currency::transfer(source, dest, value, existence_requirement)
+	}
+
+	fn slash(who: &T::AccountId, value: Self::Balance) -> (Self::NegativeImbalance, Self::Balance) {
+		T::Currency::slash(who, value)
+	}
+
+	fn deposit_into_existing(
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> Result<Self::PositiveImbalance, DispatchError> {
+		T::Currency::deposit_into_existing(who, value)
+	}
+
+	fn deposit_creating(who: &T::AccountId, value: Self::Balance) -> Self::PositiveImbalance {
+		T::Currency::deposit_creating(who, value)
+	}
+
+	fn withdraw(
+		who: &T::AccountId,
+		value: Self::Balance,
+		reasons: WithdrawReasons,
+		liveness: ExistenceRequirement,
+	) -> Result<Self::NegativeImbalance, DispatchError> {
+		T::Currency::withdraw(who, value, reasons, liveness)
+	}
+
+	fn make_free_balance_be(
+		who: &T::AccountId,
+		balance: Self::Balance,
+	) -> SignedImbalance<Self::Balance, Self::PositiveImbalance> {
+		T::Currency::make_free_balance_be(who, balance)
+	}
+}
+
+impl<T: Config> ReservableCurrency<T::AccountId> for StakedVotingCurrency<T> {
+	fn can_reserve(who: &T::AccountId, value: Self::Balance) -> bool {
+		T::Currency::can_reserve(who, value)
+	}
+
+	fn slash_reserved(
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> (Self::NegativeImbalance, Self::Balance) {
+		T::Currency::slash_reserved(who, value)
+	}
+
+	fn reserved_balance(who: &T::AccountId) -> Self::Balance {
+		T::Currency::reserved_balance(who)
+	}
+
+	fn reserve(who: &T::AccountId, value: Self::Balance) -> DispatchResult {
+		T::Currency::reserve(who, value)
+	}
+
+	fn unreserve(who: &T::AccountId, value: Self::Balance) -> Self::Balance {
+		T::Currency::unreserve(who, value)
+	}
+
+	fn repatriate_reserved(
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> Result<Self::Balance, DispatchError> {
+		T::Currency::repatriate_reserved(slashed, beneficiary, value, status)
+	}
+}
+
+impl<T: Config> LockableCurrency<T::AccountId> for StakedVotingCurrency<T> {
+	type Moment = <T::Currency as LockableCurrency<T::AccountId>>::Moment;
+	type MaxLocks = <T::Currency as LockableCurrency<T::AccountId>>::MaxLocks;
+
+	fn set_lock(
+		id: LockIdentifier,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		reasons: WithdrawReasons,
+	) {
+		T::Currency::set_lock(id, who, amount, reasons);
+		<GovernanceLocks<T>>::insert(who, amount);
+	}
+
+	fn extend_lock(
+		id: LockIdentifier,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		reasons: WithdrawReasons,
+	) {
+		T::Currency::extend_lock(id, who, amount, reasons);
+		<GovernanceLocks<T>>::mutate(who, |locked| *locked = (*locked).max(amount));
+	}
+
+	fn remove_lock(id: LockIdentifier, who: &T::AccountId) {
+		T::Currency::remove_lock(id, who);
+		<GovernanceLocks<T>>::remove(who);
+	}
+}
@@ -59,6 +59,7 @@
 
 mod auto_compound;
 pub mod delegation_requests;
+pub mod governance;
 pub mod inflation;
 pub mod traits;
 pub mod types;
@@ -69,6 +70,7 @@ mod benchmarking;
 #[cfg(test)]
 mod mock;
 pub mod set;
+pub mod slashing;
 #[cfg(test)]
 mod tests;
 
@@ -77,7 +79,9 @@ pub use weights::WeightInfo;
 
 pub use auto_compound::{AutoCompoundConfig, AutoCompoundDelegations};
 pub use delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest};
+pub use governance::StakedVotingCurrency;
 pub use pallet::*;
+pub use slashing::SlashingConfig;
 pub use traits::*;
 pub use types::*;
 pub use RoundIndex;
@@ -89,13 +93,14 @@ pub mod pallet {
 		set::OrderedSet,
 		traits::*,
 		types::*,
-		AutoCompoundConfig, AutoCompoundDelegations, InflationInfo, Range, WeightInfo,
+		AutoCompoundConfig, AutoCompoundDelegations, InflationInfo, Range, SlashingConfig,
+		WeightInfo,
 	};
 	use frame_support::{
 		dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
 		traits::{
-			Currency, EstimateNextSessionRotation, Get, Imbalance, LockableCurrency,
+			Currency, EstimateNextSessionRotation, Get, Imbalance, LockableCurrency, OnUnbalanced,
 			ReservableCurrency,
 		},
 	};
@@ -114,8 +119,13 @@ pub mod pallet {
 
 	pub type RoundIndex = u32;
 	type RewardPoint = u32;
+	/// Reward points awarded to the author of a block
+	pub(crate) const POINTS_PER_BLOCK: RewardPoint = 20;
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
 
 	/// Configuration trait of this pallet.
 	#[pallet::config]
@@ -194,6 +204,8 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		/// The source for adjusted inflation base.
 		type IssuanceAdapter: IssuanceAdapter<BalanceOf<Self>>;
+		/// Handler for the balance slashed from collators missing their slots (e.g. treasury)
+		type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
 	}
 
 	#[pallet::error]
@@ -236,6 +248,7 @@ pub mod pallet {
 		CannotDelegateLessThanOrEqualToLowestBottomWhenFull,
 		PendingDelegationRevoke,
 		CandidateUnauthorized,
+		StakeLockedByVote,
 	}
 
 	#[pallet::event]
@@ -457,6 +470,31 @@ pub mod pallet {
 			delegator: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Set the liveness penalty parameters.
+		SlashingConfigSet {
+			old: SlashingConfig,
+			new: SlashingConfig,
+		},
+		/// A selected collator authored fewer blocks than required in the round.
+		CollatorMissedRound {
+			collator: T::AccountId,
+			round: RoundIndex,
+			authored: u32,
+			expected: u32,
+			missed_rounds: u32,
+		},
+		/// Slashed the self bond and the delegations of a candidate.
+		CandidateSlashed {
+			candidate: T::AccountId,
+			bond_slashed: BalanceOf<T>,
+			delegations_slashed: BalanceOf<T>,
+		},
+		/// Slashed a delegation along with its candidate.
+		DelegationSlashed {
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -466,6 +504,9 @@ pub mod pallet {
 
 			let mut round = <Round<T>>::get();
 			if round.should_update(n) {
+				// penalize the collators which missed their slots in the ending round
+				weight =
+					weight.saturating_add(Self::handle_missed_slots(round.current, round.length));
 				// mutate round
 				round.update(n);
 				// notify that new round begin
@@ -493,6 +534,8 @@ pub mod pallet {
 
 			weight = weight.saturating_add(Self::handle_delayed_payouts(round.current));
 
+			weight = weight.saturating_add(Self::handle_pending_slash());
+
 			weight
 		}
 	}
@@ -642,6 +685,22 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn slashing_config)]
+	/// Liveness penalty parameters
+	pub type SlashingConfiguration<T: Config> = StorageValue<_, SlashingConfig, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn missed_rounds)]
+	/// Consecutive rounds in which a selected collator missed its slots
+	pub type MissedRounds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_slash)]
+	/// Collators which missed their slots and are yet to be slashed, one per block
+	pub type PendingSlashes<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Perbill, OptionQuery>;
+
 	/// The whitelist of collation candidates.
 	/// This storage should be safe to delete after
 	/// we release the restriction
@@ -649,6 +708,12 @@ pub mod pallet {
 	#[pallet::getter(fn candidates)]
 	pub type Candidates<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
 
+	/// The vote locks set through [`crate::StakedVotingCurrency`]
+	#[pallet::storage]
+	#[pallet::getter(fn governance_lock)]
+	pub type GovernanceLocks<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub candidates: Vec<(T::AccountId, BalanceOf<T>)>,
@@ -999,6 +1064,7 @@ pub mod pallet {
 			ensure_signed(origin)?;
 			let state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
 			state.can_leave::<T>()?;
+			Self::ensure_unbond_respects_votes(&candidate, state.bond)?;
 			let return_stake = |bond: Bond<T::AccountId, BalanceOf<T>>| -> DispatchResult {
 				T::Currency::unreserve(&bond.owner, bond.amount);
 				// remove delegation from delegator state
@@ -1046,6 +1112,8 @@ pub mod pallet {
 			// return stake to collator
 			T::Currency::unreserve(&candidate, state.bond);
 			<CandidateInfo<T>>::remove(&candidate);
+			<MissedRounds<T>>::remove(&candidate);
+			<PendingSlashes<T>>::remove(&candidate);
 			<DelegationScheduledRequests<T>>::remove(&candidate);
 			<AutoCompoundingDelegations<T>>::remove(&candidate);
 			<TopDelegations<T>>::remove(&candidate);
@@ -1071,6 +1139,8 @@ pub mod pallet {
 			let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
 			ensure!(state.is_leaving(), Error::<T>::CandidateNotLeaving);
 			state.go_online();
+			// going back online gives a deselected collator another chance
+			<MissedRounds<T>>::remove(&collator);
 			let mut candidates = <CandidatePool<T>>::get();
 			ensure!(
 				candidates.insert(Bond { owner: collator.clone(), amount: state.total_counted }),
@@ -1160,6 +1230,9 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?; // we may want to reward this if caller != candidate
 			let mut state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+			if let Some(request) = state.request {
+				Self::ensure_unbond_respects_votes(&candidate, request.amount)?;
+			}
 			state.execute_bond_less::<T>(candidate.clone())?;
 			<CandidateInfo<T>>::insert(&candidate, state);
 			Ok(().into())
@@ -1343,6 +1416,39 @@ pub mod pallet {
 			let delegator = ensure_signed(origin)?;
 			<AutoCompoundDelegations<T>>::set_auto_compound(candidate, delegator, value)
 		}
+
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_slashing_config())]
+		/// Set the liveness penalty parameters
+		pub fn set_slashing_config(
+			origin: OriginFor<T>,
+			new: SlashingConfig,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			let old = <SlashingConfiguration<T>>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			<SlashingConfiguration<T>>::put(new);
+			Self::deposit_event(Event::SlashingConfigSet { old, new });
+			Ok(().into())
+		}
+
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::slash_candidate(
+			<T as Config>::MaxTopDelegationsPerCandidate::get()
+				+ <T as Config>::MaxBottomDelegationsPerCandidate::get()
+		))]
+		/// Slash a fraction of the self bond and the delegations of a candidate, e.g. for an
+		/// equivocation reported off-chain
+		pub fn slash_candidate(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			fraction: Perbill,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_candidate(&candidate), Error::<T>::CandidateDNE);
+			Self::slash(&candidate, fraction);
+			Ok(().into())
+		}
 	}
 	impl<T: Config> Pallet<T> {
 		pub fn is_delegator(acc: &T::AccountId) -> bool {
@@ -1582,6 +1688,7 @@ pub mod pallet {
 			let mut collators = candidates
 				.into_iter()
 				.rev()
+				.filter(|x| !Self::is_deselected(&x.owner))
 				.take(top_n)
 				.filter(|x| x.amount >= T::MinCollatorStk::get())
 				.map(|x| x.owner)
@@ -1785,9 +1892,9 @@ pub mod pallet {
 	impl<T: Config> pallet_authorship::EventHandler<T::AccountId, T::BlockNumber> for Pallet<T> {
 		fn note_author(author: T::AccountId) {
 			let now = <Round<T>>::get().current;
-			let score_plus_20 = <AwardedPts<T>>::get(now, &author).saturating_add(20);
+			let score_plus_20 = <AwardedPts<T>>::get(now, &author).saturating_add(POINTS_PER_BLOCK);
 			<AwardedPts<T>>::insert(now, author, score_plus_20);
-			<Points<T>>::mutate(now, |x| *x = x.saturating_add(20));
+			<Points<T>>::mutate(now, |x| *x = x.saturating_add(POINTS_PER_BLOCK));
 		}
 	}
 
@@ -149,6 +149,7 @@ impl Config for Test {
 	type OnNewRound = ();
 	type WeightInfo = ();
 	type IssuanceAdapter = ();
+	type OnSlash = ();
 }
 
 pub(crate) struct ExtBuilder {
@@ -0,0 +1,266 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Liveness penalties for collators
+//!
+//! At the end of each round, every selected collator that authored fewer than
+//! `SlashingConfig::min_authored` of its expected slots (the round length divided by the number
+//! of selected collators) has missed the round. `SlashingConfig::slash_fraction` of its self
+//! bond and of each of its delegations is slashed and handed to `Config::OnSlash`. The slashes
+//! are queued in `PendingSlashes` and applied one collator per block. After
+//! `SlashingConfig::max_missed_rounds` consecutive missed rounds the collator is left out of
+//! [`Pallet::compute_top_candidates`] until it goes offline and back online.
+
+use crate::{
+	pallet::{
+		AwardedPts, BalanceOf, BottomDelegations, CandidateInfo, Config,
+		DelegationScheduledRequests, DelegatorState, Event, MissedRounds, NegativeImbalanceOf,
+		Pallet, PendingSlashes, RoundIndex, SlashingConfiguration, TopDelegations, Total,
+		POINTS_PER_BLOCK,
+	},
+	DelegationAction,
+};
+use frame_support::{
+	pallet_prelude::*,
+	storage::with_storage_layer,
+	traits::{Imbalance, OnUnbalanced, ReservableCurrency},
+};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	PerThing, Perbill,
+};
+use sp_std::prelude::*;
+
+/// Liveness penalty parameters, the default disables the penalties
+#[derive(Clone, Copy, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct SlashingConfig {
+	/// Fraction of its expected slots a selected collator must author in a round
+	pub min_authored: Perbill,
+	/// Fraction of the self bond and the delegations slashed for each missed round
+	pub slash_fraction: Perbill,
+	/// Consecutive missed rounds after which the collator is not selected, 0 to never deselect
+	pub max_missed_rounds: u32,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Checks the authored slots of the collators selected for `round`, which lasted `length`
+	/// blocks, and queues the slashes of the ones which missed the round
+	pub(crate) fn handle_missed_slots(round: RoundIndex, length: u32) -> Weight {
+		let config = <SlashingConfiguration<T>>::get();
+		let mut weight = T::DbWeight::get().reads(1);
+		if config.min_authored.is_zero() {
+			return weight
+		}
+
+		let selected = Self::selected_candidates();
+		weight.saturating_accrue(T::DbWeight::get().reads(1));
+		if selected.is_empty() {
+			return weight
+		}
+		let expected = length / selected.len() as u32;
+		let required = config.min_authored.mul_ceil(expected);
+
+		for collator in selected {
+			let authored = <AwardedPts<T>>::get(round, &collator) / POINTS_PER_BLOCK;
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+			if authored >= required {
+				<MissedRounds<T>>::remove(&collator);
+				continue
+			}
+
+			let missed_rounds = <MissedRounds<T>>::mutate(&collator, |missed| {
+				*missed = missed.saturating_add(1);
+				*missed
+			});
+			Self::deposit_event(Event::CollatorMissedRound {
+				collator: collator.clone(),
+				round,
+				authored,
+				expected,
+				missed_rounds,
+			});
+			if !config.slash_fraction.is_zero() {
+				<PendingSlashes<T>>::insert(&collator, config.slash_fraction);
+			}
+			weight.saturating_accrue(T::DbWeight::get().writes(2));
+		}
+		weight
+	}
+
+	/// Applies one of the queued slashes, returns the consumed weight
+	pub(crate) fn handle_pending_slash() -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		if let Some((collator, fraction)) = <PendingSlashes<T>>::drain().next() {
+			weight.saturating_accrue(T::DbWeight::get().writes(1));
+			weight.saturating_accrue(Self::slash(&collator, fraction));
+		}
+		weight
+	}
+
+	/// Whether the collator missed too many rounds in a row to be selected
+	pub(crate) fn is_deselected(collator: &T::AccountId) -> bool {
+		let max_missed_rounds = <SlashingConfiguration<T>>::get().max_missed_rounds;
+		max_missed_rounds > 0 && <MissedRounds<T>>::get(collator) >= max_missed_rounds
+	}
+
+	/// Slashes `fraction` of the candidate self bond and of each of its delegations, the slashed
+	/// balance goes to `T::OnSlash`. The scheduled requests of the slashed delegations are cut
+	/// down so that they can still be executed, and so is the scheduled request of the candidate.
+	/// Returns the consumed weight.
+	pub(crate) fn slash(candidate: &T::AccountId, fraction: Perbill) -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		if fraction.is_zero() {
+			return weight
+		}
+		let mut state = match <CandidateInfo<T>>::get(candidate) {
+			Some(state) => state,
+			None => return weight,
+		};
+
+		let mut imbalance = NegativeImbalanceOf::<T>::zero();
+		let mut delegations =
+			<TopDelegations<T>>::get(candidate).map(|d| d.delegations).unwrap_or_default();
+		delegations.extend(
+			<BottomDelegations<T>>::get(candidate)
+				.map(|d| d.delegations)
+				.unwrap_or_default(),
+		);
+		let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(candidate);
+		let mut requests_updated = false;
+		weight.saturating_accrue(T::DbWeight::get().reads(3));
+
+		let mut delegations_slashed = BalanceOf::<T>::zero();
+		for bond in delegations {
+			let amount = fraction * bond.amount;
+			if amount.is_zero() {
+				continue
+			}
+			let mut delegator = match <DelegatorState<T>>::get(&bond.owner) {
+				Some(delegator) => delegator,
+				None => continue,
+			};
+			// the delegations are updated before any funds are moved, so that the delegation is
+			// left untouched if it fails
+			let slashed = amount.min(T::Currency::reserved_balance(&bond.owner));
+			let mut updated_state = state.clone();
+			if let Err(error) = with_storage_layer(|| {
+				updated_state.decrease_delegation::<T>(
+					candidate,
+					bond.owner.clone(),
+					bond.amount,
+					slashed,
+				)
+			}) {
+				log::warn!(
+					"STORAGE CORRUPTED \nSlashing delegation of {:?} to {:?} failed with error: {:?}",
+					bond.owner,
+					candidate,
+					error
+				);
+				continue
+			}
+			state = updated_state;
+			let (slashed_imbalance, _) = T::Currency::slash_reserved(&bond.owner, slashed);
+			imbalance.subsume(slashed_imbalance);
+			for delegation in delegator.delegations.0.iter_mut() {
+				if &delegation.owner == candidate {
+					delegation.amount = delegation.amount.saturating_sub(slashed);
+				}
+			}
+			delegator.total = delegator.total.saturating_sub(slashed);
+			if let Some(idx) = scheduled_requests.iter().position(|r| r.delegator == bond.owner) {
+				let remaining = bond.amount.saturating_sub(slashed);
+				let requested = scheduled_requests[idx].action.amount();
+				let other_requests = delegator.less_total.saturating_sub(requested);
+				let action = match scheduled_requests[idx].action {
+					// the whole remaining delegation is revoked
+					DelegationAction::Revoke(_) => DelegationAction::Revoke(remaining),
+					// the delegation and the delegator must stay above their minimums
+					DelegationAction::Decrease(amount) => DelegationAction::Decrease(
+						amount.min(remaining.saturating_sub(T::MinDelegation::get())).min(
+							delegator
+								.total
+								.saturating_sub(other_requests)
+								.saturating_sub(T::MinDelegatorStk::get()),
+						),
+					),
+				};
+				delegator.less_total = other_requests.saturating_add(action.amount());
+				if matches!(action, DelegationAction::Decrease(amount) if amount.is_zero()) {
+					let request = scheduled_requests.remove(idx);
+					Self::deposit_event(Event::CancelledDelegationRequest {
+						delegator: bond.owner.clone(),
+						cancelled_request: request.into(),
+						collator: candidate.clone(),
+					});
+				} else {
+					scheduled_requests[idx].action = action;
+				}
+				requests_updated = true;
+			}
+			<DelegatorState<T>>::insert(&bond.owner, delegator);
+			delegations_slashed = delegations_slashed.saturating_add(slashed);
+			Self::deposit_event(Event::DelegationSlashed {
+				delegator: bond.owner,
+				candidate: candidate.clone(),
+				amount: slashed,
+			});
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 4));
+		}
+
+		if requests_updated {
+			<DelegationScheduledRequests<T>>::insert(candidate, scheduled_requests);
+			weight.saturating_accrue(T::DbWeight::get().writes(1));
+		}
+
+		let (slashed_imbalance, _) = T::Currency::slash_reserved(candidate, fraction * state.bond);
+		let bond_slashed = slashed_imbalance.peek();
+		imbalance.subsume(slashed_imbalance);
+		state.bond = state.bond.saturating_sub(bond_slashed);
+		state.total_counted = state.total_counted.saturating_sub(bond_slashed);
+		// the self bond must stay above the minimum once the scheduled request is executed
+		if let Some(mut request) = state.request {
+			let max_less = state.bond.saturating_sub(T::MinCandidateStk::get());
+			if request.amount > max_less {
+				if max_less.is_zero() {
+					state.request = None;
+					Self::deposit_event(Event::CancelledCandidateBondLess {
+						candidate: candidate.clone(),
+						amount: request.amount,
+						execute_round: request.when_executable,
+					});
+				} else {
+					request.amount = max_less;
+					state.request = Some(request);
+				}
+			}
+		}
+		if state.is_active() {
+			Self::update_active(candidate.clone(), state.total_counted);
+		}
+		<CandidateInfo<T>>::insert(candidate, state);
+		<Total<T>>::mutate(|total| {
+			*total = total.saturating_sub(bond_slashed.saturating_add(delegations_slashed))
+		});
+		T::OnSlash::on_unbalanced(imbalance);
+		Self::deposit_event(Event::CandidateSlashed {
+			candidate: candidate.clone(),
+			bond_slashed,
+			delegations_slashed,
+		});
+		weight.saturating_add(T::DbWeight::get().reads_writes(4, 5))
+	}
+}
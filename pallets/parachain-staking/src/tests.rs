@@ -38,11 +38,11 @@ use crate::{
 		ExtBuilder, ParachainStaking, RuntimeEvent as MetaEvent, RuntimeOrigin, Test,
 	},
 	AtStake, Bond, CollatorStatus, DelegationScheduledRequests, DelegatorAdded, Error, Event,
-	Range,
+	Range, SlashingConfig, StakedVotingCurrency,
 };
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{LockIdentifier, LockableCurrency, WithdrawReasons},
+	traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
 };
 use sp_runtime::{traits::Zero, DispatchError, ModuleError, Perbill, Percent};
 
@@ -6721,3 +6721,404 @@ fn test_delegate_skips_auto_compound_storage_but_emits_event_for_zero_auto_compo
 			}));
 		});
 }
+
+// ~~ GOVERNANCE ~~
+
+const DEMOCRACY_ID: LockIdentifier = *b"democrac";
+
+#[test]
+fn staked_voting_currency_counts_stake_and_records_locks() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 130), (2, 110)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(StakedVotingCurrency::<Test>::free_balance(&1), 130);
+			assert_eq!(StakedVotingCurrency::<Test>::free_balance(&2), 110);
+			assert_eq!(Balances::free_balance(2), 100);
+
+			StakedVotingCurrency::<Test>::extend_lock(
+				DEMOCRACY_ID,
+				&2,
+				105,
+				WithdrawReasons::TRANSFER,
+			);
+			assert_eq!(ParachainStaking::governance_lock(2), 105);
+			StakedVotingCurrency::<Test>::extend_lock(
+				DEMOCRACY_ID,
+				&2,
+				50,
+				WithdrawReasons::TRANSFER,
+			);
+			assert_eq!(ParachainStaking::governance_lock(2), 105);
+			StakedVotingCurrency::<Test>::set_lock(DEMOCRACY_ID, &2, 50, WithdrawReasons::TRANSFER);
+			assert_eq!(ParachainStaking::governance_lock(2), 50);
+			StakedVotingCurrency::<Test>::remove_lock(DEMOCRACY_ID, &2);
+			assert_eq!(ParachainStaking::governance_lock(2), 0);
+		});
+}
+
+#[test]
+fn cannot_execute_delegation_request_with_stake_backing_votes() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 130), (2, 110)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			// 5 of the vote is backed by the delegation
+			StakedVotingCurrency::<Test>::extend_lock(
+				DEMOCRACY_ID,
+				&2,
+				105,
+				WithdrawReasons::TRANSFER,
+			);
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(2), 1));
+			roll_to(10);
+			assert_noop!(
+				ParachainStaking::execute_delegation_request(RuntimeOrigin::signed(2), 2, 1),
+				Error::<Test>::StakeLockedByVote
+			);
+
+			// the vote lock is lowered once its lock period ends
+			StakedVotingCurrency::<Test>::set_lock(
+				DEMOCRACY_ID,
+				&2,
+				100,
+				WithdrawReasons::TRANSFER,
+			);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(2),
+				2,
+				1
+			));
+			assert!(!ParachainStaking::is_delegator(&2));
+		});
+}
+
+#[test]
+fn cannot_execute_candidate_bond_less_with_stake_backing_votes() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 130)])
+		.with_candidates(vec![(1, 30)])
+		.build()
+		.execute_with(|| {
+			// 25 of the vote is backed by the candidate bond
+			StakedVotingCurrency::<Test>::extend_lock(
+				DEMOCRACY_ID,
+				&1,
+				125,
+				WithdrawReasons::TRANSFER,
+			);
+			assert_ok!(ParachainStaking::schedule_candidate_bond_less(
+				RuntimeOrigin::signed(1),
+				10
+			));
+			roll_to(10);
+			assert_noop!(
+				ParachainStaking::execute_candidate_bond_less(RuntimeOrigin::signed(1), 1),
+				Error::<Test>::StakeLockedByVote
+			);
+
+			StakedVotingCurrency::<Test>::remove_lock(DEMOCRACY_ID, &1);
+			assert_ok!(ParachainStaking::execute_candidate_bond_less(RuntimeOrigin::signed(1), 1));
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 20);
+		});
+}
+
+// SLASHING
+
+fn liveness_slashing_config(max_missed_rounds: u32) -> SlashingConfig {
+	SlashingConfig {
+		min_authored: Perbill::from_percent(50),
+		slash_fraction: Perbill::from_percent(10),
+		max_missed_rounds,
+	}
+}
+
+#[test]
+fn set_slashing_config_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let config = liveness_slashing_config(2);
+		assert_noop!(
+			ParachainStaking::set_slashing_config(RuntimeOrigin::signed(45), config),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ParachainStaking::set_slashing_config(RuntimeOrigin::root(), config));
+		assert_last_event!(MetaEvent::ParachainStaking(Event::SlashingConfigSet {
+			old: SlashingConfig::default(),
+			new: config,
+		}));
+		assert_eq!(ParachainStaking::slashing_config(), config);
+		assert_noop!(
+			ParachainStaking::set_slashing_config(RuntimeOrigin::root(), config),
+			Error::<Test>::NoWritingSameValue
+		);
+	});
+}
+
+#[test]
+fn collator_missing_round_is_slashed_with_its_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 30), (2, 20)])
+		.with_delegations(vec![(3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_slashing_config(
+				RuntimeOrigin::root(),
+				liveness_slashing_config(0)
+			));
+			// 2 authored its single expected slot, 1 authored none
+			set_author(1, 2, 20);
+			roll_to_round_begin(2);
+
+			assert_event_emitted!(Event::CollatorMissedRound {
+				collator: 1,
+				round: 1,
+				authored: 0,
+				expected: 2,
+				missed_rounds: 1,
+			});
+			assert_event_emitted!(Event::DelegationSlashed {
+				delegator: 3,
+				candidate: 1,
+				amount: 1
+			});
+			assert_event_emitted!(Event::CandidateSlashed {
+				candidate: 1,
+				bond_slashed: 3,
+				delegations_slashed: 1,
+			});
+			let candidate = ParachainStaking::candidate_info(1).unwrap();
+			assert_eq!(candidate.bond, 27);
+			assert_eq!(candidate.total_counted, 36);
+			assert_eq!(ParachainStaking::delegator_state(3).unwrap().total, 9);
+			assert_eq!(Balances::total_balance(&1), 97);
+			assert_eq!(Balances::total_balance(&3), 99);
+			assert_eq!(ParachainStaking::total(), 56);
+			assert_eq!(ParachainStaking::missed_rounds(1), 1);
+
+			// 2 did not miss the round
+			assert_eq!(ParachainStaking::candidate_info(2).unwrap().bond, 20);
+			assert_eq!(ParachainStaking::missed_rounds(2), 0);
+		});
+}
+
+#[test]
+fn collator_missing_too_many_rounds_is_deselected_until_back_online() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 30), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_slashing_config(
+				RuntimeOrigin::root(),
+				liveness_slashing_config(1)
+			));
+			set_author(1, 2, 20);
+			roll_to_round_begin(2);
+			assert_eq!(ParachainStaking::selected_candidates(), vec![2]);
+
+			assert_ok!(ParachainStaking::go_offline(RuntimeOrigin::signed(1)));
+			assert_ok!(ParachainStaking::go_online(RuntimeOrigin::signed(1)));
+			assert_eq!(ParachainStaking::missed_rounds(1), 0);
+
+			set_author(2, 2, 20);
+			roll_to_round_begin(3);
+			assert_eq!(ParachainStaking::selected_candidates(), vec![1, 2]);
+		});
+}
+
+#[test]
+fn slash_candidate_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::slash_candidate(
+					RuntimeOrigin::signed(2),
+					1,
+					Perbill::from_percent(50)
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				ParachainStaking::slash_candidate(
+					RuntimeOrigin::root(),
+					2,
+					Perbill::from_percent(50)
+				),
+				Error::<Test>::CandidateDNE
+			);
+			assert_ok!(ParachainStaking::slash_candidate(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(50)
+			));
+			assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateSlashed {
+				candidate: 1,
+				bond_slashed: 10,
+				delegations_slashed: 5,
+			}));
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().total_counted, 15);
+			assert_eq!(ParachainStaking::delegator_state(2).unwrap().total, 5);
+		});
+}
+
+#[test]
+fn slashes_of_collators_missing_round_are_spread_over_blocks() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 30), (2, 20), (3, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_slashing_config(
+				RuntimeOrigin::root(),
+				liveness_slashing_config(0)
+			));
+			// 3 authored its single expected slot, 1 and 2 authored none
+			set_author(1, 3, 20);
+			roll_to_round_begin(2);
+
+			// one of the collators is slashed at the round change, the other one is pending
+			assert_eq!(
+				ParachainStaking::pending_slash(1).is_some() as u32 +
+					ParachainStaking::pending_slash(2).is_some() as u32,
+				1
+			);
+			roll_one_block();
+			assert_eq!(ParachainStaking::pending_slash(1), None);
+			assert_eq!(ParachainStaking::pending_slash(2), None);
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 27);
+			assert_eq!(ParachainStaking::candidate_info(2).unwrap().bond, 18);
+			assert_eq!(ParachainStaking::candidate_info(3).unwrap().bond, 20);
+		});
+}
+
+#[test]
+fn slash_cuts_down_scheduled_delegation_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10), (3, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(2), 1));
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(3),
+				1,
+				15
+			));
+			assert_ok!(ParachainStaking::slash_candidate(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(50)
+			));
+
+			// the revoke covers the remaining delegation
+			let requests = ParachainStaking::delegation_scheduled_requests(1);
+			assert_eq!(requests[0].action, DelegationAction::Revoke(5));
+			assert_eq!(ParachainStaking::delegator_state(2).unwrap().less_total, 5);
+			// the decrease keeps 3 above the minimum delegator stake
+			assert_eq!(requests[1].action, DelegationAction::Decrease(5));
+			assert_eq!(ParachainStaking::delegator_state(3).unwrap().less_total, 5);
+
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(2),
+				2,
+				1
+			));
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(3),
+				3,
+				1
+			));
+			assert!(ParachainStaking::delegator_state(2).is_none());
+			assert_eq!(Balances::reserved_balance(&2), 0);
+			assert_eq!(ParachainStaking::delegator_state(3).unwrap().total, 5);
+			assert_eq!(Balances::reserved_balance(&3), 5);
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().total_counted, 15);
+		});
+}
+
+#[test]
+fn slash_cancels_scheduled_decrease_which_can_no_longer_be_executed() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				10
+			));
+			assert_ok!(ParachainStaking::slash_candidate(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(80)
+			));
+			assert_event_emitted!(Event::CancelledDelegationRequest {
+				delegator: 2,
+				cancelled_request: CancelledScheduledRequest {
+					when_executable: 3,
+					action: DelegationAction::Decrease(10),
+				},
+				collator: 1,
+			});
+			assert!(ParachainStaking::delegation_scheduled_requests(1).is_empty());
+			let delegator = ParachainStaking::delegator_state(2).unwrap();
+			assert_eq!(delegator.total, 4);
+			assert_eq!(delegator.less_total, 0);
+		});
+}
+
+#[test]
+fn slash_cuts_down_scheduled_candidate_bond_less() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 30), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_candidate_bond_less(
+				RuntimeOrigin::signed(1),
+				15
+			));
+			assert_ok!(ParachainStaking::schedule_candidate_bond_less(
+				RuntimeOrigin::signed(2),
+				10
+			));
+			assert_ok!(ParachainStaking::slash_candidate(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(50)
+			));
+			assert_ok!(ParachainStaking::slash_candidate(
+				RuntimeOrigin::root(),
+				2,
+				Perbill::from_percent(50)
+			));
+
+			// the decrease keeps 1 above the minimum candidate stake
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().request.unwrap().amount, 5);
+			// 2 is at the minimum candidate stake
+			assert_event_emitted!(Event::CancelledCandidateBondLess {
+				candidate: 2,
+				amount: 10,
+				execute_round: 3,
+			});
+			assert!(ParachainStaking::candidate_info(2).unwrap().request.is_none());
+
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::execute_candidate_bond_less(RuntimeOrigin::signed(1), 1));
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().bond, 10);
+			assert_eq!(Balances::reserved_balance(&1), 10);
+		});
+}
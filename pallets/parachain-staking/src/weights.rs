@@ -81,6 +81,8 @@ pub trait WeightInfo {
 	fn base_on_initialize() -> Weight;
 	fn set_auto_compound(x: u32, y: u32, ) -> Weight;
 	fn delegate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight;
+	fn set_slashing_config() -> Weight;
+	fn slash_candidate(x: u32, ) -> Weight;
 }
 
 /// Weights for pallet_parachain_staking using the Litentry node and recommended hardware.
@@ -426,6 +428,26 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI: the `set_slashing_config` benchmark has not
+	// been run yet. The call reads and writes `SlashingConfiguration` once.
+	fn set_slashing_config() -> Weight {
+		Weight::from_parts(18_000_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Estimate, not generated by the benchmark CLI: the `slash_candidate` benchmark has not been
+	// run yet. The figures are hand-picked upper bounds for reading and writing the candidate,
+	// its delegations, the pool and the total once, plus one `DelegatorState` update and one
+	// reserve slash for each of the `x` delegations. Replace them with the CLI output once the
+	// benchmark has been run.
+	fn slash_candidate(x: u32, ) -> Weight {
+		Weight::from_parts(60_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(40_000_000 as u64, 0).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(x as u64)))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(x as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -770,5 +792,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI: the `set_slashing_config` benchmark has not
+	// been run yet. The call reads and writes `SlashingConfiguration` once.
+	fn set_slashing_config() -> Weight {
+		Weight::from_parts(18_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Estimate, not generated by the benchmark CLI: the `slash_candidate` benchmark has not been
+	// run yet. The figures are hand-picked upper bounds for reading and writing the candidate,
+	// its delegations, the pool and the total once, plus one `DelegatorState` update and one
+	// reserve slash for each of the `x` delegations. Replace them with the CLI output once the
+	// benchmark has been run.
+	fn slash_candidate(x: u32, ) -> Weight {
+		Weight::from_parts(60_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(40_000_000 as u64, 0).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(x as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(x as u64)))
+	}
 }
 
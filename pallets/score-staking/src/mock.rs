@@ -163,6 +163,7 @@ impl pallet_parachain_staking::Config for Test {
 	type OnNewRound = ();
 	type WeightInfo = ();
 	type IssuanceAdapter = ();
+	type OnSlash = ();
 }
 
 parameter_types! {
@@ -48,6 +48,10 @@ const TCB_SIGNATURE: [u8; 64] = hex!("71746f2148ecba04e35cf1ac77a7e6267ce99f6781
 const TCB_CERTIFICATE_CHAIN: &[u8; 1891] =
 	include_bytes!("./sgx_verify/test/dcap/tcb_info_issuer_chain.pem");
 
+const ROOT_CA_CRL: &[u8] = include_bytes!("./sgx_verify/test/dcap/root_ca_crl.der");
+const ROOT_CA_CRL_CERTIFICATE_CHAIN: &[u8] =
+	include_bytes!("./sgx_verify/test/dcap/root_ca_crl_issuer_chain.pem");
+
 fn register_quoting_enclave_for_testing<T: Config>()
 where
 	<T as frame_system::Config>::Hash: From<[u8; 32]>,
@@ -377,6 +381,31 @@ mod benchmarks {
 		assert_eq!(tcb_info.next_update, 1681649132000);
 	}
 
+	// registers the CRL of the Intel SGX root CA, which replaces `s` previously revoked serials
+	#[benchmark]
+	fn register_pck_crl(s: Linear<0, MAX_PCK_CRL_SERIALS>) {
+		let valid_timestamp: u64 = 1671606747000;
+		assert_ok!(pallet_timestamp::Pallet::<T>::set(
+			RawOrigin::None.into(),
+			T::Moment::saturated_from(valid_timestamp)
+		));
+		let signer: T::AccountId = account("who", 1, 1);
+		let crl = hex::decode(ROOT_CA_CRL).unwrap();
+		let issuer =
+			Teebag::<T>::verify_pck_crl(crl.clone(), ROOT_CA_CRL_CERTIFICATE_CHAIN.to_vec())
+				.unwrap()
+				.issuer;
+		for i in 0..s {
+			RevokedPckCertificate::<T>::insert(issuer, i.to_be_bytes().to_vec(), ());
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(signer), crl, ROOT_CA_CRL_CERTIFICATE_CHAIN.to_vec());
+
+		assert_eq!(RevokedPckCertificate::<T>::iter_prefix(issuer).count(), 0);
+		assert_eq!(Teebag::<T>::pck_crl_this_update(issuer), Some(1650357078000));
+	}
+
 	#[benchmark]
 	fn post_opaque_task() {
 		let who: T::AccountId = account("who", 1, 1);
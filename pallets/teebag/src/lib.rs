@@ -32,9 +32,9 @@ use sp_std::{prelude::*, str};
 
 mod sgx_verify;
 pub use sgx_verify::{
-	deserialize_enclave_identity, deserialize_tcb_info, extract_certs,
+	deserialize_enclave_identity, deserialize_tcb_info, extract_certs, extract_pck_cert_id,
 	extract_tcb_info_from_raw_dcap_quote, verify_certificate_chain, verify_dcap_quote,
-	verify_ias_report, SgxReport,
+	verify_ias_report, verify_pck_crl, PckCertId, PckCrl, SgxReport,
 };
 
 pub use pallet::*;
@@ -54,6 +54,8 @@ pub use tcb::*;
 const MAX_RA_REPORT_LEN: usize = 5244;
 const MAX_DCAP_QUOTE_LEN: usize = 5000;
 const MAX_URL_LEN: usize = 256;
+pub const MAX_COLLATERAL_BUNDLE_LEN: usize = 32;
+pub const MAX_PCK_CRL_SERIALS: u32 = 1024;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -119,6 +121,18 @@ pub mod pallet {
 			who: T::AccountId,
 			sidechain_block_number: SidechainBlockNumber,
 		},
+		ReattestationPeriodSet {
+			period: Option<u64>,
+		},
+		EnclaveEvicted {
+			who: T::AccountId,
+			reason: EvictionReason,
+		},
+		PckCrlRegistered {
+			issuer: PckCaId,
+			this_update: u64,
+			revoked: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -173,6 +187,14 @@ pub mod pallet {
 		ReceivedUnexpectedSidechainBlock,
 		/// The value for the next finalization candidate is invalid.
 		InvalidNextFinalizationCandidateBlockNumber,
+		/// The PCK CRL is not newer than the registered one.
+		PckCrlOutdated,
+		/// The PCK certificate that signed the quote is revoked.
+		PckCertificateRevoked,
+		/// The collateral bundle has too many entries.
+		CollateralBundleTooLong,
+		/// The PCK CRL revokes more certificates than MAX_PCK_CRL_SERIALS.
+		PckCrlTooLong,
 	}
 
 	#[pallet::storage]
@@ -219,6 +241,38 @@ pub mod pallet {
 	pub type TcbInfo<T: Config> =
 		StorageMap<_, Blake2_128Concat, Fmspc, TcbInfoOnChain, ValueQuery>;
 
+	// `this_update` of the latest registered CRL of each PCK CA, used to reject older CRLs
+	#[pallet::storage]
+	#[pallet::getter(fn pck_crl_this_update)]
+	pub type PckCrlThisUpdate<T: Config> =
+		StorageMap<_, Blake2_128Concat, PckCaId, u64, OptionQuery>;
+
+	// serial numbers revoked by the latest registered CRL of each PCK CA
+	#[pallet::storage]
+	#[pallet::getter(fn revoked_pck_certificate)]
+	pub type RevokedPckCertificate<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PckCaId, Blake2_128Concat, Vec<u8>, (), OptionQuery>;
+
+	// enclaves have to re-attest within this period (in milliseconds), otherwise they are evicted
+	// in `on_idle`. `None` disables the expiry, the TCB and PCK revocation checks still apply.
+	#[pallet::storage]
+	#[pallet::getter(fn reattestation_period)]
+	pub type ReattestationPeriod<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	// attestation details of each enclave, written upon `register_enclave`
+	//
+	// enclaves without an entry (added by `force_add_enclave` or registered before this storage
+	// existed) are never evicted, until they register again.
+	#[pallet::storage]
+	#[pallet::getter(fn enclave_attestation)]
+	pub type EnclaveAttestationRegistry<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, EnclaveAttestation, OptionQuery>;
+
+	// raw `EnclaveRegistry` key of the last enclave checked by the eviction sweep,
+	// the next sweep continues after it
+	#[pallet::storage]
+	pub type EvictionCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	// keep track of a list of scheduled/allowed enchalves, mainly used for enclave updates,
 	// can only be modified by AdminOrigin
 	// (worker_type, sidechain_block_number) -> expected MrEnclave
@@ -298,6 +352,13 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::evict_stale_enclaves(remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T>
 	where
@@ -476,6 +537,7 @@ pub mod pallet {
 				.with_shielding_pubkey(shielding_pubkey)
 				.with_vc_pubkey(vc_pubkey)
 				.with_attestation_type(attestation_type);
+			let mut enclave_attestation = EnclaveAttestation::default();
 
 			match attestation_type {
 				AttestationType::Ignore => {
@@ -496,12 +558,15 @@ pub mod pallet {
 				},
 				AttestationType::Dcap(provider) => {
 					ensure!(provider == DcapProvider::Intel, Error::<T>::DcapProviderNotSupported);
-					let report = Self::verify_dcap(&sender, attestation)?;
+					let (report, tcb, pck_cert) = Self::verify_dcap(&sender, attestation)?;
 					enclave.mrenclave = report.mr_enclave;
 					enclave.last_seen_timestamp = report.timestamp;
 					enclave.sgx_build_mode = report.build_mode;
+					enclave_attestation.tcb = Some(tcb);
+					enclave_attestation.pck_cert = Some(pck_cert);
 				},
 			};
+			enclave_attestation.attested_at = enclave.last_seen_timestamp;
 
 			match Self::mode() {
 				OperationalMode::Production | OperationalMode::Maintenance => {
@@ -527,6 +592,7 @@ pub mod pallet {
 				},
 			};
 			Self::add_enclave(&sender, &enclave)?;
+			EnclaveAttestationRegistry::<T>::insert(&sender, enclave_attestation);
 			Ok(Pays::No.into())
 		}
 
@@ -570,6 +636,96 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 
+		// unlike the other collaterals the sender pays for the registration, as every CRL of
+		// the same CA rewrites its revoked serials
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_pck_crl(MAX_PCK_CRL_SERIALS))]
+		pub fn register_pck_crl(
+			origin: OriginFor<T>,
+			crl: Vec<u8>,
+			certificate_chain: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// PCK CRLs are registered globally and not for a specific sender
+			let _ = ensure_signed(origin)?;
+			let pck_crl = Self::verify_pck_crl(crl, certificate_chain)?;
+			let revoked: u32 = pck_crl
+				.revoked_serials
+				.len()
+				.try_into()
+				.ok()
+				.filter(|len| *len <= MAX_PCK_CRL_SERIALS)
+				.ok_or(Error::<T>::PckCrlTooLong)?;
+			ensure!(
+				Self::pck_crl_this_update(pck_crl.issuer).map_or(true, |t| t < pck_crl.this_update),
+				Error::<T>::PckCrlOutdated
+			);
+			// a CRL lists all revoked certificates of its CA, so it replaces the previous one,
+			// which is bounded by MAX_PCK_CRL_SERIALS as well
+			let removed =
+				RevokedPckCertificate::<T>::clear_prefix(pck_crl.issuer, MAX_PCK_CRL_SERIALS, None)
+					.unique;
+			for serial in pck_crl.revoked_serials {
+				RevokedPckCertificate::<T>::insert(pck_crl.issuer, serial, ());
+			}
+			PckCrlThisUpdate::<T>::insert(pck_crl.issuer, pck_crl.this_update);
+			Self::deposit_event(Event::PckCrlRegistered {
+				issuer: pck_crl.issuer,
+				this_update: pck_crl.this_update,
+				revoked,
+			});
+			Ok(Some(<T as Config>::WeightInfo::register_pck_crl(removed.max(revoked))).into())
+		}
+
+		/// Set the period (in milliseconds) within which enclaves have to re-attest,
+		/// `None` disables the expiry
+		#[pallet::call_index(13)]
+		#[pallet::weight((2 * T::DbWeight::get().write, DispatchClass::Normal))]
+		pub fn set_reattestation_period(
+			origin: OriginFor<T>,
+			period: Option<u64>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_admin_or_root(origin)?;
+			ReattestationPeriod::<T>::set(period);
+			Self::deposit_event(Event::ReattestationPeriodSet { period });
+			Ok(Pays::No.into())
+		}
+
+		/// Register the quoting enclave and any number of TCB infos at once, the whole bundle
+		/// is rejected if one of them fails to verify
+		#[pallet::call_index(14)]
+		#[pallet::weight({
+			let tcb_infos = <T as Config>::WeightInfo::register_tcb_info()
+				.saturating_mul(bundle.tcb_infos.len() as u64);
+			if bundle.quoting_enclave.is_some() {
+				tcb_infos.saturating_add(<T as Config>::WeightInfo::register_quoting_enclave())
+			} else {
+				tcb_infos
+			}
+		})]
+		pub fn register_collateral_bundle(
+			origin: OriginFor<T>,
+			bundle: CollateralBundle,
+		) -> DispatchResultWithPostInfo {
+			// collateral is registered globally and not for a specific sender
+			let _ = ensure_signed(origin)?;
+			ensure!(bundle.len() <= MAX_COLLATERAL_BUNDLE_LEN, Error::<T>::CollateralBundleTooLong);
+
+			if let Some(qe) = bundle.quoting_enclave {
+				let quoting_enclave =
+					Self::verify_quoting_enclave(qe.data, qe.signature, qe.certificate_chain)?;
+				<QuotingEnclaveRegistry<T>>::put(quoting_enclave);
+			}
+			for tcb_info in bundle.tcb_infos {
+				let (fmspc, on_chain_info) = Self::verify_tcb_info(
+					tcb_info.data,
+					tcb_info.signature,
+					tcb_info.certificate_chain,
+				)?;
+				TcbInfo::<T>::insert(fmspc, on_chain_info);
+			}
+			Ok(Pays::No.into())
+		}
+
 		// ===============================================================================
 		// Following extrinsics are for runtime communication between parachain and worker
 		// ===============================================================================
@@ -714,6 +870,7 @@ impl<T: Config> Pallet<T> {
 		})?;
 
 		EnclaveRegistry::<T>::remove(sender);
+		EnclaveAttestationRegistry::<T>::remove(sender);
 		Self::deposit_event(Event::<T>::EnclaveRemoved { who: sender.clone() });
 		Ok(().into())
 	}
@@ -742,15 +899,19 @@ impl<T: Config> Pallet<T> {
 	fn verify_dcap(
 		sender: &T::AccountId,
 		dcap_quote: Vec<u8>,
-	) -> Result<SgxReport, DispatchErrorWithPostInfo> {
+	) -> Result<(SgxReport, (Fmspc, TcbVersionStatus), PckCertId), DispatchErrorWithPostInfo> {
 		ensure!(dcap_quote.len() <= MAX_DCAP_QUOTE_LEN, Error::<T>::AttestationTooLong);
 		let timestamp = Self::now();
 		let qe = <QuotingEnclaveRegistry<T>>::get();
-		let (fmspc, tcb_info, report) =
+		let (fmspc, tcb_info, pck_cert, report) =
 			verify_dcap_quote(&dcap_quote, timestamp.saturated_into(), &qe).map_err(|e| {
 				log::warn!("verify_dcap_quote failed: {:?}", e);
 				Error::<T>::RemoteAttestationVerificationFailed
 			})?;
+		ensure!(
+			!RevokedPckCertificate::<T>::contains_key(pck_cert.issuer, &pck_cert.serial),
+			Error::<T>::PckCertificateRevoked
+		);
 
 		let tcb_info_on_chain = <TcbInfo<T>>::get(fmspc);
 		ensure!(tcb_info_on_chain.verify_examinee(&tcb_info), "tcb_info is outdated");
@@ -759,7 +920,7 @@ impl<T: Config> Pallet<T> {
 			.map_err(|_| Error::<T>::EnclaveSignerDecodeError)?;
 		ensure!(sender == &enclave_signer, Error::<T>::SenderIsNotAttestedEnclave);
 
-		Ok(report)
+		Ok((report, (fmspc, tcb_info), pck_cert))
 	}
 
 	fn verify_quoting_enclave(
@@ -808,6 +969,14 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	pub fn verify_pck_crl(
+		crl: Vec<u8>,
+		certificate_chain: Vec<u8>,
+	) -> Result<PckCrl, DispatchErrorWithPostInfo> {
+		let verification_time: u64 = Self::now().saturated_into();
+		Ok(verify_pck_crl(&crl, &certificate_chain, verification_time)?)
+	}
+
 	fn ensure_timestamp_within_24_hours(report_timestamp: u64) -> DispatchResultWithPostInfo {
 		let elapsed_time = Self::now()
 			.checked_sub(&T::Moment::saturated_from(report_timestamp))
@@ -820,6 +989,80 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	// checks the enclaves one by one while the weight allows it, resuming where the previous
+	// sweep stopped, so every enclave is eventually checked regardless of the registry size
+	fn evict_stale_enclaves(remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		// period and cursor
+		let mut used_weight = db_weight.reads_writes(2, 1);
+		// enclave key, attestation, TCB info and PCK revocation
+		let check_weight = db_weight.reads(4);
+		// enclave, identifier and attestation
+		let evict_weight = db_weight.reads_writes(1, 3);
+
+		let step_weight = check_weight.saturating_add(evict_weight);
+		if remaining_weight.any_lt(used_weight.saturating_add(step_weight)) {
+			return Weight::zero()
+		}
+
+		let now: u64 = Self::now().saturated_into();
+		let period = Self::reattestation_period();
+		let mut keys = match EvictionCursor::<T>::get() {
+			Some(cursor) => EnclaveRegistry::<T>::iter_keys_from(cursor),
+			None => EnclaveRegistry::<T>::iter_keys(),
+		};
+
+		let mut cursor = None;
+		while remaining_weight.all_gte(used_weight.saturating_add(step_weight)) {
+			let who = match keys.next() {
+				Some(who) => who,
+				None => {
+					// the whole registry is checked, start over in the next sweep
+					cursor = None;
+					break
+				},
+			};
+			used_weight.saturating_accrue(check_weight);
+			if let Some(reason) = Self::eviction_reason(&who, now, period) {
+				used_weight.saturating_accrue(evict_weight);
+				match Self::remove_enclave(&who) {
+					Ok(_) =>
+						Self::deposit_event(Event::EnclaveEvicted { who: who.clone(), reason }),
+					Err(e) => log::warn!("Failed to evict enclave {:?}: {:?}", who, e),
+				}
+			}
+			cursor = Some(EnclaveRegistry::<T>::hashed_key_for(&who));
+		}
+
+		EvictionCursor::<T>::set(cursor);
+		used_weight
+	}
+
+	fn eviction_reason(
+		who: &T::AccountId,
+		now: u64,
+		period: Option<u64>,
+	) -> Option<EvictionReason> {
+		let attestation = EnclaveAttestationRegistry::<T>::get(who)?;
+
+		if let Some(period) = period {
+			if now.saturating_sub(attestation.attested_at) > period {
+				return Some(EvictionReason::AttestationExpired)
+			}
+		}
+		if let Some((fmspc, tcb)) = attestation.tcb {
+			if !TcbInfo::<T>::get(fmspc).verify_examinee(&tcb) {
+				return Some(EvictionReason::TcbOutOfDate)
+			}
+		}
+		if let Some(pck_cert) = attestation.pck_cert {
+			if RevokedPckCertificate::<T>::contains_key(pck_cert.issuer, &pck_cert.serial) {
+				return Some(EvictionReason::PckCertificateRevoked)
+			}
+		}
+		None
+	}
+
 	fn finalize_block(
 		sender: T::AccountId,
 		shard: ShardIdentifier,
@@ -36,7 +36,8 @@ use self::{
 	utils::length_from_raw_data,
 };
 use crate::{
-	Cpusvn, Fmspc, MrEnclave, MrSigner, Pcesvn, QuotingEnclave, SgxBuildMode, TcbVersionStatus,
+	Cpusvn, Fmspc, MrEnclave, MrSigner, Pcesvn, PckCaId, QuotingEnclave, SgxBuildMode,
+	TcbVersionStatus,
 };
 use alloc::string::String;
 use chrono::DateTime;
@@ -512,6 +513,190 @@ pub fn verify_certificate_chain<'a>(
 		.map_err(|_| "Invalid certificate chain")?;
 	Ok(())
 }
+
+/// Identifies a PCK certificate. Serial numbers are only unique per issuing CA, so the CA is
+/// part of the identifier.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct PckCertId {
+	pub issuer: PckCaId,
+	pub serial: Vec<u8>,
+}
+
+/// A PCK certificate revocation list whose signature was verified.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PckCrl {
+	pub issuer: PckCaId,
+	/// unix timestamp in milliseconds
+	pub this_update: u64,
+	pub revoked_serials: Vec<Vec<u8>>,
+}
+
+/// Verifies that the PCK CRL was signed by the first certificate of `certificate_chain` and that
+/// the chain is rooted in the Intel SGX root CA, see `PCK_Certificate_CRL_Spec-1.1` chapter 4.
+/// Returns the revoked serial numbers.
+pub fn verify_pck_crl(
+	crl_der: &[u8],
+	certificate_chain: &[u8],
+	verification_time: u64,
+) -> Result<PckCrl, &'static str> {
+	let certs = extract_certs(certificate_chain);
+	verify_ca_certificate_chain(&certs, verification_time)?;
+
+	let crl: x509_cert::crl::CertificateList =
+		der::Decode::from_der(crl_der).map_err(|_| "Error parsing CRL")?;
+	let issuer: Certificate =
+		der::Decode::from_der(&certs[0]).map_err(|_| "Error parsing certificate")?;
+	let issuer_id = pck_ca_id(&crl.tbs_cert_list.issuer)?;
+	ensure!(
+		issuer_id == pck_ca_id(&issuer.tbs_certificate.subject)?,
+		"CRL must be issued by the first certificate of the chain"
+	);
+	ensure_crl_signer(&issuer)?;
+
+	let (tbs_cert_list, signature) = split_signed_der(crl_der)?;
+	let issuer_cert_der = webpki::types::CertificateDer::from(certs[0].as_slice());
+	let issuer_cert = webpki::EndEntityCert::try_from(&issuer_cert_der)
+		.map_err(|_| "Failed to parse CRL issuer certificate")?;
+	verify_signature(&issuer_cert, tbs_cert_list, signature, webpki::ring::ECDSA_P256_SHA256)?;
+
+	let this_update: u64 = crl
+		.tbs_cert_list
+		.this_update
+		.to_unix_duration()
+		.as_millis()
+		.try_into()
+		.map_err(|_| "Invalid CRL update time")?;
+	ensure!(this_update <= verification_time, "CRL is not valid yet");
+
+	let revoked_serials = crl
+		.tbs_cert_list
+		.revoked_certificates
+		.unwrap_or_default()
+		.iter()
+		.map(|c| c.serial_number.as_bytes().to_vec())
+		.collect();
+
+	Ok(PckCrl { issuer: issuer_id, this_update, revoked_serials })
+}
+
+/// Checks that the certificate belongs to a CA that may sign CRLs, i.e. that the basic
+/// constraints mark it as a CA and that its key usage includes `cRLSign`, see RFC 5280 4.2.1.
+fn ensure_crl_signer(cert: &Certificate) -> Result<(), &'static str> {
+	let extension = |oid: ObjectIdentifier| {
+		cert.tbs_certificate
+			.extensions
+			.as_deref()
+			.unwrap_or(&[])
+			.iter()
+			.find(|e| e.extn_id == oid)
+			.map(|e| e.extn_value)
+	};
+
+	// BasicConstraints ::= SEQUENCE { cA BOOLEAN DEFAULT FALSE, pathLen INTEGER OPTIONAL },
+	// so a non-CA certificate usually has an empty sequence
+	let basic_constraints: der::asn1::AnyRef = der::Decode::from_der(
+		extension(OID_BASIC_CONSTRAINTS).ok_or("CRL issuer must be a CA certificate")?,
+	)
+	.map_err(|_| "Invalid basic constraints")?;
+	let mut reader = der::SliceReader::new(basic_constraints.value())
+		.map_err(|_| "Invalid basic constraints")?;
+	let is_ca: bool = der::Reader::decode(&mut reader).unwrap_or(false);
+	ensure!(is_ca, "CRL issuer must be a CA certificate");
+
+	let key_usage: der::asn1::BitStringRef = der::Decode::from_der(
+		extension(OID_KEY_USAGE).ok_or("CRL issuer must be allowed to sign CRLs")?,
+	)
+	.map_err(|_| "Invalid key usage")?;
+	ensure!(
+		key_usage.raw_bytes().first().map_or(false, |b| b & KEY_USAGE_CRL_SIGN != 0),
+		"CRL issuer must be allowed to sign CRLs"
+	);
+	Ok(())
+}
+
+/// Verifies that each certificate is valid and signed by the next one, and that the last one is
+/// the Intel SGX root CA. Unlike `verify_certificate_chain` this accepts a CA certificate as
+/// the first certificate, which `webpki` refuses to treat as an end entity. A chain consisting of
+/// the root CA alone is valid, it signs the CRL of the intermediate CAs.
+fn verify_ca_certificate_chain(
+	certs: &[Vec<u8>],
+	verification_time: u64,
+) -> Result<(), &'static str> {
+	ensure!(!certs.is_empty(), "Certificate chain must not be empty");
+	let time = Duration::from_millis(verification_time);
+	for (i, cert_der) in certs.iter().enumerate() {
+		let cert: Certificate =
+			der::Decode::from_der(cert_der).map_err(|_| "Error parsing certificate")?;
+		let validity = cert.tbs_certificate.validity;
+		ensure!(
+			validity.not_before.to_unix_duration() <= time &&
+				time <= validity.not_after.to_unix_duration(),
+			"Certificate is not valid at verification time"
+		);
+
+		match certs.get(i + 1) {
+			Some(issuer_der) => {
+				let (tbs_certificate, signature) = split_signed_der(cert_der)?;
+				let issuer_der = webpki::types::CertificateDer::from(issuer_der.as_slice());
+				let issuer = webpki::EndEntityCert::try_from(&issuer_der)
+					.map_err(|_| "Failed to parse issuer certificate")?;
+				verify_signature(
+					&issuer,
+					tbs_certificate,
+					signature,
+					webpki::ring::ECDSA_P256_SHA256,
+				)?;
+			},
+			None => {
+				let spki = der::Encode::to_vec(&cert.tbs_certificate.subject_public_key_info)
+					.map_err(|_| "Error encoding public key")?;
+				let spki: der::asn1::AnyRef =
+					der::Decode::from_der(&spki).map_err(|_| "Error parsing public key")?;
+				ensure!(
+					DCAP_SERVER_ROOTS
+						.iter()
+						.any(|root| root.subject_public_key_info.as_ref() == spki.value()),
+					"Certificate chain must end with the Intel SGX root CA"
+				);
+			},
+		}
+	}
+	Ok(())
+}
+
+/// Splits a signed DER structure, i.e. a certificate or a CRL, into the raw bytes that were signed
+/// and the DER encoded signature.
+fn split_signed_der(der_encoded: &[u8]) -> Result<(&[u8], &[u8]), &'static str> {
+	let signed: der::asn1::AnyRef =
+		der::Decode::from_der(der_encoded).map_err(|_| "Invalid DER structure")?;
+	let content = signed.value();
+	let mut reader = der::SliceReader::new(content).map_err(|_| "Invalid DER structure")?;
+	let tbs: der::asn1::AnyRef =
+		der::Reader::decode(&mut reader).map_err(|_| "Invalid DER structure")?;
+	let _algorithm: der::asn1::AnyRef =
+		der::Reader::decode(&mut reader).map_err(|_| "Invalid DER structure")?;
+	let signature: der::asn1::BitStringRef =
+		der::Reader::decode(&mut reader).map_err(|_| "Invalid DER structure")?;
+	let tbs_len: usize = der::Encode::encoded_len(&tbs)
+		.and_then(|l| l.try_into())
+		.map_err(|_| "Invalid DER structure")?;
+	Ok((&content[..tbs_len], signature.raw_bytes()))
+}
+
+fn pck_ca_id(name: &x509_cert::name::Name) -> Result<PckCaId, &'static str> {
+	let encoded = der::Encode::to_vec(name).map_err(|_| "Error encoding name")?;
+	let mut id = PckCaId::default();
+	id.copy_from_slice(ring::digest::digest(&ring::digest::SHA256, &encoded).as_ref());
+	Ok(id)
+}
+
+pub fn extract_pck_cert_id(cert: &[u8]) -> Result<PckCertId, &'static str> {
+	let cert: Certificate = der::Decode::from_der(cert).map_err(|_| "Error parsing certificate")?;
+	Ok(PckCertId {
+		issuer: pck_ca_id(&cert.tbs_certificate.issuer)?,
+		serial: cert.tbs_certificate.serial_number.as_bytes().to_vec(),
+	})
+}
 #[allow(unused)]
 pub fn extract_tcb_info_from_raw_dcap_quote(
 	dcap_quote_raw: &[u8],
@@ -538,7 +723,7 @@ pub fn verify_dcap_quote(
 	dcap_quote_raw: &[u8],
 	verification_time: u64,
 	qe: &QuotingEnclave,
-) -> Result<(Fmspc, TcbVersionStatus, SgxReport), &'static str> {
+) -> Result<(Fmspc, TcbVersionStatus, PckCertId, SgxReport), &'static str> {
 	let mut dcap_quote_clone = dcap_quote_raw;
 	let quote: DcapQuote =
 		Decode::decode(&mut dcap_quote_clone).map_err(|_| "Failed to decode attestation report")?;
@@ -563,6 +748,7 @@ pub fn verify_dcap_quote(
 	verify_certificate_chain(&leaf_cert, &intermediate_certificate_slices, verification_time)?;
 
 	let (fmspc, tcb_info) = extract_tcb_info(&certs[0])?;
+	let pck_cert = extract_pck_cert_id(&certs[0])?;
 
 	// For this part some understanding of the document (Especially chapter A.4: Quote Format)
 	// Intel® Software Guard Extensions (Intel® SGX) Data Center Attestation Primitives: ECDSA Quote
@@ -631,7 +817,7 @@ pub fn verify_dcap_quote(
 		timestamp: verification_time,
 		build_mode: quote.body.sgx_build_mode(),
 	};
-	Ok((fmspc, tcb_info, report))
+	Ok((fmspc, tcb_info, pck_cert, report))
 }
 
 // make sure this function doesn't panic!
@@ -755,6 +941,10 @@ pub fn verify_server_cert(
 /// Profile Specification" https://download.01.org/intel-sgx/dcap-1.2/linux/docs/Intel_SGX_PCK_Certificate_CRL_Spec-1.1.pdf
 const INTEL_SGX_EXTENSION_OID: ObjectIdentifier =
 	ObjectIdentifier::new_unwrap("1.2.840.113741.1.13.1");
+const OID_BASIC_CONSTRAINTS: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.19");
+const OID_KEY_USAGE: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.15");
+/// `cRLSign` is bit 6 of the key usage bit string, counted from the most significant bit
+const KEY_USAGE_CRL_SIGN: u8 = 0x80 >> 6;
 const OID_FMSPC: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113741.1.13.1.4");
 const OID_PCESVN: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113741.1.13.1.2.17");
 const OID_CPUSVN: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113741.1.13.1.2.18");
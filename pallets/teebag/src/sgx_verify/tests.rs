@@ -40,6 +40,7 @@ const TEST7_SIGNER_PUB: &[u8] = include_bytes!("./test/enclave-signing-pubkey-TE
 const QE_IDENTITY_CERT: &str = include_str!("./test/dcap/qe_identity_cert.pem");
 const DCAP_QUOTE_CERT: &str = include_str!("./test/dcap/dcap_quote_cert.der");
 const PCK_CRL: &[u8] = include_bytes!("./test/dcap/pck_crl.der");
+const ROOT_CA_CRL: &[u8] = include_bytes!("./test/dcap/root_ca_crl.der");
 
 // reproduce with "make mrenclave" in worker repo root
 const TEST1_MRENCLAVE: &[u8] = &[
@@ -261,9 +262,8 @@ fn verify_tcb_info_signature() {
 	verify_signature(&leaf_cert, data, &signature, webpki::ring::ECDSA_P256_SHA256).unwrap();
 }
 
-/// This is demo code of how a CRL certificate can be parsed and how the revoked serials can be
-/// extracted The part that is missing/open is how to verify the certificate chain of the CRL
-/// TODO: Implement CRL handling
+/// The CRL in the test data is issued by the PCK Platform CA, but `pck_crl_issuer_chain.pem`
+/// contains the PCK Processor CA, so only parsing can be tested with it.
 #[test]
 fn parse_pck_crl() {
 	let crl_decoded = hex::decode(PCK_CRL).unwrap();
@@ -279,6 +279,88 @@ fn parse_pck_crl() {
 	assert_eq!(3, serials.len());
 }
 
+#[test]
+fn verify_pck_crl_with_wrong_issuer_is_err() {
+	let crl = hex::decode(PCK_CRL).unwrap();
+	let certificate_chain = include_bytes!("./test/dcap/pck_crl_issuer_chain.pem");
+	assert_eq!(
+		verify_pck_crl(&crl, certificate_chain, COLLATERAL_VERIFICATION_TIMESTAMP),
+		Err("CRL must be issued by the first certificate of the chain")
+	);
+}
+
+/// `root_ca_crl.der` is the CRL of the Intel SGX root CA, which revokes none of its
+/// intermediate CAs.
+#[test]
+fn verify_root_ca_crl_works() {
+	let crl = hex::decode(ROOT_CA_CRL).unwrap();
+	let certificate_chain = include_bytes!("./test/dcap/root_ca_crl_issuer_chain.pem");
+	let pck_crl =
+		verify_pck_crl(&crl, certificate_chain, COLLATERAL_VERIFICATION_TIMESTAMP).unwrap();
+	// Tue Apr 19 08:31:18 2022 GMT
+	assert_eq!(pck_crl.this_update, 1650357078000);
+	assert!(pck_crl.revoked_serials.is_empty());
+}
+
+#[test]
+fn verify_root_ca_crl_before_this_update_is_err() {
+	let crl = hex::decode(ROOT_CA_CRL).unwrap();
+	let certificate_chain = include_bytes!("./test/dcap/root_ca_crl_issuer_chain.pem");
+	// 2022-01-01
+	assert_eq!(verify_pck_crl(&crl, certificate_chain, 1640995200000), Err("CRL is not valid yet"));
+}
+
+#[test]
+fn ensure_crl_signer_works() {
+	let certs = extract_certs(include_bytes!("./test/dcap/pck_crl_issuer_chain.pem"));
+	for cert in certs {
+		let cert: Certificate = der::Decode::from_der(&cert).unwrap();
+		assert_eq!(ensure_crl_signer(&cert), Ok(()));
+	}
+}
+
+#[test]
+fn ensure_crl_signer_with_signing_certificate_is_err() {
+	// the first certificate is the TCB signing certificate, which is not a CA
+	let certs = extract_certs(include_bytes!("./test/dcap/tcb_info_issuer_chain.pem"));
+	let cert: Certificate = der::Decode::from_der(&certs[0]).unwrap();
+	assert_eq!(ensure_crl_signer(&cert), Err("CRL issuer must be a CA certificate"));
+}
+
+#[test]
+fn verify_ca_certificate_chain_works() {
+	let certs = extract_certs(include_bytes!("./test/dcap/pck_crl_issuer_chain.pem"));
+	assert_eq!(verify_ca_certificate_chain(&certs, COLLATERAL_VERIFICATION_TIMESTAMP), Ok(()));
+}
+
+#[test]
+fn verify_ca_certificate_chain_not_ending_in_root_is_err() {
+	let mut certs = extract_certs(include_bytes!("./test/dcap/pck_crl_issuer_chain.pem"));
+	certs.reverse();
+	assert!(verify_ca_certificate_chain(&certs, COLLATERAL_VERIFICATION_TIMESTAMP).is_err());
+}
+
+#[test]
+fn verify_ca_certificate_chain_with_expired_certificate_is_err() {
+	let certs = extract_certs(include_bytes!("./test/dcap/pck_crl_issuer_chain.pem"));
+	// 2034-01-01, after the PCK Processor CA certificate expired
+	assert_eq!(
+		verify_ca_certificate_chain(&certs, 2019686400000),
+		Err("Certificate is not valid at verification time")
+	);
+}
+
+#[test]
+fn extract_pck_cert_id_works() {
+	let der = DCAP_QUOTE_CERT.replace('\n', "");
+	let der = base64::decode(der).unwrap();
+
+	let id = extract_pck_cert_id(&der).unwrap();
+	// SHA-256 of the PCK Processor CA name
+	assert_eq!(id.issuer, hex!("5b4eac8945e4549bd4b4ebdbf6f2cedb2334c7ba3ad62584f447c6cf0dc2acc1"));
+	assert_eq!(id.serial, hex!("cc96aa50f79a4c6ed85858b8fb6b0a9d9b21dd3a").to_vec());
+}
+
 #[test]
 fn parse_pck_certificate() {
 	let der = DCAP_QUOTE_CERT.replace('\n', "");
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Ed25519Public;
+use crate::{Ed25519Public, PckCertId, TcbVersionStatus};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
@@ -27,6 +27,8 @@ pub type MrEnclave = [u8; 32];
 pub type Fmspc = [u8; 6];
 pub type Cpusvn = [u8; 16];
 pub type Pcesvn = u16;
+/// SHA-256 hash of the DER encoded name of a PCK CA
+pub type PckCaId = [u8; 32];
 pub type ShardIdentifier = H256;
 pub type EnclaveFingerprint = H256;
 pub type SidechainBlockNumber = u64;
@@ -157,6 +159,50 @@ impl Enclave {
 	}
 }
 
+/// Collateral as published by Intel, e.g. the TCB info or the quoting enclave identity
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SignedCollateral {
+	pub data: Vec<u8>,              // raw json, as covered by the signature
+	pub signature: Vec<u8>,         // raw ECDSA signature over `data`
+	pub certificate_chain: Vec<u8>, // PEM encoded issuer chain
+}
+
+/// Collateral updates that are registered within a single extrinsic
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CollateralBundle {
+	pub quoting_enclave: Option<SignedCollateral>,
+	pub tcb_infos: Vec<SignedCollateral>,
+}
+
+impl CollateralBundle {
+	pub fn len(&self) -> usize {
+		self.tcb_infos.len() + self.quoting_enclave.iter().count()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+/// Attestation details of a registered enclave, re-checked periodically to evict stale enclaves
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EnclaveAttestation {
+	pub attested_at: u64, // unix epoch in milliseconds of the last successful attestation
+	pub tcb: Option<(Fmspc, TcbVersionStatus)>, // only available for DCAP attestation
+	pub pck_cert: Option<PckCertId>, // only available for DCAP attestation
+}
+
+/// Why an enclave was evicted from the registry
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum EvictionReason {
+	/// The last attestation is older than the re-attestation period
+	AttestationExpired,
+	/// The attested TCB is no longer covered by the registered TCB info
+	TcbOutOfDate,
+	/// The PCK certificate that signed the quote has been revoked
+	PckCertificateRevoked,
+}
+
 // use the name `RsaRequest` to differentiate from `AesRequest` (see aes_request.rs in
 // tee-worker) `Rsa` implies that the payload is RSA-encrypted (using enclave's shielding key)
 #[macro_export]
@@ -61,6 +61,7 @@ pub trait WeightInfo {
 	fn unregister_enclave() -> Weight;
 	fn register_quoting_enclave() -> Weight;
 	fn register_tcb_info() -> Weight;
+	fn register_pck_crl(s: u32, ) -> Weight;
 	fn post_opaque_task() -> Weight;
 	fn parentchain_block_processed() -> Weight;
 	fn sidechain_block_imported() -> Weight;
@@ -226,6 +227,17 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: Teebag PckCrlThisUpdate (r:1 w:1)
+	/// Storage: Teebag RevokedPckCertificate (r:0 w:s)
+	/// The range of component `s` is `[0, 1024]`.
+	/// Not generated by the benchmark CLI yet: the signature verification is priced like
+	/// `register_tcb_info` and every cleared or inserted serial is charged as a write.
+	fn register_pck_crl(s: u32, ) -> Weight {
+		Self::register_tcb_info()
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+	}
 	fn post_opaque_task() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -425,6 +437,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: Teebag PckCrlThisUpdate (r:1 w:1)
+	/// Storage: Teebag RevokedPckCertificate (r:0 w:s)
+	/// The range of component `s` is `[0, 1024]`.
+	/// Not generated by the benchmark CLI yet: the signature verification is priced like
+	/// `register_tcb_info` and every cleared or inserted serial is charged as a write.
+	fn register_pck_crl(s: u32, ) -> Weight {
+		Self::register_tcb_info()
+			.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+	}
 	fn post_opaque_task() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -24,6 +24,7 @@ use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
 use frame_support::traits::EnsureOrigin;
 use frame_system::RawOrigin;
 use sp_core::H256;
+use sp_runtime::traits::Saturating;
 use sp_std::vec;
 
 use pallet_teebag::test_util::TEST8_MRENCLAVE;
@@ -39,6 +40,16 @@ benchmarks! {
 	add_delegatee {
 		let account: T::AccountId =  frame_benchmarking::account("TEST_A", 0u32, USER_SEED);
 	}: _(RawOrigin::Root, account.clone())
+	verify{
+		assert!(PendingDelegatees::<T>::contains_key(account));
+	}
+	// Benchmark `accept_delegatee`. There are no worst conditions.
+	accept_delegatee {
+		let account: T::AccountId =  frame_benchmarking::account("TEST_A", 0u32, USER_SEED);
+		let balance = T::DelegateeDeposit::get().saturating_mul(2u32.into());
+		T::Currency::make_free_balance_be(&account, balance);
+		VCManagement::<T>::add_delegatee(RawOrigin::Root.into(), account.clone())?;
+	}: _(RawOrigin::Signed(account.clone()))
 	verify{
 		assert!(Delegatee::<T>::contains_key(account));
 	}
@@ -46,7 +57,10 @@ benchmarks! {
 	// execution time is constant irrespective of encrypted_data size.
 	remove_delegatee {
 		let account: T::AccountId =  frame_benchmarking::account("TEST_A", 0u32, USER_SEED);
+		let balance = T::DelegateeDeposit::get().saturating_mul(2u32.into());
+		T::Currency::make_free_balance_be(&account, balance);
 		VCManagement::<T>::add_delegatee(RawOrigin::Root.into(), account.clone())?;
+		VCManagement::<T>::accept_delegatee(RawOrigin::Signed(account.clone()).into())?;
 	}: _(RawOrigin::Root, account.clone())
 	verify{
 		assert!(!Delegatee::<T>::contains_key(account));
@@ -35,6 +35,7 @@ pub mod weights;
 
 pub use crate::weights::WeightInfo;
 
+use frame_support::traits::{Currency, ReservableCurrency};
 pub use pallet::*;
 use pallet_teebag::ShardIdentifier;
 use sp_core::H256;
@@ -45,6 +46,9 @@ pub use schema::*;
 
 pub type VCIndex = H256;
 
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -69,6 +73,11 @@ pub mod pallet {
 		type DelegateeAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		// origin that is allowed to call extrinsics
 		type ExtrinsicWhitelistOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+		// currency used to reserve the delegatee deposits
+		type Currency: ReservableCurrency<Self::AccountId>;
+		// deposit reserved from a delegatee account when it accepts to be a delegatee
+		#[pallet::constant]
+		type DelegateeDeposit: Get<BalanceOf<Self>>;
 	}
 
 	// the admin account
@@ -85,6 +94,20 @@ pub mod pallet {
 	#[pallet::getter(fn delegatee)]
 	pub type Delegatee<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
 
+	// accounts that were invited to be delegatees by `DelegateeAdminOrigin`, they become
+	// delegatees once they accept and pay the deposit
+	#[pallet::storage]
+	#[pallet::getter(fn pending_delegatee)]
+	pub type PendingDelegatees<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	// deposits reserved from the delegatees, kept apart from `Delegatee` as the worker reads its
+	// keys
+	#[pallet::storage]
+	#[pallet::getter(fn delegatee_deposit)]
+	pub type DelegateeDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn schema_index)]
 	pub type SchemaRegistryIndex<T: Config> = StorageValue<_, SchemaIndex, ValueQuery>;
@@ -97,6 +120,9 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
+		DelegateeInvited {
+			account: T::AccountId,
+		},
 		DelegateeAdded {
 			account: T::AccountId,
 		},
@@ -167,6 +193,8 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// a delegatee doesn't exist
 		DelegateeNotExist,
+		/// the account wasn't invited to be a delegatee
+		DelegateeNotInvited,
 		/// a `request_vc` request from unauthorized user
 		UnauthorizedUser,
 		/// the VC already exists
@@ -212,24 +240,30 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// add an account to the delegatees
+		/// invite an account to be a delegatee, it becomes a delegatee when it accepts with
+		/// `accept_delegatee`, which reserves the deposit from it
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_delegatee())]
 		pub fn add_delegatee(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
 			let _ = T::DelegateeAdminOrigin::ensure_origin(origin)?;
 			// we don't care if `account` already exists
-			Delegatee::<T>::insert(account.clone(), ());
-			Self::deposit_event(Event::DelegateeAdded { account });
+			if !Delegatee::<T>::contains_key(&account) {
+				PendingDelegatees::<T>::insert(&account, ());
+				Self::deposit_event(Event::DelegateeInvited { account });
+			}
 			Ok(())
 		}
 
-		/// remove an account from the delegatees
+		/// remove an account from the delegatees or withdraw its invitation
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as Config>::WeightInfo::remove_delegatee())]
 		pub fn remove_delegatee(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
 			let _ = T::DelegateeAdminOrigin::ensure_origin(origin)?;
-			ensure!(Delegatee::<T>::contains_key(&account), Error::<T>::DelegateeNotExist);
-			Delegatee::<T>::remove(account.clone());
+			if PendingDelegatees::<T>::take(&account).is_none() {
+				ensure!(Delegatee::<T>::contains_key(&account), Error::<T>::DelegateeNotExist);
+				T::Currency::unreserve(&account, DelegateeDeposits::<T>::take(&account));
+				Delegatee::<T>::remove(account.clone());
+			}
 			Self::deposit_event(Event::DelegateeRemoved { account });
 			Ok(())
 		}
@@ -250,6 +284,21 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// accept the invitation to be a delegatee, the deposit is reserved from the caller
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_delegatee())]
+		pub fn accept_delegatee(origin: OriginFor<T>) -> DispatchResult {
+			let who = T::ExtrinsicWhitelistOrigin::ensure_origin(origin)?;
+			ensure!(PendingDelegatees::<T>::contains_key(&who), Error::<T>::DelegateeNotInvited);
+			let deposit = T::DelegateeDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			PendingDelegatees::<T>::remove(&who);
+			DelegateeDeposits::<T>::insert(&who, deposit);
+			Delegatee::<T>::insert(&who, ());
+			Self::deposit_event(Event::DelegateeAdded { account: who });
+			Ok(())
+		}
+
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as Config>::WeightInfo::set_admin())]
 		pub fn set_admin(origin: OriginFor<T>, new: T::AccountId) -> DispatchResultWithPostInfo {
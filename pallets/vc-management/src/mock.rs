@@ -148,6 +148,8 @@ impl pallet_vc_management::Config for Test {
 	type SetAdminOrigin = EnsureRoot<Self::AccountId>;
 	type DelegateeAdminOrigin = EnsureRoot<Self::AccountId>;
 	type ExtrinsicWhitelistOrigin = VCMPExtrinsicWhitelist;
+	type Currency = Balances;
+	type DelegateeDeposit = ConstU128<50>;
 }
 
 parameter_types! {
@@ -173,14 +175,20 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		get_signer, TEST8_CERT, TEST8_SIGNER_PUB, TEST8_TIMESTAMP, URL,
 	};
 
-	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
 	let alice: SystemAccountId = get_signer(&[1u8; 32]);
 	let eddie: SystemAccountId = get_signer(&[5u8; 32]);
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(alice.clone(), 1_000), (eddie.clone(), 1_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| {
 		System::set_block_number(1);
 		let _ = VCManagement::set_admin(RuntimeOrigin::root(), alice.clone());
-		let _ = VCManagement::add_delegatee(RuntimeOrigin::root(), eddie);
+		let _ = VCManagement::add_delegatee(RuntimeOrigin::root(), eddie.clone());
+		let _ = VCManagement::accept_delegatee(RuntimeOrigin::signed(eddie));
 		assert_ok!(Teebag::set_admin(RuntimeOrigin::root(), alice.clone()));
 		assert_ok!(Teebag::set_mode(
 			RuntimeOrigin::signed(alice.clone()),
@@ -14,7 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{mock::*, Error, ShardIdentifier, Status};
+use crate::{
+	mock::*, Delegatee, DelegateeDeposits, Error, PendingDelegatees, ShardIdentifier, Status,
+};
 use core_primitives::{Assertion, Identity};
 use frame_support::{assert_noop, assert_ok};
 use sp_core::H256;
@@ -81,6 +83,83 @@ fn request_vc_13_with_unauthorized_delegatee_fails() {
 	});
 }
 
+#[test]
+fn delegatee_deposit_is_reserved_and_released() {
+	new_test_ext().execute_with(|| {
+		let eddie: SystemAccountId = get_signer(EDDIE_PUBKEY);
+		assert_eq!(Balances::reserved_balance(&eddie), 50);
+		assert_eq!(DelegateeDeposits::<Test>::get(&eddie), 50);
+		// adding an existing delegatee doesn't invite it again
+		assert_ok!(VCManagement::add_delegatee(RuntimeOrigin::root(), eddie.clone()));
+		assert!(!PendingDelegatees::<Test>::contains_key(&eddie));
+		assert_eq!(Balances::reserved_balance(&eddie), 50);
+
+		assert_ok!(VCManagement::remove_delegatee(RuntimeOrigin::root(), eddie.clone()));
+		assert_eq!(Balances::reserved_balance(&eddie), 0);
+		assert!(!DelegateeDeposits::<Test>::contains_key(&eddie));
+		assert!(!Delegatee::<Test>::contains_key(&eddie));
+	});
+}
+
+#[test]
+fn delegatee_deposit_is_only_reserved_when_accepted() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		assert_ok!(VCManagement::add_delegatee(RuntimeOrigin::root(), alice.clone()));
+		assert!(PendingDelegatees::<Test>::contains_key(&alice));
+		assert!(!Delegatee::<Test>::contains_key(&alice));
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		System::assert_last_event(RuntimeEvent::VCManagement(crate::Event::DelegateeInvited {
+			account: alice.clone(),
+		}));
+
+		assert_ok!(VCManagement::accept_delegatee(RuntimeOrigin::signed(alice.clone())));
+		assert!(!PendingDelegatees::<Test>::contains_key(&alice));
+		assert!(Delegatee::<Test>::contains_key(&alice));
+		assert_eq!(Balances::reserved_balance(&alice), 50);
+		System::assert_last_event(RuntimeEvent::VCManagement(crate::Event::DelegateeAdded {
+			account: alice,
+		}));
+	});
+}
+
+#[test]
+fn accept_delegatee_without_invitation_fails() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		assert_noop!(
+			VCManagement::accept_delegatee(RuntimeOrigin::signed(alice)),
+			Error::<Test>::DelegateeNotInvited
+		);
+	});
+}
+
+#[test]
+fn accept_delegatee_without_enough_balance_fails() {
+	new_test_ext().execute_with(|| {
+		// an account without any balance
+		let bob: SystemAccountId = get_signer(&[9u8; 32]);
+		assert_ok!(VCManagement::add_delegatee(RuntimeOrigin::root(), bob.clone()));
+		assert!(VCManagement::accept_delegatee(RuntimeOrigin::signed(bob.clone())).is_err());
+		assert!(PendingDelegatees::<Test>::contains_key(&bob));
+		assert!(!Delegatee::<Test>::contains_key(&bob));
+	});
+}
+
+#[test]
+fn remove_delegatee_withdraws_invitation() {
+	new_test_ext().execute_with(|| {
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		assert_ok!(VCManagement::add_delegatee(RuntimeOrigin::root(), bob.clone()));
+		assert_ok!(VCManagement::remove_delegatee(RuntimeOrigin::root(), bob.clone()));
+		assert!(!PendingDelegatees::<Test>::contains_key(&bob));
+		assert_noop!(
+			VCManagement::accept_delegatee(RuntimeOrigin::signed(bob)),
+			Error::<Test>::DelegateeNotInvited
+		);
+	});
+}
+
 #[test]
 fn vc_issued_works() {
 	new_test_ext().execute_with(|| {
@@ -49,6 +49,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn add_delegatee() -> Weight;
 	fn remove_delegatee() -> Weight;
+	fn accept_delegatee() -> Weight;
 	fn request_vc() -> Weight;
 	fn vc_issued() -> Weight;
 	fn some_error() -> Weight;
@@ -75,6 +76,14 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// The delegatee deposit isn't covered by the generated `add_delegatee` and `remove_delegatee`
+	// weights, they have to be regenerated together with `accept_delegatee`. Until then
+	// `accept_delegatee` is estimated as `remove_delegatee` plus the reserve and the
+	// `PendingDelegatees` and `DelegateeDeposits` accesses.
+	fn accept_delegatee() -> Weight {
+		Self::remove_delegatee()
+			.saturating_add(T::DbWeight::get().reads_writes(3_u64, 3_u64))
+	}
 	// Storage: VCMPExtrinsicWhitelist GroupControlOn (r:1 w:0)
 	// Proof Skipped: VCMPExtrinsicWhitelist GroupControlOn (max_values: Some(1), max_size: None, mode: Measured)
 	fn request_vc() -> Weight {
@@ -158,6 +167,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// The delegatee deposit isn't covered by the generated `add_delegatee` and `remove_delegatee`
+	// weights, they have to be regenerated together with `accept_delegatee`. Until then
+	// `accept_delegatee` is estimated as `remove_delegatee` plus the reserve and the
+	// `PendingDelegatees` and `DelegateeDeposits` accesses.
+	fn accept_delegatee() -> Weight {
+		Self::remove_delegatee()
+			.saturating_add(RocksDbWeight::get().reads_writes(3_u64, 3_u64))
+	}
 	// Storage: VCMPExtrinsicWhitelist GroupControlOn (r:1 w:0)
 	// Proof Skipped: VCMPExtrinsicWhitelist GroupControlOn (max_values: Some(1), max_size: None, mode: Measured)
 	fn request_vc() -> Weight {
@@ -19,6 +19,7 @@
 use crate::{AssetMetadata, BalanceOf, Call, Config, Pallet};
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
+use sp_std::boxed::Box;
 use xcm::latest::prelude::*;
 
 benchmarks! {
@@ -176,6 +177,82 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::asset_id_type(asset_id), Some(asset_type_new_default));
 		assert!(Pallet::<T>::asset_type_id(asset_type_to_be_removed).is_none());
 	}
+
+	set_asset_reserve {
+		let reserve = MultiLocation::new(1, X1(Parachain(1000)));
+		let asset_type: T::ForeignAssetType = Some(reserve).into();
+		let metadata = AssetMetadata::<BalanceOf<T>> {
+			name: "test".into(),
+			symbol: "TST".into(),
+			decimals: 12,
+			minimal_balance: BalanceOf::<T>::default(),
+			is_frozen: false,
+		};
+		let asset_id = Pallet::<T>::foreign_asset_tracker();
+		Pallet::<T>::register_foreign_asset_type(
+			RawOrigin::Root.into(),
+			asset_type,
+			metadata
+		)?;
+
+	}: _(RawOrigin::Root, asset_id.clone(), Some(Box::new(reserve)))
+	verify {
+		assert_eq!(Pallet::<T>::asset_id_reserve(asset_id), Some(reserve));
+	}
+
+	set_asset_destination_fee {
+		let reserve = MultiLocation::new(1, X1(Parachain(1000)));
+		let asset_type: T::ForeignAssetType = Some(reserve).into();
+		let metadata = AssetMetadata::<BalanceOf<T>> {
+			name: "test".into(),
+			symbol: "TST".into(),
+			decimals: 12,
+			minimal_balance: BalanceOf::<T>::default(),
+			is_frozen: false,
+		};
+		let asset_id = Pallet::<T>::foreign_asset_tracker();
+		Pallet::<T>::register_foreign_asset_type(
+			RawOrigin::Root.into(),
+			asset_type,
+			metadata
+		)?;
+
+	}: _(RawOrigin::Root, asset_id.clone(), Box::new(reserve), Some(1))
+	verify {
+		assert_eq!(Pallet::<T>::asset_id_destination_fee(asset_id, reserve), Some(1));
+	}
+
+	// Only the building of the program is measured here, its execution is
+	// accounted for by `TransferXcmWeight`
+	transfer_asset_to_parachain {
+		let reserve = MultiLocation::new(1, X1(Parachain(1000)));
+		let dest = MultiLocation::new(1, X1(Parachain(3000)));
+		let asset_type: T::ForeignAssetType = Some(reserve).into();
+		let metadata = AssetMetadata::<BalanceOf<T>> {
+			name: "test".into(),
+			symbol: "TST".into(),
+			decimals: 12,
+			minimal_balance: BalanceOf::<T>::default(),
+			is_frozen: false,
+		};
+		let asset_id = Pallet::<T>::foreign_asset_tracker();
+		Pallet::<T>::register_foreign_asset_type(
+			RawOrigin::Root.into(),
+			asset_type,
+			metadata
+		)?;
+		Pallet::<T>::set_asset_reserve(RawOrigin::Root.into(), asset_id.clone(), Some(Box::new(reserve)))?;
+		Pallet::<T>::set_asset_destination_fee(RawOrigin::Root.into(), asset_id.clone(), Box::new(reserve), Some(1))?;
+		Pallet::<T>::set_asset_destination_fee(RawOrigin::Root.into(), asset_id.clone(), Box::new(dest), Some(1))?;
+		let mut message = None;
+
+	}: {
+		// sending through the reserve reads both configured fees
+		message = Some(Pallet::<T>::build_transfer_xcm(asset_id, 1_000, 3000, [0u8; 32])?);
+	}
+	verify {
+		assert!(message.is_some());
+	}
 }
 
 #[cfg(test)]
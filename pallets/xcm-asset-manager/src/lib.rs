@@ -31,15 +31,18 @@
 //! and control the creation of foreign assets
 //! The assumption is we work with AssetTypes, which can then be comverted to AssetIds
 //!
-//! This pallet has five storage items:
+//! This pallet has seven storage items:
 //! - AssetIdType: A mapping from AssetId->AssetType.
 //! - AssetIdUnitsPerSecond: An AssetId->u128 mapping that holds how much each AssetId should be
 //!   charged per unit of second, in the case such an Asset is received as a XCM asset.
 //! - AssetTypeId: A mapping from AssetType -> AssetId.
 //! - ForeignAssetTracker: The counter of foreign assets that have been created so far.
 //! - AssetIdMetadata: An AssetId->AssetMetadata mapping that holds the metadata token info.
+//! - AssetIdReserve: An AssetId->MultiLocation mapping of the trusted reserve of each asset.
+//! - AssetIdDestinationFee: An (AssetId, MultiLocation)->u128 mapping that holds how much of an
+//!   asset is spent on execution when it is sent to a particular destination.
 //!
-//! This pallet has five extrinsics:
+//! This pallet has eight extrinsics:
 //! - register_foreign_asset: Register a foreign asset in this pallet.
 //! - set_asset_units_per_second: Set the unit per second that should be charged for a particular
 //!   asset.
@@ -47,6 +50,10 @@
 //! - add_asset_type: Add the correspondence between AssetId and AssetType.
 //! - remove_asset_type: Remove the correspondence between AssetId and AssetType. At least one
 //!   relation must exist.
+//! - set_asset_reserve: Set or clear the trusted reserve location of an asset.
+//! - set_asset_destination_fee: Set or clear the execution fee paid in an asset on a destination.
+//! - transfer_asset_to_parachain: Send an asset to an account on another parachain, routing it
+//!   through its trusted reserve.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::clone_on_copy)]
@@ -68,9 +75,16 @@ use frame_support::{
 use frame_system::pallet_prelude::*;
 use orml_traits::GetByKey;
 pub use pallet::*;
-use parity_scale_codec::HasCompact;
-use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, One};
-use sp_std::{convert::*, vec::Vec};
+use parity_scale_codec::{Encode, HasCompact};
+use sp_runtime::{
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, Convert, One},
+	SaturatedConversion,
+};
+use sp_std::{boxed::Box, convert::*, vec, vec::Vec};
+use xcm::latest::{
+	AssetId as XcmAssetId, ExecuteXcm, Instruction, InteriorMultiLocation, Junction, Junctions,
+	MultiAsset, MultiAssetFilter, MultiLocation, WeightLimit, WildMultiAsset, Xcm,
+};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -131,7 +145,7 @@ pub mod pallet {
 			+ MaxEncodedLen;
 
 		/// The Foreign Asset Kind.
-		type ForeignAssetType: Parameter + Member + Ord + PartialOrd;
+		type ForeignAssetType: Parameter + Member + Ord + PartialOrd + Into<Option<MultiLocation>>;
 
 		/// The units in which we record balances.
 		type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
@@ -143,6 +157,19 @@ pub mod pallet {
 		type Currency: ReservableCurrency<Self::AccountId>;
 
 		type WeightInfo: WeightInfo;
+
+		/// Executor of the XCM programs built by `transfer_asset_to_parachain`
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Convert an account into the XCM origin withdrawing the transferred asset
+		type AccountIdToMultiLocation: Convert<Self::AccountId, MultiLocation>;
+
+		/// Location of this chain, used to express assets from the view of other chains
+		type UniversalLocation: Get<InteriorMultiLocation>;
+
+		/// Maximum weight of the local XCM program executed by `transfer_asset_to_parachain`
+		#[pallet::constant]
+		type TransferXcmWeight: Get<Weight>;
 	}
 
 	/// An error that can occur while executing the mapping pallet's logic.
@@ -153,6 +180,12 @@ pub mod pallet {
 		AssetIdDoesNotExist,
 		DefaultAssetTypeRemoved,
 		AssetIdLimitReached,
+		AssetReserveNotSet,
+		DestinationFeeNotSet,
+		AssetNotLocatable,
+		AmountTooLowToPayFees,
+		CannotReanchor,
+		XcmExecutionFailed,
 	}
 
 	#[pallet::event]
@@ -174,6 +207,19 @@ pub mod pallet {
 		/// Changed the amount of units we
 		/// are charging per execution second for a given asset
 		UnitsPerSecondChanged { asset_id: T::AssetId, units_per_second: u128 },
+		/// The trusted reserve of an asset is set, or cleared when `reserve` is None
+		AssetReserveChanged { asset_id: T::AssetId, reserve: Option<MultiLocation> },
+		/// The execution fee paid in an asset on a destination is set, or cleared when `fee` is
+		/// None
+		DestinationFeeChanged { asset_id: T::AssetId, dest: MultiLocation, fee: Option<u128> },
+		/// An asset is sent to an account on another parachain
+		AssetTransferredToParachain {
+			asset_id: T::AssetId,
+			from: T::AccountId,
+			para_id: u32,
+			beneficiary: [u8; 32],
+			amount: T::Balance,
+		},
 	}
 
 	/// Mapping from an asset id to asset type.
@@ -213,6 +259,28 @@ pub mod pallet {
 	pub type AssetIdMetadata<T: Config> =
 		StorageMap<_, Twox64Concat, T::AssetId, AssetMetadata<BalanceOf<T>>, OptionQuery>;
 
+	/// The trusted reserve of each foreign asset.
+	/// Reserve transfers of the asset are accepted from this location, and outgoing
+	/// transfers are routed through it.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_id_reserve)]
+	pub type AssetIdReserve<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, MultiLocation, OptionQuery>;
+
+	/// The amount of an asset bought as execution on a destination chain,
+	/// when the asset is sent to (or through) that destination.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_id_destination_fee)]
+	pub type AssetIdDestinationFee<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		MultiLocation,
+		u128,
+		OptionQuery,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Register new asset with the asset manager
@@ -355,6 +423,89 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Set the trusted reserve location of an asset
+		/// None removes the reserve, which disables outgoing transfers of the asset
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_asset_reserve())]
+		pub fn set_asset_reserve(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			reserve: Option<Box<MultiLocation>>,
+		) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			ensure!(AssetIdType::<T>::contains_key(&asset_id), Error::<T>::AssetIdDoesNotExist);
+
+			let reserve = reserve.map(|r| *r);
+			AssetIdReserve::<T>::set(&asset_id, reserve);
+
+			Self::deposit_event(Event::AssetReserveChanged { asset_id, reserve });
+			Ok(())
+		}
+
+		/// Set the amount of an asset spent on execution when it arrives at `dest`
+		/// None removes the fee, which disables transfers to or through `dest`
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_asset_destination_fee())]
+		pub fn set_asset_destination_fee(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			dest: Box<MultiLocation>,
+			fee: Option<u128>,
+		) -> DispatchResult {
+			T::ForeignAssetModifierOrigin::ensure_origin(origin)?;
+
+			ensure!(AssetIdType::<T>::contains_key(&asset_id), Error::<T>::AssetIdDoesNotExist);
+
+			let dest = *dest;
+			AssetIdDestinationFee::<T>::set(&asset_id, &dest, fee);
+
+			Self::deposit_event(Event::DestinationFeeChanged { asset_id, dest, fee });
+			Ok(())
+		}
+
+		/// Send `amount` of an asset to `beneficiary` on parachain `para_id`
+		/// The asset is withdrawn from its trusted reserve, which deposits it directly when it is
+		/// the destination, and forwards it as a reserve transfer otherwise.
+		/// Execution on each hop is paid with the fee configured for that chain.
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_asset_to_parachain()
+				.saturating_add(T::TransferXcmWeight::get())
+		)]
+		pub fn transfer_asset_to_parachain(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			amount: T::Balance,
+			para_id: u32,
+			beneficiary: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let message =
+				Self::build_transfer_xcm(asset_id, amount.saturated_into(), para_id, beneficiary)?;
+			let hash = message.using_encoded(sp_io::hashing::blake2_256);
+			let weight = T::TransferXcmWeight::get();
+			T::XcmExecutor::execute_xcm_in_credit(
+				T::AccountIdToMultiLocation::convert(who.clone()),
+				message,
+				hash,
+				weight,
+				weight,
+			)
+			.ensure_complete()
+			.map_err(|_| Error::<T>::XcmExecutionFailed)?;
+
+			Self::deposit_event(Event::AssetTransferredToParachain {
+				asset_id,
+				from: who,
+				para_id,
+				beneficiary,
+				amount,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -362,6 +513,89 @@ pub mod pallet {
 		pub fn account_id() -> T::AccountId {
 			PALLET_ID.into_account_truncating()
 		}
+
+		/// Whether `origin` is the trusted reserve of the asset registered as `asset_type`
+		pub fn is_trusted_reserve(
+			asset_type: &T::ForeignAssetType,
+			origin: &MultiLocation,
+		) -> bool {
+			AssetTypeId::<T>::get(asset_type)
+				.and_then(|asset_id| AssetIdReserve::<T>::get(asset_id))
+				.map_or(false, |reserve| &reserve == origin)
+		}
+
+		/// Build the local XCM program sending `amount` of `asset_id` to `beneficiary` on
+		/// parachain `para_id` through the asset's trusted reserve
+		pub fn build_transfer_xcm(
+			asset_id: T::AssetId,
+			amount: u128,
+			para_id: u32,
+			beneficiary: [u8; 32],
+		) -> Result<Xcm<<T as frame_system::Config>::RuntimeCall>, DispatchError> {
+			let asset_type =
+				AssetIdType::<T>::get(&asset_id).ok_or(Error::<T>::AssetIdDoesNotExist)?;
+			let asset_location: MultiLocation = Into::<Option<MultiLocation>>::into(asset_type)
+				.ok_or(Error::<T>::AssetNotLocatable)?;
+			let reserve =
+				AssetIdReserve::<T>::get(&asset_id).ok_or(Error::<T>::AssetReserveNotSet)?;
+			let dest = MultiLocation::new(1, Junctions::X1(Junction::Parachain(para_id)));
+			let beneficiary = MultiLocation::new(
+				0,
+				Junctions::X1(Junction::AccountId32 { network: None, id: beneficiary }),
+			);
+
+			let context = T::UniversalLocation::get();
+			// The fee asset as seen from `chain`, paying the fee configured for it
+			let fee_on = |chain: &MultiLocation| -> Result<(MultiAsset, u128), DispatchError> {
+				let fee = AssetIdDestinationFee::<T>::get(&asset_id, chain)
+					.ok_or(Error::<T>::DestinationFeeNotSet)?;
+				let location = asset_location
+					.reanchored(chain, context)
+					.map_err(|_| Error::<T>::CannotReanchor)?;
+				Ok(((XcmAssetId::Concrete(location), fee).into(), fee))
+			};
+			let all = MultiAssetFilter::Wild(WildMultiAsset::AllCounted(1));
+
+			let (reserve_fee, mut total_fee) = fee_on(&reserve)?;
+			let reserve_xcm = if dest == reserve {
+				Xcm(vec![
+					Instruction::BuyExecution {
+						fees: reserve_fee,
+						weight_limit: WeightLimit::Unlimited,
+					},
+					Instruction::DepositAsset { assets: all.clone(), beneficiary },
+				])
+			} else {
+				let (dest_fee, fee) = fee_on(&dest)?;
+				total_fee = total_fee.saturating_add(fee);
+				Xcm(vec![
+					Instruction::BuyExecution {
+						fees: reserve_fee,
+						weight_limit: WeightLimit::Unlimited,
+					},
+					Instruction::DepositReserveAsset {
+						assets: all.clone(),
+						dest: dest
+							.reanchored(&reserve, context)
+							.map_err(|_| Error::<T>::CannotReanchor)?,
+						xcm: Xcm(vec![
+							Instruction::BuyExecution {
+								fees: dest_fee,
+								weight_limit: WeightLimit::Unlimited,
+							},
+							Instruction::DepositAsset { assets: all.clone(), beneficiary },
+						]),
+					},
+				])
+			};
+			ensure!(amount > total_fee, Error::<T>::AmountTooLowToPayFees);
+
+			let asset: MultiAsset = (XcmAssetId::Concrete(asset_location), amount).into();
+			Ok(Xcm(vec![
+				Instruction::WithdrawAsset(asset.into()),
+				Instruction::InitiateReserveWithdraw { assets: all, reserve, xcm: reserve_xcm },
+			]))
+		}
 	}
 
 	impl<T: Config> GetByKey<T::AssetId, BalanceOf<T>> for Pallet<T> {
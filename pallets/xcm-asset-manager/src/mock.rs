@@ -28,7 +28,7 @@ use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, Hash as THash, IdentityLookup},
+	traits::{BlakeTwo256, Convert, Hash as THash, IdentityLookup},
 };
 use xcm::latest::prelude::*;
 
@@ -144,6 +144,20 @@ impl From<MockAssetType> for Option<MultiLocation> {
 	}
 }
 
+pub struct AccountIdToMultiLocation;
+impl Convert<AccountId, MultiLocation> for AccountIdToMultiLocation {
+	fn convert(account: AccountId) -> MultiLocation {
+		let mut id = [0u8; 32];
+		id[..8].copy_from_slice(&account.to_le_bytes());
+		MultiLocation::new(0, X1(AccountId32 { network: None, id }))
+	}
+}
+
+parameter_types! {
+	pub UniversalLocation: InteriorMultiLocation = X1(Parachain(2));
+	pub TransferXcmWeight: Weight = Weight::from_parts(1_000_000_000, 0);
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u64;
@@ -152,6 +166,10 @@ impl Config for Test {
 	type ForeignAssetModifierOrigin = EnsureRoot<u64>;
 	type Currency = Balances;
 	type WeightInfo = ();
+	type XcmExecutor = ();
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type UniversalLocation = UniversalLocation;
+	type TransferXcmWeight = TransferXcmWeight;
 }
 
 #[derive(Default)]
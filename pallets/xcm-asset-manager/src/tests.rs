@@ -18,7 +18,8 @@
 use crate::*;
 use mock::*;
 
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok};
+use xcm::latest::prelude::*;
 
 #[test]
 fn registering_foreign_works() {
@@ -676,3 +677,332 @@ fn test_asset_id_over_flow_error() {
 		);
 	});
 }
+
+fn register_reserve_asset() -> MultiLocation {
+	let reserve = MultiLocation::new(1, X1(Parachain(1000)));
+	let asset_metadata = crate::AssetMetadata::<BalanceOf<Test>> {
+		name: "test".into(),
+		symbol: "TST".into(),
+		decimals: 12,
+		minimal_balance: 0,
+		is_frozen: false,
+	};
+	assert_ok!(AssetManager::register_foreign_asset_type(
+		RuntimeOrigin::root(),
+		MockAssetType::Xcm(Box::new(reserve)),
+		asset_metadata
+	));
+	reserve
+}
+
+#[test]
+fn set_asset_reserve_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let reserve = register_reserve_asset();
+		let asset_type = MockAssetType::Xcm(Box::new(reserve));
+
+		assert_noop!(
+			AssetManager::set_asset_reserve(RuntimeOrigin::signed(1), 0, Some(Box::new(reserve))),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetManager::set_asset_reserve(RuntimeOrigin::root(), 1, Some(Box::new(reserve))),
+			Error::<Test>::AssetIdDoesNotExist
+		);
+		assert!(!AssetManager::is_trusted_reserve(&asset_type, &reserve));
+
+		assert_ok!(AssetManager::set_asset_reserve(
+			RuntimeOrigin::root(),
+			0,
+			Some(Box::new(reserve))
+		));
+		assert_eq!(AssetManager::asset_id_reserve(0), Some(reserve));
+		assert!(AssetManager::is_trusted_reserve(&asset_type, &reserve));
+		assert!(!AssetManager::is_trusted_reserve(&asset_type, &MultiLocation::parent()));
+
+		assert_ok!(AssetManager::set_asset_reserve(RuntimeOrigin::root(), 0, None));
+		assert_eq!(AssetManager::asset_id_reserve(0), None);
+		assert!(!AssetManager::is_trusted_reserve(&asset_type, &reserve));
+
+		assert!(events().ends_with(&[
+			crate::Event::AssetReserveChanged { asset_id: 0, reserve: Some(reserve) },
+			crate::Event::AssetReserveChanged { asset_id: 0, reserve: None },
+		]));
+	});
+}
+
+#[test]
+fn set_asset_destination_fee_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let reserve = register_reserve_asset();
+
+		assert_noop!(
+			AssetManager::set_asset_destination_fee(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(reserve),
+				Some(100)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetManager::set_asset_destination_fee(
+				RuntimeOrigin::root(),
+				1,
+				Box::new(reserve),
+				Some(100)
+			),
+			Error::<Test>::AssetIdDoesNotExist
+		);
+
+		assert_ok!(AssetManager::set_asset_destination_fee(
+			RuntimeOrigin::root(),
+			0,
+			Box::new(reserve),
+			Some(100)
+		));
+		assert_eq!(AssetManager::asset_id_destination_fee(0, reserve), Some(100));
+		assert_eq!(AssetManager::asset_id_destination_fee(0, MultiLocation::parent()), None);
+
+		assert_ok!(AssetManager::set_asset_destination_fee(
+			RuntimeOrigin::root(),
+			0,
+			Box::new(reserve),
+			None
+		));
+		assert_eq!(AssetManager::asset_id_destination_fee(0, reserve), None);
+
+		assert!(events().ends_with(&[
+			crate::Event::DestinationFeeChanged { asset_id: 0, dest: reserve, fee: Some(100) },
+			crate::Event::DestinationFeeChanged { asset_id: 0, dest: reserve, fee: None },
+		]));
+	});
+}
+
+#[test]
+fn transfer_to_reserve_builds_withdraw_program() {
+	ExtBuilder::default().build().execute_with(|| {
+		let reserve = register_reserve_asset();
+		assert_ok!(AssetManager::set_asset_reserve(
+			RuntimeOrigin::root(),
+			0,
+			Some(Box::new(reserve))
+		));
+		assert_ok!(AssetManager::set_asset_destination_fee(
+			RuntimeOrigin::root(),
+			0,
+			Box::new(reserve),
+			Some(100)
+		));
+
+		let beneficiary = MultiLocation::new(0, X1(AccountId32 { network: None, id: [1u8; 32] }));
+		assert_eq!(
+			AssetManager::build_transfer_xcm(0, 1_000, 1000, [1u8; 32]).unwrap(),
+			Xcm(vec![
+				WithdrawAsset(MultiAsset::from((reserve, 1_000u128)).into()),
+				InitiateReserveWithdraw {
+					assets: Wild(AllCounted(1)),
+					reserve,
+					xcm: Xcm(vec![
+						BuyExecution { fees: (Here, 100u128).into(), weight_limit: Unlimited },
+						DepositAsset { assets: Wild(AllCounted(1)), beneficiary },
+					]),
+				},
+			])
+		);
+	});
+}
+
+#[test]
+fn transfer_through_reserve_builds_forwarding_program() {
+	ExtBuilder::default().build().execute_with(|| {
+		let reserve = register_reserve_asset();
+		let dest = MultiLocation::new(1, X1(Parachain(3000)));
+		assert_ok!(AssetManager::set_asset_reserve(
+			RuntimeOrigin::root(),
+			0,
+			Some(Box::new(reserve))
+		));
+		assert_ok!(AssetManager::set_asset_destination_fee(
+			RuntimeOrigin::root(),
+			0,
+			Box::new(reserve),
+			Some(100)
+		));
+		assert_err!(
+			AssetManager::build_transfer_xcm(0, 1_000, 3000, [1u8; 32]),
+			Error::<Test>::DestinationFeeNotSet
+		);
+		assert_ok!(AssetManager::set_asset_destination_fee(
+			RuntimeOrigin::root(),
+			0,
+			Box::new(dest),
+			Some(200)
+		));
+
+		let beneficiary = MultiLocation::new(0, X1(AccountId32 { network: None, id: [1u8; 32] }));
+		assert_eq!(
+			AssetManager::build_transfer_xcm(0, 1_000, 3000, [1u8; 32]).unwrap(),
+			Xcm(vec![
+				WithdrawAsset(MultiAsset::from((reserve, 1_000u128)).into()),
+				InitiateReserveWithdraw {
+					assets: Wild(AllCounted(1)),
+					reserve,
+					xcm: Xcm(vec![
+						BuyExecution { fees: (Here, 100u128).into(), weight_limit: Unlimited },
+						DepositReserveAsset {
+							assets: Wild(AllCounted(1)),
+							dest,
+							xcm: Xcm(vec![
+								BuyExecution {
+									fees: (reserve, 200u128).into(),
+									weight_limit: Unlimited
+								},
+								DepositAsset { assets: Wild(AllCounted(1)), beneficiary },
+							]),
+						},
+					]),
+				},
+			])
+		);
+		assert_err!(
+			AssetManager::build_transfer_xcm(0, 300, 3000, [1u8; 32]),
+			Error::<Test>::AmountTooLowToPayFees
+		);
+	});
+}
+
+#[test]
+fn transfer_through_relay_reserve_reanchors_forwarded_program() {
+	ExtBuilder::default().build().execute_with(|| {
+		let relay = MultiLocation::parent();
+		let asset_metadata = crate::AssetMetadata::<BalanceOf<Test>> {
+			name: "relay".into(),
+			symbol: "RLY".into(),
+			decimals: 12,
+			minimal_balance: 0,
+			is_frozen: false,
+		};
+		assert_ok!(AssetManager::register_foreign_asset_type(
+			RuntimeOrigin::root(),
+			MockAssetType::Xcm(Box::new(relay)),
+			asset_metadata
+		));
+		assert_ok!(AssetManager::set_asset_reserve(
+			RuntimeOrigin::root(),
+			0,
+			Some(Box::new(relay))
+		));
+		assert_ok!(AssetManager::set_asset_destination_fee(
+			RuntimeOrigin::root(),
+			0,
+			Box::new(relay),
+			Some(100)
+		));
+		assert_ok!(AssetManager::set_asset_destination_fee(
+			RuntimeOrigin::root(),
+			0,
+			Box::new(MultiLocation::new(1, X1(Parachain(3000)))),
+			Some(200)
+		));
+
+		// The relay chain forwards to its child, which sees the relay asset as its parent
+		let beneficiary = MultiLocation::new(0, X1(AccountId32 { network: None, id: [1u8; 32] }));
+		assert_eq!(
+			AssetManager::build_transfer_xcm(0, 1_000, 3000, [1u8; 32]).unwrap(),
+			Xcm(vec![
+				WithdrawAsset(MultiAsset::from((relay, 1_000u128)).into()),
+				InitiateReserveWithdraw {
+					assets: Wild(AllCounted(1)),
+					reserve: relay,
+					xcm: Xcm(vec![
+						BuyExecution { fees: (Here, 100u128).into(), weight_limit: Unlimited },
+						DepositReserveAsset {
+							assets: Wild(AllCounted(1)),
+							dest: MultiLocation::new(0, X1(Parachain(3000))),
+							xcm: Xcm(vec![
+								BuyExecution {
+									fees: (Parent, 200u128).into(),
+									weight_limit: Unlimited
+								},
+								DepositAsset { assets: Wild(AllCounted(1)), beneficiary },
+							]),
+						},
+					]),
+				},
+			])
+		);
+	});
+}
+
+#[test]
+fn transfer_asset_to_parachain_errors() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetManager::transfer_asset_to_parachain(
+				RuntimeOrigin::signed(1),
+				0,
+				1_000,
+				1000,
+				[1u8; 32]
+			),
+			Error::<Test>::AssetIdDoesNotExist
+		);
+
+		let reserve = register_reserve_asset();
+		assert_noop!(
+			AssetManager::transfer_asset_to_parachain(
+				RuntimeOrigin::signed(1),
+				0,
+				1_000,
+				1000,
+				[1u8; 32]
+			),
+			Error::<Test>::AssetReserveNotSet
+		);
+
+		assert_ok!(AssetManager::set_asset_reserve(
+			RuntimeOrigin::root(),
+			0,
+			Some(Box::new(reserve))
+		));
+		assert_noop!(
+			AssetManager::transfer_asset_to_parachain(
+				RuntimeOrigin::signed(1),
+				0,
+				1_000,
+				1000,
+				[1u8; 32]
+			),
+			Error::<Test>::DestinationFeeNotSet
+		);
+
+		assert_ok!(AssetManager::set_asset_destination_fee(
+			RuntimeOrigin::root(),
+			0,
+			Box::new(reserve),
+			Some(100)
+		));
+		assert_noop!(
+			AssetManager::transfer_asset_to_parachain(
+				RuntimeOrigin::signed(1),
+				0,
+				100,
+				1000,
+				[1u8; 32]
+			),
+			Error::<Test>::AmountTooLowToPayFees
+		);
+		// The mock has no XCM executor
+		assert_noop!(
+			AssetManager::transfer_asset_to_parachain(
+				RuntimeOrigin::signed(1),
+				0,
+				1_000,
+				1000,
+				[1u8; 32]
+			),
+			Error::<Test>::XcmExecutionFailed
+		);
+	});
+}
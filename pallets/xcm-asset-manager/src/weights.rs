@@ -54,6 +54,9 @@ pub trait WeightInfo {
 	fn set_asset_units_per_second() -> Weight;
 	fn add_asset_type() -> Weight;
 	fn remove_asset_type() -> Weight;
+	fn set_asset_reserve() -> Weight;
+	fn set_asset_destination_fee() -> Weight;
+	fn transfer_asset_to_parachain() -> Weight;
 }
 
 /// Weights for pallet_asset_manager using the Litentry node and recommended hardware.
@@ -97,6 +100,27 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
+	// Storage: AssetManager AssetIdType (r:1 w:0)
+	// Storage: AssetManager AssetIdReserve (r:0 w:1)
+	fn set_asset_reserve() -> Weight {
+		Weight::from_parts(16_500_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetManager AssetIdType (r:1 w:0)
+	// Storage: AssetManager AssetIdDestinationFee (r:0 w:1)
+	fn set_asset_destination_fee() -> Weight {
+		Weight::from_parts(17_100_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetManager AssetIdType (r:1 w:0)
+	// Storage: AssetManager AssetIdReserve (r:1 w:0)
+	// Storage: AssetManager AssetIdDestinationFee (r:2 w:0)
+	fn transfer_asset_to_parachain() -> Weight {
+		Weight::from_parts(24_800_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -139,4 +163,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as u64))
 			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
+	// Storage: AssetManager AssetIdType (r:1 w:0)
+	// Storage: AssetManager AssetIdReserve (r:0 w:1)
+	fn set_asset_reserve() -> Weight {
+		Weight::from_parts(16_500_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetManager AssetIdType (r:1 w:0)
+	// Storage: AssetManager AssetIdDestinationFee (r:0 w:1)
+	fn set_asset_destination_fee() -> Weight {
+		Weight::from_parts(17_100_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetManager AssetIdType (r:1 w:0)
+	// Storage: AssetManager AssetIdReserve (r:1 w:0)
+	// Storage: AssetManager AssetIdDestinationFee (r:2 w:0)
+	fn transfer_asset_to_parachain() -> Weight {
+		Weight::from_parts(24_800_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+	}
 }
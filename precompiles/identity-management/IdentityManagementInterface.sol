@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @author The Litentry Team
+/// @title Pallet Identity Management Interface
+/// @dev The interface through which solidity contracts will interact with Identity Management
+/// We follow this same interface including four-byte function selectors, in the precompile that
+/// wraps the pallet
+/// The payloads are expected to be shielded (encrypted with the TEE shielding key) beforehand
+interface IdentityManagement {
+    /// @dev Emitted when an identity linking is requested, mirrors the pallet event
+    event LinkIdentityRequested(
+        bytes32 indexed account,
+        bytes32 shard,
+        bytes encryptedIdentity,
+        bytes encryptedValidationData,
+        bytes encryptedWeb3networks
+    );
+
+    /// @dev Emitted when an identity deactivation is requested, mirrors the pallet event
+    event DeactivateIdentityRequested(bytes32 indexed account, bytes32 shard, bytes encryptedIdentity);
+
+    /// @dev Emitted when an identity activation is requested, mirrors the pallet event
+    event ActivateIdentityRequested(bytes32 indexed account, bytes32 shard, bytes encryptedIdentity);
+
+    /// @dev Check whether the specified account is a delegatee
+    /// @custom:selector fc37bef6
+    /// @param account the account that we want to confirm is a delegatee
+    /// @return A boolean confirming whether the account is a delegatee
+    function isDelegatee(bytes32 account) external view returns (bool);
+
+    /// @dev Request to link an identity, only a delegatee can request it for another user
+    /// @custom:selector b492fa85
+    /// @param shard the shard of the TEE worker
+    /// @param user the account whose IDGraph the identity is linked to
+    /// @param encryptedIdentity the shielded identity
+    /// @param encryptedValidationData the shielded validation data
+    /// @param encryptedWeb3networks the shielded web3 networks
+    function linkIdentity(
+        bytes32 shard,
+        bytes32 user,
+        bytes calldata encryptedIdentity,
+        bytes calldata encryptedValidationData,
+        bytes calldata encryptedWeb3networks
+    ) external;
+
+    /// @dev Request to deactivate an identity of the caller
+    /// @custom:selector 705d7fc2
+    /// @param shard the shard of the TEE worker
+    /// @param encryptedIdentity the shielded identity
+    function deactivateIdentity(bytes32 shard, bytes calldata encryptedIdentity) external;
+
+    /// @dev Request to activate an identity of the caller
+    /// @custom:selector 121e97c8
+    /// @param shard the shard of the TEE worker
+    /// @param encryptedIdentity the shielded identity
+    function activateIdentity(bytes32 shard, bytes calldata encryptedIdentity) external;
+}
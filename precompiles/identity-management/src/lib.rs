@@ -0,0 +1,164 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the LinkIdentityRequested log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_LINK_IDENTITY_REQUESTED: [u8; 32] =
+	keccak256!("LinkIdentityRequested(bytes32,bytes32,bytes,bytes,bytes)");
+
+/// Solidity selector of the DeactivateIdentityRequested log, which is the Keccak of the Log
+/// signature.
+pub const SELECTOR_LOG_DEACTIVATE_IDENTITY_REQUESTED: [u8; 32] =
+	keccak256!("DeactivateIdentityRequested(bytes32,bytes32,bytes)");
+
+/// Solidity selector of the ActivateIdentityRequested log, which is the Keccak of the Log
+/// signature.
+pub const SELECTOR_LOG_ACTIVATE_IDENTITY_REQUESTED: [u8; 32] =
+	keccak256!("ActivateIdentityRequested(bytes32,bytes32,bytes)");
+
+/// A precompile to wrap the identity requests of pallet_identity_management.
+///
+/// The payloads are shielded (encrypted with the TEE shielding key) off-chain, the precompile
+/// passes them through as-is. The emitted logs mirror the pallet events, with the account
+/// as the indexed topic so that EVM clients can filter on it.
+pub struct IdentityManagementPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> IdentityManagementPrecompile<Runtime>
+where
+	Runtime: pallet_identity_management::Config + pallet_evm::Config,
+	Runtime::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_identity_management::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+{
+	#[precompile::public("isDelegatee(bytes32)")]
+	#[precompile::view]
+	fn is_delegatee(handle: &mut impl PrecompileHandle, account: H256) -> EvmResult<bool> {
+		let account: [u8; 32] = account.into();
+		let account = Runtime::AccountId::from(account);
+
+		// Delegatee: Blake2128(16) + AccountId(32)
+		handle.record_db_read::<Runtime>(48)?;
+
+		Ok(pallet_identity_management::Delegatee::<Runtime>::contains_key(&account))
+	}
+
+	/// `user` is the account for whom the identity is linked, it can be different from the caller
+	/// only if the caller is a delegatee
+	#[precompile::public("linkIdentity(bytes32,bytes32,bytes,bytes,bytes)")]
+	fn link_identity(
+		handle: &mut impl PrecompileHandle,
+		shard: H256,
+		user: H256,
+		encrypted_identity: UnboundedBytes,
+		encrypted_validation_data: UnboundedBytes,
+		encrypted_web3networks: UnboundedBytes,
+	) -> EvmResult {
+		let event_data = solidity::encode_event_data((
+			shard,
+			encrypted_identity.clone(),
+			encrypted_validation_data.clone(),
+			encrypted_web3networks.clone(),
+		));
+		handle.record_log_costs_manual(2, event_data.len())?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let account: [u8; 32] = user.into();
+		let call = pallet_identity_management::Call::<Runtime>::link_identity {
+			shard,
+			user: Runtime::AccountId::from(account),
+			encrypted_identity: encrypted_identity.into(),
+			encrypted_validation_data: encrypted_validation_data.into(),
+			encrypted_web3networks: encrypted_web3networks.into(),
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(handle.context().address, SELECTOR_LOG_LINK_IDENTITY_REQUESTED, user, event_data)
+			.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("deactivateIdentity(bytes32,bytes)")]
+	fn deactivate_identity(
+		handle: &mut impl PrecompileHandle,
+		shard: H256,
+		encrypted_identity: UnboundedBytes,
+	) -> EvmResult {
+		let event_data = solidity::encode_event_data((shard, encrypted_identity.clone()));
+		handle.record_log_costs_manual(2, event_data.len())?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let account = H256::from(Into::<[u8; 32]>::into(origin.clone()));
+		let call = pallet_identity_management::Call::<Runtime>::deactivate_identity {
+			shard,
+			encrypted_identity: encrypted_identity.into(),
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_DEACTIVATE_IDENTITY_REQUESTED,
+			account,
+			event_data,
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("activateIdentity(bytes32,bytes)")]
+	fn activate_identity(
+		handle: &mut impl PrecompileHandle,
+		shard: H256,
+		encrypted_identity: UnboundedBytes,
+	) -> EvmResult {
+		let event_data = solidity::encode_event_data((shard, encrypted_identity.clone()));
+		handle.record_log_costs_manual(2, event_data.len())?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let account = H256::from(Into::<[u8; 32]>::into(origin.clone()));
+		let call = pallet_identity_management::Call::<Runtime>::activate_identity {
+			shard,
+			encrypted_identity: encrypted_identity.into(),
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_ACTIVATE_IDENTITY_REQUESTED,
+			account,
+			event_data,
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,226 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64},
+	weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_evm::{AddressMapping, EnsureAddressNever, EnsureAddressRoot};
+use precompile_utils::precompile_set::{AddressU64, PrecompileAt, PrecompileSetBuilder};
+use sp_core::{H160, H256};
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub type Balance = u128;
+pub type BlockNumber = u32;
+
+pub type Signature = sp_runtime::MultiSignature;
+pub type AccountId = sp_runtime::AccountId32;
+pub type Address = sp_runtime::MultiAddress<AccountId, ()>;
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+pub type UncheckedExtrinsic =
+	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+
+pub type SignedExtra = (
+	frame_system::CheckSpecVersion<Test>,
+	frame_system::CheckTxVersion<Test>,
+	frame_system::CheckGenesis<Test>,
+	frame_system::CheckEra<Test>,
+	frame_system::CheckNonce<Test>,
+	frame_system::CheckWeight<Test>,
+);
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
+		Evm: pallet_evm,
+		IdentityManagement: pallet_identity_management,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type PalletInfo = PalletInfo;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<100>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+impl pallet_identity_management::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type TEECallOrigin = EnsureRoot<AccountId>;
+	type DelegateeAdminOrigin = EnsureRoot<AccountId>;
+	type ExtrinsicWhitelistOrigin = EnsureSigned<AccountId>;
+	type MaxOIDCClientRedirectUris = ConstU32<3>;
+	type Currency = Balances;
+	type OIDCClientDepositBase = ConstU128<100>;
+	type OIDCClientDepositPerByte = ConstU128<1>;
+	type DelegateeDeposit = ConstU128<50>;
+}
+
+pub fn precompile_address() -> H160 {
+	H160::from_low_u64_be(20480 + 64)
+}
+
+pub type IdentityManagementMockPrecompile<R> =
+	PrecompileSetBuilder<R, (PrecompileAt<AddressU64<20544>, IdentityManagementPrecompile<R>>,)>;
+
+pub type PCall<Runtime> = IdentityManagementPrecompileCall<Runtime>;
+
+pub struct TruncatedAddressMapping;
+impl AddressMapping<AccountId> for TruncatedAddressMapping {
+	fn into_account_id(address: H160) -> AccountId {
+		let mut data = [0u8; 32];
+		data[0..20].copy_from_slice(&address[..]);
+		AccountId::from(Into::<[u8; 32]>::into(data))
+	}
+}
+
+// silly for test purpose only
+pub struct U8Wrapper(pub u8);
+impl From<U8Wrapper> for H160 {
+	fn from(x: U8Wrapper) -> H160 {
+		H160::repeat_byte(x.0)
+	}
+}
+impl From<U8Wrapper> for H256 {
+	fn from(x: U8Wrapper) -> H256 {
+		let h160 = H160::repeat_byte(x.0);
+		let mut data = [0u8; 32];
+		data[0..20].copy_from_slice(&h160[..]);
+		data.into()
+	}
+}
+impl From<U8Wrapper> for AccountId {
+	fn from(x: U8Wrapper) -> AccountId {
+		TruncatedAddressMapping::into_account_id(x.into())
+	}
+}
+
+parameter_types! {
+	pub PrecompilesValue: IdentityManagementMockPrecompile<Test> = IdentityManagementMockPrecompile::new();
+	pub WeightPerGas: Weight = Weight::from_parts(1, 0);
+}
+
+impl pallet_evm::Config for Test {
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = TruncatedAddressMapping;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type PrecompilesType = IdentityManagementMockPrecompile<Self>;
+	type PrecompilesValue = PrecompilesValue;
+	type Timestamp = Timestamp;
+	type ChainId = ();
+	type OnChargeTransaction = ();
+	type BlockGasLimit = ();
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type FindAuthor = ();
+	type OnCreate = ();
+	type WeightInfo = ();
+	type GasLimitPovSizeRatio = ConstU64<4>;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+pub fn alice() -> AccountId {
+	U8Wrapper(1u8).into()
+}
+
+pub fn bob() -> AccountId {
+	U8Wrapper(2u8).into()
+}
+
+// the delegatee
+pub fn eddie() -> AccountId {
+	U8Wrapper(5u8).into()
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(alice(), 1_000), (eddie(), 1_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(IdentityManagement::add_delegatee(RuntimeOrigin::root(), eddie()));
+		assert_ok!(IdentityManagement::accept_delegatee(RuntimeOrigin::signed(eddie())));
+	});
+	ext
+}
@@ -0,0 +1,206 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use core::str::from_utf8;
+use pallet_identity_management::Event;
+use precompile_utils::testing::*;
+use sp_core::H256;
+
+fn precompiles() -> IdentityManagementMockPrecompile<Test> {
+	PrecompilesValue::get()
+}
+
+fn account_topic(account: AccountId) -> H256 {
+	H256::from(Into::<[u8; 32]>::into(account))
+}
+
+#[test]
+fn link_identity_works() {
+	new_test_ext().execute_with(|| {
+		let shard = H256::from_low_u64_be(1);
+		let event_data = solidity::encode_event_data((
+			shard,
+			UnboundedBytes::from(vec![1u8]),
+			UnboundedBytes::from(vec![2u8]),
+			UnboundedBytes::from(vec![3u8]),
+		));
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::link_identity {
+					shard,
+					user: account_topic(alice()),
+					encrypted_identity: vec![1u8].into(),
+					encrypted_validation_data: vec![2u8].into(),
+					encrypted_web3networks: vec![3u8].into(),
+				},
+			)
+			.expect_log(log2(
+				precompile_address(),
+				SELECTOR_LOG_LINK_IDENTITY_REQUESTED,
+				account_topic(alice()),
+				event_data,
+			))
+			.execute_returns(());
+
+		System::assert_last_event(RuntimeEvent::IdentityManagement(Event::LinkIdentityRequested {
+			shard,
+			account: alice(),
+			encrypted_identity: vec![1u8],
+			encrypted_validation_data: vec![2u8],
+			encrypted_web3networks: vec![3u8],
+		}));
+	});
+}
+
+#[test]
+fn link_identity_by_delegatee_works() {
+	new_test_ext().execute_with(|| {
+		let shard = H256::from_low_u64_be(1);
+		let event_data = solidity::encode_event_data((
+			shard,
+			UnboundedBytes::from(vec![1u8]),
+			UnboundedBytes::from(vec![2u8]),
+			UnboundedBytes::from(vec![3u8]),
+		));
+
+		// eddie is a delegatee and links the identity for bob
+		precompiles()
+			.prepare_test(
+				U8Wrapper(5u8),
+				precompile_address(),
+				PCall::<Test>::link_identity {
+					shard,
+					user: account_topic(bob()),
+					encrypted_identity: vec![1u8].into(),
+					encrypted_validation_data: vec![2u8].into(),
+					encrypted_web3networks: vec![3u8].into(),
+				},
+			)
+			.expect_log(log2(
+				precompile_address(),
+				SELECTOR_LOG_LINK_IDENTITY_REQUESTED,
+				account_topic(bob()),
+				event_data,
+			))
+			.execute_returns(());
+
+		System::assert_last_event(RuntimeEvent::IdentityManagement(Event::LinkIdentityRequested {
+			shard,
+			account: bob(),
+			encrypted_identity: vec![1u8],
+			encrypted_validation_data: vec![2u8],
+			encrypted_web3networks: vec![3u8],
+		}));
+	});
+}
+
+#[test]
+fn link_identity_by_non_delegatee_fails() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::link_identity {
+					shard: H256::from_low_u64_be(1),
+					user: account_topic(bob()),
+					encrypted_identity: vec![1u8].into(),
+					encrypted_validation_data: vec![2u8].into(),
+					encrypted_web3networks: vec![3u8].into(),
+				},
+			)
+			.execute_reverts(|output| from_utf8(output).unwrap().contains("UnauthorizedUser"));
+	});
+}
+
+#[test]
+fn deactivate_and_activate_identity_works() {
+	new_test_ext().execute_with(|| {
+		let shard = H256::from_low_u64_be(1);
+		let event_data = solidity::encode_event_data((shard, UnboundedBytes::from(vec![1u8])));
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::deactivate_identity { shard, encrypted_identity: vec![1u8].into() },
+			)
+			.expect_log(log2(
+				precompile_address(),
+				SELECTOR_LOG_DEACTIVATE_IDENTITY_REQUESTED,
+				account_topic(alice()),
+				event_data.clone(),
+			))
+			.execute_returns(());
+
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			Event::DeactivateIdentityRequested {
+				shard,
+				account: alice(),
+				encrypted_identity: vec![1u8],
+			},
+		));
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::activate_identity { shard, encrypted_identity: vec![1u8].into() },
+			)
+			.expect_log(log2(
+				precompile_address(),
+				SELECTOR_LOG_ACTIVATE_IDENTITY_REQUESTED,
+				account_topic(alice()),
+				event_data,
+			))
+			.execute_returns(());
+
+		System::assert_last_event(RuntimeEvent::IdentityManagement(
+			Event::ActivateIdentityRequested {
+				shard,
+				account: alice(),
+				encrypted_identity: vec![1u8],
+			},
+		));
+	});
+}
+
+#[test]
+fn is_delegatee_works() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::is_delegatee { account: account_topic(eddie()) },
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::is_delegatee { account: account_topic(alice()) },
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
@@ -7,8 +7,6 @@ pragma solidity >=0.8.3;
 /// We follow this same interface including four-byte function selectors, in the precompile that
 /// wraps the pallet
 interface ParachainStaking {
-/* TODO: Only part for delagator is implemented for minimal task purpose 
-
     /// @dev Check whether the specified address is currently a staking delegator
     /// @custom:selector ef9bb4a8
     /// @param delegator the address that we want to confirm is a delegator
@@ -110,7 +108,11 @@ interface ParachainStaking {
     /// @custom:selector bcf868a6
     /// @return The selected candidate accounts
     function selectedCandidates() external view returns (bytes32[] memory);
-TODO: Only part for delagator is implemented for minimal task purpose */
+
+    /// @dev Get the collator candidates in the candidate pool
+    /// @custom:selector 498ea441
+    /// @return The candidate accounts in the candidate pool
+    function candidateList() external view returns (bytes32[] memory);
 
     /// @dev Whether there exists a pending request for a delegation made by a delegator
     /// @custom:selector e40d85c0
@@ -122,7 +124,6 @@ TODO: Only part for delagator is implemented for minimal task purpose */
         bytes32 candidate
     ) external view returns (bool);
 
-/* TODO: Only part for delagator is implemented for minimal task purpose
     /// @dev Whether there exists a pending exit for candidate
     /// @custom:selector 89485bd5
     /// @param candidate the candidate for which the exit request was made
@@ -149,6 +150,7 @@ TODO: Only part for delagator is implemented for minimal task purpose */
         bytes32 candidate
     ) external view returns (uint8);
 
+/* TODO: Only part for delagator is implemented for minimal task purpose
     /// @dev Join the set of collator candidates
     /// @custom:selector 28716aba
     /// @param amount The amount self-bonded by the caller to become a collator candidate
@@ -259,7 +261,6 @@ TODO: Only part for delagator is implemented for minimal task purpose */
         uint8 value
     ) external;
 
-/* TODO: Only part for delagator is implemented for minimal task purpose 
     /// @dev Fetch the total staked amount of a delegator, regardless of the
     /// candidate.
     /// @custom:selector 1f2d4c64
@@ -276,5 +277,4 @@ TODO: Only part for delagator is implemented for minimal task purpose */
     function getCandidateTotalCounted(
         bytes32 candidate
     ) external view returns (uint256);
-TODO: Only part for delagator is implemented for minimal task purpose */
 }
@@ -30,7 +30,7 @@ use pallet_evm::AddressMapping;
 use precompile_utils::{prelude::*, EvmResult};
 use sp_core::{H256, U256};
 use sp_runtime::traits::Dispatchable;
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 type BalanceOf<Runtime> = <<Runtime as pallet_parachain_staking::Config>::Currency as Currency<
 	<Runtime as frame_system::Config>::AccountId,
@@ -50,12 +50,264 @@ pub struct ParachainStakingPrecompile<Runtime>(PhantomData<Runtime>);
 impl<Runtime> ParachainStakingPrecompile<Runtime>
 where
 	Runtime: pallet_parachain_staking::Config + pallet_evm::Config,
-	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
 	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
 	Runtime::RuntimeCall: From<pallet_parachain_staking::Call<Runtime>>,
 	BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
 {
+	#[precompile::public("isDelegator(bytes32)")]
+	#[precompile::view]
+	fn is_delegator(handle: &mut impl PrecompileHandle, delegator: H256) -> EvmResult<bool> {
+		let delegator: [u8; 32] = delegator.into();
+		let delegator = Runtime::AccountId::from(delegator);
+
+		handle.record_db_read::<Runtime>(Self::delegator_state_size())?;
+
+		Ok(<pallet_parachain_staking::Pallet<Runtime>>::is_delegator(&delegator))
+	}
+
+	#[precompile::public("isCandidate(bytes32)")]
+	#[precompile::view]
+	fn is_candidate(handle: &mut impl PrecompileHandle, candidate: H256) -> EvmResult<bool> {
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// CandidateInfo: Twox64Concat(8) + AccountId(32) + CandidateMetadata(112)
+		handle.record_db_read::<Runtime>(152)?;
+
+		Ok(<pallet_parachain_staking::Pallet<Runtime>>::is_candidate(&candidate))
+	}
+
+	#[precompile::public("isSelectedCandidate(bytes32)")]
+	#[precompile::view]
+	fn is_selected_candidate(
+		handle: &mut impl PrecompileHandle,
+		candidate: H256,
+	) -> EvmResult<bool> {
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// TotalSelected: u32(4)
+		handle.record_db_read::<Runtime>(4)?;
+		// SelectedCandidates: AccountId(32) * TotalSelected
+		let total_selected = <pallet_parachain_staking::Pallet<Runtime>>::total_selected();
+		handle.record_db_read::<Runtime>(32 * total_selected as usize)?;
+
+		Ok(<pallet_parachain_staking::Pallet<Runtime>>::is_selected_candidate(&candidate))
+	}
+
+	#[precompile::public("points(uint32)")]
+	#[precompile::view]
+	fn points(handle: &mut impl PrecompileHandle, round: u32) -> EvmResult<u32> {
+		// Points: Twox64Concat(8) + RoundIndex(4) + RewardPoint(4)
+		handle.record_db_read::<Runtime>(16)?;
+
+		Ok(<pallet_parachain_staking::Pallet<Runtime>>::points(round))
+	}
+
+	#[precompile::public("awardedPoints(uint32,bytes32)")]
+	#[precompile::view]
+	fn awarded_points(
+		handle: &mut impl PrecompileHandle,
+		round: u32,
+		candidate: H256,
+	) -> EvmResult<u32> {
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// AwardedPts:
+		// Twox64Concat(8) + RoundIndex(4) + Twox64Concat(8) + AccountId(32) + RewardPoint(4)
+		handle.record_db_read::<Runtime>(56)?;
+
+		Ok(<pallet_parachain_staking::Pallet<Runtime>>::awarded_pts(round, &candidate))
+	}
+
+	#[precompile::public("delegationAmount(bytes32,bytes32)")]
+	#[precompile::view]
+	fn delegation_amount(
+		handle: &mut impl PrecompileHandle,
+		delegator: H256,
+		candidate: H256,
+	) -> EvmResult<U256> {
+		let delegator: [u8; 32] = delegator.into();
+		let delegator = Runtime::AccountId::from(delegator);
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		handle.record_db_read::<Runtime>(Self::delegator_state_size())?;
+
+		// Return 0 if the delegation doesn't exist
+		let amount = <pallet_parachain_staking::Pallet<Runtime>>::delegator_state(&delegator)
+			.and_then(|state| state.get_bond_amount(&candidate))
+			.map(Into::into)
+			.unwrap_or_default();
+
+		Ok(amount)
+	}
+
+	#[precompile::public("isInTopDelegations(bytes32,bytes32)")]
+	#[precompile::view]
+	fn is_in_top_delegations(
+		handle: &mut impl PrecompileHandle,
+		delegator: H256,
+		candidate: H256,
+	) -> EvmResult<bool> {
+		let delegator: [u8; 32] = delegator.into();
+		let delegator = Runtime::AccountId::from(delegator);
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// TopDelegations:
+		// Twox64Concat(8) + AccountId(32) + Delegations(48 * MaxTopDelegationsPerCandidate + 16)
+		handle.record_db_read::<Runtime>(
+			56 + 48 *
+				<Runtime as pallet_parachain_staking::Config>::MaxTopDelegationsPerCandidate::get()
+					as usize,
+		)?;
+
+		let is_in_top_delegations =
+			<pallet_parachain_staking::Pallet<Runtime>>::top_delegations(&candidate)
+				.map(|top| top.delegations.iter().any(|bond| bond.owner == delegator))
+				.unwrap_or_default();
+
+		Ok(is_in_top_delegations)
+	}
+
+	#[precompile::public("minDelegation()")]
+	#[precompile::view]
+	fn min_delegation(_handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		let min_delegation: BalanceOf<Runtime> =
+			<Runtime as pallet_parachain_staking::Config>::MinDelegation::get();
+
+		Ok(min_delegation.into())
+	}
+
+	#[precompile::public("candidateCount()")]
+	#[precompile::view]
+	fn candidate_count(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		let candidate_pool = <pallet_parachain_staking::Pallet<Runtime>>::candidate_pool();
+
+		// CandidatePool is unbounded, so the read is charged by its actual size:
+		// Bond(AccountId(32) + Balance(16)) * candidate count
+		handle.record_db_read::<Runtime>(48 * candidate_pool.0.len())?;
+
+		Ok(candidate_pool.0.len() as u32)
+	}
+
+	#[precompile::public("candidateList()")]
+	#[precompile::view]
+	fn candidate_list(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<H256>> {
+		let candidate_pool = <pallet_parachain_staking::Pallet<Runtime>>::candidate_pool();
+
+		// CandidatePool is unbounded, so the read is charged by its actual size:
+		// Bond(AccountId(32) + Balance(16)) * candidate count
+		handle.record_db_read::<Runtime>(48 * candidate_pool.0.len())?;
+
+		let candidates = candidate_pool
+			.0
+			.into_iter()
+			.map(|bond| H256::from(Into::<[u8; 32]>::into(bond.owner)))
+			.collect();
+
+		Ok(candidates)
+	}
+
+	#[precompile::public("round()")]
+	#[precompile::view]
+	fn round(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		// Round: RoundInfo(RoundIndex(4) + BlockNumber(4) + 4)
+		handle.record_db_read::<Runtime>(12)?;
+
+		Ok(<pallet_parachain_staking::Pallet<Runtime>>::round().current)
+	}
+
+	#[precompile::public("candidateDelegationCount(bytes32)")]
+	#[precompile::view]
+	fn candidate_delegation_count(
+		handle: &mut impl PrecompileHandle,
+		candidate: H256,
+	) -> EvmResult<u32> {
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// CandidateInfo: Twox64Concat(8) + AccountId(32) + CandidateMetadata(112)
+		handle.record_db_read::<Runtime>(152)?;
+
+		// Return 0 if the candidate doesn't exist
+		let count = <pallet_parachain_staking::Pallet<Runtime>>::candidate_info(&candidate)
+			.map(|info| info.delegation_count)
+			.unwrap_or_default();
+
+		Ok(count)
+	}
+
+	#[precompile::public("candidateAutoCompoundingDelegationCount(bytes32)")]
+	#[precompile::view]
+	fn candidate_auto_compounding_delegation_count(
+		handle: &mut impl PrecompileHandle,
+		candidate: H256,
+	) -> EvmResult<u32> {
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// AutoCompoundingDelegations:
+		// Blake2128(16) + AccountId(32)
+		// + Vec(
+		// 	AutoCompoundConfig(AccountId(32) + Percent(1))
+		// 	* (MaxTopDelegationsPerCandidate + MaxBottomDelegationsPerCandidate)
+		// )
+		handle.record_db_read::<Runtime>(
+			48 + (
+				33 * (<Runtime as pallet_parachain_staking::Config>::MaxTopDelegationsPerCandidate::get()
+				+ <Runtime as pallet_parachain_staking::Config>::MaxBottomDelegationsPerCandidate::get())
+				as usize),
+		)?;
+
+		let count =
+			<pallet_parachain_staking::Pallet<Runtime>>::auto_compounding_delegations(&candidate)
+				.len() as u32;
+
+		Ok(count)
+	}
+
+	#[precompile::public("delegatorDelegationCount(bytes32)")]
+	#[precompile::view]
+	fn delegator_delegation_count(
+		handle: &mut impl PrecompileHandle,
+		delegator: H256,
+	) -> EvmResult<u32> {
+		let delegator: [u8; 32] = delegator.into();
+		let delegator = Runtime::AccountId::from(delegator);
+
+		handle.record_db_read::<Runtime>(Self::delegator_state_size())?;
+
+		// Return 0 if the delegator doesn't exist
+		let count = <pallet_parachain_staking::Pallet<Runtime>>::delegator_state(&delegator)
+			.map(|state| state.delegations.0.len() as u32)
+			.unwrap_or_default();
+
+		Ok(count)
+	}
+
+	#[precompile::public("selectedCandidates()")]
+	#[precompile::view]
+	fn selected_candidates(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<H256>> {
+		// TotalSelected: u32(4)
+		handle.record_db_read::<Runtime>(4)?;
+		// SelectedCandidates: AccountId(32) * TotalSelected
+		let total_selected = <pallet_parachain_staking::Pallet<Runtime>>::total_selected();
+		handle.record_db_read::<Runtime>(32 * total_selected as usize)?;
+
+		let selected_candidates =
+			<pallet_parachain_staking::Pallet<Runtime>>::selected_candidates()
+				.into_iter()
+				.map(|account| H256::from(Into::<[u8; 32]>::into(account)))
+				.collect();
+
+		Ok(selected_candidates)
+	}
+
 	#[precompile::public("delegationRequestIsPending(bytes32,bytes32)")]
 	#[precompile::view]
 	fn delegation_request_is_pending(
@@ -90,6 +342,117 @@ where
 		Ok(pending)
 	}
 
+	#[precompile::public("candidateExitIsPending(bytes32)")]
+	#[precompile::view]
+	fn candidate_exit_is_pending(
+		handle: &mut impl PrecompileHandle,
+		candidate: H256,
+	) -> EvmResult<bool> {
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// CandidateInfo: Twox64Concat(8) + AccountId(32) + CandidateMetadata(112)
+		handle.record_db_read::<Runtime>(152)?;
+
+		// If we are not able to get candidate metadata, we return false
+		// Users can call `is_candidate` to determine when this happens
+		let pending = <pallet_parachain_staking::Pallet<Runtime>>::candidate_info(&candidate)
+			.map(|info| info.is_leaving())
+			.unwrap_or_default();
+
+		Ok(pending)
+	}
+
+	#[precompile::public("candidateRequestIsPending(bytes32)")]
+	#[precompile::view]
+	fn candidate_request_is_pending(
+		handle: &mut impl PrecompileHandle,
+		candidate: H256,
+	) -> EvmResult<bool> {
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// CandidateInfo: Twox64Concat(8) + AccountId(32) + CandidateMetadata(112)
+		handle.record_db_read::<Runtime>(152)?;
+
+		// If we are not able to get candidate metadata, we return false
+		// Users can call `is_candidate` to determine when this happens
+		let pending = <pallet_parachain_staking::Pallet<Runtime>>::candidate_info(&candidate)
+			.map(|info| info.request.is_some())
+			.unwrap_or_default();
+
+		Ok(pending)
+	}
+
+	#[precompile::public("delegationAutoCompound(bytes32,bytes32)")]
+	#[precompile::view]
+	fn delegation_auto_compound(
+		handle: &mut impl PrecompileHandle,
+		delegator: H256,
+		candidate: H256,
+	) -> EvmResult<u8> {
+		let delegator: [u8; 32] = delegator.into();
+		let delegator = Runtime::AccountId::from(delegator);
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// AutoCompoundingDelegations:
+		// Blake2128(16) + AccountId(32)
+		// + Vec(
+		// 	AutoCompoundConfig(AccountId(32) + Percent(1))
+		// 	* (MaxTopDelegationsPerCandidate + MaxBottomDelegationsPerCandidate)
+		// )
+		handle.record_db_read::<Runtime>(
+			48 + (
+				33 * (<Runtime as pallet_parachain_staking::Config>::MaxTopDelegationsPerCandidate::get()
+				+ <Runtime as pallet_parachain_staking::Config>::MaxBottomDelegationsPerCandidate::get())
+				as usize),
+		)?;
+
+		let value = <pallet_parachain_staking::Pallet<Runtime>>::delegation_auto_compound(
+			&candidate, &delegator,
+		);
+
+		Ok(value.deconstruct())
+	}
+
+	#[precompile::public("getDelegatorTotalStaked(bytes32)")]
+	#[precompile::view]
+	fn get_delegator_total_staked(
+		handle: &mut impl PrecompileHandle,
+		delegator: H256,
+	) -> EvmResult<U256> {
+		let delegator: [u8; 32] = delegator.into();
+		let delegator = Runtime::AccountId::from(delegator);
+
+		handle.record_db_read::<Runtime>(Self::delegator_state_size())?;
+
+		let amount = <pallet_parachain_staking::Pallet<Runtime>>::delegator_state(&delegator)
+			.map(|state| state.total.into())
+			.unwrap_or_default();
+
+		Ok(amount)
+	}
+
+	#[precompile::public("getCandidateTotalCounted(bytes32)")]
+	#[precompile::view]
+	fn get_candidate_total_counted(
+		handle: &mut impl PrecompileHandle,
+		candidate: H256,
+	) -> EvmResult<U256> {
+		let candidate: [u8; 32] = candidate.into();
+		let candidate = Runtime::AccountId::from(candidate);
+
+		// CandidateInfo: Twox64Concat(8) + AccountId(32) + CandidateMetadata(112)
+		handle.record_db_read::<Runtime>(152)?;
+
+		let amount = <pallet_parachain_staking::Pallet<Runtime>>::candidate_info(&candidate)
+			.map(|info| info.total_counted.into())
+			.unwrap_or_default();
+
+		Ok(amount)
+	}
+
 	#[precompile::public("delegate(bytes32,uint256)")]
 	fn delegate(handle: &mut impl PrecompileHandle, candidate: H256, amount: U256) -> EvmResult {
 		let candidate: [u8; 32] = candidate.into();
@@ -277,3 +640,17 @@ where
 		Ok(())
 	}
 }
+
+impl<Runtime> ParachainStakingPrecompile<Runtime>
+where
+	Runtime: pallet_parachain_staking::Config,
+{
+	/// DelegatorState:
+	/// Twox64Concat(8) + AccountId(32)
+	/// + Delegator(AccountId(32) + Bond(48) * MaxDelegationsPerDelegator + 2 * Balance(16) + 5)
+	fn delegator_state_size() -> usize {
+		109 + 48 *
+			<Runtime as pallet_parachain_staking::Config>::MaxDelegationsPerDelegator::get()
+				as usize
+	}
+}
@@ -148,6 +148,7 @@ impl pallet_parachain_staking::Config for Test {
 	type OnNewRound = ();
 	type WeightInfo = ();
 	type IssuanceAdapter = ();
+	type OnSlash = ();
 }
 
 pub fn precompile_address() -> H160 {
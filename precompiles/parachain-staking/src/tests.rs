@@ -19,6 +19,7 @@ use crate::{
 	mock::{RuntimeEvent as MetaEvent, *},
 	*,
 };
+use frame_support::assert_ok;
 use precompile_utils::testing::*;
 
 fn precompiles() -> ParachainStakingMockPrecompile<Test> {
@@ -119,3 +120,253 @@ fn delegation_request_is_pending_works() {
 				.execute_returns(true);
 		})
 }
+
+#[test]
+fn candidate_and_delegator_views_work() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(U8Wrapper(1).into(), 10_000),
+			(U8Wrapper(2).into(), 500),
+			(U8Wrapper(3).into(), 500),
+		])
+		.with_candidates(vec![(U8Wrapper(1).into(), 1_000)])
+		.with_delegations(vec![(U8Wrapper(2).into(), U8Wrapper(1).into(), 50)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::is_candidate { candidate: H256::from(U8Wrapper(1u8)) },
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::is_selected_candidate { candidate: H256::from(U8Wrapper(1u8)) },
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::is_delegator { delegator: H256::from(U8Wrapper(2u8)) },
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::is_delegator { delegator: H256::from(U8Wrapper(3u8)) },
+				)
+				.expect_no_logs()
+				.execute_returns(false);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::delegation_amount {
+						delegator: H256::from(U8Wrapper(2u8)),
+						candidate: H256::from(U8Wrapper(1u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(50u128));
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::is_in_top_delegations {
+						delegator: H256::from(U8Wrapper(2u8)),
+						candidate: H256::from(U8Wrapper(1u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::candidate_delegation_count {
+						candidate: H256::from(U8Wrapper(1u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(1u32);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::delegator_delegation_count {
+						delegator: H256::from(U8Wrapper(2u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(1u32);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::get_delegator_total_staked {
+						delegator: H256::from(U8Wrapper(2u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(50u128));
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::get_candidate_total_counted {
+						candidate: H256::from(U8Wrapper(1u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(1_050u128));
+		})
+}
+
+#[test]
+fn round_and_candidate_list_views_work() {
+	ExtBuilder::default()
+		.with_balances(vec![(U8Wrapper(1).into(), 10_000), (U8Wrapper(2).into(), 10_000)])
+		.with_candidates(vec![(U8Wrapper(1).into(), 1_000), (U8Wrapper(2).into(), 500)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(U8Wrapper(3u8), precompile_address(), PCall::<Test>::round {})
+				.expect_no_logs()
+				.execute_returns(1u32);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::candidate_count {},
+				)
+				.expect_no_logs()
+				.execute_returns(2u32);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::candidate_list {},
+				)
+				.expect_no_logs()
+				.execute_returns(vec![H256::from(U8Wrapper(1u8)), H256::from(U8Wrapper(2u8))]);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::selected_candidates {},
+				)
+				.expect_no_logs()
+				.execute_returns(vec![H256::from(U8Wrapper(1u8)), H256::from(U8Wrapper(2u8))]);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::min_delegation {},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(3u128));
+		})
+}
+
+#[test]
+fn auto_compound_and_pending_request_views_work() {
+	ExtBuilder::default()
+		.with_balances(vec![(U8Wrapper(1).into(), 10_000), (U8Wrapper(2).into(), 500)])
+		.with_candidates(vec![(U8Wrapper(1).into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					U8Wrapper(2u8),
+					precompile_address(),
+					PCall::<Test>::delegate_with_auto_compound {
+						candidate: H256::from(U8Wrapper(1u8)),
+						amount: 10u128.into(),
+						auto_compound: 50u8,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::delegation_auto_compound {
+						delegator: H256::from(U8Wrapper(2u8)),
+						candidate: H256::from(U8Wrapper(1u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(50u8);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::candidate_auto_compounding_delegation_count {
+						candidate: H256::from(U8Wrapper(1u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(1u32);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::candidate_exit_is_pending {
+						candidate: H256::from(U8Wrapper(1u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(false);
+
+			assert_ok!(ParachainStaking::schedule_leave_candidates(RuntimeOrigin::signed(
+				U8Wrapper(1u8).into()
+			)));
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::candidate_exit_is_pending {
+						candidate: H256::from(U8Wrapper(1u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(3u8),
+					precompile_address(),
+					PCall::<Test>::candidate_request_is_pending {
+						candidate: H256::from(U8Wrapper(1u8)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(false);
+		})
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @author The Litentry Team
+/// @title Pallet VC Management Interface
+/// @dev The interface through which solidity contracts will interact with VC Management
+/// We follow this same interface including four-byte function selectors, in the precompile that
+/// wraps the pallet
+interface VCManagement {
+    /// @dev Emitted when a VC is requested, mirrors the pallet event
+    event VCRequested(bytes32 indexed account, bytes32 shard, bytes assertion);
+
+    /// @dev Check whether the specified account is a delegatee
+    /// @custom:selector fc37bef6
+    /// @param account the account that we want to confirm is a delegatee
+    /// @return A boolean confirming whether the account is a delegatee
+    function isDelegatee(bytes32 account) external view returns (bool);
+
+    /// @dev Request a VC for the caller
+    /// @custom:selector 1777a244
+    /// @param shard the shard of the TEE worker
+    /// @param assertion the SCALE-encoded assertion
+    function requestVc(bytes32 shard, bytes calldata assertion) external;
+}
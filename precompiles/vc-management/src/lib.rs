@@ -0,0 +1,87 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+use core_primitives::Assertion;
+use fp_evm::{PrecompileFailure, PrecompileHandle};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::DecodeAll;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the VCRequested log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_VC_REQUESTED: [u8; 32] = keccak256!("VCRequested(bytes32,bytes32,bytes)");
+
+/// A precompile to wrap the VC requests of pallet_vc_management.
+///
+/// The assertion is passed as SCALE-encoded bytes, as it's a nested enum which can't be expressed
+/// in solidity types. The emitted log mirrors the `VCRequested` pallet event.
+pub struct VCManagementPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> VCManagementPrecompile<Runtime>
+where
+	Runtime: pallet_vc_management::Config + pallet_evm::Config,
+	Runtime::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_vc_management::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+{
+	#[precompile::public("isDelegatee(bytes32)")]
+	#[precompile::view]
+	fn is_delegatee(handle: &mut impl PrecompileHandle, account: H256) -> EvmResult<bool> {
+		let account: [u8; 32] = account.into();
+		let account = Runtime::AccountId::from(account);
+
+		// Delegatee: Blake2128(16) + AccountId(32)
+		handle.record_db_read::<Runtime>(48)?;
+
+		Ok(pallet_vc_management::Delegatee::<Runtime>::contains_key(&account))
+	}
+
+	#[precompile::public("requestVc(bytes32,bytes)")]
+	fn request_vc(
+		handle: &mut impl PrecompileHandle,
+		shard: H256,
+		assertion: UnboundedBytes,
+	) -> EvmResult {
+		let event_data = solidity::encode_event_data((shard, assertion.clone()));
+		handle.record_log_costs_manual(2, event_data.len())?;
+
+		let assertion: Vec<u8> = assertion.into();
+		let assertion = Assertion::decode_all(&mut assertion.as_slice()).map_err(|_| {
+			Into::<PrecompileFailure>::into(RevertReason::custom("Invalid assertion"))
+		})?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let account = H256::from(Into::<[u8; 32]>::into(origin.clone()));
+		let call = pallet_vc_management::Call::<Runtime>::request_vc { shard, assertion };
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log2(handle.context().address, SELECTOR_LOG_VC_REQUESTED, account, event_data)
+			.record(handle)?;
+
+		Ok(())
+	}
+}
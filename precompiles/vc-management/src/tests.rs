@@ -0,0 +1,116 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use core::str::from_utf8;
+use core_primitives::Assertion;
+use pallet_vc_management::Event;
+use parity_scale_codec::Encode;
+use precompile_utils::testing::*;
+use sp_core::H256;
+
+fn precompiles() -> VCManagementMockPrecompile<Test> {
+	PrecompilesValue::get()
+}
+
+fn account_topic(account: AccountId) -> H256 {
+	H256::from(Into::<[u8; 32]>::into(account))
+}
+
+#[test]
+fn request_vc_works() {
+	new_test_ext().execute_with(|| {
+		let shard = H256::from_low_u64_be(1);
+		let assertion = Assertion::A1.encode();
+		let event_data =
+			solidity::encode_event_data((shard, UnboundedBytes::from(assertion.clone())));
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::request_vc { shard, assertion: assertion.into() },
+			)
+			.expect_log(log2(
+				precompile_address(),
+				SELECTOR_LOG_VC_REQUESTED,
+				account_topic(alice()),
+				event_data,
+			))
+			.execute_returns(());
+
+		System::assert_last_event(RuntimeEvent::VCManagement(Event::VCRequested {
+			account: alice(),
+			shard,
+			assertion: Assertion::A1,
+		}));
+	});
+}
+
+#[test]
+fn request_vc_with_invalid_assertion_fails() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::request_vc {
+					shard: H256::from_low_u64_be(1),
+					assertion: vec![255u8, 1, 2].into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Invalid assertion");
+	});
+}
+
+#[test]
+fn request_a13_by_non_delegatee_fails() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::request_vc {
+					shard: H256::from_low_u64_be(1),
+					assertion: Assertion::A13(bob()).encode().into(),
+				},
+			)
+			.execute_reverts(|output| from_utf8(output).unwrap().contains("UnauthorizedUser"));
+	});
+}
+
+#[test]
+fn is_delegatee_works() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::is_delegatee { account: account_topic(eddie()) },
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				PCall::<Test>::is_delegatee { account: account_topic(alice()) },
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+	});
+}
@@ -0,0 +1,51 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// What a bitacross relayer is allowed to request from the tee workers.
+/// Any relayer can perform check runs, as they never return a signature.
+#[derive(Encode, Decode, Copy, Clone, Default, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum RelayerRole {
+	// all kinds of signing requests
+	#[codec(index = 0)]
+	#[default]
+	Full,
+	#[codec(index = 1)]
+	BtcOnly,
+	#[codec(index = 2)]
+	EthOnly,
+	#[codec(index = 3)]
+	SolOnly,
+	// check runs only
+	#[codec(index = 4)]
+	ReadOnly,
+}
+
+impl RelayerRole {
+	pub fn can_sign_btc(&self) -> bool {
+		matches!(self, Self::Full | Self::BtcOnly)
+	}
+
+	pub fn can_sign_eth(&self) -> bool {
+		matches!(self, Self::Full | Self::EthOnly)
+	}
+
+	pub fn can_sign_sol(&self) -> bool {
+		matches!(self, Self::Full | Self::SolOnly)
+	}
+}
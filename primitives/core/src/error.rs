@@ -69,6 +69,9 @@ pub enum ErrorDetail {
 	// error when trying to build vc but no eligible identity is found
 	#[codec(index = 11)]
 	NoEligibleIdentity,
+	// error when the last verification of a web2 identity is older than the allowed max age
+	#[codec(index = 12)]
+	VerificationExpired,
 }
 
 impl fmt::Debug for ErrorDetail {
@@ -91,6 +94,7 @@ impl fmt::Debug for ErrorDetail {
 			ErrorDetail::UnexpectedMessage => write!(f, "UnexpectedMessage"),
 			ErrorDetail::VerifyWeb3SignatureFailed => write!(f, "VerifyWeb3SignatureFailed"),
 			ErrorDetail::NoEligibleIdentity => write!(f, "NoEligibleIdentity"),
+			ErrorDetail::VerificationExpired => write!(f, "VerificationExpired"),
 		}
 	}
 }
@@ -135,6 +139,18 @@ pub enum IMPError {
 	// we should classify the error if we ever get this
 	#[codec(index = 3)]
 	UnclassifiedError(ErrorDetail),
+	#[codec(index = 4)]
+	UnlinkIdentityFailed(ErrorDetail),
+	#[codec(index = 5)]
+	MergeIDGraphsFailed(ErrorDetail),
+	#[codec(index = 6)]
+	TransferPrimeIdentityFailed(ErrorDetail),
+	#[codec(index = 7)]
+	RecoveryFailed(ErrorDetail),
+	#[codec(index = 8)]
+	ReverifyIdentityFailed(ErrorDetail),
+	#[codec(index = 9)]
+	SetIdentityLabelFailed(ErrorDetail),
 }
 
 impl frame_support::traits::PalletError for IMPError {
@@ -17,6 +17,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::identity_op)]
 
+mod bitacross;
+pub use bitacross::*;
+
 mod error;
 pub use error::*;
 
@@ -32,7 +32,7 @@ use frame_support::assert_ok;
 use frame_support::{
 	pallet_prelude::DispatchClass,
 	parameter_types, sp_runtime,
-	traits::{Currency, EitherOfDiverse, EnsureOrigin, OnUnbalanced, OriginTrait},
+	traits::{Currency, EitherOfDiverse, EnsureOrigin, IsSubType, OnUnbalanced, OriginTrait},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_SECOND},
 		Weight,
@@ -40,7 +40,12 @@ use frame_support::{
 };
 use frame_system::{limits, EnsureRoot};
 use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
-use sp_runtime::{traits::Bounded, FixedPointNumber, Perbill, Perquintill};
+use parity_scale_codec::{Compact, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Bounded, StaticLookup},
+	FixedPointNumber, Perbill, Perquintill,
+};
 
 use xcm::latest::prelude::*;
 
@@ -368,3 +373,65 @@ where
 		Ok(frame_system::RawOrigin::Signed(signer).into())
 	}
 }
+
+/// Predicate on the call arguments for `pallet_extrinsic_filter`
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub enum CallArgumentPredicate {
+	/// funds are sent to the account
+	Destination(AccountId),
+	/// the asset is transferred or minted
+	Asset(AssetId),
+}
+
+/// Matches the balance and asset transfers against a `CallArgumentPredicate`
+///
+/// The destination is resolved with the runtime `Lookup`, the same way as on dispatch, so any
+/// encoding of the same account is matched.
+pub struct CallArgumentMatcher<R>(PhantomData<R>);
+impl<R>
+	pallet_extrinsic_filter::CallArgumentMatcher<
+		<R as frame_system::Config>::RuntimeCall,
+		CallArgumentPredicate,
+	> for CallArgumentMatcher<R>
+where
+	R: frame_system::Config<AccountId = AccountId>
+		+ pallet_balances::Config
+		+ pallet_assets::Config<AssetIdParameter = Compact<AssetId>>,
+	<R as frame_system::Config>::RuntimeCall:
+		IsSubType<pallet_balances::Call<R>> + IsSubType<pallet_assets::Call<R>>,
+{
+	fn matches(
+		call: &<R as frame_system::Config>::RuntimeCall,
+		predicate: &CallArgumentPredicate,
+	) -> bool {
+		let is_destination =
+			|dest: &<<R as frame_system::Config>::Lookup as StaticLookup>::Source| match predicate {
+				CallArgumentPredicate::Destination(who) =>
+					R::Lookup::lookup(dest.clone()).map_or(false, |dest| &dest == who),
+				_ => false,
+			};
+		let is_asset = |id: &Compact<AssetId>| matches!(predicate, CallArgumentPredicate::Asset(asset) if id.0 == *asset);
+
+		if let Some(call) = IsSubType::<pallet_balances::Call<R>>::is_sub_type(call) {
+			return match call {
+				pallet_balances::Call::transfer { dest, .. } |
+				pallet_balances::Call::transfer_keep_alive { dest, .. } |
+				pallet_balances::Call::transfer_all { dest, .. } |
+				pallet_balances::Call::force_transfer { dest, .. } => is_destination(dest),
+				_ => false,
+			}
+		}
+		if let Some(call) = IsSubType::<pallet_assets::Call<R>>::is_sub_type(call) {
+			return match call {
+				pallet_assets::Call::transfer { id, target, .. } |
+				pallet_assets::Call::transfer_keep_alive { id, target, .. } |
+				pallet_assets::Call::force_transfer { id, dest: target, .. } |
+				pallet_assets::Call::transfer_approved { id, destination: target, .. } |
+				pallet_assets::Call::mint { id, beneficiary: target, .. } =>
+					is_asset(id) || is_destination(target),
+				_ => false,
+			}
+		}
+		false
+	}
+}
@@ -95,8 +95,10 @@ pub trait TestXCMRequirements {
 		+ frame_system::Config<AccountId = AccountId, RuntimeOrigin = Self::ParaOrigin>
 		+ orml_xtokens::Config<Balance = Balance, CurrencyId = CurrencyId<Self::ParaRuntime>>
 		+ pallet_assets::Config<Balance = Balance, AssetId = AssetId>
-		+ pallet_asset_manager::Config<ForeignAssetType = CurrencyId<Self::ParaRuntime>>
-		+ pallet_treasury::Config
+		+ pallet_asset_manager::Config<
+			Balance = Balance,
+			ForeignAssetType = CurrencyId<Self::ParaRuntime>,
+		> + pallet_treasury::Config
 		+ cumulus_pallet_parachain_system::Config;
 	type ParaA: xcm_simulator::TestExt;
 	type ParaB: xcm_simulator::TestExt;
@@ -1295,6 +1297,86 @@ where
 	});
 }
 
+pub fn test_asset_manager_transfer_asset_to_parachain<R: TestXCMRequirements>() {
+	relaychain_parachains_set_up::<R>();
+	let native_fee = u128::from(R::UnitWeightCost::get().ref_time() * 4) * WEIGHT_TO_FEE_FACTOR;
+	R::ParaA::execute_with(|| {
+		assert_ok!(XTokens::<R::ParaRuntime>::transfer(
+			R::ParaOrigin::signed(alice()),
+			CurrencyId::<R::ParaRuntime>::SelfReserve(PhantomData),
+			WEIGHT_TO_FEE_FACTOR * UNIT,
+			Box::new(
+				(Parent, Parachain(2), Junction::AccountId32 { network: None, id: BOB }).into()
+			),
+			xcm_simulator::Limited((R::UnitWeightCost::get().ref_time() * 4).into())
+		));
+	});
+
+	let received =
+		WEIGHT_TO_FEE_FACTOR * UNIT - u128::from(R::UnitWeightCost::get().ref_time() * 4);
+	R::ParaB::execute_with(|| {
+		assert_eq!(Assets::<R::ParaRuntime>::balance(0, &bob()), received);
+
+		// Neither the reserve nor the fee on Para A is known yet
+		assert_noop!(
+			AssetManager::<R::ParaRuntime>::transfer_asset_to_parachain(
+				R::ParaOrigin::signed(bob()),
+				0,
+				WEIGHT_TO_FEE_FACTOR * UNIT * 4 / 10,
+				1,
+				BOB
+			),
+			pallet_asset_manager::Error::<R::ParaRuntime>::AssetReserveNotSet
+		);
+		assert_ok!(AssetManager::<R::ParaRuntime>::set_asset_reserve(
+			RawOrigin::Root.into(),
+			0,
+			Some(Box::new((Parent, Parachain(1)).into()))
+		));
+		assert_noop!(
+			AssetManager::<R::ParaRuntime>::transfer_asset_to_parachain(
+				R::ParaOrigin::signed(bob()),
+				0,
+				WEIGHT_TO_FEE_FACTOR * UNIT * 4 / 10,
+				1,
+				BOB
+			),
+			pallet_asset_manager::Error::<R::ParaRuntime>::DestinationFeeNotSet
+		);
+		assert_ok!(AssetManager::<R::ParaRuntime>::set_asset_destination_fee(
+			RawOrigin::Root.into(),
+			0,
+			Box::new((Parent, Parachain(1)).into()),
+			Some(native_fee)
+		));
+
+		// Send Para A token back to its reserve, Para A's BOB
+		assert_ok!(AssetManager::<R::ParaRuntime>::transfer_asset_to_parachain(
+			R::ParaOrigin::signed(bob()),
+			0,
+			WEIGHT_TO_FEE_FACTOR * UNIT * 4 / 10,
+			1,
+			BOB
+		));
+		assert_eq!(
+			Assets::<R::ParaRuntime>::balance(0, &bob()),
+			received - WEIGHT_TO_FEE_FACTOR * UNIT * 4 / 10
+		);
+	});
+
+	R::ParaA::execute_with(|| {
+		// Execution on Para A is paid out of the transferred amount
+		assert_eq!(
+			Balances::<R::ParaRuntime>::free_balance(&bob()),
+			WEIGHT_TO_FEE_FACTOR * UNIT * 4 / 10 - native_fee
+		);
+		assert_eq!(
+			Balances::<R::ParaRuntime>::free_balance(&sibling_account::<R::LocationToAccountId>(2)),
+			WEIGHT_TO_FEE_FACTOR * UNIT * 6 / 10
+		);
+	});
+}
+
 fn register_channel_info<R: ParaRuntimeRequirements + cumulus_pallet_parachain_system::Config>(
 	self_para_id: u32,
 	remote_para_id: u32,
@@ -1487,5 +1569,11 @@ macro_rules! run_xcm_tests {
 			xcm_parachain::test_pallet_xcm_send_capacity_parachain_manipulation::<XCMRequirements>(
 			);
 		}
+
+		// Registered foreign asset sent back to its trusted reserve by AssetManager
+		#[test]
+		fn test_asset_manager_transfer_asset_to_parachain() {
+			xcm_parachain::test_asset_manager_transfer_asset_to_parachain::<XCMRequirements>();
+		}
 	};
 }
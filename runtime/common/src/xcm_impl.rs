@@ -222,6 +222,22 @@ impl ContainsPair<MultiAsset, MultiLocation> for MultiNativeAsset {
 	}
 }
 
+/// A `FilterAssetLocation` implementation. Filters foreign assets whose trusted
+/// reserve, as registered in `pallet_asset_manager`, is `origin`.
+pub struct TrustedReserve<R>(PhantomData<R>);
+
+impl<R: ParaRuntimeRequirements> ContainsPair<MultiAsset, MultiLocation> for TrustedReserve<R>
+where
+	R: pallet_asset_manager::Config<ForeignAssetType = CurrencyId<R>>,
+{
+	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		if let xcmAssetId::Concrete(location) = asset.id {
+			return AssetManager::<R>::is_trusted_reserve(&CurrencyId::<R>::from(location), origin)
+		}
+		false
+	}
+}
+
 #[derive(Clone, Eq, Debug, PartialEq, Ord, PartialOrd, Encode, Decode, TypeInfo)]
 pub enum CurrencyId4Compare {
 	#[codec(index = 0)]
@@ -1,22 +1,28 @@
 use super::{
 	weights, AccountId, AssetId, Balance, Balances, Runtime, RuntimeEvent, TreasuryPalletId,
 };
-use crate::{constants::currency::deposit, precompiles::ASSET_PRECOMPILE_ADDRESS_PREFIX};
+use crate::{
+	constants::currency::deposit,
+	precompiles::ASSET_PRECOMPILE_ADDRESS_PREFIX,
+	xcm_config::{UnitWeightCost, UniversalLocation, XcmConfig},
+};
 use frame_support::{
 	parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, NeverEnsureOrigin},
+	weights::Weight,
 };
 use frame_system::EnsureRoot;
 use pallet_evm_precompile_assets_erc20::AddressToAssetId;
 use parity_scale_codec::Compact;
 use runtime_common::{
 	currency::{DOLLARS, EXISTENTIAL_DEPOSIT},
-	xcm_impl::CurrencyId,
+	xcm_impl::{AccountIdToMultiLocation, CurrencyId},
 	EnsureRootOrHalfCouncil,
 };
 use sp_core::{ConstU128, H160};
 use sp_runtime::traits::AccountIdConversion;
 use sp_std::prelude::*;
+use xcm_executor::XcmExecutor;
 
 pub fn get_all_module_accounts() -> Vec<AccountId> {
 	// Add whitelist here, usually this is the system account like treasury
@@ -92,6 +98,11 @@ impl pallet_assets::Config for Runtime {
 	type BenchmarkHelper = AssetsBenchmarkHelper;
 }
 
+parameter_types! {
+	/// WithdrawAsset + InitiateReserveWithdraw, with some headroom
+	pub AssetTransferXcmWeight: Weight = UnitWeightCost::get().saturating_mul(4);
+}
+
 impl pallet_asset_manager::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -100,4 +111,8 @@ impl pallet_asset_manager::Config for Runtime {
 	type ForeignAssetModifierOrigin = EnsureRootOrHalfCouncil;
 	type Currency = Balances;
 	type WeightInfo = weights::pallet_asset_manager::WeightInfo<Runtime>;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type UniversalLocation = UniversalLocation;
+	type TransferXcmWeight = AssetTransferXcmWeight;
 }
@@ -160,4 +160,22 @@ impl<T: frame_system::Config> pallet_bridge::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Estimate, not generated by the benchmark CLI: the `set_relayer_fee` benchmark has not
+	// been run yet. The call does one `ChainNonces` read and one `RelayerFees` write, the same
+	// storage access as `whitelist_chain`, so it reuses that weight.
+	fn set_relayer_fee() -> Weight {
+		<Self as pallet_bridge::WeightInfo>::whitelist_chain()
+	}
+	// Estimate, not generated by the benchmark CLI: the `charge_relayer_fee` benchmark has not
+	// been run yet. The figures are hand-picked upper bounds for one balance transfer and one
+	// `RelayerVoteStats` update per paid relayer, plus the transfer of the remainder to the
+	// treasury. Replace them with the CLI output once the benchmark has been run.
+	fn charge_relayer_fee(r: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(50_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+	}
 }
@@ -217,6 +217,17 @@ impl<T: frame_system::Config> pallet_teebag::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: Teebag PckCrlThisUpdate (r:1 w:1)
+	/// Storage: Teebag RevokedPckCertificate (r:0 w:s)
+	/// The range of component `s` is `[0, 1024]`.
+	/// Not generated by the benchmark CLI yet: the signature verification is priced like
+	/// `register_tcb_info` and every cleared or inserted serial is charged as a write.
+	fn register_pck_crl(s: u32, ) -> Weight {
+		<Self as pallet_teebag::WeightInfo>::register_tcb_info()
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+	}
 	fn post_opaque_task() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
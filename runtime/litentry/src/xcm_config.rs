@@ -48,7 +48,7 @@ use runtime_common::{
 	xcm_impl::{
 		AccountIdToMultiLocation, AssetIdMuliLocationConvert, CurrencyId,
 		CurrencyIdMultiLocationConvert, FirstAssetTrader, MultiNativeAsset,
-		NewAnchoringSelfReserve, OldAnchoringSelfReserve, XcmFeesToAccount,
+		NewAnchoringSelfReserve, OldAnchoringSelfReserve, TrustedReserve, XcmFeesToAccount,
 	},
 	EnsureRootOrTwoThirdsCouncil, FilterEnsureOrigin, WEIGHT_TO_FEE_FACTOR,
 };
@@ -216,8 +216,8 @@ impl xcm_executor::Config for XcmConfig {
 	type AssetTransactor = AssetTransactors;
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
 	// Only Allow chains to handle their own reserve assets crossed on local chain whatever way they
-	// want.
-	type IsReserve = MultiNativeAsset;
+	// want, and the foreign assets whose trusted reserve they are registered as in AssetManager.
+	type IsReserve = (MultiNativeAsset, TrustedReserve<Runtime>);
 	type IsTeleporter = (); // Teleporting is disabled.
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barriers;
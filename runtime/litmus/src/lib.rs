@@ -70,11 +70,12 @@ pub use core_primitives::{
 pub use runtime_common::currency::*;
 use runtime_common::{
 	impl_runtime_transaction_payment_fees, prod_or_fast, BlockHashCount, BlockLength,
-	CouncilInstance, CouncilMembershipInstance, EnsureRootOrAllCouncil,
-	EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfCouncil, EnsureRootOrHalfTechnicalCommittee,
-	EnsureRootOrTwoThirdsCouncil, EnsureRootOrTwoThirdsTechnicalCommittee, NegativeImbalance,
-	RuntimeBlockWeights, SlowAdjustingFeeUpdate, TechnicalCommitteeInstance,
-	TechnicalCommitteeMembershipInstance, MAXIMUM_BLOCK_WEIGHT, WEIGHT_TO_FEE_FACTOR,
+	CallArgumentMatcher, CallArgumentPredicate, CouncilInstance, CouncilMembershipInstance,
+	EnsureRootOrAllCouncil, EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfCouncil,
+	EnsureRootOrHalfTechnicalCommittee, EnsureRootOrTwoThirdsCouncil,
+	EnsureRootOrTwoThirdsTechnicalCommittee, NegativeImbalance, RuntimeBlockWeights,
+	SlowAdjustingFeeUpdate, TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance,
+	MAXIMUM_BLOCK_WEIGHT, WEIGHT_TO_FEE_FACTOR,
 };
 use xcm_config::{XcmConfig, XcmOriginToTransactDispatchOrigin};
 
@@ -729,6 +730,8 @@ impl pallet_vesting::Config for Runtime {
 parameter_types! {
 	pub const BridgeChainId: u8 = 1;
 	pub const ProposalLifetime: BlockNumber = 50400; // ~7 days
+	pub const CompletedProposalLifetime: BlockNumber = 4 * 50400; // ~28 days
+	pub const MaxRelayers: u32 = 16;
 	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
 }
 
@@ -739,6 +742,10 @@ impl pallet_bridge::Config for Runtime {
 	type BridgeChainId = BridgeChainId;
 	type Balance = Balance;
 	type ProposalLifetime = ProposalLifetime;
+	type CompletedProposalLifetime = CompletedProposalLifetime;
+	type MaxRelayers = MaxRelayers;
+	type Currency = Balances;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = weights::pallet_bridge::WeightInfo<Runtime>;
 }
 
@@ -784,11 +791,16 @@ impl pallet_assets_handler::Config for Runtime {
 
 impl pallet_extrinsic_filter::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type UpdateOrigin = EnsureRootOrHalfTechnicalCommittee;
 	type NormalModeFilter = NormalModeFilter;
 	type SafeModeFilter = SafeModeFilter;
 	type TestModeFilter = Everything;
 	type WeightInfo = weights::pallet_extrinsic_filter::WeightInfo<Runtime>;
+	type MaxExpiringExtrinsicsPerBlock = ConstU32<64>;
+	type ArgumentPredicate = CallArgumentPredicate;
+	type ArgumentMatcher = CallArgumentMatcher<Runtime>;
+	type MaxArgumentPredicates = ConstU32<16>;
 }
 
 parameter_types! {
@@ -930,6 +942,8 @@ impl Contains<RuntimeCall> for NormalModeFilter {
 			RuntimeCall::BridgeTransfer(_) |
 			// XTokens::transfer for normal users
 			RuntimeCall::XTokens(orml_xtokens::Call::transfer { .. }) |
+			// AssetManager::transfer_asset_to_parachain for normal users
+			RuntimeCall::AssetManager(pallet_asset_manager::Call::transfer_asset_to_parachain { .. }) |
 			// PolkadotXcm
 			RuntimeCall::PolkadotXcm(_) |
 			// memberships
@@ -123,4 +123,47 @@ impl<T: frame_system::Config> pallet_asset_manager::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdReserve (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetIdReserve (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_reserve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `302`
+		//  Estimated: `3767`
+		// Minimum execution time: 19_702_000 picoseconds.
+		Weight::from_parts(20_214_000, 0)
+			.saturating_add(Weight::from_parts(0, 3767))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdDestinationFee (r:0 w:1)
+	/// Proof Skipped: AssetManager AssetIdDestinationFee (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_destination_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `302`
+		//  Estimated: `3767`
+		// Minimum execution time: 20_388_000 picoseconds.
+		Weight::from_parts(20_931_000, 0)
+			.saturating_add(Weight::from_parts(0, 3767))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetManager AssetIdType (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdType (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdReserve (r:1 w:0)
+	/// Proof Skipped: AssetManager AssetIdReserve (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetManager AssetIdDestinationFee (r:2 w:0)
+	/// Proof Skipped: AssetManager AssetIdDestinationFee (max_values: None, max_size: None, mode: Measured)
+	fn transfer_asset_to_parachain() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `6438`
+		// Minimum execution time: 27_540_000 picoseconds.
+		Weight::from_parts(28_106_000, 0)
+			.saturating_add(Weight::from_parts(0, 6438))
+			.saturating_add(T::DbWeight::get().reads(4))
+	}
 }
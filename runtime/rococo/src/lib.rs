@@ -74,8 +74,8 @@ pub use runtime_common::currency::*;
 
 use runtime_common::{
 	impl_runtime_transaction_payment_fees, prod_or_fast, BlockHashCount, BlockLength,
-	CouncilInstance, CouncilMembershipInstance, DeveloperCommitteeInstance,
-	DeveloperCommitteeMembershipInstance, EnsureRootOrAllCouncil,
+	CallArgumentMatcher, CallArgumentPredicate, CouncilInstance, CouncilMembershipInstance,
+	DeveloperCommitteeInstance, DeveloperCommitteeMembershipInstance, EnsureRootOrAllCouncil,
 	EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfCouncil, EnsureRootOrHalfTechnicalCommittee,
 	EnsureRootOrTwoThirdsCouncil, EnsureRootOrTwoThirdsTechnicalCommittee,
 	IMPExtrinsicWhitelistInstance, NegativeImbalance, RuntimeBlockWeights, SlowAdjustingFeeUpdate,
@@ -521,7 +521,8 @@ parameter_types! {
 impl pallet_democracy::Config for Runtime {
 	type Preimages = Preimage;
 	type RuntimeEvent = RuntimeEvent;
-	type Currency = Balances;
+	// delegations and candidate bonds count toward the voting balance
+	type Currency = pallet_parachain_staking::StakedVotingCurrency<Runtime>;
 	type EnactmentPeriod = EnactmentPeriod;
 	type LaunchPeriod = LaunchPeriod;
 	type VotingPeriod = VotingPeriod;
@@ -905,6 +906,7 @@ impl pallet_parachain_staking::Config for Runtime {
 	type OnNewRound = ();
 	type WeightInfo = weights::pallet_parachain_staking::WeightInfo<Runtime>;
 	type IssuanceAdapter = AssetsHandler;
+	type OnSlash = Treasury;
 }
 
 parameter_types! {
@@ -928,6 +930,8 @@ impl pallet_vesting::Config for Runtime {
 parameter_types! {
 	pub const BridgeChainId: u8 = 3;
 	pub const ProposalLifetime: BlockNumber = 50400; // ~7 days
+	pub const CompletedProposalLifetime: BlockNumber = 4 * 50400; // ~28 days
+	pub const MaxRelayers: u32 = 16;
 	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
 }
 
@@ -938,6 +942,10 @@ impl pallet_bridge::Config for Runtime {
 	type BridgeChainId = BridgeChainId;
 	type Balance = Balance;
 	type ProposalLifetime = ProposalLifetime;
+	type CompletedProposalLifetime = CompletedProposalLifetime;
+	type MaxRelayers = MaxRelayers;
+	type Currency = Balances;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = weights::pallet_bridge::WeightInfo<Runtime>;
 }
 
@@ -982,11 +990,16 @@ impl pallet_assets_handler::Config for Runtime {
 
 impl pallet_extrinsic_filter::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type UpdateOrigin = EnsureRootOrHalfTechnicalCommittee;
 	type NormalModeFilter = NormalModeFilter;
 	type SafeModeFilter = SafeModeFilter;
 	type TestModeFilter = Everything;
 	type WeightInfo = weights::pallet_extrinsic_filter::WeightInfo<Runtime>;
+	type MaxExpiringExtrinsicsPerBlock = ConstU32<64>;
+	type ArgumentPredicate = CallArgumentPredicate;
+	type ArgumentMatcher = CallArgumentMatcher<Runtime>;
+	type MaxArgumentPredicates = ConstU32<16>;
 }
 
 parameter_types! {
@@ -1002,6 +1015,12 @@ impl pallet_teebag::Config for Runtime {
 	type WeightInfo = weights::pallet_teebag::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const OIDCClientDepositBase: Balance = deposit(1, 64);
+	pub const OIDCClientDepositPerByte: Balance = deposit(0, 1);
+	pub const DelegateeDeposit: Balance = deposit(1, 64);
+}
+
 impl pallet_identity_management::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
@@ -1009,6 +1028,10 @@ impl pallet_identity_management::Config for Runtime {
 	type DelegateeAdminOrigin = EnsureRootOrAllCouncil;
 	type ExtrinsicWhitelistOrigin = IMPExtrinsicWhitelist;
 	type MaxOIDCClientRedirectUris = ConstU32<10>;
+	type Currency = Balances;
+	type OIDCClientDepositBase = OIDCClientDepositBase;
+	type OIDCClientDepositPerByte = OIDCClientDepositPerByte;
+	type DelegateeDeposit = DelegateeDeposit;
 }
 
 impl pallet_bitacross::Config for Runtime {
@@ -1036,6 +1059,8 @@ impl pallet_vc_management::Config for Runtime {
 	type SetAdminOrigin = EnsureRootOrHalfCouncil;
 	type DelegateeAdminOrigin = EnsureRootOrAllCouncil;
 	type ExtrinsicWhitelistOrigin = VCMPExtrinsicWhitelist;
+	type Currency = Balances;
+	type DelegateeDeposit = DelegateeDeposit;
 }
 
 impl pallet_group::Config<VCMPExtrinsicWhitelistInstance> for Runtime {
@@ -1188,6 +1213,20 @@ impl pallet_score_staking::Config for Runtime {
 	type MaxScoreUserCount = ConstU32<1_000_000>;
 }
 
+parameter_types! {
+	pub const LiquidStakingPalletId: PalletId = PalletId(*b"py/lqstk");
+}
+
+impl pallet_liquid_staking::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Assets = Assets;
+	type PalletId = LiquidStakingPalletId;
+	type MaxCandidates = ConstU32<16>;
+	type MaxUnbondingChunks = ConstU32<32>;
+	type AdminOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = weights::pallet_liquid_staking::WeightInfo<Runtime>;
+}
+
 impl runtime_common::BaseRuntimeRequirements for Runtime {}
 impl runtime_common::ParaRuntimeRequirements for Runtime {}
 
@@ -1270,6 +1309,7 @@ construct_runtime! {
 		DeveloperCommittee: pallet_collective::<Instance3> = 73,
 		DeveloperCommitteeMembership: pallet_membership::<Instance3> = 74,
 		ScoreStaking: pallet_score_staking = 75,
+		LiquidStaking: pallet_liquid_staking = 77,
 
 		// New Bridge Added
 		AssetsHandler: pallet_assets_handler = 76,
@@ -1334,6 +1374,8 @@ impl Contains<RuntimeCall> for NormalModeFilter {
 			RuntimeCall::BridgeTransfer(_) |
 			// XTokens::transfer for normal users
 			RuntimeCall::XTokens(orml_xtokens::Call::transfer { .. }) |
+			// AssetManager::transfer_asset_to_parachain for normal users
+			RuntimeCall::AssetManager(pallet_asset_manager::Call::transfer_asset_to_parachain { .. }) |
 			// collective
 			RuntimeCall::DeveloperCommittee(_) |
 			// memberships
@@ -1379,7 +1421,8 @@ impl Contains<RuntimeCall> for NormalModeFilter {
 			RuntimeCall::AccountFix(_) |
 			RuntimeCall::Bitacross(_) |
 			RuntimeCall::EvmAssertions(_) |
-			RuntimeCall::ScoreStaking(_)
+			RuntimeCall::ScoreStaking(_) |
+			RuntimeCall::LiquidStaking(_)
 		)
 	}
 }
@@ -1404,6 +1447,7 @@ mod benches {
 		[pallet_preimage, Preimage]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_parachain_staking, ParachainStaking]
+		[pallet_liquid_staking, LiquidStaking]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_identity_management, IdentityManagement]
 		[pallet_vc_management, VCManagement]
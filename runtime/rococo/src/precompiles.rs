@@ -29,11 +29,13 @@ use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
 use pallet_evm_precompile_bridge_transfer::BridgeTransferPrecompile;
 use pallet_evm_precompile_dispatch::{Dispatch, DispatchValidateT};
 use pallet_evm_precompile_ed25519::Ed25519Verify;
+use pallet_evm_precompile_identity_management::IdentityManagementPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_parachain_staking::ParachainStakingPrecompile;
 use pallet_evm_precompile_score_staking::ScoreStakingPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_vc_management::VCManagementPrecompile;
 use precompile_utils::precompile_set::*;
 use sp_std::fmt::Debug;
 
@@ -128,6 +130,18 @@ pub type PrecompilesSetAt<R> = (
 		BridgeTransferPrecompile<R>,
 		(CallableByContract, CallableByPrecompile),
 	>,
+	// IdentityManagement: pallet_identity_management = 64 + 20480
+	PrecompileAt<
+		AddressU64<20544>,
+		IdentityManagementPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	// VCManagement: pallet_vc_management = 66 + 20480
+	PrecompileAt<
+		AddressU64<20546>,
+		VCManagementPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
 	// ScoreStaking: pallet_score_staking = 75 + 20480
 	PrecompileAt<
 		AddressU64<20555>,
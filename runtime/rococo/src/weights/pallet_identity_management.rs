@@ -72,6 +72,14 @@ impl<T: frame_system::Config> pallet_identity_management::WeightInfo for WeightI
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// The delegatee deposit isn't covered by the generated `add_delegatee` and `remove_delegatee`
+	// weights, they have to be regenerated together with `accept_delegatee`. Until then
+	// `accept_delegatee` is estimated as `remove_delegatee` plus the reserve and the
+	// `PendingDelegatees` and `DelegateeDeposits` accesses.
+	fn accept_delegatee() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::remove_delegatee()
+			.saturating_add(T::DbWeight::get().reads_writes(3_u64, 3_u64))
+	}
 	/// Storage: IMPExtrinsicWhitelist GroupControlOn (r:1 w:0)
 	/// Proof Skipped: IMPExtrinsicWhitelist GroupControlOn (max_values: Some(1), max_size: None, mode: Measured)
 	fn link_identity() -> Weight {
@@ -149,6 +157,39 @@ impl<T: frame_system::Config> pallet_identity_management::WeightInfo for WeightI
 			.saturating_add(Weight::from_parts(0, 3720))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	// The TEE callbacks from `identity_unlinked` to `identity_reverified` are benchmarked but
+	// their weights are not generated yet, they reuse the `identity_networks_set` weight
+	// until the weight files are regenerated with the benchmark CLI.
+	fn identity_unlinked() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
+	fn id_graphs_merged() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
+	fn prime_identity_transferred() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
+	fn recovery_guardians_set() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
+	fn recovery_initiated() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
+	fn recovery_approved() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
+	fn recovery_cancelled() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
+	fn prime_identity_recovered() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
+	fn identity_label_set() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
+	fn identity_reverified() -> Weight {
+		<Self as pallet_identity_management::WeightInfo>::identity_networks_set()
+	}
 	/// Storage: Teerex EnclaveIndex (r:1 w:0)
 	/// Proof Skipped: Teerex EnclaveIndex (max_values: None, max_size: None, mode: Measured)
 	fn some_error() -> Weight {
@@ -0,0 +1,129 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_liquid_staking`
+//!
+//! Hand estimates, the benchmarks exist but the benchmark CLI has not been run for this pallet
+//! yet. The weights are derived from the parachain-staking calls they wrap and the storage
+//! accesses listed per call are counted from the code, not measured. `d` is the number of pool
+//! delegations. Regenerate the file with `--pallet=pallet_liquid_staking` before the pallet is
+//! deployed.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_liquid_staking`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T> {
+	// Storage: LiquidStaking DerivativeAssetId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	fn create_derivative() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	// Storage: LiquidStaking Candidates (r:1 w:1)
+	fn add_candidate() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: LiquidStaking Candidates (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(80_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: LiquidStaking DerivativeAssetId (r:1 w:0)
+	// Storage: LiquidStaking Candidates (r:1 w:0)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	// Storage: ParachainStaking TopDelegations (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking Total (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn stake() -> Weight {
+		Weight::from_parts(230_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	// Storage: LiquidStaking DerivativeAssetId (r:1 w:0)
+	// Storage: LiquidStaking Unbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalUnstaked (r:1 w:1)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:d w:d)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:d w:d)
+	fn unstake(d: u32, ) -> Weight {
+		Weight::from_parts(110_000_000, 0)
+			.saturating_add(Weight::from_parts(60_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+	}
+	// Storage: ParachainStaking DelegatorState (r:d w:d)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:d w:d)
+	// Storage: ParachainStaking CandidateInfo (r:d w:d)
+	// Storage: ParachainStaking Total (r:d w:d)
+	// Storage: LiquidStaking Unbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	// Storage: LiquidStaking TotalWithdrawn (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn withdraw_unbonded(d: u32, ) -> Weight {
+		Weight::from_parts(90_000_000, 0)
+			.saturating_add(Weight::from_parts(110_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(d.into())))
+	}
+	// Storage: ParachainStaking DelegatorState (r:d w:d)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:d w:d)
+	// Storage: ParachainStaking CandidateInfo (r:d w:d)
+	// Storage: ParachainStaking Total (r:d w:d)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: LiquidStaking Reserve (r:1 w:1)
+	fn process_unbonding(d: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(160_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(d.into())))
+	}
+}
@@ -715,4 +715,27 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(Weight::from_parts(0, 31).saturating_mul(y.into()))
 			.saturating_add(Weight::from_parts(0, 29).saturating_mul(z.into()))
 	}
+	// Estimate, not generated by the benchmark CLI: the `set_slashing_config` benchmark has not
+	// been run yet. The call reads and writes `SlashingConfiguration` once.
+	fn set_slashing_config() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1890))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Estimate, not generated by the benchmark CLI: the `slash_candidate` benchmark has not been
+	// run yet. The figures are hand-picked upper bounds for reading and writing the candidate,
+	// its delegations, the pool and the total once, plus one `DelegatorState` update and one
+	// reserve slash for each of the `x` delegations. Replace them with the CLI output once the
+	// benchmark has been run.
+	fn slash_candidate(x: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6000))
+			.saturating_add(Weight::from_parts(40_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2600).saturating_mul(x.into()))
+	}
 }
@@ -72,6 +72,14 @@ impl<T: frame_system::Config> pallet_vc_management::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// The delegatee deposit isn't covered by the generated `add_delegatee` and `remove_delegatee`
+	// weights, they have to be regenerated together with `accept_delegatee`. Until then
+	// `accept_delegatee` is estimated as `remove_delegatee` plus the reserve and the
+	// `PendingDelegatees` and `DelegateeDeposits` accesses.
+	fn accept_delegatee() -> Weight {
+		<Self as pallet_vc_management::WeightInfo>::remove_delegatee()
+			.saturating_add(T::DbWeight::get().reads_writes(3_u64, 3_u64))
+	}
 	/// Storage: VCMPExtrinsicWhitelist GroupControlOn (r:1 w:0)
 	/// Proof Skipped: VCMPExtrinsicWhitelist GroupControlOn (max_values: Some(1), max_size: None, mode: Measured)
 	fn request_vc() -> Weight {
@@ -24,7 +24,7 @@ use itp_types::{
 	parentchain::{
 		events::{
 			ActivateIdentityRequested, AssertionCreated, DeactivateIdentityRequested,
-			EnclaveUnauthorized, LinkIdentityRequested, OpaqueTaskPosted,
+			EnclaveEvicted, EnclaveUnauthorized, LinkIdentityRequested, OpaqueTaskPosted,
 			ParentchainBlockProcessed, VCRequested,
 		},
 		FilterEvents,
@@ -91,6 +91,10 @@ impl FilterEvents for FilterableEvents {
 		self.filter()
 	}
 
+	fn get_enclave_evicted_events(&self) -> Result<Vec<EnclaveEvicted>, Self::Error> {
+		self.filter()
+	}
+
 	fn get_opaque_task_posted_events(&self) -> Result<Vec<OpaqueTaskPosted>, Self::Error> {
 		self.filter()
 	}
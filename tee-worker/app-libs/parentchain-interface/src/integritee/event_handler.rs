@@ -321,6 +321,15 @@ where
 				.map_err(|_| ParentchainEventProcessingError::AssertionCreatedFailure)?;
 		}
 
+		// the service re-registers the enclave if it's evicted, so this is only for monitoring
+		if let Ok(events) = events.get_enclave_evicted_events() {
+			debug!("Handling EnclaveEvicted events");
+			events.iter().for_each(|event| {
+				warn!("found EnclaveEvicted event: {}", event);
+				handled_events.push(hash_of(&event));
+			});
+		}
+
 		if let Ok(events) = events.get_parentchain_block_proccessed_events() {
 			debug!("Handling ParentchainBlockProcessed events");
 			events.iter().for_each(|event| {
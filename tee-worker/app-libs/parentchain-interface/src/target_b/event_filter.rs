@@ -90,6 +90,12 @@ impl FilterEvents for FilterableEvents {
 		self.filter()
 	}
 
+	fn get_enclave_evicted_events(
+		&self,
+	) -> Result<Vec<itp_types::parentchain::events::EnclaveEvicted>, Self::Error> {
+		self.filter()
+	}
+
 	fn get_opaque_task_posted_events(
 		&self,
 	) -> Result<Vec<itp_types::parentchain::events::OpaqueTaskPosted>, Self::Error> {
@@ -73,7 +73,7 @@ pub use frame_support::{
 	},
 	StorageValue,
 };
-use itp_sgx_runtime_primitives::types::Moment;
+use itp_sgx_runtime_primitives::{constants::DAYS, types::Moment};
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_parentchain::Call as ParentchainPalletCall;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -139,7 +139,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("node-template"),
 	impl_name: create_runtime_str!("node-template"),
 	authoring_version: 1,
-	spec_version: 109,
+	spec_version: 110,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -287,6 +287,8 @@ impl pallet_imt::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ManageOrigin = EnsureRoot<AccountId>;
 	type MaxIDGraphLength = ConstU32<64>;
+	type MaxGuardians = ConstU32<8>;
+	type MinRecoveryDelay = ConstU32<DAYS>;
 }
 
 // The plain sgx-runtime without the `evm-pallet`
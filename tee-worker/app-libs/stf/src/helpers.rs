@@ -147,6 +147,24 @@ pub fn get_expected_raw_message(
 	blake2_256(payload.as_slice()).to_vec()
 }
 
+// the other prime identity consents to merge its IDGraph into the IDGraph of `who` by signing
+// ```
+// blake2_256("merge:" + <sidechain nonce> + <who> + <other>)
+// ```
+// the prefix makes sure that a signature collected for linking an identity can't be replayed
+// to merge a whole IDGraph
+pub fn get_expected_merge_message(
+	who: &Identity,
+	other: &Identity,
+	sidechain_nonce: Index,
+) -> Vec<u8> {
+	let mut payload = b"merge:".to_vec();
+	payload.append(&mut sidechain_nonce.encode());
+	payload.append(&mut who.encode());
+	payload.append(&mut other.encode());
+	blake2_256(payload.as_slice()).to_vec()
+}
+
 // [P-923] Verify a web3 identity
 // This function validates the signature with both the raw message and its prettified format. Any of them is valid.
 // The prettified version was introduced to extend the support for utf-8 signatures for browser wallets that
@@ -219,3 +237,46 @@ mod non_prod {
 		signer == &enclave_signer_account::<AccountId32>() || ensure_alice(signer)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use litentry_primitives::{LitentryMultiSignature, Web3CommonValidationData};
+	use sp_core::Pair;
+	use sp_keyring::AccountKeyring;
+
+	fn validation_data(pair: &sp_core::sr25519::Pair, message: Vec<u8>) -> Web3ValidationData {
+		Web3ValidationData::Substrate(Web3CommonValidationData {
+			signature: LitentryMultiSignature::Sr25519(pair.sign(&message)),
+			message: message.try_into().unwrap(),
+		})
+	}
+
+	#[test]
+	fn verify_merge_message_works() {
+		let who: Identity = AccountKeyring::Alice.to_account_id().into();
+		let other_pair = AccountKeyring::Bob.pair();
+		let other: Identity = other_pair.public().into();
+
+		let merge_msg = get_expected_merge_message(&who, &other, 1);
+		let data = validation_data(&other_pair, merge_msg.clone());
+		assert!(verify_web3_identity(&other, &merge_msg, &data).is_ok());
+	}
+
+	#[test]
+	fn link_signature_is_rejected_for_merge() {
+		let who: Identity = AccountKeyring::Alice.to_account_id().into();
+		let other_pair = AccountKeyring::Bob.pair();
+		let other: Identity = other_pair.public().into();
+
+		let link_msg = get_expected_raw_message(&who, &other, 1);
+		let merge_msg = get_expected_merge_message(&who, &other, 1);
+		assert_ne!(link_msg, merge_msg);
+
+		let data = validation_data(&other_pair, link_msg);
+		assert_eq!(
+			verify_web3_identity(&other, &merge_msg, &data),
+			Err(StfError::LinkIdentityFailed(ErrorDetail::UnexpectedMessage))
+		);
+	}
+}
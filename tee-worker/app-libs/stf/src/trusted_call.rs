@@ -26,19 +26,21 @@ use crate::{
 	format,
 	helpers::{enclave_signer_account, ensure_enclave_signer_account, ensure_self},
 	trusted_call_result::{
-		ActivateIdentityResult, DeactivateIdentityResult, RequestVCResult,
-		SetIdentityNetworksResult, TrustedCallResult,
+		ActivateIdentityResult, DeactivateIdentityResult, MergeIDGraphsResult, RequestVCResult,
+		SetIdentityLabelResult, SetIdentityNetworksResult, TransferPrimeIdentityResult,
+		TrustedCallResult, UnlinkIdentityResult,
 	},
 	Arc, Getter, String, ToString, Vec,
 };
 use codec::{Decode, Encode};
 use frame_support::{ensure, traits::UnfilteredDispatchable};
-#[cfg(feature = "evm")]
-use ita_sgx_runtime::{AddressMapping, HashedAddressMapping};
 pub use ita_sgx_runtime::{
-	Balance, IDGraph, Index, ParentchainInstanceLitentry, ParentchainInstanceTargetA,
-	ParentchainInstanceTargetB, ParentchainLitentry, Runtime, System, VERSION as SIDECHAIN_VERSION,
+	pallet_imt::PublicMessageProof, Balance, BlockNumber, IDGraph, Index,
+	ParentchainInstanceLitentry, ParentchainInstanceTargetA, ParentchainInstanceTargetB,
+	ParentchainLitentry, Runtime, System, VERSION as SIDECHAIN_VERSION,
 };
+#[cfg(feature = "evm")]
+use ita_sgx_runtime::{AddressMapping, HashedAddressMapping};
 use itp_node_api::metadata::{
 	pallet_system::SystemConstants, provider::AccessNodeMetadata, NodeMetadataTrait,
 };
@@ -58,7 +60,8 @@ use litentry_hex_utils::hex_encode;
 pub use litentry_primitives::{
 	aes_encrypt_default, all_evm_web3networks, all_substrate_web3networks, AesOutput, Assertion,
 	ErrorDetail, IMPError, Identity, LitentryMultiSignature, ParentchainBlockNumber, RequestAesKey,
-	RequestAesKeyNonce, VCMPError, ValidationData, Web3Network,
+	RequestAesKeyNonce, VCMPError, ValidationData, VerificationMethod, Web2ValidationData,
+	Web3Network, Web3ValidationData,
 };
 use log::*;
 use sp_core::{
@@ -120,6 +123,41 @@ pub enum TrustedCall {
 	remove_identity(Identity, Identity, Vec<Identity>),
 	#[codec(index = 6)]
 	request_batch_vc(Identity, Identity, VecAssertion, Option<RequestAesKey>, H256),
+	#[codec(index = 7)]
+	unlink_identity(Identity, Identity, Identity, Option<RequestAesKey>, H256),
+	#[codec(index = 8)]
+	merge_id_graphs(Identity, Identity, Identity, Web3ValidationData, Option<RequestAesKey>, H256),
+	#[codec(index = 9)]
+	transfer_prime_identity(Identity, Identity, Identity, Option<RequestAesKey>, H256),
+	#[codec(index = 10)]
+	set_recovery_guardians(
+		Identity,
+		Identity,
+		Vec<Identity>,
+		u32,
+		BlockNumber,
+		Option<RequestAesKey>,
+		H256,
+	),
+	#[codec(index = 11)]
+	initiate_recovery(Identity, Identity, Identity, Option<RequestAesKey>, H256),
+	#[codec(index = 12)]
+	approve_recovery(Identity, Identity, Option<RequestAesKey>, H256),
+	#[codec(index = 13)]
+	cancel_recovery(Identity, Identity, Option<RequestAesKey>, H256),
+	#[codec(index = 14)]
+	finalize_recovery(Identity, Identity, Option<RequestAesKey>, H256),
+	#[codec(index = 15)]
+	set_identity_label(Identity, Identity, Identity, Option<Vec<u8>>, Option<RequestAesKey>, H256),
+	#[codec(index = 16)]
+	reverify_identity(
+		Identity,
+		Identity,
+		Identity,
+		Web2ValidationData,
+		Option<RequestAesKey>,
+		H256,
+	),
 	// the following trusted calls should not be requested directly from external
 	// they are guarded by the signature check (either root or enclave_signer_account)
 	// starting from index 20 to leave some room for future "normal" trusted calls
@@ -129,6 +167,8 @@ pub enum TrustedCall {
 		Identity,
 		Identity,
 		Vec<Web3Network>,
+		Option<VerificationMethod>,
+		Option<PublicMessageProof>,
 		Option<RequestAesKey>,
 		H256,
 	),
@@ -142,6 +182,16 @@ pub enum TrustedCall {
 	send_erroneous_parentchain_call(Identity),
 	#[codec(index = 25)]
 	maybe_create_id_graph(Identity, Identity),
+	#[codec(index = 26)]
+	reverify_identity_callback(
+		Identity,
+		Identity,
+		Identity,
+		Result<VerificationMethod, ErrorDetail>,
+		Option<PublicMessageProof>,
+		Option<RequestAesKey>,
+		H256,
+	),
 
 	// original integritee trusted calls, starting from index 50
 	#[codec(index = 50)]
@@ -231,6 +281,17 @@ impl TrustedCall {
 			#[cfg(feature = "development")]
 			Self::remove_identity(sender_identity, ..) => sender_identity,
 			Self::request_batch_vc(sender_identity, ..) => sender_identity,
+			Self::unlink_identity(sender_identity, ..) => sender_identity,
+			Self::merge_id_graphs(sender_identity, ..) => sender_identity,
+			Self::transfer_prime_identity(sender_identity, ..) => sender_identity,
+			Self::set_recovery_guardians(sender_identity, ..) => sender_identity,
+			Self::initiate_recovery(sender_identity, ..) => sender_identity,
+			Self::approve_recovery(sender_identity, ..) => sender_identity,
+			Self::cancel_recovery(sender_identity, ..) => sender_identity,
+			Self::finalize_recovery(sender_identity, ..) => sender_identity,
+			Self::set_identity_label(sender_identity, ..) => sender_identity,
+			Self::reverify_identity(sender_identity, ..) => sender_identity,
+			Self::reverify_identity_callback(sender_identity, ..) => sender_identity,
 		}
 	}
 
@@ -244,6 +305,17 @@ impl TrustedCall {
 			Self::handle_imp_error(..) => "handle_imp_error",
 			Self::deactivate_identity(..) => "deactivate_identity",
 			Self::activate_identity(..) => "activate_identity",
+			Self::unlink_identity(..) => "unlink_identity",
+			Self::merge_id_graphs(..) => "merge_id_graphs",
+			Self::transfer_prime_identity(..) => "transfer_prime_identity",
+			Self::set_recovery_guardians(..) => "set_recovery_guardians",
+			Self::initiate_recovery(..) => "initiate_recovery",
+			Self::approve_recovery(..) => "approve_recovery",
+			Self::cancel_recovery(..) => "cancel_recovery",
+			Self::finalize_recovery(..) => "finalize_recovery",
+			Self::set_identity_label(..) => "set_identity_label",
+			Self::reverify_identity(..) => "reverify_identity",
+			Self::reverify_identity_callback(..) => "reverify_identity_callback",
 			Self::maybe_create_id_graph(..) => "maybe_create_id_graph",
 			_ => "unsupported_trusted_call",
 		}
@@ -621,6 +693,7 @@ where
 				req_ext_hash,
 			) => {
 				debug!("link_identity, who: {}", account_id_to_string(&who));
+				let verification_method = validation_data.verification_method();
 				let verification_done = Self::link_identity_internal(
 					shard,
 					signer.to_account_id().ok_or(Self::Error::InvalidAccount)?,
@@ -651,6 +724,8 @@ where
 						who,
 						identity,
 						web3networks,
+						Some(verification_method),
+						None,
 						maybe_key,
 						req_ext_hash,
 					)
@@ -764,11 +839,356 @@ where
 
 				Ok(TrustedCallResult::Empty)
 			},
+			TrustedCall::unlink_identity(signer, who, identity, maybe_key, req_ext_hash) => {
+				debug!("unlink_identity, who: {}", account_id_to_string(&who));
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.identity_unlinked_call_indexes())??;
+				let old_id_graph = IMT::id_graph(&who);
+
+				Self::unlink_identity_internal(
+					signer.to_account_id().ok_or(Self::Error::InvalidAccount)?,
+					who.clone(),
+					identity,
+				)
+				.map_err(|e| {
+					debug!("pushing error event ... error: {}", e);
+					push_call_imp_some_error(
+						calls,
+						node_metadata_repo.clone(),
+						Some(who.clone()),
+						e.to_imp_error(),
+						req_ext_hash,
+					);
+					e
+				})?;
+
+				let id_graph_hash: H256 = IMT::id_graph_hash(&who).ok_or(StfError::EmptyIDGraph)?;
+
+				debug!("pushing identity_unlinked event ...");
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who.clone(),
+					id_graph_hash,
+					req_ext_hash,
+				))));
+
+				// unlike other IDGraph mutations, the mutated part is what was removed
+				let new_id_graph = IMT::id_graph(&who);
+				let mut mutated_id_graph = old_id_graph;
+				mutated_id_graph.retain(|i| !new_id_graph.contains(i));
+
+				if let Some(key) = maybe_key {
+					return Ok(TrustedCallResult::UnlinkIdentity(UnlinkIdentityResult {
+						mutated_id_graph: aes_encrypt_default(&key, &mutated_id_graph.encode()),
+						id_graph_hash,
+					}))
+				}
+
+				Ok(TrustedCallResult::Empty)
+			},
+			TrustedCall::merge_id_graphs(
+				signer,
+				who,
+				other,
+				validation_data,
+				maybe_key,
+				req_ext_hash,
+			) => {
+				debug!(
+					"merge_id_graphs, who: {}, other: {}",
+					account_id_to_string(&who),
+					account_id_to_string(&other)
+				);
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.id_graphs_merged_call_indexes())??;
+				let old_id_graph = IMT::id_graph(&who);
+
+				Self::merge_id_graphs_internal(
+					signer.to_account_id().ok_or(Self::Error::InvalidAccount)?,
+					who.clone(),
+					other.clone(),
+					validation_data,
+				)
+				.map_err(|e| {
+					debug!("pushing error event ... error: {}", e);
+					push_call_imp_some_error(
+						calls,
+						node_metadata_repo.clone(),
+						Some(who.clone()),
+						e.to_imp_error(),
+						req_ext_hash,
+					);
+					e
+				})?;
+
+				let id_graph_hash: H256 = IMT::id_graph_hash(&who).ok_or(StfError::EmptyIDGraph)?;
+
+				debug!("pushing id_graphs_merged event ...");
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who.clone(),
+					other,
+					id_graph_hash,
+					req_ext_hash,
+				))));
+
+				let mut mutated_id_graph = IMT::id_graph(&who);
+				mutated_id_graph.retain(|i| !old_id_graph.contains(i));
+
+				if let Some(key) = maybe_key {
+					return Ok(TrustedCallResult::MergeIDGraphs(MergeIDGraphsResult {
+						mutated_id_graph: aes_encrypt_default(&key, &mutated_id_graph.encode()),
+						id_graph_hash,
+					}))
+				}
+
+				Ok(TrustedCallResult::Empty)
+			},
+			TrustedCall::transfer_prime_identity(
+				signer,
+				who,
+				new_prime,
+				maybe_key,
+				req_ext_hash,
+			) => {
+				debug!(
+					"transfer_prime_identity, who: {}, new_prime: {}",
+					account_id_to_string(&who),
+					account_id_to_string(&new_prime)
+				);
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.prime_identity_transferred_call_indexes())??;
+
+				Self::transfer_prime_identity_internal(
+					signer.to_account_id().ok_or(Self::Error::InvalidAccount)?,
+					who.clone(),
+					new_prime.clone(),
+				)
+				.map_err(|e| {
+					debug!("pushing error event ... error: {}", e);
+					push_call_imp_some_error(
+						calls,
+						node_metadata_repo.clone(),
+						Some(who.clone()),
+						e.to_imp_error(),
+						req_ext_hash,
+					);
+					e
+				})?;
+
+				let id_graph_hash: H256 =
+					IMT::id_graph_hash(&new_prime).ok_or(StfError::EmptyIDGraph)?;
+
+				debug!("pushing prime_identity_transferred event ...");
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who,
+					new_prime.clone(),
+					id_graph_hash,
+					req_ext_hash,
+				))));
+
+				// the whole IDGraph is re-keyed, so it's returned as a whole
+				if let Some(key) = maybe_key {
+					return Ok(TrustedCallResult::TransferPrimeIdentity(
+						TransferPrimeIdentityResult {
+							mutated_id_graph: aes_encrypt_default(
+								&key,
+								&IMT::id_graph(&new_prime).encode(),
+							),
+							id_graph_hash,
+						},
+					))
+				}
+
+				Ok(TrustedCallResult::Empty)
+			},
+			TrustedCall::set_recovery_guardians(
+				signer,
+				who,
+				guardians,
+				threshold,
+				delay,
+				_maybe_key,
+				req_ext_hash,
+			) => {
+				debug!("set_recovery_guardians, who: {}", account_id_to_string(&who));
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.recovery_guardians_set_call_indexes())??;
+
+				Self::set_recovery_guardians_internal(
+					signer.to_account_id().ok_or(Self::Error::InvalidAccount)?,
+					who.clone(),
+					guardians,
+					threshold,
+					delay,
+				)
+				.map_err(|e| {
+					debug!("pushing error event ... error: {}", e);
+					push_call_imp_some_error(
+						calls,
+						node_metadata_repo.clone(),
+						Some(who.clone()),
+						e.to_imp_error(),
+						req_ext_hash,
+					);
+					e
+				})?;
+
+				debug!("pushing recovery_guardians_set event ...");
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who,
+					req_ext_hash,
+				))));
+
+				Ok(TrustedCallResult::Empty)
+			},
+			TrustedCall::initiate_recovery(signer, who, new_prime, _maybe_key, req_ext_hash) => {
+				debug!(
+					"initiate_recovery, who: {}, new_prime: {}",
+					account_id_to_string(&who),
+					account_id_to_string(&new_prime)
+				);
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.recovery_initiated_call_indexes())??;
+
+				Self::initiate_recovery_internal(signer, who.clone(), new_prime.clone()).map_err(
+					|e| {
+						debug!("pushing error event ... error: {}", e);
+						push_call_imp_some_error(
+							calls,
+							node_metadata_repo.clone(),
+							Some(who.clone()),
+							e.to_imp_error(),
+							req_ext_hash,
+						);
+						e
+					},
+				)?;
+
+				debug!("pushing recovery_initiated event ...");
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who,
+					new_prime,
+					req_ext_hash,
+				))));
+
+				Ok(TrustedCallResult::Empty)
+			},
+			TrustedCall::approve_recovery(signer, who, _maybe_key, req_ext_hash) => {
+				debug!("approve_recovery, who: {}", account_id_to_string(&who));
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.recovery_approved_call_indexes())??;
+
+				Self::approve_recovery_internal(signer, who.clone()).map_err(|e| {
+					debug!("pushing error event ... error: {}", e);
+					push_call_imp_some_error(
+						calls,
+						node_metadata_repo.clone(),
+						Some(who.clone()),
+						e.to_imp_error(),
+						req_ext_hash,
+					);
+					e
+				})?;
+
+				let approvals = IMT::active_recoveries(&who)
+					.map(|recovery| recovery.approval_count())
+					.unwrap_or_default();
+
+				debug!("pushing recovery_approved event ...");
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who,
+					approvals,
+					req_ext_hash,
+				))));
+
+				Ok(TrustedCallResult::Empty)
+			},
+			TrustedCall::cancel_recovery(signer, who, _maybe_key, req_ext_hash) => {
+				debug!("cancel_recovery, who: {}", account_id_to_string(&who));
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.recovery_cancelled_call_indexes())??;
+
+				Self::cancel_recovery_internal(
+					signer.to_account_id().ok_or(Self::Error::InvalidAccount)?,
+					who.clone(),
+				)
+				.map_err(|e| {
+					debug!("pushing error event ... error: {}", e);
+					push_call_imp_some_error(
+						calls,
+						node_metadata_repo.clone(),
+						Some(who.clone()),
+						e.to_imp_error(),
+						req_ext_hash,
+					);
+					e
+				})?;
+
+				debug!("pushing recovery_cancelled event ...");
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who,
+					req_ext_hash,
+				))));
+
+				Ok(TrustedCallResult::Empty)
+			},
+			TrustedCall::finalize_recovery(_signer, who, maybe_key, req_ext_hash) => {
+				debug!("finalize_recovery, who: {}", account_id_to_string(&who));
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.prime_identity_recovered_call_indexes())??;
+
+				let new_prime = Self::finalize_recovery_internal(who.clone()).map_err(|e| {
+					debug!("pushing error event ... error: {}", e);
+					push_call_imp_some_error(
+						calls,
+						node_metadata_repo.clone(),
+						Some(who.clone()),
+						e.to_imp_error(),
+						req_ext_hash,
+					);
+					e
+				})?;
+
+				let id_graph_hash: H256 =
+					IMT::id_graph_hash(&new_prime).ok_or(StfError::EmptyIDGraph)?;
+
+				debug!("pushing prime_identity_recovered event ...");
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who,
+					new_prime.clone(),
+					id_graph_hash,
+					req_ext_hash,
+				))));
+
+				// like `transfer_prime_identity`, the whole re-keyed IDGraph is returned
+				if let Some(key) = maybe_key {
+					return Ok(TrustedCallResult::TransferPrimeIdentity(
+						TransferPrimeIdentityResult {
+							mutated_id_graph: aes_encrypt_default(
+								&key,
+								&IMT::id_graph(&new_prime).encode(),
+							),
+							id_graph_hash,
+						},
+					))
+				}
+
+				Ok(TrustedCallResult::Empty)
+			},
 			TrustedCall::link_identity_callback(
 				signer,
 				who,
 				identity,
 				web3networks,
+				verification_method,
+				public_message_proof,
 				maybe_key,
 				req_ext_hash,
 			) => Self::handle_link_identity_callback(
@@ -778,6 +1198,8 @@ where
 				who,
 				identity,
 				web3networks,
+				verification_method,
+				public_message_proof,
 				maybe_key,
 				req_ext_hash,
 			),
@@ -928,6 +1350,110 @@ where
 
 				Ok(TrustedCallResult::Empty)
 			},
+			TrustedCall::set_identity_label(
+				signer,
+				who,
+				identity,
+				label,
+				maybe_key,
+				req_ext_hash,
+			) => {
+				debug!("set_identity_label, who: {}", account_id_to_string(&who));
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.identity_label_set_call_indexes())??;
+				let old_id_graph = IMT::id_graph(&who);
+
+				Self::set_identity_label_internal(
+					signer.to_account_id().ok_or(Self::Error::InvalidAccount)?,
+					who.clone(),
+					identity,
+					label,
+				)
+				.map_err(|e| {
+					debug!("pushing error event ... error: {}", e);
+					push_call_imp_some_error(
+						calls,
+						node_metadata_repo.clone(),
+						Some(who.clone()),
+						e.to_imp_error(),
+						req_ext_hash,
+					);
+					e
+				})?;
+
+				let id_graph_hash: H256 = IMT::id_graph_hash(&who).ok_or(StfError::EmptyIDGraph)?;
+
+				debug!("pushing identity_label_set event ...");
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who.clone(),
+					id_graph_hash,
+					req_ext_hash,
+				))));
+
+				let mut mutated_id_graph = IMT::id_graph(&who);
+				mutated_id_graph.retain(|i| !old_id_graph.contains(i));
+
+				if let Some(key) = maybe_key {
+					return Ok(TrustedCallResult::SetIdentityLabel(SetIdentityLabelResult {
+						mutated_id_graph: aes_encrypt_default(&key, &mutated_id_graph.encode()),
+						id_graph_hash,
+					}))
+				}
+
+				Ok(TrustedCallResult::Empty)
+			},
+			TrustedCall::reverify_identity(
+				signer,
+				who,
+				identity,
+				validation_data,
+				maybe_key,
+				req_ext_hash,
+			) => {
+				debug!("reverify_identity, who: {}", account_id_to_string(&who));
+				Self::reverify_identity_internal(
+					shard,
+					signer.to_account_id().ok_or(Self::Error::InvalidAccount)?,
+					who.clone(),
+					identity,
+					validation_data,
+					top_hash,
+					maybe_key,
+					req_ext_hash,
+				)
+				.map_err(|e| {
+					debug!("pushing error event ... error: {}", e);
+					push_call_imp_some_error(
+						calls,
+						node_metadata_repo.clone(),
+						Some(who.clone()),
+						e.to_imp_error(),
+						req_ext_hash,
+					);
+					e
+				})?;
+				Ok(TrustedCallResult::Streamed)
+			},
+			TrustedCall::reverify_identity_callback(
+				signer,
+				who,
+				identity,
+				result,
+				public_message_proof,
+				maybe_key,
+				req_ext_hash,
+			) => Self::handle_reverify_identity_callback(
+				calls,
+				node_metadata_repo,
+				signer,
+				who,
+				identity,
+				result,
+				public_message_proof,
+				maybe_key,
+				req_ext_hash,
+			),
 			TrustedCall::handle_imp_error(_enclave_account, identity, e, req_ext_hash) => {
 				// checking of `_enclave_account` is not strictly needed, as this trusted call can
 				// only be constructed internally
@@ -20,17 +20,23 @@ extern crate sgx_tstd as std;
 use super::*;
 use crate::{
 	helpers::{
-		ensure_enclave_signer_account, ensure_enclave_signer_or_self, get_expected_raw_message,
-		verify_web3_identity,
+		ensure_enclave_signer_account, ensure_enclave_signer_or_self, ensure_self,
+		get_expected_merge_message, get_expected_raw_message, verify_web3_identity,
 	},
-	trusted_call_result::{LinkIdentityResult, TrustedCallResult},
+	trusted_call_result::{LinkIdentityResult, ReverifyIdentityResult, TrustedCallResult},
 	Arc, Vec,
 };
 use codec::Encode;
-use frame_support::{dispatch::UnfilteredDispatchable, ensure, sp_runtime::traits::One};
+use frame_support::{
+	dispatch::UnfilteredDispatchable,
+	ensure,
+	sp_runtime::{traits::One, DispatchError},
+};
 use ita_sgx_runtime::{
-	pallet_imt::{get_eligible_identities, IdentityContext},
-	BlockNumber, ParentchainLitentry, RuntimeOrigin, System,
+	pallet_imt::{
+		self, get_eligible_identities, get_web2_verified_at, DeactivationReason, IdentityContext,
+	},
+	BlockNumber, ParentchainLitentry, RuntimeOrigin, System, Timestamp,
 };
 use itp_node_api::metadata::NodeMetadataTrait;
 use itp_node_api_metadata::pallet_imp::IMPCallIndexes;
@@ -47,7 +53,8 @@ use lc_stf_task_sender::{
 };
 use litentry_macros::if_development_or;
 use litentry_primitives::{
-	Assertion, ErrorDetail, Identity, RequestAesKey, ValidationData, Web3Network,
+	Assertion, ErrorDetail, Identity, RequestAesKey, ValidationData, VerificationMethod,
+	Web2ValidationData, Web3Network, Web3ValidationData,
 };
 use log::*;
 
@@ -116,6 +123,49 @@ impl TrustedCallSigned {
 		}
 	}
 
+	// re-check the ownership of an already linked web2 identity, the user is expected to publish
+	// the raw message derived from the current nonce again, in the same way as linking
+	#[allow(clippy::too_many_arguments)]
+	pub fn reverify_identity_internal(
+		shard: &ShardIdentifier,
+		signer: AccountId,
+		who: Identity,
+		identity: Identity,
+		validation_data: Web2ValidationData,
+		top_hash: H256,
+		maybe_key: Option<RequestAesKey>,
+		req_ext_hash: H256,
+	) -> StfResult<()> {
+		ensure!(
+			ensure_enclave_signer_or_self(&signer, who.to_account_id()),
+			StfError::ReverifyIdentityFailed(ErrorDetail::UnauthorizedSigner)
+		);
+		ensure!(identity.is_web2(), StfError::ReverifyIdentityFailed(ErrorDetail::InvalidIdentity));
+		let context = IMT::id_graphs(&who, &identity).ok_or_else(|| {
+			StfError::ReverifyIdentityFailed(
+				DispatchError::from(pallet_imt::Error::<Runtime>::IdentityNotExist).into(),
+			)
+		})?;
+
+		let sidechain_nonce = System::account_nonce(&signer) - 1;
+		let raw_msg = get_expected_raw_message(&who, &identity, sidechain_nonce);
+
+		let request = RequestType::IdentityReverification(Web2IdentityVerificationRequest {
+			shard: *shard,
+			who,
+			identity,
+			raw_msg,
+			validation_data,
+			web3networks: context.web3networks,
+			top_hash,
+			maybe_key,
+			req_ext_hash,
+		});
+		StfRequestSender::new()
+			.send_stf_request(request)
+			.map_err(|_| StfError::ReverifyIdentityFailed(ErrorDetail::SendStfRequestFailed))
+	}
+
 	pub fn deactivate_identity_internal(
 		signer: AccountId,
 		who: Identity,
@@ -126,7 +176,7 @@ impl TrustedCallSigned {
 			StfError::DeactivateIdentityFailed(ErrorDetail::UnauthorizedSigner)
 		);
 
-		IMTCall::deactivate_identity { who, identity }
+		IMTCall::deactivate_identity { who, identity, reason: DeactivationReason::User }
 			.dispatch_bypass_filter(RuntimeOrigin::root())
 			.map_err(|e| StfError::DeactivateIdentityFailed(e.into()))?;
 
@@ -150,6 +200,166 @@ impl TrustedCallSigned {
 		Ok(())
 	}
 
+	// unlinking is only allowed for the prime identity itself, which is proven by the signature
+	// of the trusted call, the unlinked identity can then be linked to another IDGraph
+	pub fn unlink_identity_internal(
+		signer: AccountId,
+		who: Identity,
+		identity: Identity,
+	) -> StfResult<()> {
+		ensure!(
+			who.to_account_id().map_or(false, |who| ensure_self(&signer, &who)),
+			StfError::UnlinkIdentityFailed(ErrorDetail::UnauthorizedSigner)
+		);
+
+		IMTCall::unlink_identity { who, identity }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::UnlinkIdentityFailed(e.into()))?;
+
+		Ok(())
+	}
+
+	// merging requires the signatures of both prime identities: the trusted call is signed by
+	// `who`, and `validation_data` is signed by `other` over the same raw message as linking
+	pub fn merge_id_graphs_internal(
+		signer: AccountId,
+		who: Identity,
+		other: Identity,
+		validation_data: Web3ValidationData,
+	) -> StfResult<()> {
+		ensure!(
+			who.to_account_id().map_or(false, |who| ensure_self(&signer, &who)),
+			StfError::MergeIDGraphsFailed(ErrorDetail::UnauthorizedSigner)
+		);
+		ensure!(other.is_web3(), StfError::MergeIDGraphsFailed(ErrorDetail::InvalidIdentity));
+
+		let sidechain_nonce = System::account_nonce(&signer) - 1;
+		let raw_msg = get_expected_merge_message(&who, &other, sidechain_nonce);
+		verify_web3_identity(&other, &raw_msg, &validation_data).map_err(|e| match e {
+			StfError::LinkIdentityFailed(d) => StfError::MergeIDGraphsFailed(d),
+			e => e,
+		})?;
+
+		IMTCall::merge_id_graphs { who, other }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::MergeIDGraphsFailed(e.into()))?;
+
+		Ok(())
+	}
+
+	// the trusted call must be signed by the current prime identity, a linked identity alone
+	// can't take over the IDGraph. A user who lost the key of the prime identity recovers the
+	// IDGraph with the help of the recovery guardians instead.
+	pub fn transfer_prime_identity_internal(
+		signer: AccountId,
+		who: Identity,
+		new_prime: Identity,
+	) -> StfResult<()> {
+		ensure!(
+			who.to_account_id().map_or(false, |who| ensure_self(&signer, &who)),
+			StfError::TransferPrimeIdentityFailed(ErrorDetail::UnauthorizedSigner)
+		);
+
+		IMTCall::transfer_prime_identity { who, new_prime }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::TransferPrimeIdentityFailed(e.into()))?;
+
+		Ok(())
+	}
+
+	// the label is private to the owner of the IDGraph
+	pub fn set_identity_label_internal(
+		signer: AccountId,
+		who: Identity,
+		identity: Identity,
+		label: Option<Vec<u8>>,
+	) -> StfResult<()> {
+		ensure!(
+			who.to_account_id().map_or(false, |who| ensure_self(&signer, &who)),
+			StfError::SetIdentityLabelFailed(ErrorDetail::UnauthorizedSigner)
+		);
+
+		IMTCall::set_identity_label { who, identity, label }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::SetIdentityLabelFailed(e.into()))?;
+
+		Ok(())
+	}
+
+	// the recovery guardians are set by the prime identity
+	pub fn set_recovery_guardians_internal(
+		signer: AccountId,
+		who: Identity,
+		guardians: Vec<Identity>,
+		threshold: u32,
+		delay: BlockNumber,
+	) -> StfResult<()> {
+		ensure!(
+			who.to_account_id().map_or(false, |who| ensure_self(&signer, &who)),
+			StfError::RecoveryFailed(ErrorDetail::UnauthorizedSigner)
+		);
+
+		IMTCall::set_recovery_guardians { who, guardians, threshold, delay }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::RecoveryFailed(e.into()))?;
+
+		Ok(())
+	}
+
+	// initiating and approving a recovery must be signed by a guardian, which is the sender
+	// identity of the trusted call, so its signature is already verified
+	pub fn initiate_recovery_internal(
+		guardian: Identity,
+		who: Identity,
+		new_prime: Identity,
+	) -> StfResult<()> {
+		IMTCall::initiate_recovery { who, guardian, new_prime }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::RecoveryFailed(e.into()))?;
+
+		Ok(())
+	}
+
+	pub fn approve_recovery_internal(guardian: Identity, who: Identity) -> StfResult<()> {
+		IMTCall::approve_recovery { who, guardian }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::RecoveryFailed(e.into()))?;
+
+		Ok(())
+	}
+
+	// only the prime identity can cancel a recovery, a malicious recovery is cancelled this way
+	// by the owner who still holds the key
+	pub fn cancel_recovery_internal(signer: AccountId, who: Identity) -> StfResult<()> {
+		ensure!(
+			who.to_account_id().map_or(false, |who| ensure_self(&signer, &who)),
+			StfError::RecoveryFailed(ErrorDetail::UnauthorizedSigner)
+		);
+
+		IMTCall::cancel_recovery { who }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::RecoveryFailed(e.into()))?;
+
+		Ok(())
+	}
+
+	// anyone can finalize a recovery once it's approved by enough guardians and the delay has
+	// elapsed, the new prime identity is returned
+	pub fn finalize_recovery_internal(who: Identity) -> StfResult<Identity> {
+		let new_prime =
+			IMT::active_recoveries(&who).map(|recovery| recovery.new_prime).ok_or_else(|| {
+				StfError::RecoveryFailed(
+					DispatchError::from(pallet_imt::Error::<Runtime>::RecoveryNotInProgress).into(),
+				)
+			})?;
+
+		IMTCall::finalize_recovery { who }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::RecoveryFailed(e.into()))?;
+
+		Ok(new_prime)
+	}
+
 	#[allow(clippy::too_many_arguments)]
 	pub fn request_vc_internal(
 		signer: AccountId,
@@ -206,6 +416,7 @@ impl TrustedCallSigned {
 			!identities.is_empty(),
 			StfError::RequestVCFailed(assertion, ErrorDetail::NoEligibleIdentity)
 		);
+		let web2_verified_at = get_web2_verified_at(id_graph.as_ref(), &identities);
 
 		let parachain_block_number = ParentchainLitentry::block_number();
 		let sidechain_block_number = System::block_number();
@@ -223,6 +434,7 @@ impl TrustedCallSigned {
 			sidechain_runtime_version,
 			maybe_key,
 			should_create_id_graph,
+			web2_verified_at,
 			req_ext_hash,
 		}
 		.into();
@@ -238,6 +450,8 @@ impl TrustedCallSigned {
 		who: Identity,
 		identity: Identity,
 		web3networks: Vec<Web3Network>,
+		verification_method: Option<VerificationMethod>,
+		public_message_proof: Option<PublicMessageProof>,
 	) -> StfResult<()> {
 		if_development_or!(
 			{
@@ -253,9 +467,21 @@ impl TrustedCallSigned {
 					.map_err(|_| StfError::LinkIdentityFailed(ErrorDetail::UnauthorizedSigner))?;
 			}
 		);
-		IMTCall::link_identity { who, identity, web3networks }
+		IMTCall::link_identity { who: who.clone(), identity: identity.clone(), web3networks }
+			.dispatch_bypass_filter(RuntimeOrigin::root())
+			.map_err(|e| StfError::LinkIdentityFailed(e.into()))?;
+
+		if let Some(method) = verification_method {
+			IMTCall::record_identity_verification {
+				who,
+				identity,
+				method,
+				verified_at: Timestamp::now(),
+				proof: public_message_proof,
+			}
 			.dispatch_bypass_filter(RuntimeOrigin::root())
 			.map_err(|e| StfError::LinkIdentityFailed(e.into()))?;
+		}
 
 		Ok(())
 	}
@@ -288,6 +514,8 @@ impl TrustedCallSigned {
 		who: Identity,
 		identity: Identity,
 		web3networks: Vec<Web3Network>,
+		verification_method: Option<VerificationMethod>,
+		public_message_proof: Option<PublicMessageProof>,
 		maybe_key: Option<RequestAesKey>,
 		req_ext_hash: H256,
 	) -> StfResult<TrustedCallResult>
@@ -305,6 +533,8 @@ impl TrustedCallSigned {
 			who.clone(),
 			identity,
 			web3networks,
+			verification_method,
+			public_message_proof,
 		)
 		.map_err(|e| {
 			debug!("pushing error event ... error: {}", e);
@@ -343,4 +573,137 @@ impl TrustedCallSigned {
 
 		Ok(TrustedCallResult::Empty)
 	}
+
+	// the callback of `reverify_identity`, it's also submitted by the background re-verification
+	// job, either with the result of checking the kept public message again, or with
+	// `ErrorDetail::VerificationExpired` for identities that can't be checked without the user
+	//
+	// a re-verification which proves the ownership is lost deactivates the identity, please note
+	// the state change persists even though an error is returned, as the STF execution is not
+	// transactional. Any other error is only reported to the parachain. A successful one only
+	// re-activates the identity if it was deactivated by a re-verification, not by the user.
+	#[allow(clippy::too_many_arguments)]
+	pub fn handle_reverify_identity_callback<NodeMetadataRepository>(
+		calls: &mut Vec<ParentchainCall>,
+		node_metadata_repo: Arc<NodeMetadataRepository>,
+		signer: Identity,
+		who: Identity,
+		identity: Identity,
+		result: Result<VerificationMethod, ErrorDetail>,
+		public_message_proof: Option<PublicMessageProof>,
+		maybe_key: Option<RequestAesKey>,
+		req_ext_hash: H256,
+	) -> StfResult<TrustedCallResult>
+	where
+		NodeMetadataRepository: AccessNodeMetadata,
+		NodeMetadataRepository::MetadataType: NodeMetadataTrait,
+	{
+		debug!("reverify_identity_callback, who: {}", account_id_to_string(&who));
+		let signer = signer.to_account_id().ok_or(StfError::InvalidAccount)?;
+		if_development_or!(
+			{
+				// In non-prod: we allow to use `Alice` as the dummy signer
+				ensure!(
+					ensure_enclave_signer_or_alice(&signer),
+					StfError::ReverifyIdentityFailed(ErrorDetail::UnauthorizedSigner)
+				);
+			},
+			{
+				// In prod: the signer has to be enclave_signer_account, as this TrustedCall can only be constructed internally
+				ensure_enclave_signer_account(&signer).map_err(|_| {
+					StfError::ReverifyIdentityFailed(ErrorDetail::UnauthorizedSigner)
+				})?;
+			}
+		);
+
+		let old_id_graph = IMT::id_graph(&who);
+		let context = IMT::id_graphs(&who, &identity).ok_or_else(|| {
+			StfError::ReverifyIdentityFailed(
+				DispatchError::from(pallet_imt::Error::<Runtime>::IdentityNotExist).into(),
+			)
+		})?;
+
+		match result {
+			Ok(method) => {
+				IMTCall::record_identity_verification {
+					who: who.clone(),
+					identity: identity.clone(),
+					method,
+					verified_at: Timestamp::now(),
+					proof: public_message_proof,
+				}
+				.dispatch_bypass_filter(RuntimeOrigin::root())
+				.map_err(|e| StfError::ReverifyIdentityFailed(e.into()))?;
+
+				if context.metadata.deactivation_reason == Some(DeactivationReason::OwnershipLost) {
+					IMTCall::activate_identity { who: who.clone(), identity }
+						.dispatch_bypass_filter(RuntimeOrigin::root())
+						.map_err(|e| StfError::ReverifyIdentityFailed(e.into()))?;
+				}
+
+				let id_graph_hash: H256 = IMT::id_graph_hash(&who).ok_or(StfError::EmptyIDGraph)?;
+
+				debug!("pushing identity_reverified event ...");
+				let call_index = node_metadata_repo
+					.get_from_metadata(|m| m.identity_reverified_call_indexes())??;
+				calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+					call_index,
+					who.clone(),
+					id_graph_hash,
+					req_ext_hash,
+				))));
+
+				let mut mutated_id_graph = IMT::id_graph(&who);
+				mutated_id_graph.retain(|i| !old_id_graph.contains(i));
+
+				if let Some(key) = maybe_key {
+					return Ok(TrustedCallResult::ReverifyIdentity(ReverifyIdentityResult {
+						mutated_id_graph: aes_encrypt_default(&key, &mutated_id_graph.encode()),
+						id_graph_hash,
+					}))
+				}
+
+				Ok(TrustedCallResult::Empty)
+			},
+			Err(detail) => {
+				// only a verifier that found the message missing or posted by another handle
+				// proves the ownership is lost, e.g. a provider outage or an expired verification
+				// leaves the identity as it is
+				let ownership_lost =
+					matches!(detail, ErrorDetail::WrongWeb2Handle | ErrorDetail::UnexpectedMessage);
+				if ownership_lost && context.is_active() {
+					IMTCall::deactivate_identity {
+						who: who.clone(),
+						identity,
+						reason: DeactivationReason::OwnershipLost,
+					}
+					.dispatch_bypass_filter(RuntimeOrigin::root())
+					.map_err(|e| StfError::ReverifyIdentityFailed(e.into()))?;
+
+					let id_graph_hash: H256 =
+						IMT::id_graph_hash(&who).ok_or(StfError::EmptyIDGraph)?;
+
+					debug!("pushing identity_deactivated event ...");
+					let call_index = node_metadata_repo
+						.get_from_metadata(|m| m.identity_deactivated_call_indexes())??;
+					calls.push(ParentchainCall::Litentry(OpaqueCall::from_tuple(&(
+						call_index,
+						who.clone(),
+						id_graph_hash,
+						req_ext_hash,
+					))));
+				}
+
+				let e = StfError::ReverifyIdentityFailed(detail);
+				push_call_imp_some_error(
+					calls,
+					node_metadata_repo,
+					Some(who),
+					e.to_imp_error(),
+					req_ext_hash,
+				);
+				Err(e)
+			},
+		}
+	}
 }
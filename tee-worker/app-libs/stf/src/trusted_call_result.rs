@@ -41,6 +41,16 @@ pub enum TrustedCallResult {
 	ActivateIdentity(ActivateIdentityResult),
 	#[codec(index = 6)]
 	SetIdentityNetworks(SetIdentityNetworksResult),
+	#[codec(index = 7)]
+	UnlinkIdentity(UnlinkIdentityResult),
+	#[codec(index = 8)]
+	MergeIDGraphs(MergeIDGraphsResult),
+	#[codec(index = 9)]
+	TransferPrimeIdentity(TransferPrimeIdentityResult),
+	#[codec(index = 10)]
+	SetIdentityLabel(SetIdentityLabelResult),
+	#[codec(index = 11)]
+	ReverifyIdentity(ReverifyIdentityResult),
 }
 
 impl StfExecutionResult for TrustedCallResult {
@@ -53,6 +63,11 @@ impl StfExecutionResult for TrustedCallResult {
 			Self::DeactivateIdentity(result) => result.encode(),
 			Self::ActivateIdentity(result) => result.encode(),
 			Self::SetIdentityNetworks(result) => result.encode(),
+			Self::UnlinkIdentity(result) => result.encode(),
+			Self::MergeIDGraphs(result) => result.encode(),
+			Self::TransferPrimeIdentity(result) => result.encode(),
+			Self::SetIdentityLabel(result) => result.encode(),
+			Self::ReverifyIdentity(result) => result.encode(),
 		}
 	}
 
@@ -70,6 +85,12 @@ impl StfExecutionResult for TrustedCallResult {
 /// - `activate_identity`
 /// - `deactivate_identity`
 /// - `set_identity_networks`
+/// - `unlink_identity`, where `mutated_id_graph` contains the unlinked identity
+/// - `merge_id_graphs`
+/// - `transfer_prime_identity` and `finalize_recovery`, where `mutated_id_graph` is the whole
+///   re-keyed IDGraph
+/// - `set_identity_label`
+/// - `reverify_identity`, where `mutated_id_graph` contains the refreshed verification metadata
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
 pub struct LinkIdentityResult {
 	pub mutated_id_graph: AesOutput,
@@ -94,6 +115,36 @@ pub struct SetIdentityNetworksResult {
 	pub id_graph_hash: H256,
 }
 
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct UnlinkIdentityResult {
+	pub mutated_id_graph: AesOutput,
+	pub id_graph_hash: H256,
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct MergeIDGraphsResult {
+	pub mutated_id_graph: AesOutput,
+	pub id_graph_hash: H256,
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct TransferPrimeIdentityResult {
+	pub mutated_id_graph: AesOutput,
+	pub id_graph_hash: H256,
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct SetIdentityLabelResult {
+	pub mutated_id_graph: AesOutput,
+	pub id_graph_hash: H256,
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct ReverifyIdentityResult {
+	pub mutated_id_graph: AesOutput,
+	pub id_graph_hash: H256,
+}
+
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
 pub struct RequestVCResult {
 	pub vc_payload: AesOutput,
@@ -71,6 +71,8 @@ impl LinkIdentityCommand {
 			dst_id,
 			networks,
 			None,
+			None,
+			None,
 			Default::default(),
 		)
 		.sign(&KeyPair::Sr25519(Box::new(alice)), nonce, &mrenclave, &shard)
@@ -21,3 +21,4 @@ pub mod link_identity;
 pub mod remove_identity;
 pub mod request_vc;
 pub mod send_erroneous_parentchain_call;
+pub mod unlink_identity;
@@ -0,0 +1,58 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	get_layer_two_nonce,
+	trusted_cli::TrustedCli,
+	trusted_command_utils::{get_identifiers, get_pair_from_str},
+	trusted_operation::perform_trusted_operation,
+	Cli, CliResult, CliResultOk,
+};
+use clap::Parser;
+use ita_stf::{Index, TrustedCall};
+use itp_stf_primitives::{traits::TrustedCallSigning, types::KeyPair};
+use litentry_primitives::Identity;
+use sp_core::Pair;
+
+// usage exmaple:
+//
+// # unlink `my_twitter` identity from substrate //Bob account, signed by //Bob
+// ./bin/litentry-cli trusted -d unlink-identity //Bob did:litentry:twitter:my_twitter
+
+#[derive(Parser)]
+pub struct UnlinkIdentityCommand {
+	/// The prime account, which must sign the request
+	account: String,
+	/// The to-be-unlinked identity in did format
+	dst_did: String,
+}
+
+impl UnlinkIdentityCommand {
+	pub(crate) fn run(&self, cli: &Cli, trusted_cli: &TrustedCli) -> CliResult {
+		let signer = get_pair_from_str(trusted_cli, &self.account, cli);
+		let src_id: Identity = signer.public().into();
+		let dst_id: Identity = Identity::from_did(self.dst_did.as_str()).unwrap();
+
+		let (mrenclave, shard) = get_identifiers(trusted_cli, cli);
+		let nonce = get_layer_two_nonce!(signer, cli, trusted_cli);
+
+		let top =
+			TrustedCall::unlink_identity(src_id.clone(), src_id, dst_id, None, Default::default())
+				.sign(&KeyPair::Sr25519(Box::new(signer)), nonce, &mrenclave, &shard)
+				.into_trusted_operation(trusted_cli.direct);
+		Ok(perform_trusted_operation::<()>(cli, trusted_cli, &top).map(|_| CliResultOk::None)?)
+	}
+}
@@ -25,6 +25,7 @@ use crate::{
 			get_storage::GetStorageCommand, link_identity::LinkIdentityCommand,
 			request_vc::RequestVcCommand,
 			send_erroneous_parentchain_call::SendErroneousParentchainCallCommand,
+			unlink_identity::UnlinkIdentityCommand,
 		},
 		nonce::NonceCommand,
 		set_balance::SetBalanceCommand,
@@ -77,6 +78,9 @@ pub enum TrustedBaseCommand {
 	/// Link the given identity to the prime identity, with specified networks
 	LinkIdentity(LinkIdentityCommand),
 
+	/// Unlink the given identity from the signer's IDGraph
+	UnlinkIdentity(UnlinkIdentityCommand),
+
 	/// The IDGraph for the given identity
 	IDGraph(IDGraphCommand),
 
@@ -102,6 +106,7 @@ impl TrustedBaseCommand {
 			TrustedBaseCommand::GetStorage(cmd) => cmd.run(cli, trusted_cli),
 			TrustedBaseCommand::SendErroneousParentchainCall(cmd) => cmd.run(cli, trusted_cli),
 			TrustedBaseCommand::LinkIdentity(cmd) => cmd.run(cli, trusted_cli),
+			TrustedBaseCommand::UnlinkIdentity(cmd) => cmd.run(cli, trusted_cli),
 			TrustedBaseCommand::IDGraph(cmd) => cmd.run(cli, trusted_cli),
 			TrustedBaseCommand::RequestVc(cmd) => cmd.run(cli, trusted_cli),
 			#[cfg(feature = "development")]
@@ -19,6 +19,26 @@ export default {
             mutated_id_graph: "AesOutput",
             id_graph_hash: "H256",
         },
+        UnlinkIdentityResult: {
+            mutated_id_graph: "AesOutput",
+            id_graph_hash: "H256",
+        },
+        MergeIDGraphsResult: {
+            mutated_id_graph: "AesOutput",
+            id_graph_hash: "H256",
+        },
+        TransferPrimeIdentityResult: {
+            mutated_id_graph: "AesOutput",
+            id_graph_hash: "H256",
+        },
+        SetIdentityLabelResult: {
+            mutated_id_graph: "AesOutput",
+            id_graph_hash: "H256",
+        },
+        ReverifyIdentityResult: {
+            mutated_id_graph: "AesOutput",
+            id_graph_hash: "H256",
+        },
         LitentryIdentity: {
             _enum: {
                 Twitter: "IdentityString",
@@ -139,6 +159,24 @@ export default {
             link_block: "BlockNumber",
             web3networks: "BoundedWeb3Network",
             status: "IdentityStatus",
+            metadata: "IdentityMetadata",
+        },
+        IdentityMetadata: {
+            label: "Option<Bytes>",
+            verified_at: "Option<u64>",
+            verification_method: "Option<VerificationMethod>",
+            public_message_proof: "Option<PublicMessageProof>",
+            deactivation_reason: "Option<DeactivationReason>",
+        },
+        PublicMessageProof: {
+            raw_msg: "Bytes",
+            validation_data: "Web2ValidationData",
+        },
+        DeactivationReason: {
+            _enum: ["User", "OwnershipLost", "Recovery"],
+        },
+        VerificationMethod: {
+            _enum: ["Web3Signature", "PublicMessage", "OAuth2"],
         },
         BoundedWeb3Network: "BoundedVec<Web3Network, ConstU32<128>>",
     },
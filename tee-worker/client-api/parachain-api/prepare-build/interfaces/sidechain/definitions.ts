@@ -53,12 +53,12 @@ export default {
                 UnclassifiedError: "Null",
                 RemoveIdentityFailed: "(ErrorDetail)",
                 EmptyIDGraph: "Null",
-                __Unused10: "Null",
-                __Unused11: "Null",
-                __Unused12: "Null",
-                __Unused13: "Null",
-                __Unused14: "Null",
-                __Unused15: "Null",
+                UnlinkIdentityFailed: "(ErrorDetail)",
+                MergeIDGraphsFailed: "(ErrorDetail)",
+                TransferPrimeIdentityFailed: "(ErrorDetail)",
+                RecoveryFailed: "(ErrorDetail)",
+                ReverifyIdentityFailed: "(ErrorDetail)",
+                SetIdentityLabelFailed: "(ErrorDetail)",
                 __Unused16: "Null",
                 __Unused17: "Null",
                 __Unused18: "Null",
@@ -87,6 +87,7 @@ export default {
                 __Unused_WrongSignatureType: "Null",
                 VerifyWeb3SignatureFailed: "Null",
                 NoEligibleIdentity: "Null",
+                VerificationExpired: "Null",
             },
         },
         ShardIdentifier: "H256",
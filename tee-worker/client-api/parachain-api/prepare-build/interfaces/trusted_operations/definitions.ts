@@ -40,24 +40,30 @@ export default {
                     "(LitentryIdentity, LitentryIdentity, LitentryIdentity, Vec<Web3Network>, Option<RequestAesKey>, H256)",
                 __Unused_remove_identity: "Null",
                 request_batch_vc: "(LitentryIdentity, LitentryIdentity, BoundedVec<Assertion, ConstU32<32>>, Option<RequestAesKey>, H256)",
+                unlink_identity: "(LitentryIdentity, LitentryIdentity, LitentryIdentity, Option<RequestAesKey>, H256)",
+                merge_id_graphs:
+                    "(LitentryIdentity, LitentryIdentity, LitentryIdentity, Web3ValidationData, Option<RequestAesKey>, H256)",
+                transfer_prime_identity:
+                    "(LitentryIdentity, LitentryIdentity, LitentryIdentity, Option<RequestAesKey>, H256)",
 
-                __Unused_7: "Null",
-                __Unused_8: "Null",
-                __Unused_9: "Null",
-                __Unused_10: "Null",
-                __Unused_11: "Null",
-                __Unused_12: "Null",
-                __Unused_13: "Null",
-                __Unused_14: "Null",
-                __Unused_15: "Null",
-                __Unused_16: "Null",
+                set_recovery_guardians:
+                    "(LitentryIdentity, LitentryIdentity, Vec<LitentryIdentity>, u32, u32, Option<RequestAesKey>, H256)",
+                initiate_recovery:
+                    "(LitentryIdentity, LitentryIdentity, LitentryIdentity, Option<RequestAesKey>, H256)",
+                approve_recovery: "(LitentryIdentity, LitentryIdentity, Option<RequestAesKey>, H256)",
+                cancel_recovery: "(LitentryIdentity, LitentryIdentity, Option<RequestAesKey>, H256)",
+                finalize_recovery: "(LitentryIdentity, LitentryIdentity, Option<RequestAesKey>, H256)",
+                set_identity_label:
+                    "(LitentryIdentity, LitentryIdentity, LitentryIdentity, Option<Bytes>, Option<RequestAesKey>, H256)",
+                reverify_identity:
+                    "(LitentryIdentity, LitentryIdentity, LitentryIdentity, Web2ValidationData, Option<RequestAesKey>, H256)",
                 __Unused_17: "Null",
                 __Unused_18: "Null",
                 __Unused_19: "Null",
 
                 // this trusted call can only be requested directly by root or enclave_signer_account
                 link_identity_callback:
-                    "(LitentryIdentity, LitentryIdentity, LitentryIdentity, Vec<Web3Network>, Option<RequestAesKey>, H256)",
+                    "(LitentryIdentity, LitentryIdentity, LitentryIdentity, Vec<Web3Network>, Option<VerificationMethod>, Option<PublicMessageProof>, Option<RequestAesKey>, H256)",
             },
         },
         TrustedOperationStatus: {
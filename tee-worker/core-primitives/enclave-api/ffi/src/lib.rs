@@ -238,6 +238,13 @@ extern "C" {
 		shard_size: u32,
 	) -> sgx_status_t;
 
+	pub fn init_oidc_issuer(
+		eid: sgx_enclave_id_t,
+		retval: *mut sgx_status_t,
+		issuer: *const u8,
+		issuer_size: u32,
+	) -> sgx_status_t;
+
 	pub fn ignore_parentchain_block_import_validation_until(
 		eid: sgx_enclave_id_t,
 		retval: *mut sgx_status_t,
@@ -81,6 +81,9 @@ pub trait EnclaveBase: Send + Sync + 'static {
 
 	// litentry
 	fn migrate_shard(&self, new_shard: Vec<u8>) -> EnclaveResult<()>;
+
+	/// Seal the issuer URL of the OIDC provider, it can't be changed once it's sealed
+	fn init_oidc_issuer(&self, issuer: &str) -> EnclaveResult<()>;
 }
 
 /// EnclaveApi implementation for Enclave struct
@@ -386,6 +389,26 @@ mod impl_ffi {
 
 			Ok(())
 		}
+
+		fn init_oidc_issuer(&self, issuer: &str) -> EnclaveResult<()> {
+			let mut retval = sgx_status_t::SGX_SUCCESS;
+
+			let encoded_issuer = issuer.encode();
+
+			let result = unsafe {
+				ffi::init_oidc_issuer(
+					self.eid,
+					&mut retval,
+					encoded_issuer.as_ptr(),
+					encoded_issuer.len() as u32,
+				)
+			};
+
+			ensure!(result == sgx_status_t::SGX_SUCCESS, Error::Sgx(result));
+			ensure!(retval == sgx_status_t::SGX_SUCCESS, Error::Sgx(retval));
+
+			Ok(())
+		}
 	}
 
 	fn init_parentchain_components_ffi(
@@ -51,6 +51,7 @@ pub trait PalletTeebagApi {
 		worker_type: WorkerType,
 		at_block: Option<Self::Hash>,
 	) -> ApiResult<Vec<Enclave>>;
+	fn reattestation_period(&self, at_block: Option<Self::Hash>) -> ApiResult<Option<u64>>;
 }
 
 impl<RuntimeConfig, Client> PalletTeebagApi for Api<RuntimeConfig, Client>
@@ -131,4 +132,8 @@ where
 			.collect();
 		Ok(enclaves)
 	}
+
+	fn reattestation_period(&self, at_block: Option<Self::Hash>) -> ApiResult<Option<u64>> {
+		self.get_storage(TEEBAG, "ReattestationPeriod", at_block)
+	}
 }
@@ -59,6 +59,16 @@ pub struct NodeMetadataMock {
 	imp_identity_deactivated: u8,
 	imp_identity_activated: u8,
 	imp_identity_networks_set: u8,
+	imp_identity_unlinked: u8,
+	imp_id_graphs_merged: u8,
+	imp_prime_identity_transferred: u8,
+	imp_recovery_guardians_set: u8,
+	imp_recovery_initiated: u8,
+	imp_recovery_approved: u8,
+	imp_recovery_cancelled: u8,
+	imp_prime_identity_recovered: u8,
+	imp_identity_label_set: u8,
+	imp_identity_reverified: u8,
 	imp_some_error: u8,
 	// VCMP
 	vcmp_module: u8,
@@ -115,6 +125,16 @@ impl NodeMetadataMock {
 			imp_identity_activated: 8u8,
 			imp_identity_networks_set: 9u8,
 			imp_some_error: 10u8,
+			imp_identity_unlinked: 11u8,
+			imp_id_graphs_merged: 12u8,
+			imp_prime_identity_transferred: 13u8,
+			imp_recovery_guardians_set: 14u8,
+			imp_recovery_initiated: 15u8,
+			imp_recovery_approved: 16u8,
+			imp_recovery_cancelled: 17u8,
+			imp_prime_identity_recovered: 18u8,
+			imp_identity_label_set: 19u8,
+			imp_identity_reverified: 20u8,
 
 			vcmp_module: 66u8,
 			vcmp_request_vc: 0u8,
@@ -210,6 +230,46 @@ impl IMPCallIndexes for NodeMetadataMock {
 		Ok([self.imp_module, self.imp_identity_networks_set])
 	}
 
+	fn identity_unlinked_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_identity_unlinked])
+	}
+
+	fn id_graphs_merged_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_id_graphs_merged])
+	}
+
+	fn prime_identity_transferred_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_prime_identity_transferred])
+	}
+
+	fn recovery_guardians_set_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_recovery_guardians_set])
+	}
+
+	fn recovery_initiated_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_recovery_initiated])
+	}
+
+	fn recovery_approved_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_recovery_approved])
+	}
+
+	fn recovery_cancelled_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_recovery_cancelled])
+	}
+
+	fn prime_identity_recovered_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_prime_identity_recovered])
+	}
+
+	fn identity_label_set_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_identity_label_set])
+	}
+
+	fn identity_reverified_call_indexes(&self) -> Result<[u8; 2]> {
+		Ok([self.imp_module, self.imp_identity_reverified])
+	}
+
 	fn imp_some_error_call_indexes(&self) -> Result<[u8; 2]> {
 		Ok([self.imp_module, self.imp_some_error])
 	}
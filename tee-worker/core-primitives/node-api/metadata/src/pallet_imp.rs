@@ -29,6 +29,16 @@ pub trait IMPCallIndexes {
 	fn identity_deactivated_call_indexes(&self) -> Result<[u8; 2]>;
 	fn identity_activated_call_indexes(&self) -> Result<[u8; 2]>;
 	fn identity_networks_set_call_indexes(&self) -> Result<[u8; 2]>;
+	fn identity_unlinked_call_indexes(&self) -> Result<[u8; 2]>;
+	fn id_graphs_merged_call_indexes(&self) -> Result<[u8; 2]>;
+	fn prime_identity_transferred_call_indexes(&self) -> Result<[u8; 2]>;
+	fn recovery_guardians_set_call_indexes(&self) -> Result<[u8; 2]>;
+	fn recovery_initiated_call_indexes(&self) -> Result<[u8; 2]>;
+	fn recovery_approved_call_indexes(&self) -> Result<[u8; 2]>;
+	fn recovery_cancelled_call_indexes(&self) -> Result<[u8; 2]>;
+	fn prime_identity_recovered_call_indexes(&self) -> Result<[u8; 2]>;
+	fn identity_label_set_call_indexes(&self) -> Result<[u8; 2]>;
+	fn identity_reverified_call_indexes(&self) -> Result<[u8; 2]>;
 	fn imp_some_error_call_indexes(&self) -> Result<[u8; 2]>;
 }
 
@@ -65,6 +75,46 @@ impl IMPCallIndexes for NodeMetadata {
 		self.call_indexes(IMP, "identity_networks_set")
 	}
 
+	fn identity_unlinked_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "identity_unlinked")
+	}
+
+	fn id_graphs_merged_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "id_graphs_merged")
+	}
+
+	fn prime_identity_transferred_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "prime_identity_transferred")
+	}
+
+	fn recovery_guardians_set_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "recovery_guardians_set")
+	}
+
+	fn recovery_initiated_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "recovery_initiated")
+	}
+
+	fn recovery_approved_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "recovery_approved")
+	}
+
+	fn recovery_cancelled_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "recovery_cancelled")
+	}
+
+	fn prime_identity_recovered_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "prime_identity_recovered")
+	}
+
+	fn identity_label_set_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "identity_label_set")
+	}
+
+	fn identity_reverified_call_indexes(&self) -> Result<[u8; 2]> {
+		self.call_indexes(IMP, "identity_reverified")
+	}
+
 	fn imp_some_error_call_indexes(&self) -> Result<[u8; 2]> {
 		self.call_indexes(IMP, "some_error")
 	}
@@ -48,6 +48,8 @@ pub mod files {
 	// litentry
 	pub const ASSERTIONS_FILE: &str = "assertions_sealed.bin";
 
+	pub const OIDC_ISSUER_FILE: &str = "oidc_issuer_sealed.bin";
+
 	pub const RA_DUMP_CERT_DER_FILE: &str = "ra_dump_cert.der";
 
 	// used by worker and enclave
@@ -51,6 +51,24 @@ pub enum StfError {
 	RemoveIdentityFailed(ErrorDetail),
 	#[codec(index = 9)]
 	EmptyIDGraph,
+	#[codec(index = 10)]
+	#[display(fmt = "UnlinkIdentityFailed: {:?}", _0)]
+	UnlinkIdentityFailed(ErrorDetail),
+	#[codec(index = 11)]
+	#[display(fmt = "MergeIDGraphsFailed: {:?}", _0)]
+	MergeIDGraphsFailed(ErrorDetail),
+	#[codec(index = 12)]
+	#[display(fmt = "TransferPrimeIdentityFailed: {:?}", _0)]
+	TransferPrimeIdentityFailed(ErrorDetail),
+	#[codec(index = 13)]
+	#[display(fmt = "RecoveryFailed: {:?}", _0)]
+	RecoveryFailed(ErrorDetail),
+	#[codec(index = 14)]
+	#[display(fmt = "ReverifyIdentityFailed: {:?}", _0)]
+	ReverifyIdentityFailed(ErrorDetail),
+	#[codec(index = 15)]
+	#[display(fmt = "SetIdentityLabelFailed: {:?}", _0)]
+	SetIdentityLabelFailed(ErrorDetail),
 
 	// upstream errors
 	#[codec(index = 20)]
@@ -82,6 +100,12 @@ impl From<IMPError> for StfError {
 			IMPError::LinkIdentityFailed(d) => StfError::LinkIdentityFailed(d),
 			IMPError::DeactivateIdentityFailed(d) => StfError::DeactivateIdentityFailed(d),
 			IMPError::ActivateIdentityFailed(d) => StfError::ActivateIdentityFailed(d),
+			IMPError::UnlinkIdentityFailed(d) => StfError::UnlinkIdentityFailed(d),
+			IMPError::MergeIDGraphsFailed(d) => StfError::MergeIDGraphsFailed(d),
+			IMPError::TransferPrimeIdentityFailed(d) => StfError::TransferPrimeIdentityFailed(d),
+			IMPError::RecoveryFailed(d) => StfError::RecoveryFailed(d),
+			IMPError::ReverifyIdentityFailed(d) => StfError::ReverifyIdentityFailed(d),
+			IMPError::SetIdentityLabelFailed(d) => StfError::SetIdentityLabelFailed(d),
 			_ => StfError::UnclassifiedError,
 		}
 	}
@@ -103,6 +127,13 @@ impl StfError {
 			StfError::LinkIdentityFailed(d) => IMPError::LinkIdentityFailed(d.clone()),
 			StfError::DeactivateIdentityFailed(d) => IMPError::DeactivateIdentityFailed(d.clone()),
 			StfError::ActivateIdentityFailed(d) => IMPError::ActivateIdentityFailed(d.clone()),
+			StfError::UnlinkIdentityFailed(d) => IMPError::UnlinkIdentityFailed(d.clone()),
+			StfError::MergeIDGraphsFailed(d) => IMPError::MergeIDGraphsFailed(d.clone()),
+			StfError::TransferPrimeIdentityFailed(d) =>
+				IMPError::TransferPrimeIdentityFailed(d.clone()),
+			StfError::RecoveryFailed(d) => IMPError::RecoveryFailed(d.clone()),
+			StfError::ReverifyIdentityFailed(d) => IMPError::ReverifyIdentityFailed(d.clone()),
+			StfError::SetIdentityLabelFailed(d) => IMPError::SetIdentityLabelFailed(d.clone()),
 			_ => IMPError::UnclassifiedError(ErrorDetail::StfError(ErrorString::truncate_from(
 				format!("{:?}", self).as_bytes().to_vec(),
 			))),
@@ -6,6 +6,7 @@ use crate::{
 use codec::{Decode, Encode};
 use core::fmt::Debug;
 use itp_utils::{hex::ToHexPrefixed, stringify::account_id_to_string};
+use litentry_primitives::EvictionReason;
 
 use sp_core::H160;
 use substrate_api_client::ac_node_api::StaticEvent;
@@ -100,6 +101,28 @@ impl StaticEvent for EnclaveUnauthorized {
 	const EVENT: &'static str = "EnclaveUnauthorized";
 }
 
+#[derive(Encode, Decode, Debug)]
+pub struct EnclaveEvicted {
+	pub who: AccountId,
+	pub reason: EvictionReason,
+}
+
+impl core::fmt::Display for EnclaveEvicted {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let message = format!(
+			"EnclaveEvicted :: who: {}, reason: {:?}",
+			account_id_to_string(&self.who),
+			self.reason
+		);
+		write!(f, "{}", message)
+	}
+}
+
+impl StaticEvent for EnclaveEvicted {
+	const PALLET: &'static str = "Teebag";
+	const EVENT: &'static str = "EnclaveEvicted";
+}
+
 // IdentityManagement events
 
 #[derive(Encode, Decode, Debug)]
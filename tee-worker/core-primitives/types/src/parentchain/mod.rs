@@ -22,7 +22,7 @@ use alloc::vec::Vec;
 use codec::{Decode, Encode};
 use core::fmt::Debug;
 use events::{
-	ActivateIdentityRequested, DeactivateIdentityRequested, EnclaveUnauthorized,
+	ActivateIdentityRequested, DeactivateIdentityRequested, EnclaveEvicted, EnclaveUnauthorized,
 	LinkIdentityRequested, OpaqueTaskPosted, VCRequested,
 };
 use itp_stf_primitives::traits::{IndirectExecutor, TrustedCallVerification};
@@ -109,6 +109,8 @@ pub trait FilterEvents {
 
 	fn get_enclave_unauthorized_events(&self) -> Result<Vec<EnclaveUnauthorized>, Self::Error>;
 
+	fn get_enclave_evicted_events(&self) -> Result<Vec<EnclaveEvicted>, Self::Error>;
+
 	fn get_opaque_task_posted_events(&self) -> Result<Vec<OpaqueTaskPosted>, Self::Error>;
 
 	fn get_assertion_created_events(&self) -> Result<Vec<AssertionCreated>, Self::Error>;
@@ -5,7 +5,8 @@ use itp_types::{
 	parentchain::{
 		events::{
 			ActivateIdentityRequested, AssertionCreated, DeactivateIdentityRequested,
-			EnclaveUnauthorized, LinkIdentityRequested, OpaqueTaskPosted, VCRequested,
+			EnclaveEvicted, EnclaveUnauthorized, LinkIdentityRequested, OpaqueTaskPosted,
+			VCRequested,
 		},
 		FilterEvents, HandleParentchainEvents, ProcessedEventsArtifacts,
 	},
@@ -61,6 +62,10 @@ impl FilterEvents for MockEvents {
 		Ok(Vec::new())
 	}
 
+	fn get_enclave_evicted_events(&self) -> Result<Vec<EnclaveEvicted>, Self::Error> {
+		Ok(Vec::new())
+	}
+
 	fn get_assertion_created_events(&self) -> Result<Vec<AssertionCreated>, Self::Error> {
 		Ok(Vec::new())
 	}
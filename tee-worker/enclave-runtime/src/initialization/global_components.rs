@@ -27,7 +27,7 @@ use crate::{
 		target_b_parachain::TargetBParachainHandler, target_b_solochain::TargetBSolochainHandler,
 	},
 	ocall::OcallApi,
-	rpc::rpc_response_channel::RpcResponseChannel,
+	rpc::{oidc_api::OIDCIssuerSeal, rpc_response_channel::RpcResponseChannel},
 	tls_ra::seal_handler::SealHandler,
 };
 use ita_parentchain_interface::{integritee, target_a, target_b};
@@ -506,3 +506,6 @@ pub static GLOBAL_SIDECHAIN_FAIL_SLOT_ON_DEMAND_COMPONENT: ComponentContainer<
 
 pub static GLOBAL_DATA_PROVIDER_CONFIG: ComponentContainer<DataProviderConfig> =
 	ComponentContainer::new("data_provider_config");
+
+pub static GLOBAL_OIDC_ISSUER_SEAL_COMPONENT: ComponentContainer<OIDCIssuerSeal> =
+	ComponentContainer::new("oidc_issuer_seal");
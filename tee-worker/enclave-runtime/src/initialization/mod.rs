@@ -29,18 +29,22 @@ use crate::{
 		DIRECT_RPC_REQUEST_SINK_COMPONENT, GLOBAL_ASSERTION_REPOSITORY,
 		GLOBAL_ATTESTATION_HANDLER_COMPONENT, GLOBAL_DATA_PROVIDER_CONFIG,
 		GLOBAL_DIRECT_RPC_BROADCASTER_COMPONENT, GLOBAL_INTEGRITEE_PARENTCHAIN_LIGHT_CLIENT_SEAL,
-		GLOBAL_OCALL_API_COMPONENT, GLOBAL_RPC_WS_HANDLER_COMPONENT,
-		GLOBAL_SHIELDING_KEY_REPOSITORY_COMPONENT, GLOBAL_SIDECHAIN_BLOCK_COMPOSER_COMPONENT,
-		GLOBAL_SIDECHAIN_BLOCK_SYNCER_COMPONENT, GLOBAL_SIDECHAIN_FAIL_SLOT_ON_DEMAND_COMPONENT,
-		GLOBAL_SIDECHAIN_IMPORT_QUEUE_COMPONENT, GLOBAL_SIDECHAIN_IMPORT_QUEUE_WORKER_COMPONENT,
-		GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT, GLOBAL_STATE_HANDLER_COMPONENT,
-		GLOBAL_STATE_KEY_REPOSITORY_COMPONENT, GLOBAL_STATE_OBSERVER_COMPONENT,
-		GLOBAL_TARGET_A_PARENTCHAIN_LIGHT_CLIENT_SEAL,
+		GLOBAL_OCALL_API_COMPONENT, GLOBAL_OIDC_ISSUER_SEAL_COMPONENT,
+		GLOBAL_RPC_WS_HANDLER_COMPONENT, GLOBAL_SHIELDING_KEY_REPOSITORY_COMPONENT,
+		GLOBAL_SIDECHAIN_BLOCK_COMPOSER_COMPONENT, GLOBAL_SIDECHAIN_BLOCK_SYNCER_COMPONENT,
+		GLOBAL_SIDECHAIN_FAIL_SLOT_ON_DEMAND_COMPONENT, GLOBAL_SIDECHAIN_IMPORT_QUEUE_COMPONENT,
+		GLOBAL_SIDECHAIN_IMPORT_QUEUE_WORKER_COMPONENT, GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT,
+		GLOBAL_STATE_HANDLER_COMPONENT, GLOBAL_STATE_KEY_REPOSITORY_COMPONENT,
+		GLOBAL_STATE_OBSERVER_COMPONENT, GLOBAL_TARGET_A_PARENTCHAIN_LIGHT_CLIENT_SEAL,
 		GLOBAL_TARGET_B_PARENTCHAIN_LIGHT_CLIENT_SEAL, GLOBAL_TOP_POOL_AUTHOR_COMPONENT,
 		GLOBAL_WEB_SOCKET_SERVER_COMPONENT,
 	},
 	ocall::OcallApi,
-	rpc::{common_api::add_common_api, rpc_response_channel::RpcResponseChannel},
+	rpc::{
+		common_api::add_common_api,
+		oidc_api::{add_oidc_api, OIDCIssuerSeal},
+		rpc_response_channel::RpcResponseChannel,
+	},
 	utils::{
 		get_extrinsic_factory_from_integritee_solo_or_parachain,
 		get_node_metadata_repository_from_integritee_solo_or_parachain,
@@ -67,12 +71,14 @@ use itp_attestation_handler::IntelAttestationHandler;
 use itp_component_container::{ComponentGetter, ComponentInitializer};
 use itp_primitives_cache::GLOBAL_PRIMITIVES_CACHE;
 use itp_settings::files::{
-	ASSERTIONS_FILE, LITENTRY_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, STATE_SNAPSHOTS_CACHE_SIZE,
-	TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
+	ASSERTIONS_FILE, LITENTRY_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, OIDC_ISSUER_FILE,
+	STATE_SNAPSHOTS_CACHE_SIZE, TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
+	TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
 };
 use itp_sgx_crypto::{
 	get_aes_repository, get_ed25519_repository, get_rsa3072_repository, key_repository::AccessKey,
 };
+use itp_sgx_io::SealedIO;
 use itp_stf_state_handler::{
 	file_io::StateDir, handle_state::HandleState, query_shard_state::QueryShardState,
 	state_snapshot_repository::VersionedStateAccess,
@@ -89,7 +95,9 @@ use jsonrpc_core::IoHandler;
 use lc_data_providers::DataProviderConfig;
 use lc_evm_dynamic_assertions::repository::EvmAssertionRepository;
 use lc_parachain_extrinsic_task_receiver::run_parachain_extrinsic_task_receiver;
-use lc_stf_task_receiver::{run_stf_task_receiver, StfTaskContext};
+use lc_stf_task_receiver::{
+	run_identity_reverification_job, run_stf_task_receiver, StfTaskContext,
+};
 use lc_vc_task_receiver::run_vc_handler_runner;
 use litentry_primitives::BroadcastedRequest;
 use log::*;
@@ -133,6 +141,9 @@ pub(crate) fn init_enclave(
 	)?);
 	GLOBAL_TARGET_B_PARENTCHAIN_LIGHT_CLIENT_SEAL.initialize(target_b_light_client_seal);
 
+	let oidc_issuer_seal = Arc::new(OIDCIssuerSeal::new(base_dir.join(OIDC_ISSUER_FILE)));
+	GLOBAL_OIDC_ISSUER_SEAL_COMPONENT.initialize(oidc_issuer_seal.clone());
+
 	let state_file_io =
 		Arc::new(EnclaveStateFileIo::new(state_key_repository, StateDir::new(base_dir)));
 	let state_initializer =
@@ -220,10 +231,18 @@ pub(crate) fn init_enclave(
 		getter_executor,
 		shielding_key_repository,
 		ocall_api.clone(),
-		Some(state_handler),
+		Some(state_handler.clone()),
 		data_provider_config,
 	);
 
+	add_oidc_api(
+		&mut io_handler,
+		ocall_api.clone(),
+		state_handler,
+		signing_key_repository.clone(),
+		oidc_issuer_seal,
+	);
+
 	#[cfg(feature = "sidechain")]
 	{
 		use crate::initialization::global_components::EnclaveSidechainBlockImportQueue;
@@ -281,18 +300,23 @@ fn run_stf_task_handler() -> Result<(), Error> {
 
 	let enclave_account = Arc::new(GLOBAL_SIGNING_KEY_REPOSITORY_COMPONENT.get()?.retrieve_key()?);
 
-	let stf_task_context = StfTaskContext::new(
+	let stf_task_context = Arc::new(StfTaskContext::new(
 		shielding_key_repository,
 		author_api,
 		stf_enclave_signer,
 		enclave_account,
 		state_handler,
 		ocall_api,
-		data_provider_config,
+		data_provider_config.clone(),
 		evm_assertion_repository,
-	);
+	));
 
-	run_stf_task_receiver(Arc::new(stf_task_context)).map_err(Error::StfTaskReceiver)
+	if data_provider_config.identity_reverification_interval > 0 {
+		let context = stf_task_context.clone();
+		std::thread::spawn(move || run_identity_reverification_job(context));
+	}
+
+	run_stf_task_receiver(stf_task_context).map_err(Error::StfTaskReceiver)
 }
 
 fn run_vc_issuance() -> Result<(), Error> {
@@ -471,6 +495,27 @@ pub(crate) fn migrate_shard(new_shard: ShardIdentifier) -> EnclaveResult<()> {
 	Ok(())
 }
 
+/// Seal the issuer of the OIDC provider, the ID tokens and the client assertions are checked
+/// against the sealed issuer, so the untrusted worker can't change it once it's sealed
+pub(crate) fn init_oidc_issuer(issuer: String) -> EnclaveResult<()> {
+	if issuer.is_empty() {
+		return Err(Error::Other("empty OIDC issuer".into()))
+	}
+	let issuer_seal = GLOBAL_OIDC_ISSUER_SEAL_COMPONENT.get()?;
+	if issuer_seal.exists() {
+		let sealed = issuer_seal.unseal()?;
+		if sealed != issuer {
+			return Err(Error::Other(
+				format!("OIDC issuer is already sealed as {}, got {}", sealed, issuer).into(),
+			))
+		}
+		return Ok(())
+	}
+	issuer_seal.seal(&issuer)?;
+	info!("Sealed OIDC issuer {}", issuer);
+	Ok(())
+}
+
 /// Initialize the TOP pool author component.
 pub fn create_top_pool_author(
 	rpc_responder: Arc<EnclaveRpcResponder>,
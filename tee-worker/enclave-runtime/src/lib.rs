@@ -441,6 +441,23 @@ pub unsafe extern "C" fn migrate_shard(new_shard: *const u8, shard_size: u32) ->
 	sgx_status_t::SGX_SUCCESS
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn init_oidc_issuer(issuer: *const u8, issuer_size: u32) -> sgx_status_t {
+	let issuer = match String::decode(&mut slice::from_raw_parts(issuer, issuer_size as usize))
+		.map_err(Error::Codec)
+	{
+		Ok(issuer) => issuer,
+		Err(e) => return e.into(),
+	};
+
+	if let Err(e) = initialization::init_oidc_issuer(issuer) {
+		error!("Failed to initialize the OIDC issuer: {:?}", e);
+		return sgx_status_t::SGX_ERROR_UNEXPECTED
+	}
+
+	sgx_status_t::SGX_SUCCESS
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn sync_parentchain(
 	blocks_to_sync: *const u8,
@@ -0,0 +1,212 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::utils::get_validator_accessor_from_integritee_solo_or_parachain;
+use codec::{Decode, Encode};
+use ita_sgx_runtime::{
+	pallet_imt::{sort_id_graph, IdentityContext},
+	Runtime,
+};
+use itc_parentchain::light_client::{concurrent_access::ValidatorAccess, LightClientState};
+use itp_ocall_api::EnclaveOnChainOCallApi;
+use itp_rpc::RpcReturnValue;
+use itp_sgx_crypto::key_repository::AccessKey;
+use itp_sgx_externalities::SgxExternalitiesTrait;
+use itp_sgx_io::{seal, unseal, SealedIO};
+use itp_stf_state_handler::handle_state::HandleState;
+use itp_storage::{storage_map_key, StorageHasher};
+use itp_time_utils::now_as_secs;
+use itp_types::{
+	parentchain::ParentchainId, AccountId, DirectRequestStatus, ShardIdentifier, H256,
+};
+use itp_utils::ToHexPrefixed;
+use jsonrpc_core::{serde_json::json, IoHandler, Params};
+use lc_oidc::{
+	handle_request, Error as OIDCError, HttpRequest, IdentityManagementStorage,
+	IdentityManagementStorageKeys, OIDCClient, OIDCContext, Result as OIDCResult,
+};
+use litentry_primitives::Identity;
+use log::debug;
+use sp_core::{blake2_256, ed25519};
+use std::{
+	format,
+	io::{Error as IOError, ErrorKind},
+	path::PathBuf,
+	sgxfs::SgxFile,
+	string::String,
+	sync::Arc,
+	vec::Vec,
+};
+
+/// The issuer URL of the OIDC provider, it's sealed once by `init_oidc_issuer` and isn't taken
+/// from the proxied requests
+#[derive(Clone, Debug)]
+pub struct OIDCIssuerSeal {
+	path: PathBuf,
+}
+
+impl OIDCIssuerSeal {
+	pub fn new(path: PathBuf) -> Self {
+		Self { path }
+	}
+
+	pub fn exists(&self) -> bool {
+		SgxFile::open(&self.path).is_ok()
+	}
+}
+
+impl SealedIO for OIDCIssuerSeal {
+	type Error = IOError;
+	type Unsealed = String;
+
+	fn unseal(&self) -> Result<Self::Unsealed, Self::Error> {
+		String::from_utf8(unseal(&self.path)?)
+			.map_err(|e| IOError::new(ErrorKind::InvalidData, format!("{:?}", e)))
+	}
+
+	fn seal(&self, issuer: &Self::Unsealed) -> Result<(), Self::Error> {
+		seal(issuer.as_bytes(), &self.path)
+	}
+}
+
+/// The OIDC context of the enclave: the OIDC clients are read from the parachain storage with
+/// proof, the IDGraphs are read from the sidechain state
+pub struct EnclaveOIDCContext<OcallApi, State, SigningKeyRepository> {
+	ocall_api: Arc<OcallApi>,
+	state_handler: Arc<State>,
+	signing_key_repository: Arc<SigningKeyRepository>,
+	issuer_seal: Arc<OIDCIssuerSeal>,
+}
+
+impl<OcallApi, State, SigningKeyRepository> OIDCContext
+	for EnclaveOIDCContext<OcallApi, State, SigningKeyRepository>
+where
+	OcallApi: EnclaveOnChainOCallApi,
+	State: HandleState,
+	State::StateT: SgxExternalitiesTrait,
+	SigningKeyRepository: AccessKey<KeyType = ed25519::Pair>,
+{
+	fn oidc_client(&self, client_id: &AccountId) -> OIDCResult<Option<OIDCClient>> {
+		let validator_access = get_validator_accessor_from_integritee_solo_or_parachain()
+			.map_err(|e| OIDCError::Other(format!("{:?}", e)))?;
+		let header = validator_access
+			.execute_on_validator(|v| v.latest_finalized_header())
+			.map_err(|e| OIDCError::Other(format!("{:?}", e)))?;
+		let client = self
+			.ocall_api
+			.get_storage_verified(
+				IdentityManagementStorage::oidc_client(client_id),
+				&header,
+				&ParentchainId::Litentry,
+			)
+			.map_err(|e| OIDCError::Other(format!("{:?}", e)))?
+			.into_tuple()
+			.1;
+		Ok(client)
+	}
+
+	fn id_graph(
+		&self,
+		shard: &ShardIdentifier,
+		who: &Identity,
+	) -> OIDCResult<Option<(Vec<Identity>, H256)>> {
+		let mut id_graph = self
+			.state_handler
+			.execute_on_current(shard, |state, _| {
+				let storage_key = storage_map_key(
+					"IdentityManagement",
+					"IDGraphs",
+					who,
+					&StorageHasher::Blake2_128Concat,
+				);
+				state
+					.iter_prefix::<Identity, IdentityContext<Runtime>>(&storage_key)
+					.unwrap_or_default()
+			})
+			.map_err(|e| OIDCError::Other(format!("{:?}", e)))?;
+		if id_graph.is_empty() {
+			return Ok(None)
+		}
+
+		// same as `IdentityManagement::id_graph_hash`
+		sort_id_graph::<Runtime>(&mut id_graph);
+		let id_graph_hash = H256::from(blake2_256(&id_graph.encode()));
+		let identities = id_graph
+			.into_iter()
+			.filter(|(_, context)| context.is_active())
+			.map(|(identity, _)| identity)
+			.collect();
+		Ok(Some((identities, id_graph_hash)))
+	}
+
+	fn signer(&self) -> OIDCResult<ed25519::Pair> {
+		self.signing_key_repository
+			.retrieve_key()
+			.map_err(|e| OIDCError::Other(format!("{:?}", e)))
+	}
+
+	fn issuer(&self) -> OIDCResult<String> {
+		self.issuer_seal
+			.unseal()
+			.map_err(|e| OIDCError::Other(format!("OIDC issuer is not sealed: {:?}", e)))
+	}
+}
+
+fn compute_hex_encoded_return_error(error_msg: &str) -> String {
+	RpcReturnValue::from_error_message(error_msg).to_hex()
+}
+
+fn compute_hex_encoded_return_value(value: Vec<u8>) -> String {
+	RpcReturnValue::new(value, false, DirectRequestStatus::Ok).to_hex()
+}
+
+fn decode_single_param<T: Decode>(params: Params) -> Result<T, String> {
+	let hex_encoded_params = params.parse::<Vec<String>>().map_err(|e| format!("{:?}", e))?;
+	let param = hex_encoded_params.get(0).ok_or("Could not get first param")?;
+	let encoded = litentry_hex_utils::decode_hex(param).map_err(|e| format!("{:?}", e))?;
+	T::decode(&mut encoded.as_slice()).map_err(|e| format!("{:?}", e))
+}
+
+pub fn add_oidc_api<OcallApi, State, SigningKeyRepository>(
+	io_handler: &mut IoHandler,
+	ocall_api: Arc<OcallApi>,
+	state_handler: Arc<State>,
+	signing_key_repository: Arc<SigningKeyRepository>,
+	issuer_seal: Arc<OIDCIssuerSeal>,
+) where
+	OcallApi: EnclaveOnChainOCallApi + Send + Sync + 'static,
+	State: HandleState + Send + Sync + 'static,
+	State::StateT: SgxExternalitiesTrait,
+	SigningKeyRepository: AccessKey<KeyType = ed25519::Pair> + Send + Sync + 'static,
+{
+	let context =
+		EnclaveOIDCContext { ocall_api, state_handler, signing_key_repository, issuer_seal };
+
+	// the HTTP endpoints of the OIDC provider, see `lc_oidc::http`
+	io_handler.add_sync_method("oidc_request", move |params: Params| {
+		debug!("worker_api_direct rpc was called: oidc_request");
+		let json_value = match decode_single_param::<HttpRequest>(params) {
+			Ok(request) => {
+				let response = handle_request(&context, request, now_as_secs());
+				compute_hex_encoded_return_value(response.encode())
+			},
+			Err(e) => compute_hex_encoded_return_error(
+				format!("Could not decode OIDC request: {}", e).as_str(),
+			),
+		};
+		Ok(json!(json_value))
+	});
+}
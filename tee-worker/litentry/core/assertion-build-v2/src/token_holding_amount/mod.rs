@@ -102,6 +102,7 @@ mod tests {
 			sidechain_runtime_version: 0u32,
 			maybe_key: None,
 			should_create_id_graph: false,
+			web2_verified_at: None,
 			req_ext_hash: Default::default(),
 		}
 	}
@@ -128,6 +128,7 @@ pub mod assertion_test {
 			maybe_key: None,
 			req_ext_hash: Default::default(),
 			should_create_id_graph: Default::default(),
+			web2_verified_at: None,
 		};
 
 		let repository = InMemorySmartContractRepo::new();
@@ -171,6 +172,7 @@ pub mod assertion_test {
 			maybe_key: None,
 			req_ext_hash: Default::default(),
 			should_create_id_graph: Default::default(),
+			web2_verified_at: None,
 		};
 
 		let repository = InMemorySmartContractRepo::new();
@@ -212,6 +214,7 @@ pub mod assertion_test {
 			maybe_key: None,
 			req_ext_hash: Default::default(),
 			should_create_id_graph: Default::default(),
+			web2_verified_at: None,
 		};
 
 		let repository = InMemorySmartContractRepo::new();
@@ -250,6 +253,7 @@ pub mod assertion_test {
 			maybe_key: None,
 			req_ext_hash: Default::default(),
 			should_create_id_graph: Default::default(),
+			web2_verified_at: None,
 		};
 
 		let repository = InMemorySmartContractRepo::new();
@@ -301,6 +305,7 @@ pub mod assertion_test {
 			maybe_key: None,
 			req_ext_hash: Default::default(),
 			should_create_id_graph: Default::default(),
+			web2_verified_at: None,
 		};
 
 		let repository = InMemorySmartContractRepo::new();
@@ -226,6 +226,10 @@ pub struct Credential {
 	/// The parachain and sidechain block number on which the state is read and calculated
 	pub parachain_block_number: ParentchainBlockNumber,
 	pub sidechain_block_number: SidechainBlockNumber,
+	/// The oldest verification time (unix ms) of the web2 identities the credential is built from,
+	/// it's absent if no web2 identity is involved or the verification time is unknown
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub web2_verified_at: Option<u64>,
 	/// Digital proof with the signature of Issuer
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub proof: Option<Proof>,
@@ -213,6 +213,10 @@ pub struct DataProviderConfig {
 	pub blockchain_info_api_retry_delay: u64,
 	pub blockchain_info_api_retry_times: u16,
 	pub blockchain_info_api_url: String,
+	// interval in seconds of the background web2 identity re-verification job, 0 means disabled
+	pub identity_reverification_interval: u64,
+	// max age in seconds of a web2 identity verification before it's considered expired
+	pub identity_reverification_max_age: u64,
 }
 
 impl DataProviderConfig {
@@ -268,6 +272,8 @@ impl DataProviderConfig {
 			blockchain_info_api_retry_delay: 5000,
 			blockchain_info_api_retry_times: 2,
 			blockchain_info_api_url: "https://blockchain.info/".to_string(),
+			identity_reverification_interval: 0,
+			identity_reverification_max_age: 2_592_000,
 		};
 
 		// we allow to override following config properties for non prod dev
@@ -413,6 +419,13 @@ impl DataProviderConfig {
 		if let Ok(v) = env::var("MAGIC_CRAFT_API_KEY") {
 			config.set_magic_craft_api_key(v);
 		}
+		// set the identity re-verification schedule from env variables
+		if let Ok(v) = env::var("IDENTITY_REVERIFICATION_INTERVAL") {
+			config.set_identity_reverification_interval(v.parse::<u64>().unwrap());
+		}
+		if let Ok(v) = env::var("IDENTITY_REVERIFICATION_MAX_AGE") {
+			config.set_identity_reverification_max_age(v.parse::<u64>().unwrap());
+		}
 		Ok(config)
 	}
 	pub fn set_twitter_official_url(&mut self, v: String) -> Result<(), Error> {
@@ -631,6 +644,14 @@ impl DataProviderConfig {
 		self.blockchain_info_api_url = v;
 		Ok(())
 	}
+	pub fn set_identity_reverification_interval(&mut self, v: u64) {
+		debug!("set_identity_reverification_interval: {:?}", v);
+		self.identity_reverification_interval = v;
+	}
+	pub fn set_identity_reverification_max_age(&mut self, v: u64) {
+		debug!("set_identity_reverification_max_age: {:?}", v);
+		self.identity_reverification_max_age = v;
+	}
 }
 
 fn check_url(v: &String) -> Result<(), Error> {
@@ -0,0 +1,144 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+use crate::{Error, Result};
+use litentry_primitives::Identity;
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+use std::{format, string::String, vec::Vec};
+
+/// A claim derived from a VC that was issued by this enclave
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VCClaim {
+	pub id: String,
+	pub description: String,
+	#[serde(rename = "type")]
+	pub types: String,
+	pub values: Vec<bool>,
+}
+
+/// The scope that every OpenID Connect authorization request must contain, it grants `sub` only
+pub const OPENID_SCOPE: &str = "openid";
+/// The scope that grants the `identities` and `id_graph_hash` claims
+pub const IDENTITIES_SCOPE: &str = "identities";
+/// The scope that grants the `vcs` claim
+pub const VC_SCOPE: &str = "vc";
+
+/// The claims granted by each supported scope, a client can only register these scopes
+pub const SCOPE_CLAIMS: &[(&str, &[&str])] = &[
+	(OPENID_SCOPE, &["sub"]),
+	(IDENTITIES_SCOPE, &["identities", "id_graph_hash"]),
+	(VC_SCOPE, &["vcs"]),
+];
+
+pub fn is_supported_scope(scope: &str) -> bool {
+	SCOPE_CLAIMS.iter().any(|(supported, _)| *supported == scope)
+}
+
+fn grants_claim(scopes: &[&str], claim: &str) -> bool {
+	SCOPE_CLAIMS
+		.iter()
+		.any(|(scope, claims)| scopes.contains(scope) && claims.contains(&claim))
+}
+
+/// The claims about the user, they are returned by the userinfo endpoint as they are
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UserClaims {
+	/// DID of the prime identity
+	pub sub: String,
+	/// DIDs of the active identities in the IDGraph, including the prime identity
+	#[serde(skip_serializing_if = "Vec::is_empty", default)]
+	pub identities: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	pub id_graph_hash: Option<String>,
+	#[serde(skip_serializing_if = "Vec::is_empty", default)]
+	pub vcs: Vec<VCClaim>,
+}
+
+impl UserClaims {
+	/// The claims granted by `scopes`, the others are left out
+	pub fn new(
+		who: &Identity,
+		scopes: &[&str],
+		identities: &[Identity],
+		id_graph_hash: H256,
+		vcs: Vec<VCClaim>,
+	) -> Result<Self> {
+		let identities = if grants_claim(scopes, "identities") {
+			identities.iter().map(to_did).collect::<Result<Vec<_>>>()?
+		} else {
+			Vec::new()
+		};
+		Ok(Self {
+			sub: to_did(who)?,
+			identities,
+			id_graph_hash: grants_claim(scopes, "id_graph_hash")
+				.then(|| format!("{:?}", id_graph_hash)),
+			vcs: if grants_claim(scopes, "vcs") { vcs } else { Vec::new() },
+		})
+	}
+}
+
+/// The claims of the ID token, see
+/// https://openid.net/specs/openid-connect-core-1_0.html#IDToken
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IdTokenClaims {
+	/// the issuer URL, the ID token is signed by the enclave signer
+	pub iss: String,
+	pub sub: String,
+	/// the client id
+	pub aud: String,
+	pub iat: u64,
+	pub exp: u64,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	pub nonce: Option<String>,
+	#[serde(skip_serializing_if = "Vec::is_empty", default)]
+	pub identities: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none", default)]
+	pub id_graph_hash: Option<String>,
+	#[serde(skip_serializing_if = "Vec::is_empty", default)]
+	pub vcs: Vec<VCClaim>,
+}
+
+impl IdTokenClaims {
+	pub fn new(
+		iss: String,
+		aud: String,
+		user: UserClaims,
+		nonce: Option<String>,
+		iat: u64,
+		ttl: u64,
+	) -> Self {
+		Self {
+			iss,
+			sub: user.sub,
+			aud,
+			iat,
+			exp: iat.saturating_add(ttl),
+			nonce,
+			identities: user.identities,
+			id_graph_hash: user.id_graph_hash,
+			vcs: user.vcs,
+		}
+	}
+}
+
+pub(crate) fn to_did(identity: &Identity) -> Result<String> {
+	identity.to_did().map_err(|e| Error::ParseError(format!("{:?}", e)))
+}
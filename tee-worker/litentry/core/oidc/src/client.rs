@@ -0,0 +1,110 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+use codec::{Decode, Encode};
+use itp_storage::{storage_map_key, StorageHasher};
+use itp_types::AccountId;
+use litentry_primitives::ParentchainBalance;
+use std::vec::Vec;
+
+/// The client metadata shown to the user when asking for consent
+#[derive(Encode, Decode, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OIDCClientMetadata {
+	pub name: Vec<u8>,
+	pub logo_uri: Vec<u8>,
+	pub scopes: Vec<Vec<u8>>,
+}
+
+/// The OIDC client as stored in the parachain `OIDCClients` storage, the bounded vectors
+/// have the same encoding as `Vec`
+#[derive(Encode, Decode, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OIDCClient {
+	pub redirect_uris: Vec<Vec<u8>>,
+	pub metadata: OIDCClientMetadata,
+	pub deposit: ParentchainBalance,
+}
+
+impl OIDCClient {
+	// the redirect URI must match one of the registered ones exactly, see
+	// https://openid.net/specs/openid-connect-core-1_0.html#AuthRequest
+	pub fn allows_redirect_uri(&self, redirect_uri: &str) -> bool {
+		self.redirect_uris.iter().any(|uri| uri.as_slice() == redirect_uri.as_bytes())
+	}
+
+	// `openid` is allowed for every client, the other scopes must be supported and registered
+	pub fn allows_scope(&self, scope: &str) -> bool {
+		scope == crate::claims::OPENID_SCOPE
+			|| (crate::claims::is_supported_scope(scope)
+				&& self.metadata.scopes.iter().any(|s| s.as_slice() == scope.as_bytes()))
+	}
+}
+
+pub struct IdentityManagementStorage;
+
+pub trait StoragePrefix {
+	fn prefix() -> &'static str;
+}
+
+impl StoragePrefix for IdentityManagementStorage {
+	fn prefix() -> &'static str {
+		"IdentityManagement"
+	}
+}
+
+pub trait IdentityManagementStorageKeys {
+	fn oidc_client(client_id: &AccountId) -> Vec<u8>;
+}
+
+impl<S: StoragePrefix> IdentityManagementStorageKeys for S {
+	fn oidc_client(client_id: &AccountId) -> Vec<u8> {
+		storage_map_key(Self::prefix(), "OIDCClients", client_id, &StorageHasher::Blake2_128Concat)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn allows_redirect_uri_works() {
+		let client = OIDCClient {
+			redirect_uris: vec![
+				b"https://dapp.example/callback".to_vec(),
+				b"https://dapp.example/other".to_vec(),
+			],
+			..Default::default()
+		};
+		assert!(client.allows_redirect_uri("https://dapp.example/callback"));
+		assert!(client.allows_redirect_uri("https://dapp.example/other"));
+		assert!(!client.allows_redirect_uri("https://dapp.example/callback/"));
+		assert!(!client.allows_redirect_uri("https://evil.example/callback"));
+	}
+
+	#[test]
+	fn allows_scope_works() {
+		let client = OIDCClient {
+			metadata: OIDCClientMetadata { scopes: vec![b"vc".to_vec()], ..Default::default() },
+			..Default::default()
+		};
+		assert!(client.allows_scope("openid"));
+		assert!(client.allows_scope("vc"));
+		assert!(!client.allows_scope("email"));
+		assert!(!client.allows_scope("v"));
+	}
+}
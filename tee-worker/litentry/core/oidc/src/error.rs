@@ -0,0 +1,61 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+use crate::sgx_reexport_prelude::*;
+
+use std::string::String;
+use thiserror::Error;
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Error {
+	#[error("OIDC client is not registered")]
+	UnknownClient,
+	#[error("Redirect URI is not registered for the client")]
+	InvalidRedirectUri,
+	#[error("Invalid request: {0}")]
+	InvalidRequest(String),
+	#[error("Unsupported response type")]
+	UnsupportedResponseType,
+	#[error("Unsupported grant type")]
+	UnsupportedGrantType,
+	#[error("Invalid scope")]
+	InvalidScope,
+	#[error("Client authentication failed: {0}")]
+	InvalidClient(String),
+	#[error("Invalid signature")]
+	InvalidSignature,
+	#[error("Request expired")]
+	RequestExpired,
+	#[error("IDGraph is empty")]
+	EmptyIDGraph,
+	#[error("Invalid VC: {0}")]
+	InvalidVC(String),
+	#[error("Invalid authorization code")]
+	InvalidAuthorizationCode,
+	#[error("Invalid PKCE code verifier")]
+	InvalidCodeVerifier,
+	#[error("Invalid access token")]
+	InvalidAccessToken,
+	#[error("Parse error: {0}")]
+	ParseError(String),
+	#[error("Lock poisoning")]
+	LockPoisoning,
+	#[error("Other error: {0}")]
+	Other(String),
+}
@@ -0,0 +1,626 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! The HTTP endpoints of the OIDC provider
+//!
+//! The enclave doesn't serve HTTP itself, the untrusted worker proxies the requests to the
+//! `oidc_request` RPC method. The responses follow the OAuth 2.0 and OpenID Connect specs, so
+//! that the clients can use any standard OIDC library.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+use crate::sgx_reexport_prelude::*;
+
+use crate::{
+	jwt::{
+		decode_jwt_claims_unverified, ClientAssertionClaims, CLIENT_ASSERTION_TYPE, JWT_ALGORITHM,
+	},
+	pkce::CODE_CHALLENGE_METHOD,
+	provider::{
+		authorize, exchange_code, jwks, parse_client_id, userinfo, AuthorizationRequest,
+		OIDCContext, TokenRequest,
+	},
+	Error, Result, SCOPE_CLAIMS,
+};
+use codec::{Decode, Encode};
+use itp_types::{AccountId, ShardIdentifier};
+use litentry_primitives::{Identity, LitentryMultiSignature};
+use serde::{Deserialize, Serialize};
+use std::{
+	format,
+	string::{String, ToString},
+	vec,
+	vec::Vec,
+};
+
+pub const AUTHORIZATION_PATH: &str = "/authorize";
+pub const TOKEN_PATH: &str = "/token";
+pub const USERINFO_PATH: &str = "/userinfo";
+pub const JWKS_PATH: &str = "/jwks";
+pub const DISCOVERY_PATH: &str = "/.well-known/openid-configuration";
+
+/// The HTTP request as forwarded by the proxy
+#[derive(Encode, Decode, Clone, Debug, Default, PartialEq, Eq)]
+pub struct HttpRequest {
+	pub method: String,
+	pub path: String,
+	/// the query string without the leading `?`
+	pub query: String,
+	/// the value of the `Authorization` header
+	pub authorization: Option<String>,
+	/// the `application/x-www-form-urlencoded` body
+	pub body: Vec<u8>,
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct HttpResponse {
+	pub status: u16,
+	pub headers: Vec<(String, String)>,
+	pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+	fn json<T: Serialize>(status: u16, value: &T) -> Self {
+		match serde_json::to_vec(value) {
+			Ok(body) => Self {
+				status,
+				headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+				body,
+			},
+			Err(_) => Self { status: 500, headers: Vec::new(), body: Vec::new() },
+		}
+	}
+
+	// the responses that contain tokens or claims must not be cached
+	fn no_store(mut self) -> Self {
+		self.headers.push(("Cache-Control".to_string(), "no-store".to_string()));
+		self
+	}
+
+	fn redirect(location: String) -> Self {
+		Self { status: 302, headers: vec![("Location".to_string(), location)], body: Vec::new() }
+	}
+
+	/// See https://www.rfc-editor.org/rfc/rfc6749#section-5.2
+	fn error(error: &Error) -> Self {
+		let code = error_code(error);
+		let status = match code {
+			"server_error" => 500,
+			"invalid_client" | "invalid_token" => 401,
+			_ => 400,
+		};
+		let mut response = Self::json(
+			status,
+			&ErrorResponse { error: code.to_string(), error_description: error.to_string() },
+		)
+		.no_store();
+		if code == "invalid_token" {
+			response.headers.push((
+				"WWW-Authenticate".to_string(),
+				"Bearer error=\"invalid_token\"".to_string(),
+			));
+		}
+		response
+	}
+
+	fn not_found() -> Self {
+		Self { status: 404, headers: Vec::new(), body: Vec::new() }
+	}
+
+	fn method_not_allowed() -> Self {
+		Self { status: 405, headers: Vec::new(), body: Vec::new() }
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ErrorResponse {
+	pub error: String,
+	pub error_description: String,
+}
+
+/// See https://openid.net/specs/openid-connect-discovery-1_0.html#ProviderMetadata
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProviderMetadata {
+	pub issuer: String,
+	pub authorization_endpoint: String,
+	pub token_endpoint: String,
+	pub userinfo_endpoint: String,
+	pub jwks_uri: String,
+	pub scopes_supported: Vec<String>,
+	pub claims_supported: Vec<String>,
+	pub response_types_supported: Vec<String>,
+	pub grant_types_supported: Vec<String>,
+	pub subject_types_supported: Vec<String>,
+	pub id_token_signing_alg_values_supported: Vec<String>,
+	pub token_endpoint_auth_methods_supported: Vec<String>,
+	pub token_endpoint_auth_signing_alg_values_supported: Vec<String>,
+	pub code_challenge_methods_supported: Vec<String>,
+}
+
+impl ProviderMetadata {
+	pub fn new(issuer: &str) -> Self {
+		Self {
+			issuer: issuer.to_string(),
+			authorization_endpoint: endpoint(issuer, AUTHORIZATION_PATH),
+			token_endpoint: endpoint(issuer, TOKEN_PATH),
+			userinfo_endpoint: endpoint(issuer, USERINFO_PATH),
+			jwks_uri: endpoint(issuer, JWKS_PATH),
+			scopes_supported: SCOPE_CLAIMS.iter().map(|(scope, _)| scope.to_string()).collect(),
+			claims_supported: SCOPE_CLAIMS
+				.iter()
+				.flat_map(|(_, claims)| claims.iter())
+				.map(|claim| claim.to_string())
+				.collect(),
+			response_types_supported: vec!["code".to_string()],
+			grant_types_supported: vec!["authorization_code".to_string()],
+			subject_types_supported: vec!["public".to_string()],
+			id_token_signing_alg_values_supported: vec![JWT_ALGORITHM.to_string()],
+			token_endpoint_auth_methods_supported: vec!["private_key_jwt".to_string()],
+			token_endpoint_auth_signing_alg_values_supported: vec![JWT_ALGORITHM.to_string()],
+			code_challenge_methods_supported: vec![CODE_CHALLENGE_METHOD.to_string()],
+		}
+	}
+}
+
+pub fn endpoint(issuer: &str, path: &str) -> String {
+	format!("{}{}", issuer.trim_end_matches('/'), path)
+}
+
+pub fn handle_request<C: OIDCContext>(context: &C, request: HttpRequest, now: u64) -> HttpResponse {
+	match (request.method.as_str(), request.path.as_str()) {
+		("GET", AUTHORIZATION_PATH) =>
+			handle_authorization(context, &Params::parse(request.query.as_bytes()), now),
+		("POST", AUTHORIZATION_PATH) =>
+			handle_authorization(context, &Params::parse(&request.body), now),
+		("POST", TOKEN_PATH) => handle_token(context, &Params::parse(&request.body), now),
+		("GET" | "POST", USERINFO_PATH) => handle_userinfo(request.authorization.as_deref(), now),
+		("GET", JWKS_PATH) => match jwks(context) {
+			Ok(jwks) => HttpResponse::json(200, &jwks),
+			Err(e) => HttpResponse::error(&e),
+		},
+		("GET", DISCOVERY_PATH) => match context.issuer() {
+			Ok(issuer) => HttpResponse::json(200, &ProviderMetadata::new(&issuer)),
+			Err(e) => HttpResponse::error(&e),
+		},
+		(_, AUTHORIZATION_PATH | TOKEN_PATH | USERINFO_PATH | JWKS_PATH | DISCOVERY_PATH) =>
+			HttpResponse::method_not_allowed(),
+		_ => HttpResponse::not_found(),
+	}
+}
+
+// See https://openid.net/specs/openid-connect-core-1_0.html#AuthRequest, besides the standard
+// parameters, the request carries the consent of the user: `shard`, `who` (hex encoded
+// `Identity`), `issued_at`, `vc` (repeated) and `signature` (hex encoded
+// `LitentryMultiSignature`) over `AuthorizationRequest::signature_payload`
+fn handle_authorization<C: OIDCContext>(context: &C, params: &Params, now: u64) -> HttpResponse {
+	// the errors about the client or the redirect URI must not be redirected
+	let client_id = match params.required("client_id").and_then(parse_client_id) {
+		Ok(client_id) => client_id,
+		Err(e) => return HttpResponse::error(&e),
+	};
+	let redirect_uri = match params.required("redirect_uri") {
+		Ok(redirect_uri) => redirect_uri,
+		Err(e) => return HttpResponse::error(&e),
+	};
+	match context.oidc_client(&client_id) {
+		Ok(Some(client)) if client.allows_redirect_uri(redirect_uri) => {},
+		Ok(Some(_)) => return HttpResponse::error(&Error::InvalidRedirectUri),
+		Ok(None) => return HttpResponse::error(&Error::UnknownClient),
+		Err(e) => return HttpResponse::error(&e),
+	}
+
+	let mut response_params = match parse_authorization_request(params, client_id, redirect_uri)
+		.and_then(|request| authorize(context, request, now))
+	{
+		Ok(code) => vec![("code", code)],
+		Err(e) => vec![("error", error_code(&e).to_string()), ("error_description", e.to_string())],
+	};
+	if let Some(state) = params.get("state") {
+		response_params.push(("state", state.to_string()));
+	}
+	let query = response_params
+		.iter()
+		.map(|(name, value)| format!("{}={}", name, percent_encode(value)))
+		.collect::<Vec<_>>()
+		.join("&");
+	let separator = if redirect_uri.contains('?') { '&' } else { '?' };
+	HttpResponse::redirect(format!("{}{}{}", redirect_uri, separator, query))
+}
+
+fn parse_authorization_request(
+	params: &Params,
+	client_id: AccountId,
+	redirect_uri: &str,
+) -> Result<AuthorizationRequest> {
+	if params.required("response_type")? != "code" {
+		return Err(Error::UnsupportedResponseType)
+	}
+	if params.required("code_challenge_method")? != CODE_CHALLENGE_METHOD {
+		return Err(Error::InvalidRequest("code_challenge_method must be S256".to_string()))
+	}
+	let shard = decode_hex(params.required("shard")?)
+		.ok()
+		.and_then(|shard| <[u8; 32]>::try_from(shard).ok())
+		.map(ShardIdentifier::from)
+		.ok_or_else(|| Error::InvalidRequest("invalid shard".to_string()))?;
+	Ok(AuthorizationRequest {
+		shard,
+		client_id,
+		redirect_uri: redirect_uri.to_string(),
+		scope: params.required("scope")?.to_string(),
+		nonce: params.get("nonce").map(ToString::to_string),
+		code_challenge: params.required("code_challenge")?.to_string(),
+		issued_at: params
+			.required("issued_at")?
+			.parse()
+			.map_err(|_| Error::InvalidRequest("invalid issued_at".to_string()))?,
+		who: decode_scale::<Identity>(params.required("who")?, "who")?,
+		vcs: params.get_all("vc").map(ToString::to_string).collect(),
+		signature: decode_scale::<LitentryMultiSignature>(
+			params.required("signature")?,
+			"signature",
+		)?,
+	})
+}
+
+// See https://openid.net/specs/openid-connect-core-1_0.html#TokenRequest
+fn handle_token<C: OIDCContext>(context: &C, params: &Params, now: u64) -> HttpResponse {
+	match parse_token_request(params).and_then(|request| exchange_code(context, request, now)) {
+		Ok(response) => HttpResponse::json(200, &response).no_store(),
+		Err(e) => HttpResponse::error(&e),
+	}
+}
+
+fn parse_token_request(params: &Params) -> Result<TokenRequest> {
+	if params.required("grant_type")? != "authorization_code" {
+		return Err(Error::UnsupportedGrantType)
+	}
+	if params.get("client_assertion_type") != Some(CLIENT_ASSERTION_TYPE) {
+		return Err(Error::InvalidClient("private_key_jwt is required".to_string()))
+	}
+	let client_assertion = params.required("client_assertion")?;
+	// `client_id` is optional, the assertion is verified against it in `exchange_code`
+	let client_id = match params.get("client_id") {
+		Some(client_id) => client_id.to_string(),
+		None => decode_jwt_claims_unverified(client_assertion)
+			.ok()
+			.and_then(|claims| serde_json::from_slice::<ClientAssertionClaims>(&claims).ok())
+			.map(|claims| claims.sub)
+			.ok_or_else(|| Error::InvalidClient("invalid client assertion".to_string()))?,
+	};
+	Ok(TokenRequest {
+		client_id: parse_client_id(&client_id)
+			.map_err(|_| Error::InvalidClient("invalid client_id".to_string()))?,
+		code: params.required("code")?.to_string(),
+		redirect_uri: params.required("redirect_uri")?.to_string(),
+		code_verifier: params.required("code_verifier")?.to_string(),
+		client_assertion: client_assertion.to_string(),
+	})
+}
+
+// See https://openid.net/specs/openid-connect-core-1_0.html#UserInfo
+fn handle_userinfo(authorization: Option<&str>, now: u64) -> HttpResponse {
+	let access_token = authorization
+		.and_then(|value| value.split_once(' '))
+		.filter(|(scheme, _)| scheme.eq_ignore_ascii_case("Bearer"))
+		.map(|(_, token)| token.trim());
+	match access_token
+		.ok_or(Error::InvalidAccessToken)
+		.and_then(|token| userinfo(token, now))
+	{
+		Ok(claims) => HttpResponse::json(200, &claims).no_store(),
+		Err(e) => HttpResponse::error(&e),
+	}
+}
+
+fn error_code(error: &Error) -> &'static str {
+	match error {
+		Error::UnknownClient | Error::InvalidClient(_) => "invalid_client",
+		Error::InvalidRedirectUri
+		| Error::InvalidRequest(_)
+		| Error::RequestExpired
+		| Error::ParseError(_) => "invalid_request",
+		Error::InvalidSignature | Error::EmptyIDGraph | Error::InvalidVC(_) => "access_denied",
+		Error::InvalidAuthorizationCode | Error::InvalidCodeVerifier => "invalid_grant",
+		Error::UnsupportedResponseType => "unsupported_response_type",
+		Error::UnsupportedGrantType => "unsupported_grant_type",
+		Error::InvalidScope => "invalid_scope",
+		Error::InvalidAccessToken => "invalid_token",
+		Error::LockPoisoning | Error::Other(_) => "server_error",
+	}
+}
+
+fn decode_hex(value: &str) -> core::result::Result<Vec<u8>, hex::FromHexError> {
+	hex::decode(value.trim_start_matches("0x"))
+}
+
+fn decode_scale<T: Decode>(value: &str, name: &str) -> Result<T> {
+	decode_hex(value)
+		.ok()
+		.and_then(|encoded| T::decode(&mut encoded.as_slice()).ok())
+		.ok_or_else(|| Error::InvalidRequest(format!("invalid {}", name)))
+}
+
+/// `application/x-www-form-urlencoded` parameters
+struct Params(Vec<(String, String)>);
+
+impl Params {
+	fn parse(input: &[u8]) -> Self {
+		Self(
+			input
+				.split(|c| *c == b'&')
+				.filter(|pair| !pair.is_empty())
+				.map(|pair| {
+					let mut parts = pair.splitn(2, |c| *c == b'=');
+					let name = percent_decode(parts.next().unwrap_or_default());
+					let value = percent_decode(parts.next().unwrap_or_default());
+					(name, value)
+				})
+				.collect(),
+		)
+	}
+
+	fn get(&self, name: &str) -> Option<&str> {
+		self.get_all(name).next()
+	}
+
+	fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> {
+		self.0.iter().filter(move |(n, _)| n == name).map(|(_, value)| value.as_str())
+	}
+
+	fn required(&self, name: &str) -> Result<&str> {
+		self.get(name)
+			.filter(|value| !value.is_empty())
+			.ok_or_else(|| Error::InvalidRequest(format!("missing {}", name)))
+	}
+}
+
+fn percent_decode(input: &[u8]) -> String {
+	let hex_value = |c: u8| (c as char).to_digit(16).map(|d| d as u8);
+	let mut decoded = Vec::with_capacity(input.len());
+	let mut i = 0;
+	while i < input.len() {
+		match input[i] {
+			b'+' => decoded.push(b' '),
+			b'%' if i + 2 < input.len() => match (hex_value(input[i + 1]), hex_value(input[i + 2]))
+			{
+				(Some(high), Some(low)) => {
+					decoded.push((high << 4) | low);
+					i += 2;
+				},
+				_ => decoded.push(b'%'),
+			},
+			c => decoded.push(c),
+		}
+		i += 1;
+	}
+	String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn percent_encode(input: &str) -> String {
+	input
+		.bytes()
+		.map(|c| {
+			if c.is_ascii_alphanumeric() || matches!(c, b'-' | b'.' | b'_' | b'~') {
+				(c as char).to_string()
+			} else {
+				format!("%{:02X}", c)
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{mock::*, IdTokenClaims, TokenResponse, UserClaims};
+	use std::collections::BTreeMap;
+
+	fn get(path: &str, query: String) -> HttpRequest {
+		HttpRequest {
+			method: "GET".to_string(),
+			path: path.to_string(),
+			query,
+			..Default::default()
+		}
+	}
+
+	fn post(path: &str, body: String) -> HttpRequest {
+		HttpRequest {
+			method: "POST".to_string(),
+			path: path.to_string(),
+			body: body.into_bytes(),
+			..Default::default()
+		}
+	}
+
+	fn form(params: &[(&str, &str)]) -> String {
+		params
+			.iter()
+			.map(|(name, value)| format!("{}={}", name, percent_encode(value)))
+			.collect::<Vec<_>>()
+			.join("&")
+	}
+
+	fn authorization_query(context: &ContextMock, response_type: &str) -> String {
+		let request = context.authorization_request(REDIRECT_URI);
+		form(&[
+			("response_type", response_type),
+			("client_id", &crate::provider::client_id_to_string(&request.client_id)),
+			("redirect_uri", &request.redirect_uri),
+			("scope", &request.scope),
+			("state", "af0ifjsldkj"),
+			("nonce", "nonce"),
+			("code_challenge", &request.code_challenge),
+			("code_challenge_method", "S256"),
+			("shard", &format!("{:?}", request.shard)),
+			("who", &format!("0x{}", hex::encode(request.who.encode()))),
+			("issued_at", &request.issued_at.to_string()),
+			("signature", &format!("0x{}", hex::encode(request.signature.encode()))),
+		])
+	}
+
+	fn header<'a>(response: &'a HttpResponse, name: &str) -> Option<&'a str> {
+		response
+			.headers
+			.iter()
+			.find(|(n, _)| n == name)
+			.map(|(_, value)| value.as_str())
+	}
+
+	fn redirect_params(response: &HttpResponse) -> BTreeMap<String, String> {
+		assert_eq!(response.status, 302);
+		let location = header(response, "Location").unwrap();
+		let (uri, query) = location.split_once('?').unwrap();
+		assert_eq!(uri, REDIRECT_URI);
+		Params::parse(query.as_bytes()).0.into_iter().collect()
+	}
+
+	#[test]
+	fn authorization_code_flow_works() {
+		let context = ContextMock::default();
+		let response = handle_request(
+			&context,
+			get(AUTHORIZATION_PATH, authorization_query(&context, "code")),
+			NOW,
+		);
+		let params = redirect_params(&response);
+		assert_eq!(params["state"], "af0ifjsldkj");
+		let code = params["code"].clone();
+
+		let client_assertion = context.client_assertion(&code, &endpoint(ISSUER, TOKEN_PATH));
+		let response = handle_request(
+			&context,
+			post(
+				TOKEN_PATH,
+				form(&[
+					("grant_type", "authorization_code"),
+					("code", &code),
+					("redirect_uri", REDIRECT_URI),
+					("code_verifier", CODE_VERIFIER),
+					("client_assertion_type", CLIENT_ASSERTION_TYPE),
+					("client_assertion", &client_assertion),
+				]),
+			),
+			NOW,
+		);
+		assert_eq!(response.status, 200);
+		assert_eq!(header(&response, "Cache-Control"), Some("no-store"));
+		let tokens: TokenResponse = serde_json::from_slice(&response.body).unwrap();
+		let id_token = crate::jwt::verify_jwt(&tokens.id_token, &context.signer.public()).unwrap();
+		let id_token: IdTokenClaims = serde_json::from_slice(&id_token).unwrap();
+		assert_eq!(id_token.iss, ISSUER);
+
+		let mut request = get(USERINFO_PATH, String::new());
+		request.authorization = Some(format!("Bearer {}", tokens.access_token));
+		let response = handle_request(&context, request, NOW);
+		assert_eq!(response.status, 200);
+		let claims: UserClaims = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(claims.sub, id_token.sub);
+	}
+
+	#[test]
+	fn authorization_errors_are_redirected_with_state() {
+		let context = ContextMock::default();
+		let response = handle_request(
+			&context,
+			get(AUTHORIZATION_PATH, authorization_query(&context, "token")),
+			NOW,
+		);
+		let params = redirect_params(&response);
+		assert_eq!(params["error"], "unsupported_response_type");
+		assert_eq!(params["state"], "af0ifjsldkj");
+		assert!(!params.contains_key("code"));
+	}
+
+	#[test]
+	fn authorization_with_unregistered_redirect_uri_is_not_redirected() {
+		let context = ContextMock::default();
+		let query = authorization_query(&context, "code")
+			.replace(&percent_encode(REDIRECT_URI), &percent_encode("https://evil.example"));
+		let response = handle_request(&context, get(AUTHORIZATION_PATH, query), NOW);
+		assert_eq!(response.status, 400);
+		assert_eq!(header(&response, "Location"), None);
+		let error: ErrorResponse = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(error.error, "invalid_request");
+	}
+
+	#[test]
+	fn token_request_without_client_authentication_fails() {
+		let context = ContextMock::default();
+		let response = handle_request(
+			&context,
+			post(
+				TOKEN_PATH,
+				form(&[
+					("grant_type", "authorization_code"),
+					("code", "code"),
+					("redirect_uri", REDIRECT_URI),
+					("code_verifier", CODE_VERIFIER),
+					("client_id", &crate::provider::client_id_to_string(&context.client_id())),
+				]),
+			),
+			NOW,
+		);
+		assert_eq!(response.status, 401);
+		let error: ErrorResponse = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(error.error, "invalid_client");
+	}
+
+	#[test]
+	fn userinfo_with_invalid_token_fails() {
+		let context = ContextMock::default();
+		let mut request = get(USERINFO_PATH, String::new());
+		request.authorization = Some("Bearer invalid".to_string());
+		let response = handle_request(&context, request, NOW);
+		assert_eq!(response.status, 401);
+		assert_eq!(header(&response, "WWW-Authenticate"), Some("Bearer error=\"invalid_token\""));
+	}
+
+	#[test]
+	fn discovery_works() {
+		let context = ContextMock::default();
+		let response = handle_request(&context, get(DISCOVERY_PATH, String::new()), NOW);
+		assert_eq!(response.status, 200);
+		let metadata: ProviderMetadata = serde_json::from_slice(&response.body).unwrap();
+		assert_eq!(metadata.issuer, ISSUER);
+		assert_eq!(metadata.token_endpoint, "https://oidc.example/token");
+		assert_eq!(metadata.code_challenge_methods_supported, vec!["S256".to_string()]);
+		assert_eq!(metadata.scopes_supported, vec!["openid", "identities", "vc"]);
+
+		let response = handle_request(&context, get(JWKS_PATH, String::new()), NOW);
+		assert_eq!(response.status, 200);
+		assert_eq!(handle_request(&context, get("/other", String::new()), NOW).status, 404);
+		assert_eq!(handle_request(&context, get(TOKEN_PATH, String::new()), NOW).status, 405);
+	}
+
+	#[test]
+	fn params_parse_works() {
+		let params = Params::parse(b"a=1+2&b=%2Fx%3F&vc=1&vc=2&c&d=%zz");
+		assert_eq!(params.get("a"), Some("1 2"));
+		assert_eq!(params.get("b"), Some("/x?"));
+		assert_eq!(params.get_all("vc").collect::<Vec<_>>(), vec!["1", "2"]);
+		assert_eq!(params.get("c"), Some(""));
+		assert!(params.required("c").is_err());
+		assert_eq!(params.get("d"), Some("%zz"));
+		assert_eq!(
+			percent_decode(percent_encode("https://a.b/c?d=e f").as_bytes()),
+			"https://a.b/c?d=e f"
+		);
+	}
+}
@@ -0,0 +1,256 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+use crate::sgx_reexport_prelude::*;
+
+use crate::{Error, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+use sp_core::{ed25519, Pair};
+use std::{
+	format,
+	string::{String, ToString},
+	vec,
+	vec::Vec,
+};
+
+/// JWS algorithm of Ed25519 signatures, see https://www.rfc-editor.org/rfc/rfc8037
+pub const JWT_ALGORITHM: &str = "EdDSA";
+
+#[derive(Serialize, Deserialize)]
+struct JwtHeader {
+	alg: String,
+	typ: String,
+	kid: String,
+}
+
+/// The `client_assertion_type` of the `private_key_jwt` client authentication, see
+/// https://openid.net/specs/openid-connect-core-1_0.html#ClientAuthentication
+pub const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Audience {
+	One(String),
+	Many(Vec<String>),
+}
+
+impl Audience {
+	pub fn contains(&self, audience: &str) -> bool {
+		match self {
+			Audience::One(aud) => aud == audience,
+			Audience::Many(auds) => auds.iter().any(|aud| aud == audience),
+		}
+	}
+}
+
+/// The claims of the JWT that the client signs with the key of its account to authenticate
+/// to the token endpoint
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClientAssertionClaims {
+	/// the client id, same as `sub`
+	pub iss: String,
+	pub sub: String,
+	/// the issuer or the token endpoint URL
+	pub aud: Audience,
+	pub exp: u64,
+	pub jti: String,
+}
+
+/// A public key of the enclave in the JSON Web Key format
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Jwk {
+	pub kty: String,
+	pub crv: String,
+	#[serde(rename = "use")]
+	pub key_use: String,
+	pub alg: String,
+	pub kid: String,
+	pub x: String,
+}
+
+impl Jwk {
+	pub fn ed25519(public: &ed25519::Public) -> Self {
+		Self {
+			kty: "OKP".to_string(),
+			crv: "Ed25519".to_string(),
+			key_use: "sig".to_string(),
+			alg: JWT_ALGORITHM.to_string(),
+			kid: key_id(public),
+			x: URL_SAFE_NO_PAD.encode(public.0),
+		}
+	}
+}
+
+/// The JSON Web Key Set that is served by the JWKS endpoint
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Jwks {
+	pub keys: Vec<Jwk>,
+}
+
+impl Jwks {
+	pub fn new(public: &ed25519::Public) -> Self {
+		Self { keys: vec![Jwk::ed25519(public)] }
+	}
+}
+
+// the key id is the hex-encoded public key, which is also the enclave account registered
+// in teebag, so that relying parties can check the key against the on-chain attestation
+pub fn key_id(public: &ed25519::Public) -> String {
+	format!("0x{}", hex::encode(public.0))
+}
+
+pub fn sign_jwt<C: Serialize>(signer: &ed25519::Pair, claims: &C) -> Result<String> {
+	let header = JwtHeader {
+		alg: JWT_ALGORITHM.to_string(),
+		typ: "JWT".to_string(),
+		kid: key_id(&signer.public()),
+	};
+	let header = serde_json::to_vec(&header).map_err(|e| Error::ParseError(e.to_string()))?;
+	let claims = serde_json::to_vec(claims).map_err(|e| Error::ParseError(e.to_string()))?;
+	let signing_input =
+		format!("{}.{}", URL_SAFE_NO_PAD.encode(header), URL_SAFE_NO_PAD.encode(claims));
+	let signature = signer.sign(signing_input.as_bytes());
+	Ok(format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(signature.0)))
+}
+
+/// Verify the JWT signature and return the decoded claims
+pub fn verify_jwt(token: &str, public: &ed25519::Public) -> Result<Vec<u8>> {
+	let (header, claims, signature) = split_jwt(token)?;
+	#[derive(Deserialize)]
+	struct Header {
+		alg: String,
+	}
+	let decoded_header: Header = URL_SAFE_NO_PAD
+		.decode(header)
+		.ok()
+		.and_then(|header| serde_json::from_slice(&header).ok())
+		.ok_or_else(|| Error::ParseError("malformed JWT header".to_string()))?;
+	if decoded_header.alg != JWT_ALGORITHM {
+		return Err(Error::InvalidSignature)
+	}
+	let signature = URL_SAFE_NO_PAD
+		.decode(signature)
+		.ok()
+		.and_then(|s| ed25519::Signature::from_slice(&s))
+		.ok_or(Error::InvalidSignature)?;
+	let signing_input = format!("{}.{}", header, claims);
+	if !ed25519::Pair::verify(&signature, signing_input.as_bytes(), public) {
+		return Err(Error::InvalidSignature)
+	}
+	URL_SAFE_NO_PAD.decode(claims).map_err(|e| Error::ParseError(e.to_string()))
+}
+
+/// Decode the claims without verifying the signature, they must not be trusted
+pub fn decode_jwt_claims_unverified(token: &str) -> Result<Vec<u8>> {
+	let (_, claims, _) = split_jwt(token)?;
+	URL_SAFE_NO_PAD.decode(claims).map_err(|e| Error::ParseError(e.to_string()))
+}
+
+fn split_jwt(token: &str) -> Result<(&str, &str, &str)> {
+	let mut parts = token.split('.');
+	match (parts.next(), parts.next(), parts.next(), parts.next()) {
+		(Some(h), Some(c), Some(s), None) => Ok((h, c, s)),
+		_ => Err(Error::ParseError("malformed JWT".to_string())),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::IdTokenClaims;
+
+	fn claims() -> IdTokenClaims {
+		IdTokenClaims {
+			iss: "did:litentry:substrate:0x01".to_string(),
+			sub: "did:litentry:substrate:0x02".to_string(),
+			aud: "client".to_string(),
+			iat: 1,
+			exp: 2,
+			nonce: Some("nonce".to_string()),
+			identities: vec!["did:litentry:substrate:0x02".to_string()],
+			id_graph_hash: Some("0x03".to_string()),
+			vcs: vec![],
+		}
+	}
+
+	#[test]
+	fn sign_and_verify_jwt_works() {
+		let signer = ed25519::Pair::from_seed(&[1u8; 32]);
+		let token = sign_jwt(&signer, &claims()).unwrap();
+
+		let decoded = verify_jwt(&token, &signer.public()).unwrap();
+		let decoded: IdTokenClaims = serde_json::from_slice(&decoded).unwrap();
+		assert_eq!(decoded, claims());
+
+		let header = URL_SAFE_NO_PAD.decode(token.split('.').next().unwrap()).unwrap();
+		let header: JwtHeader = serde_json::from_slice(&header).unwrap();
+		assert_eq!(header.alg, "EdDSA");
+		assert_eq!(header.kid, key_id(&signer.public()));
+	}
+
+	#[test]
+	fn verify_jwt_fails_with_other_key_or_tampered_claims() {
+		let signer = ed25519::Pair::from_seed(&[1u8; 32]);
+		let other = ed25519::Pair::from_seed(&[2u8; 32]);
+		let token = sign_jwt(&signer, &claims()).unwrap();
+		assert_eq!(verify_jwt(&token, &other.public()), Err(Error::InvalidSignature));
+
+		let mut tampered = claims();
+		tampered.sub = "did:litentry:substrate:0x04".to_string();
+		let tampered = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&tampered).unwrap());
+		let parts: Vec<&str> = token.split('.').collect();
+		let token = format!("{}.{}.{}", parts[0], tampered, parts[2]);
+		assert_eq!(verify_jwt(&token, &signer.public()), Err(Error::InvalidSignature));
+	}
+
+	#[test]
+	fn verify_jwt_fails_with_other_algorithm() {
+		let signer = ed25519::Pair::from_seed(&[1u8; 32]);
+		let token = sign_jwt(&signer, &claims()).unwrap();
+		let parts: Vec<&str> = token.split('.').collect();
+		let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"none","typ":"JWT"}"#);
+		let token = format!("{}.{}.{}", header, parts[1], parts[2]);
+		assert_eq!(verify_jwt(&token, &signer.public()), Err(Error::InvalidSignature));
+	}
+
+	#[test]
+	fn audience_contains_works() {
+		let one: Audience = serde_json::from_str("\"https://oidc.example\"").unwrap();
+		assert!(one.contains("https://oidc.example"));
+		let many: Audience =
+			serde_json::from_str("[\"https://oidc.example\",\"https://oidc.example/token\"]")
+				.unwrap();
+		assert!(many.contains("https://oidc.example/token"));
+		assert!(!many.contains("https://evil.example"));
+	}
+
+	#[test]
+	fn jwks_works() {
+		let signer = ed25519::Pair::from_seed(&[1u8; 32]);
+		let jwks = Jwks::new(&signer.public());
+		assert_eq!(jwks.keys.len(), 1);
+		assert_eq!(jwks.keys[0].kty, "OKP");
+		assert_eq!(jwks.keys[0].crv, "Ed25519");
+		assert_eq!(URL_SAFE_NO_PAD.decode(&jwks.keys[0].x).unwrap(), signer.public().0.to_vec());
+		let json = serde_json::to_string(&jwks).unwrap();
+		assert!(json.contains("\"use\":\"sig\""));
+	}
+}
@@ -0,0 +1,76 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! An OpenID Connect provider for the OIDC clients registered in the parachain
+//! `pallet-identity-management`.
+//!
+//! The enclave acts as the identity provider: the user proves the ownership of the prime
+//! identity by signing the authorization request, the claims are derived from the user's
+//! IDGraph and the VCs that were issued by this enclave, and the ID tokens are signed by the
+//! enclave signer, whose public key is attested on-chain.
+//!
+//! The endpoints in `http` implement the authorization code flow with PKCE and the
+//! `private_key_jwt` client authentication, the untrusted worker proxies them over HTTP.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+// re-export module to properly feature gate sgx and regular std environment
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+pub mod sgx_reexport_prelude {
+	pub use serde_json_sgx as serde_json;
+	pub use thiserror_sgx as thiserror;
+}
+
+#[cfg(all(feature = "std", feature = "sgx"))]
+compile_error!("feature \"std\" and feature \"sgx\" cannot be enabled at the same time");
+
+pub mod claims;
+pub mod client;
+pub mod error;
+pub mod http;
+pub mod jwt;
+#[cfg(test)]
+mod mock;
+pub mod pkce;
+pub mod provider;
+pub mod store;
+pub mod vc;
+
+pub use claims::{
+	IdTokenClaims, UserClaims, VCClaim, IDENTITIES_SCOPE, OPENID_SCOPE, SCOPE_CLAIMS, VC_SCOPE,
+};
+pub use client::{
+	IdentityManagementStorage, IdentityManagementStorageKeys, OIDCClient, OIDCClientMetadata,
+};
+pub use error::{Error, Result};
+pub use http::{handle_request, HttpRequest, HttpResponse, ProviderMetadata};
+pub use jwt::{Jwk, Jwks};
+pub use provider::{
+	authorize, exchange_code, jwks, userinfo, AuthorizationRequest, OIDCContext, TokenRequest,
+	TokenResponse,
+};
+
+/// How long a signed authorization request is accepted, in seconds
+pub const AUTHORIZATION_REQUEST_TTL: u64 = 5 * 60;
+/// How long an authorization code can be exchanged for tokens, in seconds
+pub const AUTHORIZATION_CODE_TTL: u64 = 5 * 60;
+/// How long an access token and an ID token are valid, in seconds
+pub const TOKEN_TTL: u64 = 60 * 60;
+/// The maximum lifetime of a client assertion, in seconds
+pub const CLIENT_ASSERTION_TTL: u64 = 5 * 60;
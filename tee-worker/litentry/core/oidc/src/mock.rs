@@ -0,0 +1,135 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	http::{endpoint, TOKEN_PATH},
+	jwt::{sign_jwt, Audience, ClientAssertionClaims},
+	provider::{client_id_to_string, AuthorizationRequest, OIDCContext, TokenRequest},
+	OIDCClient, OIDCClientMetadata, Result,
+};
+use itp_types::{AccountId, ShardIdentifier, H256};
+use litentry_primitives::{Identity, LitentryMultiSignature};
+use sp_core::{ed25519, sr25519, Pair};
+use std::{
+	string::{String, ToString},
+	vec,
+	vec::Vec,
+};
+
+pub const NOW: u64 = 1_700_000_000;
+pub const ISSUER: &str = "https://oidc.example";
+pub const REDIRECT_URI: &str = "https://dapp.example/callback";
+// see https://www.rfc-editor.org/rfc/rfc7636#appendix-B
+pub const CODE_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+pub const CODE_CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+pub struct ContextMock {
+	pub client: ed25519::Pair,
+	pub user: sr25519::Pair,
+	pub signer: ed25519::Pair,
+}
+
+impl Default for ContextMock {
+	fn default() -> Self {
+		Self {
+			client: ed25519::Pair::from_seed(&[1u8; 32]),
+			user: sr25519::Pair::from_seed(&[2u8; 32]),
+			signer: ed25519::Pair::from_seed(&[3u8; 32]),
+		}
+	}
+}
+
+impl ContextMock {
+	pub fn client_id(&self) -> AccountId {
+		self.client.public().into()
+	}
+
+	pub fn who(&self) -> Identity {
+		self.user.public().into()
+	}
+
+	pub fn authorization_request(&self, redirect_uri: &str) -> AuthorizationRequest {
+		self.sign(AuthorizationRequest {
+			shard: ShardIdentifier::default(),
+			client_id: self.client_id(),
+			redirect_uri: redirect_uri.to_string(),
+			scope: "openid".to_string(),
+			nonce: Some("nonce".to_string()),
+			code_challenge: CODE_CHALLENGE.to_string(),
+			issued_at: NOW,
+			who: self.who(),
+			vcs: vec![],
+			signature: LitentryMultiSignature::Sr25519(self.user.sign(b"")),
+		})
+	}
+
+	pub fn sign(&self, mut request: AuthorizationRequest) -> AuthorizationRequest {
+		request.signature =
+			LitentryMultiSignature::Sr25519(self.user.sign(&request.signature_payload()));
+		request
+	}
+
+	pub fn client_assertion(&self, jti: &str, aud: &str) -> String {
+		let client_id = client_id_to_string(&self.client_id());
+		let claims = ClientAssertionClaims {
+			iss: client_id.clone(),
+			sub: client_id,
+			aud: Audience::One(aud.to_string()),
+			exp: NOW + 60,
+			jti: jti.to_string(),
+		};
+		sign_jwt(&self.client, &claims).unwrap()
+	}
+
+	pub fn token_request(&self, code: String) -> TokenRequest {
+		TokenRequest {
+			client_id: self.client_id(),
+			client_assertion: self.client_assertion(&code, &endpoint(ISSUER, TOKEN_PATH)),
+			code,
+			redirect_uri: REDIRECT_URI.to_string(),
+			code_verifier: CODE_VERIFIER.to_string(),
+		}
+	}
+}
+
+impl OIDCContext for ContextMock {
+	fn oidc_client(&self, client_id: &AccountId) -> Result<Option<OIDCClient>> {
+		Ok((client_id == &self.client_id()).then(|| OIDCClient {
+			redirect_uris: vec![REDIRECT_URI.as_bytes().to_vec()],
+			metadata: OIDCClientMetadata {
+				scopes: vec![b"identities".to_vec(), b"vc".to_vec()],
+				..Default::default()
+			},
+			..Default::default()
+		}))
+	}
+
+	fn id_graph(
+		&self,
+		_shard: &ShardIdentifier,
+		who: &Identity,
+	) -> Result<Option<(Vec<Identity>, H256)>> {
+		Ok((who == &self.who()).then(|| (vec![self.who()], H256::repeat_byte(1))))
+	}
+
+	fn signer(&self) -> Result<ed25519::Pair> {
+		Ok(self.signer.clone())
+	}
+
+	fn issuer(&self) -> Result<String> {
+		Ok(ISSUER.to_string())
+	}
+}
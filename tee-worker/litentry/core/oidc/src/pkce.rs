@@ -0,0 +1,63 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Proof Key for Code Exchange with the `S256` method, see https://www.rfc-editor.org/rfc/rfc7636
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use sp_core::hashing::sha2_256;
+
+pub const CODE_CHALLENGE_METHOD: &str = "S256";
+
+/// The code challenge is the base64url encoded SHA-256 hash of the code verifier
+pub fn is_valid_code_challenge(code_challenge: &str) -> bool {
+	code_challenge.len() == 43
+		&& URL_SAFE_NO_PAD.decode(code_challenge).map_or(false, |hash| hash.len() == 32)
+}
+
+/// The code verifier must consist of 43 to 128 unreserved characters
+pub fn verify_code_verifier(code_verifier: &str, code_challenge: &str) -> bool {
+	(43..=128).contains(&code_verifier.len())
+		&& code_verifier
+			.bytes()
+			.all(|c| c.is_ascii_alphanumeric() || matches!(c, b'-' | b'.' | b'_' | b'~'))
+		&& URL_SAFE_NO_PAD.encode(sha2_256(code_verifier.as_bytes())) == code_challenge
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// see https://www.rfc-editor.org/rfc/rfc7636#appendix-B
+	const CODE_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+	const CODE_CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+	#[test]
+	fn verify_code_verifier_works() {
+		assert!(is_valid_code_challenge(CODE_CHALLENGE));
+		assert!(verify_code_verifier(CODE_VERIFIER, CODE_CHALLENGE));
+	}
+
+	#[test]
+	fn verify_code_verifier_fails_with_other_verifier() {
+		assert!(!verify_code_verifier(
+			"dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXK",
+			CODE_CHALLENGE
+		));
+		assert!(!verify_code_verifier("short", CODE_CHALLENGE));
+		assert!(!is_valid_code_challenge(CODE_VERIFIER.trim_end_matches('k')));
+		assert!(!is_valid_code_challenge("plain-challenge"));
+	}
+}
@@ -0,0 +1,499 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+use crate::sgx_reexport_prelude::*;
+
+use crate::{
+	claims::to_did,
+	http::{endpoint, TOKEN_PATH},
+	jwt::{sign_jwt, verify_jwt, ClientAssertionClaims},
+	pkce::{is_valid_code_challenge, verify_code_verifier},
+	store::{AuthorizationGrant, ACCESS_TOKENS, AUTHORIZATION_CODES, CLIENT_ASSERTIONS},
+	vc::verify_vc,
+	Error, IdTokenClaims, Jwks, OIDCClient, Result, UserClaims, AUTHORIZATION_CODE_TTL,
+	AUTHORIZATION_REQUEST_TTL, CLIENT_ASSERTION_TTL, OPENID_SCOPE, TOKEN_TTL, VC_SCOPE,
+};
+use codec::{Decode, Encode};
+use itp_types::{AccountId, ShardIdentifier};
+use litentry_primitives::{Identity, LitentryMultiSignature};
+use log::*;
+use serde::{Deserialize, Serialize};
+use sp_core::{blake2_256, ed25519, Pair, H256};
+use std::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+
+/// Access to the data that the OIDC provider relies on
+pub trait OIDCContext {
+	/// Get the OIDC client registered on the parachain
+	fn oidc_client(&self, client_id: &AccountId) -> Result<Option<OIDCClient>>;
+
+	/// Get the active identities in the IDGraph of `who` and the IDGraph hash,
+	/// `None` if the IDGraph doesn't exist
+	fn id_graph(
+		&self,
+		shard: &ShardIdentifier,
+		who: &Identity,
+	) -> Result<Option<(Vec<Identity>, H256)>>;
+
+	/// Get the enclave signer, which signs the ID tokens
+	fn signer(&self) -> Result<ed25519::Pair>;
+
+	/// Get the issuer URL, which is pinned in the enclave and not taken from the requests
+	fn issuer(&self) -> Result<String>;
+}
+
+/// The authorization request, which is signed by the prime identity of the user as consent
+/// to share the claims with the client
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizationRequest {
+	pub shard: ShardIdentifier,
+	pub client_id: AccountId,
+	pub redirect_uri: String,
+	/// space separated scopes, must contain `openid`, see `SCOPE_CLAIMS` for the claims they grant
+	pub scope: String,
+	pub nonce: Option<String>,
+	/// the S256 PKCE code challenge of the client
+	pub code_challenge: String,
+	/// unix timestamp in seconds of when the request was signed
+	pub issued_at: u64,
+	pub who: Identity,
+	/// VCs that should be shared with the client, they must have been issued by this enclave
+	pub vcs: Vec<String>,
+	pub signature: LitentryMultiSignature,
+}
+
+impl AuthorizationRequest {
+	pub fn signature_payload(&self) -> Vec<u8> {
+		blake2_256(
+			&(
+				&self.shard,
+				&self.client_id,
+				&self.redirect_uri,
+				&self.scope,
+				&self.nonce,
+				&self.code_challenge,
+				self.issued_at,
+				&self.who,
+				&self.vcs,
+			)
+				.encode(),
+		)
+		.to_vec()
+	}
+}
+
+/// The token request of the authorization code flow, the client authenticates with the
+/// `private_key_jwt` method, see
+/// https://openid.net/specs/openid-connect-core-1_0.html#TokenRequest
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenRequest {
+	pub client_id: AccountId,
+	pub code: String,
+	pub redirect_uri: String,
+	pub code_verifier: String,
+	/// JWT with `ClientAssertionClaims`, signed by the ed25519 key of the client account
+	pub client_assertion: String,
+}
+
+/// See https://openid.net/specs/openid-connect-core-1_0.html#TokenResponse
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TokenResponse {
+	pub access_token: String,
+	pub token_type: String,
+	pub expires_in: u64,
+	pub id_token: String,
+}
+
+/// Handle the authorization request and return the authorization code
+pub fn authorize<C: OIDCContext>(
+	context: &C,
+	request: AuthorizationRequest,
+	now: u64,
+) -> Result<String> {
+	if now > request.issued_at.saturating_add(AUTHORIZATION_REQUEST_TTL)
+		|| request.issued_at > now.saturating_add(AUTHORIZATION_REQUEST_TTL)
+	{
+		return Err(Error::RequestExpired)
+	}
+
+	let client = context.oidc_client(&request.client_id)?.ok_or(Error::UnknownClient)?;
+	if !client.allows_redirect_uri(&request.redirect_uri) {
+		return Err(Error::InvalidRedirectUri)
+	}
+	let scopes: Vec<&str> = request.scope.split(' ').filter(|scope| !scope.is_empty()).collect();
+	if !scopes.contains(&OPENID_SCOPE) || !scopes.iter().all(|scope| client.allows_scope(scope)) {
+		return Err(Error::InvalidScope)
+	}
+	// the VCs would be dropped from the claims anyway
+	if !request.vcs.is_empty() && !scopes.contains(&VC_SCOPE) {
+		return Err(Error::InvalidScope)
+	}
+	if !is_valid_code_challenge(&request.code_challenge) {
+		return Err(Error::InvalidRequest("invalid code_challenge".to_string()))
+	}
+
+	if !request.signature.verify(&request.signature_payload(), &request.who) {
+		return Err(Error::InvalidSignature)
+	}
+
+	let (identities, id_graph_hash) =
+		context.id_graph(&request.shard, &request.who)?.ok_or(Error::EmptyIDGraph)?;
+	// the VCs must be issued to one of the identities, even if they are not shared
+	let subjects = identities.iter().map(to_did).collect::<Result<Vec<_>>>()?;
+	let issuer = context.signer()?.public();
+	let vcs = request
+		.vcs
+		.iter()
+		.map(|vc| verify_vc(vc, &issuer, &subjects))
+		.collect::<Result<Vec<_>>>()?;
+	let claims = UserClaims::new(&request.who, &scopes, &identities, id_graph_hash, vcs)?;
+
+	debug!("OIDC authorization granted to client {:?}", request.client_id);
+	AUTHORIZATION_CODES.insert(
+		&request.client_id,
+		AuthorizationGrant {
+			client_id: request.client_id.clone(),
+			redirect_uri: request.redirect_uri,
+			scope: request.scope,
+			nonce: request.nonce,
+			code_challenge: request.code_challenge,
+			claims,
+		},
+		now.saturating_add(AUTHORIZATION_CODE_TTL),
+	)
+}
+
+/// Exchange the authorization code for an access token and an ID token
+pub fn exchange_code<C: OIDCContext>(
+	context: &C,
+	request: TokenRequest,
+	now: u64,
+) -> Result<TokenResponse> {
+	let issuer = context.issuer()?;
+	authenticate_client(
+		&request.client_id,
+		&request.client_assertion,
+		&[issuer.as_str(), endpoint(&issuer, TOKEN_PATH).as_str()],
+		now,
+	)?;
+
+	let grant = AUTHORIZATION_CODES
+		.take(&request.code, now)?
+		.ok_or(Error::InvalidAuthorizationCode)?;
+	if grant.client_id != request.client_id || grant.redirect_uri != request.redirect_uri {
+		return Err(Error::InvalidAuthorizationCode)
+	}
+	if !verify_code_verifier(&request.code_verifier, &grant.code_challenge) {
+		return Err(Error::InvalidCodeVerifier)
+	}
+	// the client might have been unregistered in the meantime
+	context.oidc_client(&request.client_id)?.ok_or(Error::UnknownClient)?;
+
+	let signer = context.signer()?;
+	let id_token_claims = IdTokenClaims::new(
+		issuer,
+		client_id_to_string(&request.client_id),
+		grant.claims.clone(),
+		grant.nonce,
+		now,
+		TOKEN_TTL,
+	);
+	let id_token = sign_jwt(&signer, &id_token_claims)?;
+	let access_token =
+		ACCESS_TOKENS.insert(&request.client_id, grant.claims, now.saturating_add(TOKEN_TTL))?;
+
+	Ok(TokenResponse {
+		access_token,
+		token_type: "Bearer".to_string(),
+		expires_in: TOKEN_TTL,
+		id_token,
+	})
+}
+
+/// Get the claims about the user that granted the access token
+pub fn userinfo(access_token: &str, now: u64) -> Result<UserClaims> {
+	ACCESS_TOKENS.get(access_token, now)?.ok_or(Error::InvalidAccessToken)
+}
+
+pub fn jwks<C: OIDCContext>(context: &C) -> Result<Jwks> {
+	Ok(Jwks::new(&context.signer()?.public()))
+}
+
+// `private_key_jwt` client authentication: the assertion must be signed by the ed25519 key of
+// the client account, be addressed to one of `audiences` and can only be used once, see
+// https://openid.net/specs/openid-connect-core-1_0.html#ClientAuthentication
+fn authenticate_client(
+	client_id: &AccountId,
+	client_assertion: &str,
+	audiences: &[&str],
+	now: u64,
+) -> Result<()> {
+	let public = ed25519::Public::from_raw(client_id.clone().into());
+	let claims = verify_jwt(client_assertion, &public)
+		.map_err(|_| Error::InvalidClient("invalid client assertion".to_string()))?;
+	let claims: ClientAssertionClaims =
+		serde_json::from_slice(&claims).map_err(|e| Error::InvalidClient(e.to_string()))?;
+
+	let client = client_id_to_string(client_id);
+	if claims.iss != client || claims.sub != client {
+		return Err(Error::InvalidClient("unexpected iss or sub".to_string()))
+	}
+	if !audiences.iter().any(|audience| claims.aud.contains(audience)) {
+		return Err(Error::InvalidClient("unexpected aud".to_string()))
+	}
+	if claims.exp <= now || claims.exp > now.saturating_add(CLIENT_ASSERTION_TTL) {
+		return Err(Error::InvalidClient("expired client assertion".to_string()))
+	}
+	let jti = format!("{}:{}", client, claims.jti);
+	if !CLIENT_ASSERTIONS.insert_unique(client_id, jti, (), claims.exp, now)? {
+		return Err(Error::InvalidClient("client assertion was already used".to_string()))
+	}
+	Ok(())
+}
+
+pub(crate) fn client_id_to_string(client_id: &AccountId) -> String {
+	format!("0x{}", hex::encode(AsRef::<[u8]>::as_ref(client_id)))
+}
+
+pub(crate) fn parse_client_id(client_id: &str) -> Result<AccountId> {
+	hex::decode(client_id.trim_start_matches("0x"))
+		.ok()
+		.and_then(|id| <[u8; 32]>::try_from(id).ok())
+		.map(AccountId::new)
+		.ok_or(Error::UnknownClient)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		jwt::{decode_jwt_claims_unverified, verify_jwt},
+		mock::*,
+	};
+	use std::vec;
+
+	#[test]
+	fn oidc_flow_works() {
+		let context = ContextMock::default();
+		let code = authorize(&context, context.authorization_request(REDIRECT_URI), NOW).unwrap();
+		let response = exchange_code(&context, context.token_request(code), NOW + 1).unwrap();
+		assert_eq!(response.token_type, "Bearer");
+		assert_eq!(response.expires_in, TOKEN_TTL);
+
+		let claims = verify_jwt(&response.id_token, &jwks_public(&context)).unwrap();
+		let claims: IdTokenClaims = serde_json::from_slice(&claims).unwrap();
+		assert_eq!(claims.sub, context.who().to_did().unwrap());
+		assert_eq!(claims.aud, client_id_to_string(&context.client_id()));
+		assert_eq!(claims.iss, ISSUER);
+		assert_eq!(claims.nonce, Some("nonce".to_string()));
+		assert_eq!(claims.exp, NOW + 1 + TOKEN_TTL);
+		// `openid` alone grants `sub` only
+		assert!(claims.identities.is_empty());
+		assert_eq!(claims.id_graph_hash, None);
+
+		let user_claims = userinfo(&response.access_token, NOW + 2).unwrap();
+		assert_eq!(user_claims.sub, claims.sub);
+		assert_eq!(
+			userinfo(&response.access_token, NOW + 1 + TOKEN_TTL),
+			Err(Error::InvalidAccessToken)
+		);
+	}
+
+	#[test]
+	fn identities_scope_grants_identities() {
+		let context = ContextMock::default();
+		let mut request = context.authorization_request(REDIRECT_URI);
+		request.scope = "openid identities".to_string();
+		let code = authorize(&context, context.sign(request), NOW).unwrap();
+		let response = exchange_code(&context, context.token_request(code), NOW + 1).unwrap();
+
+		let claims = verify_jwt(&response.id_token, &jwks_public(&context)).unwrap();
+		let claims: IdTokenClaims = serde_json::from_slice(&claims).unwrap();
+		assert_eq!(claims.identities, vec![context.who().to_did().unwrap()]);
+		assert_eq!(claims.id_graph_hash, Some(format!("{:?}", H256::repeat_byte(1))));
+		assert!(claims.vcs.is_empty());
+
+		let user_claims = userinfo(&response.access_token, NOW + 2).unwrap();
+		assert_eq!(user_claims.identities, claims.identities);
+	}
+
+	#[test]
+	fn authorize_fails_with_vcs_without_vc_scope() {
+		let context = ContextMock::default();
+		let mut request = context.authorization_request(REDIRECT_URI);
+		request.scope = "openid identities".to_string();
+		request.vcs = vec!["{}".to_string()];
+		assert_eq!(authorize(&context, context.sign(request), NOW), Err(Error::InvalidScope));
+	}
+
+	fn jwks_public(context: &ContextMock) -> ed25519::Public {
+		let jwks = jwks(context).unwrap();
+		assert_eq!(jwks.keys[0].kid, crate::jwt::key_id(&context.signer.public()));
+		context.signer.public()
+	}
+
+	#[test]
+	fn authorize_fails_with_unregistered_redirect_uri() {
+		let context = ContextMock::default();
+		assert_eq!(
+			authorize(&context, context.authorization_request("https://evil.example"), NOW),
+			Err(Error::InvalidRedirectUri)
+		);
+	}
+
+	#[test]
+	fn authorize_fails_with_unknown_client() {
+		let context = ContextMock::default();
+		let mut request = context.authorization_request(REDIRECT_URI);
+		request.client_id = AccountId::new([9u8; 32]);
+		assert_eq!(authorize(&context, request, NOW), Err(Error::UnknownClient));
+	}
+
+	#[test]
+	fn authorize_fails_with_invalid_signature() {
+		let context = ContextMock::default();
+		let mut request = context.authorization_request(REDIRECT_URI);
+		request.nonce = Some("other".to_string());
+		assert_eq!(authorize(&context, request, NOW), Err(Error::InvalidSignature));
+	}
+
+	#[test]
+	fn authorize_fails_with_expired_request() {
+		let context = ContextMock::default();
+		assert_eq!(
+			authorize(
+				&context,
+				context.authorization_request(REDIRECT_URI),
+				NOW + AUTHORIZATION_REQUEST_TTL + 1
+			),
+			Err(Error::RequestExpired)
+		);
+	}
+
+	#[test]
+	fn authorize_fails_without_openid_scope() {
+		let context = ContextMock::default();
+		let mut request = context.authorization_request(REDIRECT_URI);
+		request.scope = "profile".to_string();
+		assert_eq!(authorize(&context, context.sign(request), NOW), Err(Error::InvalidScope));
+	}
+
+	#[test]
+	fn authorize_fails_with_unregistered_scope() {
+		let context = ContextMock::default();
+		let mut request = context.authorization_request(REDIRECT_URI);
+		request.scope = "openid vc".to_string();
+		assert!(authorize(&context, context.sign(request.clone()), NOW).is_ok());
+		request.scope = "openid vc email".to_string();
+		assert_eq!(authorize(&context, context.sign(request), NOW), Err(Error::InvalidScope));
+	}
+
+	#[test]
+	fn client_cannot_register_unsupported_scope() {
+		let mut client = OIDCClient::default();
+		client.metadata.scopes = vec![b"vc".to_vec(), b"email".to_vec()];
+		assert!(client.allows_scope(OPENID_SCOPE));
+		assert!(client.allows_scope(VC_SCOPE));
+		assert!(!client.allows_scope("email"));
+	}
+
+	#[test]
+	fn authorize_fails_without_code_challenge() {
+		let context = ContextMock::default();
+		let mut request = context.authorization_request(REDIRECT_URI);
+		request.code_challenge = CODE_VERIFIER.to_string();
+		assert_eq!(
+			authorize(&context, context.sign(request), NOW),
+			Err(Error::InvalidRequest("invalid code_challenge".to_string()))
+		);
+	}
+
+	#[test]
+	fn authorization_code_can_only_be_used_once() {
+		let context = ContextMock::default();
+		let code = authorize(&context, context.authorization_request(REDIRECT_URI), NOW).unwrap();
+		assert!(exchange_code(&context, context.token_request(code.clone()), NOW).is_ok());
+		let mut request = context.token_request(code);
+		request.client_assertion = context.client_assertion("other", ISSUER);
+		assert_eq!(exchange_code(&context, request, NOW), Err(Error::InvalidAuthorizationCode));
+	}
+
+	#[test]
+	fn exchange_code_fails_with_wrong_code_verifier() {
+		let context = ContextMock::default();
+		let code = authorize(&context, context.authorization_request(REDIRECT_URI), NOW).unwrap();
+		let mut request = context.token_request(code);
+		request.code_verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXK".to_string();
+		assert_eq!(exchange_code(&context, request, NOW), Err(Error::InvalidCodeVerifier));
+	}
+
+	#[test]
+	fn exchange_code_fails_with_invalid_client_assertion() {
+		let context = ContextMock::default();
+		let code = authorize(&context, context.authorization_request(REDIRECT_URI), NOW).unwrap();
+
+		let mut request = context.token_request(code.clone());
+		request.client_assertion = sign_jwt(
+			&context.signer,
+			&serde_json::from_slice::<ClientAssertionClaims>(
+				&decode_jwt_claims_unverified(&request.client_assertion).unwrap(),
+			)
+			.unwrap(),
+		)
+		.unwrap();
+		assert_eq!(
+			exchange_code(&context, request, NOW),
+			Err(Error::InvalidClient("invalid client assertion".to_string()))
+		);
+
+		let mut request = context.token_request(code.clone());
+		request.client_assertion = context.client_assertion("aud", "https://evil.example");
+		assert_eq!(
+			exchange_code(&context, request, NOW),
+			Err(Error::InvalidClient("unexpected aud".to_string()))
+		);
+
+		let request = context.token_request(code);
+		assert_eq!(
+			exchange_code(&context, request, NOW + 60),
+			Err(Error::InvalidClient("expired client assertion".to_string()))
+		);
+	}
+
+	#[test]
+	fn client_assertion_cannot_be_replayed() {
+		let context = ContextMock::default();
+		let code = authorize(&context, context.authorization_request(REDIRECT_URI), NOW).unwrap();
+		let request = context.token_request(code);
+		let client_assertion = request.client_assertion.clone();
+		assert!(exchange_code(&context, request, NOW).is_ok());
+
+		let code = authorize(&context, context.authorization_request(REDIRECT_URI), NOW).unwrap();
+		let mut request = context.token_request(code);
+		request.client_assertion = client_assertion;
+		assert_eq!(
+			exchange_code(&context, request, NOW),
+			Err(Error::InvalidClient("client assertion was already used".to_string()))
+		);
+	}
+}
@@ -0,0 +1,246 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_rand as rand;
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+use crate::{Error, Result, UserClaims};
+use itp_types::AccountId;
+use lazy_static::lazy_static;
+use lru::LruCache;
+use rand::{thread_rng, Rng};
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+#[cfg(feature = "sgx")]
+use std::sync::SgxRwLock as RwLock;
+use std::{
+	collections::{BTreeMap, VecDeque},
+	num::NonZeroUsize,
+	string::String,
+};
+
+const STORE_CAPACITY: usize = 10_000;
+/// The maximum number of entries of a single client, so that one client can't evict the
+/// entries of the other clients
+pub const MAX_ENTRIES_PER_CLIENT: usize = 100;
+
+lazy_static! {
+	pub static ref AUTHORIZATION_CODES: ExpiringStore<AuthorizationGrant> =
+		ExpiringStore::new(STORE_CAPACITY, MAX_ENTRIES_PER_CLIENT);
+	pub static ref ACCESS_TOKENS: ExpiringStore<UserClaims> =
+		ExpiringStore::new(STORE_CAPACITY, MAX_ENTRIES_PER_CLIENT);
+	/// The `jti` of the client assertions that were already used, to prevent replays
+	pub static ref CLIENT_ASSERTIONS: ExpiringStore<()> =
+		ExpiringStore::new(STORE_CAPACITY, MAX_ENTRIES_PER_CLIENT);
+}
+
+/// What an authorization code is exchanged for
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizationGrant {
+	pub client_id: AccountId,
+	pub redirect_uri: String,
+	pub scope: String,
+	pub nonce: Option<String>,
+	/// the S256 PKCE code challenge, which the code verifier of the token request must match
+	pub code_challenge: String,
+	pub claims: UserClaims,
+}
+
+struct Entries<V> {
+	values: LruCache<String, (AccountId, V, u64)>,
+	// the keys of each client, the oldest first
+	owners: BTreeMap<AccountId, VecDeque<String>>,
+}
+
+impl<V> Entries<V> {
+	fn put(&mut self, owner: AccountId, key: String, value: V, expires_at: u64, max: usize) {
+		let keys = self.owners.entry(owner.clone()).or_default();
+		let oldest = if keys.len() >= max { keys.pop_front() } else { None };
+		keys.push_back(key.clone());
+		if let Some(oldest) = oldest {
+			self.values.pop(&oldest);
+		}
+		// the least recently used entry is evicted when the store is full
+		if let Some((evicted, (evicted_owner, ..))) =
+			self.values.push(key.clone(), (owner, value, expires_at))
+		{
+			if evicted != key {
+				self.forget(&evicted_owner, &evicted);
+			}
+		}
+	}
+
+	fn remove(&mut self, key: &str) -> Option<(V, u64)> {
+		let (owner, value, expires_at) = self.values.pop(key)?;
+		self.forget(&owner, key);
+		Some((value, expires_at))
+	}
+
+	fn forget(&mut self, owner: &AccountId, key: &str) {
+		if let Some(keys) = self.owners.get_mut(owner) {
+			keys.retain(|k| k != key);
+			if keys.is_empty() {
+				self.owners.remove(owner);
+			}
+		}
+	}
+}
+
+/// In-memory store of the short-lived authorization codes and access tokens
+///
+/// Every entry belongs to the client it was issued to. When a client reaches
+/// `max_entries_per_client`, its oldest entry is evicted, and when the store is full, the least
+/// recently used entry is evicted.
+pub struct ExpiringStore<V> {
+	entries: RwLock<Entries<V>>,
+	max_entries_per_client: usize,
+}
+
+impl<V: Clone> ExpiringStore<V> {
+	pub fn new(capacity: usize, max_entries_per_client: usize) -> Self {
+		#[allow(clippy::unwrap_used)]
+		let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::new(1).unwrap());
+		Self {
+			entries: RwLock::new(Entries {
+				values: LruCache::new(capacity),
+				owners: BTreeMap::new(),
+			}),
+			max_entries_per_client: max_entries_per_client.max(1),
+		}
+	}
+
+	/// Store the value of `client` under a new random key, which is returned
+	pub fn insert(&self, client: &AccountId, value: V, expires_at: u64) -> Result<String> {
+		let key = random_token();
+		self.entries.write().map_err(|_| Error::LockPoisoning)?.put(
+			client.clone(),
+			key.clone(),
+			value,
+			expires_at,
+			self.max_entries_per_client,
+		);
+		Ok(key)
+	}
+
+	/// Store the value of `client` under `key`, returns `false` if a value that is not expired
+	/// yet is already stored under `key`
+	pub fn insert_unique(
+		&self,
+		client: &AccountId,
+		key: String,
+		value: V,
+		expires_at: u64,
+		now: u64,
+	) -> Result<bool> {
+		let mut entries = self.entries.write().map_err(|_| Error::LockPoisoning)?;
+		if matches!(entries.values.peek(&key), Some((_, _, expires_at)) if *expires_at > now) {
+			return Ok(false)
+		}
+		entries.remove(&key);
+		entries.put(client.clone(), key, value, expires_at, self.max_entries_per_client);
+		Ok(true)
+	}
+
+	/// Get the value if it's not expired yet
+	pub fn get(&self, key: &str, now: u64) -> Result<Option<V>> {
+		let mut entries = self.entries.write().map_err(|_| Error::LockPoisoning)?;
+		match entries.values.get(key) {
+			Some((_, value, expires_at)) if *expires_at > now => Ok(Some(value.clone())),
+			Some(_) => {
+				entries.remove(key);
+				Ok(None)
+			},
+			None => Ok(None),
+		}
+	}
+
+	/// Remove the value, it's returned if it's not expired yet
+	pub fn take(&self, key: &str, now: u64) -> Result<Option<V>> {
+		let entry = self.entries.write().map_err(|_| Error::LockPoisoning)?.remove(key);
+		Ok(entry.and_then(|(value, expires_at)| (expires_at > now).then_some(value)))
+	}
+}
+
+fn random_token() -> String {
+	let bytes: [u8; 32] = thread_rng().gen();
+	hex::encode(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn client(id: u8) -> AccountId {
+		AccountId::new([id; 32])
+	}
+
+	#[test]
+	fn take_works_only_once() {
+		let store = ExpiringStore::<u32>::new(10, 10);
+		let key = store.insert(&client(1), 1, 100).unwrap();
+		assert_eq!(key.len(), 64);
+		assert_eq!(store.take(&key, 50).unwrap(), Some(1));
+		assert_eq!(store.take(&key, 50).unwrap(), None);
+	}
+
+	#[test]
+	fn expired_entries_are_not_returned() {
+		let store = ExpiringStore::<u32>::new(10, 10);
+		let key = store.insert(&client(1), 1, 100).unwrap();
+		assert_eq!(store.get(&key, 99).unwrap(), Some(1));
+		assert_eq!(store.get(&key, 99).unwrap(), Some(1));
+		assert_eq!(store.get(&key, 100).unwrap(), None);
+
+		let key = store.insert(&client(1), 2, 100).unwrap();
+		assert_eq!(store.take(&key, 100).unwrap(), None);
+	}
+
+	#[test]
+	fn least_recently_used_entries_are_evicted() {
+		let store = ExpiringStore::<u32>::new(1, 10);
+		let first = store.insert(&client(1), 1, 100).unwrap();
+		let second = store.insert(&client(2), 2, 100).unwrap();
+		assert_eq!(store.get(&first, 0).unwrap(), None);
+		assert_eq!(store.get(&second, 0).unwrap(), Some(2));
+		assert!(!store.entries.read().unwrap().owners.contains_key(&client(1)));
+	}
+
+	#[test]
+	fn a_client_can_only_evict_its_own_entries() {
+		let store = ExpiringStore::<u32>::new(4, 2);
+		let other = store.insert(&client(2), 0, 100).unwrap();
+		let keys: Vec<String> =
+			(1..=5).map(|i| store.insert(&client(1), i, 100).unwrap()).collect();
+
+		assert_eq!(store.get(&other, 0).unwrap(), Some(0));
+		assert_eq!(store.get(&keys[2], 0).unwrap(), None);
+		assert_eq!(store.get(&keys[3], 0).unwrap(), Some(4));
+		assert_eq!(store.get(&keys[4], 0).unwrap(), Some(5));
+		assert_eq!(store.entries.read().unwrap().owners[&client(1)].len(), 2);
+	}
+
+	#[test]
+	fn insert_unique_rejects_keys_that_are_not_expired() {
+		let store = ExpiringStore::<()>::new(10, 10);
+		assert!(store.insert_unique(&client(1), "jti".into(), (), 100, 0).unwrap());
+		assert!(!store.insert_unique(&client(1), "jti".into(), (), 200, 99).unwrap());
+		assert!(!store.insert_unique(&client(1), "jti".into(), (), 200, 99).unwrap());
+		assert!(store.insert_unique(&client(1), "jti".into(), (), 200, 100).unwrap());
+		assert_eq!(store.entries.read().unwrap().owners[&client(1)].len(), 1);
+	}
+}
@@ -0,0 +1,139 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+use crate::sgx_reexport_prelude::*;
+
+use crate::{Error, Result, VCClaim};
+use lc_credentials::Credential;
+use sp_core::{ed25519, Pair};
+use std::{
+	format,
+	string::{String, ToString},
+};
+
+/// Verify that the VC was issued by `issuer` to one of the `subjects` and derive the claim
+///
+/// The VC is signed over its JSON without the `proof` field, which is then appended as the last
+/// field, see `lc_credentials::Credential::add_proof`. We can't re-serialize the parsed VC to
+/// get the signed payload as some fields (e.g. `assertions`) are skipped when deserializing,
+/// so the payload is restored by stripping the `proof` field from the original JSON.
+pub fn verify_vc(vc: &str, issuer: &ed25519::Public, subjects: &[String]) -> Result<VCClaim> {
+	let credential: Credential =
+		serde_json::from_str(vc).map_err(|e| Error::InvalidVC(e.to_string()))?;
+	let proof = credential.proof.as_ref().ok_or_else(|| Error::InvalidVC("no proof".into()))?;
+
+	let proof_field = format!(
+		",\"proof\":{}",
+		serde_json::to_string(proof).map_err(|e| Error::InvalidVC(e.to_string()))?
+	);
+	if !vc.contains(proof_field.as_str()) {
+		return Err(Error::InvalidVC("unexpected proof format".into()))
+	}
+	let payload = vc.replacen(proof_field.as_str(), "", 1);
+
+	let signature = hex::decode(proof.proof_value.trim_start_matches("0x"))
+		.ok()
+		.and_then(|s| ed25519::Signature::from_slice(&s))
+		.ok_or_else(|| Error::InvalidVC("invalid proof value".into()))?;
+	if !ed25519::Pair::verify(&signature, payload.as_bytes(), issuer) {
+		return Err(Error::InvalidVC("not issued by this enclave".into()))
+	}
+
+	if !subjects.contains(&credential.credential_subject.id) {
+		return Err(Error::InvalidVC("subject is not in the IDGraph".into()))
+	}
+
+	Ok(VCClaim {
+		id: credential.id,
+		description: credential.credential_subject.description,
+		types: credential.credential_subject.types,
+		values: credential.credential_subject.values,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use itp_types::ShardIdentifier;
+	use lc_credentials::IssuerRuntimeVersion;
+	use litentry_primitives::Identity;
+	use sp_core::crypto::AccountId32;
+
+	fn issue_vc(signer: &ed25519::Pair, subject: &Identity) -> String {
+		let runtime_version = IssuerRuntimeVersion { parachain: 0u32, sidechain: 0u32 };
+		let mut credential =
+			Credential::new(subject, &ShardIdentifier::default(), &runtime_version).unwrap();
+		// `assertions` are not deserialized, which must not break the verification
+		credential.update_holder(false, &"1".to_string(), &"2017-01-01".to_string());
+		let payload = credential.to_json().unwrap();
+		let signature = signer.sign(payload.as_bytes());
+		credential.add_proof(&signature.0.to_vec(), "issuer".to_string());
+		credential.to_json().unwrap()
+	}
+
+	#[test]
+	fn verify_vc_works() {
+		let signer = ed25519::Pair::from_seed(&[1u8; 32]);
+		let subject: Identity = AccountId32::new([2u8; 32]).into();
+		let vc = issue_vc(&signer, &subject);
+
+		let claim = verify_vc(&vc, &signer.public(), &[subject.to_did().unwrap()]).unwrap();
+		assert_eq!(claim.values, vec![false]);
+		assert!(claim.id.starts_with("0x"));
+	}
+
+	#[test]
+	fn verify_vc_fails_with_other_issuer() {
+		let signer = ed25519::Pair::from_seed(&[1u8; 32]);
+		let other = ed25519::Pair::from_seed(&[3u8; 32]);
+		let subject: Identity = AccountId32::new([2u8; 32]).into();
+		let vc = issue_vc(&other, &subject);
+
+		assert_eq!(
+			verify_vc(&vc, &signer.public(), &[subject.to_did().unwrap()]),
+			Err(Error::InvalidVC("not issued by this enclave".into()))
+		);
+	}
+
+	#[test]
+	fn verify_vc_fails_with_tampered_vc() {
+		let signer = ed25519::Pair::from_seed(&[1u8; 32]);
+		let subject: Identity = AccountId32::new([2u8; 32]).into();
+		let vc = issue_vc(&signer, &subject).replace("\"values\":[false]", "\"values\":[true]");
+
+		assert_eq!(
+			verify_vc(&vc, &signer.public(), &[subject.to_did().unwrap()]),
+			Err(Error::InvalidVC("not issued by this enclave".into()))
+		);
+	}
+
+	#[test]
+	fn verify_vc_fails_with_foreign_subject() {
+		let signer = ed25519::Pair::from_seed(&[1u8; 32]);
+		let subject: Identity = AccountId32::new([2u8; 32]).into();
+		let other: Identity = AccountId32::new([4u8; 32]).into();
+		let vc = issue_vc(&signer, &subject);
+
+		assert_eq!(
+			verify_vc(&vc, &signer.public(), &[other.to_did().unwrap()]),
+			Err(Error::InvalidVC("subject is not in the IDGraph".into()))
+		);
+	}
+}
@@ -305,6 +305,7 @@ where
 
 	credential.parachain_block_number = req.parachain_block_number;
 	credential.sidechain_block_number = req.sidechain_block_number;
+	credential.web2_verified_at = req.web2_verified_at;
 
 	credential.credential_subject.endpoint =
 		context.data_provider_config.credential_endpoint.to_string();
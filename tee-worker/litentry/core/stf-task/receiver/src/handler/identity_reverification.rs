@@ -0,0 +1,136 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	handler::TaskHandler, EnclaveOnChainOCallApi, Getter, StfTaskContext, TrustedCall,
+	TrustedCallSigned,
+};
+use ita_sgx_runtime::{pallet_imt::PublicMessageProof, Hash};
+use itp_sgx_crypto::{key_repository::AccessKey, ShieldingCryptoEncrypt};
+use itp_sgx_externalities::SgxExternalitiesTrait;
+use itp_stf_executor::traits::StfEnclaveSigning;
+use itp_stf_state_handler::handle_state::HandleState;
+use itp_top_pool_author::traits::AuthorApi;
+use itp_types::{ShardIdentifier, H256};
+use lc_dynamic_assertion::AssertionLogicRepository;
+use lc_evm_dynamic_assertions::AssertionRepositoryItem;
+use lc_stf_task_sender::Web2IdentityVerificationRequest;
+use litentry_primitives::{ErrorDetail, ErrorString, IMPError, VerificationMethod};
+use log::*;
+use sp_core::H160;
+use std::{
+	format,
+	sync::{mpsc::Sender, Arc},
+};
+
+pub(crate) struct IdentityReverificationHandler<
+	ShieldingKeyRepository,
+	A: AuthorApi<Hash, Hash, TrustedCallSigned, Getter>,
+	S: StfEnclaveSigning<TrustedCallSigned>,
+	H: HandleState,
+	O: EnclaveOnChainOCallApi,
+	AR: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem>,
+> where
+	ShieldingKeyRepository: AccessKey,
+	<ShieldingKeyRepository as AccessKey>::KeyType: ShieldingCryptoEncrypt + 'static,
+{
+	pub(crate) req: Web2IdentityVerificationRequest,
+	pub(crate) context: Arc<StfTaskContext<ShieldingKeyRepository, A, S, H, O, AR>>,
+}
+
+impl<ShieldingKeyRepository, A, S, H, O, AR>
+	IdentityReverificationHandler<ShieldingKeyRepository, A, S, H, O, AR>
+where
+	ShieldingKeyRepository: AccessKey,
+	<ShieldingKeyRepository as AccessKey>::KeyType: ShieldingCryptoEncrypt + 'static,
+	A: AuthorApi<Hash, Hash, TrustedCallSigned, Getter>,
+	S: StfEnclaveSigning<TrustedCallSigned>,
+	H: HandleState,
+	O: EnclaveOnChainOCallApi,
+	AR: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem>,
+{
+	fn send_callback(
+		&self,
+		result: Result<VerificationMethod, ErrorDetail>,
+		sender: Sender<(ShardIdentifier, H256, TrustedCall)>,
+	) {
+		if let Ok(enclave_signer) = self.context.enclave_signer.get_enclave_account() {
+			let proof = match result {
+				Ok(_) => PublicMessageProof::new(
+					self.req.raw_msg.clone(),
+					self.req.validation_data.clone(),
+				),
+				Err(_) => None,
+			};
+			let c = TrustedCall::reverify_identity_callback(
+				enclave_signer.into(),
+				self.req.who.clone(),
+				self.req.identity.clone(),
+				result,
+				proof,
+				self.req.maybe_key,
+				self.req.req_ext_hash,
+			);
+			if let Err(e) = sender.send((self.req.shard, self.req.top_hash, c)) {
+				error!("Unable to send message to the trusted_call_receiver: {:?}", e);
+			}
+		} else {
+			error!("can't get enclave signer");
+		}
+	}
+}
+
+impl<ShieldingKeyRepository, A, S, H, O, AR> TaskHandler
+	for IdentityReverificationHandler<ShieldingKeyRepository, A, S, H, O, AR>
+where
+	ShieldingKeyRepository: AccessKey,
+	<ShieldingKeyRepository as AccessKey>::KeyType: ShieldingCryptoEncrypt + 'static,
+	A: AuthorApi<Hash, Hash, TrustedCallSigned, Getter>,
+	S: StfEnclaveSigning<TrustedCallSigned>,
+	H: HandleState,
+	H::StateT: SgxExternalitiesTrait,
+	O: EnclaveOnChainOCallApi,
+	AR: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem>,
+{
+	type Error = IMPError;
+	type Result = ();
+
+	// the web2 verifiers are shared with `link_identity`
+	fn on_process(&self) -> Result<Self::Result, Self::Error> {
+		lc_identity_verification::verify(&self.req, &self.context.data_provider_config)
+	}
+
+	fn on_success(
+		&self,
+		_result: Self::Result,
+		sender: Sender<(ShardIdentifier, H256, TrustedCall)>,
+	) {
+		debug!("reverify identity OK");
+		self.send_callback(Ok(self.req.validation_data.verification_method()), sender)
+	}
+
+	// the failure is reported back via the callback too, which deactivates the identity if the
+	// ownership is lost
+	fn on_failure(&self, error: Self::Error, sender: Sender<(ShardIdentifier, H256, TrustedCall)>) {
+		error!("reverify identity failed:{:?}", error);
+		// the verifiers always report `LinkIdentityFailed` as they are shared with linking
+		let detail = match error {
+			IMPError::LinkIdentityFailed(detail) => detail,
+			e => ErrorDetail::StfError(ErrorString::truncate_from(format!("{e:?}").into())),
+		};
+		self.send_callback(Err(detail), sender)
+	}
+}
@@ -18,7 +18,7 @@ use crate::{
 	handler::TaskHandler, EnclaveOnChainOCallApi, Getter, StfTaskContext, TrustedCall,
 	TrustedCallSigned,
 };
-use ita_sgx_runtime::Hash;
+use ita_sgx_runtime::{pallet_imt::PublicMessageProof, Hash};
 use itp_sgx_crypto::{key_repository::AccessKey, ShieldingCryptoEncrypt};
 use itp_sgx_externalities::SgxExternalitiesTrait;
 use itp_stf_executor::traits::StfEnclaveSigning;
@@ -78,6 +78,8 @@ where
 				self.req.who.clone(),
 				self.req.identity.clone(),
 				self.req.web3networks.clone(),
+				Some(self.req.validation_data.verification_method()),
+				PublicMessageProof::new(self.req.raw_msg.clone(), self.req.validation_data.clone()),
 				self.req.maybe_key,
 				self.req.req_ext_hash,
 			);
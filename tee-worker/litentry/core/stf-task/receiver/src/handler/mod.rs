@@ -15,6 +15,7 @@
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
 pub mod assertion;
+pub mod identity_reverification;
 pub mod identity_verification;
 
 use ita_stf::TrustedCall;
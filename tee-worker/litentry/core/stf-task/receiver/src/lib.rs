@@ -34,13 +34,14 @@ compile_error!("feature \"std\" and feature \"sgx\" cannot be enabled at the sam
 
 pub mod handler;
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use frame_support::sp_tracing::warn;
 use futures::{executor, executor::ThreadPoolBuilder};
 use handler::{
-	assertion::AssertionHandler, identity_verification::IdentityVerificationHandler, TaskHandler,
+	assertion::AssertionHandler, identity_reverification::IdentityReverificationHandler,
+	identity_verification::IdentityVerificationHandler, TaskHandler,
 };
-use ita_sgx_runtime::Hash;
+use ita_sgx_runtime::{pallet_imt::IdentityContext, BlockNumber, Hash, Runtime, SLOT_DURATION};
 use ita_stf::{Getter, TrustedCall, TrustedCallSigned};
 use itp_enclave_metrics::EnclaveMetric;
 use itp_ocall_api::{EnclaveMetricsOCallApi, EnclaveOnChainOCallApi};
@@ -48,22 +49,30 @@ use itp_sgx_crypto::{key_repository::AccessKey, ShieldingCryptoEncrypt};
 use itp_sgx_externalities::SgxExternalitiesTrait;
 use itp_stf_executor::traits::StfEnclaveSigning;
 use itp_stf_primitives::types::TrustedOperation;
-use itp_stf_state_handler::handle_state::HandleState;
+use itp_stf_state_handler::{handle_state::HandleState, query_shard_state::QueryShardState};
+use itp_storage::{storage_map_key, storage_value_key, StorageHasher};
+use itp_time_utils::now_as_millis;
 use itp_top_pool_author::traits::AuthorApi;
 use itp_types::{RsaRequest, ShardIdentifier, H256};
 use lc_data_providers::DataProviderConfig;
 use lc_dynamic_assertion::AssertionLogicRepository;
 use lc_evm_dynamic_assertions::AssertionRepositoryItem;
-use lc_stf_task_sender::{init_stf_task_sender_storage, RequestType};
+use lc_stf_task_sender::{
+	init_stf_task_sender_storage, RequestType, SendStfRequest, StfRequestSender,
+	Web2IdentityVerificationRequest,
+};
+use litentry_primitives::{ErrorDetail, Identity};
 use log::*;
 use sp_core::{ed25519::Pair as Ed25519Pair, H160};
 use std::{
 	boxed::Box,
+	collections::HashMap,
 	format,
 	string::{String, ToString},
 	sync::{mpsc::channel, Arc},
 	thread,
-	time::Instant,
+	time::{Duration, Instant},
+	vec::Vec,
 };
 
 #[cfg(test)]
@@ -256,6 +265,11 @@ where
 				RequestType::AssertionVerification(req) =>
 					AssertionHandler { req: req.clone(), context: context_pool.clone() }
 						.start(sender_pool),
+				RequestType::IdentityReverification(req) => IdentityReverificationHandler {
+					req: req.clone(),
+					context: context_pool.clone(),
+				}
+				.start(sender_pool),
 			}
 
 			if let Err(e) =
@@ -270,3 +284,162 @@ where
 	warn!("stf_task_receiver loop terminated");
 	Ok(())
 }
+
+// Periodically scan the IDGraphs of all shards for the active web2 identities whose last
+// verification is older than `identity_reverification_max_age`. An identity verified by a public
+// message gets the kept message checked again by the `IdentityReverificationHandler`, same as a
+// `reverify_identity` request, and `reverify_identity_callback` deactivates it if the message is
+// gone or was posted by another handle. The OAuth2 codes can't be reused, so the owner of an
+// identity verified by OAuth2 (or linked before the verification was recorded) is only notified
+// with a `VerificationExpired` error. Identities linked before the verification time was recorded
+// count as verified at their link block.
+//
+// The job keeps the time up to which each shard was scanned, so every identity is checked once
+// after it becomes stale. The time isn't persisted, the first scan after the worker starts covers
+// all stale identities, including those which became stale while the worker was down.
+//
+// It's only meant to be run when `identity_reverification_interval` is non-zero.
+pub fn run_identity_reverification_job<ShieldingKeyRepository, A, S, H, O, AR>(
+	context: Arc<StfTaskContext<ShieldingKeyRepository, A, S, H, O, AR>>,
+) where
+	ShieldingKeyRepository: AccessKey,
+	<ShieldingKeyRepository as AccessKey>::KeyType: ShieldingCryptoEncrypt + 'static,
+	A: AuthorApi<Hash, Hash, TrustedCallSigned, Getter>,
+	S: StfEnclaveSigning<TrustedCallSigned>,
+	H: HandleState + QueryShardState,
+	H::StateT: SgxExternalitiesTrait,
+	O: EnclaveOnChainOCallApi,
+	AR: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem>,
+{
+	let interval =
+		Duration::from_secs(context.data_provider_config.identity_reverification_interval);
+	let max_age = context
+		.data_provider_config
+		.identity_reverification_max_age
+		.saturating_mul(1000);
+	let request_sender = StfRequestSender::new();
+	let mut checked_until: HashMap<ShardIdentifier, u64> = HashMap::new();
+
+	loop {
+		thread::sleep(interval);
+
+		let enclave_signer = match context.enclave_signer.get_enclave_account() {
+			Ok(account) => account,
+			Err(e) => {
+				error!("can't get enclave signer: {:?}", e);
+				continue
+			},
+		};
+		let shards = match context.state_handler.list_shards() {
+			Ok(shards) => shards,
+			Err(e) => {
+				error!("failed to list shards: {:?}", e);
+				continue
+			},
+		};
+		let now = now_as_millis();
+		let stale_before = now.saturating_sub(max_age);
+
+		for shard in shards {
+			let stale_since = checked_until.get(&shard).copied().unwrap_or_default();
+			let stale = match context.state_handler.execute_on_current(&shard, |state, _| {
+				get_stale_web2_identities(state, now, stale_since, stale_before)
+			}) {
+				Ok(stale) => stale,
+				Err(e) => {
+					error!("failed to read state of shard {:?}: {:?}", shard, e);
+					continue
+				},
+			};
+			debug!("found {} stale web2 identities in shard {:?}", stale.len(), shard);
+
+			for (who, identity, identity_context) in stale {
+				match identity_context.metadata.public_message_proof {
+					Some(proof) => {
+						// there's neither a trusted operation nor a parachain extrinsic behind it
+						let request =
+							RequestType::IdentityReverification(Web2IdentityVerificationRequest {
+								shard,
+								who,
+								identity,
+								raw_msg: proof.raw_msg,
+								validation_data: proof.validation_data,
+								web3networks: identity_context.web3networks,
+								top_hash: H256::zero(),
+								maybe_key: None,
+								req_ext_hash: H256::zero(),
+							});
+						if let Err(e) = request_sender.send_stf_request(request) {
+							warn!("failed to send identity reverification request: {:?}", e);
+						}
+					},
+					None => {
+						let c = TrustedCall::reverify_identity_callback(
+							enclave_signer.clone().into(),
+							who,
+							identity,
+							Err(ErrorDetail::VerificationExpired),
+							None,
+							None,
+							H256::zero(),
+						);
+						if let Err(e) = context.submit_trusted_call(&shard, None, &c) {
+							warn!("failed to submit reverify_identity_callback: {:?}", e);
+						}
+					},
+				}
+			}
+			checked_until.insert(shard, stale_before);
+		}
+	}
+}
+
+// get the active web2 identities whose last verification falls in [`stale_since`, `stale_before`)
+// (unix ms) together with their prime identity, an identity without verification time counts as
+// verified at its link block
+fn get_stale_web2_identities<State: SgxExternalitiesTrait>(
+	state: &State,
+	now: u64,
+	stale_since: u64,
+	stale_before: u64,
+) -> Vec<(Identity, Identity, IdentityContext<Runtime>)> {
+	let block_number: BlockNumber = state
+		.get(&storage_value_key("System", "Number"))
+		.and_then(|v| BlockNumber::decode(&mut v.as_slice()).ok())
+		.unwrap_or_default();
+	let linked_at = |link_block: BlockNumber| {
+		let age = block_number.saturating_sub(link_block) as u64;
+		now.saturating_sub(age.saturating_mul(SLOT_DURATION))
+	};
+
+	// the keys of `IDGraphLens` are exactly the prime identities
+	let prime_identities: Vec<(Identity, u32)> = state
+		.iter_prefix::<Identity, u32>(&storage_value_key("IdentityManagement", "IDGraphLens"))
+		.unwrap_or_default();
+
+	prime_identities
+		.into_iter()
+		.flat_map(|(who, _)| {
+			let storage_key = storage_map_key(
+				"IdentityManagement",
+				"IDGraphs",
+				&who,
+				&StorageHasher::Blake2_128Concat,
+			);
+			state
+				.iter_prefix::<Identity, IdentityContext<Runtime>>(&storage_key)
+				.unwrap_or_default()
+				.into_iter()
+				.filter(|(identity, context)| {
+					let verified_at = context
+						.metadata
+						.verified_at
+						.unwrap_or_else(|| linked_at(context.link_block));
+					identity.is_web2()
+						&& context.is_active() && (stale_since..stale_before).contains(&verified_at)
+				})
+				.map(move |(identity, context)| (who.clone(), identity, context))
+				.collect::<Vec<_>>()
+		})
+		.collect()
+}
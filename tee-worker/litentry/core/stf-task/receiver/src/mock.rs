@@ -47,6 +47,7 @@ pub fn construct_assertion_request(assertion: Assertion) -> RequestType {
 		sidechain_runtime_version: 0u32,
 		top_hash: H256::zero(),
 		should_create_id_graph: false,
+		web2_verified_at: None,
 		req_ext_hash: H256::zero(),
 	}
 	.into();
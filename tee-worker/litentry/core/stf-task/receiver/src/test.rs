@@ -76,3 +76,90 @@ fn test_threadpool_behaviour() {
 		}
 	}
 }
+
+#[test]
+fn get_stale_web2_identities_works() {
+	use ita_sgx_runtime::pallet_imt::{DeactivationReason, PublicMessageProof};
+	use itp_sgx_externalities::SgxExternalities;
+	use itp_storage::storage_double_map_key;
+	use litentry_primitives::{
+		IdentityString, TwitterValidationData, VerificationMethod, Web2ValidationData,
+	};
+
+	let twitter = |handle: &str| Identity::Twitter(IdentityString::new(handle.as_bytes().to_vec()));
+	let who: Identity = [1u8; 32].into();
+	let now = 1_700_000_000_000u64;
+	let (stale_since, stale_before) = (now - 20 * SLOT_DURATION, now - 10 * SLOT_DURATION);
+
+	let mut state = SgxExternalities::default();
+	state.insert(storage_value_key("System", "Number"), 100u32.encode());
+	state.insert(
+		storage_map_key(
+			"IdentityManagement",
+			"IDGraphLens",
+			&who,
+			&StorageHasher::Blake2_128Concat,
+		),
+		5u32.encode(),
+	);
+	let mut insert = |identity: Identity, context: IdentityContext<Runtime>| {
+		state.insert(
+			storage_double_map_key(
+				"IdentityManagement",
+				"IDGraphs",
+				&who,
+				&StorageHasher::Blake2_128Concat,
+				&identity,
+				&StorageHasher::Blake2_128Concat,
+			),
+			context.encode(),
+		);
+	};
+	let verified = |verified_at: u64| {
+		let mut context = IdentityContext::<Runtime>::new(1, vec![]);
+		context.set_verified(VerificationMethod::OAuth2, verified_at, None);
+		context
+	};
+
+	// verified by a public message since the last scan
+	let proof = PublicMessageProof::new(
+		b"raw_msg".to_vec(),
+		Web2ValidationData::Twitter(TwitterValidationData::PublicTweet {
+			tweet_id: b"100".to_vec().try_into().unwrap(),
+		}),
+	);
+	let mut public_message = IdentityContext::<Runtime>::new(1, vec![]);
+	public_message.set_verified(
+		VerificationMethod::PublicMessage,
+		now - 15 * SLOT_DURATION,
+		proof.clone(),
+	);
+	insert(twitter("stale"), public_message);
+	// verified recently, or already checked by an earlier scan
+	insert(twitter("fresh"), verified(now - SLOT_DURATION));
+	insert(twitter("flagged"), verified(now - 30 * SLOT_DURATION));
+	// never verified, counts as verified at the link block
+	insert(twitter("linked_stale"), IdentityContext::new(85, vec![]));
+	insert(twitter("linked_fresh"), IdentityContext::new(100, vec![]));
+	// deactivated identities are left alone
+	let mut inactive = verified(now - 15 * SLOT_DURATION);
+	inactive.deactivate(DeactivationReason::OwnershipLost);
+	insert(twitter("inactive"), inactive);
+
+	let mut stale: Vec<Identity> =
+		get_stale_web2_identities(&state, now, stale_since, stale_before)
+			.into_iter()
+			.map(|(prime, identity, context)| {
+				assert_eq!(prime, who);
+				// the public message is handed over for the re-check
+				if identity == twitter("stale") {
+					assert_eq!(context.metadata.public_message_proof, proof);
+				}
+				identity
+			})
+			.collect();
+	stale.sort_by_key(|identity| identity.encode());
+	let mut expected = vec![twitter("stale"), twitter("linked_stale")];
+	expected.sort_by_key(|identity| identity.encode());
+	assert_eq!(stale, expected);
+}
@@ -77,6 +77,8 @@ pub struct AssertionBuildRequest {
 	pub sidechain_runtime_version: u32,
 	pub maybe_key: Option<RequestAesKey>,
 	pub should_create_id_graph: bool,
+	// the oldest verification time (unix ms) of the web2 identities used to build the credential
+	pub web2_verified_at: Option<u64>,
 	pub req_ext_hash: H256,
 }
 
@@ -86,6 +88,9 @@ pub enum RequestType {
 	IdentityVerification(Web2IdentityVerificationRequest),
 	#[codec(index = 1)]
 	AssertionVerification(AssertionBuildRequest),
+	// re-check the ownership of an already linked web2 identity
+	#[codec(index = 2)]
+	IdentityReverification(Web2IdentityVerificationRequest),
 }
 
 impl From<Web2IdentityVerificationRequest> for RequestType {
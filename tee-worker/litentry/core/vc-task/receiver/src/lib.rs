@@ -24,7 +24,8 @@ use codec::{Decode, Encode};
 use frame_support::{ensure, sp_runtime::traits::One};
 use futures::executor::ThreadPoolBuilder;
 use ita_sgx_runtime::{
-	pallet_imt::get_eligible_identities, BlockNumber, Hash, Runtime, VERSION as SIDECHAIN_VERSION,
+	pallet_imt::{get_eligible_identities, get_web2_verified_at},
+	BlockNumber, Hash, Runtime, VERSION as SIDECHAIN_VERSION,
 };
 
 #[cfg(feature = "development")]
@@ -529,6 +530,7 @@ where
 			assertion.skip_identity_filtering(),
 		);
 		ensure!(!identities.is_empty(), RequestVcErrorDetail::NoEligibleIdentity);
+		let web2_verified_at = get_web2_verified_at(id_graph.as_ref(), &identities);
 
 		let signer_account =
 			signer.to_account_id().ok_or(RequestVcErrorDetail::InvalidSignerAccount)?;
@@ -558,6 +560,7 @@ where
 			sidechain_runtime_version,
 			maybe_key,
 			should_create_id_graph,
+			web2_verified_at,
 			req_ext_hash,
 		};
 
@@ -17,10 +17,17 @@
 use crate::{BlockNumberOf, Config, IDGraph, Web3Network};
 use codec::{Decode, Encode};
 use core::cmp::Ordering;
-use litentry_primitives::{Identity, IdentityNetworkTuple};
+use frame_support::{traits::ConstU32, BoundedVec};
+use litentry_primitives::{
+	DiscordValidationData, Identity, IdentityNetworkTuple, TwitterValidationData,
+	VerificationMethod, Web2ValidationData,
+};
 use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 
+pub type MaxIdentityLabelLength = ConstU32<64>;
+pub type IdentityLabel = BoundedVec<u8, MaxIdentityLabelLength>;
+
 #[derive(Clone, Eq, PartialEq, Default, Debug, Encode, Decode, TypeInfo)]
 pub enum IdentityStatus {
 	#[default]
@@ -30,6 +37,55 @@ pub enum IdentityStatus {
 	Inactive,
 }
 
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub enum DeactivationReason {
+	// deactivated on the user's request
+	#[codec(index = 0)]
+	User,
+	// a re-verification found the public message missing or posted by another handle
+	#[codec(index = 1)]
+	OwnershipLost,
+	// the key of the former prime identity was replaced by a recovery
+	#[codec(index = 2)]
+	Recovery,
+}
+
+// The public message which proved the ownership of a web2 identity, it's kept so that the
+// message can be checked again later without the user's help
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct PublicMessageProof {
+	pub raw_msg: Vec<u8>,
+	pub validation_data: Web2ValidationData,
+}
+
+impl PublicMessageProof {
+	// the OAuth2 codes can only be used once, so there's nothing to keep for them
+	pub fn new(raw_msg: Vec<u8>, validation_data: Web2ValidationData) -> Option<Self> {
+		match validation_data {
+			Web2ValidationData::Twitter(TwitterValidationData::PublicTweet { .. })
+			| Web2ValidationData::Discord(DiscordValidationData::PublicMessage { .. }) =>
+				Some(Self { raw_msg, validation_data }),
+			_ => None,
+		}
+	}
+}
+
+// The optional user metadata of an identity, it's only readable by the owner of the IDGraph
+#[derive(Clone, Eq, PartialEq, Default, Debug, Encode, Decode, TypeInfo)]
+pub struct IdentityMetadata {
+	// a user-defined label, e.g. "cold wallet"
+	pub label: Option<IdentityLabel>,
+	// the unix timestamp in milliseconds at which the identity was last verified
+	pub verified_at: Option<u64>,
+	// the method used in the last verification
+	pub verification_method: Option<VerificationMethod>,
+	// the public message of the last verification, `None` if it wasn't verified by one
+	pub public_message_proof: Option<PublicMessageProof>,
+	// why the identity was deactivated, `None` if it's active or it was deactivated before the
+	// reason was recorded
+	pub deactivation_reason: Option<DeactivationReason>,
+}
+
 // The context associated with the (litentry-account, did) pair
 #[derive(Clone, Eq, PartialEq, Default, Debug, Encode, Decode, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -41,29 +97,53 @@ pub struct IdentityContext<T: Config> {
 	pub web3networks: Vec<Web3Network>,
 	// the identity status
 	pub status: IdentityStatus,
+	// the user metadata of the identity
+	pub metadata: IdentityMetadata,
 }
 
 impl<T: Config> IdentityContext<T> {
 	pub fn new(link_block: BlockNumberOf<T>, web3networks: Vec<Web3Network>) -> Self {
-		Self { link_block, web3networks: Self::dedup(web3networks), status: IdentityStatus::Active }
+		Self {
+			link_block,
+			web3networks: Self::dedup(web3networks),
+			status: IdentityStatus::Active,
+			metadata: Default::default(),
+		}
 	}
 
 	pub fn set_web3networks(&mut self, web3networks: Vec<Web3Network>) {
 		self.web3networks = Self::dedup(web3networks);
 	}
 
-	pub fn deactivate(&mut self) {
-		self.status = IdentityStatus::Inactive
+	pub fn deactivate(&mut self, reason: DeactivationReason) {
+		self.status = IdentityStatus::Inactive;
+		self.metadata.deactivation_reason = Some(reason);
 	}
 
 	pub fn activate(&mut self) {
-		self.status = IdentityStatus::Active
+		self.status = IdentityStatus::Active;
+		self.metadata.deactivation_reason = None;
 	}
 
 	pub fn is_active(&self) -> bool {
 		self.status == IdentityStatus::Active
 	}
 
+	pub fn set_label(&mut self, label: Option<IdentityLabel>) {
+		self.metadata.label = label;
+	}
+
+	pub fn set_verified(
+		&mut self,
+		method: VerificationMethod,
+		verified_at: u64,
+		proof: Option<PublicMessageProof>,
+	) {
+		self.metadata.verified_at = Some(verified_at);
+		self.metadata.verification_method = Some(method);
+		self.metadata.public_message_proof = proof;
+	}
+
 	// a small helper fn to apply mutable changes
 	fn dedup(mut web3networks: Vec<Web3Network>) -> Vec<Web3Network> {
 		web3networks.sort();
@@ -133,3 +213,23 @@ pub fn get_eligible_identities<T: Config>(
 		})
 		.collect()
 }
+
+// get the oldest verification timestamp among the web2 identities in `identities`, it's used to
+// tell how fresh the web2 ownership proofs behind a credential are
+//
+// `None` is returned if there's no web2 identity, or if any of them has never been verified
+// (e.g. it was linked before the verification metadata was introduced)
+pub fn get_web2_verified_at<T: Config>(
+	id_graph: &IDGraph<T>,
+	identities: &[IdentityNetworkTuple],
+) -> Option<u64> {
+	let mut verified_at: Option<u64> = None;
+	for (identity, _) in identities.iter().filter(|(identity, _)| identity.is_web2()) {
+		let at = id_graph
+			.iter()
+			.find(|(i, _)| i == identity)
+			.and_then(|(_, context)| context.metadata.verified_at)?;
+		verified_at = Some(verified_at.map_or(at, |v| v.min(at)));
+	}
+	verified_at
+}
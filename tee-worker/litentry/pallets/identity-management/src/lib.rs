@@ -37,13 +37,19 @@ pub mod migrations;
 pub use pallet::*;
 pub mod identity_context;
 pub use identity_context::*;
+pub mod recovery;
+pub use recovery::*;
 
-use frame_support::{pallet_prelude::*, sp_runtime::traits::One, traits::StorageVersion};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{One, Saturating},
+	traits::StorageVersion,
+};
 use frame_system::pallet_prelude::*;
 
 pub use litentry_primitives::{
 	all_bitcoin_web3networks, all_evm_web3networks, all_substrate_web3networks, Identity,
-	ParentchainBlockNumber, Web3Network,
+	ParentchainBlockNumber, VerificationMethod, Web3Network,
 };
 use sp_core::{blake2_256, H256};
 use sp_std::{vec, vec::Vec};
@@ -56,7 +62,7 @@ pub mod pallet {
 	use super::*;
 	use log::debug;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -72,6 +78,12 @@ pub mod pallet {
 		/// maximum number of identities an account can have, if you change this value to lower some accounts may exceed this limit
 		#[pallet::constant]
 		type MaxIDGraphLength: Get<u32>;
+		/// maximum number of recovery guardians an IDGraph can have
+		#[pallet::constant]
+		type MaxGuardians: Get<u32>;
+		/// minimum number of sidechain blocks the owner has to cancel a recovery
+		#[pallet::constant]
+		type MinRecoveryDelay: Get<BlockNumberOf<Self>>;
 	}
 
 	#[pallet::event]
@@ -85,6 +97,26 @@ pub mod pallet {
 		IdentityActivated { who: Identity, identity: Identity },
 		/// an identity was removed
 		IdentityRemoved { who: Identity, identity: Identity },
+		/// an identity was unlinked and can be linked to another IDGraph
+		IdentityUnlinked { who: Identity, identity: Identity },
+		/// the IDGraph of `other` was merged into the IDGraph of `who`
+		IDGraphsMerged { who: Identity, other: Identity },
+		/// a linked identity was promoted to the prime identity of the IDGraph
+		PrimeIdentityTransferred { old_prime: Identity, new_prime: Identity },
+		/// the recovery guardians of the IDGraph were set
+		RecoveryGuardiansSet { who: Identity },
+		/// a guardian initiated the recovery of the IDGraph
+		RecoveryInitiated { who: Identity, new_prime: Identity },
+		/// a guardian approved the recovery of the IDGraph
+		RecoveryApproved { who: Identity, guardian: Identity },
+		/// the owner cancelled the recovery of the IDGraph
+		RecoveryCancelled { who: Identity },
+		/// the recovery was finalized and the IDGraph was re-keyed to `new_prime`
+		PrimeIdentityRecovered { old_prime: Identity, new_prime: Identity },
+		/// the label of an identity was set or cleared
+		IdentityLabelSet { who: Identity, identity: Identity },
+		/// an identity was verified
+		IdentityVerified { who: Identity, identity: Identity, method: VerificationMethod },
 	}
 
 	#[pallet::error]
@@ -101,6 +133,38 @@ pub mod pallet {
 		WrongWeb3NetworkTypes,
 		/// identity cannot be used to build prime identity
 		NotSupportedIdentity,
+		/// unlinking the prime identity is disallowed
+		UnlinkPrimeIdentityDisallowed,
+		/// an IDGraph can't be merged with itself
+		MergeSameIDGraphDisallowed,
+		/// the IDGraph doesn't exist
+		IDGraphNotExist,
+		/// too many recovery guardians
+		TooManyGuardians,
+		/// a guardian is duplicated, is the prime identity itself or can't sign
+		InvalidGuardian,
+		/// the threshold is zero or larger than the number of guardians
+		InvalidRecoveryThreshold,
+		/// no recovery guardians are set for the IDGraph
+		RecoveryNotConfigured,
+		/// the identity is not a guardian of the IDGraph
+		NotGuardian,
+		/// a recovery of the IDGraph is already ongoing
+		RecoveryInProgress,
+		/// there's no ongoing recovery of the IDGraph
+		RecoveryNotInProgress,
+		/// the guardian has already approved the recovery
+		RecoveryAlreadyApproved,
+		/// the recovery doesn't have enough guardian approvals
+		NotEnoughApprovals,
+		/// the recovery delay hasn't elapsed yet
+		RecoveryDelayNotElapsed,
+		/// the identity label is too long
+		IdentityLabelTooLong,
+		/// the recovery delay is shorter than `MinRecoveryDelay`
+		RecoveryDelayTooShort,
+		/// the identity is deactivated
+		IdentityInactive,
 	}
 
 	#[pallet::storage]
@@ -123,6 +187,16 @@ pub mod pallet {
 	#[pallet::getter(fn id_graph_lens)]
 	pub type IDGraphLens<T: Config> = StorageMap<_, Blake2_128Concat, Identity, u32, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn recovery_configs)]
+	pub type RecoveryConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identity, RecoveryConfig<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn active_recoveries)]
+	pub type ActiveRecoveries<T: Config> =
+		StorageMap<_, Blake2_128Concat, Identity, ActiveRecovery<T>, OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(1)]
@@ -161,6 +235,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			who: Identity,
 			identity: Identity,
+			reason: DeactivationReason,
 		) -> DispatchResult {
 			T::ManageOrigin::ensure_origin(origin)?;
 			Self::maybe_create_id_graph(&who)?;
@@ -168,7 +243,7 @@ pub mod pallet {
 
 			IDGraphs::<T>::try_mutate(&who, &identity, |context| {
 				let mut c = context.take().ok_or(Error::<T>::IdentityNotExist)?;
-				c.deactivate();
+				c.deactivate(reason);
 				*context = Some(c);
 				Result::<(), Error<T>>::Ok(())
 			})?;
@@ -251,8 +326,288 @@ pub mod pallet {
 					.iter()
 					.for_each(|(identity, _context)| LinkedIdentities::<T>::remove(identity));
 				let _ = IDGraphs::<T>::clear_prefix(&who, u32::MAX, None);
+				RecoveryConfigs::<T>::remove(&who);
+				ActiveRecoveries::<T>::remove(&who);
+			}
+
+			Ok(())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight({15_000_000})]
+		pub fn unlink_identity(
+			origin: OriginFor<T>,
+			who: Identity,
+			identity: Identity,
+		) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+			ensure!(identity != who, Error::<T>::UnlinkPrimeIdentityDisallowed);
+			// the recovery might rotate the prime identity to the unlinked one
+			ensure!(!ActiveRecoveries::<T>::contains_key(&who), Error::<T>::RecoveryInProgress);
+			ensure!(IDGraphs::<T>::contains_key(&who, &identity), Error::<T>::IdentityNotExist);
+
+			IDGraphs::<T>::remove(&who, &identity);
+			LinkedIdentities::<T>::remove(&identity);
+			IDGraphLens::<T>::mutate(&who, |len| *len = len.saturating_sub(1));
+
+			Self::deposit_event(Event::IdentityUnlinked { who, identity });
+			Ok(())
+		}
+
+		// move all identities of the IDGraph of `other`, including `other` itself, to the
+		// IDGraph of `who`
+		#[pallet::call_index(7)]
+		#[pallet::weight({15_000_000})]
+		pub fn merge_id_graphs(
+			origin: OriginFor<T>,
+			who: Identity,
+			other: Identity,
+		) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+			ensure!(who != other, Error::<T>::MergeSameIDGraphDisallowed);
+			ensure!(!ActiveRecoveries::<T>::contains_key(&who), Error::<T>::RecoveryInProgress);
+			ensure!(IDGraphs::<T>::contains_key(&other, &other), Error::<T>::IDGraphNotExist);
+			ensure!(!ActiveRecoveries::<T>::contains_key(&other), Error::<T>::RecoveryInProgress);
+			Self::maybe_create_id_graph(&who)?;
+
+			let new_len = IDGraphLens::<T>::get(&who)
+				.checked_add(IDGraphLens::<T>::get(&other))
+				.ok_or(Error::<T>::IDGraphLenLimitReached)?;
+			ensure!(new_len <= T::MaxIDGraphLength::get(), Error::<T>::IDGraphLenLimitReached);
+
+			IDGraphs::<T>::drain_prefix(&other).for_each(|(identity, context)| {
+				IDGraphs::<T>::insert(&who, identity, context);
+			});
+			IDGraphLens::<T>::remove(&other);
+			IDGraphLens::<T>::insert(&who, new_len);
+			// the guardians of `other` don't protect the merged IDGraph
+			RecoveryConfigs::<T>::remove(&other);
+
+			Self::deposit_event(Event::IDGraphsMerged { who, other });
+			Ok(())
+		}
+
+		// re-key the IDGraph of `who` to `new_prime`, `who` stays in the IDGraph as a linked identity.
+		// It must be authorized by `who`, the STF only dispatches it for trusted calls signed by `who`
+		#[pallet::call_index(8)]
+		#[pallet::weight({15_000_000})]
+		pub fn transfer_prime_identity(
+			origin: OriginFor<T>,
+			who: Identity,
+			new_prime: Identity,
+		) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+			ensure!(new_prime != who, Error::<T>::LinkPrimeIdentityDisallowed);
+			ensure!(new_prime.is_web3(), Error::<T>::NotSupportedIdentity);
+			// the guardians may be rotating the prime identity because its key was stolen
+			ensure!(!ActiveRecoveries::<T>::contains_key(&who), Error::<T>::RecoveryInProgress);
+			let context =
+				IDGraphs::<T>::get(&who, &new_prime).ok_or(Error::<T>::IdentityNotExist)?;
+			ensure!(context.is_active(), Error::<T>::IdentityInactive);
+
+			Self::rekey_id_graph(&who, &new_prime);
+
+			Self::deposit_event(Event::PrimeIdentityTransferred { old_prime: who, new_prime });
+			Ok(())
+		}
+
+		// set the recovery guardians of the IDGraph of `who`, an empty list removes them
+		#[pallet::call_index(9)]
+		#[pallet::weight({15_000_000})]
+		pub fn set_recovery_guardians(
+			origin: OriginFor<T>,
+			who: Identity,
+			guardians: Vec<Identity>,
+			threshold: u32,
+			delay: BlockNumberOf<T>,
+		) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+			ensure!(!ActiveRecoveries::<T>::contains_key(&who), Error::<T>::RecoveryInProgress);
+			Self::maybe_create_id_graph(&who)?;
+
+			if guardians.is_empty() {
+				RecoveryConfigs::<T>::remove(&who);
+			} else {
+				ensure!(
+					guardians.len() <= T::MaxGuardians::get() as usize,
+					Error::<T>::TooManyGuardians
+				);
+				for (i, guardian) in guardians.iter().enumerate() {
+					ensure!(
+						guardian != &who
+							&& guardian.is_web3() && !guardians[..i].contains(guardian),
+						Error::<T>::InvalidGuardian
+					);
+				}
+				ensure!(
+					threshold > 0 && threshold as usize <= guardians.len(),
+					Error::<T>::InvalidRecoveryThreshold
+				);
+				ensure!(delay >= T::MinRecoveryDelay::get(), Error::<T>::RecoveryDelayTooShort);
+				RecoveryConfigs::<T>::insert(&who, RecoveryConfig { guardians, threshold, delay });
+			}
+
+			Self::deposit_event(Event::RecoveryGuardiansSet { who });
+			Ok(())
+		}
+
+		// start rotating the prime identity of `who` to `new_prime`, which can be a linked
+		// identity or an identity not linked to any IDGraph yet
+		#[pallet::call_index(10)]
+		#[pallet::weight({15_000_000})]
+		pub fn initiate_recovery(
+			origin: OriginFor<T>,
+			who: Identity,
+			guardian: Identity,
+			new_prime: Identity,
+		) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+			let config =
+				RecoveryConfigs::<T>::get(&who).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(config.is_guardian(&guardian), Error::<T>::NotGuardian);
+			ensure!(!ActiveRecoveries::<T>::contains_key(&who), Error::<T>::RecoveryInProgress);
+			ensure!(new_prime != who, Error::<T>::LinkPrimeIdentityDisallowed);
+			ensure!(new_prime.is_web3(), Error::<T>::NotSupportedIdentity);
+			ensure!(
+				IDGraphs::<T>::contains_key(&who, &new_prime)
+					|| !LinkedIdentities::<T>::contains_key(&new_prime),
+				Error::<T>::IdentityAlreadyLinked
+			);
+
+			ActiveRecoveries::<T>::insert(
+				&who,
+				ActiveRecovery::new(
+					new_prime.clone(),
+					<frame_system::Pallet<T>>::block_number(),
+					guardian,
+				),
+			);
+
+			Self::deposit_event(Event::RecoveryInitiated { who, new_prime });
+			Ok(())
+		}
+
+		#[pallet::call_index(11)]
+		#[pallet::weight({15_000_000})]
+		pub fn approve_recovery(
+			origin: OriginFor<T>,
+			who: Identity,
+			guardian: Identity,
+		) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+			let config =
+				RecoveryConfigs::<T>::get(&who).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(config.is_guardian(&guardian), Error::<T>::NotGuardian);
+
+			ActiveRecoveries::<T>::try_mutate(&who, |recovery| {
+				let recovery = recovery.as_mut().ok_or(Error::<T>::RecoveryNotInProgress)?;
+				ensure!(
+					!recovery.approvals.contains(&guardian),
+					Error::<T>::RecoveryAlreadyApproved
+				);
+				recovery.approvals.push(guardian.clone());
+				Result::<(), Error<T>>::Ok(())
+			})?;
+
+			Self::deposit_event(Event::RecoveryApproved { who, guardian });
+			Ok(())
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight({15_000_000})]
+		pub fn cancel_recovery(origin: OriginFor<T>, who: Identity) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+			ensure!(ActiveRecoveries::<T>::contains_key(&who), Error::<T>::RecoveryNotInProgress);
+			ActiveRecoveries::<T>::remove(&who);
+
+			Self::deposit_event(Event::RecoveryCancelled { who });
+			Ok(())
+		}
+
+		// re-key the IDGraph of `who` to the `new_prime` of the ongoing recovery, once enough
+		// guardians have approved it and the owner didn't cancel it within the delay
+		#[pallet::call_index(13)]
+		#[pallet::weight({15_000_000})]
+		pub fn finalize_recovery(origin: OriginFor<T>, who: Identity) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+			let config =
+				RecoveryConfigs::<T>::get(&who).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			let recovery =
+				ActiveRecoveries::<T>::get(&who).ok_or(Error::<T>::RecoveryNotInProgress)?;
+			ensure!(recovery.approval_count() >= config.threshold, Error::<T>::NotEnoughApprovals);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number()
+					>= recovery.initiated_at.saturating_add(config.delay),
+				Error::<T>::RecoveryDelayNotElapsed
+			);
+
+			let new_prime = recovery.new_prime;
+			if !IDGraphs::<T>::contains_key(&who, &new_prime) {
+				ensure!(
+					!LinkedIdentities::<T>::contains_key(&new_prime),
+					Error::<T>::IdentityAlreadyLinked
+				);
+				let context = <IdentityContext<T>>::new(
+					<frame_system::Pallet<T>>::block_number(),
+					new_prime.default_web3networks(),
+				);
+				Self::insert_identity_with_limit(&who, &new_prime, context)?;
 			}
+			ActiveRecoveries::<T>::remove(&who);
+			Self::rekey_id_graph(&who, &new_prime);
+			// the key of the old prime identity is presumably lost or stolen, it stays in the
+			// IDGraph so that it can't be linked elsewhere, but can't act for it any more
+			IDGraphs::<T>::mutate(&new_prime, &who, |context| {
+				if let Some(c) = context.as_mut() {
+					c.deactivate(DeactivationReason::Recovery);
+				}
+			});
+
+			Self::deposit_event(Event::PrimeIdentityRecovered { old_prime: who, new_prime });
+			Ok(())
+		}
+
+		#[pallet::call_index(14)]
+		#[pallet::weight({15_000_000})]
+		pub fn set_identity_label(
+			origin: OriginFor<T>,
+			who: Identity,
+			identity: Identity,
+			label: Option<Vec<u8>>,
+		) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+			let label = label
+				.map(IdentityLabel::try_from)
+				.transpose()
+				.map_err(|_| Error::<T>::IdentityLabelTooLong)?;
 
+			IDGraphs::<T>::try_mutate(&who, &identity, |context| {
+				let c = context.as_mut().ok_or(Error::<T>::IdentityNotExist)?;
+				c.set_label(label);
+				Result::<(), Error<T>>::Ok(())
+			})?;
+			Self::deposit_event(Event::IdentityLabelSet { who, identity });
+			Ok(())
+		}
+
+		#[pallet::call_index(15)]
+		#[pallet::weight({15_000_000})]
+		pub fn record_identity_verification(
+			origin: OriginFor<T>,
+			who: Identity,
+			identity: Identity,
+			method: VerificationMethod,
+			verified_at: u64,
+			proof: Option<PublicMessageProof>,
+		) -> DispatchResult {
+			T::ManageOrigin::ensure_origin(origin)?;
+
+			IDGraphs::<T>::try_mutate(&who, &identity, |context| {
+				let c = context.as_mut().ok_or(Error::<T>::IdentityNotExist)?;
+				c.set_verified(method, verified_at, proof);
+				Result::<(), Error<T>>::Ok(())
+			})?;
+			Self::deposit_event(Event::IdentityVerified { who, identity, method });
 			Ok(())
 		}
 	}
@@ -277,6 +632,21 @@ pub mod pallet {
 			Ok(())
 		}
 
+		// move the IDGraph of `who` to `new_prime`, the guardians stay with the IDGraph unless
+		// `new_prime` is one of them, in which case the owner has to set them again
+		fn rekey_id_graph(who: &Identity, new_prime: &Identity) {
+			IDGraphs::<T>::drain_prefix(who).for_each(|(identity, context)| {
+				IDGraphs::<T>::insert(new_prime, identity, context);
+			});
+			IDGraphLens::<T>::insert(new_prime, IDGraphLens::<T>::take(who));
+
+			if let Some(config) = RecoveryConfigs::<T>::take(who) {
+				if !config.is_guardian(new_prime) {
+					RecoveryConfigs::<T>::insert(new_prime, config);
+				}
+			}
+		}
+
 		fn insert_identity_with_limit(
 			owner: &Identity,
 			identity: &Identity,
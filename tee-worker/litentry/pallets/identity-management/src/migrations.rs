@@ -14,10 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{BlockNumberOf, IDGraphs, IdentityContext, IdentityStatus, Web3Network};
+use codec::Decode;
 use frame_support::{
-	traits::{GetStorageVersion, PalletInfoAccess, StorageVersion},
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
 	weights::Weight,
 };
+use sp_std::vec::Vec;
 
 // This is just an example of how to write a custom migration
 pub fn migrate_to_v1<T: crate::Config, P: GetStorageVersion + PalletInfoAccess>() -> Weight {
@@ -61,3 +64,32 @@ pub fn migrate_to_v2<T: crate::Config, P: GetStorageVersion + PalletInfoAccess>(
 	}
 	Weight::zero()
 }
+
+// the `IdentityContext` before the user metadata was introduced
+#[derive(Decode)]
+pub struct IdentityContextV2<T: crate::Config> {
+	pub link_block: BlockNumberOf<T>,
+	pub web3networks: Vec<Web3Network>,
+	pub status: IdentityStatus,
+}
+
+pub fn migrate_to_v3<T: crate::Config, P: GetStorageVersion + PalletInfoAccess>() -> Weight {
+	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
+	if on_chain_storage_version < 3 {
+		log::info!("Doing migrations now for IMT version {:?}", on_chain_storage_version);
+		let mut translated = 0u64;
+		IDGraphs::<T>::translate::<IdentityContextV2<T>, _>(|_, _, old| {
+			translated += 1;
+			Some(IdentityContext {
+				link_block: old.link_block,
+				web3networks: old.web3networks,
+				status: old.status,
+				metadata: Default::default(),
+			})
+		});
+		StorageVersion::new(3).put::<P>();
+		log::info!("Migrated {} identity contexts to IMT version 3", translated);
+		return T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+	Weight::zero()
+}
@@ -17,7 +17,7 @@
 use crate as pallet_tee_identity_management;
 use frame_support::{
 	ord_parameter_types, parameter_types,
-	traits::{ConstU128, ConstU16, ConstU32},
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64},
 };
 use frame_system as system;
 use frame_system::EnsureSignedBy;
@@ -101,6 +101,8 @@ impl pallet_tee_identity_management::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ManageOrigin = EnsureSignedBy<One, AccountId32>;
 	type MaxIDGraphLength = ConstU32<64>;
+	type MaxGuardians = ConstU32<8>;
+	type MinRecoveryDelay = ConstU64<5>;
 }
 
 const ALICE_KEY: &str = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
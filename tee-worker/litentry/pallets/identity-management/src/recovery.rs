@@ -0,0 +1,64 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BlockNumberOf, Config};
+use codec::{Decode, Encode};
+use litentry_primitives::Identity;
+use scale_info::TypeInfo;
+use sp_std::{vec, vec::Vec};
+
+// The guardians who are allowed to rotate the prime identity of an IDGraph, in case the
+// key of the prime identity is lost
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct RecoveryConfig<T: Config> {
+	// the guardians, either identities in the IDGraph or external ones
+	pub guardians: Vec<Identity>,
+	// the number of guardian approvals required to finalize a recovery
+	pub threshold: u32,
+	// the number of sidechain blocks the owner has to cancel a recovery
+	pub delay: BlockNumberOf<T>,
+}
+
+impl<T: Config> RecoveryConfig<T> {
+	pub fn is_guardian(&self, identity: &Identity) -> bool {
+		self.guardians.contains(identity)
+	}
+}
+
+// An ongoing recovery of an IDGraph
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ActiveRecovery<T: Config> {
+	// the identity that becomes the prime identity once the recovery is finalized
+	pub new_prime: Identity,
+	// the sidechain block number at which the recovery was initiated
+	pub initiated_at: BlockNumberOf<T>,
+	// the guardians who have approved the recovery, including the initiator
+	pub approvals: Vec<Identity>,
+}
+
+impl<T: Config> ActiveRecovery<T> {
+	pub fn new(new_prime: Identity, initiated_at: BlockNumberOf<T>, initiator: Identity) -> Self {
+		Self { new_prime, initiated_at, approvals: vec![initiator] }
+	}
+
+	pub fn approval_count(&self) -> u32 {
+		self.approvals.len() as u32
+	}
+}
@@ -15,11 +15,16 @@
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	all_evm_web3networks, get_eligible_identities, mock::*, Error, IDGraph, Identity,
-	IdentityContext, IdentityStatus, Web3Network,
+	all_evm_web3networks, get_eligible_identities, get_web2_verified_at, mock::*,
+	DeactivationReason, Error, IDGraph, Identity, IdentityContext, IdentityLabel, IdentityMetadata,
+	IdentityStatus, PublicMessageProof, VerificationMethod, Web3Network,
 };
-use frame_support::{assert_err, assert_noop, assert_ok, traits::Get};
-use litentry_primitives::all_substrate_web3networks;
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{Get, GetStorageVersion, StorageVersion},
+};
+use litentry_primitives::{all_substrate_web3networks, TwitterValidationData, Web2ValidationData};
 use sp_runtime::AccountId32;
 pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
 pub const BOB: AccountId32 = AccountId32::new([2u8; 32]);
@@ -62,6 +67,32 @@ fn get_eligible_identities_works() {
 	assert_eq!(identities[2].1, vec![]);
 }
 
+#[test]
+fn get_web2_verified_at_works() {
+	let mut id_graph = IDGraph::<Test>::default();
+	id_graph.push((alice_substrate_identity(), IdentityContext::new(1u64, vec![])));
+	id_graph.push((alice_twitter_identity(1), IdentityContext::new(2u64, vec![])));
+	id_graph.push((alice_twitter_identity(2), IdentityContext::new(3u64, vec![])));
+
+	let identities = get_eligible_identities(id_graph.as_ref(), vec![], true);
+
+	// no web2 identity was verified yet
+	assert_eq!(get_web2_verified_at(id_graph.as_ref(), &identities), None);
+
+	// one of the web2 identities is still unverified
+	id_graph[1].1.set_verified(VerificationMethod::PublicMessage, 2000, None);
+	assert_eq!(get_web2_verified_at(id_graph.as_ref(), &identities), None);
+
+	// the oldest verification wins
+	id_graph[2].1.set_verified(VerificationMethod::OAuth2, 1000, None);
+	assert_eq!(get_web2_verified_at(id_graph.as_ref(), &identities), Some(1000));
+
+	// web3 identities are not taken into account
+	let identities =
+		get_eligible_identities(id_graph.as_ref(), all_substrate_web3networks(), false);
+	assert_eq!(get_web2_verified_at(id_graph.as_ref(), &identities), None);
+}
+
 #[test]
 fn link_twitter_identity_works() {
 	new_test_ext().execute_with(|| {
@@ -75,7 +106,12 @@ fn link_twitter_identity_works() {
 		));
 		assert_eq!(
 			IMT::id_graphs(who.clone(), alice_twitter_identity(1)).unwrap(),
-			IdentityContext { link_block: 1, web3networks: vec![], status: IdentityStatus::Active }
+			IdentityContext {
+				link_block: 1,
+				web3networks: vec![],
+				status: IdentityStatus::Active,
+				metadata: Default::default(),
+			}
 		);
 		assert_eq!(crate::IDGraphLens::<Test>::get(&who), 2);
 	});
@@ -94,7 +130,12 @@ fn link_substrate_identity_works() {
 		));
 		assert_eq!(
 			IMT::id_graphs(who.clone(), alice_substrate_identity()).unwrap(),
-			IdentityContext { link_block: 1, web3networks, status: IdentityStatus::Active }
+			IdentityContext {
+				link_block: 1,
+				web3networks,
+				status: IdentityStatus::Active,
+				metadata: Default::default(),
+			}
 		);
 		assert_eq!(crate::IDGraphLens::<Test>::get(&who), 2);
 	});
@@ -113,7 +154,12 @@ fn link_evm_identity_works() {
 		));
 		assert_eq!(
 			IMT::id_graphs(who.clone(), alice_evm_identity()).unwrap(),
-			IdentityContext { link_block: 1, web3networks, status: IdentityStatus::Active }
+			IdentityContext {
+				link_block: 1,
+				web3networks,
+				status: IdentityStatus::Active,
+				metadata: Default::default(),
+			}
 		);
 		assert_eq!(crate::IDGraphLens::<Test>::get(&who), 2);
 	});
@@ -155,7 +201,8 @@ fn link_identity_fails_for_linked_identity() {
 			IdentityContext {
 				link_block: 1,
 				web3networks: web3networks.clone(),
-				status: IdentityStatus::Active
+				status: IdentityStatus::Active,
+				metadata: Default::default(),
 			}
 		);
 		assert_eq!(crate::IDGraphLens::<Test>::get(&bob), 2);
@@ -204,6 +251,7 @@ fn cannot_link_identity_again() {
 			IdentityContext {
 				link_block: 1,
 				status: IdentityStatus::Active,
+				metadata: Default::default(),
 				web3networks: web3networks.clone()
 			}
 		);
@@ -261,6 +309,7 @@ fn deactivate_identity_works() {
 				RuntimeOrigin::signed(ALICE),
 				who.clone(),
 				alice_substrate_identity(),
+				DeactivationReason::User,
 			),
 			Error::<Test>::IdentityNotExist
 		);
@@ -275,7 +324,8 @@ fn deactivate_identity_works() {
 			IdentityContext {
 				link_block: 1,
 				web3networks: vec![Web3Network::Litentry].try_into().unwrap(),
-				status: IdentityStatus::Active
+				status: IdentityStatus::Active,
+				metadata: Default::default(),
 			}
 		);
 
@@ -287,13 +337,18 @@ fn deactivate_identity_works() {
 			RuntimeOrigin::signed(ALICE),
 			who.clone(),
 			alice_substrate_identity(),
+			DeactivationReason::User,
 		));
 		assert_eq!(
 			IMT::id_graphs(who.clone(), alice_substrate_identity()).unwrap(),
 			IdentityContext {
 				link_block: 1,
 				web3networks: vec![Web3Network::Litentry].try_into().unwrap(),
-				status: IdentityStatus::Inactive
+				status: IdentityStatus::Inactive,
+				metadata: IdentityMetadata {
+					deactivation_reason: Some(DeactivationReason::User),
+					..Default::default()
+				},
 			}
 		);
 
@@ -311,6 +366,7 @@ fn deactivate_identity_works() {
 			RuntimeOrigin::signed(ALICE),
 			who.clone(),
 			bob_substrate_identity(),
+			DeactivationReason::User,
 		));
 	});
 }
@@ -331,7 +387,8 @@ fn activate_identity_works() {
 			IdentityContext {
 				link_block: 1,
 				web3networks: vec![Web3Network::Litentry].try_into().unwrap(),
-				status: IdentityStatus::Active
+				status: IdentityStatus::Active,
+				metadata: Default::default(),
 			}
 		);
 		let id_graph = IMT::id_graph(&who.clone());
@@ -342,13 +399,18 @@ fn activate_identity_works() {
 			RuntimeOrigin::signed(ALICE),
 			who.clone(),
 			alice_substrate_identity(),
+			DeactivationReason::User,
 		));
 		assert_eq!(
 			IMT::id_graphs(who.clone(), alice_substrate_identity()).unwrap(),
 			IdentityContext {
 				link_block: 1,
 				web3networks: vec![Web3Network::Litentry].try_into().unwrap(),
-				status: IdentityStatus::Inactive
+				status: IdentityStatus::Inactive,
+				metadata: IdentityMetadata {
+					deactivation_reason: Some(DeactivationReason::User),
+					..Default::default()
+				},
 			}
 		);
 		let id_graph = IMT::id_graph(&who.clone())
@@ -365,6 +427,11 @@ fn activate_identity_works() {
 			who.clone(),
 			alice_substrate_identity(),
 		));
+		// the deactivation reason is cleared
+		assert_eq!(
+			IMT::id_graphs(who.clone(), alice_substrate_identity()).unwrap().metadata,
+			IdentityMetadata::default()
+		);
 
 		let id_graph = IMT::id_graph(&who.clone());
 		assert_eq!(id_graph.len(), 2);
@@ -388,7 +455,8 @@ fn set_identity_networks_works() {
 			IdentityContext {
 				link_block: 1,
 				web3networks: vec![Web3Network::Litentry].try_into().unwrap(),
-				status: IdentityStatus::Active
+				status: IdentityStatus::Active,
+				metadata: Default::default(),
 			}
 		);
 
@@ -422,7 +490,8 @@ fn set_identity_networks_with_wrong_network_fails() {
 			IdentityContext {
 				link_block: 1,
 				web3networks: vec![Web3Network::Litentry].try_into().unwrap(),
-				status: IdentityStatus::Active
+				status: IdentityStatus::Active,
+				metadata: Default::default(),
 			}
 		);
 
@@ -641,3 +710,784 @@ fn remove_identity_graph_of_other_account_fails() {
 		);
 	});
 }
+
+#[test]
+fn unlink_identity_works() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let bob: Identity = BOB.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			bob_substrate_identity(),
+			vec![Web3Network::Litentry].try_into().unwrap(),
+		));
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			vec![],
+		));
+		assert_eq!(IMT::id_graph_lens(&alice), 3);
+		let id_graph_hash = IMT::id_graph_hash(&alice);
+
+		assert_ok!(IMT::unlink_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			bob_substrate_identity(),
+		));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::IdentityUnlinked {
+			who: alice.clone(),
+			identity: bob_substrate_identity(),
+		}));
+
+		let id_graph = IMT::id_graph(&alice);
+		assert_eq!(id_graph.len(), 2);
+		assert!(!id_graph.iter().any(|(identity, _)| identity == &bob_substrate_identity()));
+		assert_eq!(IMT::id_graph_lens(&alice), 2);
+		assert_ne!(IMT::id_graph_hash(&alice), id_graph_hash);
+
+		// the unlinked identity can be linked to another IDGraph
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			bob.clone(),
+			bob_substrate_identity(),
+			vec![Web3Network::Litentry].try_into().unwrap(),
+		));
+		assert_eq!(IMT::id_graph(&bob).len(), 2);
+	});
+}
+
+#[test]
+fn unlink_prime_identity_fails() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			vec![],
+		));
+
+		assert_noop!(
+			IMT::unlink_identity(RuntimeOrigin::signed(ALICE), alice.clone(), alice.clone()),
+			Error::<Test>::UnlinkPrimeIdentityDisallowed
+		);
+	});
+}
+
+#[test]
+fn unlink_identity_of_other_id_graph_fails() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let bob: Identity = BOB.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			vec![],
+		));
+
+		assert_noop!(
+			IMT::unlink_identity(RuntimeOrigin::signed(ALICE), bob, alice_twitter_identity(1)),
+			Error::<Test>::IdentityNotExist
+		);
+	});
+}
+
+#[test]
+fn merge_id_graphs_works() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let bob: Identity = BOB.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			vec![],
+		));
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			bob.clone(),
+			alice_evm_identity(),
+			vec![Web3Network::Ethereum],
+		));
+
+		assert_ok!(IMT::merge_id_graphs(RuntimeOrigin::signed(ALICE), alice.clone(), bob.clone()));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::IDGraphsMerged {
+			who: alice.clone(),
+			other: bob.clone(),
+		}));
+
+		let id_graph = IMT::id_graph(&alice);
+		assert_eq!(id_graph.len(), 4);
+		assert!(id_graph.iter().any(|(identity, _)| identity == &bob));
+		assert!(id_graph.iter().any(|(identity, _)| identity == &alice_evm_identity()));
+		assert_eq!(IMT::id_graph_lens(&alice), 4);
+		assert!(IMT::id_graph(&bob).is_empty());
+		assert_eq!(IMT::id_graph_lens(&bob), 0);
+	});
+}
+
+#[test]
+fn merge_id_graphs_respects_max_id_graph_length() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let bob: Identity = BOB.into();
+		let max_len: u32 = <Test as crate::Config>::MaxIDGraphLength::get();
+		for i in 1..max_len {
+			assert_ok!(IMT::link_identity(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				alice_twitter_identity(i),
+				vec![],
+			));
+		}
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			bob.clone(),
+			alice_evm_identity(),
+			vec![Web3Network::Ethereum],
+		));
+
+		assert_noop!(
+			IMT::merge_id_graphs(RuntimeOrigin::signed(ALICE), alice, bob),
+			Error::<Test>::IDGraphLenLimitReached
+		);
+	});
+}
+
+#[test]
+fn merge_id_graphs_fails_for_same_or_missing_id_graph() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let bob: Identity = BOB.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			vec![],
+		));
+
+		assert_noop!(
+			IMT::merge_id_graphs(RuntimeOrigin::signed(ALICE), alice.clone(), alice.clone()),
+			Error::<Test>::MergeSameIDGraphDisallowed
+		);
+		assert_noop!(
+			IMT::merge_id_graphs(RuntimeOrigin::signed(ALICE), alice, bob),
+			Error::<Test>::IDGraphNotExist
+		);
+	});
+}
+
+#[test]
+fn transfer_prime_identity_works() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+			vec![Web3Network::Ethereum],
+		));
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			vec![],
+		));
+
+		assert_ok!(IMT::transfer_prime_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+		));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::PrimeIdentityTransferred {
+			old_prime: alice.clone(),
+			new_prime: alice_evm_identity(),
+		}));
+
+		let id_graph = IMT::id_graph(&alice_evm_identity());
+		assert_eq!(id_graph.len(), 3);
+		assert!(id_graph.iter().any(|(identity, _)| identity == &alice));
+		assert_eq!(IMT::id_graph_lens(&alice_evm_identity()), 3);
+		assert!(IMT::id_graph(&alice).is_empty());
+		assert_eq!(IMT::id_graph_lens(&alice), 0);
+
+		// the old prime identity is still linked
+		assert_noop!(
+			IMT::link_identity(
+				RuntimeOrigin::signed(ALICE),
+				BOB.into(),
+				alice.clone(),
+				all_substrate_web3networks(),
+			),
+			Error::<Test>::IdentityAlreadyLinked
+		);
+	});
+}
+
+#[test]
+fn transfer_prime_identity_to_web2_identity_fails() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			vec![],
+		));
+
+		assert_noop!(
+			IMT::transfer_prime_identity(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				alice_twitter_identity(1),
+			),
+			Error::<Test>::NotSupportedIdentity
+		);
+		assert_noop!(
+			IMT::transfer_prime_identity(RuntimeOrigin::signed(ALICE), alice, alice_evm_identity()),
+			Error::<Test>::IdentityNotExist
+		);
+	});
+}
+
+#[test]
+fn set_recovery_guardians_works() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		assert_ok!(IMT::set_recovery_guardians(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			vec![BOB.into(), CHARLIE.into()],
+			2,
+			10,
+		));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::RecoveryGuardiansSet {
+			who: alice.clone(),
+		}));
+		let config = IMT::recovery_configs(&alice).unwrap();
+		assert_eq!(config.guardians, vec![BOB.into(), CHARLIE.into()]);
+		assert_eq!(config.threshold, 2);
+		assert_eq!(config.delay, 10);
+		// the IDGraph is created if there's none
+		assert_eq!(IMT::id_graph_lens(&alice), 1);
+
+		// an empty list removes the guardians
+		assert_ok!(IMT::set_recovery_guardians(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			vec![],
+			0,
+			0,
+		));
+		assert!(IMT::recovery_configs(&alice).is_none());
+	});
+}
+
+#[test]
+fn set_recovery_guardians_checks_work() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let max_guardians = <Test as crate::Config>::MaxGuardians::get();
+		let too_many = (0..=max_guardians)
+			.map(|i| AccountId32::new([10 + i as u8; 32]).into())
+			.collect();
+		assert_noop!(
+			IMT::set_recovery_guardians(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				too_many,
+				1,
+				0
+			),
+			Error::<Test>::TooManyGuardians
+		);
+		assert_noop!(
+			IMT::set_recovery_guardians(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				vec![BOB.into(), BOB.into()],
+				1,
+				0,
+			),
+			Error::<Test>::InvalidGuardian
+		);
+		assert_noop!(
+			IMT::set_recovery_guardians(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				vec![alice.clone()],
+				1,
+				0,
+			),
+			Error::<Test>::InvalidGuardian
+		);
+		assert_noop!(
+			IMT::set_recovery_guardians(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				vec![alice_twitter_identity(1)],
+				1,
+				0,
+			),
+			Error::<Test>::InvalidGuardian
+		);
+		assert_noop!(
+			IMT::set_recovery_guardians(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				vec![BOB.into()],
+				2,
+				0,
+			),
+			Error::<Test>::InvalidRecoveryThreshold
+		);
+		assert_noop!(
+			IMT::set_recovery_guardians(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				vec![BOB.into()],
+				0,
+				0,
+			),
+			Error::<Test>::InvalidRecoveryThreshold
+		);
+		let min_delay = <Test as crate::Config>::MinRecoveryDelay::get();
+		assert_noop!(
+			IMT::set_recovery_guardians(
+				RuntimeOrigin::signed(ALICE),
+				alice,
+				vec![BOB.into()],
+				1,
+				min_delay - 1,
+			),
+			Error::<Test>::RecoveryDelayTooShort
+		);
+	});
+}
+
+#[test]
+fn recovery_works() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let new_prime: Identity = AccountId32::new([4u8; 32]).into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+			vec![Web3Network::Ethereum],
+		));
+		assert_ok!(IMT::set_recovery_guardians(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			vec![BOB.into(), CHARLIE.into(), alice_evm_identity()],
+			2,
+			10,
+		));
+
+		assert_ok!(IMT::initiate_recovery(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			BOB.into(),
+			new_prime.clone(),
+		));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::RecoveryInitiated {
+			who: alice.clone(),
+			new_prime: new_prime.clone(),
+		}));
+		assert_noop!(
+			IMT::finalize_recovery(RuntimeOrigin::signed(ALICE), alice.clone()),
+			Error::<Test>::NotEnoughApprovals
+		);
+		assert_noop!(
+			IMT::approve_recovery(RuntimeOrigin::signed(ALICE), alice.clone(), BOB.into()),
+			Error::<Test>::RecoveryAlreadyApproved
+		);
+
+		assert_ok!(IMT::approve_recovery(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+		));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::RecoveryApproved {
+			who: alice.clone(),
+			guardian: alice_evm_identity(),
+		}));
+		assert_noop!(
+			IMT::finalize_recovery(RuntimeOrigin::signed(ALICE), alice.clone()),
+			Error::<Test>::RecoveryDelayNotElapsed
+		);
+
+		System::set_block_number(11);
+		assert_ok!(IMT::finalize_recovery(RuntimeOrigin::signed(ALICE), alice.clone()));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::PrimeIdentityRecovered {
+			old_prime: alice.clone(),
+			new_prime: new_prime.clone(),
+		}));
+
+		let id_graph = IMT::id_graph(&new_prime);
+		assert_eq!(id_graph.len(), 3);
+		assert!(id_graph.iter().any(|(identity, _)| identity == &alice));
+		assert!(IMT::id_graph(&alice).is_empty());
+		assert!(IMT::active_recoveries(&alice).is_none());
+		// the old prime identity is deactivated
+		assert!(!IMT::id_graphs(&new_prime, &alice).unwrap().is_active());
+		// the guardians stay with the IDGraph
+		assert!(IMT::recovery_configs(&alice).is_none());
+		assert_eq!(IMT::recovery_configs(&new_prime).unwrap().threshold, 2);
+	});
+}
+
+#[test]
+fn old_prime_identity_cannot_take_over_recovered_id_graph() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let new_prime: Identity = AccountId32::new([4u8; 32]).into();
+		assert_ok!(IMT::set_recovery_guardians(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			vec![BOB.into()],
+			1,
+			10,
+		));
+		assert_ok!(IMT::initiate_recovery(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			BOB.into(),
+			new_prime.clone(),
+		));
+		System::set_block_number(11);
+		assert_ok!(IMT::finalize_recovery(RuntimeOrigin::signed(ALICE), alice.clone()));
+
+		assert_noop!(
+			IMT::transfer_prime_identity(RuntimeOrigin::signed(ALICE), new_prime.clone(), alice),
+			Error::<Test>::IdentityInactive
+		);
+		assert_eq!(IMT::id_graph_lens(&new_prime), 2);
+	});
+}
+
+#[test]
+fn recovery_cannot_be_aborted_by_transfer_or_merge() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let new_prime: Identity = AccountId32::new([4u8; 32]).into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+			vec![Web3Network::Ethereum],
+		));
+		assert_ok!(IMT::set_recovery_guardians(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			vec![BOB.into()],
+			1,
+			10,
+		));
+		assert_ok!(IMT::initiate_recovery(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			BOB.into(),
+			new_prime.clone(),
+		));
+
+		assert_noop!(
+			IMT::transfer_prime_identity(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				alice_evm_identity(),
+			),
+			Error::<Test>::RecoveryInProgress
+		);
+		assert_noop!(
+			IMT::merge_id_graphs(RuntimeOrigin::signed(ALICE), BOB.into(), alice.clone()),
+			Error::<Test>::RecoveryInProgress
+		);
+
+		System::set_block_number(11);
+		assert_ok!(IMT::finalize_recovery(RuntimeOrigin::signed(ALICE), alice.clone()));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::PrimeIdentityRecovered {
+			old_prime: alice,
+			new_prime,
+		}));
+	});
+}
+
+#[test]
+fn id_graph_in_recovery_cannot_be_unlinked_from_or_merged_into() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let bob: Identity = BOB.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+			vec![Web3Network::Ethereum],
+		));
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			bob.clone(),
+			alice_twitter_identity(1),
+			vec![],
+		));
+		assert_ok!(IMT::set_recovery_guardians(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			vec![BOB.into()],
+			1,
+			10,
+		));
+		assert_ok!(IMT::initiate_recovery(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			BOB.into(),
+			alice_evm_identity(),
+		));
+
+		assert_noop!(
+			IMT::unlink_identity(RuntimeOrigin::signed(ALICE), alice.clone(), alice_evm_identity()),
+			Error::<Test>::RecoveryInProgress
+		);
+		assert_noop!(
+			IMT::merge_id_graphs(RuntimeOrigin::signed(ALICE), alice.clone(), bob.clone()),
+			Error::<Test>::RecoveryInProgress
+		);
+
+		assert_ok!(IMT::cancel_recovery(RuntimeOrigin::signed(ALICE), alice.clone()));
+		assert_ok!(IMT::unlink_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity()
+		));
+		assert_ok!(IMT::merge_id_graphs(RuntimeOrigin::signed(ALICE), alice, bob));
+	});
+}
+
+#[test]
+fn recovery_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		assert_ok!(IMT::set_recovery_guardians(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			vec![BOB.into()],
+			1,
+			10,
+		));
+		assert_noop!(
+			IMT::initiate_recovery(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				CHARLIE.into(),
+				CHARLIE.into(),
+			),
+			Error::<Test>::NotGuardian
+		);
+		assert_ok!(IMT::initiate_recovery(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			BOB.into(),
+			BOB.into(),
+		));
+		assert_noop!(
+			IMT::initiate_recovery(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				BOB.into(),
+				BOB.into(),
+			),
+			Error::<Test>::RecoveryInProgress
+		);
+
+		assert_ok!(IMT::cancel_recovery(RuntimeOrigin::signed(ALICE), alice.clone()));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::RecoveryCancelled {
+			who: alice.clone(),
+		}));
+
+		System::set_block_number(11);
+		assert_noop!(
+			IMT::finalize_recovery(RuntimeOrigin::signed(ALICE), alice.clone()),
+			Error::<Test>::RecoveryNotInProgress
+		);
+		assert_eq!(IMT::id_graph_lens(&alice), 1);
+	});
+}
+
+#[test]
+fn initiate_recovery_to_identity_of_other_id_graph_fails() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		let bob: Identity = BOB.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			bob,
+			alice_evm_identity(),
+			vec![Web3Network::Ethereum],
+		));
+		assert_ok!(IMT::set_recovery_guardians(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			vec![CHARLIE.into()],
+			1,
+			10,
+		));
+		assert_noop!(
+			IMT::initiate_recovery(
+				RuntimeOrigin::signed(ALICE),
+				alice,
+				CHARLIE.into(),
+				alice_evm_identity(),
+			),
+			Error::<Test>::IdentityAlreadyLinked
+		);
+	});
+}
+
+#[test]
+fn set_identity_label_works() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+			vec![Web3Network::Ethereum],
+		));
+		assert_ok!(IMT::set_identity_label(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+			Some(b"cold wallet".to_vec()),
+		));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::IdentityLabelSet {
+			who: alice.clone(),
+			identity: alice_evm_identity(),
+		}));
+		assert_eq!(
+			IMT::id_graphs(&alice, alice_evm_identity()).unwrap().metadata.label,
+			Some(IdentityLabel::truncate_from(b"cold wallet".to_vec()))
+		);
+
+		assert_ok!(IMT::set_identity_label(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+			None,
+		));
+		assert_eq!(IMT::id_graphs(&alice, alice_evm_identity()).unwrap().metadata.label, None);
+	});
+}
+
+#[test]
+fn set_identity_label_fails() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_evm_identity(),
+			vec![Web3Network::Ethereum],
+		));
+		assert_noop!(
+			IMT::set_identity_label(
+				RuntimeOrigin::signed(ALICE),
+				alice.clone(),
+				alice_evm_identity(),
+				Some(vec![0u8; 65]),
+			),
+			Error::<Test>::IdentityLabelTooLong
+		);
+		assert_noop!(
+			IMT::set_identity_label(
+				RuntimeOrigin::signed(ALICE),
+				alice,
+				bob_substrate_identity(),
+				Some(b"hot wallet".to_vec()),
+			),
+			Error::<Test>::IdentityNotExist
+		);
+	});
+}
+
+#[test]
+fn record_identity_verification_works() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		assert_ok!(IMT::link_identity(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			vec![],
+		));
+		assert_eq!(
+			IMT::id_graphs(&alice, alice_twitter_identity(1)).unwrap().metadata,
+			IdentityMetadata::default()
+		);
+
+		assert_ok!(IMT::record_identity_verification(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			VerificationMethod::OAuth2,
+			1_700_000_000_000,
+			None,
+		));
+		System::assert_last_event(RuntimeEvent::IMT(crate::Event::IdentityVerified {
+			who: alice.clone(),
+			identity: alice_twitter_identity(1),
+			method: VerificationMethod::OAuth2,
+		}));
+		let metadata = IMT::id_graphs(&alice, alice_twitter_identity(1)).unwrap().metadata;
+		assert_eq!(metadata.verified_at, Some(1_700_000_000_000));
+		assert_eq!(metadata.verification_method, Some(VerificationMethod::OAuth2));
+		assert_eq!(metadata.public_message_proof, None);
+
+		// the public message of the verification is kept
+		let proof = PublicMessageProof::new(
+			b"raw_msg".to_vec(),
+			Web2ValidationData::Twitter(TwitterValidationData::PublicTweet {
+				tweet_id: b"100".to_vec().try_into().unwrap(),
+			}),
+		);
+		assert!(proof.is_some());
+		assert_ok!(IMT::record_identity_verification(
+			RuntimeOrigin::signed(ALICE),
+			alice.clone(),
+			alice_twitter_identity(1),
+			VerificationMethod::PublicMessage,
+			1_700_000_001_000,
+			proof.clone(),
+		));
+		let metadata = IMT::id_graphs(&alice, alice_twitter_identity(1)).unwrap().metadata;
+		assert_eq!(metadata.verified_at, Some(1_700_000_001_000));
+		assert_eq!(metadata.public_message_proof, proof);
+	});
+}
+
+#[test]
+fn migrate_to_v3_works() {
+	new_test_ext().execute_with(|| {
+		let alice: Identity = ALICE.into();
+		StorageVersion::new(2).put::<IMT>();
+		unhashed::put(
+			&crate::IDGraphs::<Test>::hashed_key_for(&alice, alice_evm_identity()),
+			&(5u64, vec![Web3Network::Ethereum], IdentityStatus::Inactive),
+		);
+
+		crate::migrations::migrate_to_v3::<Test, IMT>();
+
+		assert_eq!(IMT::on_chain_storage_version(), 3);
+		assert_eq!(
+			IMT::id_graphs(&alice, alice_evm_identity()).unwrap(),
+			IdentityContext {
+				link_block: 5,
+				web3networks: vec![Web3Network::Ethereum],
+				status: IdentityStatus::Inactive,
+				metadata: Default::default(),
+			}
+		);
+	});
+}
@@ -44,7 +44,8 @@ use itp_sgx_crypto::ShieldingCryptoDecrypt;
 use log::error;
 pub use pallet_teebag::{
 	decl_rsa_request, extract_tcb_info_from_raw_dcap_quote, AttestationType, DcapProvider, Enclave,
-	EnclaveFingerprint, MrEnclave, ShardIdentifier, SidechainBlockNumber, WorkerMode, WorkerType,
+	EnclaveFingerprint, EvictionReason, MrEnclave, ShardIdentifier, SidechainBlockNumber,
+	WorkerMode, WorkerType,
 };
 pub use parentchain_primitives::{
 	assertion::{
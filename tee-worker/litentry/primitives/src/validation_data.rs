@@ -105,3 +105,39 @@ pub enum ValidationData {
 	#[codec(index = 1)]
 	Web3(Web3ValidationData),
 }
+
+impl ValidationData {
+	pub fn verification_method(&self) -> VerificationMethod {
+		match self {
+			Self::Web2(data) => data.verification_method(),
+			Self::Web3(_) => VerificationMethod::Web3Signature,
+		}
+	}
+}
+
+impl Web2ValidationData {
+	pub fn verification_method(&self) -> VerificationMethod {
+		match self {
+			Self::Twitter(TwitterValidationData::PublicTweet { .. })
+			| Self::Discord(DiscordValidationData::PublicMessage { .. }) =>
+				VerificationMethod::PublicMessage,
+			Self::Twitter(TwitterValidationData::OAuth2 { .. })
+			| Self::Discord(DiscordValidationData::OAuth2 { .. }) => VerificationMethod::OAuth2,
+		}
+	}
+}
+
+// how an identity was verified when it was linked to the IDGraph
+#[derive(Encode, Decode, Copy, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum VerificationMethod {
+	// a signature of the expected message by the web3 identity
+	#[codec(index = 0)]
+	Web3Signature,
+	// a public tweet or message posted by the web2 identity
+	#[codec(index = 1)]
+	PublicMessage,
+	// an OAuth2 authorization granted by the web2 identity
+	#[codec(index = 2)]
+	OAuth2,
+}
@@ -34,6 +34,7 @@ static DEFAULT_MU_RA_PORT: &str = "3443";
 static DEFAULT_METRICS_PORT: &str = "8787";
 static DEFAULT_UNTRUSTED_HTTP_PORT: &str = "4545";
 static DEFAULT_MOCK_SERVER_PORT: &str = "19527";
+static DEFAULT_OIDC_PORT: &str = "4646";
 static DEFAULT_PARENTCHAIN_START_BLOCK: &str = "0";
 static DEFAULT_FAIL_AT: &str = "0";
 
@@ -64,6 +65,10 @@ pub struct Config {
 	pub metrics_server_port: String,
 	/// Port for the untrusted HTTP server (e.g. for `is_initialized`)
 	pub untrusted_http_port: String,
+	/// Public URL of the OIDC provider, the OIDC proxy is only started if it's set
+	pub oidc_issuer_url: Option<String>,
+	/// Port for the OIDC proxy
+	pub oidc_port: String,
 	/// Data directory used by all the services.
 	pub data_dir: PathBuf,
 	/// Config of the 'run' subcommand
@@ -100,6 +105,8 @@ impl Config {
 		enable_metrics_server: bool,
 		metrics_server_port: String,
 		untrusted_http_port: String,
+		oidc_issuer_url: Option<String>,
+		oidc_port: String,
 		data_dir: PathBuf,
 		run_config: Option<RunConfig>,
 		enable_mock_server: bool,
@@ -125,6 +132,8 @@ impl Config {
 			enable_metrics_server,
 			metrics_server_port,
 			untrusted_http_port,
+			oidc_issuer_url,
+			oidc_port,
 			data_dir,
 			run_config,
 			enable_mock_server,
@@ -230,6 +239,14 @@ impl Config {
 		self.untrusted_http_port.parse::<u16>().ok()
 	}
 
+	pub fn oidc_issuer_url(&self) -> Option<&str> {
+		self.oidc_issuer_url.as_deref()
+	}
+
+	pub fn try_parse_oidc_port(&self) -> Option<u16> {
+		self.oidc_port.parse::<u16>().ok()
+	}
+
 	pub fn try_parse_mock_server_port(&self) -> Option<u16> {
 		self.mock_server_port.parse::<u16>().ok()
 	}
@@ -248,6 +265,7 @@ impl From<&ArgMatches<'_>> for Config {
 		let metrics_server_port = m.value_of("metrics-port").unwrap_or(DEFAULT_METRICS_PORT);
 		let untrusted_http_port =
 			m.value_of("untrusted-http-port").unwrap_or(DEFAULT_UNTRUSTED_HTTP_PORT);
+		let oidc_port = m.value_of("oidc-port").unwrap_or(DEFAULT_OIDC_PORT);
 
 		let data_dir = match m.value_of("data-dir") {
 			Some(d) => {
@@ -295,6 +313,8 @@ impl From<&ArgMatches<'_>> for Config {
 			is_metrics_server_enabled,
 			metrics_server_port.to_string(),
 			untrusted_http_port.to_string(),
+			m.value_of("oidc-issuer-url").map(Into::into),
+			oidc_port.to_string(),
 			data_dir,
 			run_config,
 			is_mock_server_enabled,
@@ -428,6 +448,8 @@ mod test {
 		assert!(config.mu_ra_external_address.is_none());
 		assert!(!config.enable_metrics_server);
 		assert_eq!(config.untrusted_http_port, DEFAULT_UNTRUSTED_HTTP_PORT);
+		assert_eq!(config.oidc_issuer_url, None);
+		assert_eq!(config.oidc_port, DEFAULT_OIDC_PORT);
 		assert_eq!(config.data_dir, pwd());
 		assert!(config.run_config.is_none());
 		assert_eq!(config.mock_server_port, DEFAULT_MOCK_SERVER_PORT);
@@ -458,6 +480,8 @@ mod test {
 		let mu_ra_ext_addr = "1.1.3.1:1000";
 		let mu_ra_port = "99";
 		let untrusted_http_port = "4321";
+		let oidc_issuer_url = "https://oidc.litentry.example";
+		let oidc_port = "4747";
 
 		let mock_server_port = "19527";
 		let parentchain_start_block = "30";
@@ -474,6 +498,8 @@ mod test {
 			("untrusted-worker-port", Default::default()),
 			("trusted-worker-port", Default::default()),
 			("untrusted-http-port", Default::default()),
+			("oidc-issuer-url", Default::default()),
+			("oidc-port", Default::default()),
 			("mock-server-port", Default::default()),
 			("parentchain-start-block", Default::default()),
 		]);
@@ -488,6 +514,8 @@ mod test {
 		args.args.get_mut("untrusted-worker-port").unwrap().vals = vec![untrusted_port.into()];
 		args.args.get_mut("trusted-worker-port").unwrap().vals = vec![trusted_port.into()];
 		args.args.get_mut("untrusted-http-port").unwrap().vals = vec![untrusted_http_port.into()];
+		args.args.get_mut("oidc-issuer-url").unwrap().vals = vec![oidc_issuer_url.into()];
+		args.args.get_mut("oidc-port").unwrap().vals = vec![oidc_port.into()];
 		args.args.get_mut("mock-server-port").unwrap().vals = vec![mock_server_port.into()];
 		args.args.get_mut("parentchain-start-block").unwrap().vals =
 			vec![parentchain_start_block.into()];
@@ -503,6 +531,8 @@ mod test {
 		assert_eq!(config.untrusted_external_worker_address, Some(untrusted_ext_addr.to_string()));
 		assert_eq!(config.mu_ra_external_address, Some(mu_ra_ext_addr.to_string()));
 		assert_eq!(config.untrusted_http_port, untrusted_http_port.to_string());
+		assert_eq!(config.oidc_issuer_url(), Some(oidc_issuer_url));
+		assert_eq!(config.try_parse_oidc_port(), Some(4747));
 		assert_eq!(config.mock_server_port, mock_server_port.to_string());
 		assert_eq!(config.parentchain_start_block, parentchain_start_block.to_string());
 	}
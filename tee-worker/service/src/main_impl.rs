@@ -15,6 +15,7 @@ use crate::{
 	ocall_bridge::{
 		bridge_api::Bridge as OCallBridge, component_factory::OCallBridgeComponentFactory,
 	},
+	oidc_proxy::start_oidc_proxy,
 	parentchain_handler::{HandleParentchain, ParentchainHandler},
 	prometheus_metrics::{start_metrics_server, EnclaveMetricsReceiver, MetricsHandler},
 	setup,
@@ -69,8 +70,14 @@ use sp_core::crypto::{AccountId32, Ss58Codec};
 use sp_keyring::AccountKeyring;
 use sp_runtime::MultiSigner;
 use std::{
-	collections::HashSet, fmt::Debug, path::PathBuf, str, str::Utf8Error, sync::Arc, thread,
-	time::Duration,
+	collections::HashSet,
+	fmt::Debug,
+	path::PathBuf,
+	str,
+	str::Utf8Error,
+	sync::Arc,
+	thread,
+	time::{Duration, Instant},
 };
 use substrate_api_client::ac_node_api::{EventRecord, Phase::ApplyExtrinsic};
 
@@ -412,6 +419,20 @@ fn start_worker<E, T, D, InitializationHandler, WorkerModeProvider>(
 		});
 	}
 
+	// ------------------------------------------------------------------------
+	// Start the proxy of the OIDC endpoints of the enclave.
+	if let Some(issuer) = config.oidc_issuer_url() {
+		// the issuer is sealed in the enclave on the first run and can't be changed afterwards
+		enclave.init_oidc_issuer(issuer).expect("OIDC issuer to match the sealed one");
+		let trusted_worker_url = config.trusted_worker_url_internal();
+		let oidc_port = config.try_parse_oidc_port().expect("oidc port to be a valid port number");
+		tokio_handle.spawn(async move {
+			if let Err(e) = start_oidc_proxy(trusted_worker_url, oidc_port).await {
+				error!("Unexpected error in OIDC proxy: {:?}", e);
+			}
+		});
+	}
+
 	// ------------------------------------------------------------------------
 	// Start trusted worker rpc server
 	if WorkerModeProvider::worker_mode() == WorkerMode::Sidechain
@@ -509,6 +530,27 @@ fn start_worker<E, T, D, InitializationHandler, WorkerModeProvider>(
 		.expect("our enclave should be registered at this point");
 	trace!("verified that our enclave is registered: {:?}", my_enclave);
 
+	{
+		let enclave = enclave.clone();
+		let api = litentry_rpc_api.clone();
+		let tee_accountid = tee_accountid.clone();
+		let trusted_url = trusted_url.clone();
+		start_periodic_reattestation_thread(
+			litentry_rpc_api.clone(),
+			tee_accountid.clone(),
+			move || {
+				register_enclave(
+					enclave.clone(),
+					&api,
+					&tee_accountid,
+					&trusted_url,
+					skip_ra,
+					is_development_mode,
+				)
+			},
+		);
+	}
+
 	// Litentry:
 	// the logic differs from upstream a bit here (due to different impl in parachain pallet),
 	// theoretically the `primary_enclave_identifier_for_shard` should never be empty, unless the previous
@@ -919,6 +961,52 @@ fn register_collateral(
 	}
 }
 
+/// Re-register the enclave after half of the on-chain `ReattestationPeriod`, so that it's never
+/// evicted for an expired attestation. An enclave that was evicted anyway, e.g. because its TCB
+/// became outdated, is re-registered as soon as possible.
+fn start_periodic_reattestation_thread<F>(
+	api: ParentchainApi,
+	tee_account: AccountId32,
+	send_register_xt: F,
+) where
+	F: Fn() -> ServiceResult<Hash> + Send + 'static,
+{
+	const MIN_INTERVAL_SECS: u64 = 60;
+	const POLL_INTERVAL_SECS: u64 = 60 * 60;
+
+	thread::Builder::new()
+		.name("reattestation_loop".to_owned())
+		.spawn(move || {
+			let mut last_attested = Instant::now();
+			loop {
+				let half_period = api
+					.reattestation_period(None)
+					.map_err(|e| warn!("Failed to get the re-attestation period: {:?}", e))
+					.ok()
+					.flatten()
+					.map(|period| Duration::from_millis(period / 2));
+				let evicted = matches!(api.enclave(&tee_account, None), Ok(None));
+				if evicted {
+					warn!("Enclave is not registered anymore, it was probably evicted");
+				}
+
+				if evicted || half_period.map_or(false, |p| last_attested.elapsed() >= p) {
+					info!("Re-attesting enclave");
+					match send_register_xt() {
+						Ok(_) => last_attested = Instant::now(),
+						Err(e) => error!("Failed to re-attest enclave: {:?}", e),
+					}
+				}
+
+				let poll_interval = Duration::from_secs(POLL_INTERVAL_SECS);
+				thread::sleep(half_period.map_or(poll_interval, |p| {
+					p.clamp(Duration::from_secs(MIN_INTERVAL_SECS), poll_interval)
+				}));
+			}
+		})
+		.unwrap();
+}
+
 fn send_litentry_extrinsic(
 	extrinsic: Vec<u8>,
 	api: &ParentchainApi,
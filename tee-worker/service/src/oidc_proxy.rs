@@ -0,0 +1,125 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Proxy of the OIDC endpoints of the enclave, see `lc_oidc::http`.
+//!
+//! The enclave doesn't serve HTTP, so the requests are forwarded as they are to the
+//! `oidc_request` RPC method of the trusted worker. The issuer URL isn't forwarded, the enclave
+//! uses the one that was sealed by `init_oidc_issuer`.
+
+use crate::error::{Error, ServiceResult};
+use codec::Decode;
+use itc_rpc_client::direct_client::{DirectApi, DirectClient};
+use itp_rpc::{Id, RpcRequest, RpcResponse, RpcReturnValue};
+use itp_types::DirectRequestStatus;
+use itp_utils::{FromHexPrefixed, ToHexPrefixed};
+use lc_oidc::{HttpRequest, HttpResponse};
+use log::*;
+use std::{convert::Infallible, net::SocketAddr};
+use warp::{
+	http::{Method, Response, StatusCode},
+	hyper::body::Bytes,
+	path::FullPath,
+	reply::Reply,
+	Filter,
+};
+
+/// The largest request body that is forwarded, the authorization request can contain VCs
+const MAX_BODY_SIZE: u64 = 256 * 1024;
+
+pub async fn start_oidc_proxy(trusted_worker_url: String, port: u16) -> ServiceResult<()> {
+	let route = warp::method()
+		.and(warp::path::full())
+		.and(warp::query::raw().or(warp::any().map(String::new)).unify())
+		.and(warp::header::optional::<String>("authorization"))
+		.and(
+			warp::body::content_length_limit(MAX_BODY_SIZE)
+				.and(warp::body::bytes())
+				.or(warp::get().map(Bytes::new))
+				.unify(),
+		)
+		.and_then(
+			move |method: Method,
+			      path: FullPath,
+			      query: String,
+			      authorization: Option<String>,
+			      body: Bytes| {
+				let request = HttpRequest {
+					method: method.to_string(),
+					path: path.as_str().to_string(),
+					query,
+					authorization,
+					body: body.to_vec(),
+				};
+				let url = trusted_worker_url.clone();
+				async move {
+					let response = match tokio::task::spawn_blocking(move || {
+						forward_to_enclave(&url, &request)
+					})
+					.await
+					{
+						Ok(response) => response,
+						Err(e) => Err(Error::Custom(e.into())),
+					};
+					Ok::<_, Infallible>(match response {
+						Ok(response) => into_reply(response),
+						Err(e) => {
+							error!("Failed to forward OIDC request to the enclave: {:?}", e);
+							StatusCode::BAD_GATEWAY.into_response()
+						},
+					})
+				}
+			},
+		);
+
+	let socket_addr: SocketAddr = ([0, 0, 0, 0], port).into();
+
+	info!("Running OIDC proxy on: {:?}", socket_addr);
+	warp::serve(route).run(socket_addr).await;
+
+	info!("OIDC proxy shut down");
+	Ok(())
+}
+
+fn forward_to_enclave(
+	trusted_worker_url: &str,
+	request: &HttpRequest,
+) -> ServiceResult<HttpResponse> {
+	let jsonrpc_call = RpcRequest::compose_jsonrpc_call(
+		Id::Text("1".to_string()),
+		"oidc_request".to_string(),
+		vec![request.to_hex()],
+	)?;
+	let response = DirectClient::new(trusted_worker_url.to_string()).get(&jsonrpc_call)?;
+	let response: RpcResponse = serde_json::from_str(&response)?;
+	let return_value = RpcReturnValue::from_hex(&response.result)
+		.map_err(|e| Error::Custom(format!("{:?}", e).into()))?;
+	if return_value.status != DirectRequestStatus::Ok {
+		let message = String::decode(&mut return_value.value.as_slice())?;
+		return Err(Error::Custom(message.into()))
+	}
+	Ok(HttpResponse::decode(&mut return_value.value.as_slice())?)
+}
+
+fn into_reply(response: HttpResponse) -> warp::reply::Response {
+	let mut builder = Response::builder().status(response.status);
+	for (name, value) in response.headers {
+		builder = builder.header(name, value);
+	}
+	builder
+		.body(response.body.into())
+		.unwrap_or_else(|_| StatusCode::BAD_GATEWAY.into_response())
+}
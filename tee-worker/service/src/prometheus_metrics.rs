@@ -271,10 +271,12 @@ fn handle_stf_call_request(req: RequestType, time: f64) {
 	let category = match req {
 		RequestType::IdentityVerification(_) => "link_identity",
 		RequestType::AssertionVerification(_) => "request_vc",
+		RequestType::IdentityReverification(_) => "reverify_identity",
 	};
 
 	let label: String = match req {
-		RequestType::IdentityVerification(request) => match request.identity {
+		RequestType::IdentityVerification(request)
+		| RequestType::IdentityReverification(request) => match request.identity {
 			Identity::Twitter(_) => "Twitter".into(),
 			Identity::Discord(_) => "Discord".into(),
 			Identity::Github(_) => "Github".into(),
@@ -54,6 +54,8 @@ pub fn local_worker_config(
 		false,
 		"8787".to_string(),
 		"4545".to_string(),
+		None,
+		"4646".to_string(),
 		crate::config::pwd(),
 		None,
 		false,
@@ -66,4 +66,8 @@ impl PalletTeebagApi for TestNodeApi {
 	) -> ApiResult<Option<Enclave>> {
 		unreachable!()
 	}
+
+	fn reattestation_period(&self, _at_block: Option<Hash>) -> ApiResult<Option<u64>> {
+		unreachable!()
+	}
 }
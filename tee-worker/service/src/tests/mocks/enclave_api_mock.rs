@@ -104,6 +104,10 @@ impl EnclaveBase for EnclaveMock {
 	fn migrate_shard(&self, new_shard: Vec<u8>) -> EnclaveResult<()> {
 		unimplemented!()
 	}
+
+	fn init_oidc_issuer(&self, _issuer: &str) -> EnclaveResult<()> {
+		Ok(())
+	}
 }
 
 impl Sidechain for EnclaveMock {
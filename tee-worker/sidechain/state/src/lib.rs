@@ -193,7 +193,10 @@ impl<T: SidechainState> SidechainSystemExt for T {
 	}
 
 	fn set_timestamp(&mut self, timestamp: &Timestamp) {
-		self.set_with_name("System", "Timestamp", timestamp)
+		self.set_with_name("System", "Timestamp", timestamp);
+		// Litentry: mirror it to `pallet_timestamp`, so that the STF reads the block timestamp
+		// from `Timestamp::now()` rather than the enclave clock
+		self.set_with_name("Timestamp", "Now", timestamp)
 	}
 
 	fn reset_events(&mut self) {
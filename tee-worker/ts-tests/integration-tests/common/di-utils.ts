@@ -245,6 +245,54 @@ export async function createSignedTrustedCallSetIdentityNetworks(
     );
 }
 
+export async function createSignedTrustedCallSetIdentityLabel(
+    parachainApi: ApiPromise,
+    mrenclave: string,
+    nonce: Codec,
+    signer: Signer,
+    primeIdentity: CorePrimitivesIdentity,
+    identity: string,
+    label: string | null,
+    aesKey: string,
+    hash: string
+) {
+    return createSignedTrustedCall(
+        parachainApi,
+        [
+            'set_identity_label',
+            '(LitentryIdentity, LitentryIdentity, LitentryIdentity, Option<Bytes>, Option<RequestAesKey>, H256)',
+        ],
+        signer,
+        mrenclave,
+        nonce,
+        [primeIdentity.toHuman(), primeIdentity.toHuman(), identity, label, aesKey, hash]
+    );
+}
+
+export async function createSignedTrustedCallReverifyIdentity(
+    parachainApi: ApiPromise,
+    mrenclave: string,
+    nonce: Codec,
+    signer: Signer,
+    primeIdentity: CorePrimitivesIdentity,
+    identity: string,
+    validationData: string,
+    aesKey: string,
+    hash: string
+) {
+    return createSignedTrustedCall(
+        parachainApi,
+        [
+            'reverify_identity',
+            '(LitentryIdentity, LitentryIdentity, LitentryIdentity, Web2ValidationData, Option<RequestAesKey>, H256)',
+        ],
+        signer,
+        mrenclave,
+        nonce,
+        [primeIdentity.toHuman(), primeIdentity.toHuman(), identity, validationData, aesKey, hash]
+    );
+}
+
 export async function createSignedTrustedCallRequestVc(
     parachainApi: ApiPromise,
     mrenclave: string,
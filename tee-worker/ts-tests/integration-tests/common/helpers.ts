@@ -100,6 +100,12 @@ export function stfErrorToString(stfError: StfError): string {
         stfError.isLinkIdentityFailed ||
         stfError.isMissingPrivileges ||
         stfError.isRemoveIdentityFailed ||
+        stfError.isUnlinkIdentityFailed ||
+        stfError.isMergeIDGraphsFailed ||
+        stfError.isTransferPrimeIdentityFailed ||
+        stfError.isRecoveryFailed ||
+        stfError.isReverifyIdentityFailed ||
+        stfError.isSetIdentityLabelFailed ||
         stfError.isDispatch
     ) {
         const errorDetail = stfError.value as ErrorDetail;